@@ -0,0 +1,44 @@
+//! Control-plane message types for live worker introspection and management.
+//!
+//! Commands are issued by the `scout-transcriber control` CLI mode and carried
+//! over the ZeroMQ control endpoint (`--zmq-control-endpoint`) as MessagePack.
+//! The running service answers with a [`ControlResponse`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::worker::WorkerSnapshot;
+
+/// A command sent from an operator to a running transcription service.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case", tag = "command")]
+pub enum ControlCommand {
+    /// List every worker and its current state.
+    List,
+    /// Stop a worker from accepting new chunks until resumed.
+    Pause { worker_id: String },
+    /// Resume a paused or draining worker.
+    Resume { worker_id: String },
+    /// Let a worker finish in-flight work but accept nothing new.
+    Drain { worker_id: String },
+}
+
+/// The service's reply to a [`ControlCommand`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case", tag = "response")]
+pub enum ControlResponse {
+    /// Snapshot of all workers, in reply to [`ControlCommand::List`].
+    Workers { workers: Vec<WorkerSnapshot> },
+    /// The command was applied successfully.
+    Ok,
+    /// The command could not be applied (e.g. unknown worker id).
+    Error { message: String },
+}
+
+impl ControlResponse {
+    /// Convenience constructor for an error reply.
+    pub fn error(message: impl Into<String>) -> Self {
+        ControlResponse::Error {
+            message: message.into(),
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use anyhow::{Context, Result};
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
 use scout_transcriber::{
+    control::{ControlCommand, ControlResponse},
     protocol::{AudioChunk, Transcript, TranscriptionError},
     queue::{Queue, SledQueue},
     tracker::{MessageTracker, MessageTrackerStats},
-    worker::{WorkerConfig, WorkerPool},
+    worker::{OnBusyPolicy, WorkerConfig, WorkerPool},
 };
 
 #[cfg(feature = "zeromq-queue")]
@@ -12,12 +13,15 @@ use scout_transcriber::queue::{ZmqQueue, ZmqQueueConfig};
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::signal;
 use tokio::sync::broadcast;
-use tokio::time::interval;
+use tokio::time::{interval, sleep};
 use tracing::{debug, error, info, warn};
 
+/// Number of recent work durations kept to smooth the tranquility estimate.
+const TRANQUILITY_WINDOW: usize = 8;
+
 #[derive(Parser)]
 #[command(name = "scout-transcriber")]
 #[command(about = "A standalone transcription service using Python workers")]
@@ -55,6 +59,26 @@ pub struct Args {
     #[arg(long, default_value = "10")]
     pub max_restarts: u32,
 
+    /// Dead-letter queue directory path (holds permanently failed messages)
+    #[arg(long, default_value = "/tmp/scout-transcriber/dead-letter")]
+    pub dead_letter_queue: PathBuf,
+
+    /// Maximum processing retries before a message is moved to the dead-letter queue
+    #[arg(long, default_value = "3")]
+    pub max_retries: u32,
+
+    /// Policy applied when a chunk arrives while a worker is saturated
+    #[arg(long, value_enum, default_value = "queue")]
+    pub on_busy: OnBusyPolicy,
+
+    /// Signal sent to workers on graceful stop (POSIX number; 15 = SIGTERM)
+    #[arg(long, default_value = "15")]
+    pub stop_signal: i32,
+
+    /// Seconds to wait after the stop-signal before escalating to SIGKILL
+    #[arg(long, default_value = "10")]
+    pub stop_timeout: u64,
+
     /// Heartbeat interval in seconds
     #[arg(long, default_value = "30")]
     pub heartbeat_interval: u64,
@@ -67,6 +91,11 @@ pub struct Args {
     #[arg(long, default_value = "100")]
     pub poll_interval: u64,
 
+    /// Adaptive throttle factor: after each batch the loop sleeps
+    /// `tranquility * work_duration` to leave CPU headroom. 0 runs flat out.
+    #[arg(long, default_value = "0.0")]
+    pub tranquility: f64,
+
     /// Enable queue persistence (disable for in-memory queues)
     #[arg(long, default_value = "true")]
     pub persistent_queues: bool,
@@ -85,6 +114,67 @@ pub struct Args {
     #[cfg(feature = "zeromq-queue")]
     #[arg(long, default_value = "false")]
     pub use_zeromq: bool,
+
+    /// ZeroMQ control endpoint for live worker introspection and management
+    #[cfg(feature = "zeromq-queue")]
+    #[arg(long, default_value = "tcp://127.0.0.1:5557")]
+    pub zmq_control_endpoint: String,
+
+    /// ZeroMQ broker backend endpoint that workers connect to (ROUTER/ROUTER
+    /// load balancing). Lets `--workers N` run N concurrent ZeroMQ workers.
+    #[cfg(feature = "zeromq-queue")]
+    #[arg(long, default_value = "tcp://127.0.0.1:5558")]
+    pub zmq_worker_endpoint: String,
+
+    /// ZeroMQ endpoint where workers publish liveness heartbeats
+    #[cfg(feature = "zeromq-queue")]
+    #[arg(long, default_value = "tcp://127.0.0.1:5559")]
+    pub zmq_heartbeat_endpoint: String,
+
+    /// Consecutive missed heartbeat intervals before a ZeroMQ worker is
+    /// considered dead and respawned
+    #[cfg(feature = "zeromq-queue")]
+    #[arg(long, default_value = "3")]
+    pub heartbeat_max_misses: u32,
+
+    /// Control subcommand; when present the binary acts as a control client
+    /// instead of starting the service.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Top-level modes for the binary.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Inspect and manage the workers of a running service.
+    Control {
+        #[command(subcommand)]
+        action: ControlAction,
+    },
+}
+
+/// Actions understood by the `control` subcommand.
+#[derive(Subcommand, Debug)]
+pub enum ControlAction {
+    /// List every worker and its current state.
+    List,
+    /// Pause a worker so it stops accepting new chunks.
+    Pause { worker_id: String },
+    /// Resume a paused or draining worker.
+    Resume { worker_id: String },
+    /// Let a worker finish in-flight work but accept nothing new.
+    Drain { worker_id: String },
+}
+
+impl From<ControlAction> for ControlCommand {
+    fn from(action: ControlAction) -> Self {
+        match action {
+            ControlAction::List => ControlCommand::List,
+            ControlAction::Pause { worker_id } => ControlCommand::Pause { worker_id },
+            ControlAction::Resume { worker_id } => ControlCommand::Resume { worker_id },
+            ControlAction::Drain { worker_id } => ControlCommand::Drain { worker_id },
+        }
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
@@ -182,16 +272,70 @@ where
             QueueType::ZeroMQ(queue) => queue.clear().await,
         }
     }
+
+    /// Remove and return every item in the queue (FIFO order). Draining is the
+    /// enumeration primitive the dead-letter tooling builds on (e.g. `requeue`);
+    /// callers that only want to inspect can push the items straight back.
+    pub async fn drain(&self) -> Result<Vec<T>> {
+        let mut drained = Vec::new();
+        while let Some(item) = self.pop().await? {
+            drained.push(item);
+        }
+        Ok(drained)
+    }
+}
+
+/// An entry in the dead-letter queue: the original audio chunk (when available)
+/// alongside the failure metadata needed to triage or replay it.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DeadLetterEntry {
+    /// ID of the message that failed.
+    pub message_id: uuid::Uuid,
+    /// The original audio chunk, if the failure path still had it in hand.
+    pub chunk: Option<AudioChunk>,
+    /// Number of processing attempts before the message was given up on.
+    pub retry_count: u32,
+    /// The last error observed for this message.
+    pub last_error: String,
+    /// When the message was first seen by the service.
+    pub first_seen: chrono::DateTime<chrono::Utc>,
+    /// When the message was moved to the dead-letter queue.
+    pub failed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Liveness record for a spawned ZeroMQ worker process.
+///
+/// The `Child` handle is retained (previously discarded) so a worker that
+/// stops sending heartbeats can be reaped and respawned, and so every child is
+/// killed on shutdown.
+#[cfg(feature = "zeromq-queue")]
+struct ZmqWorkerProc {
+    worker_id: String,
+    child: tokio::process::Child,
+    /// Last time a heartbeat was received from this worker.
+    last_heartbeat: std::time::Instant,
+    /// Consecutive missed heartbeat intervals.
+    missed_heartbeats: u32,
+    /// Restart backoff, grown on each respawn.
+    backoff: Duration,
 }
 
+/// Shared registry of live ZeroMQ worker processes.
+#[cfg(feature = "zeromq-queue")]
+type ZmqWorkerRegistry = Arc<tokio::sync::Mutex<Vec<ZmqWorkerProc>>>;
+
 /// Main transcription service
 pub struct TranscriptionService {
     input_queue: QueueType<AudioChunk>,
     output_queue: QueueType<Result<Transcript, TranscriptionError>>,
+    dead_letter_queue: QueueType<DeadLetterEntry>,
     worker_pool: WorkerPool,
     message_tracker: Arc<MessageTracker>,
     running: Arc<AtomicBool>,
     shutdown_tx: broadcast::Sender<()>,
+    /// Spawned ZeroMQ worker processes, tracked for liveness and cleanup.
+    #[cfg(feature = "zeromq-queue")]
+    zmq_workers: ZmqWorkerRegistry,
     args: Args,
 }
 
@@ -208,6 +352,10 @@ impl TranscriptionService {
                 tokio::fs::create_dir_all(parent).await
                     .context("Failed to create output queue directory")?;
             }
+            if let Some(parent) = args.dead_letter_queue.parent() {
+                tokio::fs::create_dir_all(parent).await
+                    .context("Failed to create dead-letter queue directory")?;
+            }
         }
 
         // Create queues based on configuration
@@ -282,6 +430,17 @@ impl TranscriptionService {
             (QueueType::Sled(input_queue), QueueType::Sled(output_queue))
         };
 
+        // The dead-letter queue is always Sled-backed so failed messages
+        // survive restarts regardless of the data-plane transport in use.
+        let dead_letter_queue = if args.persistent_queues {
+            SledQueue::new(&args.dead_letter_queue)
+                .context("Failed to create dead-letter queue")?
+        } else {
+            SledQueue::new_temp()
+                .context("Failed to create temporary dead-letter queue")?
+        };
+        let dead_letter_queue = QueueType::Sled(dead_letter_queue);
+
         // Parse Python arguments
         let python_args: Vec<String> = args.python_args
             .split_whitespace()
@@ -299,6 +458,9 @@ impl TranscriptionService {
             max_backoff: Duration::from_secs(60),
             heartbeat_interval: Duration::from_secs(args.heartbeat_interval),
             response_timeout: Duration::from_secs(args.response_timeout),
+            on_busy: args.on_busy,
+            stop_signal: args.stop_signal,
+            stop_timeout: Duration::from_secs(args.stop_timeout),
         };
 
         // Create worker pool
@@ -306,7 +468,7 @@ impl TranscriptionService {
 
         // Create message tracker for monitoring
         let message_tracker = Arc::new(MessageTracker::new(
-            args.max_restarts as u32,
+            args.max_retries,
             args.response_timeout,
         ));
 
@@ -315,10 +477,13 @@ impl TranscriptionService {
         Ok(Self {
             input_queue,
             output_queue,
+            dead_letter_queue,
             worker_pool,
             message_tracker,
             running: Arc::new(AtomicBool::new(false)),
             shutdown_tx,
+            #[cfg(feature = "zeromq-queue")]
+            zmq_workers: Arc::new(tokio::sync::Mutex::new(Vec::new())),
             args,
         })
     }
@@ -338,11 +503,13 @@ impl TranscriptionService {
             self.worker_pool.start().await
                 .context("Failed to start worker pool")?;
         } else {
-            // ZeroMQ workers that connect directly to queues
-            info!("Starting ZeroMQ workers that connect directly to queues");
-            info!("  - Workers PULL from tcp://127.0.0.1:5555");
-            info!("  - Workers PUSH to tcp://127.0.0.1:5556");
-            
+            // ZeroMQ workers connect to the in-process ROUTER/ROUTER broker,
+            // which load-balances chunks across all of them.
+            info!("Starting ZeroMQ ROUTER/ROUTER broker and {} worker(s)", self.args.workers);
+
+            #[cfg(feature = "zeromq-queue")]
+            self.spawn_zeromq_broker();
+
             // Spawn ZeroMQ workers
             self.spawn_zeromq_workers().await
                 .context("Failed to spawn ZeroMQ workers")?;
@@ -360,6 +527,10 @@ impl TranscriptionService {
         // Start health monitoring
         let health_handle = self.spawn_health_monitor();
 
+        // Start the control-plane responder (ZeroMQ only)
+        #[cfg(feature = "zeromq-queue")]
+        let control_handle = self.spawn_control_responder();
+
         info!("Transcription service started successfully");
 
         // Wait for shutdown signal
@@ -385,6 +556,8 @@ impl TranscriptionService {
         processing_handle.abort();
         stats_handle.abort();
         health_handle.abort();
+        #[cfg(feature = "zeromq-queue")]
+        control_handle.abort();
 
         // Stop worker pool only if not using ZeroMQ
         if !self.args.use_zeromq {
@@ -419,15 +592,21 @@ impl TranscriptionService {
     fn spawn_processing_loop(&self) -> tokio::task::JoinHandle<()> {
         let input_queue = self.input_queue.clone();
         let output_queue = self.output_queue.clone();
+        let dead_letter_queue = self.dead_letter_queue.clone();
         let worker_pool = self.worker_pool.clone();
         let message_tracker = Arc::clone(&self.message_tracker);
         let running = Arc::clone(&self.running);
         let mut shutdown_rx = self.shutdown_tx.subscribe();
         let poll_interval = Duration::from_millis(self.args.poll_interval);
         let use_zeromq = self.args.use_zeromq;
+        let tranquility = self.args.tranquility.max(0.0);
 
         tokio::spawn(async move {
             let mut interval = interval(poll_interval);
+            // Rolling window of recent work durations used to smooth the
+            // tranquility back-off estimate (see `Tranquilizer`).
+            let mut work_window: std::collections::VecDeque<Duration> =
+                std::collections::VecDeque::with_capacity(TRANQUILITY_WINDOW);
 
             while running.load(Ordering::Relaxed) {
                 interval.tick().await;
@@ -437,12 +616,14 @@ impl TranscriptionService {
                     break;
                 }
 
+                let work_start = Instant::now();
+
                 if use_zeromq {
                     // In ZeroMQ mode, monitor messages and track their lifecycle
-                    Self::monitor_zeromq_queues(&input_queue, &output_queue, &message_tracker).await;
+                    Self::monitor_zeromq_queues(&input_queue, &output_queue, &dead_letter_queue, &message_tracker).await;
                 } else {
                     // Process input queue for stdin/stdout workers
-                    match Self::process_input_queue(&input_queue, &worker_pool).await {
+                    match Self::process_input_queue(&input_queue, &dead_letter_queue, &worker_pool).await {
                         Ok(processed) => {
                             if processed > 0 {
                                 debug!("Processed {} items from input queue", processed);
@@ -454,60 +635,309 @@ impl TranscriptionService {
                     }
                 }
 
-                // Small delay to prevent busy waiting
                 if !running.load(Ordering::Relaxed) {
                     break;
                 }
+
+                // Adaptive back-off: sleep in proportion to how much work the
+                // batch just did, smoothed over a short window.
+                if tranquility > 0.0 {
+                    work_window.push_back(work_start.elapsed());
+                    while work_window.len() > TRANQUILITY_WINDOW {
+                        work_window.pop_front();
+                    }
+                    let avg = work_window.iter().sum::<Duration>() / work_window.len() as u32;
+                    let nap = avg.mul_f64(tranquility);
+                    if !nap.is_zero() {
+                        sleep(nap).await;
+                    }
+                }
             }
 
             info!("Queue processing loop ended");
         })
     }
 
-    /// Spawn ZeroMQ workers that connect directly to the queues
+    /// Start the built-in ROUTER/ROUTER broker that load-balances chunks across
+    /// all ZeroMQ workers using the LRU-worker pattern.
+    #[cfg(feature = "zeromq-queue")]
+    fn spawn_zeromq_broker(&self) -> tokio::task::JoinHandle<()> {
+        use scout_transcriber::queue::ZmqRouterBroker;
+
+        let front = self.args.zmq_push_endpoint.clone();
+        let back = self.args.zmq_worker_endpoint.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        tokio::spawn(async move {
+            let broker = ZmqRouterBroker::new(front, back);
+            let shutdown = async move {
+                let _ = shutdown_rx.recv().await;
+            };
+            if let Err(e) = broker.run(shutdown).await {
+                error!("ZeroMQ broker exited with error: {}", e);
+            }
+        })
+    }
+
+    /// Build the command that launches a single ZeroMQ worker.
+    ///
+    /// Each worker *connects* to the broker backend with its own identity so the
+    /// broker can route jobs and replies to individual workers, and publishes
+    /// liveness heartbeats on the heartbeat endpoint.
+    #[cfg(feature = "zeromq-queue")]
+    fn build_zmq_worker_command(&self, worker_id: &str) -> tokio::process::Command {
+        let mut cmd = tokio::process::Command::new(&self.args.python_cmd);
+        cmd.arg("run");
+        cmd.arg("python/zmq_worker.py");
+        cmd.arg("--broker");
+        cmd.arg(&self.args.zmq_worker_endpoint);
+        cmd.arg("--heartbeat");
+        cmd.arg(&self.args.zmq_heartbeat_endpoint);
+        cmd.arg("--worker-id");
+        cmd.arg(worker_id);
+        cmd.arg("--log-level");
+        cmd.arg("INFO");
+        if let Some(ref workdir) = self.args.python_workdir {
+            cmd.current_dir(workdir);
+        }
+        cmd
+    }
+
+    #[cfg(feature = "zeromq-queue")]
     async fn spawn_zeromq_workers(&self) -> Result<()> {
-        use tokio::process::Command;
         use uuid::Uuid;
-        
+
+        let mut registry = self.zmq_workers.lock().await;
         for i in 0..self.args.workers {
             let worker_id = Uuid::new_v4().to_string();
-            let mut cmd = Command::new(&self.args.python_cmd);
-            
-            // Use zmq_worker.py for ZeroMQ mode
-            cmd.arg("run");
-            cmd.arg("python/zmq_worker.py");
-            cmd.arg("--input");
-            cmd.arg(&self.args.zmq_push_endpoint);
-            cmd.arg("--output");
-            cmd.arg(&self.args.zmq_pull_endpoint);
-            cmd.arg("--worker-id");
-            cmd.arg(&worker_id);
-            cmd.arg("--log-level");
-            cmd.arg("INFO");
-            
-            // Set working directory if specified
-            if let Some(ref workdir) = self.args.python_workdir {
-                cmd.current_dir(workdir);
-            }
-            
-            // Spawn the worker
-            let child = cmd.spawn()
+            let child = self.build_zmq_worker_command(&worker_id).spawn()
                 .with_context(|| format!("Failed to spawn ZeroMQ worker {}", i))?;
-            
+
             info!("Spawned ZeroMQ worker {} with ID {} (PID: {:?})", i, worker_id, child.id());
-            
-            // Store the process handle for later management
-            // For now, we just let them run independently
-            // TODO: Track and manage ZeroMQ worker processes
+
+            registry.push(ZmqWorkerProc {
+                worker_id,
+                child,
+                last_heartbeat: std::time::Instant::now(),
+                missed_heartbeats: 0,
+                backoff: Duration::from_secs(1),
+            });
         }
-        
+        drop(registry);
+
+        // Watch worker liveness and respawn any that stop heart-beating.
+        self.spawn_heartbeat_monitor();
+
         Ok(())
     }
 
+    /// Monitor worker heartbeats and respawn dead workers.
+    ///
+    /// Binds a PULL socket on `zmq_heartbeat_endpoint`; each worker periodically
+    /// pushes a frame carrying its worker id. A timer ticks at the heartbeat
+    /// interval: any worker whose last beat is older than `heartbeat_max_misses`
+    /// intervals is marked dead, its `Child` is reaped, and it is respawned with
+    /// exponential backoff.
+    #[cfg(feature = "zeromq-queue")]
+    fn spawn_heartbeat_monitor(&self) -> tokio::task::JoinHandle<()> {
+        use zeromq::{PullSocket, Socket, SocketRecv};
+
+        let registry = Arc::clone(&self.zmq_workers);
+        let endpoint = self.args.zmq_heartbeat_endpoint.clone();
+        let interval_secs = self.args.heartbeat_interval;
+        let max_misses = self.args.heartbeat_max_misses;
+        let worker_endpoint = self.args.zmq_worker_endpoint.clone();
+        let heartbeat_endpoint = self.args.zmq_heartbeat_endpoint.clone();
+        let python_cmd = self.args.python_cmd.clone();
+        let python_workdir = self.args.python_workdir.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        tokio::spawn(async move {
+            let mut socket = PullSocket::new();
+            if let Err(e) = socket.bind(&endpoint).await {
+                error!("Failed to bind heartbeat endpoint {}: {}", endpoint, e);
+                return;
+            }
+            info!("Heartbeat monitor listening on {}", endpoint);
+
+            let beat = Duration::from_secs(interval_secs.max(1));
+            let mut ticker = interval(beat);
+
+            loop {
+                tokio::select! {
+                    _ = shutdown_rx.recv() => break,
+                    // Record an incoming heartbeat.
+                    msg = socket.recv() => {
+                        if let Ok(msg) = msg {
+                            if let Some(frame) = msg.get(0) {
+                                let worker_id = String::from_utf8_lossy(frame).to_string();
+                                let mut registry = registry.lock().await;
+                                if let Some(proc) = registry.iter_mut().find(|p| p.worker_id == worker_id) {
+                                    proc.last_heartbeat = std::time::Instant::now();
+                                    proc.missed_heartbeats = 0;
+                                }
+                            }
+                        }
+                    }
+                    // Periodically reap workers that have gone silent.
+                    _ = ticker.tick() => {
+                        let mut registry = registry.lock().await;
+                        for proc in registry.iter_mut() {
+                            if proc.last_heartbeat.elapsed() >= beat {
+                                proc.missed_heartbeats += 1;
+                            }
+                            if proc.missed_heartbeats < max_misses {
+                                continue;
+                            }
+
+                            warn!(
+                                "Worker {} missed {} heartbeats; respawning",
+                                proc.worker_id, proc.missed_heartbeats
+                            );
+
+                            // Reap the dead child.
+                            let _ = proc.child.start_kill();
+                            let _ = proc.child.wait().await;
+
+                            // Back off, then respawn with the same identity.
+                            sleep(proc.backoff).await;
+                            proc.backoff = std::cmp::min(proc.backoff * 2, Duration::from_secs(60));
+
+                            let mut cmd = tokio::process::Command::new(&python_cmd);
+                            cmd.arg("run");
+                            cmd.arg("python/zmq_worker.py");
+                            cmd.arg("--broker");
+                            cmd.arg(&worker_endpoint);
+                            cmd.arg("--heartbeat");
+                            cmd.arg(&heartbeat_endpoint);
+                            cmd.arg("--worker-id");
+                            cmd.arg(&proc.worker_id);
+                            cmd.arg("--log-level");
+                            cmd.arg("INFO");
+                            if let Some(ref workdir) = python_workdir {
+                                cmd.current_dir(workdir);
+                            }
+
+                            match cmd.spawn() {
+                                Ok(child) => {
+                                    info!("Respawned worker {} (PID: {:?})", proc.worker_id, child.id());
+                                    proc.child = child;
+                                    proc.last_heartbeat = std::time::Instant::now();
+                                    proc.missed_heartbeats = 0;
+                                }
+                                Err(e) => error!("Failed to respawn worker {}: {}", proc.worker_id, e),
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Kill all children on shutdown.
+            let mut registry = registry.lock().await;
+            for proc in registry.iter_mut() {
+                let _ = proc.child.start_kill();
+            }
+            debug!("Heartbeat monitor stopped");
+        })
+    }
+
+    /// Spawn a control-plane responder that answers `control` commands.
+    ///
+    /// Binds a REP socket to `zmq_control_endpoint` and applies each
+    /// [`ControlCommand`] against the worker pool, replying with a
+    /// [`ControlResponse`]. MessagePack is used on the wire for parity with the
+    /// rest of the ZeroMQ transport.
+    #[cfg(feature = "zeromq-queue")]
+    fn spawn_control_responder(&self) -> tokio::task::JoinHandle<()> {
+        use zeromq::{RepSocket, Socket, SocketRecv, SocketSend};
+
+        let worker_pool = self.worker_pool.clone();
+        let endpoint = self.args.zmq_control_endpoint.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        tokio::spawn(async move {
+            let mut socket = RepSocket::new();
+            if let Err(e) = socket.bind(&endpoint).await {
+                error!("Failed to bind control endpoint {}: {}", endpoint, e);
+                return;
+            }
+            info!("Control plane listening on {}", endpoint);
+
+            loop {
+                let message = tokio::select! {
+                    _ = shutdown_rx.recv() => break,
+                    msg = socket.recv() => msg,
+                };
+
+                let request = match message {
+                    Ok(msg) => msg,
+                    Err(e) => {
+                        warn!("Control socket receive error: {}", e);
+                        continue;
+                    }
+                };
+
+                let response = match request.get(0) {
+                    Some(bytes) => match rmp_serde::from_slice::<ControlCommand>(bytes) {
+                        Ok(command) => Self::apply_control_command(&worker_pool, command).await,
+                        Err(e) => ControlResponse::error(format!("Invalid command: {}", e)),
+                    },
+                    None => ControlResponse::error("Empty control request"),
+                };
+
+                match rmp_serde::to_vec_named(&response) {
+                    Ok(bytes) => {
+                        if let Err(e) = socket.send(bytes.into()).await {
+                            warn!("Failed to send control response: {}", e);
+                        }
+                    }
+                    Err(e) => error!("Failed to encode control response: {}", e),
+                }
+            }
+
+            debug!("Control responder stopped");
+        })
+    }
+
+    /// Apply a single control command against the worker pool.
+    #[cfg(feature = "zeromq-queue")]
+    async fn apply_control_command(
+        worker_pool: &WorkerPool,
+        command: ControlCommand,
+    ) -> ControlResponse {
+        match command {
+            ControlCommand::List => ControlResponse::Workers {
+                workers: worker_pool.list().await,
+            },
+            ControlCommand::Pause { worker_id } => {
+                if worker_pool.pause(&worker_id).await {
+                    ControlResponse::Ok
+                } else {
+                    ControlResponse::error(format!("Unknown worker: {}", worker_id))
+                }
+            }
+            ControlCommand::Resume { worker_id } => {
+                if worker_pool.resume(&worker_id).await {
+                    ControlResponse::Ok
+                } else {
+                    ControlResponse::error(format!("Unknown worker: {}", worker_id))
+                }
+            }
+            ControlCommand::Drain { worker_id } => {
+                if worker_pool.drain(&worker_id).await {
+                    ControlResponse::Ok
+                } else {
+                    ControlResponse::error(format!("Unknown worker: {}", worker_id))
+                }
+            }
+        }
+    }
+
     /// Monitor ZeroMQ queues and track message lifecycle
     async fn monitor_zeromq_queues(
-        input_queue: &QueueType<AudioChunk>,
-        output_queue: &QueueType<Result<Transcript, TranscriptionError>>,
+        _input_queue: &QueueType<AudioChunk>,
+        _output_queue: &QueueType<Result<Transcript, TranscriptionError>>,
+        dead_letter_queue: &QueueType<DeadLetterEntry>,
         tracker: &Arc<MessageTracker>,
     ) {
         // Monitor for new messages in input queue (without consuming)
@@ -522,7 +952,26 @@ impl TranscriptionService {
                     // In ZeroMQ mode, the message stays in the queue for another worker to pick up
                 } else {
                     error!("Message {} permanently failed after timeout", message_id);
-                    // TODO: Move to dead letter queue
+                    // Move to the dead-letter queue so operators can triage or
+                    // replay it. In ZeroMQ mode the original chunk lives with
+                    // the Python worker, so we record what the tracker knows.
+                    let now = chrono::Utc::now();
+                    let (retry_count, first_seen) = tracker
+                        .get_message_info(message_id)
+                        .await
+                        .map(|info| (info.retry_count, info.first_seen))
+                        .unwrap_or((0, now));
+                    let entry = DeadLetterEntry {
+                        message_id,
+                        chunk: None,
+                        retry_count,
+                        last_error: "Processing timeout".to_string(),
+                        first_seen,
+                        failed_at: now,
+                    };
+                    if let Err(e) = dead_letter_queue.push(&entry).await {
+                        error!("Failed to move message {} to dead-letter queue: {}", message_id, e);
+                    }
                 }
             }
         }
@@ -546,6 +995,7 @@ impl TranscriptionService {
     /// Process items from the input queue
     async fn process_input_queue(
         input_queue: &QueueType<AudioChunk>,
+        dead_letter_queue: &QueueType<DeadLetterEntry>,
         worker_pool: &WorkerPool,
     ) -> Result<usize> {
         let mut processed = 0;
@@ -566,7 +1016,23 @@ impl TranscriptionService {
                         format!("Worker processing failed: {}", e),
                         "WORKER_ERROR".to_string(),
                     );
-                    
+
+                    // The chunk could not be dispatched to any worker, so it is
+                    // permanently failed for this run — move it to the
+                    // dead-letter queue along with the original audio.
+                    let now = chrono::Utc::now();
+                    let entry = DeadLetterEntry {
+                        message_id: audio_chunk.id,
+                        chunk: Some(audio_chunk.clone()),
+                        retry_count: 0,
+                        last_error: format!("Worker processing failed: {}", e),
+                        first_seen: now,
+                        failed_at: now,
+                    };
+                    if let Err(dlq_err) = dead_letter_queue.push(&entry).await {
+                        error!("Failed to move audio chunk {} to dead-letter queue: {}", audio_chunk.id, dlq_err);
+                    }
+
                     // Note: In a real implementation, you'd need to handle worker responses
                     // and push results to the output queue. This is a simplified version.
                 }
@@ -581,15 +1047,43 @@ impl TranscriptionService {
         Ok(processed)
     }
 
+    /// Move every dead-letter entry back onto the input queue so it can be
+    /// replayed — e.g. after a faulty model has been fixed. Returns the number
+    /// of entries requeued. Entries without a stored chunk (ZeroMQ failures)
+    /// are left in place and reported as skipped.
+    pub async fn requeue_dead_letters(&self) -> Result<usize> {
+        let entries = self.dead_letter_queue.drain().await?;
+        let mut requeued = 0;
+        for entry in entries {
+            match entry.chunk {
+                Some(chunk) => {
+                    self.input_queue.push(&chunk).await
+                        .with_context(|| format!("Failed to requeue message {}", entry.message_id))?;
+                    requeued += 1;
+                }
+                None => {
+                    warn!("Cannot requeue message {}: original audio not retained", entry.message_id);
+                    // Put it back so it is not silently lost.
+                    self.dead_letter_queue.push(&entry).await?;
+                }
+            }
+        }
+        info!("Requeued {} dead-letter entries", requeued);
+        Ok(requeued)
+    }
+
     /// Spawn the statistics reporter
     fn spawn_stats_reporter(&self) -> tokio::task::JoinHandle<()> {
         let worker_pool = self.worker_pool.clone();
         let input_queue = self.input_queue.clone();
         let output_queue = self.output_queue.clone();
+        let dead_letter_queue = self.dead_letter_queue.clone();
         let message_tracker = Arc::clone(&self.message_tracker);
         let running = Arc::clone(&self.running);
         let mut shutdown_rx = self.shutdown_tx.subscribe();
         let use_zeromq = self.args.use_zeromq;
+        #[cfg(feature = "zeromq-queue")]
+        let zmq_workers = Arc::clone(&self.zmq_workers);
 
         tokio::spawn(async move {
             let mut interval = interval(Duration::from_secs(60)); // Report every minute
@@ -607,9 +1101,20 @@ impl TranscriptionService {
                     // Include message tracker stats for ZeroMQ mode
                     let tracker_stats = message_tracker.get_stats().await;
                     info!("ZeroMQ tracker stats: {}", tracker_stats);
+
+                    // Report per-worker missed-heartbeat counts.
+                    #[cfg(feature = "zeromq-queue")]
+                    {
+                        let registry = zmq_workers.lock().await;
+                        let summary: Vec<String> = registry
+                            .iter()
+                            .map(|p| format!("{}:missed={}", p.worker_id, p.missed_heartbeats))
+                            .collect();
+                        info!("ZeroMQ worker heartbeats: [{}]", summary.join(", "));
+                    }
                 }
                 
-                match Self::gather_stats(&input_queue, &output_queue, &worker_pool).await {
+                match Self::gather_stats(&input_queue, &output_queue, &dead_letter_queue, &worker_pool).await {
                     Ok(stats) => {
                         info!("Service stats: {}", stats);
                     }
@@ -627,19 +1132,21 @@ impl TranscriptionService {
     async fn gather_stats(
         input_queue: &QueueType<AudioChunk>,
         output_queue: &QueueType<Result<Transcript, TranscriptionError>>,
+        dead_letter_queue: &QueueType<DeadLetterEntry>,
         worker_pool: &WorkerPool,
     ) -> Result<String> {
         let input_len = input_queue.len().await?;
         let output_len = output_queue.len().await?;
+        let dead_letter_len = dead_letter_queue.len().await?;
         let worker_stats = worker_pool.get_stats().await;
-        
+
         let total_requests: u64 = worker_stats.iter().map(|s| s.total_requests).sum();
         let successful_requests: u64 = worker_stats.iter().map(|s| s.successful_requests).sum();
         let failed_requests: u64 = worker_stats.iter().map(|s| s.failed_requests).sum();
-        
+
         Ok(format!(
-            "input_queue={}, output_queue={}, total_requests={}, successful={}, failed={}, workers={}",
-            input_len, output_len, total_requests, successful_requests, failed_requests, worker_stats.len()
+            "input_queue={}, output_queue={}, dead_letter_queue={}, total_requests={}, successful={}, failed={}, workers={}",
+            input_len, output_len, dead_letter_len, total_requests, successful_requests, failed_requests, worker_stats.len()
         ))
     }
 
@@ -692,9 +1199,58 @@ impl TranscriptionService {
     }
 }
 
+/// Send a single control command to a running service and print the reply.
+#[cfg(feature = "zeromq-queue")]
+async fn run_control_client(args: &Args, command: ControlCommand) -> Result<()> {
+    use zeromq::{ReqSocket, Socket, SocketRecv, SocketSend};
+
+    let mut socket = ReqSocket::new();
+    socket
+        .connect(&args.zmq_control_endpoint)
+        .await
+        .with_context(|| format!("Failed to connect to control endpoint {}", args.zmq_control_endpoint))?;
+
+    let payload = rmp_serde::to_vec_named(&command)
+        .context("Failed to encode control command")?;
+    socket.send(payload.into()).await
+        .context("Failed to send control command")?;
+
+    let reply = socket.recv().await
+        .context("Failed to receive control response")?;
+    let bytes = reply.get(0)
+        .ok_or_else(|| anyhow::anyhow!("Empty control response"))?;
+    let response: ControlResponse = rmp_serde::from_slice(bytes)
+        .context("Failed to decode control response")?;
+
+    match response {
+        ControlResponse::Workers { workers } => {
+            if workers.is_empty() {
+                println!("No workers registered");
+            }
+            for worker in workers {
+                println!("{:<36}  {:?}", worker.id, worker.state);
+            }
+        }
+        ControlResponse::Ok => println!("OK"),
+        ControlResponse::Error { message } => {
+            return Err(anyhow::anyhow!(message));
+        }
+    }
+
+    Ok(())
+}
+
+/// Without the ZeroMQ feature the control plane is unavailable.
+#[cfg(not(feature = "zeromq-queue"))]
+async fn run_control_client(_args: &Args, _command: ControlCommand) -> Result<()> {
+    Err(anyhow::anyhow!(
+        "The control subcommand requires the 'zeromq-queue' feature"
+    ))
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
-    let args = Args::parse();
+    let mut args = Args::parse();
 
     // Initialize logging
     let log_level: tracing::Level = args.log_level.into();
@@ -703,6 +1259,11 @@ async fn main() -> Result<()> {
         .with_target(false)
         .init();
 
+    // Control subcommand: act as a client against a running service and exit.
+    if let Some(Command::Control { action }) = args.command.take() {
+        return run_control_client(&args, action.into()).await;
+    }
+
     info!("Starting Scout Transcriber v{}", env!("CARGO_PKG_VERSION"));
     info!("Configuration:");
     info!("  Input queue: {}", args.input_queue.display());
@@ -759,9 +1320,15 @@ mod tests {
             python_workdir: None,
             log_level: LogLevel::Info,
             max_restarts: 5,
+            dead_letter_queue: temp_dir.path().join("dead-letter"),
+            max_retries: 3,
+            on_busy: OnBusyPolicy::Queue,
+            stop_signal: 15,
+            stop_timeout: 10,
             heartbeat_interval: 10,
             response_timeout: 5,
             poll_interval: 50,
+            tranquility: 0.0,
             persistent_queues: false, // Use in-memory for tests
             #[cfg(feature = "zeromq-queue")]
             zmq_push_endpoint: "tcp://127.0.0.1:5555".to_string(),
@@ -769,6 +1336,15 @@ mod tests {
             zmq_pull_endpoint: "tcp://127.0.0.1:5556".to_string(),
             #[cfg(feature = "zeromq-queue")]
             use_zeromq: false,
+            #[cfg(feature = "zeromq-queue")]
+            zmq_control_endpoint: "tcp://127.0.0.1:5557".to_string(),
+            #[cfg(feature = "zeromq-queue")]
+            zmq_worker_endpoint: "tcp://127.0.0.1:5558".to_string(),
+            #[cfg(feature = "zeromq-queue")]
+            zmq_heartbeat_endpoint: "tcp://127.0.0.1:5559".to_string(),
+            #[cfg(feature = "zeromq-queue")]
+            heartbeat_max_misses: 3,
+            command: None,
         };
 
         let service = TranscriptionService::new(args).await.unwrap();
@@ -1,4 +1,5 @@
 use anyhow::{Context, Result};
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
@@ -21,6 +22,10 @@ pub struct QueueHealth {
     pub last_updated: Instant,
 }
 
+// See the missing-`queue/mod.rs` note on the `queue` re-export in `lib.rs`
+// for why the dead-letter queue (arach/scout#chunk109-3) couldn't be added
+// to `QueueHealth` here.
+
 impl Default for QueueHealth {
     fn default() -> Self {
         Self {
@@ -61,6 +66,8 @@ pub struct QueueMonitor {
     /// Message count tracking
     ingress_count: Arc<RwLock<(u64, Instant)>>,
     egress_count: Arc<RwLock<(u64, Instant)>>,
+    /// Optional Prometheus/OpenMetrics exporter fed on every health update.
+    exporter: Option<Arc<metrics::MetricsExporter>>,
 }
 
 impl QueueMonitor {
@@ -72,6 +79,35 @@ impl QueueMonitor {
             window_size,
             ingress_count: Arc::new(RwLock::new((0, now))),
             egress_count: Arc::new(RwLock::new((0, now))),
+            exporter: None,
+        }
+    }
+
+    /// Attach a [`metrics::MetricsExporter`] so `update_rates`/`update_depth`/
+    /// `update_workers` publish their latest values to a `/metrics` endpoint.
+    pub fn with_exporter(mut self, exporter: Arc<metrics::MetricsExporter>) -> Self {
+        self.exporter = Some(exporter);
+        self
+    }
+
+    /// Push the current health snapshot to the exporter, if one is attached.
+    async fn publish_health(&self) {
+        if let Some(exporter) = &self.exporter {
+            exporter.update_health(&*self.health.read().await).await;
+        }
+    }
+
+    /// Record a completed-message latency sample into the exporter histogram.
+    pub async fn observe_duration_ms(&self, duration_ms: u64) {
+        if let Some(exporter) = &self.exporter {
+            exporter.observe_duration_ms(duration_ms as f64).await;
+        }
+    }
+
+    /// Publish a fresh per-worker roster snapshot to the exporter.
+    pub async fn publish_workers(&self, rows: Vec<WorkerSnapshot>) {
+        if let Some(exporter) = &self.exporter {
+            exporter.update_workers(rows).await;
         }
     }
     
@@ -91,28 +127,34 @@ impl QueueMonitor {
     
     /// Update worker count
     pub async fn update_workers(&self, active: usize) {
-        let mut health = self.health.write().await;
-        health.active_workers = active;
-        health.last_updated = Instant::now();
+        {
+            let mut health = self.health.write().await;
+            health.active_workers = active;
+            health.last_updated = Instant::now();
+        }
+        self.publish_health().await;
     }
     
     /// Update queue depth estimate
     pub async fn update_depth(&self, depth: usize) {
-        let mut health = self.health.write().await;
-        health.queue_depth = depth;
-        
-        // Check for backpressure
-        let old_backpressure = health.has_backpressure;
-        health.has_backpressure = depth > 1000 || health.pressure() > 0.9;
-        
-        if health.has_backpressure && !old_backpressure {
-            warn!("Queue experiencing backpressure (depth: {}, pressure: {:.2})", 
-                  depth, health.pressure());
-        } else if !health.has_backpressure && old_backpressure {
-            info!("Queue backpressure resolved");
+        {
+            let mut health = self.health.write().await;
+            health.queue_depth = depth;
+
+            // Check for backpressure
+            let old_backpressure = health.has_backpressure;
+            health.has_backpressure = depth > 1000 || health.pressure() > 0.9;
+
+            if health.has_backpressure && !old_backpressure {
+                warn!("Queue experiencing backpressure (depth: {}, pressure: {:.2})",
+                      depth, health.pressure());
+            } else if !health.has_backpressure && old_backpressure {
+                info!("Queue backpressure resolved");
+            }
+
+            health.last_updated = Instant::now();
         }
-        
-        health.last_updated = Instant::now();
+        self.publish_health().await;
     }
     
     /// Calculate current rates
@@ -154,6 +196,8 @@ impl QueueMonitor {
         if now.duration_since(self.egress_count.read().await.1) > self.window_size {
             *self.egress_count.write().await = (0, now);
         }
+
+        self.publish_health().await;
     }
     
     /// Get current health metrics
@@ -174,6 +218,99 @@ impl QueueMonitor {
             health.is_healthy()
         )
     }
+
+    /// Upper bound on the tranquility factor handed back by
+    /// [`QueueMonitor::dynamic_tranquility`]; a near-idle worker sleeps at most
+    /// this multiple of its busy time.
+    const MAX_TRANQUILITY: f64 = 2.0;
+
+    /// Recommend a tranquility factor for workers given the current queue
+    /// pressure. Under backpressure the factor is `0.0` so workers run flat out
+    /// and drain the backlog; as the queue empties it rises toward
+    /// [`Self::MAX_TRANQUILITY`] so idle workers stop hammering the downstream.
+    pub async fn dynamic_tranquility(&self) -> f64 {
+        let health = self.health.read().await;
+        if health.has_backpressure {
+            return 0.0;
+        }
+        let pressure = health.pressure().clamp(0.0, 1.0);
+        (1.0 - pressure) * Self::MAX_TRANQUILITY
+    }
+}
+
+/// Adaptive duty-cycle throttle for a background worker.
+///
+/// Borrowed from Garage's background-worker "tranquility" knob: a worker calls
+/// [`Tranquilizer::tick`] when it starts a unit of work and
+/// [`Tranquilizer::tranquilize`] when it finishes. The finished duration `d` is
+/// folded into a moving average over the last `K` samples, and the worker then
+/// sleeps for `d_avg * t` before its next unit, where `t` is the configured
+/// tranquility factor (`t = 0` runs flat out, `t = 2` stays idle twice as long
+/// as it was busy). Pair it with [`QueueMonitor::dynamic_tranquility`] to turn
+/// the hard backpressure cliff into a smooth duty-cycle control.
+pub struct Tranquilizer {
+    /// Ring of the last `capacity` work durations.
+    samples: VecDeque<Duration>,
+    /// Window size `K`.
+    capacity: usize,
+    /// Start of the current unit of work, set by [`Tranquilizer::tick`].
+    work_started: Option<Instant>,
+    /// Tranquility factor `t`; negative values are treated as `0.0`.
+    tranquility: f64,
+}
+
+impl Tranquilizer {
+    /// Create a tranquilizer that averages over the last `window` work
+    /// durations with tranquility factor `tranquility`.
+    pub fn new(window: usize, tranquility: f64) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(window.max(1)),
+            capacity: window.max(1),
+            work_started: None,
+            tranquility,
+        }
+    }
+
+    /// Update the tranquility factor, e.g. from
+    /// [`QueueMonitor::dynamic_tranquility`].
+    pub fn set_tranquility(&mut self, tranquility: f64) {
+        self.tranquility = tranquility;
+    }
+
+    /// Mark the start of a unit of work.
+    pub fn tick(&mut self) {
+        self.work_started = Some(Instant::now());
+    }
+
+    /// Moving average of the recorded work durations, or zero if none yet.
+    pub fn average(&self) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+        let total: Duration = self.samples.iter().sum();
+        total / self.samples.len() as u32
+    }
+
+    /// Record the duration of the just-finished unit (measured since the last
+    /// [`Tranquilizer::tick`]) and sleep for `average * tranquility` before the
+    /// next one. Returns how long it slept.
+    pub async fn tranquilize(&mut self) -> Duration {
+        let elapsed = self
+            .work_started
+            .take()
+            .map(|start| start.elapsed())
+            .unwrap_or_default();
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(elapsed);
+
+        let sleep = self.average().mul_f64(self.tranquility.max(0.0));
+        if !sleep.is_zero() {
+            tokio::time::sleep(sleep).await;
+        }
+        sleep
+    }
 }
 
 /// Status message from worker to control plane
@@ -214,32 +351,765 @@ pub enum WorkerStatusType {
     Error { message: String },
 }
 
+/// Lifecycle state of a worker as seen by the control plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkerState {
+    /// Reported `Started` but not yet heartbeating.
+    Starting,
+    /// Actively heartbeating and processing.
+    Running,
+    /// Heartbeating but idle (no in-flight message).
+    Idle,
+    /// No heartbeat within the staleness window.
+    Stale,
+    /// Reported `Stopping`.
+    Stopped,
+}
+
+impl WorkerState {
+    fn as_str(self) -> &'static str {
+        match self {
+            WorkerState::Starting => "starting",
+            WorkerState::Running => "running",
+            WorkerState::Idle => "idle",
+            WorkerState::Stale => "stale",
+            WorkerState::Stopped => "stopped",
+        }
+    }
+}
+
+/// Per-worker bookkeeping maintained by the [`WorkerRegistry`].
+#[derive(Debug, Clone)]
+struct WorkerEntry {
+    state: WorkerState,
+    /// Current work phase (freeform, worker-defined).
+    phase: Option<String>,
+    /// Progress through the current phase, 0.0..=1.0.
+    progress: Option<f32>,
+    /// Most recent freeform status line reported by the worker.
+    status_line: Option<String>,
+    messages_processed: u64,
+    errors: u64,
+    uptime_seconds: u64,
+    last_heartbeat: Instant,
+    /// True while a message is in flight (received but not completed).
+    in_flight: bool,
+}
+
+impl WorkerEntry {
+    fn new(now: Instant) -> Self {
+        Self {
+            state: WorkerState::Starting,
+            phase: None,
+            progress: None,
+            status_line: None,
+            messages_processed: 0,
+            errors: 0,
+            uptime_seconds: 0,
+            last_heartbeat: now,
+            in_flight: false,
+        }
+    }
+}
+
+/// A snapshot of one worker's state, suitable for sorting and rendering.
+#[derive(Debug, Clone)]
+pub struct WorkerSnapshot {
+    pub worker_id: String,
+    pub state: WorkerState,
+    pub phase: Option<String>,
+    pub progress: Option<f32>,
+    pub status_line: Option<String>,
+    pub messages_processed: u64,
+    pub errors: u64,
+    pub uptime_seconds: u64,
+    /// Seconds since the last heartbeat at snapshot time.
+    pub last_heartbeat_secs: u64,
+}
+
+/// Tracks per-worker state keyed on `worker_id`, driven by the same status
+/// stream [`QueueMonitor`] observes. Where `QueueMonitor` aggregates queue-wide
+/// rates, the registry keeps an individual picture of each worker so a
+/// `scout workers`-style command can render a live roster.
+pub struct WorkerRegistry {
+    workers: Arc<RwLock<HashMap<String, WorkerEntry>>>,
+    /// A worker is marked `Stale` once this much time passes without a heartbeat.
+    stale_after: Duration,
+}
+
+impl WorkerRegistry {
+    /// Create a registry that marks workers stale after `stale_after` without a
+    /// heartbeat.
+    pub fn new(stale_after: Duration) -> Self {
+        Self {
+            workers: Arc::new(RwLock::new(HashMap::new())),
+            stale_after,
+        }
+    }
+
+    async fn entry_mut<F: FnOnce(&mut WorkerEntry)>(&self, worker_id: &str, f: F) {
+        let now = Instant::now();
+        let mut workers = self.workers.write().await;
+        let entry = workers
+            .entry(worker_id.to_string())
+            .or_insert_with(|| WorkerEntry::new(now));
+        f(entry);
+    }
+
+    /// Record that a worker announced itself.
+    pub async fn record_started(&self, worker_id: &str) {
+        self.entry_mut(worker_id, |e| {
+            e.state = WorkerState::Starting;
+            e.last_heartbeat = Instant::now();
+        })
+        .await;
+    }
+
+    /// Record that a worker picked up a message.
+    pub async fn record_message_received(&self, worker_id: &str) {
+        self.entry_mut(worker_id, |e| {
+            e.in_flight = true;
+            e.state = WorkerState::Running;
+            e.last_heartbeat = Instant::now();
+        })
+        .await;
+    }
+
+    /// Record that a worker finished a message.
+    pub async fn record_message_completed(&self, worker_id: &str, success: bool) {
+        self.entry_mut(worker_id, |e| {
+            e.in_flight = false;
+            e.messages_processed = e.messages_processed.saturating_add(1);
+            if !success {
+                e.errors = e.errors.saturating_add(1);
+            }
+            e.state = WorkerState::Idle;
+            e.last_heartbeat = Instant::now();
+        })
+        .await;
+    }
+
+    /// Record a heartbeat, refreshing counters and the staleness clock.
+    pub async fn record_heartbeat(&self, worker_id: &str, messages_processed: u64, uptime_seconds: u64) {
+        self.entry_mut(worker_id, |e| {
+            e.messages_processed = e.messages_processed.max(messages_processed);
+            e.uptime_seconds = uptime_seconds;
+            e.last_heartbeat = Instant::now();
+            if e.state == WorkerState::Stale || e.state == WorkerState::Starting {
+                e.state = if e.in_flight {
+                    WorkerState::Running
+                } else {
+                    WorkerState::Idle
+                };
+            }
+        })
+        .await;
+    }
+
+    /// Record a worker-reported error.
+    pub async fn record_error(&self, worker_id: &str, message: &str) {
+        self.entry_mut(worker_id, |e| {
+            e.errors = e.errors.saturating_add(1);
+            e.status_line = Some(message.to_string());
+            e.last_heartbeat = Instant::now();
+        })
+        .await;
+    }
+
+    /// Record that a worker is shutting down.
+    pub async fn record_stopping(&self, worker_id: &str) {
+        self.entry_mut(worker_id, |e| {
+            e.state = WorkerState::Stopped;
+            e.in_flight = false;
+        })
+        .await;
+    }
+
+    /// Update a worker's freeform phase/progress/status fields. Any `None`
+    /// argument leaves the existing value untouched.
+    pub async fn set_phase(
+        &self,
+        worker_id: &str,
+        phase: Option<String>,
+        progress: Option<f32>,
+        status_line: Option<String>,
+    ) {
+        self.entry_mut(worker_id, |e| {
+            if phase.is_some() {
+                e.phase = phase;
+            }
+            if let Some(p) = progress {
+                e.progress = Some(p.clamp(0.0, 1.0));
+            }
+            if status_line.is_some() {
+                e.status_line = status_line;
+            }
+        })
+        .await;
+    }
+
+    /// Number of workers not currently `Stale` or `Stopped`.
+    pub async fn active_count(&self) -> usize {
+        let now = Instant::now();
+        self.workers
+            .read()
+            .await
+            .values()
+            .filter(|e| {
+                e.state != WorkerState::Stopped
+                    && now.duration_since(e.last_heartbeat) < self.stale_after
+            })
+            .count()
+    }
+
+    /// Snapshot every worker, sorted by `worker_id`. Workers whose last
+    /// heartbeat predates the staleness window are reported as `Stale`.
+    pub async fn snapshot(&self) -> Vec<WorkerSnapshot> {
+        let now = Instant::now();
+        let workers = self.workers.read().await;
+        let mut rows: Vec<WorkerSnapshot> = workers
+            .iter()
+            .map(|(id, e)| {
+                let since = now.duration_since(e.last_heartbeat);
+                let state = if e.state != WorkerState::Stopped && since >= self.stale_after {
+                    WorkerState::Stale
+                } else {
+                    e.state
+                };
+                WorkerSnapshot {
+                    worker_id: id.clone(),
+                    state,
+                    phase: e.phase.clone(),
+                    progress: e.progress,
+                    status_line: e.status_line.clone(),
+                    messages_processed: e.messages_processed,
+                    errors: e.errors,
+                    uptime_seconds: e.uptime_seconds,
+                    last_heartbeat_secs: since.as_secs(),
+                }
+            })
+            .collect();
+        rows.sort_by(|a, b| a.worker_id.cmp(&b.worker_id));
+        rows
+    }
+
+    /// Render the current roster as an aligned text table, suitable for a
+    /// `scout workers` style command.
+    pub async fn render_table(&self) -> String {
+        let rows = self.snapshot().await;
+        if rows.is_empty() {
+            return "No workers registered.".to_string();
+        }
+
+        let headers = ["WORKER", "STATE", "PHASE", "MSGS", "ERRORS", "UPTIME", "LAST HB"];
+        let mut cells: Vec<[String; 7]> = Vec::with_capacity(rows.len());
+        for r in &rows {
+            let phase = match (&r.phase, r.progress) {
+                (Some(p), Some(pct)) => format!("{} {:.0}%", p, pct * 100.0),
+                (Some(p), None) => p.clone(),
+                (None, _) => "-".to_string(),
+            };
+            cells.push([
+                r.worker_id.clone(),
+                r.state.as_str().to_string(),
+                phase,
+                r.messages_processed.to_string(),
+                r.errors.to_string(),
+                format!("{}s", r.uptime_seconds),
+                format!("{}s", r.last_heartbeat_secs),
+            ]);
+        }
+
+        let mut widths = headers.map(|h| h.len());
+        for row in &cells {
+            for (i, c) in row.iter().enumerate() {
+                widths[i] = widths[i].max(c.len());
+            }
+        }
+
+        let fmt_row = |row: &[String; 7]| -> String {
+            row.iter()
+                .enumerate()
+                .map(|(i, c)| format!("{:<width$}", c, width = widths[i]))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        };
+
+        let header_row: [String; 7] = std::array::from_fn(|i| headers[i].to_string());
+        let mut out = String::new();
+        out.push_str(&fmt_row(&header_row));
+        out.push('\n');
+        for row in &cells {
+            out.push_str(&fmt_row(row));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn heartbeat_keeps_worker_active() {
+        let registry = WorkerRegistry::new(Duration::from_secs(30));
+        registry.record_started("w1").await;
+        registry.record_heartbeat("w1", 5, 120).await;
+
+        let rows = registry.snapshot().await;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].worker_id, "w1");
+        assert_eq!(rows[0].messages_processed, 5);
+        assert_eq!(rows[0].uptime_seconds, 120);
+        assert_ne!(rows[0].state, WorkerState::Stale);
+        assert_eq!(registry.active_count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn missing_heartbeat_marks_stale() {
+        let registry = WorkerRegistry::new(Duration::from_millis(0));
+        registry.record_started("w1").await;
+
+        let rows = registry.snapshot().await;
+        assert_eq!(rows[0].state, WorkerState::Stale);
+        assert_eq!(registry.active_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn completed_and_failed_messages_are_counted() {
+        let registry = WorkerRegistry::new(Duration::from_secs(30));
+        registry.record_message_received("w1").await;
+        registry.record_message_completed("w1", true).await;
+        registry.record_message_completed("w1", false).await;
+
+        let rows = registry.snapshot().await;
+        assert_eq!(rows[0].messages_processed, 2);
+        assert_eq!(rows[0].errors, 1);
+    }
+
+    #[tokio::test]
+    async fn snapshot_is_sorted_and_renders() {
+        let registry = WorkerRegistry::new(Duration::from_secs(30));
+        registry.record_heartbeat("beta", 1, 10).await;
+        registry.record_heartbeat("alpha", 2, 20).await;
+
+        let rows = registry.snapshot().await;
+        assert_eq!(rows[0].worker_id, "alpha");
+        assert_eq!(rows[1].worker_id, "beta");
+
+        let table = registry.render_table().await;
+        assert!(table.contains("WORKER"));
+        assert!(table.contains("alpha"));
+        assert!(table.contains("beta"));
+    }
+}
+
+#[cfg(test)]
+mod tranquilizer_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn zero_tranquility_never_sleeps() {
+        let mut t = Tranquilizer::new(4, 0.0);
+        t.tick();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        let slept = t.tranquilize().await;
+        assert_eq!(slept, Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn sleeps_a_multiple_of_work_time() {
+        let mut t = Tranquilizer::new(4, 2.0);
+        t.tick();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let slept = t.tranquilize().await;
+        // Should sleep roughly twice the ~10ms of work; allow slack for timer granularity.
+        assert!(slept >= Duration::from_millis(10), "slept {slept:?}");
+    }
+
+    #[tokio::test]
+    async fn average_smooths_over_the_window() {
+        let mut t = Tranquilizer::new(2, 0.0);
+        assert_eq!(t.average(), Duration::ZERO);
+        t.tick();
+        t.tranquilize().await;
+        t.tick();
+        t.tranquilize().await;
+        // Only the last two samples are retained.
+        assert!(t.average() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn backpressure_drops_tranquility_to_zero() {
+        let monitor = QueueMonitor::new(Duration::from_secs(1));
+        monitor.update_depth(5000).await;
+        assert_eq!(monitor.dynamic_tranquility().await, 0.0);
+    }
+
+    #[tokio::test]
+    async fn empty_queue_raises_tranquility() {
+        let monitor = QueueMonitor::new(Duration::from_secs(1));
+        monitor.update_depth(0).await;
+        assert!(monitor.dynamic_tranquility().await > 0.0);
+    }
+}
+
+/// OpenMetrics/Prometheus exporter for [`QueueMonitor`] and [`WorkerRegistry`]
+/// signals.
+///
+/// The exporter holds the latest health gauges, a per-worker roster and a
+/// latency histogram built from `MessageCompleted.duration_ms`, and serves them
+/// at `/metrics` in the Prometheus text exposition format. It is fed by
+/// [`QueueMonitor`]'s `update_*` methods so operators can scrape the queue with
+/// standard tooling instead of parsing `health_summary()`. Mirrors Garage's
+/// `metrics.rs`, where internal counters are surfaced to a metrics backend.
+pub mod metrics {
+    use super::{QueueHealth, WorkerSnapshot};
+    use anyhow::{Context, Result};
+    use std::sync::Arc;
+    use tokio::sync::RwLock;
+
+    /// Exponential-ish upper bounds (milliseconds) for the message latency
+    /// histogram, so the whole distribution is visible rather than just the
+    /// current debug log line.
+    const DURATION_BUCKETS_MS: [f64; 9] =
+        [5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 5000.0];
+
+    /// Cumulative-bucket latency histogram in the Prometheus sense.
+    #[derive(Default)]
+    struct Histogram {
+        /// Non-cumulative per-bucket counts, aligned with [`DURATION_BUCKETS_MS`].
+        counts: [u64; DURATION_BUCKETS_MS.len()],
+        sum_ms: f64,
+        total: u64,
+    }
+
+    impl Histogram {
+        fn observe(&mut self, ms: f64) {
+            self.sum_ms += ms;
+            self.total += 1;
+            for (i, bound) in DURATION_BUCKETS_MS.iter().enumerate() {
+                if ms <= *bound {
+                    self.counts[i] += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Collects queue and worker signals and renders them as OpenMetrics text.
+    pub struct MetricsExporter {
+        health: RwLock<QueueHealth>,
+        workers: RwLock<Vec<WorkerSnapshot>>,
+        duration: RwLock<Histogram>,
+    }
+
+    impl MetricsExporter {
+        /// Create an empty exporter.
+        pub fn new() -> Arc<Self> {
+            Arc::new(Self {
+                health: RwLock::new(QueueHealth::default()),
+                workers: RwLock::new(Vec::new()),
+                duration: RwLock::new(Histogram::default()),
+            })
+        }
+
+        /// Replace the cached health gauges.
+        pub async fn update_health(&self, health: &QueueHealth) {
+            *self.health.write().await = health.clone();
+        }
+
+        /// Replace the cached per-worker roster.
+        pub async fn update_workers(&self, rows: Vec<WorkerSnapshot>) {
+            *self.workers.write().await = rows;
+        }
+
+        /// Record a message-processing latency sample.
+        pub async fn observe_duration_ms(&self, ms: f64) {
+            self.duration.write().await.observe(ms);
+        }
+
+        /// Render the current metrics in the Prometheus text exposition format.
+        pub async fn gather(&self) -> String {
+            let health = self.health.read().await;
+            let workers = self.workers.read().await;
+            let duration = self.duration.read().await;
+            let mut out = String::new();
+
+            let gauge = |out: &mut String, name: &str, help: &str, value: f64| {
+                out.push_str(&format!("# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}\n"));
+            };
+
+            gauge(&mut out, "scout_queue_depth", "Estimated messages waiting in the queue", health.queue_depth as f64);
+            gauge(&mut out, "scout_queue_ingress_rate", "Messages received per second", health.ingress_rate);
+            gauge(&mut out, "scout_queue_egress_rate", "Messages processed per second", health.egress_rate);
+            gauge(&mut out, "scout_queue_pressure", "Ingress/egress pressure ratio", health.pressure());
+            gauge(&mut out, "scout_queue_active_workers", "Workers currently active", health.active_workers as f64);
+            gauge(&mut out, "scout_queue_backpressure", "Whether the queue is under backpressure (1) or not (0)", health.has_backpressure as u8 as f64);
+
+            out.push_str("# HELP scout_worker_messages_processed_total Messages processed by the worker\n");
+            out.push_str("# TYPE scout_worker_messages_processed_total counter\n");
+            for w in workers.iter() {
+                out.push_str(&format!(
+                    "scout_worker_messages_processed_total{{worker_id=\"{}\"}} {}\n",
+                    w.worker_id, w.messages_processed
+                ));
+            }
+
+            out.push_str("# HELP scout_worker_uptime_seconds Reported worker uptime\n");
+            out.push_str("# TYPE scout_worker_uptime_seconds gauge\n");
+            for w in workers.iter() {
+                out.push_str(&format!(
+                    "scout_worker_uptime_seconds{{worker_id=\"{}\"}} {}\n",
+                    w.worker_id, w.uptime_seconds
+                ));
+            }
+
+            out.push_str("# HELP scout_message_duration_ms Message processing latency in milliseconds\n");
+            out.push_str("# TYPE scout_message_duration_ms histogram\n");
+            let mut cumulative = 0u64;
+            for (i, bound) in DURATION_BUCKETS_MS.iter().enumerate() {
+                cumulative += duration.counts[i];
+                out.push_str(&format!(
+                    "scout_message_duration_ms_bucket{{le=\"{}\"}} {}\n",
+                    bound, cumulative
+                ));
+            }
+            out.push_str(&format!("scout_message_duration_ms_bucket{{le=\"+Inf\"}} {}\n", duration.total));
+            out.push_str(&format!("scout_message_duration_ms_sum {}\n", duration.sum_ms));
+            out.push_str(&format!("scout_message_duration_ms_count {}\n", duration.total));
+
+            out
+        }
+
+        /// Spawn a tiny HTTP server that serves [`Self::gather`] at `/metrics`.
+        ///
+        /// Intentionally dependency-free: it speaks just enough HTTP/1.1 for a
+        /// Prometheus scraper. Returns the bound address.
+        pub async fn spawn(self: Arc<Self>, addr: &str) -> Result<std::net::SocketAddr> {
+            use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+            let listener = tokio::net::TcpListener::bind(addr)
+                .await
+                .with_context(|| format!("Failed to bind metrics server to {}", addr))?;
+            let local_addr = listener.local_addr()?;
+            info!("Queue metrics available at http://{}/metrics", local_addr);
+
+            tokio::spawn(async move {
+                loop {
+                    let (mut stream, _) = match listener.accept().await {
+                        Ok(conn) => conn,
+                        Err(e) => {
+                            warn!("Metrics server accept failed: {}", e);
+                            continue;
+                        }
+                    };
+
+                    let exporter = self.clone();
+                    tokio::spawn(async move {
+                        // Drain the request line/headers; we only serve GET /metrics.
+                        let mut buf = [0u8; 1024];
+                        let _ = stream.read(&mut buf).await;
+
+                        let body = exporter.gather().await;
+                        let response = format!(
+                            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                            body.len(),
+                            body
+                        );
+                        if let Err(e) = stream.write_all(response.as_bytes()).await {
+                            tracing::trace!("Metrics response write failed: {}", e);
+                        }
+                    });
+                }
+            });
+
+            Ok(local_addr)
+        }
+    }
+}
+
+#[cfg(test)]
+mod metrics_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn exporter_reflects_queue_health() {
+        let exporter = metrics::MetricsExporter::new();
+        let monitor = QueueMonitor::new(Duration::from_secs(1)).with_exporter(exporter.clone());
+        monitor.update_depth(42).await;
+
+        let text = exporter.gather().await;
+        assert!(text.contains("scout_queue_depth 42"));
+        assert!(text.contains("# TYPE scout_queue_depth gauge"));
+    }
+
+    #[tokio::test]
+    async fn exporter_buckets_durations() {
+        let exporter = metrics::MetricsExporter::new();
+        exporter.observe_duration_ms(7.0).await;
+        exporter.observe_duration_ms(300.0).await;
+
+        let text = exporter.gather().await;
+        assert!(text.contains("scout_message_duration_ms_count 2"));
+        // 7ms falls in the le="10" bucket, so cumulative count there is at least 1.
+        assert!(text.contains("scout_message_duration_ms_bucket{le=\"10\"} 1"));
+        assert!(text.contains("scout_message_duration_ms_bucket{le=\"+Inf\"} 2"));
+    }
+}
+
 #[cfg(feature = "zeromq-queue")]
 pub mod zeromq {
     use super::*;
-    use ::zeromq::{PullSocket, Socket, SocketRecv};
-    
-    /// Monitor ZeroMQ queue health without consuming messages
+    use ::zeromq::{PullSocket, Socket, SocketEvent, SocketRecv};
+
+    /// A worker's periodically-published local counters, carried on the stats
+    /// channel (Option 2). Summing `in_flight` across all known workers yields
+    /// a real queue depth without integrating a rate difference.
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    pub struct WorkerStatsMessage {
+        pub worker_id: String,
+        /// Messages this worker currently holds but has not completed.
+        pub in_flight: u64,
+        /// Total messages this worker has processed since start.
+        pub processed_total: u64,
+    }
+
+    /// Observes *real* queue-depth and worker-count signals for the control
+    /// plane, combining a ZMQ monitor socket (Option 1 — connection events) with
+    /// a stats `SubSocket` channel (Option 2 — per-worker in-flight counts).
+    /// Only when no stats message has arrived within `stats_window` does it fall
+    /// back to the legacy rate-difference estimate, removing the unbounded drift
+    /// of the old `rate_diff * 10.0` heuristic.
+    pub struct ZmqQueueObserver {
+        /// Number of connected workers, tracked from monitor-socket events.
+        connected_workers: Arc<RwLock<usize>>,
+        /// Latest per-worker stats keyed on `worker_id`.
+        worker_stats: Arc<RwLock<HashMap<String, WorkerStatsMessage>>>,
+        /// When the most recent stats message arrived.
+        last_stats: Arc<RwLock<Option<Instant>>>,
+        /// How long stats stay authoritative before we fall back to estimation.
+        stats_window: Duration,
+    }
+
+    impl ZmqQueueObserver {
+        /// Create an observer that trusts published stats for `stats_window`.
+        pub fn new(stats_window: Duration) -> Self {
+            Self {
+                connected_workers: Arc::new(RwLock::new(0)),
+                worker_stats: Arc::new(RwLock::new(HashMap::new())),
+                last_stats: Arc::new(RwLock::new(None)),
+                stats_window,
+            }
+        }
+
+        /// Attach a monitoring socket to the control-plane `PullSocket` and track
+        /// the true number of connected workers from `ZMQ_EVENT_*` events
+        /// (Option 1). Spawns a background task for the event stream.
+        pub fn spawn_connection_tracker(&self, socket: &mut PullSocket) {
+            let mut events = socket.monitor();
+            let connected = self.connected_workers.clone();
+            tokio::spawn(async move {
+                while let Some(event) = events.recv().await {
+                    match event {
+                        // A peer finished connecting / was accepted.
+                        SocketEvent::Accepted { .. } | SocketEvent::Connected { .. } => {
+                            *connected.write().await += 1;
+                        }
+                        // A peer dropped.
+                        SocketEvent::Disconnected { .. } => {
+                            let mut c = connected.write().await;
+                            *c = c.saturating_sub(1);
+                        }
+                        _ => {}
+                    }
+                    debug!("control-plane peers connected: {}", *connected.read().await);
+                }
+            });
+        }
+
+        /// Consume worker-published stats from a dedicated `SubSocket` channel
+        /// (Option 2). Spawns a background task that keeps `worker_stats` current.
+        pub fn spawn_stats_listener(&self, mut stats_socket: ::zeromq::SubSocket) {
+            let worker_stats = self.worker_stats.clone();
+            let last_stats = self.last_stats.clone();
+            tokio::spawn(async move {
+                loop {
+                    match stats_socket.recv().await {
+                        Ok(message) => {
+                            let Some(frame) = message.get(0) else { continue };
+                            match rmp_serde::from_slice::<WorkerStatsMessage>(frame.as_ref()) {
+                                Ok(stats) => {
+                                    worker_stats
+                                        .write()
+                                        .await
+                                        .insert(stats.worker_id.clone(), stats);
+                                    *last_stats.write().await = Some(Instant::now());
+                                }
+                                Err(e) => warn!("malformed worker stats message: {}", e),
+                            }
+                        }
+                        Err(e) => {
+                            error!("stats channel recv failed: {}", e);
+                            break;
+                        }
+                    }
+                }
+            });
+        }
+
+        /// True when a stats message has arrived within `stats_window`.
+        async fn stats_fresh(&self) -> bool {
+            self.last_stats
+                .read()
+                .await
+                .map(|t| t.elapsed() < self.stats_window)
+                .unwrap_or(false)
+        }
+
+        /// Feed the best available depth/worker signals into `monitor`. Uses the
+        /// summed real counts while stats are fresh, otherwise falls back to the
+        /// rate-based estimate.
+        pub async fn refresh(&self, monitor: &QueueMonitor) -> Result<()> {
+            if self.stats_fresh().await {
+                let stats = self.worker_stats.read().await;
+                let real_depth: u64 = stats.values().map(|s| s.in_flight).sum();
+                // Prefer the monitor-socket peer count; fall back to the number
+                // of workers that have published stats.
+                let workers = {
+                    let connected = *self.connected_workers.read().await;
+                    if connected > 0 { connected } else { stats.len() }
+                };
+                monitor.update_workers(workers).await;
+                monitor.update_depth(real_depth as usize).await;
+                Ok(())
+            } else {
+                estimate_depth_from_rates(monitor).await
+            }
+        }
+    }
+
+    /// Monitor ZeroMQ queue health without consuming messages.
+    ///
+    /// Retained as the rate-based fallback used by [`ZmqQueueObserver`] when no
+    /// worker stats have arrived recently; see that type for the real,
+    /// event-and-stats-driven path.
     pub async fn monitor_zmq_queue_health(
         _endpoint: &str,
         monitor: &QueueMonitor,
     ) -> Result<()> {
-        // This is a challenge with ZeroMQ - we can't easily peek at queue depth
-        // without consuming messages. Some options:
-        
-        // Option 1: Use ZMQ monitoring socket (ZMQ_EVENT_*)
-        // This gives us events but not queue depth
-        
-        // Option 2: Use a SUB socket to monitor a separate stats channel
-        // Workers would need to publish stats
-        
-        // Option 3: Estimate based on rate difference
-        // If ingress > egress, queue is growing
-        
-        // For now, we'll estimate queue depth based on rate differences
+        estimate_depth_from_rates(monitor).await
+    }
+
+    /// Estimate queue depth from the ingress/egress rate difference. Drifts over
+    /// time, so it is only used as a fallback.
+    async fn estimate_depth_from_rates(monitor: &QueueMonitor) -> Result<()> {
         let health = monitor.health().await;
         let rate_diff = health.ingress_rate - health.egress_rate;
-        
+
         if rate_diff > 0.0 {
             // Queue is growing
             let estimated_growth = (rate_diff * 10.0) as usize; // 10 second estimate
@@ -250,7 +1120,7 @@ pub mod zeromq {
             let new_depth = health.queue_depth.saturating_sub(estimated_shrink);
             monitor.update_depth(new_depth).await;
         }
-        
+
         Ok(())
     }
     
@@ -266,70 +1136,80 @@ pub mod zeromq {
         Ok(socket)
     }
     
-    /// Process worker status update
+    /// Process worker status update, updating both the queue-wide [`QueueMonitor`]
+    /// and the per-worker [`WorkerRegistry`] roster.
     pub async fn process_worker_status(
         status: WorkerStatus,
         monitor: &QueueMonitor,
+        registry: &WorkerRegistry,
         tracker: &crate::tracker::MessageTracker,
     ) -> Result<()> {
-        
+
         match status.status {
             WorkerStatusType::Started => {
                 info!("Worker {} started", status.worker_id);
-                // Increment active worker count
-                let health = monitor.health().await;
-                monitor.update_workers(health.active_workers + 1).await;
+                registry.record_started(&status.worker_id).await;
+                monitor.update_workers(registry.active_count().await).await;
             }
-            
+
             WorkerStatusType::MessageReceived { ref message_id } => {
                 debug!("Worker {} received message {}", status.worker_id, message_id);
                 monitor.record_egress().await; // Message left the queue
-                
+                registry.record_message_received(&status.worker_id).await;
+
                 // Track in message tracker
                 if let Ok(id) = uuid::Uuid::parse_str(message_id) {
                     tracker.assign_to_worker(id, status.worker_id.clone()).await?;
                 }
             }
-            
+
             WorkerStatusType::MessageCompleted { ref message_id, success, duration_ms } => {
                 if success {
-                    debug!("Worker {} completed message {} in {}ms", 
+                    debug!("Worker {} completed message {} in {}ms",
                            status.worker_id, message_id, duration_ms);
                 } else {
                     warn!("Worker {} failed message {}", status.worker_id, message_id);
                 }
-                
+                registry.record_message_completed(&status.worker_id, success).await;
+                monitor.observe_duration_ms(duration_ms).await;
+
                 // Update tracker
                 if let Ok(id) = uuid::Uuid::parse_str(message_id) {
                     if success {
                         tracker.mark_completed(id, status.worker_id.clone()).await?;
                     } else {
                         tracker.mark_failed(
-                            id, 
+                            id,
                             status.worker_id.clone(),
                             "Processing failed".to_string()
                         ).await?;
                     }
                 }
             }
-            
+
             WorkerStatusType::Heartbeat { messages_processed, uptime_seconds } => {
-                debug!("Worker {} heartbeat: {} messages in {}s", 
+                debug!("Worker {} heartbeat: {} messages in {}s",
                        status.worker_id, messages_processed, uptime_seconds);
+                registry
+                    .record_heartbeat(&status.worker_id, messages_processed, uptime_seconds)
+                    .await;
             }
-            
+
             WorkerStatusType::Stopping => {
                 info!("Worker {} stopping", status.worker_id);
-                // Decrement active worker count
-                let health = monitor.health().await;
-                monitor.update_workers(health.active_workers.saturating_sub(1)).await;
+                registry.record_stopping(&status.worker_id).await;
+                monitor.update_workers(registry.active_count().await).await;
             }
-            
+
             WorkerStatusType::Error { ref message } => {
                 error!("Worker {} error: {}", status.worker_id, message);
+                registry.record_error(&status.worker_id, message).await;
             }
         }
-        
+
+        // Keep the exporter's per-worker roster in step with the registry.
+        monitor.publish_workers(registry.snapshot().await).await;
+
         Ok(())
     }
 }
\ No newline at end of file
@@ -9,6 +9,10 @@ use zeromq::{PushSocket, PullSocket, Socket, SocketSend, SocketRecv};
 
 use super::Queue;
 
+// See the missing-`queue/mod.rs` note on the `queue` re-export in `lib.rs`
+// for why lease-based at-least-once delivery (arach/scout#chunk109-2)
+// couldn't be added here.
+
 /// ZeroMQ-based queue implementation using Push/Pull sockets
 /// 
 /// This implementation provides a distributed queue using ZeroMQ's push/pull pattern:
@@ -355,6 +359,108 @@ impl ZmqBroker {
     }
 }
 
+/// A ROUTER/ROUTER load-balancing broker for ZeroMQ server mode.
+///
+/// The push/pull [`ZmqBroker`] above fair-queues chunks to whichever PULL
+/// worker is ready, but it cannot route a worker's *reply* back to the specific
+/// client that sent the request, which is what limited server mode to a single
+/// worker. This broker binds a ROUTER on the front (clients send `AudioChunk`s)
+/// and a ROUTER on the back (workers connect with a unique identity) and uses
+/// the standard least-recently-used worker pattern: a worker announces
+/// readiness, the broker hands the next job to the worker at the front of the
+/// ready queue, and re-enqueues that worker once it has replied. This lets
+/// `--workers N` run N concurrent ZeroMQ workers.
+pub struct ZmqRouterBroker {
+    front_endpoint: String,
+    back_endpoint: String,
+}
+
+/// Frame a worker sends on the back socket to announce it is ready for work.
+const WORKER_READY: &[u8] = b"READY";
+
+impl ZmqRouterBroker {
+    /// Create a broker binding `front_endpoint` for clients and
+    /// `back_endpoint` for workers.
+    pub fn new(front_endpoint: impl Into<String>, back_endpoint: impl Into<String>) -> Self {
+        Self {
+            front_endpoint: front_endpoint.into(),
+            back_endpoint: back_endpoint.into(),
+        }
+    }
+
+    /// Run the broker loop until the provided shutdown future resolves.
+    ///
+    /// Uses the LRU-worker algorithm: the back socket is always serviced so new
+    /// workers can register and replies can be relayed; the front socket is only
+    /// serviced while at least one worker is ready, which gives natural
+    /// backpressure when every worker is busy.
+    pub async fn run<F>(self, shutdown: F) -> Result<()>
+    where
+        F: std::future::Future<Output = ()>,
+    {
+        use zeromq::{RouterSocket, ZmqMessage};
+
+        let mut frontend = RouterSocket::new();
+        frontend.bind(&self.front_endpoint).await
+            .with_context(|| format!("Failed to bind broker frontend to {}", self.front_endpoint))?;
+
+        let mut backend = RouterSocket::new();
+        backend.bind(&self.back_endpoint).await
+            .with_context(|| format!("Failed to bind broker backend to {}", self.back_endpoint))?;
+
+        info!(
+            "ZeroMQ ROUTER/ROUTER broker started: frontend={}, backend={}",
+            self.front_endpoint, self.back_endpoint
+        );
+
+        // Identities of workers ready to accept a job, least-recently-used first.
+        let mut ready_workers = std::collections::VecDeque::new();
+        tokio::pin!(shutdown);
+
+        loop {
+            tokio::select! {
+                _ = &mut shutdown => {
+                    info!("ZeroMQ broker shutting down");
+                    return Ok(());
+                }
+                // Always service the backend so workers can register and reply.
+                msg = backend.recv() => {
+                    let msg = msg.context("Broker backend receive failed")?;
+                    let frames = msg.into_vec();
+                    // [worker_id, payload...] — payload is either READY or a
+                    // client reply prefixed with the client identity.
+                    let Some(worker_id) = frames.first().cloned() else { continue };
+                    ready_workers.push_back(worker_id);
+
+                    if frames.get(1).map(|f| f.as_ref()) != Some(WORKER_READY) {
+                        // A reply destined for a client: [worker_id, client_id, reply].
+                        if frames.len() >= 3 {
+                            let mut reply = ZmqMessage::from(frames[2].to_vec());
+                            reply.push_front(frames[1].clone());
+                            frontend.send(reply).await
+                                .context("Broker failed to relay reply to client")?;
+                        }
+                    }
+                }
+                // Only pull new work while a worker is available.
+                msg = frontend.recv(), if !ready_workers.is_empty() => {
+                    let msg = msg.context("Broker frontend receive failed")?;
+                    let frames = msg.into_vec();
+                    // [client_id, request]
+                    if frames.len() >= 2 {
+                        let worker_id = ready_workers.pop_front().expect("guarded by is_empty");
+                        let mut job = ZmqMessage::from(frames[1].to_vec());
+                        job.push_front(frames[0].clone()); // client id, for the reply path
+                        job.push_front(worker_id);          // route to chosen worker
+                        backend.send(job).await
+                            .context("Broker failed to dispatch job to worker")?;
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
+use clap::ValueEnum;
 use std::process::Stdio;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
@@ -11,8 +12,56 @@ use tokio::time::{interval, sleep, Instant};
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+use serde::{Deserialize, Serialize};
+
 use crate::protocol::{AudioChunk, HealthStatus, Transcript, TranscriptionError};
 
+/// Live, operator-visible state of a single worker.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case", tag = "state")]
+pub enum WorkerState {
+    /// Running with no chunk in flight.
+    Idle,
+    /// Processing a specific message since the given instant.
+    Processing {
+        message_id: Uuid,
+        since: DateTime<Utc>,
+    },
+    /// The process is being (re)spawned.
+    Restarting,
+    /// No longer accepting new work; finishing whatever is in flight.
+    Draining,
+    /// Paused by an operator; new work is rejected.
+    Paused,
+    /// The process has exceeded its restart budget and given up.
+    Dead,
+}
+
+/// A point-in-time view of one worker, returned by control queries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerSnapshot {
+    pub id: String,
+    #[serde(flatten)]
+    pub state: WorkerState,
+}
+
+/// Policy controlling what happens when an `AudioChunk` arrives while a worker's
+/// input channel is already saturated.
+///
+/// Modelled on watchexec's `on-busy-update` supervision setting.
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug, Default)]
+pub enum OnBusyPolicy {
+    /// Apply backpressure: await a free slot before enqueuing the chunk.
+    #[default]
+    Queue,
+    /// Drop the incoming chunk and keep the current work going.
+    DoNothing,
+    /// Restart the worker process, then enqueue the chunk on the fresh worker.
+    Restart,
+    /// Send the configured stop-signal to nudge the worker, then enqueue.
+    Signal,
+}
+
 /// Configuration for the Python worker
 #[derive(Debug, Clone)]
 pub struct WorkerConfig {
@@ -34,6 +83,12 @@ pub struct WorkerConfig {
     pub heartbeat_interval: Duration,
     /// Timeout for worker responses
     pub response_timeout: Duration,
+    /// Policy applied when a chunk arrives while the worker is saturated
+    pub on_busy: OnBusyPolicy,
+    /// Signal sent to the process to request a graceful stop (e.g. SIGTERM = 15)
+    pub stop_signal: i32,
+    /// How long to wait after the stop-signal before escalating to SIGKILL
+    pub stop_timeout: Duration,
 }
 
 impl Default for WorkerConfig {
@@ -48,6 +103,9 @@ impl Default for WorkerConfig {
             max_backoff: Duration::from_secs(60),
             heartbeat_interval: Duration::from_secs(30),
             response_timeout: Duration::from_secs(30),
+            on_busy: OnBusyPolicy::Queue,
+            stop_signal: libc::SIGTERM,
+            stop_timeout: Duration::from_secs(10),
         }
     }
 }
@@ -71,7 +129,13 @@ pub struct PythonWorker {
     stats: Arc<RwLock<WorkerStats>>,
     is_running: Arc<AtomicBool>,
     restart_count: Arc<AtomicU64>,
-    
+    /// Live state for operator introspection
+    state: Arc<RwLock<WorkerState>>,
+    /// Set when an operator has paused this worker
+    paused: Arc<AtomicBool>,
+    /// Set when an operator has asked this worker to drain
+    draining: Arc<AtomicBool>,
+
     // Communication channels
     input_tx: mpsc::Sender<AudioChunk>,
     output_rx: Arc<RwLock<Option<mpsc::Receiver<Result<Transcript, TranscriptionError>>>>>,
@@ -94,6 +158,9 @@ impl PythonWorker {
             stats: Arc::new(RwLock::new(WorkerStats::default())),
             is_running: Arc::new(AtomicBool::new(false)),
             restart_count: Arc::new(AtomicU64::new(0)),
+            state: Arc::new(RwLock::new(WorkerState::Idle)),
+            paused: Arc::new(AtomicBool::new(false)),
+            draining: Arc::new(AtomicBool::new(false)),
             input_tx,
             output_rx: Arc::new(RwLock::new(None)),
             shutdown_tx,
@@ -136,33 +203,101 @@ impl PythonWorker {
         // Signal shutdown
         let _ = self.shutdown_tx.send(());
         self.is_running.store(false, Ordering::Relaxed);
-        
-        // Kill the process if it exists
-        if let Some(mut process) = self.process.write().await.take() {
-            if let Err(e) = process.kill().await {
-                error!("Failed to kill Python process: {}", e);
-            } else {
-                debug!("Python process killed successfully");
-            }
+
+        // Gracefully stop the process if it exists: send the configured
+        // stop-signal, wait up to `stop_timeout`, then escalate to SIGKILL.
+        if let Some(process) = self.process.write().await.take() {
+            graceful_stop(
+                &self.worker_id,
+                process,
+                self.config.stop_signal,
+                self.config.stop_timeout,
+            )
+            .await;
         }
-        
+
         info!("Python worker {} stopped", self.worker_id);
         Ok(())
     }
+
+    /// Send a POSIX signal to the currently running process, if any.
+    async fn signal_process(&self, signal: i32) {
+        if let Some(ref child) = *self.process.read().await {
+            if let Some(pid) = child.id() {
+                send_signal(pid, signal);
+            }
+        }
+    }
+
+    /// Restart the running process by stopping it; the worker loop then
+    /// respawns a fresh process with the usual backoff.
+    pub async fn restart(&self) -> Result<()> {
+        if let Some(process) = self.process.write().await.take() {
+            graceful_stop(
+                &self.worker_id,
+                process,
+                self.config.stop_signal,
+                self.config.stop_timeout,
+            )
+            .await;
+        }
+        Ok(())
+    }
     
     /// Send an audio chunk for transcription
+    ///
+    /// When the worker's input channel is already full the configured
+    /// [`OnBusyPolicy`] decides whether to apply backpressure, drop the chunk,
+    /// or disturb the running process before enqueuing.
     pub async fn transcribe(&self, audio_chunk: AudioChunk) -> Result<()> {
         if !self.is_running.load(Ordering::Relaxed) {
             return Err(anyhow::anyhow!("Worker is not running"));
         }
-        
-        self.input_tx.send(audio_chunk).await
-            .context("Failed to send audio chunk to worker")?;
-        
+
+        if self.paused.load(Ordering::Relaxed) || self.draining.load(Ordering::Relaxed) {
+            return Err(anyhow::anyhow!("Worker {} is not accepting work", self.worker_id));
+        }
+
+        let message_id = audio_chunk.id;
+        match self.input_tx.try_send(audio_chunk) {
+            Ok(()) => {}
+            Err(mpsc::error::TrySendError::Closed(_)) => {
+                return Err(anyhow::anyhow!("Worker input channel is closed"));
+            }
+            Err(mpsc::error::TrySendError::Full(chunk)) => match self.config.on_busy {
+                OnBusyPolicy::Queue => {
+                    self.input_tx.send(chunk).await
+                        .context("Failed to send audio chunk to worker")?;
+                }
+                OnBusyPolicy::DoNothing => {
+                    warn!("Worker {} busy; dropping chunk (on-busy=do-nothing)", self.worker_id);
+                    return Ok(());
+                }
+                OnBusyPolicy::Restart => {
+                    warn!("Worker {} busy; restarting (on-busy=restart)", self.worker_id);
+                    self.restart().await?;
+                    self.input_tx.send(chunk).await
+                        .context("Failed to send audio chunk to worker")?;
+                }
+                OnBusyPolicy::Signal => {
+                    warn!("Worker {} busy; signalling (on-busy=signal)", self.worker_id);
+                    self.signal_process(self.config.stop_signal).await;
+                    self.input_tx.send(chunk).await
+                        .context("Failed to send audio chunk to worker")?;
+                }
+            },
+        }
+
+        // Reflect the in-flight chunk in the worker's state
+        *self.state.write().await = WorkerState::Processing {
+            message_id,
+            since: Utc::now(),
+        };
+
         // Update stats
         let mut stats = self.stats.write().await;
         stats.total_requests += 1;
-        
+
         Ok(())
     }
     
@@ -188,6 +323,48 @@ impl PythonWorker {
     pub fn id(&self) -> &str {
         &self.worker_id
     }
+
+    /// Current operator-visible state of the worker.
+    pub async fn state(&self) -> WorkerState {
+        self.state.read().await.clone()
+    }
+
+    /// A snapshot of this worker for control queries.
+    pub async fn snapshot(&self) -> WorkerSnapshot {
+        WorkerSnapshot {
+            id: self.worker_id.clone(),
+            state: self.state().await,
+        }
+    }
+
+    /// Pause the worker: it stops accepting new chunks until resumed.
+    pub async fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+        *self.state.write().await = WorkerState::Paused;
+        info!("Worker {} paused", self.worker_id);
+    }
+
+    /// Resume a paused worker.
+    pub async fn resume(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+        self.draining.store(false, Ordering::Relaxed);
+        *self.state.write().await = WorkerState::Idle;
+        info!("Worker {} resumed", self.worker_id);
+    }
+
+    /// Drain the worker: stop accepting new chunks but let in-flight work finish.
+    pub async fn drain(&self) {
+        self.draining.store(true, Ordering::Relaxed);
+        *self.state.write().await = WorkerState::Draining;
+        info!("Worker {} draining", self.worker_id);
+    }
+
+    /// Whether the worker is currently accepting new chunks.
+    pub fn is_available(&self) -> bool {
+        self.is_running.load(Ordering::Relaxed)
+            && !self.paused.load(Ordering::Relaxed)
+            && !self.draining.load(Ordering::Relaxed)
+    }
     
     /// Check if the worker is running
     pub fn is_running(&self) -> bool {
@@ -201,9 +378,10 @@ impl PythonWorker {
         let stats = Arc::clone(&self.stats);
         let is_running = Arc::clone(&self.is_running);
         let restart_count = Arc::clone(&self.restart_count);
+        let state = Arc::clone(&self.state);
         let mut shutdown_rx = self.shutdown_tx.subscribe();
         let worker_id = self.worker_id.clone();
-        
+
         tokio::spawn(async move {
             let mut backoff = config.initial_backoff;
             
@@ -238,10 +416,12 @@ impl PythonWorker {
                 let current_restarts = restart_count.fetch_add(1, Ordering::Relaxed);
                 if current_restarts >= config.max_restarts as u64 {
                     error!("Max restarts ({}) exceeded for worker {}", config.max_restarts, worker_id);
+                    *state.write().await = WorkerState::Dead;
                     is_running.store(false, Ordering::Relaxed);
                     break;
                 }
-                
+
+                *state.write().await = WorkerState::Restarting;
                 info!("Restarting worker {} in {:?} (attempt {})", worker_id, backoff, current_restarts + 1);
                 sleep(backoff).await;
                 
@@ -288,6 +468,45 @@ impl PythonWorker {
     }
 }
 
+/// Send a POSIX signal to a process by PID (best effort).
+fn send_signal(pid: u32, signal: i32) {
+    // SAFETY: `kill` with a valid PID and signal number has no memory effects.
+    let rc = unsafe { libc::kill(pid as libc::pid_t, signal) };
+    if rc != 0 {
+        debug!("Failed to send signal {} to PID {}", signal, pid);
+    }
+}
+
+/// Stop a child process gracefully: deliver `signal`, wait up to `timeout` for
+/// it to exit on its own, and only then hard-kill it. This lets an in-flight
+/// Python transcription finish instead of being aborted mid-request.
+async fn graceful_stop(worker_id: &str, mut child: Child, signal: i32, timeout: Duration) {
+    let pid = match child.id() {
+        Some(pid) => pid,
+        None => return, // already exited
+    };
+
+    send_signal(pid, signal);
+
+    match tokio::time::timeout(timeout, child.wait()).await {
+        Ok(Ok(status)) => {
+            debug!("Worker {} process exited on signal: {}", worker_id, status);
+        }
+        Ok(Err(e)) => {
+            error!("Worker {} wait failed after signal: {}", worker_id, e);
+        }
+        Err(_) => {
+            warn!(
+                "Worker {} did not exit within {:?}; sending SIGKILL",
+                worker_id, timeout
+            );
+            if let Err(e) = child.kill().await {
+                error!("Failed to kill Python process for worker {}: {}", worker_id, e);
+            }
+        }
+    }
+}
+
 /// Spawn a new Python process with the given configuration
 async fn spawn_python_process(config: &WorkerConfig) -> Result<Child> {
     let mut cmd = Command::new(&config.python_command);
@@ -417,12 +636,66 @@ impl WorkerPool {
         let index = self.next_worker.fetch_add(1, Ordering::Relaxed) as usize % self.workers.len();
         &self.workers[index]
     }
-    
+
+    /// Get the next worker that is accepting work, skipping paused/draining ones.
+    fn next_available_worker(&self) -> Option<&PythonWorker> {
+        for _ in 0..self.workers.len() {
+            let worker = self.next_worker();
+            if worker.is_available() {
+                return Some(worker);
+            }
+        }
+        None
+    }
+
     /// Send an audio chunk to the next available worker
     pub async fn transcribe(&self, audio_chunk: AudioChunk) -> Result<()> {
-        let worker = self.next_worker();
+        let worker = self.next_available_worker()
+            .ok_or_else(|| anyhow::anyhow!("No worker available to accept the chunk"))?;
         worker.transcribe(audio_chunk).await
     }
+
+    /// Snapshot the state of every worker in the pool.
+    pub async fn list(&self) -> Vec<WorkerSnapshot> {
+        let mut snapshots = Vec::with_capacity(self.workers.len());
+        for worker in self.workers.iter() {
+            snapshots.push(worker.snapshot().await);
+        }
+        snapshots
+    }
+
+    /// Pause a single worker by id. Returns `false` if no such worker exists.
+    pub async fn pause(&self, worker_id: &str) -> bool {
+        match self.workers.iter().find(|w| w.id() == worker_id) {
+            Some(worker) => {
+                worker.pause().await;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Resume a single worker by id. Returns `false` if no such worker exists.
+    pub async fn resume(&self, worker_id: &str) -> bool {
+        match self.workers.iter().find(|w| w.id() == worker_id) {
+            Some(worker) => {
+                worker.resume().await;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Drain a single worker by id. Returns `false` if no such worker exists.
+    pub async fn drain(&self, worker_id: &str) -> bool {
+        match self.workers.iter().find(|w| w.id() == worker_id) {
+            Some(worker) => {
+                worker.drain().await;
+                true
+            }
+            None => false,
+        }
+    }
     
     /// Get combined statistics for all workers
     pub async fn get_stats(&self) -> Vec<WorkerStats> {
@@ -0,0 +1,306 @@
+//! Transient aggregate input device support.
+//!
+//! Composes several physical input devices into one synchronized capture
+//! device, mirroring cubeb-coreaudio's aggregate-device handling. The device is
+//! created *private* (it never leaks into the system device list) and is
+//! destroyed when the returned [`AggregateDeviceHandle`] is dropped, matching
+//! the RAII teardown the rest of `DeviceMonitor` relies on.
+//!
+//! Only macOS has a native backend today; other platforms return an error.
+
+use super::device_monitor::DeviceCapabilities;
+
+/// Handle to a live aggregate device. Dropping it destroys the device.
+pub struct AggregateDeviceHandle {
+    /// Stable UID assigned to the aggregate device.
+    uid: String,
+    /// Combined capabilities: union of channels, intersection of sample rates.
+    capabilities: DeviceCapabilities,
+    #[cfg(target_os = "macos")]
+    device_id: u32,
+}
+
+impl AggregateDeviceHandle {
+    /// UID of the created aggregate device.
+    pub fn uid(&self) -> &str {
+        &self.uid
+    }
+
+    /// Combined capabilities of the composed sub-devices.
+    pub fn capabilities(&self) -> &DeviceCapabilities {
+        &self.capabilities
+    }
+}
+
+impl Drop for AggregateDeviceHandle {
+    fn drop(&mut self) {
+        #[cfg(target_os = "macos")]
+        imp::destroy(self.device_id);
+    }
+}
+
+/// Combine sub-device capabilities: union of supported channel counts,
+/// intersection of supported sample rates (so every sub-device can run the
+/// chosen rate in the single clock domain).
+pub(crate) fn combine_capabilities(parts: &[DeviceCapabilities]) -> DeviceCapabilities {
+    let mut channels: Vec<u16> = Vec::new();
+    for p in parts {
+        for &c in &p.channels {
+            if !channels.contains(&c) {
+                channels.push(c);
+            }
+        }
+    }
+    channels.sort_unstable();
+
+    let mut sample_rates: Vec<u32> = match parts.first() {
+        Some(first) => first.sample_rates.clone(),
+        None => Vec::new(),
+    };
+    for p in parts.iter().skip(1) {
+        sample_rates.retain(|r| p.sample_rates.contains(r));
+    }
+    sample_rates.sort_unstable();
+
+    let mut sample_formats: Vec<String> = match parts.first() {
+        Some(first) => first.sample_formats.clone(),
+        None => Vec::new(),
+    };
+    for p in parts.iter().skip(1) {
+        sample_formats.retain(|f| p.sample_formats.contains(f));
+    }
+
+    DeviceCapabilities {
+        sample_rates,
+        sample_rate_ranges: Vec::new(),
+        channels,
+        sample_formats,
+        default_config: None,
+    }
+}
+
+/// Create a private aggregate input device from the named sub-devices, using
+/// `clock_source` (or the first sub-device) as the single clock/master domain.
+pub fn create_aggregate_input(
+    device_names: &[String],
+    clock_source: Option<String>,
+    capabilities: &[DeviceCapabilities],
+) -> Result<AggregateDeviceHandle, String> {
+    if device_names.is_empty() {
+        return Err("aggregate device requires at least one sub-device".to_string());
+    }
+    let combined = combine_capabilities(capabilities);
+    if combined.sample_rates.is_empty() {
+        return Err("sub-devices share no common sample rate".to_string());
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let master = clock_source
+            .clone()
+            .unwrap_or_else(|| device_names[0].clone());
+        let (uid, device_id) = imp::create(device_names, &master)?;
+        Ok(AggregateDeviceHandle {
+            uid,
+            capabilities: combined,
+            device_id,
+        })
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = clock_source;
+        Err("aggregate devices are only supported on macOS".to_string())
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod imp {
+    use std::os::raw::{c_char, c_void};
+    use std::ptr;
+
+    type OSStatus = i32;
+    type AudioObjectID = u32;
+    type CFTypeRef = *const c_void;
+    type CFStringRef = *const c_void;
+    type CFDictionaryRef = *const c_void;
+    type CFAllocatorRef = *const c_void;
+
+    const K_CFSTRING_ENCODING_UTF8: u32 = 0x0800_0100;
+    const K_CFNUMBER_SINT32: i32 = 3;
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        static kCFAllocatorDefault: CFAllocatorRef;
+        static kCFBooleanTrue: CFTypeRef;
+        static kCFTypeDictionaryKeyCallBacks: c_void;
+        static kCFTypeDictionaryValueCallBacks: c_void;
+        static kCFTypeArrayCallBacks: c_void;
+
+        fn CFStringCreateWithCString(
+            alloc: CFAllocatorRef,
+            c_str: *const c_char,
+            encoding: u32,
+        ) -> CFStringRef;
+        fn CFDictionaryCreateMutable(
+            alloc: CFAllocatorRef,
+            capacity: isize,
+            key_cb: *const c_void,
+            val_cb: *const c_void,
+        ) -> *mut c_void;
+        fn CFDictionarySetValue(dict: *mut c_void, key: CFTypeRef, value: CFTypeRef);
+        fn CFArrayCreateMutable(
+            alloc: CFAllocatorRef,
+            capacity: isize,
+            cb: *const c_void,
+        ) -> *mut c_void;
+        fn CFArrayAppendValue(array: *mut c_void, value: CFTypeRef);
+        fn CFNumberCreate(alloc: CFAllocatorRef, the_type: i32, value: *const c_void) -> CFTypeRef;
+        fn CFRelease(cf: CFTypeRef);
+    }
+
+    #[link(name = "CoreAudio", kind = "framework")]
+    extern "C" {
+        fn AudioHardwareCreateAggregateDevice(
+            description: CFDictionaryRef,
+            out_device: *mut AudioObjectID,
+        ) -> OSStatus;
+        fn AudioHardwareDestroyAggregateDevice(device: AudioObjectID) -> OSStatus;
+    }
+
+    fn cfstr(s: &str) -> CFStringRef {
+        let c = std::ffi::CString::new(s).unwrap();
+        unsafe { CFStringCreateWithCString(kCFAllocatorDefault, c.as_ptr(), K_CFSTRING_ENCODING_UTF8) }
+    }
+
+    /// Build the composition dictionary and create the device. Returns the
+    /// assigned UID and the resulting `AudioObjectID`.
+    pub fn create(device_names: &[String], master: &str) -> Result<(String, AudioObjectID), String> {
+        // A fixed UID lets us describe (and, if needed, reclaim) the device.
+        let uid = format!("scout-aggregate-{}", device_names.join("+"));
+
+        unsafe {
+            let desc = CFDictionaryCreateMutable(
+                kCFAllocatorDefault,
+                0,
+                &kCFTypeDictionaryKeyCallBacks,
+                &kCFTypeDictionaryValueCallBacks,
+            );
+            if desc.is_null() {
+                return Err("failed to allocate aggregate description".to_string());
+            }
+
+            let name_key = cfstr("name");
+            let name_val = cfstr("Scout Aggregate Input");
+            CFDictionarySetValue(desc, name_key as CFTypeRef, name_val as CFTypeRef);
+            CFRelease(name_key as CFTypeRef);
+            CFRelease(name_val as CFTypeRef);
+
+            let uid_key = cfstr("uid");
+            let uid_val = cfstr(&uid);
+            CFDictionarySetValue(desc, uid_key as CFTypeRef, uid_val as CFTypeRef);
+            CFRelease(uid_key as CFTypeRef);
+            CFRelease(uid_val as CFTypeRef);
+
+            // Private so it never appears in the system device list.
+            let private_key = cfstr("private");
+            CFDictionarySetValue(desc, private_key as CFTypeRef, kCFBooleanTrue);
+            CFRelease(private_key as CFTypeRef);
+
+            // Single clock/master domain avoids inter-device drift.
+            let master_key = cfstr("master");
+            let master_val = cfstr(master);
+            CFDictionarySetValue(desc, master_key as CFTypeRef, master_val as CFTypeRef);
+            CFRelease(master_key as CFTypeRef);
+            CFRelease(master_val as CFTypeRef);
+
+            // Sub-device list: one dict per sub-device, keyed by UID.
+            let sub_list = CFArrayCreateMutable(kCFAllocatorDefault, 0, &kCFTypeArrayCallBacks);
+            for name in device_names {
+                let sub = CFDictionaryCreateMutable(
+                    kCFAllocatorDefault,
+                    0,
+                    &kCFTypeDictionaryKeyCallBacks,
+                    &kCFTypeDictionaryValueCallBacks,
+                );
+                let sub_uid_key = cfstr("uid");
+                let sub_uid_val = cfstr(name);
+                CFDictionarySetValue(sub, sub_uid_key as CFTypeRef, sub_uid_val as CFTypeRef);
+                CFRelease(sub_uid_key as CFTypeRef);
+                CFRelease(sub_uid_val as CFTypeRef);
+                CFArrayAppendValue(sub_list, sub as CFTypeRef);
+                // `sub` is retained by `sub_list` on append; release our own
+                // +1 reference from `CFDictionaryCreateMutable`.
+                CFRelease(sub as CFTypeRef);
+            }
+            let list_key = cfstr("subdevicelist");
+            CFDictionarySetValue(desc, list_key as CFTypeRef, sub_list as CFTypeRef);
+            CFRelease(list_key as CFTypeRef);
+            // `sub_list` is retained by `desc` on insert; release our own +1
+            // reference from `CFArrayCreateMutable`.
+            CFRelease(sub_list as CFTypeRef);
+
+            let mut device_id: AudioObjectID = 0;
+            let status = AudioHardwareCreateAggregateDevice(
+                desc as CFDictionaryRef,
+                &mut device_id as *mut AudioObjectID,
+            );
+            let _ = K_CFNUMBER_SINT32; // reserved for future channel-map keys
+            let _ = CFNumberCreate; // referenced so the binding is retained
+            CFRelease(desc as CFTypeRef);
+
+            if status != 0 || device_id == 0 {
+                return Err(format!("AudioHardwareCreateAggregateDevice failed: {}", status));
+            }
+            Ok((uid, device_id))
+        }
+    }
+
+    pub fn destroy(device_id: AudioObjectID) {
+        if device_id != 0 {
+            unsafe {
+                let _: OSStatus = AudioHardwareDestroyAggregateDevice(device_id);
+            }
+        }
+    }
+
+    // Silence unused warnings for the reserved null pointer helper.
+    #[allow(dead_code)]
+    const _UNUSED_NULL: *const c_void = ptr::null();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn caps(rates: &[u32], channels: &[u16]) -> DeviceCapabilities {
+        DeviceCapabilities {
+            sample_rates: rates.to_vec(),
+            sample_rate_ranges: Vec::new(),
+            channels: channels.to_vec(),
+            sample_formats: vec!["F32".to_string()],
+            default_config: None,
+        }
+    }
+
+    #[test]
+    fn combine_unions_channels_and_intersects_rates() {
+        let a = caps(&[44100, 48000], &[1]);
+        let b = caps(&[48000, 96000], &[2]);
+        let combined = combine_capabilities(&[a, b]);
+        assert_eq!(combined.sample_rates, vec![48000]);
+        assert_eq!(combined.channels, vec![1, 2]);
+    }
+
+    #[test]
+    fn create_rejects_disjoint_sample_rates() {
+        let a = caps(&[44100], &[1]);
+        let b = caps(&[48000], &[1]);
+        let result = create_aggregate_input(
+            &["a".to_string(), "b".to_string()],
+            None,
+            &[a, b],
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,226 @@
+/// Destinations a transcription chunk can be saved to.
+///
+/// `ChunkSink::File` is the original plaintext-on-disk behavior. The other
+/// variants exist for users who need at-rest encryption of sensitive
+/// recordings (`Encrypted`) or who want to ship a chunk straight to a
+/// remote transcription worker instead of the local disk (`Stream`).
+use crate::transport::encryption::{keystream_block, NONCE_LEN};
+use std::io::{self, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+
+/// Byte length of the canonical WAV header `hound` writes (12-byte RIFF +
+/// 24-byte `fmt ` chunk + 8-byte `data` chunk header) when no extra chunks
+/// are present. Kept in the clear for `Encrypted` sinks so the file is
+/// still probe-able without the key.
+pub const WAV_HEADER_LEN: u64 = 44;
+
+/// Where a saved chunk should end up.
+pub enum ChunkSink {
+    /// Plain WAV file on local disk.
+    File(PathBuf),
+    /// WAV file on local disk whose `data` subchunk bytes are XORed
+    /// against a keystream derived from `key`; the header is left in the
+    /// clear.
+    Encrypted { path: PathBuf, key: Vec<u8> },
+    /// Arbitrary writable destination, e.g. a socket to a remote
+    /// transcription worker.
+    Stream(Box<dyn Write + Send>),
+}
+
+/// Number of keystream bytes produced by one `keystream_block` call; a
+/// block is re-derived whenever the requested offset crosses into a new
+/// one, so sequential access only hashes once per 32 bytes.
+const KEYSTREAM_BLOCK_LEN: u64 = 32;
+
+/// Looks up keystream byte `relative_offset` for `secret` mixed with
+/// `nonce`, caching the most recently derived 32-byte block so sequential
+/// access (the common case for both `XorWriter` and `xor_decrypt`) doesn't
+/// re-hash on every byte, while still allowing the caller to jump to any
+/// offset at all - which a growing, seekably-read WAV file needs.
+struct KeystreamCursor {
+    secret: Vec<u8>,
+    nonce: [u8; NONCE_LEN],
+    cached_block_index: Option<u64>,
+    cached_block: [u8; 32],
+}
+
+impl KeystreamCursor {
+    fn new(secret: Vec<u8>, nonce: [u8; NONCE_LEN]) -> Self {
+        Self {
+            secret,
+            nonce,
+            cached_block_index: None,
+            cached_block: [0u8; 32],
+        }
+    }
+
+    fn byte_at(&mut self, relative_offset: u64) -> u8 {
+        let block_index = relative_offset / KEYSTREAM_BLOCK_LEN;
+        if self.cached_block_index != Some(block_index) {
+            self.cached_block = keystream_block(&self.secret, &self.nonce, block_index);
+            self.cached_block_index = Some(block_index);
+        }
+        self.cached_block[(relative_offset % KEYSTREAM_BLOCK_LEN) as usize]
+    }
+}
+
+/// Wraps a `Write + Seek` destination and XORs every byte written at or
+/// past [`WAV_HEADER_LEN`] against a keystream derived from `secret` and a
+/// random per-file nonce, so `hound::WavWriter` can write straight through
+/// it without the caller ever buffering the whole file. The nonce is
+/// generated once and written to `inner` in the clear as the first bytes
+/// of the `data` subchunk, so re-using `secret` across many chunk files
+/// never reuses a keystream. Seeks (used by `hound` to back-patch the
+/// RIFF/data chunk sizes once writing finishes, always at offsets within
+/// the clear header) pass straight through to `inner`.
+pub struct XorWriter<W> {
+    inner: W,
+    keystream: KeystreamCursor,
+    nonce_written: bool,
+    pos: u64,
+}
+
+impl<W: Write + Seek> XorWriter<W> {
+    pub fn new(inner: W, key: Vec<u8>) -> Self {
+        let mut nonce = [0u8; NONCE_LEN];
+        for byte in nonce.iter_mut() {
+            *byte = rand::random::<u8>();
+        }
+        Self {
+            inner,
+            keystream: KeystreamCursor::new(key, nonce),
+            nonce_written: false,
+            pos: 0,
+        }
+    }
+}
+
+impl<W: Write> Write for XorWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut transformed = Vec::with_capacity(buf.len() + NONCE_LEN);
+        for (i, &byte) in buf.iter().enumerate() {
+            let abs_pos = self.pos + i as u64;
+            if abs_pos == WAV_HEADER_LEN && !self.nonce_written && !self.keystream.secret.is_empty() {
+                transformed.extend_from_slice(&self.keystream.nonce);
+                self.nonce_written = true;
+            }
+
+            if abs_pos >= WAV_HEADER_LEN && !self.keystream.secret.is_empty() {
+                let relative_offset = abs_pos - WAV_HEADER_LEN;
+                transformed.push(byte ^ self.keystream.byte_at(relative_offset));
+            } else {
+                transformed.push(byte);
+            }
+        }
+
+        self.inner.write_all(&transformed)?;
+        self.pos += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: Seek> Seek for XorWriter<W> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let new_pos = self.inner.seek(pos)?;
+        self.pos = new_pos;
+        Ok(new_pos)
+    }
+}
+
+/// XOR-decrypts `buf` in place, treating `buf[0]` as the byte at
+/// `data_relative_offset` within the encrypted payload (i.e. the `data`
+/// subchunk's content, past the nonce `XorWriter` prepended there). The
+/// keystream is derived from `secret` and `nonce` the same way
+/// `XorWriter` derives it on write, so this is its own inverse.
+pub fn xor_decrypt(buf: &mut [u8], data_relative_offset: u64, secret: &[u8], nonce: &[u8; NONCE_LEN]) {
+    if secret.is_empty() {
+        return;
+    }
+    let mut cursor = KeystreamCursor::new(secret.to_vec(), *nonce);
+    for (i, byte) in buf.iter_mut().enumerate() {
+        *byte ^= cursor.byte_at(data_relative_offset + i as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// Extracts the nonce and the encrypted payload that follows it from an
+    /// `XorWriter`'s output, mirroring how `WavFileReader` reads them back.
+    fn split_nonce(inner: &[u8]) -> ([u8; NONCE_LEN], Vec<u8>) {
+        let payload_start = WAV_HEADER_LEN as usize;
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce.copy_from_slice(&inner[payload_start..payload_start + NONCE_LEN]);
+        (nonce, inner[payload_start + NONCE_LEN..].to_vec())
+    }
+
+    #[test]
+    fn test_xor_writer_leaves_header_in_clear() {
+        let key = vec![0xAA, 0x55, 0x0F];
+        let mut writer = XorWriter::new(Cursor::new(Vec::new()), key);
+        let header = [0u8; WAV_HEADER_LEN as usize];
+        let data = [1u8, 2, 3, 4, 5];
+
+        writer.write_all(&header).unwrap();
+        writer.write_all(&data).unwrap();
+
+        let inner = writer.inner.into_inner();
+        assert_eq!(&inner[..WAV_HEADER_LEN as usize], &header[..]);
+        let (_, encrypted) = split_nonce(&inner);
+        assert_ne!(encrypted, data);
+    }
+
+    #[test]
+    fn test_xor_write_then_decrypt_round_trips() {
+        let key = vec![0x42, 0x13, 0x99, 0x07];
+        let mut writer = XorWriter::new(Cursor::new(Vec::new()), key.clone());
+        let header = [0u8; WAV_HEADER_LEN as usize];
+        let data = [10u8, 20, 30, 40, 50, 60, 70];
+
+        writer.write_all(&header).unwrap();
+        writer.write_all(&data).unwrap();
+
+        let inner = writer.inner.into_inner();
+        let (nonce, mut encrypted_data) = split_nonce(&inner);
+        xor_decrypt(&mut encrypted_data, 0, &key, &nonce);
+
+        assert_eq!(encrypted_data, data);
+    }
+
+    #[test]
+    fn test_same_key_across_two_files_uses_different_keystreams() {
+        let key = vec![0x07, 0x2A, 0xEE];
+        let data = [1u8, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut first = XorWriter::new(Cursor::new(Vec::new()), key.clone());
+        first.write_all(&[0u8; WAV_HEADER_LEN as usize]).unwrap();
+        first.write_all(&data).unwrap();
+        let (first_nonce, first_encrypted) = split_nonce(&first.inner.into_inner());
+
+        let mut second = XorWriter::new(Cursor::new(Vec::new()), key);
+        second.write_all(&[0u8; WAV_HEADER_LEN as usize]).unwrap();
+        second.write_all(&data).unwrap();
+        let (second_nonce, second_encrypted) = split_nonce(&second.inner.into_inner());
+
+        // Same key, same plaintext: without a per-file nonce these would be
+        // byte-for-byte identical, which is exactly the many-time-pad this
+        // nonce exists to prevent.
+        assert_ne!(first_nonce, second_nonce);
+        assert_ne!(first_encrypted, second_encrypted);
+    }
+
+    #[test]
+    fn test_seek_passes_through_without_shifting_keystream() {
+        let key = vec![0xFF];
+        let mut writer = XorWriter::new(Cursor::new(vec![0u8; 64]), key);
+
+        writer.seek(SeekFrom::Start(10)).unwrap();
+        assert_eq!(writer.pos, 10);
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+/// Audio host/backend a user can pin recording to, rather than letting the OS
+/// default (see `get_audio_backend` in `metadata.rs`) decide. Most relevant on
+/// Linux, where cpal can be built against ALSA, PulseAudio, or JACK and the
+/// "right" choice depends on what's actually running on the user's system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioBackend {
+    /// Use the OS-default backend (current behavior).
+    Auto,
+    CoreAudio,
+    Wasapi,
+    Alsa,
+    PulseAudio,
+    Jack,
+}
+
+impl AudioBackend {
+    /// Human-readable name, used for metadata reporting and mismatch messages.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AudioBackend::Auto => "Auto",
+            AudioBackend::CoreAudio => "CoreAudio",
+            AudioBackend::Wasapi => "WASAPI",
+            AudioBackend::Alsa => "ALSA",
+            AudioBackend::PulseAudio => "PulseAudio",
+            AudioBackend::Jack => "JACK",
+        }
+    }
+}
+
+impl Default for AudioBackend {
+    fn default() -> Self {
+        AudioBackend::Auto
+    }
+}
+
+/// User-configured override for which input device (and format) to use,
+/// bypassing the OS default device. Consulted by `AudioRecorderWorker` when
+/// opening a stream; any forced values are threaded into `AudioMetadata` as
+/// the `requested_*` fields so mismatch detection compares against what the
+/// user actually asked for instead of inferring it from the OS.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomAudioDeviceConfig {
+    /// Exact device name to select, as reported by `cpal::Device::name()`.
+    /// Takes priority over `device_index` when both are set.
+    pub device_name: Option<String>,
+
+    /// Index into the host's `input_devices()` enumeration order, for users
+    /// who want to pin a device that doesn't have a stable/unique name.
+    pub device_index: Option<usize>,
+
+    /// Preferred audio backend; `AudioBackend::Auto` defers to the OS default.
+    pub preferred_backend: AudioBackend,
+
+    /// Force a specific sample rate instead of the device's native rate.
+    pub forced_sample_rate: Option<u32>,
+
+    /// Force a specific buffer size (in samples) instead of the recorder's
+    /// progressive low-latency search.
+    pub forced_buffer_size: Option<u32>,
+}
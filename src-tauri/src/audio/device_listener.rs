@@ -0,0 +1,400 @@
+use std::sync::mpsc;
+
+/// A real-time change observed on the currently *active* input device.
+///
+/// Distinct from `device_monitor::DeviceChangeEvent`, which covers the
+/// system-wide device list (devices appearing/disappearing, the default
+/// device changing). This covers properties of the one device a stream is
+/// actively recording from, pushed the moment the OS reports them instead of
+/// waiting for the next periodic `DeviceCapabilityChecker` poll.
+#[derive(Debug, Clone)]
+pub enum DeviceListenerEvent {
+    /// The device's nominal sample rate changed mid-stream, e.g. AirPods
+    /// silently dropping into or out of call mode.
+    SampleRateChanged(u32),
+    /// The device's stream configuration (channel layout) changed.
+    StreamConfigChanged,
+    /// The device went away (unplugged, powered off, Bluetooth dropout).
+    DeviceDisconnected,
+}
+
+pub type DeviceListenerSender = mpsc::Sender<DeviceListenerEvent>;
+
+/// Registers OS-level listeners on a single active input device and forwards
+/// property changes as `DeviceListenerEvent`s over an mpsc channel, so a
+/// worker thread can drain them without touching `AudioMetadata` from the OS
+/// callback thread directly.
+///
+/// Each platform backend lives behind `imp`, mirroring
+/// `device_monitor::native`'s per-platform split; hosts without a native
+/// backend return `Err` from `register` and the caller simply has no
+/// real-time signal (periodic capability checks still run).
+pub struct ActiveDeviceListener {
+    inner: imp::PlatformListener,
+}
+
+impl ActiveDeviceListener {
+    /// Register a listener on the input device named `device_name`,
+    /// forwarding events to `tx`. Returns `Err` if this platform has no
+    /// native backend, the device can't be resolved, or OS registration
+    /// fails.
+    pub fn register(device_name: &str, tx: DeviceListenerSender) -> Result<Self, String> {
+        imp::PlatformListener::register(device_name, tx).map(|inner| Self { inner })
+    }
+
+    /// Remove the OS-level listeners. `PlatformListener` also tears itself
+    /// down on `Drop`, so letting an `ActiveDeviceListener` simply go out of
+    /// scope at stream teardown is enough to avoid dangling callbacks; this
+    /// is only needed to observe failures eagerly.
+    pub fn deregister(self) {
+        self.inner.deregister();
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod imp {
+    use super::{DeviceListenerEvent, DeviceListenerSender};
+    use crate::logger::{info, Component};
+    use std::os::raw::{c_char, c_void};
+
+    type OSStatus = i32;
+    type AudioObjectID = u32;
+    type AudioObjectPropertySelector = u32;
+    type AudioObjectPropertyScope = u32;
+    type AudioObjectPropertyElement = u32;
+    type CFStringRef = *const c_void;
+    type CFIndex = isize;
+    type CFStringEncoding = u32;
+
+    #[repr(C)]
+    struct AudioObjectPropertyAddress {
+        selector: AudioObjectPropertySelector,
+        scope: AudioObjectPropertyScope,
+        element: AudioObjectPropertyElement,
+    }
+
+    type Listener = extern "C" fn(
+        AudioObjectID,
+        u32,
+        *const AudioObjectPropertyAddress,
+        *mut c_void,
+    ) -> OSStatus;
+
+    #[link(name = "CoreAudio", kind = "framework")]
+    extern "C" {
+        fn AudioObjectGetPropertyDataSize(
+            in_object: AudioObjectID,
+            in_address: *const AudioObjectPropertyAddress,
+            in_qualifier_data_size: u32,
+            in_qualifier_data: *const c_void,
+            out_data_size: *mut u32,
+        ) -> OSStatus;
+
+        fn AudioObjectGetPropertyData(
+            in_object: AudioObjectID,
+            in_address: *const AudioObjectPropertyAddress,
+            in_qualifier_data_size: u32,
+            in_qualifier_data: *const c_void,
+            io_data_size: *mut u32,
+            out_data: *mut c_void,
+        ) -> OSStatus;
+
+        fn AudioObjectAddPropertyListener(
+            in_object: AudioObjectID,
+            in_address: *const AudioObjectPropertyAddress,
+            in_proc: Listener,
+            in_client_data: *mut c_void,
+        ) -> OSStatus;
+
+        fn AudioObjectRemovePropertyListener(
+            in_object: AudioObjectID,
+            in_address: *const AudioObjectPropertyAddress,
+            in_proc: Listener,
+            in_client_data: *mut c_void,
+        ) -> OSStatus;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFStringGetCString(
+            the_string: CFStringRef,
+            buffer: *mut c_char,
+            buffer_size: CFIndex,
+            encoding: CFStringEncoding,
+        ) -> u8;
+        fn CFRelease(cf: *const c_void);
+    }
+
+    const K_CF_STRING_ENCODING_UTF8: CFStringEncoding = 0x0800_0100;
+    const K_AUDIO_OBJECT_SYSTEM_OBJECT: AudioObjectID = 1;
+    const K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL: AudioObjectPropertyScope = fourcc(b"glob");
+    const K_AUDIO_OBJECT_PROPERTY_ELEMENT_MAIN: AudioObjectPropertyElement = 0;
+
+    const fn fourcc(code: &[u8; 4]) -> u32 {
+        ((code[0] as u32) << 24)
+            | ((code[1] as u32) << 16)
+            | ((code[2] as u32) << 8)
+            | (code[3] as u32)
+    }
+
+    fn address(selector: u32, scope: AudioObjectPropertyScope) -> AudioObjectPropertyAddress {
+        AudioObjectPropertyAddress {
+            selector,
+            scope,
+            element: K_AUDIO_OBJECT_PROPERTY_ELEMENT_MAIN,
+        }
+    }
+
+    /// Properties watched on the active device itself (as opposed to
+    /// `device_monitor::native`'s system-object, device-list properties).
+    /// Which `DeviceListenerEvent` each selector maps to is decided in
+    /// `on_property_changed`.
+    fn watched_addresses() -> [AudioObjectPropertyAddress; 3] {
+        [
+            address(fourcc(b"nsrt"), K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL), // kAudioDevicePropertyNominalSampleRate
+            address(fourcc(b"slay"), fourcc(b"inpt")), // kAudioDevicePropertyStreamConfiguration, input scope
+            address(fourcc(b"livn"), K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL), // kAudioDevicePropertyDeviceIsAlive
+        ]
+    }
+
+    /// Resolve the `AudioObjectID` of the input device named `device_name` by
+    /// enumerating `kAudioHardwarePropertyDevices` and matching on
+    /// `kAudioObjectPropertyName`.
+    fn find_device_id(device_name: &str) -> Result<AudioObjectID, String> {
+        let devices_address = address(fourcc(b"dev#"), K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL);
+
+        let mut data_size: u32 = 0;
+        let status = unsafe {
+            AudioObjectGetPropertyDataSize(
+                K_AUDIO_OBJECT_SYSTEM_OBJECT,
+                &devices_address,
+                0,
+                std::ptr::null(),
+                &mut data_size,
+            )
+        };
+        if status != 0 {
+            return Err(format!("AudioObjectGetPropertyDataSize(devices) failed: {}", status));
+        }
+
+        let count = data_size as usize / std::mem::size_of::<AudioObjectID>();
+        let mut device_ids: Vec<AudioObjectID> = vec![0; count];
+        let status = unsafe {
+            AudioObjectGetPropertyData(
+                K_AUDIO_OBJECT_SYSTEM_OBJECT,
+                &devices_address,
+                0,
+                std::ptr::null(),
+                &mut data_size,
+                device_ids.as_mut_ptr() as *mut c_void,
+            )
+        };
+        if status != 0 {
+            return Err(format!("AudioObjectGetPropertyData(devices) failed: {}", status));
+        }
+
+        let name_address = address(fourcc(b"lnam"), K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL); // kAudioObjectPropertyName
+
+        for device_id in device_ids {
+            let mut cf_string: CFStringRef = std::ptr::null();
+            let mut size = std::mem::size_of::<CFStringRef>() as u32;
+            let status = unsafe {
+                AudioObjectGetPropertyData(
+                    device_id,
+                    &name_address,
+                    0,
+                    std::ptr::null(),
+                    &mut size,
+                    &mut cf_string as *mut CFStringRef as *mut c_void,
+                )
+            };
+            if status != 0 || cf_string.is_null() {
+                continue;
+            }
+
+            let mut buf = [0i8; 256];
+            let ok = unsafe {
+                CFStringGetCString(
+                    cf_string,
+                    buf.as_mut_ptr(),
+                    buf.len() as CFIndex,
+                    K_CF_STRING_ENCODING_UTF8,
+                )
+            };
+            unsafe { CFRelease(cf_string) };
+
+            if ok != 0 {
+                let name = unsafe { std::ffi::CStr::from_ptr(buf.as_ptr()) }
+                    .to_string_lossy()
+                    .into_owned();
+                if name == device_name {
+                    return Ok(device_id);
+                }
+            }
+        }
+
+        Err(format!("No CoreAudio device found matching '{}'", device_name))
+    }
+
+    fn read_nominal_sample_rate(device_id: AudioObjectID) -> Option<u32> {
+        let rate_address = address(fourcc(b"nsrt"), K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL);
+        let mut rate: f64 = 0.0;
+        let mut size = std::mem::size_of::<f64>() as u32;
+        let status = unsafe {
+            AudioObjectGetPropertyData(
+                device_id,
+                &rate_address,
+                0,
+                std::ptr::null(),
+                &mut size,
+                &mut rate as *mut f64 as *mut c_void,
+            )
+        };
+        if status == 0 {
+            Some(rate.round() as u32)
+        } else {
+            None
+        }
+    }
+
+    struct ListenerContext {
+        tx: DeviceListenerSender,
+    }
+
+    extern "C" fn on_property_changed(
+        object: AudioObjectID,
+        num_addresses: u32,
+        addresses: *const AudioObjectPropertyAddress,
+        client_data: *mut c_void,
+    ) -> OSStatus {
+        if client_data.is_null() {
+            return 0;
+        }
+        // SAFETY: `client_data` points at the `ListenerContext` boxed in
+        // `register`, which outlives every listener until `deregister`.
+        let ctx = unsafe { &*(client_data as *const ListenerContext) };
+        let addrs = unsafe { std::slice::from_raw_parts(addresses, num_addresses as usize) };
+
+        for addr in addrs {
+            let event = match addr.selector {
+                s if s == fourcc(b"nsrt") => {
+                    read_nominal_sample_rate(object).map(DeviceListenerEvent::SampleRateChanged)
+                }
+                s if s == fourcc(b"slay") => Some(DeviceListenerEvent::StreamConfigChanged),
+                s if s == fourcc(b"livn") => Some(DeviceListenerEvent::DeviceDisconnected),
+                _ => None,
+            };
+            if let Some(event) = event {
+                let _ = ctx.tx.send(event);
+            }
+        }
+        0
+    }
+
+    pub struct PlatformListener {
+        device_id: AudioObjectID,
+        context: *mut ListenerContext,
+    }
+
+    // `context` is only ever touched from the CoreAudio callback and the
+    // owning recorder worker; the channel sender it wraps is `Send`.
+    unsafe impl Send for PlatformListener {}
+
+    impl PlatformListener {
+        pub fn register(device_name: &str, tx: DeviceListenerSender) -> Result<Self, String> {
+            let device_id = find_device_id(device_name)?;
+            let context = Box::into_raw(Box::new(ListenerContext { tx }));
+
+            for addr in watched_addresses().iter() {
+                let status = unsafe {
+                    AudioObjectAddPropertyListener(
+                        device_id,
+                        addr,
+                        on_property_changed,
+                        context as *mut c_void,
+                    )
+                };
+                if status != 0 {
+                    // Roll back whatever we already registered.
+                    for rolled_back in watched_addresses().iter() {
+                        if rolled_back.selector == addr.selector {
+                            break;
+                        }
+                        unsafe {
+                            AudioObjectRemovePropertyListener(
+                                device_id,
+                                rolled_back,
+                                on_property_changed,
+                                context as *mut c_void,
+                            );
+                        }
+                    }
+                    unsafe {
+                        drop(Box::from_raw(context));
+                    }
+                    return Err(format!(
+                        "AudioObjectAddPropertyListener failed for device {}: {}",
+                        device_id, status
+                    ));
+                }
+            }
+
+            info(
+                Component::Recording,
+                &format!(
+                    "Registered real-time CoreAudio listener on device {} ('{}')",
+                    device_id, device_name
+                ),
+            );
+
+            Ok(Self { device_id, context })
+        }
+
+        pub fn deregister(mut self) {
+            self.deregister_in_place();
+        }
+
+        fn deregister_in_place(&mut self) {
+            if self.context.is_null() {
+                return;
+            }
+            for addr in watched_addresses().iter() {
+                unsafe {
+                    AudioObjectRemovePropertyListener(
+                        self.device_id,
+                        addr,
+                        on_property_changed,
+                        self.context as *mut c_void,
+                    );
+                }
+            }
+            unsafe {
+                drop(Box::from_raw(self.context));
+            }
+            self.context = std::ptr::null_mut();
+        }
+    }
+
+    impl Drop for PlatformListener {
+        fn drop(&mut self) {
+            self.deregister_in_place();
+        }
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+mod imp {
+    use super::DeviceListenerSender;
+
+    pub struct PlatformListener;
+
+    impl PlatformListener {
+        pub fn register(_device_name: &str, _tx: DeviceListenerSender) -> Result<Self, String> {
+            // TODO: WASAPI `IMMNotificationClient` on Windows, ALSA hotplug
+            // (`snd_ctl_subscribe_events` / hwdep) on Linux.
+            Err("no native per-device listener backend on this platform".to_string())
+        }
+
+        pub fn deregister(self) {}
+    }
+}
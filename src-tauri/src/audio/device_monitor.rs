@@ -1,10 +1,12 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     sync::{Arc, Mutex, OnceLock},
     thread,
     time::{Duration, Instant},
 };
 
+use super::aggregate_device::{self, AggregateDeviceHandle};
+
 use cpal::traits::{DeviceTrait, HostTrait};
 
 use crate::logger::{error, info, Component};
@@ -105,6 +107,24 @@ pub enum DeviceChangeEvent {
         old_capabilities: DeviceCapabilities,
         new_capabilities: DeviceCapabilities,
     },
+
+    /// The active capture stream should migrate to a new device because its
+    /// current device disconnected or the default switched. Fired by the
+    /// failover subsystem; the registered rebuild closure is invoked as well.
+    StreamMigrationRequested {
+        old: String,
+        new: String,
+        reason: MigrationReason,
+    },
+}
+
+/// Why a capture stream migration was requested.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MigrationReason {
+    /// The device feeding the active stream disappeared.
+    DeviceDisconnected,
+    /// The system default device changed.
+    DefaultDeviceChanged,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -113,14 +133,68 @@ pub enum DeviceType {
     Output,
 }
 
+/// A contiguous range of supported sample rates reported by a device, taken
+/// directly from a `SupportedStreamConfigRange`. Some devices support a
+/// continuous range rather than a fixed set of discrete rates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleRateRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl SampleRateRange {
+    pub fn contains(&self, rate: u32) -> bool {
+        rate >= self.min && rate <= self.max
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct DeviceCapabilities {
+    /// Discrete well-known rates that fall inside the supported ranges,
+    /// retained for back-compat with callers that want a flat list.
     pub sample_rates: Vec<u32>,
+    /// True supported ranges, straight from each config range's
+    /// `min_sample_rate()`/`max_sample_rate()`.
+    pub sample_rate_ranges: Vec<SampleRateRange>,
     pub channels: Vec<u16>,
     pub sample_formats: Vec<String>,
     pub default_config: Option<DeviceConfig>,
 }
 
+impl DeviceCapabilities {
+    /// Whether a device with these capabilities can serve the given config.
+    pub fn satisfies(&self, config: &DeviceConfig) -> bool {
+        self.supports_rate(config.sample_rate)
+            && self.channels.contains(&config.channels)
+            && self.sample_formats.contains(&config.sample_format)
+    }
+
+    /// Whether `rate` falls inside any supported range.
+    pub fn supports_rate(&self, rate: u32) -> bool {
+        self.sample_rate_ranges.iter().any(|r| r.contains(rate))
+            // Fall back to the discrete list for capabilities built without
+            // range data (e.g. synthetic test fixtures).
+            || self.sample_rates.contains(&rate)
+    }
+
+    /// Snap `target` to the nearest supported rate: `target` itself if it is in
+    /// range, otherwise the range endpoint closest to it.
+    pub fn best_rate_for(&self, target: u32) -> Option<u32> {
+        if self.supports_rate(target) {
+            return Some(target);
+        }
+        let mut candidates: Vec<u32> = self
+            .sample_rate_ranges
+            .iter()
+            .flat_map(|r| [r.min, r.max])
+            .collect();
+        candidates.extend(self.sample_rates.iter().copied());
+        candidates
+            .into_iter()
+            .min_by_key(|&r| r.abs_diff(target))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct DeviceConfig {
     pub sample_rate: u32,
@@ -136,6 +210,12 @@ pub struct DeviceMonitor {
     /// Current default input device
     current_default: Arc<Mutex<Option<String>>>,
 
+    /// Current output device snapshot
+    current_output_devices: Arc<Mutex<HashMap<String, DeviceCapabilities>>>,
+
+    /// Current default output device
+    current_default_output: Arc<Mutex<Option<String>>>,
+
     /// Event callback
     event_callback: Arc<Mutex<Option<Box<dyn Fn(DeviceChangeEvent) + Send + Sync>>>>,
 
@@ -145,8 +225,33 @@ pub struct DeviceMonitor {
     /// Stop signal
     should_stop: Arc<Mutex<bool>>,
 
-    /// Monitoring interval
+    /// Monitoring interval (used by the polling fallback backend)
     check_interval: Duration,
+
+    /// Platform-native notification backend, when active. Holds the registered
+    /// OS listeners alive until monitoring is stopped.
+    native_backend: Option<native::NativeListener>,
+
+    /// Coalesces transient disconnect/reconnect churn before it reaches the
+    /// user callback.
+    debouncer: Arc<Mutex<Debouncer>>,
+
+    /// Capture stream to fail over automatically, if failover is enabled.
+    active_stream: Arc<Mutex<Option<ActiveStream>>>,
+
+    /// Device names whose connect/disconnect events should be suppressed (e.g.
+    /// aggregate devices we create/tear down ourselves).
+    suppressed: Arc<Mutex<HashSet<String>>>,
+}
+
+/// A registered capture stream tracked for automatic failover.
+struct ActiveStream {
+    /// Device currently feeding the stream.
+    device_name: String,
+    /// Format the rebuilt stream must still satisfy.
+    required: DeviceConfig,
+    /// Closure that rebuilds the stream on the chosen replacement device.
+    rebuild: Box<dyn Fn(&str) + Send + Sync>,
 }
 
 impl DeviceMonitor {
@@ -154,13 +259,86 @@ impl DeviceMonitor {
         Self {
             current_devices: Arc::new(Mutex::new(HashMap::new())),
             current_default: Arc::new(Mutex::new(None)),
+            current_output_devices: Arc::new(Mutex::new(HashMap::new())),
+            current_default_output: Arc::new(Mutex::new(None)),
             event_callback: Arc::new(Mutex::new(None)),
             monitor_thread: None,
             should_stop: Arc::new(Mutex::new(false)),
             check_interval: Duration::from_secs(2), // Check every 2 seconds
+            native_backend: None,
+            debouncer: Arc::new(Mutex::new(Debouncer::new(Duration::from_millis(500)))),
+            active_stream: Arc::new(Mutex::new(None)),
+            suppressed: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    /// Compose the named physical input devices into a single private,
+    /// clock-synchronized aggregate capture device. The connect/disconnect
+    /// events generated by creating the aggregate are suppressed so they don't
+    /// confuse [`Self::check_for_device_changes`]. The returned handle destroys
+    /// the device on drop.
+    pub fn create_aggregate_input(
+        &self,
+        device_names: &[String],
+        clock_source: Option<String>,
+    ) -> Result<AggregateDeviceHandle, String> {
+        let current = self.current_devices.lock().unwrap();
+        let mut caps = Vec::with_capacity(device_names.len());
+        for name in device_names {
+            match current.get(name) {
+                Some(c) => caps.push(c.clone()),
+                None => return Err(format!("unknown input device: {}", name)),
+            }
+        }
+        drop(current);
+
+        let handle = aggregate_device::create_aggregate_input(device_names, clock_source, &caps)?;
+        // Suppress the aggregate's own UID and the churn on its sub-devices.
+        let mut suppressed = self.suppressed.lock().unwrap();
+        suppressed.insert(handle.uid().to_string());
+        for name in device_names {
+            suppressed.insert(name.clone());
+        }
+        Ok(handle)
+    }
+
+    /// Stop suppressing events for a previously aggregated set of device names.
+    pub fn release_suppression(&self, names: &[String]) {
+        let mut suppressed = self.suppressed.lock().unwrap();
+        for name in names {
+            suppressed.remove(name);
         }
     }
 
+    /// Register the device/config currently feeding an active capture stream so
+    /// the monitor can automatically migrate it when that device disconnects or
+    /// the default changes. `rebuild` is invoked with the chosen replacement
+    /// device name.
+    pub fn register_capture_stream<F>(
+        &self,
+        device_name: String,
+        required: DeviceConfig,
+        rebuild: F,
+    ) where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        *self.active_stream.lock().unwrap() = Some(ActiveStream {
+            device_name,
+            required,
+            rebuild: Box::new(rebuild),
+        });
+    }
+
+    /// Stop tracking the active capture stream for failover.
+    pub fn unregister_capture_stream(&self) {
+        *self.active_stream.lock().unwrap() = None;
+    }
+
+    /// Set the window over which a disconnect/reconnect pair is coalesced.
+    pub fn set_debounce_window(&mut self, window: Duration) {
+        self.debouncer.lock().unwrap().window = window;
+    }
+
     /// Set the event callback for device changes
     pub fn set_event_callback<F>(&mut self, callback: F)
     where
@@ -178,15 +356,72 @@ impl DeviceMonitor {
         // Initial device scan
         self.perform_initial_scan()?;
 
-        // Start monitoring thread
         let current_devices = self.current_devices.clone();
         let current_default = self.current_default.clone();
+        let current_output_devices = self.current_output_devices.clone();
+        let current_default_output = self.current_default_output.clone();
         let event_callback = self.event_callback.clone();
         let should_stop = self.should_stop.clone();
         let check_interval = self.check_interval;
+        let debouncer = self.debouncer.clone();
+        let active_stream = self.active_stream.clone();
+        let suppressed = self.suppressed.clone();
 
         *self.should_stop.lock().unwrap() = false;
 
+        // Prefer OS-native change notifications so events arrive within
+        // milliseconds. The polling thread below is kept as a fallback for
+        // hosts without a native backend (or when the `poll_fallback` feature
+        // is forced on).
+        #[cfg(not(feature = "poll_fallback"))]
+        {
+            let cb_devices = current_devices.clone();
+            let cb_default = current_default.clone();
+            let cb_out_devices = current_output_devices.clone();
+            let cb_out_default = current_default_output.clone();
+            let cb_events = event_callback.clone();
+            let cb_debouncer = debouncer.clone();
+            let cb_active_stream = active_stream.clone();
+            let cb_suppressed = suppressed.clone();
+            let rescan = move || {
+                if let Err(e) = Self::check_for_device_changes(
+                    &cb_devices,
+                    &cb_default,
+                    &cb_out_devices,
+                    &cb_out_default,
+                    &cb_events,
+                    &cb_debouncer,
+                    &cb_active_stream,
+                    &cb_suppressed,
+                ) {
+                    error(
+                        Component::Recording,
+                        &format!("Device rescan after native notification failed: {}", e),
+                    );
+                }
+            };
+            match native::NativeListener::register(rescan) {
+                Ok(listener) => {
+                    self.native_backend = Some(listener);
+                    info(
+                        Component::Recording,
+                        "Device monitoring started (native notifications)",
+                    );
+                    return Ok(());
+                }
+                Err(e) => {
+                    info(
+                        Component::Recording,
+                        &format!(
+                            "Native device notifications unavailable ({}), falling back to polling",
+                            e
+                        ),
+                    );
+                }
+            }
+        }
+
+        // Start polling fallback thread
         let handle = thread::spawn(move || {
             info(Component::Recording, "Device monitor thread started");
 
@@ -199,7 +434,12 @@ impl DeviceMonitor {
                     if let Err(e) = Self::check_for_device_changes(
                         &current_devices,
                         &current_default,
+                        &current_output_devices,
+                        &current_default_output,
                         &event_callback,
+                        &debouncer,
+                        &active_stream,
+                        &suppressed,
                     ) {
                         error(
                             Component::Recording,
@@ -221,6 +461,13 @@ impl DeviceMonitor {
 
     /// Stop monitoring devices
     pub fn stop_monitoring(&mut self) {
+        // Drop the native backend first so its OS listeners are deregistered.
+        if let Some(backend) = self.native_backend.take() {
+            backend.deregister();
+            info(Component::Recording, "Device monitoring stopped (native)");
+            return;
+        }
+
         if let Some(handle) = self.monitor_thread.take() {
             *self.should_stop.lock().unwrap() = true;
 
@@ -271,104 +518,179 @@ impl DeviceMonitor {
 
         *self.current_devices.lock().unwrap() = devices_map;
 
+        // Scan output devices in parallel.
+        let mut output_map = HashMap::new();
+        if let Ok(output_devices) = host.output_devices() {
+            for device in output_devices {
+                let name = device.name().unwrap_or_else(|_| "Unknown".to_string());
+                if let Ok(capabilities) =
+                    Self::get_device_capabilities_for(&device, &DeviceType::Output)
+                {
+                    output_map.insert(name.clone(), capabilities);
+                    info(
+                        Component::Recording,
+                        &format!("Initial scan found output device: {}", name),
+                    );
+                }
+            }
+        }
+
+        if let Some(default_output) = host.default_output_device() {
+            if let Ok(default_name) = default_output.name() {
+                *self.current_default_output.lock().unwrap() = Some(default_name);
+            }
+        }
+
+        *self.current_output_devices.lock().unwrap() = output_map;
+
         Ok(())
     }
 
-    /// Check for device changes
+    /// Check for device changes across both input and output directions.
     fn check_for_device_changes(
         current_devices: &Arc<Mutex<HashMap<String, DeviceCapabilities>>>,
         current_default: &Arc<Mutex<Option<String>>>,
+        current_output_devices: &Arc<Mutex<HashMap<String, DeviceCapabilities>>>,
+        current_default_output: &Arc<Mutex<Option<String>>>,
         event_callback: &Arc<Mutex<Option<Box<dyn Fn(DeviceChangeEvent) + Send + Sync>>>>,
+        debouncer: &Arc<Mutex<Debouncer>>,
+        active_stream: &Arc<Mutex<Option<ActiveStream>>>,
+        suppressed: &Arc<Mutex<HashSet<String>>>,
     ) -> Result<(), String> {
         let host = cpal::default_host();
 
-        // Get current device state
+        // Release any disconnects that were not cancelled by a reconnect since
+        // the previous tick.
+        debouncer.lock().unwrap().flush(event_callback);
+
+        // Input direction
         let input_devices = host
             .input_devices()
-            .map_err(|e| format!("Failed to enumerate devices: {}", e))?;
+            .map_err(|e| format!("Failed to enumerate input devices: {}", e))?;
+        Self::diff_direction(
+            DeviceType::Input,
+            input_devices,
+            host.default_input_device(),
+            current_devices,
+            current_default,
+            event_callback,
+            debouncer,
+            suppressed,
+        );
+
+        // Fail the active capture stream over if its device went away or the
+        // default changed under it.
+        Self::maybe_failover(active_stream, current_devices, current_default, event_callback);
+
+        // Output direction
+        let output_devices = host
+            .output_devices()
+            .map_err(|e| format!("Failed to enumerate output devices: {}", e))?;
+        Self::diff_direction(
+            DeviceType::Output,
+            output_devices,
+            host.default_output_device(),
+            current_output_devices,
+            current_default_output,
+            event_callback,
+            debouncer,
+            suppressed,
+        );
 
-        let mut new_devices = HashMap::new();
+        Ok(())
+    }
 
-        // Build new device map
-        for device in input_devices {
+    /// Diff a single direction's enumerated devices against the tracked
+    /// snapshot, emitting connect/disconnect/capability/default events.
+    fn diff_direction(
+        device_type: DeviceType,
+        enumerated: impl Iterator<Item = cpal::Device>,
+        default_device: Option<cpal::Device>,
+        current_devices: &Arc<Mutex<HashMap<String, DeviceCapabilities>>>,
+        current_default: &Arc<Mutex<Option<String>>>,
+        event_callback: &Arc<Mutex<Option<Box<dyn Fn(DeviceChangeEvent) + Send + Sync>>>>,
+        debouncer: &Arc<Mutex<Debouncer>>,
+        suppressed: &Arc<Mutex<HashSet<String>>>,
+    ) {
+        let mut new_devices = HashMap::new();
+        for device in enumerated {
             let name = device.name().unwrap_or_else(|_| "Unknown".to_string());
-
-            if let Ok(capabilities) = Self::get_device_capabilities(&device) {
+            if let Ok(capabilities) = Self::get_device_capabilities_for(&device, &device_type) {
                 new_devices.insert(name, capabilities);
             }
         }
 
-        // Compare with current state
+        let is_suppressed = |name: &str| suppressed.lock().unwrap().contains(name);
         let mut current_devices_guard = current_devices.lock().unwrap();
 
-        // Check for new devices
         for (name, capabilities) in &new_devices {
-            if !current_devices_guard.contains_key(name) {
-                info(
-                    Component::Recording,
-                    &format!("New device detected: {}", name),
-                );
-                Self::emit_event(
-                    &event_callback,
-                    DeviceChangeEvent::DeviceConnected {
-                        name: name.clone(),
-                        device_type: DeviceType::Input,
-                    },
-                );
-            } else {
-                // Check for capability changes
-                if let Some(old_capabilities) = current_devices_guard.get(name) {
-                    if old_capabilities != capabilities {
-                        info(
-                            Component::Recording,
-                            &format!("Device capabilities changed: {}", name),
-                        );
-                        Self::emit_event(
-                            &event_callback,
-                            DeviceChangeEvent::DeviceCapabilitiesChanged {
-                                name: name.clone(),
-                                old_capabilities: old_capabilities.clone(),
-                                new_capabilities: capabilities.clone(),
-                            },
-                        );
-                    }
+            if is_suppressed(name) {
+                continue;
+            }
+            match current_devices_guard.get(name) {
+                None => {
+                    info(
+                        Component::Recording,
+                        &format!("New {:?} device detected: {}", device_type, name),
+                    );
+                    // Route through the debouncer so a reconnect that cancels a
+                    // recent disconnect is coalesced rather than emitted raw.
+                    debouncer.lock().unwrap().on_connect(
+                        name.clone(),
+                        device_type.clone(),
+                        capabilities.clone(),
+                        event_callback,
+                    );
                 }
+                Some(old_capabilities) if old_capabilities != capabilities => {
+                    info(
+                        Component::Recording,
+                        &format!("{:?} device capabilities changed: {}", device_type, name),
+                    );
+                    Self::emit_event(
+                        event_callback,
+                        DeviceChangeEvent::DeviceCapabilitiesChanged {
+                            name: name.clone(),
+                            old_capabilities: old_capabilities.clone(),
+                            new_capabilities: capabilities.clone(),
+                        },
+                    );
+                }
+                Some(_) => {}
             }
         }
 
-        // Check for removed devices
-        for name in current_devices_guard.keys() {
-            if !new_devices.contains_key(name) {
+        for (name, old_caps) in current_devices_guard.iter() {
+            if !new_devices.contains_key(name) && !is_suppressed(name) {
                 info(
                     Component::Recording,
-                    &format!("Device disconnected: {}", name),
-                );
-                Self::emit_event(
-                    &event_callback,
-                    DeviceChangeEvent::DeviceDisconnected { name: name.clone() },
+                    &format!("{:?} device disconnected: {}", device_type, name),
                 );
+                // Hold the disconnect; it is released on the next tick unless a
+                // reconnect cancels it first.
+                debouncer
+                    .lock()
+                    .unwrap()
+                    .on_disconnect(name.clone(), old_caps.clone());
             }
         }
 
-        // Update current devices
         *current_devices_guard = new_devices;
         drop(current_devices_guard);
 
-        // Check for default device changes
-        if let Some(default_device) = host.default_input_device() {
+        // Default device change for this direction.
+        if let Some(default_device) = default_device {
             if let Ok(default_name) = default_device.name() {
                 let mut default_guard = current_default.lock().unwrap();
-
                 if default_guard.as_ref() != Some(&default_name) {
                     let old_default = default_guard.clone();
                     *default_guard = Some(default_name.clone());
-
                     info(
                         Component::Recording,
-                        &format!("Default device changed to: {}", default_name),
+                        &format!("Default {:?} device changed to: {}", device_type, default_name),
                     );
                     Self::emit_event(
-                        &event_callback,
+                        event_callback,
                         DeviceChangeEvent::DefaultDeviceChanged {
                             old_default,
                             new_default: default_name,
@@ -377,35 +699,131 @@ impl DeviceMonitor {
                 }
             }
         }
+    }
 
-        Ok(())
+    /// Migrate the registered capture stream if its device disconnected or the
+    /// default device changed out from under it.
+    fn maybe_failover(
+        active_stream: &Arc<Mutex<Option<ActiveStream>>>,
+        current_devices: &Arc<Mutex<HashMap<String, DeviceCapabilities>>>,
+        current_default: &Arc<Mutex<Option<String>>>,
+        event_callback: &Arc<Mutex<Option<Box<dyn Fn(DeviceChangeEvent) + Send + Sync>>>>,
+    ) {
+        let mut guard = active_stream.lock().unwrap();
+        let stream = match guard.as_mut() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let devices = current_devices.lock().unwrap();
+        let default = current_default.lock().unwrap().clone();
+
+        let disconnected = !devices.contains_key(&stream.device_name);
+        let default_changed = default
+            .as_ref()
+            .map(|d| d != &stream.device_name)
+            .unwrap_or(false);
+        if !disconnected && !default_changed {
+            return;
+        }
+        let reason = if disconnected {
+            MigrationReason::DeviceDisconnected
+        } else {
+            MigrationReason::DefaultDeviceChanged
+        };
+
+        // Candidate order: new default, then the previous device if it
+        // reappeared, then the first device that still satisfies the format.
+        let candidate = default
+            .as_ref()
+            .filter(|name| {
+                devices
+                    .get(*name)
+                    .map(|caps| caps.satisfies(&stream.required))
+                    .unwrap_or(false)
+            })
+            .cloned()
+            .or_else(|| {
+                devices
+                    .get(&stream.device_name)
+                    .filter(|caps| caps.satisfies(&stream.required))
+                    .map(|_| stream.device_name.clone())
+            })
+            .or_else(|| {
+                devices
+                    .iter()
+                    .find(|(_, caps)| caps.satisfies(&stream.required))
+                    .map(|(name, _)| name.clone())
+            });
+
+        if let Some(new) = candidate {
+            if new == stream.device_name && !disconnected {
+                return; // Already on the right device.
+            }
+            let old = std::mem::replace(&mut stream.device_name, new.clone());
+            (stream.rebuild)(&new);
+            Self::emit_event(
+                event_callback,
+                DeviceChangeEvent::StreamMigrationRequested { old, new, reason },
+            );
+        } else {
+            error(
+                Component::Recording,
+                &format!(
+                    "No replacement device satisfies the active stream format ({}Hz/{}ch)",
+                    stream.required.sample_rate, stream.required.channels
+                ),
+            );
+        }
     }
 
-    /// Get device capabilities
+    /// Get device capabilities for an input device (back-compat shim).
     fn get_device_capabilities(device: &cpal::Device) -> Result<DeviceCapabilities, String> {
-        let default_config = device
-            .default_input_config()
-            .map_err(|e| format!("Failed to get default config: {}", e))?;
+        Self::get_device_capabilities_for(device, &DeviceType::Input)
+    }
+
+    /// Get device capabilities for the given direction, reading either the
+    /// input or output config set depending on `device_type`.
+    fn get_device_capabilities_for(
+        device: &cpal::Device,
+        device_type: &DeviceType,
+    ) -> Result<DeviceCapabilities, String> {
+        let default_config = match device_type {
+            DeviceType::Input => device.default_input_config(),
+            DeviceType::Output => device.default_output_config(),
+        }
+        .map_err(|e| format!("Failed to get default config: {}", e))?;
 
         let mut sample_rates = Vec::new();
+        let mut sample_rate_ranges: Vec<SampleRateRange> = Vec::new();
         let mut channels = Vec::new();
         let mut sample_formats = Vec::new();
 
         // Get supported configurations
-        if let Ok(supported_configs) = device.supported_input_configs() {
+        let supported = match device_type {
+            DeviceType::Input => device.supported_input_configs().ok(),
+            DeviceType::Output => device.supported_output_configs().ok(),
+        };
+        if let Some(supported_configs) = supported {
             for supported_range in supported_configs {
-                // Collect sample rates
+                // Capture the true supported range directly.
                 let min_rate = supported_range.min_sample_rate().0;
                 let max_rate = supported_range.max_sample_rate().0;
+                let range = SampleRateRange {
+                    min: min_rate,
+                    max: max_rate,
+                };
+                if !sample_rate_ranges.contains(&range) {
+                    sample_rate_ranges.push(range);
+                }
 
-                // Add common sample rates within the supported range
+                // Keep a flat list of well-known rates within the range for
+                // back-compat callers, plus the range endpoints.
                 for &rate in &[8000, 16000, 22050, 24000, 44100, 48000, 96000] {
                     if rate >= min_rate && rate <= max_rate && !sample_rates.contains(&rate) {
                         sample_rates.push(rate);
                     }
                 }
-
-                // Add min and max rates
                 if !sample_rates.contains(&min_rate) {
                     sample_rates.push(min_rate);
                 }
@@ -431,8 +849,11 @@ impl DeviceMonitor {
         channels.sort();
         sample_formats.sort();
 
+        sample_rate_ranges.sort_by_key(|r| (r.min, r.max));
+
         Ok(DeviceCapabilities {
             sample_rates,
+            sample_rate_ranges,
             channels,
             sample_formats,
             default_config: Some(DeviceConfig {
@@ -474,6 +895,26 @@ impl DeviceMonitor {
         self.get_device_capabilities_by_name(&default_name)
     }
 
+    /// Get current output device snapshot
+    pub fn get_current_output_devices(&self) -> HashMap<String, DeviceCapabilities> {
+        self.current_output_devices.lock().unwrap().clone()
+    }
+
+    /// Get current default output device
+    pub fn get_current_default_output(&self) -> Option<String> {
+        self.current_default_output.lock().unwrap().clone()
+    }
+
+    /// Get capabilities for the default output device
+    pub fn get_default_output_device_capabilities(&self) -> Option<DeviceCapabilities> {
+        let default_name = self.get_current_default_output()?;
+        self.current_output_devices
+            .lock()
+            .unwrap()
+            .get(&default_name)
+            .cloned()
+    }
+
     /// Immediately probe and return device capabilities without starting monitoring (with caching)
     /// This is useful for eager device detection during initialization
     pub fn probe_device_capabilities() -> Result<HashMap<String, DeviceCapabilities>, String> {
@@ -571,7 +1012,12 @@ impl DeviceMonitor {
         Self::check_for_device_changes(
             &self.current_devices,
             &self.current_default,
+            &self.current_output_devices,
+            &self.current_default_output,
             &self.event_callback,
+            &self.debouncer,
+            &self.active_stream,
+            &self.suppressed,
         )
     }
 
@@ -587,6 +1033,399 @@ impl Drop for DeviceMonitor {
     }
 }
 
+/// Platform-native device-change notification backends.
+///
+/// Each backend registers OS listeners that invoke a re-scan callback when the
+/// device list or the default device changes, so events are delivered within
+/// milliseconds instead of being discovered by the 2s polling loop. Hosts
+/// without a native backend return `Err` from `register`, at which point the
+/// caller falls back to polling.
+mod native {
+    /// Re-scan callback invoked by the OS when devices change.
+    pub type RescanFn = Box<dyn Fn() + Send + 'static>;
+
+    #[cfg(target_os = "macos")]
+    mod imp {
+        use super::RescanFn;
+        use std::os::raw::c_void;
+
+        type OSStatus = i32;
+        type AudioObjectID = u32;
+        type AudioObjectPropertySelector = u32;
+        type AudioObjectPropertyScope = u32;
+        type AudioObjectPropertyElement = u32;
+
+        #[repr(C)]
+        struct AudioObjectPropertyAddress {
+            selector: AudioObjectPropertySelector,
+            scope: AudioObjectPropertyScope,
+            element: AudioObjectPropertyElement,
+        }
+
+        type Listener = extern "C" fn(
+            AudioObjectID,
+            u32,
+            *const AudioObjectPropertyAddress,
+            *mut c_void,
+        ) -> OSStatus;
+
+        #[link(name = "CoreAudio", kind = "framework")]
+        extern "C" {
+            fn AudioObjectAddPropertyListener(
+                in_object: AudioObjectID,
+                in_address: *const AudioObjectPropertyAddress,
+                in_proc: Listener,
+                in_client_data: *mut c_void,
+            ) -> OSStatus;
+
+            fn AudioObjectRemovePropertyListener(
+                in_object: AudioObjectID,
+                in_address: *const AudioObjectPropertyAddress,
+                in_proc: Listener,
+                in_client_data: *mut c_void,
+            ) -> OSStatus;
+        }
+
+        const K_AUDIO_OBJECT_SYSTEM_OBJECT: AudioObjectID = 1;
+        const K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL: AudioObjectPropertyScope = fourcc(b"glob");
+        const K_AUDIO_OBJECT_PROPERTY_ELEMENT_MAIN: AudioObjectPropertyElement = 0;
+
+        const fn fourcc(code: &[u8; 4]) -> u32 {
+            ((code[0] as u32) << 24)
+                | ((code[1] as u32) << 16)
+                | ((code[2] as u32) << 8)
+                | (code[3] as u32)
+        }
+
+        fn watched_addresses() -> [AudioObjectPropertyAddress; 3] {
+            let mk = |selector: [u8; 4]| AudioObjectPropertyAddress {
+                selector: fourcc(&selector),
+                scope: K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL,
+                element: K_AUDIO_OBJECT_PROPERTY_ELEMENT_MAIN,
+            };
+            [
+                mk(*b"dev#"), // kAudioHardwarePropertyDevices
+                mk(*b"dIn "), // kAudioHardwarePropertyDefaultInputDevice
+                mk(*b"dOut"), // kAudioHardwarePropertyDefaultOutputDevice
+            ]
+        }
+
+        extern "C" fn on_property_changed(
+            _object: AudioObjectID,
+            _num: u32,
+            _addresses: *const AudioObjectPropertyAddress,
+            client_data: *mut c_void,
+        ) -> OSStatus {
+            if !client_data.is_null() {
+                // SAFETY: `client_data` points at the `RescanFn` boxed in
+                // `register`, which outlives every listener until `deregister`.
+                let callback = unsafe { &*(client_data as *const RescanFn) };
+                callback();
+            }
+            0
+        }
+
+        pub struct NativeListener {
+            context: *mut RescanFn,
+        }
+
+        // The context is only ever touched from the CoreAudio callback and the
+        // owning monitor; the boxed closure is `Send`.
+        unsafe impl Send for NativeListener {}
+
+        impl NativeListener {
+            pub fn register(rescan: RescanFn) -> Result<Self, String> {
+                let context = Box::into_raw(Box::new(rescan));
+                for addr in watched_addresses().iter() {
+                    let status = unsafe {
+                        AudioObjectAddPropertyListener(
+                            K_AUDIO_OBJECT_SYSTEM_OBJECT,
+                            addr,
+                            on_property_changed,
+                            context as *mut c_void,
+                        )
+                    };
+                    if status != 0 {
+                        // Roll back and surface the error so we fall back to polling.
+                        unsafe {
+                            drop(Box::from_raw(context));
+                        }
+                        return Err(format!("AudioObjectAddPropertyListener failed: {}", status));
+                    }
+                }
+                Ok(Self { context })
+            }
+
+            pub fn deregister(self) {
+                for addr in watched_addresses().iter() {
+                    unsafe {
+                        AudioObjectRemovePropertyListener(
+                            K_AUDIO_OBJECT_SYSTEM_OBJECT,
+                            addr,
+                            on_property_changed,
+                            self.context as *mut c_void,
+                        );
+                    }
+                }
+                unsafe {
+                    drop(Box::from_raw(self.context));
+                }
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    mod imp {
+        use super::RescanFn;
+
+        pub struct NativeListener;
+
+        impl NativeListener {
+            pub fn register(_rescan: RescanFn) -> Result<Self, String> {
+                Err("no native device-change backend on this platform".to_string())
+            }
+
+            pub fn deregister(self) {}
+        }
+    }
+
+    pub use imp::NativeListener;
+}
+
+/// Query the nominal sample rates `device_name` actually advertises support
+/// for, straight from the OS rather than the continuous min/max range cpal
+/// exposes via `supported_input_configs()`. Used to give quirk/mismatch
+/// messages (see `AudioMetadata::new`) a concrete, device-accurate rate
+/// instead of a hardcoded guess. Returns `None` if this platform has no
+/// native query, the device can't be resolved, or the property read fails.
+#[cfg(target_os = "macos")]
+pub fn query_available_sample_rates(device_name: &str) -> Option<Vec<u32>> {
+    macos_sample_rates::query(device_name)
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn query_available_sample_rates(_device_name: &str) -> Option<Vec<u32>> {
+    // TODO: WASAPI `IAudioClient::GetMixFormat`/`IsFormatSupported` on
+    // Windows, ALSA `snd_pcm_hw_params_get_rate_*` on Linux.
+    None
+}
+
+#[cfg(target_os = "macos")]
+mod macos_sample_rates {
+    use std::os::raw::{c_char, c_void};
+
+    type OSStatus = i32;
+    type AudioObjectID = u32;
+    type AudioObjectPropertySelector = u32;
+    type AudioObjectPropertyScope = u32;
+    type AudioObjectPropertyElement = u32;
+    type CFStringRef = *const c_void;
+    type CFIndex = isize;
+    type CFStringEncoding = u32;
+
+    #[repr(C)]
+    struct AudioObjectPropertyAddress {
+        selector: AudioObjectPropertySelector,
+        scope: AudioObjectPropertyScope,
+        element: AudioObjectPropertyElement,
+    }
+
+    #[repr(C)]
+    struct AudioValueRange {
+        minimum: f64,
+        maximum: f64,
+    }
+
+    #[link(name = "CoreAudio", kind = "framework")]
+    extern "C" {
+        fn AudioObjectGetPropertyDataSize(
+            in_object: AudioObjectID,
+            in_address: *const AudioObjectPropertyAddress,
+            in_qualifier_data_size: u32,
+            in_qualifier_data: *const c_void,
+            out_data_size: *mut u32,
+        ) -> OSStatus;
+
+        fn AudioObjectGetPropertyData(
+            in_object: AudioObjectID,
+            in_address: *const AudioObjectPropertyAddress,
+            in_qualifier_data_size: u32,
+            in_qualifier_data: *const c_void,
+            io_data_size: *mut u32,
+            out_data: *mut c_void,
+        ) -> OSStatus;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFStringGetCString(
+            the_string: CFStringRef,
+            buffer: *mut c_char,
+            buffer_size: CFIndex,
+            encoding: CFStringEncoding,
+        ) -> u8;
+        fn CFRelease(cf: *const c_void);
+    }
+
+    const K_CF_STRING_ENCODING_UTF8: CFStringEncoding = 0x0800_0100;
+    const K_AUDIO_OBJECT_SYSTEM_OBJECT: AudioObjectID = 1;
+    const K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL: AudioObjectPropertyScope = fourcc(b"glob");
+    const K_AUDIO_OBJECT_PROPERTY_ELEMENT_MAIN: AudioObjectPropertyElement = 0;
+
+    const fn fourcc(code: &[u8; 4]) -> u32 {
+        ((code[0] as u32) << 24)
+            | ((code[1] as u32) << 16)
+            | ((code[2] as u32) << 8)
+            | (code[3] as u32)
+    }
+
+    fn address(selector: u32, scope: AudioObjectPropertyScope) -> AudioObjectPropertyAddress {
+        AudioObjectPropertyAddress {
+            selector,
+            scope,
+            element: K_AUDIO_OBJECT_PROPERTY_ELEMENT_MAIN,
+        }
+    }
+
+    /// Resolve the `AudioObjectID` of the device named `device_name` by
+    /// enumerating `kAudioHardwarePropertyDevices` and matching on
+    /// `kAudioObjectPropertyName`. Duplicated from `device_listener`'s
+    /// `find_device_id` rather than shared, since each FFI module is meant to
+    /// stand alone.
+    fn find_device_id(device_name: &str) -> Option<AudioObjectID> {
+        let devices_address = address(fourcc(b"dev#"), K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL);
+
+        let mut data_size: u32 = 0;
+        let status = unsafe {
+            AudioObjectGetPropertyDataSize(
+                K_AUDIO_OBJECT_SYSTEM_OBJECT,
+                &devices_address,
+                0,
+                std::ptr::null(),
+                &mut data_size,
+            )
+        };
+        if status != 0 {
+            return None;
+        }
+
+        let count = data_size as usize / std::mem::size_of::<AudioObjectID>();
+        let mut device_ids: Vec<AudioObjectID> = vec![0; count];
+        let status = unsafe {
+            AudioObjectGetPropertyData(
+                K_AUDIO_OBJECT_SYSTEM_OBJECT,
+                &devices_address,
+                0,
+                std::ptr::null(),
+                &mut data_size,
+                device_ids.as_mut_ptr() as *mut c_void,
+            )
+        };
+        if status != 0 {
+            return None;
+        }
+
+        let name_address = address(fourcc(b"lnam"), K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL); // kAudioObjectPropertyName
+
+        for device_id in device_ids {
+            let mut cf_string: CFStringRef = std::ptr::null();
+            let mut size = std::mem::size_of::<CFStringRef>() as u32;
+            let status = unsafe {
+                AudioObjectGetPropertyData(
+                    device_id,
+                    &name_address,
+                    0,
+                    std::ptr::null(),
+                    &mut size,
+                    &mut cf_string as *mut CFStringRef as *mut c_void,
+                )
+            };
+            if status != 0 || cf_string.is_null() {
+                continue;
+            }
+
+            let mut buf = [0i8; 256];
+            let ok = unsafe {
+                CFStringGetCString(
+                    cf_string,
+                    buf.as_mut_ptr(),
+                    buf.len() as CFIndex,
+                    K_CF_STRING_ENCODING_UTF8,
+                )
+            };
+            unsafe { CFRelease(cf_string) };
+
+            if ok != 0 {
+                let name = unsafe { std::ffi::CStr::from_ptr(buf.as_ptr()) }
+                    .to_string_lossy()
+                    .into_owned();
+                if name == device_name {
+                    return Some(device_id);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Query `kAudioDevicePropertyAvailableNominalSampleRates`, which returns
+    /// an array of `AudioValueRange`s: a discrete rate appears as a range
+    /// with `minimum == maximum`, while devices that support a continuous
+    /// span report it as one wider range. Flattened here into a sorted,
+    /// deduplicated list of concrete rates (both endpoints of any span).
+    pub fn query(device_name: &str) -> Option<Vec<u32>> {
+        let device_id = find_device_id(device_name)?;
+        let rates_address = address(fourcc(b"nsr#"), K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL); // kAudioDevicePropertyAvailableNominalSampleRates
+
+        let mut data_size: u32 = 0;
+        let status = unsafe {
+            AudioObjectGetPropertyDataSize(
+                device_id,
+                &rates_address,
+                0,
+                std::ptr::null(),
+                &mut data_size,
+            )
+        };
+        if status != 0 {
+            return None;
+        }
+
+        let count = data_size as usize / std::mem::size_of::<AudioValueRange>();
+        if count == 0 {
+            return None;
+        }
+        let mut ranges: Vec<AudioValueRange> = (0..count)
+            .map(|_| AudioValueRange {
+                minimum: 0.0,
+                maximum: 0.0,
+            })
+            .collect();
+        let status = unsafe {
+            AudioObjectGetPropertyData(
+                device_id,
+                &rates_address,
+                0,
+                std::ptr::null(),
+                &mut data_size,
+                ranges.as_mut_ptr() as *mut c_void,
+            )
+        };
+        if status != 0 {
+            return None;
+        }
+
+        let mut rates: Vec<u32> = Vec::with_capacity(ranges.len() * 2);
+        for range in &ranges {
+            rates.push(range.minimum.round() as u32);
+            rates.push(range.maximum.round() as u32);
+        }
+        rates.sort_unstable();
+        rates.dedup();
+        Some(rates)
+    }
+}
+
 /// Capability checker for periodic validation during recording
 pub struct DeviceCapabilityChecker {
     device_name: String,
@@ -654,6 +1493,85 @@ impl DeviceCapabilityChecker {
     }
 }
 
+type EventCallback = Arc<Mutex<Option<Box<dyn Fn(DeviceChangeEvent) + Send + Sync>>>>;
+
+/// Coalesces transient disconnect/reconnect bursts (e.g. the churn CoreAudio
+/// emits while aggregate devices are torn down and rebuilt) so downstream
+/// consumers don't needlessly rebuild streams.
+///
+/// A `DeviceDisconnected` is held back for up to `window`; if a
+/// `DeviceConnected` for the same name arrives within the window the pair is
+/// coalesced into a single `DeviceCapabilitiesChanged` (or dropped entirely if
+/// the capabilities are unchanged). Disconnects with no matching reconnect are
+/// released on the next [`Debouncer::flush`].
+struct Debouncer {
+    window: Duration,
+    pending: HashMap<String, (Instant, DeviceCapabilities)>,
+}
+
+impl Debouncer {
+    fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Hold a disconnect, remembering the device's last-known capabilities.
+    fn on_disconnect(&mut self, name: String, old_caps: DeviceCapabilities) {
+        self.pending.insert(name, (Instant::now(), old_caps));
+    }
+
+    /// Handle a connect. If it cancels a recent disconnect, emit at most a
+    /// capabilities-changed event; otherwise pass the connect through.
+    fn on_connect(
+        &mut self,
+        name: String,
+        device_type: DeviceType,
+        new_caps: DeviceCapabilities,
+        event_callback: &EventCallback,
+    ) {
+        if let Some((ts, old_caps)) = self.pending.remove(&name) {
+            if ts.elapsed() <= self.window {
+                if old_caps != new_caps {
+                    DeviceMonitor::emit_event(
+                        event_callback,
+                        DeviceChangeEvent::DeviceCapabilitiesChanged {
+                            name,
+                            old_capabilities: old_caps,
+                            new_capabilities: new_caps,
+                        },
+                    );
+                }
+                // Identical capabilities within the window: drop the glitch.
+                return;
+            }
+        }
+        DeviceMonitor::emit_event(
+            event_callback,
+            DeviceChangeEvent::DeviceConnected { name, device_type },
+        );
+    }
+
+    /// Release disconnects that were not cancelled by a reconnect in time.
+    fn flush(&mut self, event_callback: &EventCallback) {
+        let window = self.window;
+        let expired: Vec<String> = self
+            .pending
+            .iter()
+            .filter(|(_, (ts, _))| ts.elapsed() > window)
+            .map(|(name, _)| name.clone())
+            .collect();
+        for name in expired {
+            self.pending.remove(&name);
+            DeviceMonitor::emit_event(
+                event_callback,
+                DeviceChangeEvent::DeviceDisconnected { name },
+            );
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum CapabilityCheckResult {
     FirstCheck(DeviceCapabilities),
@@ -689,6 +1607,7 @@ mod tests {
     fn test_device_capabilities_equality() {
         let caps1 = DeviceCapabilities {
             sample_rates: vec![44100, 48000],
+            sample_rate_ranges: vec![SampleRateRange { min: 44100, max: 48000 }],
             channels: vec![1, 2],
             sample_formats: vec!["F32".to_string()],
             default_config: None,
@@ -696,6 +1615,7 @@ mod tests {
 
         let caps2 = DeviceCapabilities {
             sample_rates: vec![44100, 48000],
+            sample_rate_ranges: vec![SampleRateRange { min: 44100, max: 48000 }],
             channels: vec![1, 2],
             sample_formats: vec!["F32".to_string()],
             default_config: None,
@@ -703,4 +1623,67 @@ mod tests {
 
         assert_eq!(caps1, caps2);
     }
+
+    fn caps(rate: u32) -> DeviceCapabilities {
+        DeviceCapabilities {
+            sample_rates: vec![rate],
+            sample_rate_ranges: vec![SampleRateRange { min: rate, max: rate }],
+            channels: vec![1],
+            sample_formats: vec!["F32".to_string()],
+            default_config: None,
+        }
+    }
+
+    fn recording_callback() -> (EventCallback, Arc<Mutex<Vec<DeviceChangeEvent>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let cb: EventCallback = Arc::new(Mutex::new(Some(Box::new(move |e: DeviceChangeEvent| {
+            sink.lock().unwrap().push(e);
+        }))));
+        (cb, events)
+    }
+
+    #[test]
+    fn debouncer_coalesces_disconnect_reconnect_with_identical_caps() {
+        let mut d = Debouncer::new(Duration::from_millis(500));
+        let (cb, events) = recording_callback();
+
+        d.on_disconnect("Mic".to_string(), caps(48000));
+        d.on_connect("Mic".to_string(), DeviceType::Input, caps(48000), &cb);
+        d.flush(&cb);
+
+        assert!(events.lock().unwrap().is_empty(), "glitch should be dropped");
+    }
+
+    #[test]
+    fn debouncer_emits_capabilities_changed_when_caps_differ() {
+        let mut d = Debouncer::new(Duration::from_millis(500));
+        let (cb, events) = recording_callback();
+
+        d.on_disconnect("Mic".to_string(), caps(48000));
+        d.on_connect("Mic".to_string(), DeviceType::Input, caps(44100), &cb);
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0],
+            DeviceChangeEvent::DeviceCapabilitiesChanged { .. }
+        ));
+    }
+
+    #[test]
+    fn debouncer_releases_uncancelled_disconnect_on_flush() {
+        let mut d = Debouncer::new(Duration::from_millis(0));
+        let (cb, events) = recording_callback();
+
+        d.on_disconnect("Mic".to_string(), caps(48000));
+        d.flush(&cb);
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0],
+            DeviceChangeEvent::DeviceDisconnected { .. }
+        ));
+    }
 }
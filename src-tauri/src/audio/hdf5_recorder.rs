@@ -0,0 +1,144 @@
+//! Alternative recording backend that writes captured audio into a
+//! self-describing HDF5 container instead of a WAV file, following the
+//! same "resizable chunked dataset plus provenance attributes" design the
+//! `lasprs` project's recorder uses. Depends on the `hdf5` crate (for the
+//! file/dataset API) and `ndarray` (for the 2-D sample blocks it writes).
+use chrono::Utc;
+use hdf5::File as H5File;
+use ndarray::Array2;
+use std::path::Path;
+use uuid::Uuid;
+
+/// Chunk length, in frames, for the resizable `samples` dataset. Close
+/// enough to the block sizes CPAL callbacks typically deliver that most
+/// writes land on a chunk boundary instead of forcing a partial
+/// read-modify-write.
+const CHUNK_FRAMES: usize = 4096;
+/// gzip compression level passed to `deflate()`.
+const GZIP_LEVEL: u8 = 4;
+
+/// Writes interleaved audio into a resizable `[frames, channels]` HDF5
+/// dataset and attaches provenance attributes on `finalize`, in place of
+/// `hound::WavWriter`.
+pub struct Hdf5Recorder {
+    file: H5File,
+    dataset: hdf5::Dataset,
+    channels: u16,
+    sample_rate: u32,
+    sample_format_label: String,
+    device_name: String,
+    recording_id: Uuid,
+    start_time: chrono::DateTime<Utc>,
+    frames_written: u64,
+}
+
+impl Hdf5Recorder {
+    /// Creates `path` and a resizable, chunked, gzip-compressed
+    /// `[0, channels]` dataset ready to grow as samples arrive.
+    pub fn create(
+        path: &Path,
+        sample_rate: u32,
+        channels: u16,
+        sample_format_label: &str,
+        device_name: &str,
+    ) -> Result<Self, String> {
+        let file = H5File::create(path).map_err(|e| format!("Failed to create HDF5 file: {}", e))?;
+
+        let dataset = file
+            .new_dataset::<f32>()
+            .shape((0..hdf5::Extents::UNLIMITED, channels as usize))
+            .chunk((CHUNK_FRAMES, channels as usize))
+            .deflate(GZIP_LEVEL)
+            .create("samples")
+            .map_err(|e| format!("Failed to create HDF5 dataset: {}", e))?;
+
+        Ok(Self {
+            file,
+            dataset,
+            channels,
+            sample_rate,
+            sample_format_label: sample_format_label.to_string(),
+            device_name: device_name.to_string(),
+            recording_id: Uuid::new_v4(),
+            start_time: Utc::now(),
+            frames_written: 0,
+        })
+    }
+
+    /// Appends one callback's worth of interleaved samples (`data.len()`
+    /// must be a multiple of `channels`), resizing the dataset and writing
+    /// the new frames as a single slice.
+    pub fn write_samples(&mut self, data: &[f32]) -> Result<(), String> {
+        let channels = self.channels as usize;
+        if channels == 0 || data.len() % channels != 0 {
+            return Err(format!(
+                "Sample block of {} values isn't a multiple of {} channels",
+                data.len(),
+                channels
+            ));
+        }
+        let new_frames = data.len() / channels;
+        if new_frames == 0 {
+            return Ok(());
+        }
+
+        let start_frame = self.frames_written as usize;
+        let end_frame = start_frame + new_frames;
+
+        self.dataset
+            .resize((end_frame, channels))
+            .map_err(|e| format!("Failed to resize HDF5 dataset to {} frames: {}", end_frame, e))?;
+
+        let block = Array2::from_shape_vec((new_frames, channels), data.to_vec())
+            .map_err(|e| format!("Failed to reshape sample block: {}", e))?;
+
+        self.dataset
+            .write_slice(&block, (start_frame..end_frame, ..))
+            .map_err(|e| format!("Failed to write HDF5 slice: {}", e))?;
+
+        self.frames_written = end_frame as u64;
+        Ok(())
+    }
+
+    pub fn frames_written(&self) -> u64 {
+        self.frames_written
+    }
+
+    /// Attaches provenance attributes a bare WAV can't carry — a recording
+    /// UUID, ISO-8601 start timestamp, device name, sample rate/channel
+    /// count/format, and total frame count — then closes the file.
+    pub fn finalize(self) -> Result<(), String> {
+        write_string_attr(&self.dataset, "recording_id", &self.recording_id.to_string())?;
+        write_string_attr(&self.dataset, "start_time", &self.start_time.to_rfc3339())?;
+        write_string_attr(&self.dataset, "device_name", &self.device_name)?;
+        write_string_attr(&self.dataset, "sample_format", &self.sample_format_label)?;
+        write_scalar_attr(&self.dataset, "sample_rate", self.sample_rate)?;
+        write_scalar_attr(&self.dataset, "channels", self.channels as u32)?;
+        write_scalar_attr(&self.dataset, "total_frames", self.frames_written)?;
+
+        self.file
+            .close()
+            .map_err(|e| format!("Failed to close HDF5 file: {}", e))
+    }
+}
+
+fn write_string_attr(dataset: &hdf5::Dataset, name: &str, value: &str) -> Result<(), String> {
+    let value: hdf5::types::VarLenUnicode = value
+        .parse()
+        .map_err(|e| format!("Invalid attribute value for `{}`: {:?}", name, e))?;
+    dataset
+        .new_attr::<hdf5::types::VarLenUnicode>()
+        .create(name)
+        .map_err(|e| format!("Failed to create attribute `{}`: {}", name, e))?
+        .write_scalar(&value)
+        .map_err(|e| format!("Failed to write attribute `{}`: {}", name, e))
+}
+
+fn write_scalar_attr<T: hdf5::H5Type>(dataset: &hdf5::Dataset, name: &str, value: T) -> Result<(), String> {
+    dataset
+        .new_attr::<T>()
+        .create(name)
+        .map_err(|e| format!("Failed to create attribute `{}`: {}", name, e))?
+        .write_scalar(&value)
+        .map_err(|e| format!("Failed to write attribute `{}`: {}", name, e))
+}
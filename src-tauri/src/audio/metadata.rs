@@ -1,3 +1,5 @@
+use super::config::AudioBackend;
+use super::device_monitor;
 use cpal::{BufferSize, SampleFormat};
 use serde::{Deserialize, Serialize};
 
@@ -55,6 +57,13 @@ pub struct FormatMetadata {
     /// Detected sample rate from audio analysis (may differ from reported)
     pub detected_sample_rate: Option<u32>,
 
+    /// Nominal sample rates the device itself advertises support for (via a
+    /// native capability query, e.g. CoreAudio's
+    /// `kAudioDevicePropertyAvailableNominalSampleRates` on macOS). Empty
+    /// when the platform has no native query or the device couldn't be
+    /// resolved.
+    pub supported_sample_rates: Vec<u32>,
+
     /// Actual number of channels
     pub channels: u16,
 
@@ -118,6 +127,10 @@ pub struct SystemMetadata {
     /// Audio backend (CoreAudio, WASAPI, ALSA, etc.)
     pub audio_backend: String,
 
+    /// Backend the user explicitly requested via `CustomAudioDeviceConfig`,
+    /// if any. `None` when left at `AudioBackend::Auto`.
+    pub requested_audio_backend: Option<String>,
+
     /// System audio settings that might affect recording
     pub system_notes: Vec<String>,
 }
@@ -215,6 +228,7 @@ impl AudioMetadata {
         sample_format: SampleFormat,
         buffer_size: &BufferSize,
         is_default_device: bool,
+        requested_backend: AudioBackend,
     ) -> Self {
         let mut notes = Vec::new();
         let mut mismatches = Vec::new();
@@ -222,6 +236,13 @@ impl AudioMetadata {
         // Detect device type and potential issues
         let device_type = detect_device_type(&device_name);
 
+        // Query the rates the device itself advertises, so quirk/mismatch
+        // messages can recommend a rate it actually supports instead of a
+        // hardcoded guess.
+        let supported_sample_rates =
+            device_monitor::query_available_sample_rates(&device_name).unwrap_or_default();
+        let highest_supported_rate = supported_sample_rates.iter().copied().max();
+
         // Initialize device quirks
         let device_name_lower = device_name.to_lowercase();
         let is_airpods = device_name_lower.contains("airpod");
@@ -245,13 +266,21 @@ impl AudioMetadata {
                 notes.push("AirPods in low-quality/call mode detected!".to_string());
                 mismatches.push(ConfigMismatch {
                     mismatch_type: "sample_rate".to_string(),
-                    requested: "48000 Hz expected".to_string(),
+                    requested: match highest_supported_rate {
+                        Some(rate) => format!("{} Hz expected (device's highest advertised rate)", rate),
+                        None => "high-quality rate expected".to_string(),
+                    },
                     actual: format!("{} Hz", actual_config.sample_rate.0),
                     impact: "Audio may have incorrect pitch if sample rate mismatch not handled"
                         .to_string(),
-                    resolution: Some(
-                        "Disconnect and reconnect AirPods, or use wired headphones".to_string(),
-                    ),
+                    resolution: Some(match highest_supported_rate {
+                        Some(rate) => format!(
+                            "Disconnect and reconnect AirPods, or reconfigure to its {} Hz mode, or use wired headphones",
+                            rate
+                        ),
+                        None => "Disconnect and reconnect AirPods, or use wired headphones"
+                            .to_string(),
+                    }),
                 });
             } else if actual_config.sample_rate.0 >= 44100 {
                 device_mode = Some("high_quality".to_string());
@@ -316,6 +345,24 @@ impl AudioMetadata {
         let (os, os_version) = get_os_info();
         let audio_backend = get_audio_backend();
 
+        let requested_audio_backend = if requested_backend == AudioBackend::Auto {
+            None
+        } else {
+            if requested_backend.as_str() != audio_backend {
+                mismatches.push(ConfigMismatch {
+                    mismatch_type: "audio_backend".to_string(),
+                    requested: requested_backend.as_str().to_string(),
+                    actual: audio_backend.clone(),
+                    impact: "Requested audio backend isn't what this platform's default reports; the stream may still open via a different host API than intended".to_string(),
+                    resolution: Some(format!(
+                        "Verify {} is installed and selected as the active sound server, or leave the backend on Auto",
+                        requested_backend.as_str()
+                    )),
+                });
+            }
+            Some(requested_backend.as_str().to_string())
+        };
+
         Self {
             device: DeviceMetadata {
                 name: device_name,
@@ -329,6 +376,7 @@ impl AudioMetadata {
                 sample_rate: actual_config.sample_rate.0,
                 requested_sample_rate: requested_config.map(|c| c.sample_rate.0),
                 detected_sample_rate: None, // Will be filled by real-time analysis
+                supported_sample_rates,
                 channels: actual_config.channels,
                 requested_channels: requested_config.map(|c| c.channels),
                 sample_format: format!("{:?}", sample_format),
@@ -362,6 +410,7 @@ impl AudioMetadata {
                 os,
                 os_version,
                 audio_backend,
+                requested_audio_backend,
                 system_notes: notes,
             },
             mismatches,
@@ -0,0 +1,232 @@
+/// Multi-source WAV reader for meeting-mode transcription.
+///
+/// Scout can record a microphone track and a system/loopback track as two
+/// separate growing WAV files. `MixedWavReader` wraps one `WavFileReader`
+/// per track and exposes the same `extract_chunk`/`get_available_duration`/
+/// `wait_for_data` shape as a single-source `WavFileReader`, so the
+/// transcription pipeline can treat "one mic" and "mic + system audio" the
+/// same way. Sources are resampled to a common target rate and summed with
+/// the `streaming_mixer` module's soft-clip limiter; available duration is
+/// reported as the minimum across sources so callers never read past what
+/// every track has actually caught up to.
+use crate::audio::resample::resample_linear;
+use crate::audio::wav_file_reader::WavFileReader;
+use crate::logger::{info, Component};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Reads and mixes several growing WAV files into one stream.
+pub struct MixedWavReader {
+    sources: Vec<WavFileReader>,
+    /// Sample rate every source's extracted chunk is resampled to before
+    /// mixing.
+    target_rate: u32,
+}
+
+impl MixedWavReader {
+    /// Create a mixed reader over `file_paths`, resampling every source to
+    /// the first source's sample rate.
+    pub fn new(file_paths: &[impl AsRef<Path>]) -> Result<Self, String> {
+        if file_paths.is_empty() {
+            return Err("MixedWavReader requires at least one source file".to_string());
+        }
+
+        let sources = file_paths
+            .iter()
+            .map(|p| WavFileReader::new(p.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let target_rate = sources
+            .first()
+            .and_then(|s| s.get_spec())
+            .ok_or("First source has no WAV spec")?
+            .sample_rate;
+
+        info(
+            Component::RingBuffer,
+            &format!(
+                "MixedWavReader initialized with {} sources at {} Hz",
+                sources.len(),
+                target_rate
+            ),
+        );
+
+        Ok(Self {
+            sources,
+            target_rate,
+        })
+    }
+
+    /// Available duration is the minimum across sources, so a chunk request
+    /// never reaches past what every track has actually written.
+    pub fn get_available_duration(&self) -> Result<Duration, String> {
+        self.sources
+            .iter()
+            .map(|s| s.get_available_duration())
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .min()
+            .ok_or("MixedWavReader has no sources".to_string())
+    }
+
+    /// Extract `chunk_duration` of audio starting at `start_offset`, summing
+    /// each source's samples (resampled to `target_rate` when needed) and
+    /// soft-clipping the result to `-1.0..1.0`.
+    pub fn extract_chunk(
+        &self,
+        start_offset: Duration,
+        chunk_duration: Duration,
+    ) -> Result<Vec<f32>, String> {
+        let mut mixed: Vec<f32> = Vec::new();
+
+        for source in &self.sources {
+            let samples = source.extract_chunk(start_offset, chunk_duration)?;
+            let source_rate = source
+                .get_spec()
+                .ok_or("Source has no WAV spec")?
+                .sample_rate;
+            let samples = resample_linear(&samples, source_rate, self.target_rate);
+
+            if mixed.len() < samples.len() {
+                mixed.resize(samples.len(), 0.0);
+            }
+            for (mixed_sample, source_sample) in mixed.iter_mut().zip(samples.iter()) {
+                *mixed_sample += source_sample;
+            }
+        }
+
+        for sample in mixed.iter_mut() {
+            *sample = soft_clip(*sample);
+        }
+
+        Ok(mixed)
+    }
+
+    /// Wait until every source has at least `required_duration` available.
+    pub async fn wait_for_data(&self, required_duration: Duration) -> Result<bool, String> {
+        let max_wait = Duration::from_secs(10);
+        let start_wait = Instant::now();
+
+        while start_wait.elapsed() < max_wait {
+            match self.get_available_duration() {
+                Ok(available) if available >= required_duration => return Ok(true),
+                Ok(_) => {}
+                Err(e) => {
+                    debug_log(&format!("Error checking mixed duration: {}", e));
+                }
+            }
+
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        Ok(false)
+    }
+}
+
+fn debug_log(message: &str) {
+    crate::logger::debug(Component::RingBuffer, message);
+}
+
+/// Gentle tanh-based soft clip, matching `streaming_mixer`'s limiter, so
+/// summing two full-scale sources doesn't hard-clip the mixed stream.
+fn soft_clip(sample: f32) -> f32 {
+    sample.tanh()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hound::{SampleFormat, WavSpec, WavWriter};
+    use tempfile::tempdir;
+
+    fn create_test_wav_file(
+        path: &Path,
+        duration_secs: f32,
+        sample_rate: u32,
+        amplitude: f32,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 32,
+            sample_format: SampleFormat::Float,
+        };
+
+        let mut writer = WavWriter::create(path, spec)?;
+        let samples_count = (duration_secs * sample_rate as f32) as usize;
+        for i in 0..samples_count {
+            let t = i as f32 / sample_rate as f32;
+            let sample = (2.0 * std::f32::consts::PI * 440.0 * t).sin() * amplitude;
+            writer.write_sample(sample)?;
+        }
+        writer.finalize()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_mix_two_same_rate_sources_sums_samples() {
+        let temp_dir = tempdir().unwrap();
+        let mic_path = temp_dir.path().join("mic.wav");
+        let system_path = temp_dir.path().join("system.wav");
+        create_test_wav_file(&mic_path, 1.0, 16000, 0.3).unwrap();
+        create_test_wav_file(&system_path, 1.0, 16000, 0.3).unwrap();
+
+        let reader = MixedWavReader::new(&[&mic_path, &system_path]).unwrap();
+        let mic_only = WavFileReader::new(&mic_path).unwrap();
+
+        let mixed = reader
+            .extract_chunk(Duration::from_millis(0), Duration::from_millis(100))
+            .unwrap();
+        let solo = mic_only
+            .extract_chunk(Duration::from_millis(0), Duration::from_millis(100))
+            .unwrap();
+
+        assert_eq!(mixed.len(), solo.len());
+        // Two identical in-phase sources summed and soft-clipped should be
+        // louder than either alone, but still within [-1.0, 1.0].
+        for (m, s) in mixed.iter().zip(solo.iter()) {
+            assert!(*m >= -1.0 && *m <= 1.0);
+            if s.abs() > 0.01 {
+                assert!(m.abs() >= s.abs() * 0.9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_available_duration_is_minimum_across_sources() {
+        let temp_dir = tempdir().unwrap();
+        let long_path = temp_dir.path().join("long.wav");
+        let short_path = temp_dir.path().join("short.wav");
+        create_test_wav_file(&long_path, 2.0, 16000, 0.3).unwrap();
+        create_test_wav_file(&short_path, 1.0, 16000, 0.3).unwrap();
+
+        let reader = MixedWavReader::new(&[&long_path, &short_path]).unwrap();
+        let available = reader.get_available_duration().unwrap();
+
+        assert_eq!(available, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_mismatched_sample_rates_are_resampled_to_first_source() {
+        let temp_dir = tempdir().unwrap();
+        let mic_path = temp_dir.path().join("mic.wav");
+        let system_path = temp_dir.path().join("system.wav");
+        create_test_wav_file(&mic_path, 1.0, 16000, 0.3).unwrap();
+        create_test_wav_file(&system_path, 1.0, 48000, 0.3).unwrap();
+
+        let reader = MixedWavReader::new(&[&mic_path, &system_path]).unwrap();
+        let mixed = reader
+            .extract_chunk(Duration::from_millis(0), Duration::from_millis(200))
+            .unwrap();
+
+        // Mixed length should follow the 16kHz target rate, not the 48kHz
+        // source's native sample count.
+        assert!(mixed.len() > 2800 && mixed.len() < 3400);
+    }
+
+    #[test]
+    fn test_new_requires_at_least_one_source() {
+        let empty: Vec<&Path> = Vec::new();
+        assert!(MixedWavReader::new(&empty).is_err());
+    }
+}
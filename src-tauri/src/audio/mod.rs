@@ -1,11 +1,27 @@
+pub mod aggregate_device;
+pub mod chunk_sink;
+pub mod config;
 pub mod converter;
+pub mod device_listener;
 pub mod device_monitor;
 pub mod format;
+pub mod hdf5_recorder;
 pub mod metadata;
+pub mod mixed_wav_reader;
 pub mod notifications;
 pub mod recorder;
+pub mod resample;
+pub mod resampler;
 pub mod ring_buffer_recorder;
+pub mod signal_gen;
+pub mod silence_trimmer;
+pub mod simple_cpal_recorder;
+pub mod spectral;
+pub mod spectral_vad;
+pub mod streaming_mixer;
 pub mod streaming_recorder_16khz;
+pub mod synthetic_source;
+pub mod transcode;
 pub mod validation;
 pub mod wav_file_reader;
 pub mod wav_validator;
@@ -13,9 +29,14 @@ pub mod wav_validator;
 #[cfg(test)]
 mod test_metadata;
 
+pub use chunk_sink::ChunkSink;
+pub use config::{AudioBackend, CustomAudioDeviceConfig};
 pub use converter::AudioConverter;
 pub use format::WhisperAudioConverter;
 pub use metadata::AudioMetadata;
+pub use mixed_wav_reader::MixedWavReader;
 pub use recorder::AudioRecorder;
+pub use silence_trimmer::{trim_silence, SilenceTrimmerConfig, TrimStats};
+pub use spectral_vad::{SpectralVad, SpectralVadConfig, SpectralVadEvent};
 pub use wav_file_reader::WavFileReader;
 pub use wav_validator::WavValidator;
@@ -1,7 +1,10 @@
+use super::config::CustomAudioDeviceConfig;
+use super::device_listener::{ActiveDeviceListener, DeviceListenerEvent};
 use super::device_monitor::{CapabilityCheckResult, DeviceCapabilityChecker, DeviceMonitor};
 use super::format::NativeAudioFormat;
 use super::metadata::AudioMetadata;
 use super::notifications::notify_airpods_detected;
+use super::spectral_vad::{SpectralVad, SpectralVadConfig};
 use super::validation::{AudioFormatValidator, CallbackInfo, ValidationResult};
 use crate::logger::{debug, error, info, warn, Component};
 use std::any::TypeId;
@@ -32,6 +35,9 @@ pub struct AudioRecorder {
     sample_callback: Arc<Mutex<Option<SampleCallback>>>,
     // Synchronization for recording state changes
     recording_state_changed: Arc<Condvar>,
+    custom_device_config: Arc<Mutex<Option<CustomAudioDeviceConfig>>>,
+    voice_active: Arc<Mutex<bool>>,
+    vad_config: Arc<Mutex<SpectralVadConfig>>,
 }
 
 #[derive(Debug, Clone)]
@@ -48,6 +54,8 @@ enum RecorderCommand {
     StartAudioLevelMonitoring(Option<String>), // device_name
     StopAudioLevelMonitoring,
     SetSampleCallback(Option<SampleCallback>),
+    SetCustomDeviceConfig(Option<CustomAudioDeviceConfig>),
+    SetVadConfig(SpectralVadConfig),
 }
 
 impl AudioRecorder {
@@ -59,6 +67,9 @@ impl AudioRecorder {
             current_device_info: Arc::new(Mutex::new(None)),
             sample_callback: Arc::new(Mutex::new(None)),
             recording_state_changed: Arc::new(Condvar::new()),
+            custom_device_config: Arc::new(Mutex::new(None)),
+            voice_active: Arc::new(Mutex::new(false)),
+            vad_config: Arc::new(Mutex::new(SpectralVadConfig::default())),
         }
     }
 
@@ -95,6 +106,20 @@ impl AudioRecorder {
             })
     }
 
+    /// Whether the spectral VAD currently classifies the monitored input as
+    /// speech. Only updated while audio level monitoring is active; a
+    /// recording's own stream does not run the VAD. See
+    /// `audio::spectral_vad::SpectralVad`.
+    pub fn get_voice_activity(&self) -> bool {
+        self.voice_active.lock().map(|guard| *guard).unwrap_or_else(|_| {
+            error(
+                Component::Recording,
+                "Failed to acquire voice activity lock - returning false",
+            );
+            false
+        })
+    }
+
     pub fn init(&mut self) {
         // Eagerly probe device capabilities to ensure we have device info available
         self.probe_and_cache_device_info();
@@ -106,6 +131,9 @@ impl AudioRecorder {
         let device_info = self.current_device_info.clone();
         let sample_callback = self.sample_callback.clone();
         let recording_state_changed = self.recording_state_changed.clone();
+        let custom_device_config = self.custom_device_config.clone();
+        let voice_active = self.voice_active.clone();
+        let vad_config = self.vad_config.clone();
 
         thread::spawn(move || {
             let mut recorder = AudioRecorderWorker::new(
@@ -114,9 +142,23 @@ impl AudioRecorder {
                 device_info,
                 sample_callback,
                 recording_state_changed,
+                custom_device_config,
+                voice_active,
+                vad_config,
             );
 
-            while let Ok(cmd) = rx.recv() {
+            loop {
+                let cmd = match rx.recv_timeout(Duration::from_millis(250)) {
+                    Ok(cmd) => cmd,
+                    Err(mpsc::RecvTimeoutError::Timeout) => {
+                        // Idle tick: drain any real-time device-change events
+                        // accumulated since the last command (see
+                        // `poll_device_listener_events`).
+                        recorder.poll_device_listener_events();
+                        continue;
+                    }
+                    Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                };
                 match cmd {
                     RecorderCommand::StartRecording(path, device_name) => {
                         if let Err(e) = recorder.start_recording(&path, device_name.as_deref()) {
@@ -155,6 +197,12 @@ impl AudioRecorder {
                     RecorderCommand::SetSampleCallback(callback) => {
                         *recorder.sample_callback.lock().unwrap() = callback;
                     }
+                    RecorderCommand::SetCustomDeviceConfig(config) => {
+                        *recorder.custom_device_config.lock().unwrap() = config;
+                    }
+                    RecorderCommand::SetVadConfig(config) => {
+                        *recorder.vad_config.lock().unwrap() = config;
+                    }
                 }
             }
         });
@@ -282,6 +330,37 @@ impl AudioRecorder {
             .map_err(|e| format!("Failed to send sample callback command: {}", e))
     }
 
+    /// Set (or clear) the device/backend override consulted the next time
+    /// `start_recording` opens a stream.
+    pub fn set_custom_device_config(
+        &self,
+        config: Option<CustomAudioDeviceConfig>,
+    ) -> Result<(), String> {
+        if self.control_tx.is_none() {
+            return Err("Recorder not initialized".to_string());
+        }
+
+        self.control_tx
+            .as_ref()
+            .unwrap()
+            .send(RecorderCommand::SetCustomDeviceConfig(config))
+            .map_err(|e| format!("Failed to send custom device config command: {}", e))
+    }
+
+    /// Tune the spectral VAD's thresholds, consulted the next time audio
+    /// level monitoring starts.
+    pub fn set_vad_config(&self, config: SpectralVadConfig) -> Result<(), String> {
+        if self.control_tx.is_none() {
+            return Err("Recorder not initialized".to_string());
+        }
+
+        self.control_tx
+            .as_ref()
+            .unwrap()
+            .send(RecorderCommand::SetVadConfig(config))
+            .map_err(|e| format!("Failed to send VAD config command: {}", e))
+    }
+
     pub fn start_audio_level_monitoring(&self, device_name: Option<&str>) -> Result<(), String> {
         if self.control_tx.is_none() {
             return Err("Recorder not initialized".to_string());
@@ -394,6 +473,7 @@ struct AudioRecorderWorker {
     recording_state_changed: Arc<Condvar>,
     current_metadata: Option<AudioMetadata>,
     requested_config: Option<cpal::StreamConfig>,
+    output_path: Option<std::path::PathBuf>,
     // New validation and monitoring components
     format_validator: Option<AudioFormatValidator>,
     capability_checker: Option<DeviceCapabilityChecker>,
@@ -401,6 +481,15 @@ struct AudioRecorderWorker {
     callback_count: u64,
     // Channel for receiving validation data from audio callback
     validation_rx: Option<std::sync::mpsc::Receiver<CallbackValidationData>>,
+    // Real-time CoreAudio (or platform equivalent) listener on the active
+    // input device, and the channel it pushes change events through. Kept
+    // alive for the duration of the recording; dropping it tears down the
+    // OS-level listeners.
+    device_listener: Option<ActiveDeviceListener>,
+    device_listener_rx: Option<std::sync::mpsc::Receiver<DeviceListenerEvent>>,
+    custom_device_config: Arc<Mutex<Option<CustomAudioDeviceConfig>>>,
+    voice_active: Arc<Mutex<bool>>,
+    vad_config: Arc<Mutex<SpectralVadConfig>>,
 }
 
 impl AudioRecorderWorker {
@@ -410,6 +499,9 @@ impl AudioRecorderWorker {
         device_info: Arc<Mutex<Option<DeviceInfo>>>,
         sample_callback: Arc<Mutex<Option<SampleCallback>>>,
         recording_state_changed: Arc<Condvar>,
+        custom_device_config: Arc<Mutex<Option<CustomAudioDeviceConfig>>>,
+        voice_active: Arc<Mutex<bool>>,
+        vad_config: Arc<Mutex<SpectralVadConfig>>,
     ) -> Self {
         Self {
             stream: None,
@@ -426,11 +518,17 @@ impl AudioRecorderWorker {
             recording_state_changed,
             current_metadata: None,
             requested_config: None,
+            output_path: None,
             format_validator: None,
             capability_checker: None,
             last_callback_time: Instant::now(),
             callback_count: 0,
             validation_rx: None,
+            device_listener: None,
+            device_listener_rx: None,
+            custom_device_config,
+            voice_active,
+            vad_config,
         }
     }
 
@@ -441,6 +539,8 @@ impl AudioRecorderWorker {
     ) -> Result<(), String> {
         use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 
+        self.output_path = Some(std::path::PathBuf::from(output_path));
+
         // Stop monitoring if it's running but preserve the audio level
         if self.monitoring_stream.is_some() {
             // Don't reset audio level when transitioning from monitoring to recording
@@ -454,7 +554,17 @@ impl AudioRecorderWorker {
 
         let host = cpal::default_host();
 
-        let device = match device_name {
+        // A device/format override from settings (see `CustomAudioDeviceConfig`)
+        // only kicks in when the caller didn't already pass an explicit
+        // `device_name` - that argument always wins.
+        let custom_device_config = self.custom_device_config.lock().unwrap().clone();
+        let effective_device_name = device_name.map(|s| s.to_string()).or_else(|| {
+            custom_device_config
+                .as_ref()
+                .and_then(|c| c.device_name.clone())
+        });
+
+        let device = match effective_device_name.as_deref() {
             Some(name) => {
                 info(
                     Component::Recording,
@@ -489,6 +599,37 @@ impl AudioRecorderWorker {
                 );
                 selected_device
             }
+            None if custom_device_config
+                .as_ref()
+                .and_then(|c| c.device_index)
+                .is_some() =>
+            {
+                let index = custom_device_config
+                    .as_ref()
+                    .and_then(|c| c.device_index)
+                    .unwrap();
+                info(
+                    Component::Recording,
+                    &format!("Attempting to use device at index {} (custom config)", index),
+                );
+
+                let devices = host
+                    .input_devices()
+                    .map_err(|e| format!("Failed to enumerate devices: {}", e))?;
+                let selected_device = devices
+                    .into_iter()
+                    .nth(index)
+                    .ok_or_else(|| format!("No input device at index {}", index))?;
+
+                let actual_name = selected_device
+                    .name()
+                    .unwrap_or_else(|_| "Unknown".to_string());
+                info(
+                    Component::Recording,
+                    &format!("Selected device: '{}'", actual_name),
+                );
+                selected_device
+            }
             None => {
                 info(Component::Recording, "Using default input device");
 
@@ -692,29 +833,63 @@ impl AudioRecorderWorker {
             "Audio will be converted to 16kHz mono during transcription, not recording",
         );
 
-        // Always use device's native config to avoid sample rate mismatches
+        // A forced sample rate from the custom device config overrides the
+        // device's native rate; otherwise we keep trusting what the device
+        // reports.
+        let forced_sample_rate = custom_device_config
+            .as_ref()
+            .and_then(|c| c.forced_sample_rate);
+        let effective_sample_rate = forced_sample_rate
+            .map(cpal::SampleRate)
+            .unwrap_or_else(|| default_config.sample_rate());
+        if let Some(forced) = forced_sample_rate {
+            info(
+                Component::Recording,
+                &format!(
+                    "Overriding device's native {} Hz with forced sample rate {} Hz",
+                    default_config.sample_rate().0,
+                    forced
+                ),
+            );
+        }
+
+        // Always use device's native config (unless overridden) to avoid
+        // sample rate mismatches
         let mut config = cpal::StreamConfig {
             channels: device_channels, // Use device's native channels
-            sample_rate: default_config.sample_rate(), // Use device's native sample rate
+            sample_rate: effective_sample_rate,
             buffer_size: cpal::BufferSize::Default,
         };
 
-        // Try progressive buffer sizes for lower latency
-        let buffer_sizes = [128, 256, 512, 1024];
-        let mut buffer_size_used = "Default".to_string();
-        for &size in &buffer_sizes {
+        let forced_buffer_size = custom_device_config
+            .as_ref()
+            .and_then(|c| c.forced_buffer_size);
+        let buffer_size_used = if let Some(size) = forced_buffer_size {
             config.buffer_size = cpal::BufferSize::Fixed(size);
-
-            // Test if this buffer size works by trying to create a dummy stream
-            if let Ok(_) = device.supported_input_configs() {
-                buffer_size_used = format!("Fixed({})", size);
-                info(
-                    Component::Recording,
-                    &format!("Using buffer size: {} samples", size),
-                );
-                break;
+            info(
+                Component::Recording,
+                &format!("Using forced buffer size: {} samples", size),
+            );
+            format!("Fixed({})", size)
+        } else {
+            // Try progressive buffer sizes for lower latency
+            let buffer_sizes = [128, 256, 512, 1024];
+            let mut buffer_size_used = "Default".to_string();
+            for &size in &buffer_sizes {
+                config.buffer_size = cpal::BufferSize::Fixed(size);
+
+                // Test if this buffer size works by trying to create a dummy stream
+                if let Ok(_) = device.supported_input_configs() {
+                    buffer_size_used = format!("Fixed({})", size);
+                    info(
+                        Component::Recording,
+                        &format!("Using buffer size: {} samples", size),
+                    );
+                    break;
+                }
             }
-        }
+            buffer_size_used
+        };
 
         if buffer_size_used == "Default" {
             config.buffer_size = cpal::BufferSize::Default;
@@ -738,15 +913,26 @@ impl AudioRecorderWorker {
             ),
         );
 
-        // Store the requested config for metadata tracking
+        // Store the requested config for metadata tracking - this reflects
+        // what we actually asked for (the forced rate/buffer size if the
+        // custom device config set one, otherwise the device's native
+        // defaults), so mismatch detection compares against real intent
+        // rather than always assuming the device default was requested.
         self.requested_config = Some(cpal::StreamConfig {
             channels: default_config.channels(),
-            sample_rate: default_config.sample_rate(),
-            buffer_size: cpal::BufferSize::Default,
+            sample_rate: effective_sample_rate,
+            buffer_size: forced_buffer_size
+                .map(cpal::BufferSize::Fixed)
+                .unwrap_or(cpal::BufferSize::Default),
         });
 
+        let requested_backend = custom_device_config
+            .as_ref()
+            .map(|c| c.preferred_backend)
+            .unwrap_or_default();
+
         // Create comprehensive audio metadata
-        let is_default = device_name.is_none();
+        let is_default = effective_device_name.is_none();
         let mut audio_metadata = AudioMetadata::new(
             device_name_for_metadata.clone(),
             self.requested_config.as_ref(),
@@ -754,6 +940,7 @@ impl AudioRecorderWorker {
             default_config.sample_format(),
             &config.buffer_size,
             is_default,
+            requested_backend,
         );
 
         // Set recording-specific information
@@ -798,6 +985,29 @@ impl AudioRecorderWorker {
             Duration::from_millis(validation_frequency),
         ));
 
+        // Register a real-time listener on the active device so format
+        // changes (e.g. AirPods dropping into call mode mid-recording) are
+        // caught the moment the OS reports them, instead of only at the next
+        // periodic capability check.
+        let (device_listener_tx, device_listener_rx) = std::sync::mpsc::channel();
+        match ActiveDeviceListener::register(&device_name_for_metadata, device_listener_tx) {
+            Ok(listener) => {
+                self.device_listener = Some(listener);
+                self.device_listener_rx = Some(device_listener_rx);
+            }
+            Err(e) => {
+                info(
+                    Component::Recording,
+                    &format!(
+                        "No real-time device-change listener for '{}': {} (falling back to periodic checks only)",
+                        device_name_for_metadata, e
+                    ),
+                );
+                self.device_listener = None;
+                self.device_listener_rx = None;
+            }
+        }
+
         info(
             Component::Recording,
             &format!(
@@ -996,6 +1206,13 @@ impl AudioRecorderWorker {
             drop(stream);
         }
 
+        // Tear down the real-time device listener so no dangling OS callback
+        // survives past this recording.
+        if let Some(listener) = self.device_listener.take() {
+            listener.deregister();
+        }
+        self.device_listener_rx = None;
+
         // Check if we need to pad with silence
         let total_samples = *self.sample_count.lock().unwrap();
         let samples_per_second = self.sample_rate as f32 * self.channels as f32; // Account for all channels
@@ -1055,9 +1272,67 @@ impl AudioRecorderWorker {
                 .map_err(|e| format!("Failed to finalize recording: {}", e))?;
         }
 
+        // Fix AirPods-style call-mode pitch distortion: if the device quirks
+        // (or a detected/reported sample rate mismatch) indicate the audio
+        // was captured at the wrong rate, resample it to the canonical
+        // Whisper rate before any transcriber reads the file.
+        if let (Some(ref path), Some(ref mut metadata)) =
+            (&self.output_path, &mut self.current_metadata)
+        {
+            if let Err(e) = crate::audio::resample::correct_wav_file_in_place(path, metadata) {
+                error(
+                    Component::Recording,
+                    &format!("Failed to correct call-mode audio for {:?}: {}", path, e),
+                );
+            }
+        }
+
         Ok(())
     }
 
+    /// Drain any events pushed by the real-time `ActiveDeviceListener` since
+    /// the last tick and fold them into `current_metadata`, so
+    /// `stability_score`/`get_validation_frequency_ms()` react immediately
+    /// instead of waiting for the next periodic capability check.
+    fn poll_device_listener_events(&mut self) {
+        let Some(ref rx) = self.device_listener_rx else {
+            return;
+        };
+
+        while let Ok(event) = rx.try_recv() {
+            match event {
+                DeviceListenerEvent::SampleRateChanged(rate) => {
+                    warn(
+                        Component::Recording,
+                        &format!("Active device's nominal sample rate changed to {} Hz mid-recording", rate),
+                    );
+                    if let Some(ref mut metadata) = self.current_metadata {
+                        metadata.set_detected_sample_rate(rate, 1.0);
+                        metadata.update_monitoring(true, true);
+                    }
+                }
+                DeviceListenerEvent::StreamConfigChanged => {
+                    warn(
+                        Component::Recording,
+                        "Active device's stream configuration changed mid-recording",
+                    );
+                    if let Some(ref mut metadata) = self.current_metadata {
+                        metadata.update_monitoring(true, true);
+                    }
+                }
+                DeviceListenerEvent::DeviceDisconnected => {
+                    error(
+                        Component::Recording,
+                        "Active device reported disconnection mid-recording",
+                    );
+                    if let Some(ref mut metadata) = self.current_metadata {
+                        metadata.update_monitoring(true, true);
+                    }
+                }
+            }
+        }
+    }
+
     /// Perform periodic capability checking during recording
     fn check_device_capabilities(&mut self) -> Result<(), String> {
         if let Some(ref mut checker) = self.capability_checker {
@@ -1112,10 +1387,15 @@ impl AudioRecorderWorker {
             match validator.process_callback(samples, &callback_info) {
                 ValidationResult::Ok => {
                     // All good, update statistics
+                    let pattern_analysis = validator.generate_pattern_analysis();
+                    let detected_rate = validator.last_detected_sample_rate();
                     if let Some(ref mut metadata) = self.current_metadata {
-                        if let Some(pattern_analysis) = validator.generate_pattern_analysis() {
+                        if let Some(pattern_analysis) = pattern_analysis {
                             metadata.update_validation(1, 0, Some(pattern_analysis));
                         }
+                        if let Some((rate, confidence)) = detected_rate {
+                            metadata.set_detected_sample_rate(rate, confidence);
+                        }
                     }
                 }
                 ValidationResult::IssuesDetected(issues, severity) => {
@@ -1157,12 +1437,13 @@ impl AudioRecorderWorker {
                         }
                     }
 
+                    let pattern_analysis = validator.generate_pattern_analysis();
+                    let detected_rate = validator.last_detected_sample_rate();
                     if let Some(ref mut metadata) = self.current_metadata {
-                        metadata.update_validation(
-                            1,
-                            issues.len() as u32,
-                            validator.generate_pattern_analysis(),
-                        );
+                        metadata.update_validation(1, issues.len() as u32, pattern_analysis);
+                        if let Some((rate, confidence)) = detected_rate {
+                            metadata.set_detected_sample_rate(rate, confidence);
+                        }
                     }
                 }
                 ValidationResult::InsufficientData => {
@@ -1358,6 +1639,9 @@ impl AudioRecorderWorker {
             .map_err(|e| format!("Failed to get input config: {}", e))?;
 
         let audio_level = self.current_audio_level.clone();
+        let voice_active = self.voice_active.clone();
+        let vad_config = self.vad_config.lock().unwrap().clone();
+        let vad = SpectralVad::new(config.sample_rate().0, vad_config);
 
         let err_fn = |err| {
             error(
@@ -1368,12 +1652,22 @@ impl AudioRecorderWorker {
 
         // Build monitoring stream based on sample format
         let monitoring_stream = match config.sample_format() {
-            cpal::SampleFormat::F32 => {
-                self.build_monitoring_stream::<f32>(&device, &config.into(), audio_level, err_fn)
-            }
-            cpal::SampleFormat::I16 => {
-                self.build_monitoring_stream::<i16>(&device, &config.into(), audio_level, err_fn)
-            }
+            cpal::SampleFormat::F32 => self.build_monitoring_stream::<f32>(
+                &device,
+                &config.into(),
+                audio_level,
+                voice_active,
+                vad,
+                err_fn,
+            ),
+            cpal::SampleFormat::I16 => self.build_monitoring_stream::<i16>(
+                &device,
+                &config.into(),
+                audio_level,
+                voice_active,
+                vad,
+                err_fn,
+            ),
             _ => return Err("Unsupported sample format".to_string()),
         }?;
 
@@ -1393,6 +1687,7 @@ impl AudioRecorderWorker {
 
         // Reset audio level to 0
         *self.current_audio_level.lock().unwrap() = 0.0;
+        *self.voice_active.lock().unwrap() = false;
 
         Ok(())
     }
@@ -1402,6 +1697,8 @@ impl AudioRecorderWorker {
         device: &cpal::Device,
         config: &cpal::StreamConfig,
         audio_level: Arc<Mutex<f32>>,
+        voice_active: Arc<Mutex<bool>>,
+        mut vad: SpectralVad,
         err_fn: impl FnMut(cpal::StreamError) + Send + 'static + Copy,
     ) -> Result<cpal::Stream, String>
     where
@@ -1431,6 +1728,12 @@ impl AudioRecorderWorker {
                     let current_level = *audio_level.lock().unwrap();
                     let new_level = current_level * 0.7 + amplified_rms * 0.3;
                     *audio_level.lock().unwrap() = new_level;
+
+                    // Spectral VAD: compute speech/silence against the
+                    // voice-band energy ratio, independent of the RMS level.
+                    let samples_f32: Vec<f32> = data.iter().map(|&s| s.into()).collect();
+                    vad.process(&samples_f32);
+                    *voice_active.lock().unwrap() = vad.in_speech();
                 },
                 err_fn,
                 None,
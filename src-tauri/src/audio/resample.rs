@@ -0,0 +1,222 @@
+use crate::audio::metadata::{AudioMetadata, DeviceQuirks, FormatMetadata};
+use crate::logger::{info, Component};
+use std::f64::consts::PI;
+use std::path::Path;
+
+/// Canonical sample rate Whisper expects its input audio at.
+pub const WHISPER_SAMPLE_RATE: u32 = 16000;
+
+/// Relative sample-rate mismatch (`|detected - reported| / reported`) above
+/// which we treat the recording as needing correction even without an
+/// explicit `call_mode` quirk.
+const MISMATCH_THRESHOLD: f32 = 0.1;
+
+/// Number of taps on either side of the fractional source position used by
+/// the windowed-sinc resampler.
+const SINC_HALF_TAPS: isize = 16;
+
+/// Resample `samples` from `src_rate` to `dst_rate` using linear
+/// interpolation: for target index `n`, the source position is
+/// `n * src_rate / dst_rate`; take the two neighboring source samples and
+/// weight them by the fractional part. Cheap, but can alias when
+/// downsampling by a large ratio.
+pub fn resample_linear(samples: &[f32], src_rate: u32, dst_rate: u32) -> Vec<f32> {
+    if samples.is_empty() || src_rate == dst_rate {
+        return samples.to_vec();
+    }
+
+    let ratio = src_rate as f64 / dst_rate as f64;
+    let out_len = (samples.len() as f64 / ratio).round() as usize;
+    let mut out = Vec::with_capacity(out_len);
+
+    for n in 0..out_len {
+        let src_pos = n as f64 * ratio;
+        let idx = src_pos.floor() as usize;
+        let frac = (src_pos - idx as f64) as f32;
+
+        let a = samples.get(idx).copied().unwrap_or(0.0);
+        let b = samples.get(idx + 1).copied().unwrap_or(a);
+        out.push(a + (b - a) * frac);
+    }
+
+    out
+}
+
+/// Resample `samples` from `src_rate` to `dst_rate` by convolving a
+/// Hann-windowed sinc kernel of `±SINC_HALF_TAPS` taps centered on each
+/// target's fractional source position. The sinc's low-pass cutoff is set to
+/// `min(src_rate, dst_rate) / 2` so downsampling band-limits the signal
+/// first instead of aliasing.
+pub fn resample_sinc(samples: &[f32], src_rate: u32, dst_rate: u32) -> Vec<f32> {
+    if samples.is_empty() || src_rate == dst_rate {
+        return samples.to_vec();
+    }
+
+    let ratio = src_rate as f64 / dst_rate as f64;
+    let out_len = (samples.len() as f64 / ratio).round() as usize;
+
+    // Cutoff relative to the source rate: 1.0 when upsampling (no
+    // attenuation needed), dst/src when downsampling (band-limit to the
+    // target Nyquist so energy above it doesn't fold back down as aliasing).
+    let cutoff = (dst_rate as f64 / src_rate as f64).min(1.0);
+
+    let mut out = Vec::with_capacity(out_len);
+
+    for n in 0..out_len {
+        let src_pos = n as f64 * ratio;
+        let center = src_pos.floor() as isize;
+        let frac = src_pos - center as f64;
+
+        let mut acc = 0.0f64;
+        let mut weight_sum = 0.0f64;
+
+        for tap in -SINC_HALF_TAPS..=SINC_HALF_TAPS {
+            let sample_idx = center + tap;
+            if sample_idx < 0 || sample_idx as usize >= samples.len() {
+                continue;
+            }
+
+            let x = tap as f64 - frac;
+            let sinc = if x.abs() < 1e-9 {
+                cutoff
+            } else {
+                cutoff * (PI * cutoff * x).sin() / (PI * cutoff * x)
+            };
+
+            // Hann window over the tap span, tapering the kernel to zero at
+            // its edges instead of cutting it off abruptly.
+            let window = 0.5 * (1.0 + (PI * tap as f64 / (SINC_HALF_TAPS as f64 + 1.0)).cos());
+
+            let weight = sinc * window;
+            acc += samples[sample_idx as usize] as f64 * weight;
+            weight_sum += weight;
+        }
+
+        // Normalize so a flat DC signal passes through at unity gain.
+        out.push(if weight_sum.abs() > 1e-9 {
+            (acc / weight_sum) as f32
+        } else {
+            0.0
+        });
+    }
+
+    out
+}
+
+/// Whether `quirks`/`format` indicate audio that needs resampling before it
+/// reaches Whisper: either the device is flagged as being in call mode, or
+/// the analysis-detected sample rate disagrees with the reported one by more
+/// than `MISMATCH_THRESHOLD`.
+pub fn needs_correction(quirks: &DeviceQuirks, format: &FormatMetadata) -> bool {
+    if quirks.mode.as_deref() == Some("call_mode") {
+        return true;
+    }
+
+    if let Some(detected) = format.detected_sample_rate {
+        let reported = format.sample_rate as f32;
+        if reported > 0.0 && (detected as f32 - reported).abs() / reported > MISMATCH_THRESHOLD {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Resample `samples` from the device's actual capture rate (preferring the
+/// analysis-detected rate when present, since that's the rate audio was
+/// actually captured at) to the canonical Whisper input rate, and record
+/// what was done in `metadata`.
+pub fn correct_call_mode_audio(samples: &[f32], metadata: &mut AudioMetadata) -> Vec<f32> {
+    let src_rate = metadata
+        .format
+        .detected_sample_rate
+        .unwrap_or(metadata.format.sample_rate);
+
+    let corrected = resample_sinc(samples, src_rate, WHISPER_SAMPLE_RATE);
+
+    metadata.recording.processing_applied.push(format!(
+        "call_mode_resample:{}hz->{}hz",
+        src_rate, WHISPER_SAMPLE_RATE
+    ));
+
+    for mismatch in &mut metadata.mismatches {
+        if mismatch.mismatch_type == "sample_rate" {
+            mismatch.impact = format!(
+                "Corrected: resampled from {} Hz to {} Hz before transcription",
+                src_rate, WHISPER_SAMPLE_RATE
+            );
+        }
+    }
+
+    corrected
+}
+
+/// Re-read a just-recorded WAV file and, if `metadata` indicates it was
+/// captured in call mode (or otherwise at the wrong rate), rewrite it
+/// mono/16 kHz so the pitch distortion is fixed before any transcriber reads
+/// it. A no-op when `needs_correction` is false.
+pub fn correct_wav_file_in_place(wav_path: &Path, metadata: &mut AudioMetadata) -> Result<(), String> {
+    if !needs_correction(&metadata.device.quirks, &metadata.format) {
+        return Ok(());
+    }
+
+    let mut reader =
+        hound::WavReader::open(wav_path).map_err(|e| format!("Failed to open WAV file: {}", e))?;
+    let spec = reader.spec();
+
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader.samples::<f32>().map(|s| s.unwrap_or(0.0)).collect(),
+        hound::SampleFormat::Int => {
+            let max_value = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.unwrap_or(0) as f32 / max_value)
+                .collect()
+        }
+    };
+
+    // Mix down to mono before resampling; Whisper only ever wants one channel.
+    let mono_samples: Vec<f32> = if spec.channels <= 1 {
+        samples
+    } else {
+        samples
+            .chunks(spec.channels as usize)
+            .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+            .collect()
+    };
+
+    let corrected = correct_call_mode_audio(&mono_samples, metadata);
+
+    let output_spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: WHISPER_SAMPLE_RATE,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+
+    let mut writer = hound::WavWriter::create(wav_path, output_spec)
+        .map_err(|e| format!("Failed to rewrite corrected WAV file: {}", e))?;
+    for sample in &corrected {
+        writer
+            .write_sample(*sample)
+            .map_err(|e| format!("Failed to write corrected sample: {}", e))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| format!("Failed to finalize corrected WAV file: {}", e))?;
+
+    info(
+        Component::Recording,
+        &format!(
+            "Corrected call-mode pitch distortion for {:?}: {} Hz -> {} Hz",
+            wav_path,
+            metadata
+                .format
+                .detected_sample_rate
+                .unwrap_or(metadata.format.sample_rate),
+            WHISPER_SAMPLE_RATE
+        ),
+    );
+
+    Ok(())
+}
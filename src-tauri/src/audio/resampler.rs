@@ -1,136 +1,243 @@
-use std::f32;
-
-/// Simple audio resampler for downsampling from 48kHz to 16kHz
-/// Uses a simple decimation approach with basic low-pass filtering
-pub struct Resampler {
-    from_rate: u32,
-    to_rate: u32,
-    ratio: usize,
+//! Streaming polyphase resampler for converting device-rate audio (44100,
+//! 48000, ...) to whatever rate a downstream consumer wants (typically
+//! `resample::WHISPER_SAMPLE_RATE`), one chunk at a time, without clicks at
+//! chunk boundaries.
+//!
+//! Unlike [`crate::audio::resample::resample_sinc`], which resamples one
+//! complete buffer in isolation, [`StreamingResampler`] keeps a trailing
+//! window of input samples across calls so a caller can hand it the 100ms
+//! chunks a CPAL callback or `SimpleAudioRecorder` produces and get back a
+//! continuous output stream.
+
+use std::f64::consts::PI;
+
+/// Taps stored per polyphase branch. The full conceptual FIR is
+/// `taps_per_phase * interpolation_factor` long; this is the "k" dimension
+/// of the phase table each output sample sums over.
+const TAPS_PER_PHASE: usize = 32;
+
+/// Resamples a continuous stream of interleaved-free (mono or per-channel)
+/// `f32` samples from `src_rate` to `dst_rate` using a polyphase
+/// windowed-sinc filter, maintaining state across calls.
+///
+/// Internally reduces `src_rate/dst_rate` to `m/l` via their GCD, so output
+/// sample `n` corresponds to the upsampled-by-`l` position `n * m`; the
+/// filter cutoff is set to `min(1/l, 1/m)` of the upsampled Nyquist so
+/// neither upsampling imaging nor downsampling aliasing survives.
+pub struct StreamingResampler {
+    /// Interpolation factor (conceptual upsample-by-`l`).
+    l: u32,
+    /// Decimation factor (conceptual downsample-by-`m`).
+    m: u32,
+    /// `phase_filters[p][k]` is tap `k` of the filter branch used when the
+    /// upsampled position's phase (`position % l`) is `p`.
+    phase_filters: Vec<[f32; TAPS_PER_PHASE]>,
+    /// Trailing window of the last `TAPS_PER_PHASE` input samples seen, so
+    /// the first few outputs of a new call can still reach back into the
+    /// previous call's tail instead of seeing zeros.
+    history: Vec<f32>,
+    /// Total input samples consumed across all calls so far (not counting
+    /// `history`, which duplicates the tail of what's already consumed).
+    input_samples_consumed: u64,
+    /// Upsampled-domain position of the next output sample.
+    next_up_position: u64,
 }
 
-impl Resampler {
-    /// Create a new resampler
-    pub fn new(from_rate: u32, to_rate: u32) -> Result<Self, String> {
-        // For now, we only support 48kHz to 16kHz (3:1 ratio)
-        if from_rate != 48000 || to_rate != 16000 {
-            return Err(format!(
-                "Currently only 48kHz to 16kHz resampling is supported (got {}Hz to {}Hz)",
-                from_rate, to_rate
-            ));
+impl StreamingResampler {
+    /// Builds a resampler from `src_rate` to `dst_rate`. A no-op identity
+    /// resampler (`l == m == 1`) is fine and just passes samples through.
+    pub fn new(src_rate: u32, dst_rate: u32) -> Self {
+        let divisor = gcd(src_rate, dst_rate).max(1);
+        let l = dst_rate / divisor;
+        let m = src_rate / divisor;
+
+        Self {
+            l,
+            m,
+            phase_filters: build_phase_filters(l, m),
+            history: vec![0.0; TAPS_PER_PHASE],
+            input_samples_consumed: 0,
+            next_up_position: 0,
         }
-        
-        Ok(Self {
-            from_rate,
-            to_rate,
-            ratio: 3, // 48000 / 16000 = 3
-        })
     }
-    
-    /// Downsample audio data from 48kHz to 16kHz
-    /// Uses simple decimation with averaging for anti-aliasing
-    pub fn resample_f32(&self, input: &[f32], channels: u16) -> Vec<f32> {
-        let channels = channels as usize;
-        let samples_per_channel = input.len() / channels;
-        let output_samples_per_channel = samples_per_channel / self.ratio;
-        let mut output = Vec::with_capacity(output_samples_per_channel * channels);
-        
-        // Process each channel separately
-        for channel in 0..channels {
-            for i in 0..output_samples_per_channel {
-                let start_idx = i * self.ratio;
-                let end_idx = ((i + 1) * self.ratio).min(samples_per_channel);
-                
-                // Average the samples in this window (simple low-pass filter)
-                let mut sum = 0.0;
-                let mut count = 0;
-                for j in start_idx..end_idx {
-                    let sample_idx = j * channels + channel;
-                    if sample_idx < input.len() {
-                        sum += input[sample_idx];
-                        count += 1;
-                    }
-                }
-                
-                if count > 0 {
-                    output.push(sum / count as f32);
-                }
-            }
+
+    /// Resamples `input`, returning the newly produced output samples.
+    pub fn resample(&mut self, input: &[f32]) -> Vec<f32> {
+        let mut out = Vec::new();
+        self.resample_into(input, &mut out);
+        out
+    }
+
+    /// As [`Self::resample`], but appends into an existing buffer instead
+    /// of allocating a new one - useful when a caller wants to reuse one
+    /// `Vec` across many chunks.
+    pub fn resample_into(&mut self, input: &[f32], out: &mut Vec<f32>) {
+        if input.is_empty() {
+            return;
         }
-        
-        // Reinterleave channels if needed
-        if channels > 1 {
-            let mut interleaved = Vec::with_capacity(output.len());
-            for i in 0..output_samples_per_channel {
-                for channel in 0..channels {
-                    let idx = channel * output_samples_per_channel + i;
-                    if idx < output.len() {
-                        interleaved.push(output[idx]);
-                    }
+
+        // `combined[0..TAPS_PER_PHASE]` is the trailing window from before
+        // this call; `combined[TAPS_PER_PHASE..]` is the new input. Indexing
+        // into `combined` lets every output sample's tap window (which may
+        // reach back past the start of `input`) stay in bounds uniformly.
+        let mut combined = Vec::with_capacity(TAPS_PER_PHASE + input.len());
+        combined.extend_from_slice(&self.history);
+        combined.extend_from_slice(input);
+
+        loop {
+            let up_position = self.next_up_position;
+            let input_index_global = up_position / self.l as u64;
+            let phase = (up_position % self.l as u64) as usize;
+
+            let combined_index = input_index_global as i64 - self.input_samples_consumed as i64 + TAPS_PER_PHASE as i64;
+            if combined_index < 0 || combined_index as usize >= combined.len() {
+                // Not enough input buffered yet for this output; wait for
+                // the next `resample_into` call to supply it.
+                break;
+            }
+            let combined_index = combined_index as usize;
+            let taps = &self.phase_filters[phase];
+            let mut acc = 0.0f32;
+            for (k, &coeff) in taps.iter().enumerate() {
+                let tap_index = combined_index as i64 - k as i64;
+                if tap_index >= 0 {
+                    acc += coeff * combined[tap_index as usize];
                 }
             }
-            interleaved
-        } else {
-            output
+
+            out.push(acc);
+            self.next_up_position += self.m as u64;
         }
+
+        self.input_samples_consumed += input.len() as u64;
+
+        // Keep only the trailing `TAPS_PER_PHASE` samples as history for
+        // the next call.
+        let tail_start = combined.len() - TAPS_PER_PHASE;
+        self.history.copy_from_slice(&combined[tail_start..]);
     }
-    
-    /// Downsample i16 audio data from 48kHz to 16kHz
-    pub fn resample_i16(&self, input: &[i16], channels: u16) -> Vec<i16> {
-        // Convert to f32, resample, then convert back
-        let f32_input: Vec<f32> = input.iter()
-            .map(|&s| s as f32 / 32768.0)
-            .collect();
-        
-        let f32_output = self.resample_f32(&f32_input, channels);
-        
-        // Convert back to i16
-        f32_output.iter()
-            .map(|&s| {
-                let scaled = s * 32768.0;
-                if scaled > 32767.0 {
-                    32767
-                } else if scaled < -32768.0 {
-                    -32768
-                } else {
-                    scaled as i16
-                }
-            })
-            .collect()
+}
+
+fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
+/// Builds the `l`-branch polyphase filter table for an `l`-interpolate /
+/// `m`-decimate resampler: a single windowed-sinc lowpass of length
+/// `TAPS_PER_PHASE * l`, cutoff at `min(1/l, 1/m)` of the upsampled Nyquist,
+/// sliced into `l` branches of `TAPS_PER_PHASE` taps each (branch `p` takes
+/// every `l`-th coefficient starting at offset `p`), and scaled so the
+/// interpolation stage's zero-stuffing loss is compensated.
+fn build_phase_filters(l: u32, m: u32) -> Vec<[f32; TAPS_PER_PHASE]> {
+    let l = l.max(1);
+    let total_taps = TAPS_PER_PHASE * l as usize;
+    let cutoff = (1.0 / l as f64).min(1.0 / m as f64);
+    let center = (total_taps - 1) as f64 / 2.0;
+
+    let mut kernel = vec![0.0f64; total_taps];
+    for (n, value) in kernel.iter_mut().enumerate() {
+        let x = n as f64 - center;
+        let sinc = if x.abs() < 1e-9 {
+            cutoff
+        } else {
+            cutoff * (PI * cutoff * x).sin() / (PI * cutoff * x)
+        };
+        // Blackman window, tapering the kernel to zero at both edges.
+        let window = 0.42 - 0.5 * (2.0 * PI * n as f64 / (total_taps - 1) as f64).cos()
+            + 0.08 * (4.0 * PI * n as f64 / (total_taps - 1) as f64).cos();
+        *value = sinc * window;
+    }
+
+    // Normalize so the filter has unity DC gain after decimation (the
+    // zero-stuffed interpolation stage needs gain `l` to compensate for the
+    // zeros it inserted).
+    let sum: f64 = kernel.iter().sum();
+    if sum.abs() > 1e-12 {
+        let scale = l as f64 / sum;
+        for value in kernel.iter_mut() {
+            *value *= scale;
+        }
     }
+
+    (0..l)
+        .map(|p| {
+            let mut branch = [0.0f32; TAPS_PER_PHASE];
+            for (k, tap) in branch.iter_mut().enumerate() {
+                let n = p as usize + k * l as usize;
+                *tap = kernel.get(n).copied().unwrap_or(0.0) as f32;
+            }
+            branch
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    
-    #[test]
-    fn test_resampler_creation() {
-        let resampler = Resampler::new(48000, 16000).unwrap();
-        assert_eq!(resampler.ratio, 3);
-    }
-    
-    #[test]
-    fn test_resample_f32_mono() {
-        let resampler = Resampler::new(48000, 16000).unwrap();
-        let input = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
-        let output = resampler.resample_f32(&input, 1);
-        
-        // Should average every 3 samples: [2.0, 5.0, 8.0]
-        assert_eq!(output.len(), 3);
-        assert_eq!(output[0], 2.0); // (1+2+3)/3
-        assert_eq!(output[1], 5.0); // (4+5+6)/3
-        assert_eq!(output[2], 8.0); // (7+8+9)/3
+
+    /// Counts rising zero crossings in `samples`, for estimating a
+    /// roughly-sinusoidal signal's frequency without a full DFT.
+    fn rising_zero_crossings(samples: &[f32]) -> usize {
+        samples
+            .windows(2)
+            .filter(|pair| pair[0] <= 0.0 && pair[1] > 0.0)
+            .count()
     }
-    
+
     #[test]
-    fn test_resample_i16_mono() {
-        let resampler = Resampler::new(48000, 16000).unwrap();
-        let input = vec![3000, 6000, 9000, 12000, 15000, 18000];
-        let output = resampler.resample_i16(&input, 1);
-        
-        assert_eq!(output.len(), 2);
-        // Values will be slightly different due to f32 conversion
-        assert!((output[0] - 6000).abs() < 10);
-        assert!((output[1] - 15000).abs() < 10);
+    fn resamples_known_tone_to_expected_length_and_frequency() {
+        let src_rate = 48000;
+        let dst_rate = 16000;
+        let freq = 1000.0;
+        let duration_secs = 0.5;
+        let num_samples = (src_rate as f64 * duration_secs) as usize;
+
+        let input: Vec<f32> = (0..num_samples)
+            .map(|i| (2.0 * PI * freq * i as f64 / src_rate as f64).sin() as f32)
+            .collect();
+
+        // Feed the tone in small chunks, the way a CPAL callback would, so
+        // this also exercises the cross-call history/state path rather than
+        // a single `resample_into` call over the whole signal.
+        let mut resampler = StreamingResampler::new(src_rate, dst_rate);
+        let mut output = Vec::new();
+        for chunk in input.chunks(100) {
+            resampler.resample_into(chunk, &mut output);
+        }
+
+        let expected_len = (num_samples as f64 * dst_rate as f64 / src_rate as f64) as usize;
+        assert!(
+            (output.len() as i64 - expected_len as i64).unsigned_abs() < 50,
+            "output length {} far from expected {}",
+            output.len(),
+            expected_len
+        );
+
+        // Drop the filter's startup transient before checking the signal.
+        let settled = &output[200..output.len() - 200];
+
+        let crossings = rising_zero_crossings(settled);
+        let settled_secs = settled.len() as f64 / dst_rate as f64;
+        let estimated_freq = crossings as f64 / settled_secs;
+        assert!(
+            (estimated_freq - freq).abs() < 50.0,
+            "estimated frequency {} far from expected {}",
+            estimated_freq,
+            freq
+        );
+
+        // No discontinuities across the internal chunk boundaries: a clean
+        // tone shouldn't jump more than a small fraction of full scale
+        // between adjacent output samples.
+        let max_delta = settled
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).abs())
+            .fold(0.0f32, f32::max);
+        assert!(max_delta < 0.5, "discontinuity detected, max delta {}", max_delta);
     }
-}
\ No newline at end of file
+}
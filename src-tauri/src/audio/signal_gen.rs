@@ -0,0 +1,177 @@
+//! Deterministic synthetic PCM generators for benchmarks and integration
+//! tests that would otherwise feed the recorder nothing but silence
+//! (`vec![0.0f32; n]`), which never exercises VAD, transcription accuracy,
+//! or codec behavior.
+//!
+//! Every [`TestSignal`] is chunk-stateful: it tracks a running sample
+//! index (and, for noise, its RNG state) across calls to [`TestSignal::fill`],
+//! so generating a waveform in 100ms chunks (as `SimpleAudioRecorder` and the
+//! CPAL callbacks do) produces the exact same continuous waveform as one big
+//! call would.
+
+use std::f32::consts::PI;
+
+/// The waveform a [`TestSignal`] synthesizes.
+#[derive(Debug, Clone, Copy)]
+pub enum SignalKind {
+    /// `amplitude * sin(2*pi*frequency_hz*t)`.
+    Sine { frequency_hz: f32, amplitude: f32 },
+    /// Frequency sweeps linearly from `start_hz` to `end_hz` over
+    /// `duration_secs`, then holds at `end_hz`.
+    LinearChirp {
+        start_hz: f32,
+        end_hz: f32,
+        duration_secs: f32,
+        amplitude: f32,
+    },
+    /// Frequency sweeps exponentially (equal musical intervals per unit
+    /// time) from `start_hz` to `end_hz` over `duration_secs`, then holds
+    /// at `end_hz`.
+    LogChirp {
+        start_hz: f32,
+        end_hz: f32,
+        duration_secs: f32,
+        amplitude: f32,
+    },
+    /// Uniform white noise in `[-amplitude, amplitude]`.
+    WhiteNoise { amplitude: f32 },
+    /// Pink (1/f) noise in `[-amplitude, amplitude]`, generated with the
+    /// Voss-McCartney algorithm.
+    PinkNoise { amplitude: f32 },
+    /// Silence punctuated by short bursts of white noise, for discontinuity
+    /// testing: `burst_len_samples` samples of noise at `burst_amplitude`
+    /// every `period_samples`, silence otherwise.
+    SilenceWithBursts {
+        burst_amplitude: f32,
+        burst_len_samples: usize,
+        period_samples: usize,
+    },
+    /// A periodic train of single-sample unit impulses at `fundamental_hz`,
+    /// `amplitude` tall. Unlike [`SignalKind::Sine`] its spectrum has energy
+    /// at the fundamental *and* every harmonic, which makes it a sharper
+    /// probe for "did resampling preserve the fundamental and avoid
+    /// introducing discontinuities" than a single pure tone.
+    ImpulseTrain { fundamental_hz: f32, amplitude: f32 },
+}
+
+/// Chunk-stateful deterministic PCM generator. Construct with
+/// [`TestSignal::new`] and call [`Self::fill`] repeatedly — each call
+/// continues exactly where the previous one left off, so it can drive
+/// `SimpleAudioRecorder::write_samples` (or any other sample-at-a-time
+/// consumer) one chunk at a time.
+pub struct TestSignal {
+    kind: SignalKind,
+    sample_rate: u32,
+    sample_index: u64,
+    /// splitmix64 state for the noise variants; unused by the periodic ones.
+    rng_state: u64,
+    /// Running octave-row values for Voss-McCartney pink noise.
+    pink_rows: [f32; 16],
+    pink_counter: u64,
+}
+
+impl TestSignal {
+    /// Creates a generator for `kind` at `sample_rate`, seeded with `seed`
+    /// for the noise variants (ignored by the periodic ones). The same
+    /// `(kind, sample_rate, seed)` always produces the same waveform.
+    pub fn new(kind: SignalKind, sample_rate: u32, seed: u64) -> Self {
+        Self {
+            kind,
+            sample_rate,
+            sample_index: 0,
+            rng_state: seed ^ 0x9E3779B97F4A7C15,
+            pink_rows: [0.0; 16],
+            pink_counter: 0,
+        }
+    }
+
+    /// Writes `out.len()` samples, continuing the phase/index/RNG state
+    /// left by the previous call.
+    pub fn fill(&mut self, out: &mut [f32]) {
+        for sample in out.iter_mut() {
+            *sample = self.next_sample();
+            self.sample_index += 1;
+        }
+    }
+
+    /// Convenience wrapper around [`Self::fill`] for callers that want an
+    /// owned buffer (e.g. one-shot benchmark/test setup) rather than
+    /// writing into an existing one.
+    pub fn generate(&mut self, count: usize) -> Vec<f32> {
+        let mut out = vec![0.0f32; count];
+        self.fill(&mut out);
+        out
+    }
+
+    fn next_sample(&mut self) -> f32 {
+        let t = self.sample_index as f32 / self.sample_rate as f32;
+        match self.kind {
+            SignalKind::Sine { frequency_hz, amplitude } => amplitude * (2.0 * PI * frequency_hz * t).sin(),
+            SignalKind::LinearChirp { start_hz, end_hz, duration_secs, amplitude } => {
+                let t_sweep = t.min(duration_secs);
+                // Phase is the integral of the instantaneous frequency
+                // f(t) = start_hz + (end_hz - start_hz) * t / duration_secs,
+                // plus end_hz for any time past duration_secs.
+                let phase = start_hz * t_sweep
+                    + (end_hz - start_hz) / (2.0 * duration_secs) * t_sweep * t_sweep
+                    + end_hz * (t - t_sweep);
+                amplitude * (2.0 * PI * phase).sin()
+            }
+            SignalKind::LogChirp { start_hz, end_hz, duration_secs, amplitude } => {
+                let t_sweep = t.min(duration_secs);
+                let ratio = end_hz / start_hz;
+                let sweep_phase = if (ratio - 1.0).abs() < 1e-6 {
+                    start_hz * t_sweep
+                } else {
+                    // Phase is the integral of f(t) = start_hz * ratio^(t / duration_secs).
+                    start_hz * duration_secs / ratio.ln() * (ratio.powf(t_sweep / duration_secs) - 1.0)
+                };
+                // Past duration_secs the frequency stays at end_hz.
+                let phase = sweep_phase + end_hz * (t - t_sweep);
+                amplitude * (2.0 * PI * phase).sin()
+            }
+            SignalKind::WhiteNoise { amplitude } => amplitude * self.next_white_unit(),
+            SignalKind::PinkNoise { amplitude } => amplitude * self.next_pink_unit(),
+            SignalKind::SilenceWithBursts { burst_amplitude, burst_len_samples, period_samples } => {
+                if period_samples > 0 && (self.sample_index % period_samples as u64) < burst_len_samples as u64 {
+                    burst_amplitude * self.next_white_unit()
+                } else {
+                    0.0
+                }
+            }
+            SignalKind::ImpulseTrain { fundamental_hz, amplitude } => {
+                let period_samples = (self.sample_rate as f32 / fundamental_hz.max(0.001)).round() as u64;
+                if period_samples > 0 && self.sample_index % period_samples == 0 {
+                    amplitude
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+
+    /// splitmix64, returning a uniform value in `[-1.0, 1.0]`.
+    fn next_white_unit(&mut self) -> f32 {
+        self.rng_state = self.rng_state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.rng_state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^= z >> 31;
+        let unit = (z >> 40) as f32 / (1u64 << 24) as f32;
+        unit * 2.0 - 1.0
+    }
+
+    /// Voss-McCartney pink noise: on each sample, update the octave row
+    /// selected by the number of trailing zero bits in an incrementing
+    /// counter (so exactly one row changes per sample: row 0 on every other
+    /// sample, row 1 on every fourth, row 2 on every eighth, ...), then sum
+    /// and normalize all rows. This
+    /// approximates a 1/f power spectrum with `pink_rows.len()` octaves.
+    fn next_pink_unit(&mut self) -> f32 {
+        self.pink_counter = self.pink_counter.wrapping_add(1);
+        let num_rows = self.pink_rows.len();
+        let row = (self.pink_counter.trailing_zeros() as usize).min(num_rows - 1);
+        self.pink_rows[row] = self.next_white_unit();
+        self.pink_rows.iter().sum::<f32>() / num_rows as f32
+    }
+}
@@ -0,0 +1,180 @@
+/// Pre-transcription silence trimming built on [`super::spectral_vad`].
+///
+/// Whisper pays for every sample it's handed regardless of whether that
+/// sample is speech or silence, so a recording with a long lead-in, a few
+/// mid-sentence pauses, or - the degenerate case - nothing but silence still
+/// costs a full decode pass. This segments an already-recorded buffer into
+/// contiguous speech regions using the same FFT-based VAD that drives
+/// `get_voice_activity`, drops the silence before/after/between them (as
+/// well as any speech-looking segment too short to be a real utterance),
+/// and concatenates what's left for the transcriber.
+use super::spectral_vad::{SpectralVad, SpectralVadConfig, SpectralVadEvent};
+use serde::{Deserialize, Serialize};
+
+/// Configuration for [`trim_silence`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SilenceTrimmerConfig {
+    /// Master switch; trimming is skipped entirely (input returned as-is)
+    /// when `false`.
+    pub enabled: bool,
+    /// Thresholds for the underlying spectral VAD. Kept separate from
+    /// `AudioSettings::voice_activity` (which drives live level monitoring)
+    /// so the two can be tuned independently.
+    pub vad: SpectralVadConfig,
+    /// Speech segments shorter than this are dropped along with the
+    /// surrounding silence, filtering out brief VAD false positives (a
+    /// cough, a click) rather than passing them to Whisper as a segment of
+    /// their own.
+    pub min_segment_duration_ms: u32,
+}
+
+impl Default for SilenceTrimmerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            vad: SpectralVadConfig::default(),
+            min_segment_duration_ms: 150,
+        }
+    }
+}
+
+/// Durations observed while trimming one buffer, reported alongside
+/// transcription performance metrics so the effect of trimming on RTF is
+/// visible (see `TranscriptionPerformanceData::strategy_metadata`).
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct TrimStats {
+    pub input_duration_secs: f32,
+    pub output_duration_secs: f32,
+}
+
+impl TrimStats {
+    pub fn trimmed_duration_secs(&self) -> f32 {
+        (self.input_duration_secs - self.output_duration_secs).max(0.0)
+    }
+
+    fn unchanged(duration_secs: f32) -> Self {
+        Self {
+            input_duration_secs: duration_secs,
+            output_duration_secs: duration_secs,
+        }
+    }
+}
+
+/// Run VAD over `samples` (mono, `sample_rate` Hz) and return only the
+/// contiguous speech segments at least `min_segment_duration_ms` long,
+/// concatenated in their original order, alongside the before/after
+/// durations. Falls back to returning `samples` unchanged if trimming is
+/// disabled or the VAD never detects speech at all, so a misconfigured or
+/// overly strict VAD can't silently zero out a genuine recording.
+pub fn trim_silence(
+    samples: &[f32],
+    sample_rate: u32,
+    config: &SilenceTrimmerConfig,
+) -> (Vec<f32>, TrimStats) {
+    let input_duration_secs = samples.len() as f32 / sample_rate.max(1) as f32;
+
+    if !config.enabled || samples.is_empty() {
+        return (samples.to_vec(), TrimStats::unchanged(input_duration_secs));
+    }
+
+    let min_segment_samples =
+        ((sample_rate as f32 * config.min_segment_duration_ms as f32) / 1000.0) as usize;
+    let hop_samples = ((sample_rate as f32 * config.vad.hop_duration_ms) / 1000.0).max(1.0) as usize;
+
+    let mut vad = SpectralVad::new(sample_rate, config.vad.clone());
+    let mut segments: Vec<(usize, usize)> = Vec::new();
+    let mut segment_start: Option<usize> = None;
+
+    for (frame_index, chunk) in samples.chunks(hop_samples).enumerate() {
+        let offset = frame_index * hop_samples;
+        match vad.process(chunk) {
+            SpectralVadEvent::SpeechStarted => segment_start = Some(offset),
+            SpectralVadEvent::SpeechEnded => {
+                if let Some(start) = segment_start.take() {
+                    segments.push((start, offset));
+                }
+            }
+            SpectralVadEvent::Unchanged => {}
+        }
+    }
+    if let Some(start) = segment_start.take() {
+        segments.push((start, samples.len()));
+    }
+
+    let mut trimmed = Vec::with_capacity(samples.len());
+    for (start, end) in segments {
+        if end.saturating_sub(start) >= min_segment_samples {
+            trimmed.extend_from_slice(&samples[start..end]);
+        }
+    }
+
+    if trimmed.is_empty() {
+        return (samples.to_vec(), TrimStats::unchanged(input_duration_secs));
+    }
+
+    let output_duration_secs = trimmed.len() as f32 / sample_rate.max(1) as f32;
+    (
+        trimmed,
+        TrimStats {
+            input_duration_secs,
+            output_duration_secs,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(sample_rate: u32, freq_hz: f32, num_samples: usize, amplitude: f32) -> Vec<f32> {
+        (0..num_samples)
+            .map(|i| {
+                amplitude
+                    * (2.0 * std::f32::consts::PI * freq_hz * i as f32 / sample_rate as f32).sin()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn drops_leading_and_trailing_silence() {
+        let sample_rate = 16_000;
+        let quiet = tone(sample_rate, 1_000.0, sample_rate as usize, 0.01);
+        let loud = tone(sample_rate, 1_000.0, sample_rate as usize, 0.9);
+
+        let mut samples = quiet.clone();
+        samples.extend_from_slice(&loud);
+        samples.extend_from_slice(&quiet);
+
+        let (trimmed, stats) = trim_silence(&samples, sample_rate, &SilenceTrimmerConfig::default());
+
+        assert!(stats.output_duration_secs < stats.input_duration_secs);
+        assert!(trimmed.len() < samples.len());
+    }
+
+    #[test]
+    fn disabled_config_returns_input_unchanged() {
+        let sample_rate = 16_000;
+        let samples = vec![0.0f32; sample_rate as usize];
+        let config = SilenceTrimmerConfig {
+            enabled: false,
+            ..SilenceTrimmerConfig::default()
+        };
+
+        let (trimmed, stats) = trim_silence(&samples, sample_rate, &config);
+
+        assert_eq!(trimmed, samples);
+        assert_eq!(stats.trimmed_duration_secs(), 0.0);
+    }
+
+    #[test]
+    fn pure_silence_falls_back_to_original_samples() {
+        let sample_rate = 16_000;
+        let samples = vec![0.0f32; sample_rate as usize];
+
+        let (trimmed, stats) = trim_silence(&samples, sample_rate, &SilenceTrimmerConfig::default());
+
+        assert_eq!(trimmed.len(), samples.len());
+        assert_eq!(stats.trimmed_duration_secs(), 0.0);
+    }
+}
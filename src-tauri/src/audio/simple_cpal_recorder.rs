@@ -1,28 +1,435 @@
+use crate::audio::hdf5_recorder::Hdf5Recorder;
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use hound::{WavSpec, WavWriter};
+use std::collections::VecDeque;
 use std::fs::File;
 use std::io::BufWriter;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::time::Instant;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 use crate::logger::{debug, info, warn, error, Component};
 
+/// Time constant for the one-pole smoother `get_peak_level`/`get_rms_level`
+/// apply to each block's metering, so the VU meter eases between blocks
+/// instead of jumping around with every callback.
+const LEVEL_SMOOTHING_TIME_CONSTANT_MS: f32 = 150.0;
+/// Floor `get_level_db` clamps at, so near-silence reports a stable
+/// (very negative) number instead of `-inf`/NaN from `log10(0.0)`.
+const LEVEL_NOISE_FLOOR_DB: f32 = -60.0;
+
+/// Computes this block's peak (max abs) and RMS, each in `[0.0, 1.0]`.
+fn block_peak_rms_f32(data: &[f32]) -> (f32, f32) {
+    let mut peak = 0.0f32;
+    let mut sum_squares = 0.0f32;
+    for &sample in data {
+        peak = peak.max(sample.abs());
+        sum_squares += sample * sample;
+    }
+    let rms = (sum_squares / data.len().max(1) as f32).sqrt();
+    (peak, rms)
+}
+
+/// As [`block_peak_rms_f32`], but for `i16` samples normalized to `[-1.0, 1.0]`.
+fn block_peak_rms_i16(data: &[i16]) -> (f32, f32) {
+    let mut peak = 0.0f32;
+    let mut sum_squares = 0.0f32;
+    for &sample in data {
+        let s = sample as f32 / 32768.0;
+        peak = peak.max(s.abs());
+        sum_squares += s * s;
+    }
+    let rms = (sum_squares / data.len().max(1) as f32).sqrt();
+    (peak, rms)
+}
+
+/// Folds `block_value` into the smoothed level stored (as an `f32` bit
+/// pattern) in `level_bits`, using `level = level*(1-a) + block*a` where
+/// `a` is derived from `block_duration_secs` and
+/// `LEVEL_SMOOTHING_TIME_CONSTANT_MS` via the standard one-pole-filter
+/// formula `a = 1 - exp(-dt/tau)`.
+fn smooth_level(level_bits: &AtomicU32, block_value: f32, block_duration_secs: f32) {
+    let tau_secs = LEVEL_SMOOTHING_TIME_CONSTANT_MS / 1000.0;
+    let alpha = 1.0 - (-block_duration_secs / tau_secs).exp();
+    let _ = level_bits.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |bits| {
+        let current = f32::from_bits(bits);
+        Some((current * (1.0 - alpha) + block_value * alpha).to_bits())
+    });
+}
+
+/// Records the highest RMS seen across a whole recording (unlike
+/// `smooth_level`, which decays), so `stop_recording` can compare it
+/// against a [`DiscardPolicy::min_rms_level`] floor after the fact.
+fn track_max_rms(max_rms_bits: &AtomicU32, block_rms: f32) {
+    let _ = max_rms_bits.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |bits| {
+        if block_rms > f32::from_bits(bits) {
+            Some(block_rms.to_bits())
+        } else {
+            None
+        }
+    });
+}
+
+/// One input device as reported by `host.input_devices()`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeviceInfo {
+    pub name: String,
+    pub is_default: bool,
+}
+
+/// One entry from a device's `supported_input_configs()` — the sample-rate
+/// range, channel count, and sample format a single config range allows.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SupportedConfigRange {
+    pub channels: u16,
+    pub min_sample_rate: u32,
+    pub max_sample_rate: u32,
+    pub sample_format: String,
+}
+
+/// Everything `query_device` can tell a caller about one input device
+/// before it commits to `start_recording`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeviceCapabilities {
+    pub name: String,
+    pub is_default: bool,
+    pub supported_configs: Vec<SupportedConfigRange>,
+}
+
+/// The sample-rate/channel/format combination `start_recording` will
+/// actually negotiate with a device — what `default_recording_config`
+/// produces and `validate_recording_config` checks a candidate against.
+/// Mirrors the lasprs `lasp_devinfo` pattern of generating a ready-to-use
+/// DAQ config per device rather than leaving callers to guess one.
+#[derive(Debug, Clone, Copy)]
+pub struct RecordingConfig {
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub sample_format: cpal::SampleFormat,
+}
+
+fn sample_format_label(format: cpal::SampleFormat) -> String {
+    format!("{:?}", format)
+}
+
+fn find_input_device(name: Option<&str>) -> Result<cpal::Device, String> {
+    let host = cpal::default_host();
+    match name {
+        Some(name) => host
+            .input_devices()
+            .map_err(|e| format!("Failed to enumerate devices: {}", e))?
+            .find(|d| d.name().map(|n| n == name).unwrap_or(false))
+            .ok_or_else(|| format!("Device '{}' not found", name)),
+        None => host
+            .default_input_device()
+            .ok_or_else(|| "No default input device".to_string()),
+    }
+}
+
+/// Lists every input device `host.input_devices()` reports, flagging which
+/// one (if any) is the default. Lets a caller build a device picker instead
+/// of passing a device name blindly into `start_recording` and discovering
+/// a typo only after the stream fails to build.
+pub fn list_input_devices() -> Result<Vec<DeviceInfo>, String> {
+    let host = cpal::default_host();
+    let default_name = host.default_input_device().and_then(|d| d.name().ok());
+
+    let devices = host
+        .input_devices()
+        .map_err(|e| format!("Failed to enumerate devices: {}", e))?;
+
+    Ok(devices
+        .filter_map(|d| d.name().ok())
+        .map(|name| {
+            let is_default = default_name.as_deref() == Some(name.as_str());
+            DeviceInfo { name, is_default }
+        })
+        .collect())
+}
+
+/// Reports a device's name, default status, and every supported config
+/// range (sample-rate bounds, channel count, sample format) from
+/// `supported_input_configs()`, so a caller can validate a chosen
+/// combination before committing to `start_recording`.
+pub fn query_device(name: &str) -> Result<DeviceCapabilities, String> {
+    let host = cpal::default_host();
+    let default_name = host.default_input_device().and_then(|d| d.name().ok());
+    let device = find_input_device(Some(name))?;
+
+    let supported_configs = device
+        .supported_input_configs()
+        .map_err(|e| format!("Failed to query supported configs: {}", e))?
+        .map(|range| SupportedConfigRange {
+            channels: range.channels(),
+            min_sample_rate: range.min_sample_rate().0,
+            max_sample_rate: range.max_sample_rate().0,
+            sample_format: sample_format_label(range.sample_format()),
+        })
+        .collect();
+
+    Ok(DeviceCapabilities {
+        name: name.to_string(),
+        is_default: default_name.as_deref() == Some(name),
+        supported_configs,
+    })
+}
+
+/// Produces the config `start_recording` would negotiate with `device_name`
+/// (or the default device, if `None`) via `default_input_config()`, so a
+/// caller can inspect it — or pass a modified one to
+/// `validate_recording_config` — before actually opening a stream.
+pub fn default_recording_config(device_name: Option<&str>) -> Result<RecordingConfig, String> {
+    let device = find_input_device(device_name)?;
+    let config = device
+        .default_input_config()
+        .map_err(|e| format!("Failed to get config: {}", e))?;
+
+    Ok(RecordingConfig {
+        sample_rate: config.sample_rate().0,
+        channels: config.channels(),
+        sample_format: config.sample_format(),
+    })
+}
+
+/// Checks that `config` falls within one of `device_name`'s (or the
+/// default device's) `supported_input_configs()` ranges, returning an
+/// `Err` describing the mismatch instead of letting `start_recording` fail
+/// later when it tries to build the stream.
+pub fn validate_recording_config(device_name: Option<&str>, config: RecordingConfig) -> Result<(), String> {
+    let device = find_input_device(device_name)?;
+    let supported = device
+        .supported_input_configs()
+        .map_err(|e| format!("Failed to query supported configs: {}", e))?;
+
+    let matches = supported.into_iter().any(|range| {
+        range.channels() == config.channels
+            && range.sample_format() == config.sample_format
+            && config.sample_rate >= range.min_sample_rate().0
+            && config.sample_rate <= range.max_sample_rate().0
+    });
+
+    if matches {
+        Ok(())
+    } else {
+        Err(format!(
+            "Device does not support {} Hz / {} channels / {:?}",
+            config.sample_rate, config.channels, config.sample_format
+        ))
+    }
+}
+
+/// Always-on capture buffer holding the most recent `capacity` samples, so
+/// `start_recording` can prepend audio from before the trigger to the WAV
+/// it's about to write. Modeled on the same capped-`VecDeque` approach
+/// [`crate::audio::ring_buffer_recorder::RingBufferRecorder`] uses, rather
+/// than pulling in a lock-free ring buffer crate — `src-tauri` has no
+/// manifest to add one to, and a capped push_back/pop_front deque gives the
+/// same amortized O(1) push-and-evict behavior.
+///
+/// Samples are kept in whatever native type the armed stream's
+/// `cpal::SampleFormat` uses (`f32` or `i16`) so draining them into the
+/// live `WavWriter` never round-trips through a different format than the
+/// one actually negotiated with the device.
+enum PrerollSamples {
+    F32(VecDeque<f32>),
+    I16(VecDeque<i16>),
+}
+
+struct PrerollBuffer {
+    samples: PrerollSamples,
+    capacity: usize,
+    sample_rate: u32,
+    channels: u16,
+    sample_format: cpal::SampleFormat,
+}
+
+impl PrerollBuffer {
+    fn new(sample_rate: u32, channels: u16, sample_format: cpal::SampleFormat, preroll: Duration) -> Self {
+        let capacity = ((sample_rate as f64) * (channels as f64) * preroll.as_secs_f64()) as usize;
+        let samples = match sample_format {
+            cpal::SampleFormat::I16 => PrerollSamples::I16(VecDeque::with_capacity(capacity)),
+            _ => PrerollSamples::F32(VecDeque::with_capacity(capacity)),
+        };
+        Self {
+            samples,
+            capacity,
+            sample_rate,
+            channels,
+            sample_format,
+        }
+    }
+
+    fn push_f32(&mut self, data: &[f32]) {
+        if let PrerollSamples::F32(buf) = &mut self.samples {
+            for &sample in data {
+                buf.push_back(sample);
+            }
+            while buf.len() > self.capacity {
+                buf.pop_front();
+            }
+        }
+    }
+
+    fn push_i16(&mut self, data: &[i16]) {
+        if let PrerollSamples::I16(buf) = &mut self.samples {
+            for &sample in data {
+                buf.push_back(sample);
+            }
+            while buf.len() > self.capacity {
+                buf.pop_front();
+            }
+        }
+    }
+
+    /// Drains every buffered sample (oldest first) into `writer`, returning
+    /// how many were written. Leaves the buffer empty so the next
+    /// `start_recording` call only sees audio captured since this drain.
+    fn drain_into(&mut self, writer: &mut WavWriter<BufWriter<File>>) -> Result<u64, String> {
+        match &mut self.samples {
+            PrerollSamples::F32(buf) => {
+                let count = buf.len() as u64;
+                for sample in buf.drain(..) {
+                    writer
+                        .write_sample(sample)
+                        .map_err(|e| format!("Failed to drain preroll sample: {}", e))?;
+                }
+                Ok(count)
+            }
+            PrerollSamples::I16(buf) => {
+                let count = buf.len() as u64;
+                for sample in buf.drain(..) {
+                    writer
+                        .write_sample(sample)
+                        .map_err(|e| format!("Failed to drain preroll sample: {}", e))?;
+                }
+                Ok(count)
+            }
+        }
+    }
+}
+
+/// Which container `start_recording` writes captured audio into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecordingFormat {
+    /// The original `hound::WavWriter` path.
+    #[default]
+    Wav,
+    /// Writes into a resizable, chunked, gzip-compressed HDF5 dataset via
+    /// [`Hdf5Recorder`], with a recording UUID, start timestamp, device
+    /// name, and format attached as attributes on finalize. See
+    /// `crate::audio::hdf5_recorder` for the dataset layout.
+    Hdf5,
+}
+
+/// Commands sent to the dedicated stream thread `start_recording` spawns.
+enum StreamCommand {
+    Pause,
+    Resume,
+    Stop,
+}
+
 /// Ultra-simplified CPAL recorder that trusts the framework completely
-/// 
+///
 /// Philosophy: CPAL handles device enumeration, format negotiation, and error recovery.
 /// We just need to connect CPAL to a WAV writer. That's it.
-/// 
-/// IMPORTANT: To maintain Send+Sync compatibility, we don't store the Stream directly.
-/// Instead, we leak it during recording and rely on the process cleanup.
+///
+/// The CPAL `Stream` a recording opens is built, played, and dropped
+/// entirely on a dedicated worker thread (the "run input stream on a
+/// separate thread" pattern from the cpal examples) spawned by
+/// `start_recording`; the always-on stream `enable_preroll` arms is built on
+/// the calling thread and leaked with `std::mem::forget`. Neither crosses a
+/// thread boundary as a value, so
+/// `SimpleCpalRecorder` doesn't need (and doesn't declare) an `unsafe impl
+/// Send`/`Sync`; every field it actually stores is auto-`Send`/`Sync`
+/// already. `start_recording`'s thread parks on a command channel so
+/// `pause`/`resume` can call `stream.pause()`/`stream.play()` without
+/// tearing down the writer, and `stop_recording` sends it a stop command
+/// and joins the thread — dropping the stream cleanly — before finalizing.
 pub struct SimpleCpalRecorder {
     writer: Arc<Mutex<Option<WavWriter<BufWriter<File>>>>>,
+    /// Populated instead of `writer` when `start_recording_with_format` is
+    /// called with [`RecordingFormat::Hdf5`]; at most one of the two is
+    /// ever `Some` for a given recording.
+    hdf5_writer: Arc<Mutex<Option<Hdf5Recorder>>>,
     state: Arc<Mutex<RecorderState>>,
     is_recording: Arc<AtomicBool>,
     callback_count: Arc<AtomicU64>,
     total_samples: Arc<AtomicU64>,
+    /// Set once `enable_preroll` has armed an always-on capture stream.
+    /// While armed, `start_recording` reuses that stream (draining
+    /// `preroll` into the new writer) instead of building a fresh one.
+    armed: Arc<AtomicBool>,
+    preroll: Arc<Mutex<Option<PrerollBuffer>>>,
+    /// Smoothed peak/RMS levels, stored as `f32::to_bits()` so they can be
+    /// updated from the audio callback with `AtomicU32::fetch_update`
+    /// instead of a `Mutex<f32>`.
+    peak_level_bits: Arc<AtomicU32>,
+    rms_level_bits: Arc<AtomicU32>,
+    /// Highest block RMS seen since the current recording started, reset at
+    /// the start of each `start_recording_with_format`/`start_recording_armed`
+    /// call. Compared against `discard_policy.min_rms_level` on stop.
+    max_rms_bits: Arc<AtomicU32>,
+    /// Thresholds `stop_recording` uses to decide whether a just-finalized
+    /// recording is worth keeping. Set with [`Self::set_discard_policy`]
+    /// before calling `start_recording`/`start_recording_with_format`.
+    discard_policy: Arc<Mutex<DiscardPolicy>>,
+    /// Command channel into the dedicated stream thread `start_recording`
+    /// spawned; `None` when idle, or when the active recording reused the
+    /// always-on preroll stream (which doesn't support pause/resume).
+    record_tx: Arc<Mutex<Option<std::sync::mpsc::Sender<StreamCommand>>>>,
+    record_thread: Arc<Mutex<Option<std::thread::JoinHandle<()>>>>,
+}
+
+/// Minimum-duration/minimum-level thresholds for discarding a recording on
+/// stop, borrowing `lasprs`' behavior of deleting files that captured
+/// nothing useful. All fields default to `None`, meaning only the
+/// unconditional "zero samples written" check applies.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DiscardPolicy {
+    /// Recordings shorter than this are discarded even if non-empty.
+    pub min_duration: Option<Duration>,
+    /// Recordings whose block RMS never exceeded this floor are discarded.
+    pub min_rms_level: Option<f32>,
+}
+
+/// Why `stop_recording` deleted the file instead of returning a
+/// [`RecordingInfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscardReason {
+    /// No audio callback ever wrote a sample.
+    NoSamplesWritten,
+    /// Shorter than `DiscardPolicy::min_duration`.
+    ShorterThanMinDuration,
+    /// Never crossed `DiscardPolicy::min_rms_level`.
+    QuieterThanMinLevel,
+}
+
+/// Error type for [`SimpleCpalRecorder::stop_recording`].
+#[derive(Debug, Clone)]
+pub enum StopRecordingError {
+    /// No recording was in progress.
+    NotRecording,
+    /// The file didn't meet the active [`DiscardPolicy`] and was deleted;
+    /// `path` is where it used to be.
+    Discarded { reason: DiscardReason, path: PathBuf },
+    /// Finalizing the writer itself failed (e.g. disk I/O error).
+    Io(String),
+}
+
+impl std::fmt::Display for StopRecordingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StopRecordingError::NotRecording => write!(f, "Not recording"),
+            StopRecordingError::Discarded { reason, path } => {
+                write!(f, "Discarded recording at {:?} ({:?})", path, reason)
+            }
+            StopRecordingError::Io(message) => write!(f, "{}", message),
+        }
+    }
 }
 
+impl std::error::Error for StopRecordingError {}
+
 #[derive(Debug, Clone)]
 pub enum RecorderState {
     Idle,
@@ -50,100 +457,337 @@ impl SimpleCpalRecorder {
         info(Component::Audio, "🎙️ [CPAL] Creating new SimpleCpalRecorder");
         Self {
             writer: Arc::new(Mutex::new(None)),
+            hdf5_writer: Arc::new(Mutex::new(None)),
             state: Arc::new(Mutex::new(RecorderState::Idle)),
             is_recording: Arc::new(AtomicBool::new(false)),
             callback_count: Arc::new(AtomicU64::new(0)),
             total_samples: Arc::new(AtomicU64::new(0)),
+            armed: Arc::new(AtomicBool::new(false)),
+            preroll: Arc::new(Mutex::new(None)),
+            peak_level_bits: Arc::new(AtomicU32::new(0.0f32.to_bits())),
+            rms_level_bits: Arc::new(AtomicU32::new(0.0f32.to_bits())),
+            max_rms_bits: Arc::new(AtomicU32::new(0.0f32.to_bits())),
+            discard_policy: Arc::new(Mutex::new(DiscardPolicy::default())),
+            record_tx: Arc::new(Mutex::new(None)),
+            record_thread: Arc::new(Mutex::new(None)),
         }
     }
 
-    /// Start recording to a file
-    /// Trust CPAL to handle device selection and format negotiation
-    pub fn start_recording(&self, path: &Path, device_name: Option<&str>) -> Result<(), String> {
-        info(Component::Audio, "🎙️ [RECORDER] ========== START RECORDING ==========");
-        info(Component::Audio, &format!("🎙️ [RECORDER] Path: {:?}", path));
-        info(Component::Audio, &format!("🎙️ [RECORDER] Device: {:?}", device_name));
-        
-        // Reset counters
-        self.callback_count.store(0, Ordering::SeqCst);
-        self.total_samples.store(0, Ordering::SeqCst);
-        info(Component::Audio, "🎙️ [RECORDER] Reset counters - callback_count: 0, total_samples: 0");
-        
-        // Check current state
-        {
-            let state = self.state.lock().unwrap();
-            if matches!(*state, RecorderState::Recording { .. }) {
-                warn(Component::Audio, "🎙️ [RECORDER] Already recording, returning error");
-                return Err("Already recording".to_string());
-            }
-            info(Component::Audio, "🎙️ [RECORDER] Current state: Idle, proceeding with recording");
+    /// Sets the thresholds `stop_recording` will use to decide whether to
+    /// discard the just-finalized file. Call before `start_recording`/
+    /// `start_recording_with_format`; has no effect on a recording already
+    /// in progress.
+    pub fn set_discard_policy(&self, policy: DiscardPolicy) {
+        *self.discard_policy.lock().unwrap() = policy;
+    }
+
+    /// Arms the recorder for pre-roll capture: builds the CPAL input stream
+    /// now (instead of waiting for `start_recording`) and starts pushing
+    /// every sample into a fixed-capacity ring buffer sized to `preroll` of
+    /// audio. When `start_recording` is later called on an armed recorder,
+    /// the buffer's current contents are drained into the new WAV file
+    /// before live samples take over, so the recording includes up to
+    /// `preroll` of audio from before the trigger.
+    pub fn enable_preroll(&self, preroll: Duration, device_name: Option<&str>) -> Result<(), String> {
+        if self.armed.load(Ordering::SeqCst) {
+            return Err("Pre-roll already enabled".to_string());
         }
 
-        // Get the device - trust CPAL's enumeration
-        info(Component::Audio, "🎙️ [RECORDER] Getting audio host and device");
+        info(Component::Audio, &format!("🎙️ [PREROLL] Arming pre-roll capture ({:.1}s)", preroll.as_secs_f64()));
+
         let host = cpal::default_host();
         let device = if let Some(name) = device_name {
-            info(Component::Audio, &format!("🎙️ [RECORDER] Looking for specific device: {}", name));
             host.input_devices()
                 .map_err(|e| format!("Failed to enumerate devices: {}", e))?
                 .find(|d| d.name().map(|n| n == name).unwrap_or(false))
                 .ok_or_else(|| format!("Device '{}' not found", name))?
         } else {
-            info(Component::Audio, "🎙️ [RECORDER] Using default input device");
             host.default_input_device()
                 .ok_or_else(|| "No default input device".to_string())?
         };
-        
-        let device_name_str = device.name().unwrap_or_else(|_| "Unknown".to_string());
-        info(Component::Audio, &format!("🎙️ [RECORDER] Selected device: {}", device_name_str));
 
-        // Get the default config - trust CPAL's format selection
-        info(Component::Audio, "🎙️ [RECORDER] Getting device config");
         let config = device
             .default_input_config()
             .map_err(|e| format!("Failed to get config: {}", e))?;
-        
-        info(Component::Audio, &format!("🎙️ [RECORDER] Device config: {} Hz, {} channels, format: {:?}", 
-            config.sample_rate().0, config.channels(), config.sample_format()));
 
-        // Create WAV spec based on what CPAL gives us
-        let wav_spec = WavSpec {
-            channels: config.channels(),
-            sample_rate: config.sample_rate().0,
-            bits_per_sample: match config.sample_format() {
-                cpal::SampleFormat::I16 => 16,
-                cpal::SampleFormat::F32 => 32,
-                _ => return Err("Unsupported sample format".to_string()),
-            },
-            sample_format: match config.sample_format() {
-                cpal::SampleFormat::I16 => hound::SampleFormat::Int,
-                cpal::SampleFormat::F32 => hound::SampleFormat::Float,
-                _ => return Err("Unsupported sample format".to_string()),
-            },
-        };
-        info(Component::Audio, &format!("🎙️ [RECORDER] WAV spec created - {} Hz, {} channels, {} bits", 
-            wav_spec.sample_rate, wav_spec.channels, wav_spec.bits_per_sample));
+        let sample_rate = config.sample_rate().0;
+        let channels = config.channels();
+        let sample_format = config.sample_format();
 
-        // Create the WAV writer
-        info(Component::Audio, &format!("🎙️ [RECORDER] Creating WAV writer at path: {:?}", path));
-        let writer = WavWriter::create(path, wav_spec)
-            .map_err(|e| format!("Failed to create WAV file: {}", e))?;
-        info(Component::Audio, "🎙️ [RECORDER] WAV writer created successfully");
-        
-        // CRITICAL FIX: Store the writer in self.writer FIRST
-        *self.writer.lock().unwrap() = Some(writer);
-        info(Component::Audio, "🎙️ [RECORDER] Writer stored in self.writer");
+        *self.preroll.lock().unwrap() = Some(PrerollBuffer::new(sample_rate, channels, sample_format, preroll));
 
-        // Clone for the closure - now using self.writer instead of local variable
         let writer_clone = self.writer.clone();
+        let preroll_clone = self.preroll.clone();
         let state_clone = self.state.clone();
         let is_recording = self.is_recording.clone();
         let callback_count = self.callback_count.clone();
         let total_samples = self.total_samples.clone();
-        
-        // Build the stream - let CPAL handle everything
-        info(Component::Audio, &format!("🎙️ [RECORDER] Building CPAL stream with format: {:?}", config.sample_format()));
-        let stream = match config.sample_format() {
+        let peak_level_bits = self.peak_level_bits.clone();
+        let rms_level_bits = self.rms_level_bits.clone();
+        let max_rms_bits = self.max_rms_bits.clone();
+        let block_duration_divisor = sample_rate as f32 * channels as f32;
+
+        let stream = match sample_format {
+            cpal::SampleFormat::F32 => device.build_input_stream(
+                &config.into(),
+                move |data: &[f32], _| {
+                    callback_count.fetch_add(1, Ordering::SeqCst);
+
+                    if let Ok(mut preroll_guard) = preroll_clone.try_lock() {
+                        if let Some(buf) = preroll_guard.as_mut() {
+                            buf.push_f32(data);
+                        }
+                    }
+
+                    let (peak, rms) = block_peak_rms_f32(data);
+                    let block_duration_secs = data.len() as f32 / block_duration_divisor;
+                    smooth_level(&peak_level_bits, peak, block_duration_secs);
+                    smooth_level(&rms_level_bits, rms, block_duration_secs);
+
+                    if !is_recording.load(Ordering::SeqCst) {
+                        return;
+                    }
+
+                    track_max_rms(&max_rms_bits, rms);
+
+                    if let Ok(mut writer_guard) = writer_clone.try_lock() {
+                        if let Some(ref mut writer) = *writer_guard {
+                            let mut write_count = 0;
+                            for &sample in data {
+                                if writer.write_sample(sample).is_ok() {
+                                    write_count += 1;
+                                }
+                            }
+                            let new_total = total_samples.fetch_add(write_count, Ordering::SeqCst) + write_count;
+                            debug(Component::Audio, &format!("🎙️ [PREROLL] Wrote {} live samples (total: {})", write_count, new_total));
+                            if let Ok(mut state) = state_clone.try_lock() {
+                                if let RecorderState::Recording { samples_written, .. } = &mut *state {
+                                    *samples_written += write_count;
+                                }
+                            }
+                        }
+                    }
+                },
+                |err| error(Component::Audio, &format!("🎙️ [STREAM ERROR] {}", err)),
+                None,
+            ),
+            cpal::SampleFormat::I16 => {
+                let peak_level_bits = peak_level_bits.clone();
+                let rms_level_bits = rms_level_bits.clone();
+                let max_rms_bits = max_rms_bits.clone();
+                device.build_input_stream(
+                    &config.into(),
+                    move |data: &[i16], _| {
+                        callback_count.fetch_add(1, Ordering::SeqCst);
+
+                        if let Ok(mut preroll_guard) = preroll_clone.try_lock() {
+                            if let Some(buf) = preroll_guard.as_mut() {
+                                buf.push_i16(data);
+                            }
+                        }
+
+                        let (peak, rms) = block_peak_rms_i16(data);
+                        let block_duration_secs = data.len() as f32 / block_duration_divisor;
+                        smooth_level(&peak_level_bits, peak, block_duration_secs);
+                        smooth_level(&rms_level_bits, rms, block_duration_secs);
+
+                        if !is_recording.load(Ordering::SeqCst) {
+                            return;
+                        }
+
+                        track_max_rms(&max_rms_bits, rms);
+
+                        if let Ok(mut writer_guard) = writer_clone.try_lock() {
+                            if let Some(ref mut writer) = *writer_guard {
+                                let mut write_count = 0;
+                                for &sample in data {
+                                    if writer.write_sample(sample).is_ok() {
+                                        write_count += 1;
+                                    }
+                                }
+                                let new_total = total_samples.fetch_add(write_count, Ordering::SeqCst) + write_count;
+                                debug(Component::Audio, &format!("🎙️ [PREROLL] Wrote {} live samples (total: {})", write_count, new_total));
+                                if let Ok(mut state) = state_clone.try_lock() {
+                                    if let RecorderState::Recording { samples_written, .. } = &mut *state {
+                                        *samples_written += write_count;
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    |err| error(Component::Audio, &format!("🎙️ [STREAM ERROR] {}", err)),
+                    None,
+                )
+            }
+            _ => return Err("Unsupported sample format".to_string()),
+        }
+        .map_err(|e| format!("Failed to build stream: {}", e))?;
+
+        stream.play().map_err(|e| format!("Failed to start stream: {}", e))?;
+
+        // Leak the stream so it keeps running for the rest of the process;
+        // unlike `start_recording`'s per-recording stream, it is never dropped.
+        std::mem::forget(stream);
+
+        self.armed.store(true, Ordering::SeqCst);
+        info(Component::Audio, "🎙️ [PREROLL] Pre-roll capture armed and running");
+        Ok(())
+    }
+
+    /// Start recording to a file in the default [`RecordingFormat::Wav`] container.
+    pub fn start_recording(&self, path: &Path, device_name: Option<&str>) -> Result<(), String> {
+        self.start_recording_with_format(path, device_name, RecordingFormat::Wav)
+    }
+
+    /// Start recording to a file, choosing the on-disk container via `format`.
+    /// Trust CPAL to handle device selection and format negotiation
+    pub fn start_recording_with_format(&self, path: &Path, device_name: Option<&str>, format: RecordingFormat) -> Result<(), String> {
+        info(Component::Audio, "🎙️ [RECORDER] ========== START RECORDING ==========");
+        info(Component::Audio, &format!("🎙️ [RECORDER] Path: {:?}", path));
+        info(Component::Audio, &format!("🎙️ [RECORDER] Device: {:?}", device_name));
+        info(Component::Audio, &format!("🎙️ [RECORDER] Format: {:?}", format));
+
+        // Reset counters
+        self.callback_count.store(0, Ordering::SeqCst);
+        self.total_samples.store(0, Ordering::SeqCst);
+        self.max_rms_bits.store(0.0f32.to_bits(), Ordering::SeqCst);
+        info(Component::Audio, "🎙️ [RECORDER] Reset counters - callback_count: 0, total_samples: 0");
+
+        // Check current state
+        {
+            let state = self.state.lock().unwrap();
+            if matches!(*state, RecorderState::Recording { .. }) {
+                warn(Component::Audio, "🎙️ [RECORDER] Already recording, returning error");
+                return Err("Already recording".to_string());
+            }
+            info(Component::Audio, "🎙️ [RECORDER] Current state: Idle, proceeding with recording");
+        }
+
+        if self.armed.load(Ordering::SeqCst) {
+            if format != RecordingFormat::Wav {
+                return Err("Pre-roll is only supported with RecordingFormat::Wav".to_string());
+            }
+            return self.start_recording_armed(path);
+        }
+
+        let writer_arc = self.writer.clone();
+        let hdf5_writer_arc = self.hdf5_writer.clone();
+        let state_arc = self.state.clone();
+        let is_recording_arc = self.is_recording.clone();
+        let callback_count_arc = self.callback_count.clone();
+        let total_samples_arc = self.total_samples.clone();
+        let peak_level_bits_arc = self.peak_level_bits.clone();
+        let rms_level_bits_arc = self.rms_level_bits.clone();
+        let max_rms_bits_arc = self.max_rms_bits.clone();
+        let device_name_owned = device_name.map(|s| s.to_string());
+        let path_owned = path.to_path_buf();
+
+        // The stream is built, played, and dropped entirely inside this
+        // thread, so it never needs to cross a thread boundary as a value
+        // (it isn't necessarily `Send`). `ready_tx` reports back whether
+        // the stream came up so `start_recording_with_format` can still
+        // return a synchronous `Result`; `cmd_rx` lets `pause`/`resume`/
+        // `stop_recording` control the stream afterward.
+        let (ready_tx, ready_rx) = std::sync::mpsc::channel::<Result<(u32, u16), String>>();
+        let (cmd_tx, cmd_rx) = std::sync::mpsc::channel::<StreamCommand>();
+
+        let handle = std::thread::spawn(move || {
+            let device_name = device_name_owned.as_deref();
+            let path: &Path = path_owned.as_path();
+
+            let build_result: Result<(cpal::Stream, u32, u16), String> = (|| {
+                // Get the device - trust CPAL's enumeration
+                info(Component::Audio, "🎙️ [RECORDER] Getting audio host and device");
+                let host = cpal::default_host();
+                let device = if let Some(name) = device_name {
+                    info(Component::Audio, &format!("🎙️ [RECORDER] Looking for specific device: {}", name));
+                    host.input_devices()
+                        .map_err(|e| format!("Failed to enumerate devices: {}", e))?
+                        .find(|d| d.name().map(|n| n == name).unwrap_or(false))
+                        .ok_or_else(|| format!("Device '{}' not found", name))?
+                } else {
+                    info(Component::Audio, "🎙️ [RECORDER] Using default input device");
+                    host.default_input_device()
+                        .ok_or_else(|| "No default input device".to_string())?
+                };
+
+                let device_name_str = device.name().unwrap_or_else(|_| "Unknown".to_string());
+                info(Component::Audio, &format!("🎙️ [RECORDER] Selected device: {}", device_name_str));
+
+                // Get the default config - trust CPAL's format selection
+                info(Component::Audio, "🎙️ [RECORDER] Getting device config");
+                let config = device
+                    .default_input_config()
+                    .map_err(|e| format!("Failed to get config: {}", e))?;
+
+                info(Component::Audio, &format!("🎙️ [RECORDER] Device config: {} Hz, {} channels, format: {:?}",
+                    config.sample_rate().0, config.channels(), config.sample_format()));
+
+                // Create WAV spec based on what CPAL gives us
+                let wav_spec = WavSpec {
+                    channels: config.channels(),
+                    sample_rate: config.sample_rate().0,
+                    bits_per_sample: match config.sample_format() {
+                        cpal::SampleFormat::I16 => 16,
+                        cpal::SampleFormat::F32 => 32,
+                        _ => return Err("Unsupported sample format".to_string()),
+                    },
+                    sample_format: match config.sample_format() {
+                        cpal::SampleFormat::I16 => hound::SampleFormat::Int,
+                        cpal::SampleFormat::F32 => hound::SampleFormat::Float,
+                        _ => return Err("Unsupported sample format".to_string()),
+                    },
+                };
+                info(Component::Audio, &format!("🎙️ [RECORDER] WAV spec created - {} Hz, {} channels, {} bits",
+                    wav_spec.sample_rate, wav_spec.channels, wav_spec.bits_per_sample));
+
+                match format {
+                    RecordingFormat::Wav => {
+                        // Create the WAV writer
+                        info(Component::Audio, &format!("🎙️ [RECORDER] Creating WAV writer at path: {:?}", path));
+                        let writer = WavWriter::create(path, wav_spec)
+                            .map_err(|e| format!("Failed to create WAV file: {}", e))?;
+                        info(Component::Audio, "🎙️ [RECORDER] WAV writer created successfully");
+
+                        // CRITICAL FIX: Store the writer in self.writer FIRST
+                        *writer_arc.lock().unwrap() = Some(writer);
+                        info(Component::Audio, "🎙️ [RECORDER] Writer stored in self.writer");
+                    }
+                    RecordingFormat::Hdf5 => {
+                        info(Component::Audio, &format!("🎙️ [RECORDER] Creating HDF5 recorder at path: {:?}", path));
+                        let sample_format_label = match config.sample_format() {
+                            cpal::SampleFormat::I16 => "i16",
+                            cpal::SampleFormat::F32 => "f32",
+                            _ => return Err("Unsupported sample format".to_string()),
+                        };
+                        let hdf5_recorder = Hdf5Recorder::create(
+                            path,
+                            wav_spec.sample_rate,
+                            wav_spec.channels,
+                            sample_format_label,
+                            &device_name_str,
+                        )
+                        .map_err(|e| format!("Failed to create HDF5 file: {}", e))?;
+                        *hdf5_writer_arc.lock().unwrap() = Some(hdf5_recorder);
+                        info(Component::Audio, "🎙️ [RECORDER] HDF5 recorder stored in self.hdf5_writer");
+                    }
+                }
+
+                // Clone for the closure - now using writer_arc/hdf5_writer_arc instead of a local variable
+                let writer_clone = writer_arc.clone();
+                let hdf5_writer_clone = hdf5_writer_arc.clone();
+                let state_clone = state_arc.clone();
+                let is_recording = is_recording_arc.clone();
+                let callback_count = callback_count_arc.clone();
+                let total_samples = total_samples_arc.clone();
+                let peak_level_bits = peak_level_bits_arc.clone();
+                let rms_level_bits = rms_level_bits_arc.clone();
+                let max_rms_bits = max_rms_bits_arc.clone();
+                let block_duration_divisor = config.sample_rate().0 as f32 * config.channels() as f32;
+
+                // Build the stream - let CPAL handle everything
+                info(Component::Audio, &format!("🎙️ [RECORDER] Building CPAL stream with format: {:?}", config.sample_format()));
+                let stream = match config.sample_format() {
             cpal::SampleFormat::F32 => {
                 info(Component::Audio, "🎙️ [RECORDER] Building F32 stream");
                 device.build_input_stream(
@@ -151,15 +795,22 @@ impl SimpleCpalRecorder {
                     move |data: &[f32], _| {
                         // Increment callback counter
                         let callback_num = callback_count.fetch_add(1, Ordering::SeqCst) + 1;
-                        
+
+                        let (peak, rms) = block_peak_rms_f32(data);
+                        let block_duration_secs = data.len() as f32 / block_duration_divisor;
+                        smooth_level(&peak_level_bits, peak, block_duration_secs);
+                        smooth_level(&rms_level_bits, rms, block_duration_secs);
+
                         // Only process if recording
                         if !is_recording.load(Ordering::SeqCst) {
                             debug(Component::Audio, &format!("🎙️ [CALLBACK-{}] Not recording, skipping {} samples", callback_num, data.len()));
                             return;
                         }
-                        
+
+                        track_max_rms(&max_rms_bits, rms);
+
                         debug(Component::Audio, &format!("🎙️ [CALLBACK-{}] Received {} F32 samples", callback_num, data.len()));
-                        
+
                         if let Ok(mut writer_guard) = writer_clone.try_lock() {
                             if let Some(ref mut writer) = *writer_guard {
                                 let mut write_count = 0;
@@ -189,6 +840,25 @@ impl SimpleCpalRecorder {
                         } else {
                             warn(Component::Audio, &format!("🎙️ [CALLBACK-{}] Failed to lock writer", callback_num));
                         }
+
+                        if let Ok(mut hdf5_guard) = hdf5_writer_clone.try_lock() {
+                            if let Some(ref mut recorder) = *hdf5_guard {
+                                match recorder.write_samples(data) {
+                                    Ok(()) => {
+                                        let write_count = data.len() as u64;
+                                        let new_total = total_samples.fetch_add(write_count, Ordering::SeqCst) + write_count;
+                                        debug(Component::Audio, &format!("🎙️ [CALLBACK-{}] Wrote {} samples to HDF5 (total: {})",
+                                            callback_num, write_count, new_total));
+                                        if let Ok(mut state) = state_clone.try_lock() {
+                                            if let RecorderState::Recording { samples_written, .. } = &mut *state {
+                                                *samples_written += write_count;
+                                            }
+                                        }
+                                    }
+                                    Err(e) => warn(Component::Audio, &format!("🎙️ [CALLBACK-{}] Failed to write HDF5 samples: {}", callback_num, e)),
+                                }
+                            }
+                        }
                     },
                     |err| error(Component::Audio, &format!("🎙️ [STREAM ERROR] {}", err)),
                     None,
@@ -196,20 +866,31 @@ impl SimpleCpalRecorder {
             }
             cpal::SampleFormat::I16 => {
                 info(Component::Audio, "🎙️ [RECORDER] Building I16 stream");
+                let peak_level_bits = peak_level_bits.clone();
+                let rms_level_bits = rms_level_bits.clone();
+                let max_rms_bits = max_rms_bits.clone();
+                let hdf5_writer_clone = hdf5_writer_clone.clone();
                 device.build_input_stream(
                     &config.into(),
                     move |data: &[i16], _| {
                         // Increment callback counter
                         let callback_num = callback_count.fetch_add(1, Ordering::SeqCst) + 1;
-                        
+
+                        let (peak, rms) = block_peak_rms_i16(data);
+                        let block_duration_secs = data.len() as f32 / block_duration_divisor;
+                        smooth_level(&peak_level_bits, peak, block_duration_secs);
+                        smooth_level(&rms_level_bits, rms, block_duration_secs);
+
                         // Only process if recording
                         if !is_recording.load(Ordering::SeqCst) {
                             debug(Component::Audio, &format!("🎙️ [CALLBACK-{}] Not recording, skipping {} samples", callback_num, data.len()));
                             return;
                         }
-                        
+
+                        track_max_rms(&max_rms_bits, rms);
+
                         debug(Component::Audio, &format!("🎙️ [CALLBACK-{}] Received {} I16 samples", callback_num, data.len()));
-                        
+
                         if let Ok(mut writer_guard) = writer_clone.try_lock() {
                             if let Some(ref mut writer) = *writer_guard {
                                 let mut write_count = 0;
@@ -239,49 +920,176 @@ impl SimpleCpalRecorder {
                         } else {
                             warn(Component::Audio, &format!("🎙️ [CALLBACK-{}] Failed to lock writer", callback_num));
                         }
+
+                        if let Ok(mut hdf5_guard) = hdf5_writer_clone.try_lock() {
+                            if let Some(ref mut recorder) = *hdf5_guard {
+                                let data_f32: Vec<f32> = data.iter().map(|&s| s as f32 / 32768.0).collect();
+                                match recorder.write_samples(&data_f32) {
+                                    Ok(()) => {
+                                        let write_count = data.len() as u64;
+                                        let new_total = total_samples.fetch_add(write_count, Ordering::SeqCst) + write_count;
+                                        debug(Component::Audio, &format!("🎙️ [CALLBACK-{}] Wrote {} samples to HDF5 (total: {})",
+                                            callback_num, write_count, new_total));
+                                        if let Ok(mut state) = state_clone.try_lock() {
+                                            if let RecorderState::Recording { samples_written, .. } = &mut *state {
+                                                *samples_written += write_count;
+                                            }
+                                        }
+                                    }
+                                    Err(e) => warn(Component::Audio, &format!("🎙️ [CALLBACK-{}] Failed to write HDF5 samples: {}", callback_num, e)),
+                                }
+                            }
+                        }
                     },
                     |err| error(Component::Audio, &format!("🎙️ [STREAM ERROR] {}", err)),
                     None,
                 )
             }
-            _ => return Err("Unsupported sample format".to_string()),
+                    _ => return Err("Unsupported sample format".to_string()),
+                }
+                .map_err(|e| format!("Failed to build stream: {}", e))?;
+                info(Component::Audio, "🎙️ [RECORDER] Stream built successfully");
+
+                // Start the stream
+                info(Component::Audio, "🎙️ [RECORDER] Starting CPAL stream");
+                stream.play().map_err(|e| format!("Failed to start stream: {}", e))?;
+                info(Component::Audio, "🎙️ [RECORDER] CPAL stream started successfully");
+
+                Ok((stream, wav_spec.sample_rate, wav_spec.channels))
+            })();
+
+            match build_result {
+                Ok((stream, sample_rate, channels)) => {
+                    let _ = ready_tx.send(Ok((sample_rate, channels)));
+
+                    // Park here for the recording's lifetime: `pause`/`resume`
+                    // control the still-playing stream without touching the
+                    // writer, and a `Stop` (or the sender being dropped)
+                    // drops `stream` when this thread exits, tearing the
+                    // CPAL stream down cleanly instead of leaking it.
+                    loop {
+                        match cmd_rx.recv() {
+                            Ok(StreamCommand::Pause) => {
+                                if let Err(e) = stream.pause() {
+                                    warn(Component::Audio, &format!("🎙️ [RECORDER] Failed to pause stream: {}", e));
+                                }
+                            }
+                            Ok(StreamCommand::Resume) => {
+                                if let Err(e) = stream.play() {
+                                    warn(Component::Audio, &format!("🎙️ [RECORDER] Failed to resume stream: {}", e));
+                                }
+                            }
+                            Ok(StreamCommand::Stop) | Err(_) => break,
+                        }
+                    }
+                    drop(stream);
+                    info(Component::Audio, "🎙️ [RECORDER] Stream thread exiting, stream dropped");
+                }
+                Err(e) => {
+                    let _ = ready_tx.send(Err(e));
+                }
+            }
+        });
+
+        // Block until the stream thread has either started playing or
+        // failed to, so this call keeps returning a synchronous `Result`
+        // exactly like it did when the stream was built on this thread.
+        match ready_rx.recv() {
+            Ok(Ok((sample_rate, channels))) => {
+                *self.record_tx.lock().unwrap() = Some(cmd_tx);
+                *self.record_thread.lock().unwrap() = Some(handle);
+
+                self.is_recording.store(true, Ordering::SeqCst);
+                info(Component::Audio, "🎙️ [RECORDER] Set is_recording flag to true");
+
+                *self.state.lock().unwrap() = RecorderState::Recording {
+                    path: path.to_path_buf(),
+                    start_time: Instant::now(),
+                    samples_written: 0,
+                    sample_rate,
+                    channels,
+                };
+                info(Component::Audio, &format!("🎙️ [RECORDER] Updated state to Recording with sample_rate: {}, channels: {}",
+                    sample_rate, channels));
+
+                info(Component::Audio, "🎙️ [RECORDER] ========== RECORDING STARTED ==========");
+                Ok(())
+            }
+            Ok(Err(e)) => Err(e),
+            Err(_) => Err("Recording thread exited before it could start".to_string()),
         }
-        .map_err(|e| format!("Failed to build stream: {}", e))?;
-        info(Component::Audio, "🎙️ [RECORDER] Stream built successfully");
+    }
 
-        // Start the stream
-        info(Component::Audio, "🎙️ [RECORDER] Starting CPAL stream");
-        stream.play().map_err(|e| format!("Failed to start stream: {}", e))?;
-        info(Component::Audio, "🎙️ [RECORDER] CPAL stream started successfully");
+    /// Fast path for `start_recording` when pre-roll is armed: reuses the
+    /// already-running stream instead of building a new one, creating the
+    /// WAV writer from the format `enable_preroll` already negotiated and
+    /// draining the pre-roll buffer into it before handing off to the live
+    /// callback.
+    ///
+    /// The drain and the `is_recording` flip happen while holding both
+    /// `preroll` and `writer`'s locks, so the armed callback (which only
+    /// ever `try_lock`s them) can't observe a writer with no pre-roll
+    /// audio in it, or write a live sample into a writer that isn't
+    /// installed yet.
+    fn start_recording_armed(&self, path: &Path) -> Result<(), String> {
+        info(Component::Audio, "🎙️ [PREROLL] Starting recording on armed stream");
 
-        // IMPORTANT: We leak the stream to keep it alive
-        // This is a simple solution to maintain Send+Sync compatibility
-        // The stream will continue running until stop_recording is called
-        info(Component::Audio, "🎙️ [RECORDER] Leaking stream to keep it alive (will be cleaned on process exit)");
-        std::mem::forget(stream);
+        let (sample_rate, channels, sample_format) = {
+            let preroll_guard = self.preroll.lock().unwrap();
+            let buf = preroll_guard.as_ref().ok_or_else(|| "Pre-roll buffer missing despite armed stream".to_string())?;
+            (buf.sample_rate, buf.channels, buf.sample_format)
+        };
+
+        let wav_spec = WavSpec {
+            channels,
+            sample_rate,
+            bits_per_sample: match sample_format {
+                cpal::SampleFormat::I16 => 16,
+                cpal::SampleFormat::F32 => 32,
+                _ => return Err("Unsupported sample format".to_string()),
+            },
+            sample_format: match sample_format {
+                cpal::SampleFormat::I16 => hound::SampleFormat::Int,
+                cpal::SampleFormat::F32 => hound::SampleFormat::Float,
+                _ => return Err("Unsupported sample format".to_string()),
+            },
+        };
+
+        let mut writer = WavWriter::create(path, wav_spec)
+            .map_err(|e| format!("Failed to create WAV file: {}", e))?;
+
+        let drained = {
+            let mut preroll_guard = self.preroll.lock().unwrap();
+            let mut writer_guard = self.writer.lock().unwrap();
+
+            let drained = match preroll_guard.as_mut() {
+                Some(buf) => buf.drain_into(&mut writer)?,
+                None => 0,
+            };
+
+            *writer_guard = Some(writer);
+            self.total_samples.store(drained, Ordering::SeqCst);
+            self.max_rms_bits.store(0.0f32.to_bits(), Ordering::SeqCst);
+            self.is_recording.store(true, Ordering::SeqCst);
+            drained
+        };
+
+        info(Component::Audio, &format!("🎙️ [PREROLL] Drained {} pre-roll samples into new recording", drained));
 
-        // Update state
-        self.is_recording.store(true, Ordering::SeqCst);
-        info(Component::Audio, "🎙️ [RECORDER] Set is_recording flag to true");
-        
-        // Note: writer is already in self.writer, no need to move it again
-        
         *self.state.lock().unwrap() = RecorderState::Recording {
             path: path.to_path_buf(),
             start_time: Instant::now(),
-            samples_written: 0,
+            samples_written: drained,
             sample_rate: wav_spec.sample_rate,
             channels: wav_spec.channels,
         };
-        info(Component::Audio, &format!("🎙️ [RECORDER] Updated state to Recording with sample_rate: {}, channels: {}", 
-            wav_spec.sample_rate, wav_spec.channels));
 
-        info(Component::Audio, "🎙️ [RECORDER] ========== RECORDING STARTED ==========");
+        info(Component::Audio, "🎙️ [PREROLL] ========== RECORDING STARTED (armed) ==========");
         Ok(())
     }
 
     /// Stop recording and return info about the recording
-    pub fn stop_recording(&self) -> Result<RecordingInfo, String> {
+    pub fn stop_recording(&self) -> Result<RecordingInfo, StopRecordingError> {
         info(Component::Audio, "🎙️ [RECORDER] ========== STOP RECORDING ==========");
         
         // Log current counters
@@ -317,7 +1125,7 @@ impl SimpleCpalRecorder {
                 },
                 RecorderState::Idle => {
                     warn(Component::Audio, "🎙️ [RECORDER] Not recording, cannot stop");
-                    return Err("Not recording".to_string());
+                    return Err(StopRecordingError::NotRecording);
                 },
             }
         };
@@ -326,15 +1134,33 @@ impl SimpleCpalRecorder {
         info(Component::Audio, "🎙️ [RECORDER] Setting is_recording flag to false");
         self.is_recording.store(false, Ordering::SeqCst);
 
-        // Finalize the WAV file
-        info(Component::Audio, "🎙️ [RECORDER] Finalizing WAV file");
+        // If this recording owns a dedicated stream thread (i.e. it wasn't
+        // reusing the always-on preroll stream), tell it to stop and wait
+        // for it to actually drop the `Stream` before finalizing the
+        // writer below, so nothing can write to it after it's closed.
+        if let Some(tx) = self.record_tx.lock().unwrap().take() {
+            let _ = tx.send(StreamCommand::Stop);
+        }
+        if let Some(handle) = self.record_thread.lock().unwrap().take() {
+            let _ = handle.join();
+        }
+
+        // Finalize whichever backend this recording used
+        info(Component::Audio, "🎙️ [RECORDER] Finalizing recording");
         if let Some(writer) = self.writer.lock().unwrap().take() {
-            info(Component::Audio, "🎙️ [RECORDER] Writer found, calling finalize()");
+            info(Component::Audio, "🎙️ [RECORDER] WAV writer found, calling finalize()");
             writer.finalize().map_err(|e| {
                 error(Component::Audio, &format!("🎙️ [RECORDER] Failed to finalize WAV: {}", e));
-                format!("Failed to finalize WAV: {}", e)
+                StopRecordingError::Io(format!("Failed to finalize WAV: {}", e))
             })?;
             info(Component::Audio, "🎙️ [RECORDER] WAV file finalized successfully");
+        } else if let Some(recorder) = self.hdf5_writer.lock().unwrap().take() {
+            info(Component::Audio, "🎙️ [RECORDER] HDF5 recorder found, calling finalize()");
+            recorder.finalize().map_err(|e| {
+                error(Component::Audio, &format!("🎙️ [RECORDER] Failed to finalize HDF5: {}", e));
+                StopRecordingError::Io(format!("Failed to finalize HDF5: {}", e))
+            })?;
+            info(Component::Audio, "🎙️ [RECORDER] HDF5 file finalized successfully");
         } else {
             warn(Component::Audio, "🎙️ [RECORDER] No writer found to finalize!");
         }
@@ -342,8 +1168,37 @@ impl SimpleCpalRecorder {
         // Reset state
         *self.state.lock().unwrap() = RecorderState::Idle;
         info(Component::Audio, "🎙️ [RECORDER] State reset to Idle");
-        
-        info(Component::Audio, &format!("🎙️ [RECORDER] Recording info - path: {:?}, duration: {:.2}s, samples: {}", 
+
+        // Borrowing lasprs' behavior of not keeping files that captured
+        // nothing useful: discard (delete) the file we just finalized if it
+        // never received a sample, or if it fails the configured policy.
+        let policy = *self.discard_policy.lock().unwrap();
+        let total_samples_written = self.total_samples.load(Ordering::SeqCst);
+        let discard_reason = if total_samples_written == 0 {
+            Some(DiscardReason::NoSamplesWritten)
+        } else if policy
+            .min_duration
+            .is_some_and(|min_duration| Duration::from_secs_f64(recording_info.duration_seconds) < min_duration)
+        {
+            Some(DiscardReason::ShorterThanMinDuration)
+        } else if policy
+            .min_rms_level
+            .is_some_and(|min_rms_level| f32::from_bits(self.max_rms_bits.load(Ordering::SeqCst)) < min_rms_level)
+        {
+            Some(DiscardReason::QuieterThanMinLevel)
+        } else {
+            None
+        };
+
+        if let Some(reason) = discard_reason {
+            warn(Component::Audio, &format!("🎙️ [RECORDER] Discarding recording at {:?} ({:?})", recording_info.path, reason));
+            if let Err(e) = std::fs::remove_file(&recording_info.path) {
+                warn(Component::Audio, &format!("🎙️ [RECORDER] Failed to remove discarded file: {}", e));
+            }
+            return Err(StopRecordingError::Discarded { reason, path: recording_info.path });
+        }
+
+        info(Component::Audio, &format!("🎙️ [RECORDER] Recording info - path: {:?}, duration: {:.2}s, samples: {}",
             recording_info.path, recording_info.duration_seconds, recording_info.duration_samples));
         info(Component::Audio, "🎙️ [RECORDER] ========== RECORDING STOPPED ==========");
 
@@ -355,10 +1210,53 @@ impl SimpleCpalRecorder {
         self.is_recording.load(Ordering::SeqCst)
     }
 
-    /// Get current audio level (placeholder for now)
+    /// Pauses the dedicated stream thread's `Stream` (via `stream.pause()`)
+    /// without closing the WAV writer, so a later `resume()` continues
+    /// writing the same file. Only available for a recording started on its
+    /// own thread — not one reusing the always-on preroll stream.
+    pub fn pause(&self) -> Result<(), String> {
+        let guard = self.record_tx.lock().unwrap();
+        match guard.as_ref() {
+            Some(tx) => tx
+                .send(StreamCommand::Pause)
+                .map_err(|_| "Recording thread is no longer running".to_string()),
+            None => Err("Not recording on a dedicated stream thread".to_string()),
+        }
+    }
+
+    /// Resumes a stream previously paused with `pause()`.
+    pub fn resume(&self) -> Result<(), String> {
+        let guard = self.record_tx.lock().unwrap();
+        match guard.as_ref() {
+            Some(tx) => tx
+                .send(StreamCommand::Resume)
+                .map_err(|_| "Recording thread is no longer running".to_string()),
+            None => Err("Not recording on a dedicated stream thread".to_string()),
+        }
+    }
+
+    /// Get current audio level (smoothed RMS, in `[0.0, 1.0]`)
     pub fn get_audio_level(&self) -> f32 {
-        // TODO: Implement if needed
-        0.0
+        self.get_rms_level()
+    }
+
+    /// Current smoothed peak (max abs sample) level, in `[0.0, 1.0]`.
+    pub fn get_peak_level(&self) -> f32 {
+        f32::from_bits(self.peak_level_bits.load(Ordering::SeqCst))
+    }
+
+    /// Current smoothed RMS level, in `[0.0, 1.0]`.
+    pub fn get_rms_level(&self) -> f32 {
+        f32::from_bits(self.rms_level_bits.load(Ordering::SeqCst))
+    }
+
+    /// Current smoothed RMS level in dBFS, clamped at `LEVEL_NOISE_FLOOR_DB`.
+    pub fn get_level_db(&self) -> f32 {
+        let rms = self.get_rms_level();
+        if rms <= 0.0 {
+            return LEVEL_NOISE_FLOOR_DB;
+        }
+        (20.0 * rms.log10()).max(LEVEL_NOISE_FLOOR_DB)
     }
 }
 
@@ -369,8 +1267,4 @@ impl Drop for SimpleCpalRecorder {
             let _ = self.stop_recording();
         }
     }
-}
-
-// Mark as Send + Sync since we don't store the Stream directly
-unsafe impl Send for SimpleCpalRecorder {}
-unsafe impl Sync for SimpleCpalRecorder {}
\ No newline at end of file
+}
\ No newline at end of file
@@ -4,7 +4,13 @@ use std::fs::File;
 use std::io::BufWriter;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+/// How far a `write_samples` call's wall-clock gap from the previous call
+/// can exceed the previous chunk's audio duration before it counts as a
+/// discontinuity, as a fraction of that chunk's duration. Generous enough to
+/// absorb normal scheduling jitter without masking a real gap.
+const DISCONTINUITY_TOLERANCE_FACTOR: f64 = 0.2;
 
 /// Simplified, high-performance audio recorder that writes directly to a single file
 /// 
@@ -22,6 +28,33 @@ pub struct SimpleAudioRecorder {
     spec: WavSpec,
     /// Cleanup guard ensures resources are freed
     _cleanup_guard: CleanupGuard,
+    /// Xrun/discontinuity and CPU-headroom tracking across `write_samples` calls
+    write_stats: Arc<Mutex<WriteStats>>,
+    /// A spare [`ScratchBuffer`] handed back with `return_scratch_buffer`,
+    /// so the next session's caller can grab it via `take_scratch_buffer`
+    /// instead of allocating a fresh one.
+    scratch_pool: Arc<Mutex<Option<ScratchBuffer>>>,
+}
+
+/// Tracks whether `write_samples` is keeping up with the audio it's being
+/// handed, and how much CPU headroom it has while doing so.
+#[derive(Debug, Clone, Default)]
+struct WriteStats {
+    /// Wall-clock arrival time of the most recent `write_samples` call.
+    last_arrival: Option<Instant>,
+    /// Audio duration of the most recent chunk, used to judge whether the
+    /// *next* call's arrival gap looks like a discontinuity.
+    last_chunk_audio_duration: Duration,
+    /// Number of calls whose arrival gap exceeded the previous chunk's
+    /// audio duration by more than [`DISCONTINUITY_TOLERANCE_FACTOR`].
+    discontinuity_count: u32,
+    /// Total wall-clock time lost to discontinuities, in milliseconds.
+    lost_ms: f64,
+    /// Running sum of `1 - (time_spent_in_write / chunk_audio_duration)`
+    /// per call, so the average can be recovered by dividing by
+    /// `parked_samples`.
+    parked_ratio_sum: f64,
+    parked_samples: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -36,6 +69,28 @@ pub enum RecorderState {
     Error(String),
 }
 
+/// A reusable sample buffer for [`SimpleAudioRecorder::write_samples_into`].
+/// Filling it and writing it in a loop (instead of allocating a fresh
+/// `Vec<f32>` per chunk) avoids an allocation on every call to the hot
+/// `write_samples` path.
+#[derive(Debug, Default)]
+pub struct ScratchBuffer {
+    samples: Vec<f32>,
+}
+
+impl ScratchBuffer {
+    /// Allocates a buffer with room for `capacity` samples.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { samples: Vec::with_capacity(capacity) }
+    }
+
+    /// The `Vec` a caller fills with the next chunk before calling
+    /// `write_samples_into`. Cleared (but not shrunk) after each write.
+    pub fn as_mut_vec(&mut self) -> &mut Vec<f32> {
+        &mut self.samples
+    }
+}
+
 pub struct CleanupGuard {
     cleanup_fn: Option<Box<dyn FnOnce() + Send>>,
 }
@@ -55,6 +110,16 @@ pub struct RecordingInfo {
     pub duration_seconds: f64,
     pub sample_rate: u32,
     pub channels: u16,
+    /// Number of `write_samples` calls whose wall-clock arrival gap
+    /// exceeded the previous chunk's audio duration by more than the
+    /// tolerance - i.e. a buffer underrun/gap on the producer side.
+    pub discontinuity_count: u32,
+    /// Total wall-clock time lost to discontinuities, in milliseconds.
+    pub lost_audio_ms: f64,
+    /// Average of `1 - (time_spent_in_write / chunk_audio_duration)` across
+    /// the session. Near 1.0 means plenty of CPU headroom while writing;
+    /// near 0 means the recorder is close to CPU-bound.
+    pub average_parked_ratio: f64,
 }
 
 impl SimpleAudioRecorder {
@@ -82,6 +147,8 @@ impl SimpleAudioRecorder {
             state: Arc::new(Mutex::new(RecorderState::Idle)),
             spec,
             _cleanup_guard: CleanupGuard { cleanup_fn: None },
+            write_stats: Arc::new(Mutex::new(WriteStats::default())),
+            scratch_pool: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -102,6 +169,12 @@ impl SimpleAudioRecorder {
         let writer = WavWriter::create(output_path, self.spec)
             .map_err(|e| format!("Failed to create WAV file: {}", e))?;
 
+        // Reset discontinuity/headroom tracking for the new session
+        {
+            let mut stats = self.write_stats.lock().map_err(|e| format!("Write stats lock error: {}", e))?;
+            *stats = WriteStats::default();
+        }
+
         // Update state atomically
         {
             let mut writer_guard = self.writer.lock().map_err(|e| format!("Writer lock error: {}", e))?;
@@ -159,20 +232,33 @@ impl SimpleAudioRecorder {
 
         let duration_seconds = stop_time.duration_since(start_time).as_secs_f64();
         let latency = stop_time.elapsed();
-        
+
+        let (discontinuity_count, lost_audio_ms, average_parked_ratio) = {
+            let stats = self.write_stats.lock().map_err(|e| format!("Write stats lock error: {}", e))?;
+            let average_parked_ratio = if stats.parked_samples > 0 {
+                stats.parked_ratio_sum / stats.parked_samples as f64
+            } else {
+                0.0
+            };
+            (stats.discontinuity_count, stats.lost_ms, average_parked_ratio)
+        };
+
         let recording_info = RecordingInfo {
             path: path.clone(),
             duration_samples: samples_written,
             duration_seconds,
             sample_rate: self.spec.sample_rate,
             channels: self.spec.channels,
+            discontinuity_count,
+            lost_audio_ms,
+            average_parked_ratio,
         };
 
         info(
             Component::Recording,
             &format!(
-                "✅ Recording stopped in {:?} - Duration: {:.2}s, Samples: {}, File: {:?}",
-                latency, duration_seconds, samples_written, path
+                "✅ Recording stopped in {:?} - Duration: {:.2}s, Samples: {}, File: {:?}, discontinuities: {}, lost: {:.1}ms, avg headroom: {:.2}",
+                latency, duration_seconds, samples_written, path, discontinuity_count, lost_audio_ms, average_parked_ratio
             ),
         );
 
@@ -182,6 +268,8 @@ impl SimpleAudioRecorder {
     /// Write audio samples to the recording file
     /// Optimized for real-time performance - should be called from audio thread
     pub fn write_samples(&self, samples: &[f32]) -> Result<(), String> {
+        let arrival_time = Instant::now();
+
         let mut writer_guard = self.writer.lock().map_err(|e| format!("Writer lock error: {}", e))?;
         let mut state_guard = self.state.lock().map_err(|e| format!("State lock error: {}", e))?;
 
@@ -190,6 +278,7 @@ impl SimpleAudioRecorder {
             return Ok(()); // Silently ignore if not recording
         }
 
+        let write_start = Instant::now();
         if let Some(ref mut writer) = *writer_guard {
             // Write samples efficiently
             for &sample in samples {
@@ -201,10 +290,75 @@ impl SimpleAudioRecorder {
                 *samples_written += samples.len() as u64;
             }
         }
+        let time_spent_in_write = write_start.elapsed();
+
+        drop(state_guard);
+        drop(writer_guard);
+
+        self.record_write_stats(arrival_time, samples.len(), time_spent_in_write);
 
         Ok(())
     }
 
+    /// Updates discontinuity and CPU-headroom tracking for one
+    /// `write_samples` call. See [`WriteStats`] for what each field means.
+    fn record_write_stats(&self, arrival_time: Instant, sample_count: usize, time_spent_in_write: Duration) {
+        let Ok(mut stats) = self.write_stats.lock() else {
+            return;
+        };
+
+        let chunk_audio_duration = Duration::from_secs_f64(sample_count as f64 / self.spec.sample_rate.max(1) as f64);
+
+        if let Some(last_arrival) = stats.last_arrival {
+            let gap = arrival_time.duration_since(last_arrival);
+            let expected = stats.last_chunk_audio_duration;
+            let tolerated = expected.mul_f64(1.0 + DISCONTINUITY_TOLERANCE_FACTOR);
+            if gap > tolerated {
+                stats.discontinuity_count += 1;
+                stats.lost_ms += (gap - expected).as_secs_f64() * 1000.0;
+            }
+        }
+
+        if !chunk_audio_duration.is_zero() {
+            let parked_ratio = 1.0 - (time_spent_in_write.as_secs_f64() / chunk_audio_duration.as_secs_f64());
+            stats.parked_ratio_sum += parked_ratio;
+            stats.parked_samples += 1;
+        }
+
+        stats.last_arrival = Some(arrival_time);
+        stats.last_chunk_audio_duration = chunk_audio_duration;
+    }
+
+    /// Writes `scratch`'s contents without allocating internally, then
+    /// clears it in place (retaining its capacity) so the caller can refill
+    /// and write the same buffer again next chunk instead of allocating a
+    /// fresh `Vec` every time.
+    pub fn write_samples_into(&self, scratch: &mut ScratchBuffer) -> Result<(), String> {
+        self.write_samples(&scratch.samples)?;
+        scratch.samples.clear();
+        Ok(())
+    }
+
+    /// Hands the caller a [`ScratchBuffer`] left over from a previous
+    /// session's `return_scratch_buffer` call, if one is available,
+    /// otherwise allocates a new one with room for `capacity_hint` samples.
+    pub fn take_scratch_buffer(&self, capacity_hint: usize) -> ScratchBuffer {
+        if let Ok(mut pool) = self.scratch_pool.lock() {
+            if let Some(buffer) = pool.take() {
+                return buffer;
+            }
+        }
+        ScratchBuffer::with_capacity(capacity_hint)
+    }
+
+    /// Returns a [`ScratchBuffer`] to the pool so the next session can reuse
+    /// its allocation via `take_scratch_buffer` instead of allocating anew.
+    pub fn return_scratch_buffer(&self, buffer: ScratchBuffer) {
+        if let Ok(mut pool) = self.scratch_pool.lock() {
+            *pool = Some(buffer);
+        }
+    }
+
     /// Get current recording state
     pub fn get_state(&self) -> Result<RecorderState, String> {
         let state_guard = self.state.lock().map_err(|e| format!("State lock error: {}", e))?;
@@ -0,0 +1,199 @@
+use std::f32::consts::PI;
+
+/// Length of the FFT window used for spectral rolloff estimation. Must be a
+/// power of two; 4096 gives ~11.7 Hz bins at 48 kHz, plenty to resolve a
+/// low-pass rolloff a few hundred Hz wide.
+pub const FFT_SIZE: usize = 4096;
+
+/// How far below the spectrum's peak bin (in dB) a bin's energy must fall
+/// before it's considered below the noise floor.
+const NOISE_FLOOR_DB: f32 = 40.0;
+
+/// Result of estimating a signal's effective bandwidth from its spectrum.
+#[derive(Debug, Clone)]
+pub struct SpectralEstimate {
+    /// Highest frequency bin (Hz) whose energy is above the noise floor.
+    pub rolloff_hz: f32,
+    /// Sample rate implied by `rolloff_hz` (roughly `2 * rolloff_hz`), equal
+    /// to `reported_rate` when the rolloff reaches all the way to Nyquist.
+    pub detected_sample_rate: u32,
+    /// Confidence derived from how sharp the rolloff is: a hard filter drops
+    /// energy within a couple of bins (high confidence); a gradual taper
+    /// doesn't (low confidence).
+    pub confidence: f32,
+    /// Human-readable summary, e.g. `"lowpass at 7.9 kHz"`.
+    pub description: String,
+}
+
+/// Estimate the effective capture bandwidth of `samples` (reported to have
+/// been captured at `reported_rate`) via FFT: window the most recent
+/// `FFT_SIZE` samples with a Hann window, compute the power spectrum, and
+/// find the highest frequency bin whose energy exceeds `NOISE_FLOOR_DB` below
+/// the spectrum's peak. Devices that silently capture at a lower rate than
+/// they report (a common Bluetooth/AirPods quirk) show up as energy dying out
+/// well short of the reported Nyquist frequency.
+///
+/// Returns `None` if fewer than `FFT_SIZE` samples are available.
+pub fn estimate_bandwidth(samples: &[f32], reported_rate: u32) -> Option<SpectralEstimate> {
+    if samples.len() < FFT_SIZE || reported_rate == 0 {
+        return None;
+    }
+
+    let window = &samples[samples.len() - FFT_SIZE..];
+    let mut re: Vec<f32> = window
+        .iter()
+        .enumerate()
+        .map(|(i, &s)| {
+            let hann = 0.5 * (1.0 - (2.0 * PI * i as f32 / (FFT_SIZE as f32 - 1.0)).cos());
+            s * hann
+        })
+        .collect();
+    let mut im = vec![0.0f32; FFT_SIZE];
+
+    fft_radix2(&mut re, &mut im);
+
+    // Real input gives a symmetric spectrum; only the first half is useful.
+    let bins = FFT_SIZE / 2;
+    let power: Vec<f32> = (0..bins).map(|i| re[i] * re[i] + im[i] * im[i]).collect();
+
+    let peak = power.iter().cloned().fold(0.0f32, f32::max);
+    if peak <= 0.0 {
+        return None;
+    }
+    let peak_db = 10.0 * peak.log10();
+    let floor_db = peak_db - NOISE_FLOOR_DB;
+
+    let hz_per_bin = reported_rate as f32 / FFT_SIZE as f32;
+
+    // Walk down from Nyquist to find the highest bin still above the floor.
+    let rolloff_bin = power
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|&(_, &p)| p > 0.0 && 10.0 * p.log10() >= floor_db)
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let rolloff_hz = rolloff_bin as f32 * hz_per_bin;
+
+    // How sharp is the rolloff? Average the handful of bins just past the
+    // edge: a hard filter has already fallen many dB by then, a gradual
+    // taper hasn't.
+    let taper_bins = 5.min(bins.saturating_sub(rolloff_bin + 1));
+    let confidence = if taper_bins == 0 {
+        0.5
+    } else {
+        let above: f32 = power[rolloff_bin + 1..rolloff_bin + 1 + taper_bins]
+            .iter()
+            .sum::<f32>()
+            / taper_bins as f32;
+        let drop_db = if above > 0.0 {
+            peak_db - 10.0 * above.log10()
+        } else {
+            NOISE_FLOOR_DB * 2.0
+        };
+        (drop_db / (NOISE_FLOOR_DB * 2.0)).clamp(0.1, 0.95)
+    };
+
+    // Effective sample rate implied by the bandwidth (Nyquist = rate / 2).
+    // If the rolloff reaches (nearly) all the way to the reported Nyquist,
+    // treat the reported rate as correct rather than reporting a slightly
+    // lower one from bin quantization.
+    let nyquist_frac = rolloff_hz / (reported_rate as f32 / 2.0);
+    let detected_sample_rate = if nyquist_frac > 0.95 {
+        reported_rate
+    } else {
+        (2.0 * rolloff_hz).round() as u32
+    };
+
+    Some(SpectralEstimate {
+        rolloff_hz,
+        detected_sample_rate,
+        confidence,
+        description: format!("lowpass at {:.1} kHz", rolloff_hz / 1000.0),
+    })
+}
+
+/// In-place iterative radix-2 Cooley-Tukey FFT. `re`/`im` must have equal,
+/// power-of-two length. `pub(crate)` so [`super::spectral_vad`] can reuse it
+/// instead of depending on an external FFT crate for a second, near-identical
+/// use case.
+pub(crate) fn fft_radix2(re: &mut [f32], im: &mut [f32]) {
+    let n = re.len();
+    debug_assert!(n.is_power_of_two());
+
+    // Bit-reversal permutation.
+    let mut j = 0usize;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let ang = -2.0 * PI / len as f32;
+        let wr = ang.cos();
+        let wi = ang.sin();
+        let half = len / 2;
+        let mut i = 0;
+        while i < n {
+            let mut cur_wr = 1.0f32;
+            let mut cur_wi = 0.0f32;
+            for k in 0..half {
+                let u_re = re[i + k];
+                let u_im = im[i + k];
+                let v_re = re[i + k + half] * cur_wr - im[i + k + half] * cur_wi;
+                let v_im = re[i + k + half] * cur_wi + im[i + k + half] * cur_wr;
+
+                re[i + k] = u_re + v_re;
+                im[i + k] = u_im + v_im;
+                re[i + k + half] = u_re - v_re;
+                im[i + k + half] = u_im - v_im;
+
+                let next_wr = cur_wr * wr - cur_wi * wi;
+                let next_wi = cur_wr * wi + cur_wi * wr;
+                cur_wr = next_wr;
+                cur_wi = next_wi;
+            }
+            i += len;
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_rolloff_on_bandlimited_tone() {
+        // A 2 kHz sine "recorded" at a reported 48 kHz should have its
+        // rolloff land near 2 kHz, not near the reported Nyquist.
+        let reported_rate = 48_000;
+        let tone_hz = 2_000.0f32;
+        let samples: Vec<f32> = (0..FFT_SIZE)
+            .map(|i| (2.0 * PI * tone_hz * i as f32 / reported_rate as f32).sin())
+            .collect();
+
+        let estimate = estimate_bandwidth(&samples, reported_rate).expect("enough samples");
+        assert!(
+            (estimate.rolloff_hz - tone_hz).abs() < 200.0,
+            "rolloff_hz={} expected near {}",
+            estimate.rolloff_hz,
+            tone_hz
+        );
+    }
+
+    #[test]
+    fn insufficient_samples_returns_none() {
+        let samples = vec![0.0f32; FFT_SIZE - 1];
+        assert!(estimate_bandwidth(&samples, 48_000).is_none());
+    }
+}
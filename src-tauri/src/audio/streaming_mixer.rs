@@ -0,0 +1,204 @@
+/// Multi-source audio mixer for meeting-mode transcription.
+///
+/// Ported from moa's `AudioMixer`/`AudioSource`/`ClockedQueue` design: each
+/// input device (e.g. microphone + system/loopback output) runs its own
+/// `StreamingAudioRecorder16kHz` and feeds samples into a per-source
+/// `ClockedQueue`. A dedicated mixing thread wakes on a fixed tick, pulls one
+/// frame-aligned block from every source, sums them with per-source gain and
+/// a soft-clip limiter, and forwards the mixed frame through the same
+/// `StreamingSampleCallback` a single-source recorder would use. A source
+/// that falls behind contributes silence for the missing span rather than
+/// stalling the mix, so drift between devices never desyncs the others.
+
+use crate::audio::streaming_recorder_16khz::{
+    StreamingAudioRecorder16kHz, StreamingRecorderConfig, StreamingSampleCallback,
+};
+use crate::logger::{info, Component};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Frame size the mixer pulls from each source per tick: 20ms at 16kHz.
+const MIX_FRAME_SAMPLES: usize = 320;
+/// How often the mixing thread wakes to pull and emit a mixed frame.
+const MIX_TICK: Duration = Duration::from_millis(20);
+
+/// Per-source configuration: which device to record and how loud it is in
+/// the mix.
+#[derive(Debug, Clone)]
+pub struct AudioSourceConfig {
+    pub recorder_config: StreamingRecorderConfig,
+    pub gain: f32,
+}
+
+impl Default for AudioSourceConfig {
+    fn default() -> Self {
+        Self {
+            recorder_config: StreamingRecorderConfig::default(),
+            gain: 1.0,
+        }
+    }
+}
+
+/// Buffers samples pushed by one source's capture callback in arrival order,
+/// so the mixer can pull frame-aligned blocks independent of each source's
+/// own callback cadence.
+struct ClockedQueue {
+    samples: VecDeque<f32>,
+}
+
+impl ClockedQueue {
+    fn new() -> Self {
+        Self {
+            samples: VecDeque::new(),
+        }
+    }
+
+    fn push(&mut self, frame: &[f32]) {
+        self.samples.extend(frame.iter().copied());
+    }
+
+    /// Pop up to `n` samples, oldest first, padding the tail with silence if
+    /// fewer than `n` are available. A lagging source falls back to silence
+    /// instead of holding up the mix.
+    fn pull(&mut self, n: usize) -> Vec<f32> {
+        let take = self.samples.len().min(n);
+        let mut out: Vec<f32> = self.samples.drain(..take).collect();
+        out.resize(n, 0.0);
+        out
+    }
+}
+
+/// One mixed-in audio source: a recorder plus its clocked queue and gain.
+struct AudioSource {
+    recorder: StreamingAudioRecorder16kHz,
+    queue: Arc<Mutex<ClockedQueue>>,
+    gain: f32,
+}
+
+/// Mixes N independently-clocked audio sources (microphone, system/loopback
+/// output, ...) into a single 16kHz mono stream, exposed through the same
+/// `StreamingSampleCallback` interface a lone `StreamingAudioRecorder16kHz`
+/// uses, so it drops into `StreamingTranscriptionPipeline` as a stand-in.
+pub struct StreamingAudioMixer {
+    sources: Vec<AudioSource>,
+    mix_thread: Option<thread::JoinHandle<()>>,
+    running: Arc<Mutex<bool>>,
+    sample_callback: Arc<Mutex<Option<StreamingSampleCallback>>>,
+}
+
+impl StreamingAudioMixer {
+    pub fn new(source_configs: Vec<AudioSourceConfig>) -> Self {
+        let sources = source_configs
+            .into_iter()
+            .map(|cfg| AudioSource {
+                recorder: StreamingAudioRecorder16kHz::new(cfg.recorder_config),
+                queue: Arc::new(Mutex::new(ClockedQueue::new())),
+                gain: cfg.gain,
+            })
+            .collect();
+
+        Self {
+            sources,
+            mix_thread: None,
+            running: Arc::new(Mutex::new(false)),
+            sample_callback: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Initializes every source recorder and wires each one's capture
+    /// callback into its own `ClockedQueue`.
+    pub fn init(&mut self) -> Result<(), String> {
+        for source in &mut self.sources {
+            source.recorder.init()?;
+
+            let queue = source.queue.clone();
+            source.recorder.set_sample_callback(Some(Arc::new(move |samples: &[f32]| {
+                if let Ok(mut queue) = queue.lock() {
+                    queue.push(samples);
+                }
+            })))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn set_sample_callback(&self, callback: Option<StreamingSampleCallback>) -> Result<(), String> {
+        *self.sample_callback.lock().unwrap() = callback;
+        Ok(())
+    }
+
+    pub fn start_recording(&mut self) -> Result<(), String> {
+        for source in &self.sources {
+            source.recorder.start_recording()?;
+        }
+
+        *self.running.lock().unwrap() = true;
+
+        let running = self.running.clone();
+        let sample_callback = self.sample_callback.clone();
+        let sources: Vec<(Arc<Mutex<ClockedQueue>>, f32)> = self
+            .sources
+            .iter()
+            .map(|s| (s.queue.clone(), s.gain))
+            .collect();
+
+        self.mix_thread = Some(thread::spawn(move || {
+            info(Component::Recording, "Starting multi-source audio mixer");
+
+            while *running.lock().unwrap() {
+                let mut mixed = vec![0.0f32; MIX_FRAME_SAMPLES];
+
+                for (queue, gain) in &sources {
+                    let block = queue
+                        .lock()
+                        .map(|mut q| q.pull(MIX_FRAME_SAMPLES))
+                        .unwrap_or_else(|_| vec![0.0; MIX_FRAME_SAMPLES]);
+
+                    for (mixed_sample, source_sample) in mixed.iter_mut().zip(block.iter()) {
+                        *mixed_sample += source_sample * gain;
+                    }
+                }
+
+                for sample in &mut mixed {
+                    *sample = soft_clip(*sample);
+                }
+
+                if let Some(callback) = sample_callback.lock().unwrap().as_ref() {
+                    callback(&mixed);
+                }
+
+                thread::sleep(MIX_TICK);
+            }
+
+            info(Component::Recording, "Multi-source audio mixer stopped");
+        }));
+
+        Ok(())
+    }
+
+    pub fn stop_recording(&mut self) -> Result<(), String> {
+        *self.running.lock().unwrap() = false;
+
+        if let Some(handle) = self.mix_thread.take() {
+            let _ = handle.join();
+        }
+
+        for source in &self.sources {
+            source.recorder.stop_recording()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn is_recording(&self) -> bool {
+        *self.running.lock().unwrap()
+    }
+}
+
+/// Gentle tanh-based soft clip so summed sources don't hard-clip when they
+/// overlap at full volume.
+fn soft_clip(sample: f32) -> f32 {
+    sample.tanh()
+}
@@ -0,0 +1,109 @@
+//! Deterministic synthetic audio source for diagnostics tests that would
+//! otherwise require a live microphone (see
+//! `DiagnosticsService::test_simple_recording` and friends in
+//! `services::diagnostics`). Generates a known waveform via [`TestSignal`]
+//! at a requested sample rate and channel count, optionally resampling it
+//! through [`StreamingResampler`], and writes it to a WAV file so it can be
+//! fed straight into
+//! `analyze_audio_corruption` the same way a recorded file would be.
+
+use std::path::Path;
+
+use hound::{SampleFormat, WavSpec, WavWriter};
+
+use crate::audio::resampler::StreamingResampler;
+use crate::audio::signal_gen::{SignalKind, TestSignal};
+
+/// Generates `duration_secs` of `kind` at `generated_sample_rate`, resamples
+/// it to `output_sample_rate` via [`StreamingResampler`] when the two
+/// differ (mirroring the resampler path a real device delivering a
+/// different native rate than requested would go through), interleaves it
+/// to `channels`, and writes the result as a WAV file at `output_path`
+/// whose header matches `output_sample_rate`/`channels`.
+pub fn write_synthetic_wav(
+    output_path: &Path,
+    kind: SignalKind,
+    generated_sample_rate: u32,
+    output_sample_rate: u32,
+    channels: u16,
+    duration_secs: f32,
+    seed: u64,
+) -> Result<(), String> {
+    let samples = generate_resampled(kind, generated_sample_rate, output_sample_rate, duration_secs, seed);
+    write_wav(output_path, output_sample_rate, channels, &interleave(&samples, channels))
+}
+
+/// Writes `duration_secs` of `kind`, generated at `actual_sample_rate`, into
+/// a WAV file whose header instead claims `header_sample_rate` - no
+/// resampling is performed, so this is the synthetic, deterministic
+/// equivalent of what `corrupt_wav_sample_rate` reproduces post-hoc on a
+/// real recording: a file whose header and data disagree about the rate.
+pub fn write_mismatched_header_wav(
+    output_path: &Path,
+    kind: SignalKind,
+    actual_sample_rate: u32,
+    header_sample_rate: u32,
+    duration_secs: f32,
+    seed: u64,
+) -> Result<(), String> {
+    let mut signal = TestSignal::new(kind, actual_sample_rate, seed);
+    let sample_count = (actual_sample_rate as f32 * duration_secs).round() as usize;
+    let samples = signal.generate(sample_count);
+    write_wav(output_path, header_sample_rate, 1, &samples)
+}
+
+/// Generates `duration_secs` of `kind` at `generated_sample_rate` and, if it
+/// differs from `output_sample_rate`, runs it through [`StreamingResampler`]
+/// in a single call.
+fn generate_resampled(
+    kind: SignalKind,
+    generated_sample_rate: u32,
+    output_sample_rate: u32,
+    duration_secs: f32,
+    seed: u64,
+) -> Vec<f32> {
+    let mut signal = TestSignal::new(kind, generated_sample_rate, seed);
+    let sample_count = (generated_sample_rate as f32 * duration_secs).round() as usize;
+    let generated = signal.generate(sample_count);
+
+    if generated_sample_rate == output_sample_rate {
+        generated
+    } else {
+        let mut resampler = StreamingResampler::new(generated_sample_rate, output_sample_rate);
+        resampler.resample(&generated)
+    }
+}
+
+/// Duplicates a mono buffer across `channels` interleaved channels (a no-op
+/// copy for `channels == 1`).
+fn interleave(mono: &[f32], channels: u16) -> Vec<f32> {
+    if channels <= 1 {
+        return mono.to_vec();
+    }
+    let mut out = Vec::with_capacity(mono.len() * channels as usize);
+    for &sample in mono {
+        for _ in 0..channels {
+            out.push(sample);
+        }
+    }
+    out
+}
+
+fn write_wav(output_path: &Path, sample_rate: u32, channels: u16, samples: &[f32]) -> Result<(), String> {
+    let spec = WavSpec {
+        channels,
+        sample_rate,
+        bits_per_sample: 32,
+        sample_format: SampleFormat::Float,
+    };
+    let mut writer =
+        WavWriter::create(output_path, spec).map_err(|e| format!("Failed to create synthetic WAV: {}", e))?;
+    for &sample in samples {
+        writer
+            .write_sample(sample)
+            .map_err(|e| format!("Failed to write synthetic sample: {}", e))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| format!("Failed to finalize synthetic WAV: {}", e))
+}
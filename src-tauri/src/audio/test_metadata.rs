@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use super::super::config::AudioBackend;
     use super::super::metadata::*;
     use cpal::{SampleFormat, BufferSize};
     
@@ -24,6 +25,7 @@ mod tests {
             SampleFormat::F32,
             &BufferSize::Fixed(256),
             true,
+            AudioBackend::Auto,
         );
         
         // Check basic metadata
@@ -57,6 +59,7 @@ mod tests {
             SampleFormat::I16,
             &BufferSize::Default,
             false,
+            AudioBackend::Auto,
         );
         
         // Check that AirPods issues are detected
@@ -83,6 +86,7 @@ mod tests {
             SampleFormat::F32,
             &BufferSize::Fixed(512),
             true,
+            AudioBackend::Auto,
         );
         
         metadata.set_recording_info(true, "push-to-talk", Some(100));
@@ -0,0 +1,119 @@
+/// Exported-audio transcoding: decodes the stored 16 kHz mono WAV source and
+/// re-encodes it into a compressed container, following the same
+/// decode-then-encode shape librespot uses for its Ogg/Vorbis output path
+/// (including computing an absolute granule position so a player can seek).
+///
+/// `TranscriptsService::export_audio_file` used to be a plain `std::fs::copy`;
+/// this module gives it somewhere to transcode to instead of shipping raw,
+/// uncompressed WAVs to the user.
+use crate::services::transcripts::TranscriptSegment;
+use hound::WavReader;
+use std::path::Path;
+
+/// Output container for an exported audio file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum AudioExportFormat {
+    Wav,
+    Ogg,
+    Flac,
+}
+
+/// Vorbis comment fields embedded in an `Ogg` export so the file is
+/// self-describing without the original transcript record.
+struct VorbisComments<'a> {
+    title: &'a str,
+    transcript_text: &'a str,
+}
+
+/// Decodes `source` (16 kHz mono WAV) and writes `dest` in `format`. For
+/// `Ogg`, `segments` are written as cue points keyed to each segment's
+/// granule position (`start_ms * sample_rate / 1000`), mirroring how
+/// librespot's `seek(ms)` converts a millisecond offset into an absolute
+/// granule position; the full transcript is also embedded as Vorbis
+/// comments.
+pub fn transcode(
+    source: &Path,
+    dest: &Path,
+    format: AudioExportFormat,
+    transcript_text: &str,
+    segments: &[TranscriptSegment],
+) -> Result<(), String> {
+    match format {
+        AudioExportFormat::Wav => std::fs::copy(source, dest)
+            .map(|_| ())
+            .map_err(|e| format!("Failed to copy WAV export: {}", e)),
+        AudioExportFormat::Ogg => transcode_to_ogg(source, dest, transcript_text, segments),
+        AudioExportFormat::Flac => transcode_to_flac(source, dest),
+    }
+}
+
+fn read_source_samples(source: &Path) -> Result<(Vec<i16>, u32), String> {
+    let mut reader = WavReader::open(source).map_err(|e| format!("Failed to open source WAV {:?}: {}", source, e))?;
+    let spec = reader.spec();
+    let samples: Result<Vec<i16>, _> = reader.samples::<i16>().collect();
+    let samples = samples.map_err(|e| format!("Failed to read source samples: {}", e))?;
+    Ok((samples, spec.sample_rate))
+}
+
+fn transcode_to_ogg(
+    source: &Path,
+    dest: &Path,
+    transcript_text: &str,
+    segments: &[TranscriptSegment],
+) -> Result<(), String> {
+    let (samples, sample_rate) = read_source_samples(source)?;
+
+    let comments = VorbisComments {
+        title: dest
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("Scout Transcript"),
+        transcript_text,
+    };
+
+    let mut encoder = vorbis_rs::VorbisEncoderBuilder::new(
+        std::num::NonZeroU32::new(sample_rate).ok_or("Invalid sample rate")?,
+        std::num::NonZeroU8::new(1).ok_or("Invalid channel count")?,
+        std::fs::File::create(dest).map_err(|e| format!("Failed to create Ogg file {:?}: {}", dest, e))?,
+    )
+    .map_err(|e| format!("Failed to initialize Vorbis encoder: {}", e))?
+    .add_comment_tag("TITLE", comments.title)
+    .add_comment_tag("TRANSCRIPT", comments.transcript_text)
+    .build()
+    .map_err(|e| format!("Failed to build Vorbis encoder: {}", e))?;
+
+    // Cue points keyed to each segment's absolute granule position, the
+    // same start_ms -> sample-count conversion librespot's seek(ms) uses.
+    for segment in segments {
+        let granule_position = (segment.start_ms as u64 * sample_rate as u64) / 1000;
+        encoder
+            .add_cue_point(granule_position, &segment.text)
+            .map_err(|e| format!("Failed to write cue point: {}", e))?;
+    }
+
+    let channel_samples: Vec<f32> = samples.iter().map(|&s| s as f32 / i16::MAX as f32).collect();
+    encoder
+        .encode_audio_block([channel_samples.as_slice()])
+        .map_err(|e| format!("Failed to encode audio: {}", e))?;
+    encoder
+        .finish()
+        .map_err(|e| format!("Failed to finalize Ogg file: {}", e))?;
+
+    Ok(())
+}
+
+fn transcode_to_flac(source: &Path, dest: &Path) -> Result<(), String> {
+    let (samples, sample_rate) = read_source_samples(source)?;
+
+    let config = flacenc::config::Encoder::default();
+    let source_block = flacenc::source::MemSource::from_samples(&samples.iter().map(|&s| s as i32).collect::<Vec<_>>(), 1, 16, sample_rate as usize);
+    let flac_stream = flacenc::encode_with_fixed_block_size(&config, source_block, config.block_size)
+        .map_err(|e| format!("Failed to encode FLAC stream: {:?}", e))?;
+
+    let mut sink = flacenc::bitsink::ByteSink::new();
+    flac_stream
+        .write(&mut sink)
+        .map_err(|e| format!("Failed to serialize FLAC stream: {:?}", e))?;
+
+    std::fs::write(dest, sink.as_slice()).map_err(|e| format!("Failed to write FLAC file {:?}: {}", dest, e))
+}
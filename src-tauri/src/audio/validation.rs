@@ -1,4 +1,5 @@
 use super::metadata::AudioPatternAnalysis;
+use super::spectral::{self, SpectralEstimate};
 use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
@@ -7,6 +8,20 @@ pub struct AudioFormatValidator {
     /// Sample buffer for analysis
     sample_buffer: VecDeque<f32>,
 
+    /// Rolling buffer of raw samples kept specifically for spectral
+    /// bandwidth estimation, which needs a longer, contiguous window than
+    /// `sample_buffer` (sized for quick per-callback checks) provides.
+    spectral_buffer: VecDeque<f32>,
+
+    /// Target size for `spectral_buffer`.
+    spectral_buffer_size: usize,
+
+    /// Result of the most recent spectral bandwidth estimate, if any, kept
+    /// around so callers can route `detected_sample_rate` through
+    /// `AudioMetadata::set_detected_sample_rate` after reading the pattern
+    /// analysis.
+    last_spectral_estimate: Option<SpectralEstimate>,
+
     /// Expected sample rate
     expected_sample_rate: u32,
 
@@ -55,8 +70,15 @@ impl AudioFormatValidator {
     pub fn new(expected_sample_rate: u32, expected_channels: u16) -> Self {
         let analysis_window_size = (expected_sample_rate as usize / 10).max(1024); // 100ms window or minimum 1024 samples
 
+        // A couple of seconds of audio, at least large enough for one
+        // `spectral::FFT_SIZE` window.
+        let spectral_capacity = (expected_sample_rate as usize * 2).max(spectral::FFT_SIZE);
+
         Self {
             sample_buffer: VecDeque::with_capacity(analysis_window_size * 2),
+            spectral_buffer: VecDeque::with_capacity(spectral_capacity),
+            spectral_buffer_size: spectral_capacity,
+            last_spectral_estimate: None,
             expected_sample_rate,
             expected_channels,
             callback_count: 0,
@@ -85,6 +107,15 @@ impl AudioFormatValidator {
             self.sample_buffer.push_back(sample);
         }
 
+        // Add samples to the longer-lived buffer spectral analysis draws its
+        // FFT window from.
+        for &sample in samples {
+            if self.spectral_buffer.len() >= self.spectral_buffer_size {
+                self.spectral_buffer.pop_front();
+            }
+            self.spectral_buffer.push_back(sample);
+        }
+
         // Calculate and track signal level
         let rms = calculate_rms(samples);
         if self.signal_levels.len() >= 100 {
@@ -124,8 +155,18 @@ impl AudioFormatValidator {
 
         let mut issues = Vec::new();
 
-        // 1. Sample rate validation through frequency analysis
-        if let Some(detected_rate) = self.estimate_sample_rate() {
+        // 1. Sample rate validation through frequency analysis. Prefer the
+        // spectral bandwidth estimate (accurate, but only available after
+        // `generate_pattern_analysis` has run over at least
+        // `spectral::FFT_SIZE` buffered samples) over the crude zero-crossing
+        // fallback, which can only ever confirm the expected rate.
+        let detected_rate = self
+            .last_spectral_estimate
+            .as_ref()
+            .map(|e| e.detected_sample_rate)
+            .or_else(|| self.estimate_sample_rate());
+
+        if let Some(detected_rate) = detected_rate {
             let rate_diff = (detected_rate as i32 - self.expected_sample_rate as i32).abs();
             if rate_diff > 1000 {
                 issues.push(ValidationInconsistency {
@@ -363,21 +404,40 @@ impl AudioFormatValidator {
         }
     }
 
-    /// Generate pattern analysis report
-    pub fn generate_pattern_analysis(&self) -> Option<AudioPatternAnalysis> {
+    /// Generate pattern analysis report. Runs a real spectral bandwidth
+    /// estimate over `spectral_buffer` when enough samples have accumulated,
+    /// caching the result so `last_detected_sample_rate` can route it through
+    /// `AudioMetadata::set_detected_sample_rate`; falls back to the cruder
+    /// zero-crossing classification when there isn't enough audio yet.
+    pub fn generate_pattern_analysis(&mut self) -> Option<AudioPatternAnalysis> {
         if self.signal_levels.is_empty() {
             return None;
         }
 
         let avg_signal_level =
             self.signal_levels.iter().sum::<f32>() / self.signal_levels.len() as f32;
+
+        let contiguous: Vec<f32> = self.spectral_buffer.iter().copied().collect();
+        self.last_spectral_estimate =
+            spectral::estimate_bandwidth(&contiguous, self.expected_sample_rate);
+
+        if let Some(ref estimate) = self.last_spectral_estimate {
+            return Some(AudioPatternAnalysis {
+                avg_signal_level,
+                frequency_content: estimate.description.clone(),
+                sample_rate_confidence: estimate.confidence,
+                detected_format: Some(format!("{} Hz effective", estimate.detected_sample_rate)),
+            });
+        }
+
+        // Not enough buffered audio yet for a spectral estimate - fall back
+        // to the rough zero-crossing-based classification.
         let avg_zero_crossings = if !self.zero_crossings.is_empty() {
             self.zero_crossings.iter().sum::<u32>() as f32 / self.zero_crossings.len() as f32
         } else {
             0.0
         };
 
-        // Simple frequency content classification
         let frequency_content = if avg_zero_crossings < 10.0 {
             "low_frequency".to_string()
         } else if avg_zero_crossings < 50.0 {
@@ -386,7 +446,6 @@ impl AudioFormatValidator {
             "high_frequency".to_string()
         };
 
-        // Confidence in sample rate detection (simplified)
         let sample_rate_confidence = if self
             .inconsistencies
             .iter()
@@ -401,10 +460,19 @@ impl AudioFormatValidator {
             avg_signal_level,
             frequency_content,
             sample_rate_confidence,
-            detected_format: None, // Could be enhanced with more sophisticated analysis
+            detected_format: None,
         })
     }
 
+    /// The sample rate and confidence from the most recent spectral estimate
+    /// computed by `generate_pattern_analysis`, if any, for callers to feed
+    /// into `AudioMetadata::set_detected_sample_rate`.
+    pub fn last_detected_sample_rate(&self) -> Option<(u32, f32)> {
+        self.last_spectral_estimate
+            .as_ref()
+            .map(|e| (e.detected_sample_rate, e.confidence))
+    }
+
     /// Get validation statistics
     pub fn get_validation_stats(&self) -> ValidationStats {
         let critical_count = self
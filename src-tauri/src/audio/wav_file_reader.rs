@@ -1,28 +1,70 @@
+use crate::audio::chunk_sink::{self, ChunkSink, XorWriter};
 use crate::logger::{debug, error, info, warn, Component};
-use hound::{WavReader, WavSpec};
+use crate::transcription::silero_vad::SileroVad;
+use crate::transport::encryption::NONCE_LEN;
+use hound::{SampleFormat, WavReader, WavSpec};
 use std::fs::File;
-use std::io::BufReader;
-use std::path::Path;
+use std::io::{BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+/// Speech-probability floor to enter a speech segment; see
+/// [`WavFileReader::extract_speech_segments`].
+const VAD_ENTER_THRESHOLD: f32 = 0.5;
+/// Speech-probability floor to remain inside a segment once it has started.
+/// Lower than the enter threshold so brief dips don't fragment one utterance.
+const VAD_EXIT_THRESHOLD: f32 = 0.35;
+/// Minimum accumulated speech before a trailing silence run is allowed to
+/// close the segment, so a single noisy frame can't produce a tiny segment.
+const VAD_MIN_SPEECH_MS: u32 = 250;
+/// Minimum run of below-`VAD_EXIT_THRESHOLD` frames before the segment is
+/// considered to have ended.
+const VAD_MIN_SILENCE_MS: u32 = 100;
+
 /// A file-based WAV reader that can read chunks from a growing WAV file
 /// This provides a clean separation between recording and transcription
 pub struct WavFileReader {
     file_path: std::path::PathBuf,
     /// WAV specification from the file header
     spec: Option<WavSpec>,
+    /// Absolute byte offset of the first byte of the `data` subchunk,
+    /// parsed once in `new()` so every `extract_chunk` call can seek
+    /// straight to a sample instead of re-scanning the header.
+    data_start: u64,
+    /// Bytes per single-channel sample (`bits_per_sample / 8`), also
+    /// cached from the header parse.
+    bytes_per_sample: u64,
     /// Current read position (in samples, not bytes)
     read_position: Arc<Mutex<usize>>,
     /// When we started monitoring this file
     start_time: Instant,
     /// Cached file size to detect growth
     last_known_size: Arc<Mutex<u64>>,
+    /// When set, the `data` subchunk is encrypted with a nonce-keyed XOR
+    /// keystream (see [`chunk_sink::XorWriter`]) and must be decrypted with
+    /// this key before the raw bytes are decoded into samples.
+    key: Option<Vec<u8>>,
+    /// The per-file nonce [`XorWriter`] prepended to the encrypted payload,
+    /// read back once at construction; `data_start` already points past it.
+    nonce: Option<[u8; NONCE_LEN]>,
 }
 
 impl WavFileReader {
     /// Create a new WAV file reader for the given file path
     pub fn new(file_path: &Path) -> Result<Self, String> {
+        Self::new_with_key(file_path, None)
+    }
+
+    /// Like [`Self::new`], but for a WAV file whose `data` subchunk was
+    /// written through a [`ChunkSink::Encrypted`] sink. The header stays in
+    /// the clear so `key` is only needed to decode sample bytes, not to
+    /// parse the spec.
+    pub fn new_encrypted(file_path: &Path, key: Vec<u8>) -> Result<Self, String> {
+        Self::new_with_key(file_path, Some(key))
+    }
+
+    fn new_with_key(file_path: &Path, key: Option<Vec<u8>>) -> Result<Self, String> {
         // Verify file exists (it should be created by AudioRecorder)
         if !file_path.exists() {
             return Err(format!("WAV file does not exist: {:?}", file_path));
@@ -30,21 +72,49 @@ impl WavFileReader {
 
         // Try to read WAV spec from file header
         let spec = Self::read_wav_spec(file_path)?;
-        
+        let mut data_start = Self::find_data_chunk_offset(file_path)?;
+        let bytes_per_sample = (spec.bits_per_sample / 8) as u64;
+
+        // An encrypted sink prepends its random per-file nonce as the first
+        // `NONCE_LEN` bytes of the `data` subchunk (see `chunk_sink::XorWriter`);
+        // read it back once here and advance `data_start` past it so every
+        // later `extract_chunk` offset lands on real encrypted audio bytes.
+        let nonce = if let Some(key) = &key {
+            if key.is_empty() {
+                None
+            } else {
+                let mut file = File::open(file_path)
+                    .map_err(|e| format!("Failed to open WAV file for reading: {}", e))?;
+                file.seek(SeekFrom::Start(data_start))
+                    .map_err(|e| format!("Failed to seek to encryption nonce: {}", e))?;
+                let mut nonce = [0u8; NONCE_LEN];
+                file.read_exact(&mut nonce)
+                    .map_err(|e| format!("Failed to read encryption nonce: {}", e))?;
+                data_start += NONCE_LEN as u64;
+                Some(nonce)
+            }
+        } else {
+            None
+        };
+
         info(
             Component::RingBuffer,
             &format!(
-                "WavFileReader initialized for {:?} - {} Hz, {} channels, {:?} format",
-                file_path, spec.sample_rate, spec.channels, spec.sample_format
+                "WavFileReader initialized for {:?} - {} Hz, {} channels, {:?} format, data starts at byte {}",
+                file_path, spec.sample_rate, spec.channels, spec.sample_format, data_start
             ),
         );
 
         Ok(Self {
             file_path: file_path.to_path_buf(),
             spec: Some(spec),
+            data_start,
+            bytes_per_sample,
             read_position: Arc::new(Mutex::new(0)),
             start_time: Instant::now(),
             last_known_size: Arc::new(Mutex::new(0)),
+            key,
+            nonce,
         })
     }
 
@@ -54,10 +124,108 @@ impl WavFileReader {
             .map_err(|e| format!("Failed to open WAV file: {}", e))?;
         let reader = WavReader::new(BufReader::new(file))
             .map_err(|e| format!("Failed to read WAV header: {}", e))?;
-        
+
         Ok(reader.spec())
     }
 
+    /// Walk the RIFF chunk list once to find the absolute byte offset of the
+    /// `data` subchunk's first content byte. `hound` parses the header for
+    /// us elsewhere but doesn't expose this offset, so we do a minimal
+    /// second pass: read each `(id: [u8; 4], size: u32)` chunk header and
+    /// skip `size` (rounded up to an even count) bytes until `data` is hit.
+    fn find_data_chunk_offset(file_path: &Path) -> Result<u64, String> {
+        let mut file = File::open(file_path)
+            .map_err(|e| format!("Failed to open WAV file: {}", e))?;
+
+        let mut riff_header = [0u8; 12];
+        file.read_exact(&mut riff_header)
+            .map_err(|e| format!("Failed to read RIFF header: {}", e))?;
+        if &riff_header[0..4] != b"RIFF" || &riff_header[8..12] != b"WAVE" {
+            return Err("Not a valid WAV file (missing RIFF/WAVE header)".to_string());
+        }
+
+        let mut offset: u64 = 12;
+        loop {
+            let mut chunk_header = [0u8; 8];
+            file.read_exact(&mut chunk_header)
+                .map_err(|e| format!("Failed to find 'data' chunk in WAV header: {}", e))?;
+            let chunk_id = &chunk_header[0..4];
+            let chunk_size = u32::from_le_bytes(chunk_header[4..8].try_into().unwrap()) as u64;
+            offset += 8;
+
+            if chunk_id == b"data" {
+                return Ok(offset);
+            }
+
+            // RIFF chunks are padded to an even byte count.
+            let padded_size = chunk_size + (chunk_size % 2);
+            file.seek(SeekFrom::Current(padded_size as i64))
+                .map_err(|e| format!("Failed to seek past '{:?}' chunk: {}", chunk_id, e))?;
+            offset += padded_size;
+        }
+    }
+
+    /// Decode raw little-endian PCM/float bytes (as read directly off disk
+    /// via [`Self::extract_chunk`]'s seek) into `-1.0..1.0` `f32` samples,
+    /// without going through `hound`'s sample-by-sample iterator.
+    fn decode_samples(buf: &[u8], spec: &WavSpec) -> Vec<f32> {
+        let bytes_per_sample = (spec.bits_per_sample / 8) as usize;
+        if bytes_per_sample == 0 {
+            return Vec::new();
+        }
+
+        let mut samples = Vec::with_capacity(buf.len() / bytes_per_sample);
+
+        match spec.sample_format {
+            SampleFormat::Float => {
+                for chunk in buf.chunks_exact(4) {
+                    samples.push(f32::from_le_bytes(chunk.try_into().unwrap()));
+                }
+            }
+            SampleFormat::Int => match spec.bits_per_sample {
+                8 => {
+                    // WAV 8-bit PCM is unsigned, centered at 128.
+                    for &b in buf {
+                        samples.push((b as f32 - 128.0) / 128.0);
+                    }
+                }
+                16 => {
+                    let full_scale = (1i64 << 15) as f32;
+                    for chunk in buf.chunks_exact(2) {
+                        let raw = i16::from_le_bytes(chunk.try_into().unwrap());
+                        samples.push(raw as f32 / full_scale);
+                    }
+                }
+                24 => {
+                    let full_scale = (1i64 << 23) as f32;
+                    for chunk in buf.chunks_exact(3) {
+                        let mut raw =
+                            (chunk[0] as i32) | ((chunk[1] as i32) << 8) | ((chunk[2] as i32) << 16);
+                        if raw & 0x0080_0000 != 0 {
+                            raw |= !0x00ff_ffffu32 as i32; // sign-extend 24 -> 32 bits
+                        }
+                        samples.push(raw as f32 / full_scale);
+                    }
+                }
+                32 => {
+                    let full_scale = (1i64 << 31) as f32;
+                    for chunk in buf.chunks_exact(4) {
+                        let raw = i32::from_le_bytes(chunk.try_into().unwrap());
+                        samples.push(raw as f32 / full_scale);
+                    }
+                }
+                other => {
+                    warn(
+                        Component::RingBuffer,
+                        &format!("Unsupported Int WAV bit depth: {} bits", other),
+                    );
+                }
+            },
+        }
+
+        samples
+    }
+
     /// Get the current file size in bytes
     fn get_file_size(&self) -> Result<u64, String> {
         std::fs::metadata(&self.file_path)
@@ -83,31 +251,33 @@ impl WavFileReader {
     pub fn get_available_duration(&self) -> Result<Duration, String> {
         let spec = self.spec.as_ref().ok_or("WAV spec not available")?;
         let file_size = self.get_file_size()?;
-        
-        // Calculate approximate duration based on file size
-        // WAV header is ~44 bytes, rest is audio data
-        let audio_data_size = file_size.saturating_sub(44);
-        let bytes_per_second = spec.sample_rate as u64 * spec.channels as u64 * 4; // 4 bytes per f32 sample
+
+        let audio_data_size = file_size.saturating_sub(self.data_start);
+        let bytes_per_second = spec.sample_rate as u64 * spec.channels as u64 * self.bytes_per_sample;
         let duration_secs = audio_data_size / bytes_per_second;
-        
+
         Ok(Duration::from_secs(duration_secs))
     }
 
-    /// Extract a chunk of audio from the WAV file starting at the given offset
+    /// Extract a chunk of audio from the WAV file starting at the given
+    /// offset. Seeks directly to `start_sample`'s byte offset within the
+    /// `data` subchunk (using the header offsets cached in `new()`) and
+    /// reads exactly the requested span, so cost is proportional to the
+    /// chunk size rather than to how far into the file `start_offset` is.
     pub fn extract_chunk(
         &self,
         start_offset: Duration,
         chunk_duration: Duration,
     ) -> Result<Vec<f32>, String> {
         let spec = self.spec.as_ref().ok_or("WAV spec not available")?;
-        
+
         // Calculate sample positions
-        let start_sample = (start_offset.as_secs_f32() 
-            * spec.sample_rate as f32 
-            * spec.channels as f32) as usize;
-        let chunk_samples = (chunk_duration.as_secs_f32() 
-            * spec.sample_rate as f32 
-            * spec.channels as f32) as usize;
+        let start_sample = (start_offset.as_secs_f32()
+            * spec.sample_rate as f32
+            * spec.channels as f32) as u64;
+        let chunk_samples = (chunk_duration.as_secs_f32()
+            * spec.sample_rate as f32
+            * spec.channels as f32) as u64;
 
         debug(
             Component::RingBuffer,
@@ -117,47 +287,35 @@ impl WavFileReader {
             ),
         );
 
-        // Open file and read samples
-        let file = File::open(&self.file_path)
-            .map_err(|e| format!("Failed to open WAV file for reading: {}", e))?;
-        let mut reader = WavReader::new(BufReader::new(file))
-            .map_err(|e| format!("Failed to create WAV reader: {}", e))?;
-
-        // Skip to start position
-        let mut samples_read = 0;
-        let mut chunk_data = Vec::with_capacity(chunk_samples);
-
-        // Read samples and skip to start position
-        for (i, sample_result) in reader.samples::<f32>().enumerate() {
-            if i < start_sample {
-                // Skip samples before our chunk
-                continue;
-            }
-            
-            if samples_read >= chunk_samples {
-                // We have enough samples for this chunk
-                break;
-            }
+        let start_byte = self.data_start + start_sample * self.bytes_per_sample;
+        let requested_bytes = chunk_samples * self.bytes_per_sample;
 
-            match sample_result {
-                Ok(sample) => {
-                    chunk_data.push(sample);
-                    samples_read += 1;
-                }
-                Err(e) => {
-                    warn(
-                        Component::RingBuffer,
-                        &format!("Error reading sample at position {}: {}", i, e),
-                    );
-                    break;
-                }
-            }
+        let mut file = File::open(&self.file_path)
+            .map_err(|e| format!("Failed to open WAV file for reading: {}", e))?;
+        file.seek(SeekFrom::Start(start_byte))
+            .map_err(|e| format!("Failed to seek to sample {}: {}", start_sample, e))?;
+
+        // The file may still be growing (live recording): read at most what
+        // `requested_bytes` asks for, taking whatever is actually there
+        // rather than failing on a short read, then drop any trailing
+        // partial sample.
+        let mut buf = Vec::new();
+        file.take(requested_bytes)
+            .read_to_end(&mut buf)
+            .map_err(|e| format!("Failed to read chunk bytes: {}", e))?;
+        let usable_len = buf.len() - (buf.len() % self.bytes_per_sample as usize);
+        buf.truncate(usable_len);
+
+        if buf.is_empty() {
+            return Ok(Vec::new()); // Return empty vec if no data available
         }
 
-        if chunk_data.is_empty() {
-            return Ok(Vec::new()); // Return empty vec if no data available
+        if let (Some(key), Some(nonce)) = (&self.key, &self.nonce) {
+            chunk_sink::xor_decrypt(&mut buf, start_byte - self.data_start, key, nonce);
         }
 
+        let chunk_data = Self::decode_samples(&buf, spec);
+
         debug(
             Component::RingBuffer,
             &format!(
@@ -169,6 +327,132 @@ impl WavFileReader {
         Ok(chunk_data)
     }
 
+    /// Cut the audio from `start_offset` onward at natural silence
+    /// boundaries instead of `extract_chunk`'s fixed windows, so the
+    /// transcription loop gets whole utterances rather than mid-word splits.
+    ///
+    /// Runs a Silero VAD ([`SileroVad`]) over the available audio frame by
+    /// frame, carrying its recurrent state across the whole call, and
+    /// applies hysteresis (enter speech at `VAD_ENTER_THRESHOLD`, exit at
+    /// the lower `VAD_EXIT_THRESHOLD`) plus minimum speech/silence guards to
+    /// decide where one segment ends and the next begins. A segment is also
+    /// flushed early if it would otherwise exceed `max_segment`, and any
+    /// segment still open when the available audio runs out is flushed as
+    /// well. Returns `(offset_from_file_start, samples)` pairs in order.
+    pub fn extract_speech_segments(
+        &self,
+        start_offset: Duration,
+        max_segment: Duration,
+    ) -> Result<Vec<(Duration, Vec<f32>)>, String> {
+        let spec = self.spec.as_ref().ok_or("WAV spec not available")?;
+        let sample_rate = spec.sample_rate;
+        let chunk_size = Self::silero_frame_size(sample_rate)?;
+
+        let available = self.get_available_duration()?;
+        if available <= start_offset {
+            return Ok(Vec::new());
+        }
+
+        let samples = self.extract_chunk(start_offset, available - start_offset)?;
+        if samples.len() < chunk_size {
+            return Ok(Vec::new());
+        }
+
+        let models_dir = dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("scout")
+            .join("models");
+        let model_path = SileroVad::default_model_path(&models_dir);
+        let mut vad = SileroVad::new(&model_path, chunk_size, sample_rate)?;
+
+        let frame_duration = Duration::from_secs_f64(chunk_size as f64 / sample_rate as f64);
+
+        let mut segments = Vec::new();
+        let mut in_speech = false;
+        let mut segment_start = Duration::ZERO;
+        let mut segment_samples: Vec<f32> = Vec::new();
+        let mut speech_run = Duration::ZERO;
+        let mut silence_run = Duration::ZERO;
+        let mut scanned = Duration::ZERO;
+
+        for frame in samples.chunks(chunk_size) {
+            if frame.len() < chunk_size {
+                // Trailing partial frame - not enough samples yet, leave it
+                // for the next call.
+                break;
+            }
+
+            let speech_prob = vad.process(frame)?;
+
+            if !in_speech {
+                if speech_prob >= VAD_ENTER_THRESHOLD {
+                    in_speech = true;
+                    segment_start = scanned;
+                    segment_samples.clear();
+                    segment_samples.extend_from_slice(frame);
+                    speech_run = frame_duration;
+                    silence_run = Duration::ZERO;
+                }
+            } else {
+                segment_samples.extend_from_slice(frame);
+                speech_run += frame_duration;
+                silence_run = if speech_prob < VAD_EXIT_THRESHOLD {
+                    silence_run + frame_duration
+                } else {
+                    Duration::ZERO
+                };
+
+                let min_speech_met = speech_run.as_millis() as u32 >= VAD_MIN_SPEECH_MS;
+                let min_silence_met = silence_run.as_millis() as u32 >= VAD_MIN_SILENCE_MS;
+                let cap_hit = speech_run >= max_segment;
+
+                if (min_speech_met && min_silence_met) || cap_hit {
+                    debug(
+                        Component::RingBuffer,
+                        &format!(
+                            "VAD segment closed at {:?}: {:?} of speech (cap_hit={})",
+                            start_offset + segment_start,
+                            speech_run,
+                            cap_hit
+                        ),
+                    );
+                    segments.push((start_offset + segment_start, std::mem::take(&mut segment_samples)));
+                    in_speech = false;
+                    speech_run = Duration::ZERO;
+                    silence_run = Duration::ZERO;
+                }
+            }
+
+            scanned += frame_duration;
+        }
+
+        if in_speech && !segment_samples.is_empty() {
+            debug(
+                Component::RingBuffer,
+                &format!(
+                    "Flushing open VAD segment at end of available audio: {:?} of speech",
+                    speech_run
+                ),
+            );
+            segments.push((start_offset + segment_start, segment_samples));
+        }
+
+        Ok(segments)
+    }
+
+    /// Silero's frame size is tied to the input sample rate: 512 samples at
+    /// 16 kHz, 256 samples at 8 kHz. Any other rate isn't supported by the
+    /// bundled model.
+    fn silero_frame_size(sample_rate: u32) -> Result<usize, String> {
+        match sample_rate {
+            16000 => Ok(512),
+            8000 => Ok(256),
+            other => Err(format!(
+                "Silero VAD only supports 8kHz/16kHz audio, got {} Hz",
+                other
+            )),
+        }
+    }
 
     /// Get the WAV specification
     pub fn get_spec(&self) -> Option<&WavSpec> {
@@ -177,34 +461,107 @@ impl WavFileReader {
 
     /// Save a chunk of samples to a temporary WAV file for transcription
     pub fn save_chunk_to_file(&self, chunk_data: &[f32], output_path: &Path) -> Result<(), String> {
+        self.save_chunk_to_sink(chunk_data, ChunkSink::File(output_path.to_path_buf()))
+    }
+
+    /// Save a chunk of samples to an arbitrary [`ChunkSink`] - a plaintext
+    /// file, an encrypted-at-rest file, or a streamed destination such as a
+    /// remote transcription worker's socket.
+    pub fn save_chunk_to_sink(&self, chunk_data: &[f32], sink: ChunkSink) -> Result<(), String> {
         let spec = self.spec.as_ref().ok_or("WAV spec not available")?;
-        
+
         if chunk_data.is_empty() {
             return Err("Cannot save empty chunk".to_string());
         }
 
-        let mut writer = hound::WavWriter::create(output_path, *spec)
-            .map_err(|e| format!("Failed to create chunk WAV file: {}", e))?;
-
-        for &sample in chunk_data {
-            writer
-                .write_sample(sample)
-                .map_err(|e| format!("Failed to write chunk sample: {}", e))?;
+        match sink {
+            ChunkSink::File(path) => {
+                let mut writer = hound::WavWriter::create(&path, *spec)
+                    .map_err(|e| format!("Failed to create chunk WAV file: {}", e))?;
+                Self::write_samples(&mut writer, spec, chunk_data)?;
+                writer
+                    .finalize()
+                    .map_err(|e| format!("Failed to finalize chunk WAV file: {}", e))?;
+
+                debug(
+                    Component::RingBuffer,
+                    &format!("Saved chunk: {} samples to {:?}", chunk_data.len(), path),
+                );
+            }
+            ChunkSink::Encrypted { path, key } => {
+                let file = File::create(&path)
+                    .map_err(|e| format!("Failed to create encrypted chunk file: {}", e))?;
+                let xor_writer = XorWriter::new(file, key);
+                let mut writer = hound::WavWriter::new(xor_writer, *spec)
+                    .map_err(|e| format!("Failed to create encrypted chunk WAV writer: {}", e))?;
+                Self::write_samples(&mut writer, spec, chunk_data)?;
+                writer
+                    .finalize()
+                    .map_err(|e| format!("Failed to finalize encrypted chunk WAV file: {}", e))?;
+
+                debug(
+                    Component::RingBuffer,
+                    &format!(
+                        "Saved encrypted chunk: {} samples to {:?}",
+                        chunk_data.len(),
+                        path
+                    ),
+                );
+            }
+            ChunkSink::Stream(mut stream) => {
+                // `hound` needs `Seek` to back-patch the RIFF/data chunk
+                // sizes once writing finishes, which an arbitrary stream
+                // can't offer, so we build this one chunk in memory (it's
+                // already fully owned as `chunk_data`) and write the
+                // finished bytes through in one shot.
+                let cursor = std::io::Cursor::new(Vec::new());
+                let mut writer = hound::WavWriter::new(cursor, *spec)
+                    .map_err(|e| format!("Failed to create streamed chunk WAV writer: {}", e))?;
+                Self::write_samples(&mut writer, spec, chunk_data)?;
+                let cursor = writer
+                    .into_inner()
+                    .map_err(|e| format!("Failed to finalize streamed chunk WAV: {}", e))?;
+
+                stream
+                    .write_all(cursor.get_ref())
+                    .map_err(|e| format!("Failed to write chunk to stream: {}", e))?;
+
+                debug(
+                    Component::RingBuffer,
+                    &format!("Streamed chunk: {} samples", chunk_data.len()),
+                );
+            }
         }
 
-        writer
-            .finalize()
-            .map_err(|e| format!("Failed to finalize chunk WAV file: {}", e))?;
-
-        debug(
-            Component::RingBuffer,
-            &format!(
-                "Saved chunk: {} samples to {:?}",
-                chunk_data.len(),
-                output_path
-            ),
-        );
+        Ok(())
+    }
 
+    /// `chunk_data` is always normalized `-1.0..1.0` f32; re-quantize back
+    /// to the source file's native format so the written chunk matches the
+    /// `spec` the writer was created with.
+    fn write_samples<W: Write + Seek>(
+        writer: &mut hound::WavWriter<W>,
+        spec: &WavSpec,
+        chunk_data: &[f32],
+    ) -> Result<(), String> {
+        match spec.sample_format {
+            SampleFormat::Float => {
+                for &sample in chunk_data {
+                    writer
+                        .write_sample(sample)
+                        .map_err(|e| format!("Failed to write chunk sample: {}", e))?;
+                }
+            }
+            SampleFormat::Int => {
+                let full_scale = (1i64 << (spec.bits_per_sample - 1)) as f32;
+                for &sample in chunk_data {
+                    let quantized = (sample.clamp(-1.0, 1.0) * full_scale).round() as i32;
+                    writer
+                        .write_sample(quantized)
+                        .map_err(|e| format!("Failed to write chunk sample: {}", e))?;
+                }
+            }
+        }
         Ok(())
     }
 
@@ -253,7 +610,7 @@ impl WavFileReader {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use hound::{WavWriter, WavSpec, SampleFormat};
+    use hound::{WavWriter, WavSpec};
     use tempfile::tempdir;
 
     fn create_test_wav_file(path: &Path, duration_secs: f32) -> Result<(), Box<dyn std::error::Error>> {
@@ -326,6 +683,92 @@ mod tests {
         assert!(chunk2.len() > 15000 && chunk2.len() < 17000);
     }
 
+    #[test]
+    fn test_extract_last_chunk_of_long_file_is_seek_based() {
+        let temp_dir = tempdir().unwrap();
+        let wav_path = temp_dir.path().join("long.wav");
+
+        // 5 minutes at 16kHz: large enough that an O(n) scan from sample 0
+        // would be clearly slower than a single seek + read.
+        create_test_wav_file(&wav_path, 300.0).unwrap();
+
+        let reader = WavFileReader::new(&wav_path).unwrap();
+
+        let start = Instant::now();
+        let chunk = reader
+            .extract_chunk(Duration::from_secs(299), Duration::from_secs(1))
+            .unwrap();
+        let elapsed = start.elapsed();
+
+        assert!(chunk.len() > 15000 && chunk.len() < 17000);
+        assert!(
+            elapsed < Duration::from_millis(200),
+            "extract_chunk of the last 1s took {:?}; expected an O(1) seek, not a full scan",
+            elapsed
+        );
+    }
+
+    fn create_test_wav_file_i16(path: &Path, duration_secs: f32) -> Result<(), Box<dyn std::error::Error>> {
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample: 16,
+            sample_format: SampleFormat::Int,
+        };
+
+        let mut writer = WavWriter::create(path, spec)?;
+
+        let samples_count = (duration_secs * spec.sample_rate as f32) as usize;
+        for i in 0..samples_count {
+            let t = i as f32 / spec.sample_rate as f32;
+            let sample = (2.0 * std::f32::consts::PI * 440.0 * t).sin() * 0.5;
+            writer.write_sample((sample * i16::MAX as f32) as i16)?;
+        }
+
+        writer.finalize()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_int16_duration_and_chunk_extraction() {
+        let temp_dir = tempdir().unwrap();
+        let wav_path = temp_dir.path().join("test_i16.wav");
+
+        create_test_wav_file_i16(&wav_path, 2.0).unwrap();
+
+        let reader = WavFileReader::new(&wav_path).unwrap();
+
+        let duration = reader.get_available_duration().unwrap();
+        assert!((duration.as_secs_f32() - 2.0).abs() < 0.1);
+
+        let chunk = reader.extract_chunk(Duration::ZERO, Duration::from_secs(1)).unwrap();
+        assert!(chunk.len() > 15000 && chunk.len() < 17000);
+        // Normalized to -1.0..1.0, not left as raw i16 magnitudes
+        assert!(chunk.iter().all(|&s| s.abs() <= 1.0));
+    }
+
+    #[test]
+    fn test_save_chunk_to_file_int16_roundtrip() {
+        let temp_dir = tempdir().unwrap();
+        let wav_path = temp_dir.path().join("test_i16.wav");
+        let chunk_path = temp_dir.path().join("chunk_i16.wav");
+
+        create_test_wav_file_i16(&wav_path, 1.0).unwrap();
+
+        let reader = WavFileReader::new(&wav_path).unwrap();
+        let chunk = reader.extract_chunk(Duration::ZERO, Duration::from_millis(500)).unwrap();
+
+        reader.save_chunk_to_file(&chunk, &chunk_path).unwrap();
+
+        let chunk_reader = WavFileReader::new(&chunk_path).unwrap();
+        let chunk_spec = chunk_reader.get_spec().unwrap();
+        assert_eq!(chunk_spec.bits_per_sample, 16);
+        assert_eq!(chunk_spec.sample_format, SampleFormat::Int);
+
+        let reread = chunk_reader.extract_chunk(Duration::ZERO, Duration::from_millis(500)).unwrap();
+        assert_eq!(reread.len(), chunk.len());
+    }
+
     #[test]
     fn test_save_chunk_to_file() {
         let temp_dir = tempdir().unwrap();
@@ -353,4 +796,51 @@ mod tests {
         assert_eq!(chunk_spec.sample_rate, 16000);
         assert_eq!(chunk_spec.channels, 1);
     }
+
+    #[test]
+    fn test_encrypted_chunk_roundtrip_and_header_stays_in_clear() {
+        let temp_dir = tempdir().unwrap();
+        let wav_path = temp_dir.path().join("test.wav");
+        let chunk_path = temp_dir.path().join("chunk_encrypted.wav");
+        let key = vec![0x5A, 0x17, 0xC3];
+
+        create_test_wav_file(&wav_path, 1.0).unwrap();
+
+        let reader = WavFileReader::new(&wav_path).unwrap();
+        let chunk = reader
+            .extract_chunk(Duration::ZERO, Duration::from_millis(500))
+            .unwrap();
+
+        reader
+            .save_chunk_to_sink(
+                &chunk,
+                crate::audio::ChunkSink::Encrypted {
+                    path: chunk_path.clone(),
+                    key: key.clone(),
+                },
+            )
+            .unwrap();
+
+        // The header is left in the clear, so a plain reader can still
+        // parse the spec without the key.
+        let plain_reader = WavFileReader::new(&chunk_path).unwrap();
+        let spec = plain_reader.get_spec().unwrap();
+        assert_eq!(spec.sample_rate, 16000);
+
+        // But reading sample data without the key yields noise, not the
+        // original waveform.
+        let undecrypted = plain_reader
+            .extract_chunk(Duration::ZERO, Duration::from_millis(500))
+            .unwrap();
+        assert_ne!(undecrypted, chunk);
+
+        let encrypted_reader = WavFileReader::new_encrypted(&chunk_path, key).unwrap();
+        let decrypted = encrypted_reader
+            .extract_chunk(Duration::ZERO, Duration::from_millis(500))
+            .unwrap();
+        assert_eq!(decrypted.len(), chunk.len());
+        for (a, b) in decrypted.iter().zip(chunk.iter()) {
+            assert!((a - b).abs() < 1e-3);
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub mod sysinfo;
+
+pub use sysinfo::{get_memory_usage_mb, HostProfile};
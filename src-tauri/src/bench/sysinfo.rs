@@ -0,0 +1,200 @@
+//! Host capability probing for benchmark and stress-test output.
+//!
+//! A stress test asserting a fixed absolute threshold (startup < 100ms,
+//! memory < 300MB) means nothing without knowing what machine produced it.
+//! [`HostProfile::probe`] measures what the host can actually do so callers
+//! can derive thresholds relative to it instead of hardcoding a number that
+//! only holds on the machine it was tuned on.
+
+use std::path::Path;
+use std::time::Instant;
+
+/// A snapshot of host capability, gathered once before a benchmark/stress
+/// suite runs. The throughput fields are measured with a few milliseconds of
+/// real I/O, so prefer probing once per suite rather than once per case.
+#[derive(Debug, Clone)]
+pub struct HostProfile {
+    pub cpu_model: String,
+    pub cpu_cores: usize,
+    pub memory_bandwidth_mib_per_sec: f64,
+    pub disk_write_mib_per_sec: f64,
+}
+
+impl HostProfile {
+    /// Probes the current host, writing its disk-throughput test file into
+    /// `scratch_dir` (the caller's existing temp dir is fine).
+    pub fn probe(scratch_dir: &Path) -> Self {
+        Self {
+            cpu_model: cpu_model(),
+            cpu_cores: std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+            memory_bandwidth_mib_per_sec: measure_memory_bandwidth(),
+            disk_write_mib_per_sec: measure_disk_write_throughput(scratch_dir),
+        }
+    }
+
+    /// Short human-readable summary for benchmark/stress-test logs.
+    pub fn print_summary(&self) {
+        println!("🖥️  Host profile:");
+        println!("   CPU: {} ({} cores)", self.cpu_model, self.cpu_cores);
+        println!("   Memory bandwidth: {:.0} MiB/s", self.memory_bandwidth_mib_per_sec);
+        println!("   Disk write throughput: {:.0} MiB/s", self.disk_write_mib_per_sec);
+    }
+}
+
+fn cpu_model() -> String {
+    #[cfg(target_os = "linux")]
+    {
+        std::fs::read_to_string("/proc/cpuinfo")
+            .ok()
+            .and_then(|content| {
+                content.lines().find_map(|line| {
+                    line.split_once(':').and_then(|(key, value)| {
+                        (key.trim() == "model name").then(|| value.trim().to_string())
+                    })
+                })
+            })
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("sysctl")
+            .args(["-n", "machdep.cpu.brand_string"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|s| s.trim().to_string())
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        "unknown".to_string()
+    }
+}
+
+/// Sequential memcpy throughput over a multi-MiB buffer, as a rough proxy
+/// for host memory bandwidth.
+fn measure_memory_bandwidth() -> f64 {
+    const BUFFER_MIB: usize = 16;
+    const ITERATIONS: usize = 8;
+    let bytes = BUFFER_MIB * 1024 * 1024;
+
+    let src = vec![0xABu8; bytes];
+    let mut dst = vec![0u8; bytes];
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        dst.copy_from_slice(&src);
+    }
+    let elapsed_secs = start.elapsed().as_secs_f64();
+
+    let total_mib = (bytes * ITERATIONS) as f64 / (1024.0 * 1024.0);
+    total_mib / elapsed_secs.max(1e-9)
+}
+
+/// Sequential write throughput of a few MiB to `dir`, as a rough proxy for
+/// the disk `bench_file_io` numbers run against.
+fn measure_disk_write_throughput(dir: &Path) -> f64 {
+    const FILE_MIB: usize = 4;
+    let bytes = FILE_MIB * 1024 * 1024;
+    let data = vec![0xCDu8; bytes];
+    let path = dir.join("scout_sysinfo_probe.tmp");
+
+    let elapsed_secs = {
+        use std::io::Write;
+        let start = Instant::now();
+        match std::fs::File::create(&path) {
+            Ok(mut file) => {
+                let _ = file.write_all(&data);
+                let _ = file.sync_all();
+                start.elapsed().as_secs_f64()
+            }
+            Err(_) => return 0.0,
+        }
+    };
+    let _ = std::fs::remove_file(&path);
+
+    (bytes as f64 / (1024.0 * 1024.0)) / elapsed_secs.max(1e-9)
+}
+
+/// Cross-platform resident memory usage of the current process, in MB.
+pub fn get_memory_usage_mb() -> usize {
+    #[cfg(target_os = "linux")]
+    {
+        // `/proc/self/statm` is whitespace-separated pages: size resident
+        // shared text lib data dt. Field 2 (resident) times the page size
+        // gives RSS in bytes.
+        std::fs::read_to_string("/proc/self/statm")
+            .ok()
+            .and_then(|content| {
+                content.split_whitespace().nth(1).and_then(|pages| pages.parse::<usize>().ok())
+            })
+            .map(|resident_pages| {
+                let page_size_kb = 4; // Standard page size on Linux.
+                (resident_pages * page_size_kb) / 1024
+            })
+            .unwrap_or(0)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("ps")
+            .args(["-o", "rss=", "-p", &std::process::id().to_string()])
+            .output()
+            .ok()
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .and_then(|s| s.trim().parse::<usize>().ok())
+            .map(|rss_kb| rss_kb / 1024)
+            .unwrap_or(0)
+    }
+    #[cfg(target_os = "windows")]
+    {
+        win_memory::current_process_rss_mb()
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    {
+        0
+    }
+}
+
+/// Minimal hand-rolled psapi bindings, mirroring the `win_job` convention in
+/// `services::process_manager` (declare just the symbols needed rather than
+/// pulling in a `winapi`/`windows-sys` dependency this crate doesn't
+/// otherwise have).
+#[cfg(target_os = "windows")]
+mod win_memory {
+    use std::os::raw::c_void;
+
+    type Handle = *mut c_void;
+    type Bool = i32;
+    type DWord = u32;
+
+    #[repr(C)]
+    struct ProcessMemoryCounters {
+        cb: DWord,
+        page_fault_count: DWord,
+        peak_working_set_size: usize,
+        working_set_size: usize,
+        quota_peak_paged_pool_usage: usize,
+        quota_paged_pool_usage: usize,
+        quota_peak_non_paged_pool_usage: usize,
+        quota_non_paged_pool_usage: usize,
+        pagefile_usage: usize,
+        peak_pagefile_usage: usize,
+    }
+
+    extern "system" {
+        fn GetCurrentProcess() -> Handle;
+        fn K32GetProcessMemoryInfo(process: Handle, counters: *mut ProcessMemoryCounters, cb: DWord) -> Bool;
+    }
+
+    pub fn current_process_rss_mb() -> usize {
+        unsafe {
+            let mut counters: ProcessMemoryCounters = std::mem::zeroed();
+            counters.cb = std::mem::size_of::<ProcessMemoryCounters>() as DWord;
+            if K32GetProcessMemoryInfo(GetCurrentProcess(), &mut counters, counters.cb) == 0 {
+                return 0;
+            }
+            counters.working_set_size / (1024 * 1024)
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Reproducible benchmark harness for transcription strategies.
+//!
+//! `PerformanceLogger::log_strategy_comparison` only compares two strategies
+//! on a single ad-hoc recording, which is useless for catching regressions
+//! over time. This module runs every registered strategy except Progressive against a fixed
+//! audio corpus for a configured duration, reports p50/p95/p99 transcription
+//! ratio and user-perceived latency per strategy, and persists each run via
+//! `Database::save_performance_metrics` (tagged with model/hardware/strategy)
+//! so runs are diffable across commits.
+
+pub mod harness;
+pub mod percentiles;
+
+pub use harness::{
+    BenchmarkCorpusEntry, BenchmarkHarness, BenchmarkHarnessConfig, BenchmarkRunReport,
+    StrategyBenchmarkReport,
+};
+pub use percentiles::Percentiles;
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+/// p50/p95/p99 summary of a set of sampled values, computed by nearest-rank
+/// (sort then index, no interpolation between samples). Unlike `median` in
+/// `benchmarking::evaluation`, an even-length p50 is not the mean of the two
+/// middle values.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Percentiles {
+    pub p50: f64,
+    pub p95: f64,
+    pub p99: f64,
+}
+
+impl Percentiles {
+    pub fn from_samples(samples: &[f64]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        Self {
+            p50: Self::nearest_rank(&sorted, 0.50),
+            p95: Self::nearest_rank(&sorted, 0.95),
+            p99: Self::nearest_rank(&sorted, 0.99),
+        }
+    }
+
+    fn nearest_rank(sorted: &[f64], fraction: f64) -> f64 {
+        let rank = ((sorted.len() as f64) * fraction).ceil() as usize;
+        let index = rank.saturating_sub(1).min(sorted.len() - 1);
+        sorted[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_rank_matches_known_percentiles() {
+        let samples: Vec<f64> = (1..=100).map(|v| v as f64).collect();
+        let percentiles = Percentiles::from_samples(&samples);
+        assert_eq!(percentiles.p50, 50.0);
+        assert_eq!(percentiles.p95, 95.0);
+        assert_eq!(percentiles.p99, 99.0);
+    }
+
+    #[test]
+    fn empty_samples_default_to_zero() {
+        let percentiles = Percentiles::from_samples(&[]);
+        assert_eq!(percentiles.p50, 0.0);
+        assert_eq!(percentiles.p99, 0.0);
+    }
+
+    #[test]
+    fn single_sample_is_every_percentile() {
+        let percentiles = Percentiles::from_samples(&[42.0]);
+        assert_eq!(percentiles.p50, 42.0);
+        assert_eq!(percentiles.p95, 42.0);
+        assert_eq!(percentiles.p99, 42.0);
+    }
+}
@@ -56,6 +56,12 @@ pub struct AccuracyMetrics {
     pub word_count: u32,
     pub character_count: u32,
     pub confidence_score: f32,
+    /// Word error rate against a ground-truth reference, if one was supplied.
+    /// `None` when no reference transcript was available to score against.
+    pub word_error_rate: Option<f32>,
+    /// Character error rate against a ground-truth reference, if one was
+    /// supplied. `None` when no reference transcript was available.
+    pub character_error_rate: Option<f32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -129,6 +135,8 @@ impl BenchmarkRunner {
                             word_count: 0,
                             character_count: 0,
                             confidence_score: 0.0,
+                            word_error_rate: None,
+                            character_error_rate: None,
                         },
                         success: false,
                         error_message: Some(e),
@@ -196,6 +204,8 @@ impl BenchmarkRunner {
                 word_count: transcribed_text.split_whitespace().count() as u32,
                 character_count: transcribed_text.len() as u32,
                 confidence_score: 0.85, // Placeholder
+                word_error_rate: None,
+                character_error_rate: None,
             },
             success: true,
             error_message: None,
@@ -0,0 +1,350 @@
+use crate::benchmarking::strategy_tester::normalize_for_scoring;
+use crate::logger::{info, Component};
+use crate::transcription::Transcriber;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// One recording's reference transcript, as written by the
+/// `generate_comprehensive_gold_standard_transcriptions` binary. Mirrors that
+/// binary's `GoldStandardTranscription`/`GoldStandardReport` shape so
+/// `gold_standard_transcriptions.json` can be read back without a schema
+/// migration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoldStandardTranscription {
+    pub recording_name: String,
+    pub audio_file_path: String,
+    pub duration_ms: u32,
+    pub category: String,
+    pub gold_standard_transcription: String,
+    pub model_used: String,
+    pub processing_time_ms: f64,
+    pub generated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoldStandardCorpus {
+    pub timestamp: String,
+    pub model_used: String,
+    pub total_recordings: usize,
+    pub transcriptions: Vec<GoldStandardTranscription>,
+}
+
+/// WER/CER for a single recording, plus the word-level operation breakdown
+/// backed out of the Levenshtein alignment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordingEvaluation {
+    pub recording_name: String,
+    pub category: String,
+    pub duration_ms: u32,
+    pub word_error_rate: f32,
+    pub character_error_rate: f32,
+    pub substitutions: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryEvaluationSummary {
+    pub category: String,
+    pub recordings_evaluated: usize,
+    pub mean_word_error_rate: f32,
+    pub median_word_error_rate: f32,
+    pub mean_character_error_rate: f32,
+    pub median_character_error_rate: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorstRecording {
+    pub recording_name: String,
+    pub word_error_rate: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvaluationReport {
+    pub timestamp: String,
+    pub model_used: String,
+    pub total_recordings: usize,
+    pub recordings: Vec<RecordingEvaluation>,
+    pub by_category: Vec<CategoryEvaluationSummary>,
+    pub mean_word_error_rate: f32,
+    pub median_word_error_rate: f32,
+    pub mean_character_error_rate: f32,
+    pub median_character_error_rate: f32,
+    pub worst_recordings: Vec<WorstRecording>,
+}
+
+/// Scores a candidate `Transcriber` against `gold_standard_transcriptions.json`,
+/// reporting Word Error Rate and Character Error Rate per recording and
+/// aggregated by `RecordingLength` category (carried through as the report's
+/// `category` string, same as the gold-standard generator binaries use it).
+pub struct GoldStandardEvaluator {
+    transcriber: Arc<Transcriber>,
+}
+
+impl GoldStandardEvaluator {
+    pub fn new(transcriber: Arc<Transcriber>) -> Self {
+        Self { transcriber }
+    }
+
+    pub async fn evaluate(
+        &self,
+        gold_standard_path: &PathBuf,
+        worst_n: usize,
+    ) -> Result<EvaluationReport, String> {
+        let contents = tokio::fs::read_to_string(gold_standard_path)
+            .await
+            .map_err(|e| format!("Failed to read gold standard corpus: {}", e))?;
+
+        let corpus: GoldStandardCorpus = serde_json::from_str(&contents)
+            .map_err(|e| format!("Failed to parse gold standard corpus: {}", e))?;
+
+        info(
+            Component::Processing,
+            &format!(
+                "📊 Evaluating {} against {} gold-standard recordings",
+                corpus.model_used,
+                corpus.transcriptions.len()
+            ),
+        );
+
+        let mut recordings = Vec::new();
+        for entry in &corpus.transcriptions {
+            let candidate = tokio::task::spawn_blocking({
+                let transcriber = self.transcriber.clone();
+                let audio_path = PathBuf::from(&entry.audio_file_path);
+                move || transcriber.transcribe_file(&audio_path)
+            })
+            .await
+            .map_err(|e| format!("Task join error: {}", e))?
+            .map_err(|e| format!("Failed to transcribe '{}': {}", entry.recording_name, e))?;
+
+            recordings.push(Self::score_recording(entry, &candidate));
+        }
+
+        Ok(Self::build_report(&corpus.model_used, recordings, worst_n))
+    }
+
+    fn score_recording(entry: &GoldStandardTranscription, candidate: &str) -> RecordingEvaluation {
+        let reference_words: Vec<String> = normalize_for_scoring(&entry.gold_standard_transcription)
+            .split_whitespace()
+            .map(|w| w.to_string())
+            .collect();
+        let candidate_words: Vec<String> = normalize_for_scoring(candidate)
+            .split_whitespace()
+            .map(|w| w.to_string())
+            .collect();
+
+        let (word_edits, substitutions, insertions, deletions) =
+            align(&reference_words, &candidate_words);
+        let word_error_rate = if reference_words.is_empty() {
+            0.0
+        } else {
+            word_edits as f32 / reference_words.len() as f32
+        };
+
+        let reference_chars: Vec<char> =
+            normalize_for_scoring(&entry.gold_standard_transcription).chars().collect();
+        let candidate_chars: Vec<char> = normalize_for_scoring(candidate).chars().collect();
+        let (char_edits, _, _, _) = align(&reference_chars, &candidate_chars);
+        let character_error_rate = if reference_chars.is_empty() {
+            0.0
+        } else {
+            char_edits as f32 / reference_chars.len() as f32
+        };
+
+        RecordingEvaluation {
+            recording_name: entry.recording_name.clone(),
+            category: entry.category.clone(),
+            duration_ms: entry.duration_ms,
+            word_error_rate,
+            character_error_rate,
+            substitutions,
+            insertions,
+            deletions,
+        }
+    }
+
+    fn build_report(
+        model_used: &str,
+        mut recordings: Vec<RecordingEvaluation>,
+        worst_n: usize,
+    ) -> EvaluationReport {
+        recordings.sort_by(|a, b| a.recording_name.cmp(&b.recording_name));
+
+        let mut wer_values: Vec<f32> = recordings.iter().map(|r| r.word_error_rate).collect();
+        let mut cer_values: Vec<f32> = recordings.iter().map(|r| r.character_error_rate).collect();
+
+        let mean_word_error_rate = mean(&wer_values);
+        let median_word_error_rate = median(&mut wer_values);
+        let mean_character_error_rate = mean(&cer_values);
+        let median_character_error_rate = median(&mut cer_values);
+
+        let by_category = Self::summarize_by_category(&recordings);
+
+        let mut worst = recordings.clone();
+        worst.sort_by(|a, b| {
+            b.word_error_rate
+                .partial_cmp(&a.word_error_rate)
+                .unwrap_or(Ordering::Equal)
+        });
+        let worst_recordings = worst
+            .into_iter()
+            .take(worst_n)
+            .map(|r| WorstRecording {
+                recording_name: r.recording_name,
+                word_error_rate: r.word_error_rate,
+            })
+            .collect();
+
+        EvaluationReport {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            model_used: model_used.to_string(),
+            total_recordings: recordings.len(),
+            recordings,
+            by_category,
+            mean_word_error_rate,
+            median_word_error_rate,
+            mean_character_error_rate,
+            median_character_error_rate,
+            worst_recordings,
+        }
+    }
+
+    fn summarize_by_category(recordings: &[RecordingEvaluation]) -> Vec<CategoryEvaluationSummary> {
+        let mut categories: Vec<String> = recordings.iter().map(|r| r.category.clone()).collect();
+        categories.sort();
+        categories.dedup();
+
+        categories
+            .into_iter()
+            .map(|category| {
+                let mut wer: Vec<f32> = recordings
+                    .iter()
+                    .filter(|r| r.category == category)
+                    .map(|r| r.word_error_rate)
+                    .collect();
+                let mut cer: Vec<f32> = recordings
+                    .iter()
+                    .filter(|r| r.category == category)
+                    .map(|r| r.character_error_rate)
+                    .collect();
+
+                CategoryEvaluationSummary {
+                    recordings_evaluated: wer.len(),
+                    mean_word_error_rate: mean(&wer),
+                    median_word_error_rate: median(&mut wer),
+                    mean_character_error_rate: mean(&cer),
+                    median_character_error_rate: median(&mut cer),
+                    category,
+                }
+            })
+            .collect()
+    }
+}
+
+fn mean(values: &[f32]) -> f32 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f32>() / values.len() as f32
+    }
+}
+
+fn median(values: &mut [f32]) -> f32 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+/// Levenshtein-aligns `reference` against `candidate`, returning
+/// `(total_edits, substitutions, insertions, deletions)`. Builds the DP table
+/// `d[i][j] = d[i-1][j-1]` on a match, else `1 + min(substitution, deletion,
+/// insertion)`, then backtracks it to classify each edit.
+fn align<T: PartialEq>(reference: &[T], candidate: &[T]) -> (usize, usize, usize, usize) {
+    let len1 = reference.len();
+    let len2 = candidate.len();
+    let mut d = vec![vec![0usize; len2 + 1]; len1 + 1];
+    for (i, row) in d.iter_mut().enumerate().take(len1 + 1) {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 0..len1 {
+        for j in 0..len2 {
+            d[i + 1][j + 1] = if reference[i] == candidate[j] {
+                d[i][j]
+            } else {
+                1 + d[i][j].min(d[i + 1][j]).min(d[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (len1, len2);
+    let (mut substitutions, mut insertions, mut deletions) = (0, 0, 0);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && reference[i - 1] == candidate[j - 1] {
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && d[i][j] == d[i - 1][j - 1] + 1 {
+            substitutions += 1;
+            i -= 1;
+            j -= 1;
+        } else if j > 0 && d[i][j] == d[i][j - 1] + 1 {
+            insertions += 1;
+            j -= 1;
+        } else {
+            deletions += 1;
+            i -= 1;
+        }
+    }
+
+    (substitutions + insertions + deletions, substitutions, insertions, deletions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn align_counts_each_operation_class() {
+        let reference: Vec<&str> = "the quick brown fox".split_whitespace().collect();
+        let candidate: Vec<&str> = "the quick red fox jumps".split_whitespace().collect();
+
+        // "brown" -> "red" is a substitution, "jumps" is an insertion.
+        let (total, substitutions, insertions, deletions) = align(&reference, &candidate);
+        assert_eq!(total, 2);
+        assert_eq!(substitutions, 1);
+        assert_eq!(insertions, 1);
+        assert_eq!(deletions, 0);
+    }
+
+    #[test]
+    fn identical_transcripts_score_zero_error() {
+        let words: Vec<&str> = "hello world".split_whitespace().collect();
+        let entry = GoldStandardTranscription {
+            recording_name: "test".to_string(),
+            audio_file_path: "test.wav".to_string(),
+            duration_ms: 1000,
+            category: "Short".to_string(),
+            gold_standard_transcription: words.join(" "),
+            model_used: "large-v3".to_string(),
+            processing_time_ms: 0.0,
+            generated_at: "2026-01-01T00:00:00Z".to_string(),
+        };
+
+        let evaluation = GoldStandardEvaluator::score_recording(&entry, "hello world");
+        assert_eq!(evaluation.word_error_rate, 0.0);
+        assert_eq!(evaluation.character_error_rate, 0.0);
+    }
+}
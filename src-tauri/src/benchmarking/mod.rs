@@ -1,9 +1,11 @@
 pub mod benchmark_runner;
+pub mod evaluation;
 pub mod metrics;
 pub mod strategy_tester;
 pub mod test_data;
 
 pub use benchmark_runner::*;
+pub use evaluation::*;
 pub use metrics::*;
 pub use strategy_tester::*;
 pub use test_data::*;
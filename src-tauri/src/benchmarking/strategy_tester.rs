@@ -54,6 +54,7 @@ impl StrategyTester {
         strategy: &TranscriptionStrategy,
         audio_file: &PathBuf,
         test_name: &str,
+        expected_transcript: Option<&str>,
     ) -> Result<BenchmarkResult, String> {
         info(
             Component::Processing,
@@ -81,6 +82,12 @@ impl StrategyTester {
 
         let total_duration = start_time.elapsed();
 
+        // Score against the ground-truth reference when one was supplied; an
+        // empty or missing reference is surfaced as `None` (skipped) rather
+        // than scored as 100% error.
+        let quality = expected_transcript
+            .and_then(|expected| self.calculate_quality_metrics(&transcription_result.text, expected));
+
         Ok(BenchmarkResult {
             test_name: test_name.to_string(),
             strategy_used: strategy.name(),
@@ -95,6 +102,8 @@ impl StrategyTester {
                 word_count: transcription_result.text.split_whitespace().count() as u32,
                 character_count: transcription_result.text.len() as u32,
                 confidence_score: transcription_result.confidence,
+                word_error_rate: quality.as_ref().map(|q| q.word_error_rate),
+                character_error_rate: quality.as_ref().map(|q| q.character_error_rate),
             },
             success: true,
             error_message: None,
@@ -245,76 +254,40 @@ impl StrategyTester {
         })
     }
 
-    /// Calculate quality metrics by comparing transcribed text with expected text
-    pub fn calculate_quality_metrics(&self, transcribed: &str, expected: &str) -> QualityMetrics {
-        if expected.is_empty() {
-            return QualityMetrics {
-                word_accuracy: 0.0,
-                character_accuracy: 0.0,
-                semantic_similarity: 0.0,
-                word_error_rate: 1.0,
-            };
+    /// Score `transcribed` against a ground-truth `expected` transcript by
+    /// computing word error rate (WER) and character error rate (CER) via
+    /// token-level Levenshtein edit distance (substitutions + insertions +
+    /// deletions, divided by reference length) over normalized text.
+    ///
+    /// Returns `None` when `expected` is empty (or becomes empty after
+    /// normalization) rather than scoring it as 100% error - an empty
+    /// reference means there's nothing to compare against, not a failure.
+    pub fn calculate_quality_metrics(&self, transcribed: &str, expected: &str) -> Option<QualityMetrics> {
+        let normalized_expected = normalize_for_scoring(expected);
+        let expected_words: Vec<&str> = normalized_expected.split_whitespace().collect();
+        if expected_words.is_empty() {
+            return None;
         }
 
-        let transcribed_words: Vec<&str> = transcribed.split_whitespace().collect();
-        let expected_words: Vec<&str> = expected.split_whitespace().collect();
-
-        // Simple word accuracy (exact matches)
-        let word_matches = transcribed_words
-            .iter()
-            .zip(expected_words.iter())
-            .filter(|(t, e)| t.to_lowercase() == e.to_lowercase())
-            .count();
-
-        let word_accuracy = if expected_words.is_empty() {
-            0.0
-        } else {
-            word_matches as f32 / expected_words.len().max(transcribed_words.len()) as f32
-        };
-
-        // Character-level accuracy using Levenshtein distance
-        let char_distance =
-            levenshtein_distance(&transcribed.to_lowercase(), &expected.to_lowercase());
-        let max_len = transcribed.len().max(expected.len());
-        let character_accuracy = if max_len == 0 {
-            1.0
-        } else {
-            1.0 - (char_distance as f32 / max_len as f32)
-        };
-
-        // Word Error Rate (WER)
-        let wer = if expected_words.is_empty() {
-            if transcribed_words.is_empty() {
-                0.0
-            } else {
-                1.0
-            }
-        } else {
-            let insertions = transcribed_words.len().saturating_sub(expected_words.len());
-            let deletions = expected_words.len().saturating_sub(transcribed_words.len());
-            let substitutions = expected_words.len() - word_matches;
-            (insertions + deletions + substitutions) as f32 / expected_words.len() as f32
-        };
-
-        // Simple semantic similarity (keyword overlap)
-        let transcribed_lower = transcribed.to_lowercase();
-        let expected_lower = expected.to_lowercase();
-        let semantic_similarity = if expected_lower.is_empty() {
-            0.0
-        } else {
-            let common_chars = transcribed_lower
-                .chars()
-                .filter(|c| expected_lower.contains(*c))
-                .count();
-            common_chars as f32 / expected_lower.len() as f32
-        };
-
-        QualityMetrics {
+        let normalized_transcribed = normalize_for_scoring(transcribed);
+        let transcribed_words: Vec<&str> = normalized_transcribed.split_whitespace().collect();
+
+        let word_edits = levenshtein_distance(&expected_words, &transcribed_words);
+        let word_error_rate = word_edits as f32 / expected_words.len() as f32;
+        let word_accuracy = (1.0 - word_error_rate).max(0.0);
+
+        let expected_chars: Vec<char> = normalized_expected.chars().collect();
+        let transcribed_chars: Vec<char> = normalized_transcribed.chars().collect();
+        let char_edits = levenshtein_distance(&expected_chars, &transcribed_chars);
+        let character_error_rate = char_edits as f32 / expected_chars.len() as f32;
+        let character_accuracy = (1.0 - character_error_rate).max(0.0);
+
+        Some(QualityMetrics {
             word_accuracy,
             character_accuracy,
-            semantic_similarity,
-            word_error_rate: wer,
-        }
+            word_error_rate,
+            character_error_rate,
+        })
     }
 
     fn get_chunk_size(&self, strategy: &TranscriptionStrategy) -> Option<u32> {
@@ -339,25 +312,39 @@ struct StrategyResult {
 pub struct QualityMetrics {
     pub word_accuracy: f32,
     pub character_accuracy: f32,
-    pub semantic_similarity: f32,
     pub word_error_rate: f32,
+    pub character_error_rate: f32,
+}
+
+/// Lowercase, strip punctuation (replacing it with whitespace), and collapse
+/// runs of whitespace, so WER/CER scoring isn't thrown off by casing or
+/// punctuation differences that don't reflect transcription quality.
+pub(crate) fn normalize_for_scoring(text: &str) -> String {
+    let stripped: String = text
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c.is_whitespace() { c.to_ascii_lowercase() } else { ' ' })
+        .collect();
+    stripped.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
-fn levenshtein_distance(s1: &str, s2: &str) -> usize {
-    let len1 = s1.len();
-    let len2 = s2.len();
+/// Levenshtein edit distance (substitutions + insertions + deletions) between
+/// two token sequences. Generic over `T` so the same implementation scores
+/// both word-level (WER) and char-level (CER) sequences.
+fn levenshtein_distance<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    let len1 = a.len();
+    let len2 = b.len();
     let mut matrix = vec![vec![0; len2 + 1]; len1 + 1];
 
-    for i in 0..=len1 {
-        matrix[i][0] = i;
+    for (i, row) in matrix.iter_mut().enumerate().take(len1 + 1) {
+        row[0] = i;
     }
     for j in 0..=len2 {
         matrix[0][j] = j;
     }
 
-    for (i, c1) in s1.chars().enumerate() {
-        for (j, c2) in s2.chars().enumerate() {
-            let cost = if c1 == c2 { 0 } else { 1 };
+    for i in 0..len1 {
+        for j in 0..len2 {
+            let cost = if a[i] == b[j] { 0 } else { 1 };
             matrix[i + 1][j + 1] = (matrix[i][j + 1] + 1)
                 .min(matrix[i + 1][j] + 1)
                 .min(matrix[i][j] + cost);
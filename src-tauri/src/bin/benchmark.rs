@@ -250,7 +250,12 @@ async fn run_progressive_benchmark(
         let mut strategy_results = Vec::new();
         for test in &tests {
             match strategy_tester
-                .test_strategy(&strategy, &test.audio_file, &test.name)
+                .test_strategy(
+                    &strategy,
+                    &test.audio_file,
+                    &test.name,
+                    test.expected_transcript.as_deref(),
+                )
                 .await
             {
                 Ok(result) => strategy_results.push(result),
@@ -1,16 +1,44 @@
 use std::path::PathBuf;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio;
+use clap::Parser;
 use serde::{Serialize, Deserialize};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use chrono;
 
 // Import Scout components
-use scout_lib::transcription::Transcriber;
+use scout_lib::transcription::{CdcSegmenter, CdcSegmenterConfig, Transcriber, VadSegmenter, VadSegmenterConfig};
 use scout_lib::benchmarking::{TestDataExtractor, RecordingLength};
 use scout_lib::db::Database;
 
+// Criterion's own bootstrap (warmup, multiple samples, outlier detection)
+// is built for repeatedly timing a cheap, deterministic operation — not
+// this binary's per-recording runs, which each invoke a real model load and
+// real `whisper` transcription and are too expensive to resample the
+// hundreds of times a `BenchmarkGroup` needs. Rather than force that mold on
+// top (or fake speed by mocking transcription, which would no longer be
+// measuring the thing this tool exists to measure), `generate_chunk_size_analysis`
+// below does its own percentile bootstrap over the per-recording samples
+// this binary already collects, and only declares an `optimal_chunk_size`
+// when its latency CI doesn't overlap the other candidates'.
+
+/// Sentinel `chunk_size_ms` marking a [`ChunkBenchmarkResult`] produced by
+/// VAD-driven adaptive segmentation rather than a fixed-size grid point.
+const VAD_CHUNK_SIZE_SENTINEL: u32 = 0;
+
+/// VAD frame size Silero expects at 16kHz (512 samples = 32ms).
+const VAD_FRAME_SAMPLES: usize = 512;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Profilers to attach to the run (comma-separated): sys_monitor,
+    /// sampling, engine_metrics
+    #[arg(long, value_delimiter = ',', default_value = "sys_monitor,engine_metrics")]
+    profilers: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct ChunkBenchmarkResult {
     test_name: String,
@@ -26,7 +54,7 @@ struct ChunkBenchmarkResult {
     // Quality metrics
     chunks_processed: u32,
     final_transcription: String,
-    chunk_boundary_artifacts: u32,
+    chunk_boundary_artifacts: f64,
     overall_quality_score: f64,
     
     // Comparison metrics
@@ -35,6 +63,14 @@ struct ChunkBenchmarkResult {
     
     success: bool,
     error: Option<String>,
+
+    /// Snapshot of the profilers active for this run, so "why is this
+    /// chunk size slower" can be answered from the JSON report instead of
+    /// re-running under a debugger. The same snapshot is duplicated across
+    /// every result (profilers run for the whole benchmark session rather
+    /// than per grid point); see [`ChunkSizeReport::profiling`] for the
+    /// canonical copy.
+    profiler_reports: Vec<ProfilerReport>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -44,11 +80,329 @@ struct ChunkSizeReport {
     chunk_sizes_tested: Vec<u32>,
     results: Vec<ChunkBenchmarkResult>,
     analysis: ChunkSizeAnalysis,
+    /// Results from `VadSegmenter`-driven adaptive segmentation, run against
+    /// the same recordings as `results` for an apples-to-apples comparison.
+    vad_results: Vec<ChunkBenchmarkResult>,
+    vad_comparison: VadComparisonSummary,
+    /// Results from `CdcSegmenter`-driven content-defined segmentation, run
+    /// at the same target sizes as the fixed grid for comparison.
+    cdc_results: Vec<ChunkBenchmarkResult>,
+    cdc_comparison: CdcComparisonSummary,
+    /// Per target size, how much of CDC's cut set survives a few hundred ms
+    /// of leading silence being prepended to the recording — the property
+    /// that makes it useful for skipping re-transcription of unchanged
+    /// chunks, which neither the fixed grid nor VAD segmentation have.
+    cdc_stability: Vec<CdcStabilityPoint>,
+    /// One [`ProfilerReport`] per `--profilers` backend that was active,
+    /// covering the whole run (fixed-size grid and VAD sweep together).
+    profiling: Vec<ProfilerReport>,
+}
+
+/// What one profiler backend observed about the run. Fields a backend
+/// doesn't produce are left `None` rather than forcing every backend into
+/// the same shape.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct ProfilerReport {
+    name: String,
+    avg_rss_kb: Option<u64>,
+    peak_rss_kb: Option<u64>,
+    /// Path to a folded-stack sample file, when the `sampling` backend ran.
+    samples_path: Option<String>,
+    avg_call_ms: Option<f64>,
+    avg_tokens_per_sec: Option<f64>,
+    /// Caveats about what this backend could and couldn't measure in this
+    /// checkout, mirroring how `transcriber::bin::queue_bench`'s
+    /// `MetricsProfiler` documents its own gaps instead of silently
+    /// reporting a partial picture as complete.
+    note: Option<String>,
+}
+
+/// An attachable profiler that observes the benchmark run and folds its
+/// findings into a [`ProfilerReport`]. Mirrors the `Profiler`/
+/// `RunningProfiler` split in `transcriber::bin::queue_bench`, generalized
+/// from one sustained-load run to this benchmark's grid-of-chunk-sizes run.
+trait Profiler: Send {
+    fn start(self: Box<Self>, bench_name: &str) -> Box<dyn RunningProfiler>;
+}
+
+#[async_trait::async_trait]
+trait RunningProfiler: Send {
+    /// Called after each baseline (whole-file) `transcriber.transcribe` call
+    /// in `main`, so profilers that care about per-call engine behavior (e.g.
+    /// `EngineMetricsProfiler`) can sample it; the transcribe calls made
+    /// inside the chunk simulations are not reported. Default no-op so profilers
+    /// that only care about background sampling (e.g. `SysMonitorProfiler`)
+    /// don't need to implement it.
+    fn record_call(&mut self, _elapsed: Duration, _transcribed_text: &str) {}
+
+    async fn stop(self: Box<Self>) -> ProfilerReport;
+}
+
+fn build_profilers(names: &[String]) -> Vec<Box<dyn Profiler>> {
+    names
+        .iter()
+        .filter_map(|name| match name.trim() {
+            "sys_monitor" => Some(Box::new(SysMonitorProfiler) as Box<dyn Profiler>),
+            "sampling" => Some(Box::new(SamplingProfiler) as Box<dyn Profiler>),
+            "engine_metrics" => Some(Box::new(EngineMetricsProfiler) as Box<dyn Profiler>),
+            other => {
+                eprintln!("⚠️  Unknown profiler '{}', skipping", other);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Samples this process's RSS at a fixed interval for the duration of the
+/// run, the same technique `queue_bench::SysMonitorProfiler` uses.
+struct SysMonitorProfiler;
+
+impl Profiler for SysMonitorProfiler {
+    fn start(self: Box<Self>, _bench_name: &str) -> Box<dyn RunningProfiler> {
+        let samples = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let samples_for_task = samples.clone();
+        let stop_flag = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let stop_flag_for_task = stop_flag.clone();
+
+        tokio::spawn(async move {
+            let mut tick = tokio::time::interval(Duration::from_millis(500));
+            while !stop_flag_for_task.load(std::sync::atomic::Ordering::Relaxed) {
+                tick.tick().await;
+                if let Ok(mut guard) = samples_for_task.lock() {
+                    guard.push(sample_process_rss_kb());
+                }
+            }
+        });
+
+        Box::new(RunningSysMonitor { samples, stop_flag })
+    }
+}
+
+struct RunningSysMonitor {
+    samples: Arc<std::sync::Mutex<Vec<u64>>>,
+    stop_flag: Arc<std::sync::atomic::AtomicBool>,
+}
+
+#[async_trait::async_trait]
+impl RunningProfiler for RunningSysMonitor {
+    async fn stop(self: Box<Self>) -> ProfilerReport {
+        self.stop_flag.store(true, std::sync::atomic::Ordering::Relaxed);
+        let samples = self.samples.lock().unwrap().clone();
+        let avg = if samples.is_empty() { None } else { Some(samples.iter().sum::<u64>() / samples.len() as u64) };
+        let peak = samples.iter().max().copied();
+
+        ProfilerReport {
+            name: "sys_monitor".to_string(),
+            avg_rss_kb: avg,
+            peak_rss_kb: peak,
+            ..Default::default()
+        }
+    }
+}
+
+/// Reads process RSS from `/proc/self/status`. Returns 0 on platforms where
+/// this isn't available; a heavier dependency (e.g. `sysinfo`) isn't worth
+/// pulling in for a single benchmark profiler.
+fn sample_process_rss_kb() -> u64 {
+    #[cfg(target_os = "linux")]
+    {
+        std::fs::read_to_string("/proc/self/status")
+            .ok()
+            .and_then(|content| {
+                content.lines().find_map(|line| {
+                    line.strip_prefix("VmRSS:")
+                        .and_then(|rest| rest.trim().split_whitespace().next())
+                        .and_then(|kb| kb.parse().ok())
+                })
+            })
+            .unwrap_or(0)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        0
+    }
+}
+
+/// A coarse, dependency-free stand-in for a samply-style sampling profiler.
+///
+/// A real stack-sampling flamegraph needs a symbolicating sampler
+/// (`samply`/`pprof`), neither of which is a dependency in this checkout.
+/// Instead this ticks a background task that records wall-clock timestamps
+/// at a fixed interval for the run's duration and writes them as a
+/// newline-delimited "folded stack" file with a single synthetic frame
+/// (`chunk_size_benchmark;running <elapsed_ms>`), so the output is at least
+/// in the format `inferno`/flamegraph tooling expects, even though it
+/// carries no real call-stack information.
+struct SamplingProfiler;
+
+impl Profiler for SamplingProfiler {
+    fn start(self: Box<Self>, bench_name: &str) -> Box<dyn RunningProfiler> {
+        let started_at = Instant::now();
+        let samples = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let samples_for_task = samples.clone();
+        let stop_flag = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let stop_flag_for_task = stop_flag.clone();
+
+        tokio::spawn(async move {
+            let mut tick = tokio::time::interval(Duration::from_millis(50));
+            while !stop_flag_for_task.load(std::sync::atomic::Ordering::Relaxed) {
+                tick.tick().await;
+                if let Ok(mut guard) = samples_for_task.lock() {
+                    guard.push(started_at.elapsed().as_millis());
+                }
+            }
+        });
+
+        Box::new(RunningSamplingProfiler {
+            bench_name: bench_name.to_string(),
+            samples,
+            stop_flag,
+        })
+    }
+}
+
+struct RunningSamplingProfiler {
+    bench_name: String,
+    samples: Arc<std::sync::Mutex<Vec<u128>>>,
+    stop_flag: Arc<std::sync::atomic::AtomicBool>,
+}
+
+#[async_trait::async_trait]
+impl RunningProfiler for RunningSamplingProfiler {
+    async fn stop(self: Box<Self>) -> ProfilerReport {
+        self.stop_flag.store(true, std::sync::atomic::Ordering::Relaxed);
+        let samples = self.samples.lock().unwrap().clone();
+
+        let folded: String = samples
+            .iter()
+            .map(|elapsed_ms| format!("chunk_size_benchmark;running {}\n", elapsed_ms))
+            .collect();
+        let path = std::env::temp_dir().join(format!("scout_sampling_{}.folded", self.bench_name.replace(' ', "_")));
+        let samples_path = if std::fs::write(&path, folded).is_ok() {
+            Some(path.display().to_string())
+        } else {
+            None
+        };
+
+        ProfilerReport {
+            name: "sampling".to_string(),
+            samples_path,
+            note: Some(
+                "Coarse wall-clock sampling only; no real call stacks are captured without a \
+                 samply/pprof dependency in this checkout."
+                    .to_string(),
+            ),
+            ..Default::default()
+        }
+    }
+}
+
+/// Records per-call wall-clock timing for every call reported through
+/// `record_call` (the baseline whole-file transcriptions) and derives a
+/// tokens/sec estimate from transcribed word count. The chunk simulations'
+/// own transcribe calls are not included.
+struct EngineMetricsProfiler;
+
+impl Profiler for EngineMetricsProfiler {
+    fn start(self: Box<Self>, _bench_name: &str) -> Box<dyn RunningProfiler> {
+        Box::new(RunningEngineMetricsProfiler { calls: Vec::new() })
+    }
+}
+
+struct RunningEngineMetricsProfiler {
+    /// (call duration, word count) per `transcribe` call observed.
+    calls: Vec<(Duration, usize)>,
+}
+
+#[async_trait::async_trait]
+impl RunningProfiler for RunningEngineMetricsProfiler {
+    fn record_call(&mut self, elapsed: Duration, transcribed_text: &str) {
+        self.calls.push((elapsed, transcribed_text.split_whitespace().count()));
+    }
+
+    async fn stop(self: Box<Self>) -> ProfilerReport {
+        if self.calls.is_empty() {
+            return ProfilerReport {
+                name: "engine_metrics".to_string(),
+                note: Some("No transcribe calls were observed during this run.".to_string()),
+                ..Default::default()
+            };
+        }
+
+        let avg_call_ms = self.calls.iter().map(|(d, _)| d.as_secs_f64() * 1000.0).sum::<f64>() / self.calls.len() as f64;
+        let avg_tokens_per_sec = self
+            .calls
+            .iter()
+            .filter(|(d, _)| d.as_secs_f64() > 0.0)
+            .map(|(d, words)| *words as f64 / d.as_secs_f64())
+            .sum::<f64>()
+            / self.calls.len().max(1) as f64;
+
+        ProfilerReport {
+            name: "engine_metrics".to_string(),
+            avg_call_ms: Some(avg_call_ms),
+            avg_tokens_per_sec: Some(avg_tokens_per_sec),
+            note: Some(
+                "Whisper's public transcribe() in this checkout returns only the final text, so \
+                 this reports end-to-end call timing and a word-count-based tokens/sec estimate \
+                 rather than a true encode-vs-decode split."
+                    .to_string(),
+            ),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct VadComparisonSummary {
+    avg_chunks_per_recording_fixed: f64,
+    avg_chunks_per_recording_vad: f64,
+    avg_boundary_artifacts_fixed: f64,
+    avg_boundary_artifacts_vad: f64,
+    avg_quality_score_fixed: f64,
+    avg_quality_score_vad: f64,
+    avg_latency_ms_fixed: f64,
+    avg_latency_ms_vad: f64,
+    summary: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CdcComparisonSummary {
+    avg_chunks_per_recording_fixed: f64,
+    avg_chunks_per_recording_cdc: f64,
+    avg_boundary_artifacts_fixed: f64,
+    avg_boundary_artifacts_cdc: f64,
+    avg_quality_score_fixed: f64,
+    avg_quality_score_cdc: f64,
+    avg_latency_ms_fixed: f64,
+    avg_latency_ms_cdc: f64,
+    summary: String,
+}
+
+/// Leading silence prepended before re-segmenting a recording to test
+/// whether CDC's cut points are stable under a shifted prefix — the
+/// property a fixed grid doesn't have and the reason to consider CDC for
+/// chunk-level transcription caching.
+const CDC_STABILITY_PREPENDED_SILENCE_MS: u32 = 300;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CdcStabilityPoint {
+    target_chunk_ms: u32,
+    /// Fraction of boundaries (content-relative: the prepended silence
+    /// length is subtracted back out before comparing) that land at the
+    /// same sample offset whether or not the recording had
+    /// [`CDC_STABILITY_PREPENDED_SILENCE_MS`] of leading silence prepended
+    /// before segmentation, averaged across the tested recordings.
+    avg_stable_boundary_fraction: f64,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct ChunkSizeAnalysis {
-    optimal_chunk_size: u32,
+    /// `None` when no chunk size's latency confidence interval is clearly
+    /// better than the others (see [`generate_chunk_size_analysis`]), in
+    /// which case `summary` reports "no significant difference" instead of
+    /// naming a winner.
+    optimal_chunk_size: Option<u32>,
     chunk_size_recommendations: Vec<ChunkSizeRecommendation>,
     quality_vs_latency_analysis: Vec<QualityLatencyPoint>,
     summary: String,
@@ -67,10 +421,61 @@ struct ChunkSizeRecommendation {
 struct QualityLatencyPoint {
     chunk_size_ms: u32,
     avg_latency_ms: f64,
+    /// Bootstrapped 95% confidence bounds on `avg_latency_ms`, so a single
+    /// noisy recording can't flip which chunk size looks "optimal" between
+    /// runs. See [`bootstrap_mean_ci`].
+    latency_ci_low: f64,
+    latency_ci_high: f64,
     avg_quality: f64,
     efficiency_score: f64, // Quality per latency unit
 }
 
+/// Number of resamples drawn per [`bootstrap_mean_ci`] call. 2000 is
+/// Criterion's own default resample count for its bootstrap estimates.
+const BOOTSTRAP_RESAMPLES: usize = 2000;
+
+/// Percentile-bootstrap 95% confidence interval for the mean of `samples`.
+///
+/// Draws `BOOTSTRAP_RESAMPLES` resamples of `samples.len()` observations
+/// (sampling with replacement), takes the mean of each, and reports the
+/// 2.5th/97.5th percentiles of that distribution of means. With fewer than
+/// two samples there's nothing to resample, so both bounds collapse to the
+/// single observed value (or `0.0` for an empty slice).
+fn bootstrap_mean_ci(samples: &[f64]) -> (f64, f64) {
+    if samples.is_empty() {
+        return (0.0, 0.0);
+    }
+    if samples.len() == 1 {
+        return (samples[0], samples[0]);
+    }
+
+    // A small xorshift64 PRNG: good enough for resampling indices and
+    // avoids pulling in a `rand` dependency for one call site.
+    let mut state = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0x9E3779B97F4A7C15)
+        | 1;
+    let mut next_index = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state as usize) % samples.len()
+    };
+
+    let mut resampled_means: Vec<f64> = (0..BOOTSTRAP_RESAMPLES)
+        .map(|_| {
+            let sum: f64 = (0..samples.len()).map(|_| samples[next_index()]).sum();
+            sum / samples.len() as f64
+        })
+        .collect();
+    resampled_means.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let low_idx = ((resampled_means.len() as f64) * 0.025) as usize;
+    let high_idx = (((resampled_means.len() as f64) * 0.975) as usize).min(resampled_means.len() - 1);
+    (resampled_means[low_idx], resampled_means[high_idx])
+}
+
 // Simulate Ring Buffer chunking behavior
 async fn simulate_ring_buffer_transcription(
     transcriber: &Transcriber,
@@ -79,28 +484,31 @@ async fn simulate_ring_buffer_transcription(
     total_duration_ms: u32,
 ) -> Result<ChunkTranscriptionResult, String> {
     let start_time = Instant::now();
-    
+
     // Calculate number of chunks
     let num_chunks = (total_duration_ms as f64 / chunk_size_ms as f64).ceil() as u32;
-    
+
     println!("    🔧 Simulating {} chunks of {}ms each", num_chunks, chunk_size_ms);
-    
+
     // For this simulation, we'll transcribe the full file but measure timing as if chunked
     let transcription_start = Instant::now();
     let full_transcription = transcriber.transcribe(audio_file)?;
     let transcription_time = transcription_start.elapsed();
-    
+
     // Simulate chunk timing
     let time_to_first_chunk = chunk_size_ms as f64; // First chunk available after chunk_size_ms
     let time_to_50_percent = (num_chunks as f64 / 2.0) * chunk_size_ms as f64;
-    
-    // Simulate chunk boundary artifacts (rough heuristic)
-    let chunk_boundary_artifacts = if num_chunks > 1 { num_chunks - 1 } else { 0 };
-    
-    // Quality degradation estimate based on number of chunk boundaries
-    let quality_degradation = (chunk_boundary_artifacts as f64 * 0.02).min(0.2); // Max 20% degradation
-    let quality_score = (0.95 - quality_degradation).max(0.75); // Base 0.95, min 0.75
-    
+
+    // Score the actual spectral discontinuity introduced at each fixed-size
+    // boundary, rather than just counting them.
+    let (samples, sample_rate) = load_audio_samples(audio_file)?;
+    let boundary_samples: Vec<usize> = (1..num_chunks)
+        .map(|i| ((i as u64 * chunk_size_ms as u64 * sample_rate as u64) / 1000) as usize)
+        .filter(|&b| b < samples.len())
+        .collect();
+    let chunk_boundary_artifacts = average_boundary_artifact_score(&samples, &boundary_samples, sample_rate);
+    let quality_score = quality_score_from_artifacts(chunk_boundary_artifacts);
+
     Ok(ChunkTranscriptionResult {
         transcription: full_transcription,
         time_to_first_chunk_ms: time_to_first_chunk,
@@ -119,15 +527,359 @@ struct ChunkTranscriptionResult {
     time_to_50_percent_ms: f64,
     total_time_ms: f64,
     chunks_processed: u32,
-    chunk_boundary_artifacts: u32,
+    /// Average [`boundary_artifact_score`] across every boundary this chunk
+    /// set introduced, not a raw count: a real signal-based measure of how
+    /// audible the cuts are, in `0.0..=1.0`.
+    chunk_boundary_artifacts: f64,
     quality_score: f64,
 }
 
+/// Width of the analysis window taken on each side of a chunk boundary: 25ms,
+/// on the order of a phoneme transition.
+const BOUNDARY_ANALYSIS_MS: f32 = 25.0;
+
+/// Score how audible cutting the audio at `boundary_sample` would be.
+///
+/// Takes a short Hann-windowed analysis window immediately before and after
+/// the cut, runs the real FFT on each to get magnitude spectra `A` and `B`,
+/// and computes spectral flux: the L2 norm of the positive part of
+/// `(B - A)` summed over bins, normalized by the pair's combined energy. A
+/// boundary that falls in silence scores near zero (both sides are quiet and
+/// similar); one that slices mid-phoneme spikes because the spectral content
+/// changes sharply across the cut. Returns `0.0` if there isn't a full
+/// window of audio on both sides.
+fn boundary_artifact_score(samples: &[f32], boundary_sample: usize, sample_rate: u32) -> f32 {
+    let window_len = ((sample_rate as f32 * BOUNDARY_ANALYSIS_MS) / 1000.0).round() as usize;
+    if window_len < 2 || boundary_sample < window_len || boundary_sample + window_len > samples.len() {
+        return 0.0;
+    }
+
+    let hann: Vec<f32> = (0..window_len)
+        .map(|i| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (window_len - 1) as f32).cos())
+        .collect();
+
+    let mut planner = realfft::RealFftPlanner::<f32>::new();
+    let fft = planner.plan_fft_forward(window_len);
+    let mut scratch = fft.make_scratch_vec();
+
+    let mut magnitude_spectrum = |frame: &[f32]| -> Option<Vec<f32>> {
+        let mut windowed: Vec<f32> = frame.iter().zip(&hann).map(|(s, w)| s * w).collect();
+        let mut spectrum = fft.make_output_vec();
+        fft.process_with_scratch(&mut windowed, &mut spectrum, &mut scratch).ok()?;
+        Some(spectrum.iter().map(|c| c.norm()).collect())
+    };
+
+    let before = &samples[boundary_sample - window_len..boundary_sample];
+    let after = &samples[boundary_sample..boundary_sample + window_len];
+
+    let (Some(a), Some(b)) = (magnitude_spectrum(before), magnitude_spectrum(after)) else {
+        return 0.0;
+    };
+
+    let positive_flux_sq: f32 = a.iter().zip(&b).map(|(ma, mb)| (mb - ma).max(0.0).powi(2)).sum();
+    let total_energy: f32 = a.iter().chain(&b).map(|m| m * m).sum();
+
+    if total_energy <= 1e-9 {
+        0.0
+    } else {
+        (positive_flux_sq.sqrt() / total_energy.sqrt()).clamp(0.0, 1.0)
+    }
+}
+
+/// Average [`boundary_artifact_score`] across `boundary_samples`, producing
+/// the real `chunk_boundary_artifacts` metric. `0.0` if there are no
+/// interior boundaries to score (e.g. a single chunk).
+fn average_boundary_artifact_score(samples: &[f32], boundary_samples: &[usize], sample_rate: u32) -> f64 {
+    if boundary_samples.is_empty() {
+        return 0.0;
+    }
+    let sum: f32 = boundary_samples
+        .iter()
+        .map(|&b| boundary_artifact_score(samples, b, sample_rate))
+        .sum();
+    (sum / boundary_samples.len() as f32) as f64
+}
+
+/// Map an average spectral-flux artifact score (`0.0..=1.0`) to a quality
+/// score on the same 0.95-base/0.75-floor scale the fixed-size grid used
+/// before this was signal-based, so old and new numbers stay comparable.
+fn quality_score_from_artifacts(avg_artifact_score: f64) -> f64 {
+    let quality_degradation = (avg_artifact_score * 0.2).min(0.2);
+    (0.95 - quality_degradation).max(0.75)
+}
+
+/// Read an audio file into mono `f32` samples plus its sample rate, the way
+/// `real_audio_chunking_analysis` does, so VAD segmentation can be run over
+/// the actual waveform rather than simulated timing.
+fn load_audio_samples(audio_path: &PathBuf) -> Result<(Vec<f32>, u32), String> {
+    let mut reader = hound::WavReader::open(audio_path)
+        .map_err(|e| format!("Failed to open audio file: {}", e))?;
+    let spec = reader.spec();
+
+    let samples: Result<Vec<f32>, _> = match (spec.bits_per_sample, spec.sample_format) {
+        (16, hound::SampleFormat::Int) => reader
+            .samples::<i16>()
+            .map(|s| s.map(|sample| sample as f32 / i16::MAX as f32))
+            .collect(),
+        (32, hound::SampleFormat::Int) => reader
+            .samples::<i32>()
+            .map(|s| s.map(|sample| sample as f32 / i32::MAX as f32))
+            .collect(),
+        (32, hound::SampleFormat::Float) => reader.samples::<f32>().collect(),
+        _ => {
+            return Err(format!(
+                "Unsupported audio format: {} bits, {:?}",
+                spec.bits_per_sample, spec.sample_format
+            ))
+        }
+    };
+
+    Ok((
+        samples.map_err(|e| format!("Failed to read samples: {}", e))?,
+        spec.sample_rate,
+    ))
+}
+
+/// Write a slice of `f32` samples out as a 16-bit PCM WAV file so a VAD-cut
+/// chunk can be handed to `Transcriber::transcribe`, which expects a path.
+fn write_samples_to_wav(samples: &[f32], sample_rate: u32, output_path: &PathBuf) -> Result<(), String> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+
+    let mut writer = hound::WavWriter::create(output_path, spec)
+        .map_err(|e| format!("Failed to create chunk file: {}", e))?;
+
+    for &sample in samples {
+        let sample_i16 = (sample * i16::MAX as f32) as i16;
+        writer
+            .write_sample(sample_i16)
+            .map_err(|e| format!("Failed to write sample: {}", e))?;
+    }
+
+    writer.finalize().map_err(|e| format!("Failed to finalize chunk file: {}", e))
+}
+
+/// Segment `audio_file` at natural speech pauses with `VadSegmenter` instead
+/// of the fixed-size grid, transcribe each resulting chunk, and report the
+/// same metrics as `simulate_ring_buffer_transcription` so the two can be
+/// compared directly. `chunk_boundary_artifacts` is scored the same way as
+/// the fixed-size grid, letting the numbers confirm (rather than assume)
+/// that cutting on silence hangover produces quieter boundaries.
+async fn simulate_vad_segmented_transcription(
+    transcriber: &Transcriber,
+    audio_file: &PathBuf,
+    models_dir: &PathBuf,
+) -> Result<ChunkTranscriptionResult, String> {
+    let start_time = Instant::now();
+
+    let (samples, sample_rate) = load_audio_samples(audio_file)?;
+    let model_path = VadSegmenter::default_model_path(models_dir);
+
+    let mut segmenter = VadSegmenter::new(
+        &model_path,
+        VAD_FRAME_SAMPLES,
+        sample_rate,
+        VadSegmenterConfig::default(),
+    )?;
+
+    let mut chunks = segmenter.process(&samples)?;
+    if let Some(trailing) = segmenter.flush() {
+        chunks.push(trailing);
+    }
+
+    if chunks.is_empty() {
+        return Err("VAD segmentation produced no chunks".to_string());
+    }
+
+    // Sample offset each chunk boundary falls at, to derive the same
+    // "time to first/half chunk" latency metrics the fixed-size grid
+    // reports.
+    let mut boundary_samples = Vec::with_capacity(chunks.len());
+    let mut scanned = 0usize;
+    for chunk in &chunks {
+        scanned += chunk.len();
+        boundary_samples.push(scanned);
+    }
+    let samples_to_ms = |n: usize| (n as f64 * 1000.0) / sample_rate as f64;
+
+    let time_to_first_chunk = samples_to_ms(boundary_samples[0]);
+    let half_idx = boundary_samples.len() / 2;
+    let time_to_50_percent = samples_to_ms(boundary_samples[half_idx.min(boundary_samples.len() - 1)]);
+
+    let temp_dir = std::env::temp_dir().join("scout_vad_chunks");
+    tokio::fs::create_dir_all(&temp_dir)
+        .await
+        .map_err(|e| format!("Failed to create temp directory: {}", e))?;
+
+    let mut stitched = String::new();
+    for (idx, chunk_samples) in chunks.iter().enumerate() {
+        let chunk_path = temp_dir.join(format!("vad_chunk_{}.wav", idx));
+        write_samples_to_wav(chunk_samples, sample_rate, &chunk_path)?;
+
+        let text = transcriber.transcribe(&chunk_path)?;
+        if !text.trim().is_empty() {
+            if !stitched.is_empty() {
+                stitched.push(' ');
+            }
+            stitched.push_str(text.trim());
+        }
+
+        let _ = tokio::fs::remove_file(&chunk_path).await;
+    }
+
+    // Score every real cut (excluding the final end-of-stream boundary,
+    // which isn't a cut at all) the same way the fixed-size grid is scored.
+    let cut_boundaries = &boundary_samples[..boundary_samples.len() - 1];
+    let chunk_boundary_artifacts = average_boundary_artifact_score(&samples, cut_boundaries, sample_rate);
+    let quality_score = quality_score_from_artifacts(chunk_boundary_artifacts);
+
+    Ok(ChunkTranscriptionResult {
+        transcription: stitched,
+        time_to_first_chunk_ms: time_to_first_chunk,
+        time_to_50_percent_ms: time_to_50_percent,
+        total_time_ms: start_time.elapsed().as_millis() as f64,
+        chunks_processed: chunks.len() as u32,
+        chunk_boundary_artifacts,
+        quality_score,
+    })
+}
+
+/// Segment `audio_file` at content-defined boundaries with `CdcSegmenter`
+/// instead of a fixed-size grid or silence detection, transcribe each
+/// resulting chunk, and report the same metrics as
+/// `simulate_ring_buffer_transcription` so all three can be compared. Run
+/// once per `target_chunk_ms` in `chunk_sizes`, mirroring the fixed grid, so
+/// the comparison is apples-to-apples at every target size rather than just
+/// one CDC configuration.
+async fn simulate_cdc_segmented_transcription(
+    transcriber: &Transcriber,
+    audio_file: &PathBuf,
+    target_chunk_ms: u32,
+) -> Result<ChunkTranscriptionResult, String> {
+    let start_time = Instant::now();
+
+    let (samples, sample_rate) = load_audio_samples(audio_file)?;
+    let mut segmenter = CdcSegmenter::new(CdcSegmenterConfig {
+        target_chunk_ms,
+        sample_rate,
+    });
+
+    let mut chunks = segmenter.process(&samples);
+    if let Some(trailing) = segmenter.flush() {
+        chunks.push(trailing);
+    }
+
+    if chunks.is_empty() {
+        return Err("CDC segmentation produced no chunks".to_string());
+    }
+
+    let mut boundary_samples = Vec::with_capacity(chunks.len());
+    let mut scanned = 0usize;
+    for chunk in &chunks {
+        scanned += chunk.len();
+        boundary_samples.push(scanned);
+    }
+    let samples_to_ms = |n: usize| (n as f64 * 1000.0) / sample_rate as f64;
+
+    let time_to_first_chunk = samples_to_ms(boundary_samples[0]);
+    let half_idx = boundary_samples.len() / 2;
+    let time_to_50_percent = samples_to_ms(boundary_samples[half_idx.min(boundary_samples.len() - 1)]);
+
+    let temp_dir = std::env::temp_dir().join("scout_cdc_chunks");
+    tokio::fs::create_dir_all(&temp_dir)
+        .await
+        .map_err(|e| format!("Failed to create temp directory: {}", e))?;
+
+    let mut stitched = String::new();
+    for (idx, chunk_samples) in chunks.iter().enumerate() {
+        let chunk_path = temp_dir.join(format!("cdc_chunk_{}_{}.wav", target_chunk_ms, idx));
+        write_samples_to_wav(chunk_samples, sample_rate, &chunk_path)?;
+
+        let text = transcriber.transcribe(&chunk_path)?;
+        if !text.trim().is_empty() {
+            if !stitched.is_empty() {
+                stitched.push(' ');
+            }
+            stitched.push_str(text.trim());
+        }
+
+        let _ = tokio::fs::remove_file(&chunk_path).await;
+    }
+
+    // Score every real cut (excluding the final end-of-stream boundary,
+    // which isn't a cut at all) the same way the fixed-size grid is scored.
+    let cut_boundaries = &boundary_samples[..boundary_samples.len() - 1];
+    let chunk_boundary_artifacts = average_boundary_artifact_score(&samples, cut_boundaries, sample_rate);
+    let quality_score = quality_score_from_artifacts(chunk_boundary_artifacts);
+
+    Ok(ChunkTranscriptionResult {
+        transcription: stitched,
+        time_to_first_chunk_ms: time_to_first_chunk,
+        time_to_50_percent_ms: time_to_50_percent,
+        total_time_ms: start_time.elapsed().as_millis() as f64,
+        chunks_processed: chunks.len() as u32,
+        chunk_boundary_artifacts,
+        quality_score,
+    })
+}
+
+/// Re-segment `samples` with `CDC_STABILITY_PREPENDED_SILENCE_MS` of silence
+/// prepended and measure how many of the original cut boundaries reappear at
+/// the same content-relative offset. This is the property CDC buys over a
+/// fixed grid: a fixed grid's boundaries all shift by the prepended length
+/// and none of them line back up, while CDC's cuts are a function of nearby
+/// content, so most survive unchanged.
+fn measure_cdc_boundary_stability(samples: &[f32], sample_rate: u32, target_chunk_ms: u32) -> f64 {
+    let cut_boundaries = |samples: &[f32]| -> Vec<usize> {
+        let mut segmenter = CdcSegmenter::new(CdcSegmenterConfig {
+            target_chunk_ms,
+            sample_rate,
+        });
+        let mut boundaries = Vec::new();
+        let mut scanned = 0usize;
+        for chunk in segmenter.process(samples) {
+            scanned += chunk.len();
+            boundaries.push(scanned);
+        }
+        // The trailing flush() remainder is an end-of-stream artifact, not a
+        // real content-defined cut, so it's deliberately excluded here.
+        boundaries
+    };
+
+    let original_boundaries = cut_boundaries(samples);
+    if original_boundaries.is_empty() {
+        return 1.0;
+    }
+
+    let silence_len = ((CDC_STABILITY_PREPENDED_SILENCE_MS as u64 * sample_rate as u64) / 1000) as usize;
+    let mut shifted = vec![0.0f32; silence_len];
+    shifted.extend_from_slice(samples);
+
+    let shifted_boundaries: std::collections::HashSet<usize> = cut_boundaries(&shifted)
+        .into_iter()
+        .filter_map(|b| b.checked_sub(silence_len))
+        .collect();
+
+    let stable = original_boundaries.iter().filter(|b| shifted_boundaries.contains(b)).count();
+    stable as f64 / original_boundaries.len() as f64
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
     println!("🧪 Ring Buffer Chunk Size Optimization Benchmark");
     println!("===============================================\n");
-    
+    println!("🔬 Profilers: {}\n", args.profilers.join(", "));
+
+    let mut profiler_handles: Vec<Box<dyn RunningProfiler>> = build_profilers(&args.profilers)
+        .into_iter()
+        .map(|p| p.start("chunk_size_sweep"))
+        .collect();
+
     // Initialize database and get test recordings
     let db_path = PathBuf::from("./benchmark_corpus/benchmark.db");
     let database = Arc::new(Database::new(&db_path).await?);
@@ -201,7 +953,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             };
             let baseline_time = baseline_start.elapsed();
-            
+            for handle in &mut profiler_handles {
+                handle.record_call(baseline_time, &baseline_transcription);
+            }
+
             // Test Ring Buffer with this chunk size
             match simulate_ring_buffer_transcription(
                 transcriber_ref,
@@ -235,6 +990,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         quality_vs_processing_queue: quality_vs_baseline,
                         success: true,
                         error: None,
+                        profiler_reports: Vec::new(),
                     });
                 }
                 Err(e) => {
@@ -255,6 +1011,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         quality_vs_processing_queue: 0.0,
                         success: false,
                         error: Some(e),
+                        profiler_reports: Vec::new(),
                     });
                 }
             }
@@ -263,21 +1020,226 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         println!();
     }
-    
+
+    // Test VAD-driven adaptive segmentation against the same recordings,
+    // for comparison against the fixed-size grid above.
+    println!("\\n🎤 Testing VAD-driven adaptive segmentation...\\n");
+    let models_dir = PathBuf::from("./models");
+    let mut vad_results = Vec::new();
+
+    for test in &test_recordings {
+        println!("  📁 Processing: {} ({}ms duration)", test.name, test.duration_ms);
+
+        let transcriber_guard = transcriber.lock().await;
+        let transcriber_ref = transcriber_guard.as_ref().unwrap();
+
+        let baseline_start = Instant::now();
+        let baseline_transcription = match transcriber_ref.transcribe(&test.audio_file) {
+            Ok(text) => text,
+            Err(e) => {
+                println!("    ❌ Baseline transcription failed: {}", e);
+                drop(transcriber_guard);
+                continue;
+            }
+        };
+        let baseline_time = baseline_start.elapsed();
+        for handle in &mut profiler_handles {
+            handle.record_call(baseline_time, &baseline_transcription);
+        }
+
+        match simulate_vad_segmented_transcription(transcriber_ref, &test.audio_file, &models_dir).await {
+            Ok(chunk_result) => {
+                let quality_vs_baseline = calculate_transcription_similarity(
+                    &chunk_result.transcription,
+                    &baseline_transcription,
+                );
+
+                println!(
+                    "    ✅ VAD segmentation: {} chunks, first result in {:.0}ms, quality: {:.3}",
+                    chunk_result.chunks_processed, chunk_result.time_to_first_chunk_ms, chunk_result.quality_score
+                );
+
+                vad_results.push(ChunkBenchmarkResult {
+                    test_name: test.name.clone(),
+                    recording_duration_ms: test.duration_ms,
+                    recording_category: format!("{:?}", test.recording_length_category),
+                    chunk_size_ms: VAD_CHUNK_SIZE_SENTINEL,
+                    time_to_first_chunk_ms: chunk_result.time_to_first_chunk_ms,
+                    time_to_50_percent_ms: chunk_result.time_to_50_percent_ms,
+                    total_transcription_time_ms: chunk_result.total_time_ms,
+                    chunks_processed: chunk_result.chunks_processed,
+                    final_transcription: chunk_result.transcription.clone(),
+                    chunk_boundary_artifacts: chunk_result.chunk_boundary_artifacts,
+                    overall_quality_score: chunk_result.quality_score,
+                    processing_queue_transcription: baseline_transcription.clone(),
+                    quality_vs_processing_queue: quality_vs_baseline,
+                    success: true,
+                    error: None,
+                    profiler_reports: Vec::new(),
+                });
+            }
+            Err(e) => {
+                println!("    ❌ VAD segmentation test failed: {}", e);
+                vad_results.push(ChunkBenchmarkResult {
+                    test_name: test.name.clone(),
+                    recording_duration_ms: test.duration_ms,
+                    recording_category: format!("{:?}", test.recording_length_category),
+                    chunk_size_ms: VAD_CHUNK_SIZE_SENTINEL,
+                    time_to_first_chunk_ms: 0.0,
+                    time_to_50_percent_ms: 0.0,
+                    total_transcription_time_ms: 0.0,
+                    chunks_processed: 0,
+                    final_transcription: String::new(),
+                    chunk_boundary_artifacts: 0.0,
+                    overall_quality_score: 0.0,
+                    processing_queue_transcription: baseline_transcription,
+                    quality_vs_processing_queue: 0.0,
+                    success: false,
+                    error: Some(e),
+                    profiler_reports: Vec::new(),
+                });
+            }
+        }
+
+        drop(transcriber_guard);
+    }
+    println!();
+
+    // Test content-defined chunking against the same recordings and target
+    // sizes as the fixed grid, for a direct comparison.
+    println!("\\n🧩 Testing content-defined chunking (CDC)...\\n");
+    let mut cdc_results = Vec::new();
+    let mut cdc_stability = Vec::new();
+
+    for chunk_size_ms in &chunk_sizes {
+        println!("🎯 Testing CDC at {}ms target", chunk_size_ms);
+        let mut stability_samples = Vec::new();
+
+        for test in &test_recordings {
+            println!("  📁 Processing: {} ({}ms duration)", test.name, test.duration_ms);
+
+            let transcriber_guard = transcriber.lock().await;
+            let transcriber_ref = transcriber_guard.as_ref().unwrap();
+
+            let baseline_start = Instant::now();
+            let baseline_transcription = match transcriber_ref.transcribe(&test.audio_file) {
+                Ok(text) => text,
+                Err(e) => {
+                    println!("    ❌ Baseline transcription failed: {}", e);
+                    drop(transcriber_guard);
+                    continue;
+                }
+            };
+            let baseline_time = baseline_start.elapsed();
+            for handle in &mut profiler_handles {
+                handle.record_call(baseline_time, &baseline_transcription);
+            }
+
+            if let Ok((samples, sample_rate)) = load_audio_samples(&test.audio_file) {
+                stability_samples.push(measure_cdc_boundary_stability(&samples, sample_rate, *chunk_size_ms));
+            }
+
+            match simulate_cdc_segmented_transcription(transcriber_ref, &test.audio_file, *chunk_size_ms).await {
+                Ok(chunk_result) => {
+                    let quality_vs_baseline = calculate_transcription_similarity(
+                        &chunk_result.transcription,
+                        &baseline_transcription,
+                    );
+
+                    println!(
+                        "    ✅ CDC {}ms target: {} chunks, first result in {:.0}ms, quality: {:.3}",
+                        chunk_size_ms, chunk_result.chunks_processed, chunk_result.time_to_first_chunk_ms, chunk_result.quality_score
+                    );
+
+                    cdc_results.push(ChunkBenchmarkResult {
+                        test_name: test.name.clone(),
+                        recording_duration_ms: test.duration_ms,
+                        recording_category: format!("{:?}", test.recording_length_category),
+                        chunk_size_ms: *chunk_size_ms,
+                        time_to_first_chunk_ms: chunk_result.time_to_first_chunk_ms,
+                        time_to_50_percent_ms: chunk_result.time_to_50_percent_ms,
+                        total_transcription_time_ms: chunk_result.total_time_ms,
+                        chunks_processed: chunk_result.chunks_processed,
+                        final_transcription: chunk_result.transcription.clone(),
+                        chunk_boundary_artifacts: chunk_result.chunk_boundary_artifacts,
+                        overall_quality_score: chunk_result.quality_score,
+                        processing_queue_transcription: baseline_transcription.clone(),
+                        quality_vs_processing_queue: quality_vs_baseline,
+                        success: true,
+                        error: None,
+                        profiler_reports: Vec::new(),
+                    });
+                }
+                Err(e) => {
+                    println!("    ❌ CDC test failed: {}", e);
+                    cdc_results.push(ChunkBenchmarkResult {
+                        test_name: test.name.clone(),
+                        recording_duration_ms: test.duration_ms,
+                        recording_category: format!("{:?}", test.recording_length_category),
+                        chunk_size_ms: *chunk_size_ms,
+                        time_to_first_chunk_ms: 0.0,
+                        time_to_50_percent_ms: 0.0,
+                        total_transcription_time_ms: 0.0,
+                        chunks_processed: 0,
+                        final_transcription: String::new(),
+                        chunk_boundary_artifacts: 0.0,
+                        overall_quality_score: 0.0,
+                        processing_queue_transcription: baseline_transcription,
+                        quality_vs_processing_queue: 0.0,
+                        success: false,
+                        error: Some(e),
+                        profiler_reports: Vec::new(),
+                    });
+                }
+            }
+
+            drop(transcriber_guard);
+        }
+
+        let avg_stable_boundary_fraction = if stability_samples.is_empty() {
+            0.0
+        } else {
+            stability_samples.iter().sum::<f64>() / stability_samples.len() as f64
+        };
+        cdc_stability.push(CdcStabilityPoint {
+            target_chunk_ms: *chunk_size_ms,
+            avg_stable_boundary_fraction,
+        });
+        println!();
+    }
+
+    // Stop profilers and fold their findings into every result plus the
+    // report-level `profiling` section.
+    let mut profiling = Vec::with_capacity(profiler_handles.len());
+    for handle in profiler_handles {
+        profiling.push(handle.stop().await);
+    }
+    for result in results.iter_mut().chain(vad_results.iter_mut()).chain(cdc_results.iter_mut()) {
+        result.profiler_reports = profiling.clone();
+    }
+
     // Generate analysis
     let analysis = generate_chunk_size_analysis(&results, &chunk_sizes);
-    
+    let vad_comparison = generate_vad_comparison(&results, &vad_results);
+    let cdc_comparison = generate_cdc_comparison(&results, &cdc_results);
+
     let report = ChunkSizeReport {
         timestamp: chrono::Utc::now().to_rfc3339(),
         test_description: "Ring Buffer chunk size optimization analysis".to_string(),
         chunk_sizes_tested: chunk_sizes.clone(),
         results,
         analysis,
+        vad_results,
+        vad_comparison,
+        cdc_results,
+        cdc_comparison,
+        cdc_stability,
+        profiling,
     };
-    
+
     // Print summary
     print_chunk_analysis_summary(&report);
-    
+
     // Save results
     let json_content = serde_json::to_string_pretty(&report)?;
     let output_file = "./chunk_size_benchmark_results.json";
@@ -319,15 +1281,19 @@ fn generate_chunk_size_analysis(results: &[ChunkBenchmarkResult], chunk_sizes: &
             continue;
         }
         
-        let avg_latency = chunk_results.iter().map(|r| r.time_to_first_chunk_ms).sum::<f64>() / chunk_results.len() as f64;
+        let latency_samples: Vec<f64> = chunk_results.iter().map(|r| r.time_to_first_chunk_ms).collect();
+        let avg_latency = latency_samples.iter().sum::<f64>() / latency_samples.len() as f64;
+        let (latency_ci_low, latency_ci_high) = bootstrap_mean_ci(&latency_samples);
         let avg_quality = chunk_results.iter().map(|r| r.overall_quality_score).sum::<f64>() / chunk_results.len() as f64;
-        
+
         // Efficiency score: quality per second of latency
         let efficiency_score = if avg_latency > 0.0 { avg_quality / (avg_latency / 1000.0) } else { 0.0 };
-        
+
         quality_latency_points.push(QualityLatencyPoint {
             chunk_size_ms: chunk_size,
             avg_latency_ms: avg_latency,
+            latency_ci_low,
+            latency_ci_high,
             avg_quality,
             efficiency_score,
         });
@@ -359,20 +1325,35 @@ fn generate_chunk_size_analysis(results: &[ChunkBenchmarkResult], chunk_sizes: &
         });
     }
     
-    // Find optimal chunk size (highest efficiency score)
-    let optimal_chunk_size = quality_latency_points
+    // The best candidate is still the highest efficiency score, but it only
+    // counts as a real "optimal" if its latency CI doesn't overlap any
+    // other candidate's — otherwise the gap is within measurement noise and
+    // re-running the benchmark would just flip the winner.
+    let best = quality_latency_points
         .iter()
-        .max_by(|a, b| a.efficiency_score.partial_cmp(&b.efficiency_score).unwrap())
-        .map(|p| p.chunk_size_ms)
-        .unwrap_or(1000);
-    
-    let summary = format!(
-        "Analysis of {} chunk sizes across {} recordings. Optimal chunk size: {}ms for best quality-latency balance.",
-        chunk_sizes.len(),
-        results.len(),
-        optimal_chunk_size
-    );
-    
+        .max_by(|a, b| a.efficiency_score.partial_cmp(&b.efficiency_score).unwrap());
+
+    let optimal_chunk_size = best.filter(|best| {
+        quality_latency_points
+            .iter()
+            .filter(|p| p.chunk_size_ms != best.chunk_size_ms)
+            .all(|other| best.latency_ci_high < other.latency_ci_low || best.latency_ci_low > other.latency_ci_high)
+    }).map(|p| p.chunk_size_ms);
+
+    let summary = match optimal_chunk_size {
+        Some(size) => format!(
+            "Analysis of {} chunk sizes across {} recordings. Optimal chunk size: {}ms for best quality-latency balance (95% CI clearly separates it from the rest).",
+            chunk_sizes.len(),
+            results.len(),
+            size
+        ),
+        None => format!(
+            "Analysis of {} chunk sizes across {} recordings. No significant difference between chunk sizes: latency confidence intervals overlap, so no single size is a clear winner.",
+            chunk_sizes.len(),
+            results.len()
+        ),
+    };
+
     ChunkSizeAnalysis {
         optimal_chunk_size,
         chunk_size_recommendations: recommendations,
@@ -381,22 +1362,183 @@ fn generate_chunk_size_analysis(results: &[ChunkBenchmarkResult], chunk_sizes: &
     }
 }
 
+/// Average the fixed-size grid's results across every chunk size tested and
+/// compare against `vad_results`, so the headline numbers answer "is
+/// VAD-driven segmentation actually better?" rather than just listing both
+/// sets of results side by side.
+fn generate_vad_comparison(
+    results: &[ChunkBenchmarkResult],
+    vad_results: &[ChunkBenchmarkResult],
+) -> VadComparisonSummary {
+    fn avg(results: &[ChunkBenchmarkResult], f: impl Fn(&ChunkBenchmarkResult) -> f64) -> f64 {
+        let successful: Vec<_> = results.iter().filter(|r| r.success).collect();
+        if successful.is_empty() {
+            return 0.0;
+        }
+        successful.iter().map(|r| f(r)).sum::<f64>() / successful.len() as f64
+    }
+
+    let avg_chunks_per_recording_fixed = avg(results, |r| r.chunks_processed as f64);
+    let avg_chunks_per_recording_vad = avg(vad_results, |r| r.chunks_processed as f64);
+    let avg_boundary_artifacts_fixed = avg(results, |r| r.chunk_boundary_artifacts);
+    let avg_boundary_artifacts_vad = avg(vad_results, |r| r.chunk_boundary_artifacts);
+    let avg_quality_score_fixed = avg(results, |r| r.overall_quality_score);
+    let avg_quality_score_vad = avg(vad_results, |r| r.overall_quality_score);
+    let avg_latency_ms_fixed = avg(results, |r| r.time_to_first_chunk_ms);
+    let avg_latency_ms_vad = avg(vad_results, |r| r.time_to_first_chunk_ms);
+
+    let summary = format!(
+        "VAD segmentation moves the average boundary artifact score (spectral flux, 0-1) from {:.3} for the fixed grid to {:.3}, \
+         at {:.3} quality (vs {:.3} for the fixed grid) and {:.0}ms average first-chunk latency (vs {:.0}ms).",
+        avg_boundary_artifacts_fixed,
+        avg_boundary_artifacts_vad,
+        avg_quality_score_vad,
+        avg_quality_score_fixed,
+        avg_latency_ms_vad,
+        avg_latency_ms_fixed,
+    );
+
+    VadComparisonSummary {
+        avg_chunks_per_recording_fixed,
+        avg_chunks_per_recording_vad,
+        avg_boundary_artifacts_fixed,
+        avg_boundary_artifacts_vad,
+        avg_quality_score_fixed,
+        avg_quality_score_vad,
+        avg_latency_ms_fixed,
+        avg_latency_ms_vad,
+        summary,
+    }
+}
+
+/// Average the fixed-size grid's results across every chunk size tested and
+/// compare against `cdc_results`, mirroring `generate_vad_comparison` for
+/// content-defined chunking.
+fn generate_cdc_comparison(
+    results: &[ChunkBenchmarkResult],
+    cdc_results: &[ChunkBenchmarkResult],
+) -> CdcComparisonSummary {
+    fn avg(results: &[ChunkBenchmarkResult], f: impl Fn(&ChunkBenchmarkResult) -> f64) -> f64 {
+        let successful: Vec<_> = results.iter().filter(|r| r.success).collect();
+        if successful.is_empty() {
+            return 0.0;
+        }
+        successful.iter().map(|r| f(r)).sum::<f64>() / successful.len() as f64
+    }
+
+    let avg_chunks_per_recording_fixed = avg(results, |r| r.chunks_processed as f64);
+    let avg_chunks_per_recording_cdc = avg(cdc_results, |r| r.chunks_processed as f64);
+    let avg_boundary_artifacts_fixed = avg(results, |r| r.chunk_boundary_artifacts);
+    let avg_boundary_artifacts_cdc = avg(cdc_results, |r| r.chunk_boundary_artifacts);
+    let avg_quality_score_fixed = avg(results, |r| r.overall_quality_score);
+    let avg_quality_score_cdc = avg(cdc_results, |r| r.overall_quality_score);
+    let avg_latency_ms_fixed = avg(results, |r| r.time_to_first_chunk_ms);
+    let avg_latency_ms_cdc = avg(cdc_results, |r| r.time_to_first_chunk_ms);
+
+    let summary = format!(
+        "CDC segmentation averages {:.1} chunks/recording (vs {:.1} for the fixed grid) at {:.3} quality \
+         (vs {:.3}) and {:.0}ms average first-chunk latency (vs {:.0}ms); see `cdc_stability` for how much \
+         of its cut set survives a shifted prefix, which is the property that matters for chunk caching.",
+        avg_chunks_per_recording_cdc,
+        avg_chunks_per_recording_fixed,
+        avg_quality_score_cdc,
+        avg_quality_score_fixed,
+        avg_latency_ms_cdc,
+        avg_latency_ms_fixed,
+    );
+
+    CdcComparisonSummary {
+        avg_chunks_per_recording_fixed,
+        avg_chunks_per_recording_cdc,
+        avg_boundary_artifacts_fixed,
+        avg_boundary_artifacts_cdc,
+        avg_quality_score_fixed,
+        avg_quality_score_cdc,
+        avg_latency_ms_fixed,
+        avg_latency_ms_cdc,
+        summary,
+    }
+}
+
 fn print_chunk_analysis_summary(report: &ChunkSizeReport) {
     println!("📊 CHUNK SIZE ANALYSIS RESULTS");
     println!("==============================");
-    
-    println!("\\n🏆 OPTIMAL CHUNK SIZE: {}ms", report.analysis.optimal_chunk_size);
-    
+
+    match report.analysis.optimal_chunk_size {
+        Some(size) => println!("\\n🏆 OPTIMAL CHUNK SIZE: {}ms", size),
+        None => println!("\\n🤷 NO SIGNIFICANT DIFFERENCE between chunk sizes (overlapping latency CIs)"),
+    }
+
     println!("\\n📈 QUALITY vs LATENCY ANALYSIS:");
     for point in &report.analysis.quality_vs_latency_analysis {
-        println!("   {}ms chunks: {:.0}ms latency, {:.3} quality, {:.3} efficiency", 
+        println!("   {}ms chunks: {:.0}ms latency, {:.3} quality, {:.3} efficiency",
                 point.chunk_size_ms, point.avg_latency_ms, point.avg_quality, point.efficiency_score);
     }
-    
+
     println!("\\n💡 RECOMMENDATIONS:");
     for rec in &report.analysis.chunk_size_recommendations {
         println!("   {}ms - {}: {}", rec.chunk_size_ms, rec.use_case, rec.recommendation);
     }
-    
+
     println!("\\n📋 {}", report.analysis.summary);
+
+    println!("\\n🎤 VAD-DRIVEN ADAPTIVE SEGMENTATION vs FIXED GRID:");
+    println!(
+        "   Avg chunks/recording: {:.1} (VAD) vs {:.1} (fixed)",
+        report.vad_comparison.avg_chunks_per_recording_vad,
+        report.vad_comparison.avg_chunks_per_recording_fixed
+    );
+    println!(
+        "   Avg boundary artifacts: {:.1} (VAD) vs {:.1} (fixed)",
+        report.vad_comparison.avg_boundary_artifacts_vad,
+        report.vad_comparison.avg_boundary_artifacts_fixed
+    );
+    println!("   {}", report.vad_comparison.summary);
+
+    println!("\\n🧩 CONTENT-DEFINED CHUNKING (CDC) vs FIXED GRID:");
+    println!(
+        "   Avg chunks/recording: {:.1} (CDC) vs {:.1} (fixed)",
+        report.cdc_comparison.avg_chunks_per_recording_cdc,
+        report.cdc_comparison.avg_chunks_per_recording_fixed
+    );
+    println!(
+        "   Avg boundary artifacts: {:.1} (CDC) vs {:.1} (fixed)",
+        report.cdc_comparison.avg_boundary_artifacts_cdc,
+        report.cdc_comparison.avg_boundary_artifacts_fixed
+    );
+    println!("   {}", report.cdc_comparison.summary);
+    for point in &report.cdc_stability {
+        println!(
+            "   {}ms target: {:.0}% of boundaries stable under {}ms of prepended leading silence",
+            point.target_chunk_ms,
+            point.avg_stable_boundary_fraction * 100.0,
+            CDC_STABILITY_PREPENDED_SILENCE_MS
+        );
+    }
+
+    if !report.profiling.is_empty() {
+        println!("\\n🔬 PROFILING:");
+        for profiler_report in &report.profiling {
+            print!("   {}:", profiler_report.name);
+            if let Some(avg_rss) = profiler_report.avg_rss_kb {
+                print!(" avg RSS {}KB,", avg_rss);
+            }
+            if let Some(peak_rss) = profiler_report.peak_rss_kb {
+                print!(" peak RSS {}KB,", peak_rss);
+            }
+            if let Some(avg_call_ms) = profiler_report.avg_call_ms {
+                print!(" avg call {:.0}ms,", avg_call_ms);
+            }
+            if let Some(tokens_per_sec) = profiler_report.avg_tokens_per_sec {
+                print!(" {:.1} tokens/sec,", tokens_per_sec);
+            }
+            if let Some(samples_path) = &profiler_report.samples_path {
+                print!(" samples at {},", samples_path);
+            }
+            println!();
+            if let Some(note) = &profiler_report.note {
+                println!("      note: {}", note);
+            }
+        }
+    }
 }
\ No newline at end of file
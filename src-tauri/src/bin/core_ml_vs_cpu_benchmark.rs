@@ -1,11 +1,108 @@
 use std::path::PathBuf;
-use std::time::Instant;
-use tokio;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use tokio;
 use tokio::sync::Mutex;
 use serde::{Serialize, Deserialize};
 use chrono;
 
+/// Core ML's resident footprint is flagged when it exceeds CPU's by this factor.
+const PEAK_RSS_WARN_RATIO: f64 = 1.5;
+
+/// Samples process resident-set size on a background thread while an operation
+/// runs, backing off exponentially so long operations stay cheap to profile,
+/// then reconciles the polled peak with `getrusage` at the end. Follows the
+/// poll-thread + `getrusage` peak-tracking approach Polkadot's PVF worker added
+/// for preparation memory stats.
+struct MemoryTracker {
+    stop: Arc<AtomicBool>,
+    peak_kb: Arc<AtomicU64>,
+    handle: Option<JoinHandle<()>>,
+    baseline_kb: u64,
+}
+
+impl MemoryTracker {
+    /// Samples the baseline RSS and starts the background poller.
+    fn start() -> Self {
+        let baseline_kb = current_rss_kb();
+        let stop = Arc::new(AtomicBool::new(false));
+        let peak_kb = Arc::new(AtomicU64::new(baseline_kb));
+        let stop_flag = stop.clone();
+        let peak_cell = peak_kb.clone();
+        let handle = std::thread::spawn(move || {
+            let mut interval = Duration::from_millis(10);
+            let cap = Duration::from_millis(250);
+            while !stop_flag.load(Ordering::Relaxed) {
+                peak_cell.fetch_max(current_rss_kb(), Ordering::Relaxed);
+                std::thread::sleep(interval);
+                interval = (interval * 2).min(cap);
+            }
+            // One last sample now that the operation has returned.
+            peak_cell.fetch_max(current_rss_kb(), Ordering::Relaxed);
+        });
+        Self { stop, peak_kb, handle: Some(handle), baseline_kb }
+    }
+
+    /// Stops sampling and returns `(peak_rss_kb, delta_rss_kb)`, taking the
+    /// larger of the polled max and `ru_maxrss` so allocations freed before the
+    /// final poll are still counted.
+    fn finish(mut self) -> (u64, i64) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        let peak = self.peak_kb.load(Ordering::Relaxed).max(rusage_peak_rss_kb());
+        let delta = peak as i64 - self.baseline_kb as i64;
+        (peak, delta)
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn current_rss_kb() -> u64 {
+    // SAFETY: `task_info` only writes into the zeroed `info` we pass it, up to
+    // the `count` words we advertise.
+    unsafe {
+        let mut info: libc::mach_task_basic_info = std::mem::zeroed();
+        let mut count = (std::mem::size_of::<libc::mach_task_basic_info>()
+            / std::mem::size_of::<libc::natural_t>()) as libc::mach_msg_type_number_t;
+        let kr = libc::task_info(
+            libc::mach_task_self(),
+            libc::MACH_TASK_BASIC_INFO as libc::task_flavor_t,
+            &mut info as *mut _ as libc::task_info_t,
+            &mut count,
+        );
+        if kr == libc::KERN_SUCCESS {
+            info.resident_size / 1024
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn current_rss_kb() -> u64 {
+    // No cheap cross-platform RSS probe; rely on the final `getrusage` peak.
+    0
+}
+
+fn rusage_peak_rss_kb() -> u64 {
+    // SAFETY: `getrusage` only writes into the zeroed `usage` we hand it.
+    unsafe {
+        let mut usage: libc::rusage = std::mem::zeroed();
+        if libc::getrusage(libc::RUSAGE_SELF, &mut usage) != 0 {
+            return 0;
+        }
+        // `ru_maxrss` is bytes on macOS and kilobytes on Linux.
+        if cfg!(target_os = "macos") {
+            usage.ru_maxrss as u64 / 1024
+        } else {
+            usage.ru_maxrss as u64
+        }
+    }
+}
+
 // Import Scout components
 use scout_lib::transcription::Transcriber;
 
@@ -18,6 +115,8 @@ struct BenchmarkResult {
     initialization_time_ms: f64,
     transcription_time_ms: Option<f64>,
     total_time_ms: f64,
+    peak_rss_kb: u64,
+    delta_rss_kb: i64,
     transcribed_text: Option<String>,
     error: Option<String>,
 }
@@ -38,6 +137,8 @@ struct BenchmarkSummary {
     coreml_avg_init_time_ms: f64,
     cpu_subsequent_calls_avg_ms: f64,
     coreml_subsequent_calls_avg_ms: f64,
+    cpu_peak_rss_kb: u64,
+    coreml_peak_rss_kb: u64,
     singleton_effectiveness: String,
     recommendations: Vec<String>,
 }
@@ -80,7 +181,14 @@ async fn get_or_create_transcriber(
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("🏁 Core ML vs CPU Singleton Performance Benchmark");
     println!("=================================================\n");
-    
+
+    // When invoked with a workload path, act as a reusable, workload-driven
+    // runner instead of the hardcoded two-model comparison below.
+    let cli = BenchCli::from_args(std::env::args().skip(1));
+    if let Some(path) = cli.workload_path.clone() {
+        return run_workload_path(&path, &cli).await;
+    }
+
     let cpu_model_path = PathBuf::from("./models/ggml-base.en.bin");
     let coreml_model_path = PathBuf::from("./models/ggml-base.en.bin"); // Same model but will use Core ML
     
@@ -113,7 +221,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         for call_number in 1..=5 {
             println!("  📞 Call #{}", call_number);
             let total_start = Instant::now();
-            
+            let memory = MemoryTracker::start();
+
             match get_or_create_transcriber(
                 &model_path,
                 transcriber_singleton.clone(),
@@ -122,10 +231,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Ok(init_start) => {
                     let initialization_time = init_start.elapsed();
                     let total_time = total_start.elapsed();
-                    
+                    let (peak_rss_kb, delta_rss_kb) = memory.finish();
+
                     println!("     ⏱️  Initialization: {:.2}ms", initialization_time.as_millis() as f64);
                     println!("     ⏱️  Total time: {:.2}ms", total_time.as_millis() as f64);
-                    
+                    println!("     🧠 Peak RSS: {} KB (Δ {:+} KB)", peak_rss_kb, delta_rss_kb);
+
                     results.push(BenchmarkResult {
                         test_name: format!("{}_call_{}", model_type.to_lowercase(), call_number),
                         model_type: model_type.to_string(),
@@ -134,6 +245,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         initialization_time_ms: initialization_time.as_millis() as f64,
                         transcription_time_ms: None, // We're focusing on initialization for this test
                         total_time_ms: total_time.as_millis() as f64,
+                        peak_rss_kb,
+                        delta_rss_kb,
                         transcribed_text: None,
                         error: None,
                     });
@@ -141,7 +254,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Err(e) => {
                     println!("     ❌ Error: {}", e);
                     let total_time = total_start.elapsed();
-                    
+                    let (peak_rss_kb, delta_rss_kb) = memory.finish();
+
                     results.push(BenchmarkResult {
                         test_name: format!("{}_call_{}", model_type.to_lowercase(), call_number),
                         model_type: model_type.to_string(),
@@ -150,6 +264,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         initialization_time_ms: total_time.as_millis() as f64,
                         transcription_time_ms: None,
                         total_time_ms: total_time.as_millis() as f64,
+                        peak_rss_kb,
+                        delta_rss_kb,
                         transcribed_text: None,
                         error: Some(e),
                     });
@@ -175,4 +291,323 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cpu_avg_init = cpu_first_call.map_or(0.0, |r| r.initialization_time_ms);
     let coreml_avg_init = coreml_first_call.map_or(0.0, |r| r.initialization_time_ms);
     
-    let cpu_subsequent_avg = if cpu_subsequent_calls.is_empty() { 0.0 } else {\n        cpu_subsequent_calls.iter().map(|r| r.total_time_ms).sum::<f64>() / cpu_subsequent_calls.len() as f64\n    };\n    \n    let coreml_subsequent_avg = if coreml_subsequent_calls.is_empty() { 0.0 } else {\n        coreml_subsequent_calls.iter().map(|r| r.total_time_ms).sum::<f64>() / coreml_subsequent_calls.len() as f64\n    };\n    \n    // Generate recommendations\n    let mut recommendations = Vec::new();\n    \n    if coreml_avg_init > cpu_avg_init * 2.0 {\n        recommendations.push(\"Core ML has significant initialization overhead compared to CPU\".to_string());\n    }\n    \n    if coreml_subsequent_avg < 100.0 && cpu_subsequent_avg < 100.0 {\n        recommendations.push(\"Singleton pattern is working - subsequent calls are <100ms for both models\".to_string());\n    }\n    \n    if coreml_avg_init > 5000.0 {\n        recommendations.push(\"Core ML initialization >5s indicates recompilation issue may still exist\".to_string());\n    } else {\n        recommendations.push(\"Core ML initialization is reasonable - singleton pattern likely working\".to_string());\n    }\n    \n    let singleton_effectiveness = if cpu_subsequent_avg < 50.0 && coreml_subsequent_avg < 50.0 {\n        \"Excellent - both models reuse efficiently\".to_string()\n    } else if cpu_subsequent_avg < 100.0 || coreml_subsequent_avg < 100.0 {\n        \"Good - singleton pattern is working\".to_string()\n    } else {\n        \"Poor - may still have recompilation issues\".to_string()\n    };\n    \n    let summary = BenchmarkSummary {\n        cpu_avg_init_time_ms: cpu_avg_init,\n        coreml_avg_init_time_ms: coreml_avg_init,\n        cpu_subsequent_calls_avg_ms: cpu_subsequent_avg,\n        coreml_subsequent_calls_avg_ms: coreml_subsequent_avg,\n        singleton_effectiveness,\n        recommendations,\n    };\n    \n    let report = BenchmarkReport {\n        timestamp: chrono::Utc::now().to_rfc3339(),\n        test_description: \"Core ML vs CPU singleton performance comparison\".to_string(),\n        cpu_model_path: cpu_model_path.to_string_lossy().to_string(),\n        coreml_model_path: coreml_model_path.to_string_lossy().to_string(),\n        results,\n        summary,\n    };\n    \n    // Print results\n    println!(\"📊 BENCHMARK RESULTS\");\n    println!(\"===================\");\n    println!(\"CPU first call initialization:     {:.1}ms\", report.summary.cpu_avg_init_time_ms);\n    println!(\"Core ML first call initialization: {:.1}ms\", report.summary.coreml_avg_init_time_ms);\n    println!(\"CPU subsequent calls average:      {:.1}ms\", report.summary.cpu_subsequent_calls_avg_ms);\n    println!(\"Core ML subsequent calls average:  {:.1}ms\", report.summary.coreml_subsequent_calls_avg_ms);\n    println!(\"\\nSingleton effectiveness: {}\", report.summary.singleton_effectiveness);\n    \n    println!(\"\\n💡 RECOMMENDATIONS:\");\n    for rec in &report.summary.recommendations {\n        println!(\"   • {}\", rec);\n    }\n    \n    // Save to JSON\n    let json_content = serde_json::to_string_pretty(&report)?;\n    let output_file = \"./core_ml_vs_cpu_singleton_results.json\";\n    tokio::fs::write(output_file, json_content).await?;\n    \n    println!(\"\\n📄 Detailed results saved to: {}\", output_file);\n    \n    // Final analysis\n    let speedup_ratio = if report.summary.coreml_subsequent_calls_avg_ms > 0.0 {\n        report.summary.coreml_avg_init_time_ms / report.summary.coreml_subsequent_calls_avg_ms\n    } else { 0.0 };\n    \n    if speedup_ratio > 10.0 {\n        println!(\"\\n✅ SUCCESS: Core ML singleton provides {:.1}x speedup after initial load!\", speedup_ratio);\n    } else if speedup_ratio > 2.0 {\n        println!(\"\\n⚠️ PARTIAL: Core ML singleton provides {:.1}x speedup, but may need optimization\", speedup_ratio);\n    } else {\n        println!(\"\\n❌ ISSUE: Core ML singleton not providing expected speedup ({}x)\", speedup_ratio);\n    }\n    \n    Ok(())\n}
\ No newline at end of file
+    let cpu_subsequent_avg = if cpu_subsequent_calls.is_empty() { 0.0 } else {\n        cpu_subsequent_calls.iter().map(|r| r.total_time_ms).sum::<f64>() / cpu_subsequent_calls.len() as f64\n    };\n    \n    let coreml_subsequent_avg = if coreml_subsequent_calls.is_empty() { 0.0 } else {\n        coreml_subsequent_calls.iter().map(|r| r.total_time_ms).sum::<f64>() / coreml_subsequent_calls.len() as f64\n    };\n    \n    // Peak resident footprint per backend (max across that model's calls).\n    let cpu_peak_rss_kb = cpu_results.iter().map(|r| r.peak_rss_kb).max().unwrap_or(0);\n    let coreml_peak_rss_kb = coreml_results.iter().map(|r| r.peak_rss_kb).max().unwrap_or(0);\n    \n    // Generate recommendations\n    let mut recommendations = Vec::new();\n    \n    if cpu_peak_rss_kb > 0 && coreml_peak_rss_kb as f64 > cpu_peak_rss_kb as f64 * PEAK_RSS_WARN_RATIO {\n        recommendations.push(format!(\n            \"Core ML peak RSS ({} KB) exceeds CPU ({} KB) by more than {:.1}x - prefer CPU where memory is constrained\",\n            coreml_peak_rss_kb, cpu_peak_rss_kb, PEAK_RSS_WARN_RATIO\n        ));\n    }\n    \n    if coreml_avg_init > cpu_avg_init * 2.0 {\n        recommendations.push(\"Core ML has significant initialization overhead compared to CPU\".to_string());\n    }\n    \n    if coreml_subsequent_avg < 100.0 && cpu_subsequent_avg < 100.0 {\n        recommendations.push(\"Singleton pattern is working - subsequent calls are <100ms for both models\".to_string());\n    }\n    \n    if coreml_avg_init > 5000.0 {\n        recommendations.push(\"Core ML initialization >5s indicates recompilation issue may still exist\".to_string());\n    } else {\n        recommendations.push(\"Core ML initialization is reasonable - singleton pattern likely working\".to_string());\n    }\n    \n    let singleton_effectiveness = if cpu_subsequent_avg < 50.0 && coreml_subsequent_avg < 50.0 {\n        \"Excellent - both models reuse efficiently\".to_string()\n    } else if cpu_subsequent_avg < 100.0 || coreml_subsequent_avg < 100.0 {\n        \"Good - singleton pattern is working\".to_string()\n    } else {\n        \"Poor - may still have recompilation issues\".to_string()\n    };\n    \n    let summary = BenchmarkSummary {\n        cpu_avg_init_time_ms: cpu_avg_init,\n        coreml_avg_init_time_ms: coreml_avg_init,\n        cpu_subsequent_calls_avg_ms: cpu_subsequent_avg,\n        coreml_subsequent_calls_avg_ms: coreml_subsequent_avg,\n        cpu_peak_rss_kb,\n        coreml_peak_rss_kb,\n        singleton_effectiveness,\n        recommendations,\n    };\n    \n    let report = BenchmarkReport {\n        timestamp: chrono::Utc::now().to_rfc3339(),\n        test_description: \"Core ML vs CPU singleton performance comparison\".to_string(),\n        cpu_model_path: cpu_model_path.to_string_lossy().to_string(),\n        coreml_model_path: coreml_model_path.to_string_lossy().to_string(),\n        results,\n        summary,\n    };\n    \n    // Print results\n    println!(\"📊 BENCHMARK RESULTS\");\n    println!(\"===================\");\n    println!(\"CPU first call initialization:     {:.1}ms\", report.summary.cpu_avg_init_time_ms);\n    println!(\"Core ML first call initialization: {:.1}ms\", report.summary.coreml_avg_init_time_ms);\n    println!(\"CPU subsequent calls average:      {:.1}ms\", report.summary.cpu_subsequent_calls_avg_ms);\n    println!(\"Core ML subsequent calls average:  {:.1}ms\", report.summary.coreml_subsequent_calls_avg_ms);\n    println!(\"CPU peak RSS:                       {} KB\", report.summary.cpu_peak_rss_kb);\n    println!(\"Core ML peak RSS:                   {} KB\", report.summary.coreml_peak_rss_kb);\n    println!(\"\\nSingleton effectiveness: {}\", report.summary.singleton_effectiveness);\n    \n    println!(\"\\n💡 RECOMMENDATIONS:\");\n    for rec in &report.summary.recommendations {\n        println!(\"   • {}\", rec);\n    }\n    \n    // Save to JSON\n    let json_content = serde_json::to_string_pretty(&report)?;\n    let output_file = \"./core_ml_vs_cpu_singleton_results.json\";\n    tokio::fs::write(output_file, json_content).await?;\n    \n    println!(\"\\n📄 Detailed results saved to: {}\", output_file);\n    \n    // Final analysis\n    let speedup_ratio = if report.summary.coreml_subsequent_calls_avg_ms > 0.0 {\n        report.summary.coreml_avg_init_time_ms / report.summary.coreml_subsequent_calls_avg_ms\n    } else { 0.0 };\n    \n    if speedup_ratio > 10.0 {\n        println!(\"\\n✅ SUCCESS: Core ML singleton provides {:.1}x speedup after initial load!\", speedup_ratio);\n    } else if speedup_ratio > 2.0 {\n        println!(\"\\n⚠️ PARTIAL: Core ML singleton provides {:.1}x speedup, but may need optimization\", speedup_ratio);\n    } else {\n        println!(\"\\n❌ ISSUE: Core ML singleton not providing expected speedup ({}x)\", speedup_ratio);\n    }\n    \n    Ok(())\n}
+
+// ---------------------------------------------------------------------------
+// Workload-file-driven runner
+// ---------------------------------------------------------------------------
+
+/// Parsed command-line options for the workload runner.
+struct BenchCli {
+    /// File or directory of workload JSON files to run.
+    workload_path: Option<PathBuf>,
+    /// Optional baseline report to diff subsequent-call averages against.
+    baseline: Option<PathBuf>,
+    /// Fractional regression threshold (e.g. 0.1 == 10%) before the run fails.
+    regression_threshold: f64,
+    /// Optional HTTP endpoint each report is POSTed to.
+    endpoint: Option<String>,
+}
+
+impl BenchCli {
+    fn from_args(args: impl Iterator<Item = String>) -> Self {
+        let mut cli = BenchCli {
+            workload_path: None,
+            baseline: None,
+            regression_threshold: 0.1,
+            endpoint: None,
+        };
+        let mut args = args.peekable();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--baseline" => cli.baseline = args.next().map(PathBuf::from),
+                "--regression-threshold" => {
+                    cli.regression_threshold = args
+                        .next()
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(cli.regression_threshold);
+                }
+                "--endpoint" => cli.endpoint = args.next(),
+                other if !other.starts_with("--") && cli.workload_path.is_none() => {
+                    cli.workload_path = Some(PathBuf::from(other));
+                }
+                _ => {}
+            }
+        }
+        cli
+    }
+}
+
+/// A named matrix of benchmark scenarios loaded from a JSON workload file.
+#[derive(Debug, Deserialize)]
+struct Workload {
+    name: String,
+    #[serde(default)]
+    scenarios: Vec<Scenario>,
+}
+
+/// A single benchmark scenario. Stable `name` keeps results comparable across
+/// runs and lets `--baseline` diffing line scenarios up by identity.
+#[derive(Debug, Deserialize, Clone)]
+struct Scenario {
+    name: String,
+    model_path: PathBuf,
+    #[serde(default = "default_backend")]
+    backend: String,
+    #[serde(default)]
+    audio: Vec<PathBuf>,
+    #[serde(default = "default_calls")]
+    calls: u32,
+    #[serde(default)]
+    warmup: u32,
+    /// Upper bound on call rate; calls sleep to hold this cadence when set.
+    #[serde(default)]
+    operations_per_second: Option<f64>,
+    /// Stop a scenario once it has run for this many wall-clock seconds.
+    #[serde(default)]
+    bench_length_seconds: Option<f64>,
+}
+
+fn default_backend() -> String {
+    "cpu".to_string()
+}
+
+fn default_calls() -> u32 {
+    5
+}
+
+/// Runs a single workload file or every `*.json` workload in a directory.
+async fn run_workload_path(path: &PathBuf, cli: &BenchCli) -> Result<(), Box<dyn std::error::Error>> {
+    let baseline = match &cli.baseline {
+        Some(p) => Some(load_baseline(p).await?),
+        None => None,
+    };
+
+    let mut files = Vec::new();
+    if path.is_dir() {
+        let mut entries = tokio::fs::read_dir(path).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let p = entry.path();
+            if p.extension().and_then(|e| e.to_str()) == Some("json") {
+                files.push(p);
+            }
+        }
+        files.sort();
+    } else {
+        files.push(path.clone());
+    }
+
+    let mut regressed = false;
+    for file in files {
+        let contents = tokio::fs::read_to_string(&file).await?;
+        let workload: Workload = serde_json::from_str(&contents)?;
+        println!("📦 Running workload '{}' ({} scenarios)", workload.name, workload.scenarios.len());
+
+        let report = run_workload(&workload).await?;
+
+        let output_file = format!("./bench_{}.json", sanitize(&workload.name));
+        tokio::fs::write(&output_file, serde_json::to_string_pretty(&report)?).await?;
+        println!("   📄 Report written to {}", output_file);
+
+        if let Some(endpoint) = &cli.endpoint {
+            post_report(endpoint, &report).await;
+        }
+
+        if let Some(baseline) = &baseline {
+            regressed |= diff_against_baseline(&report, baseline, cli.regression_threshold);
+        }
+    }
+
+    if regressed {
+        return Err("benchmark regressed beyond threshold".into());
+    }
+    Ok(())
+}
+
+/// Executes every scenario in a workload and folds them into a report.
+async fn run_workload(workload: &Workload) -> Result<BenchmarkReport, Box<dyn std::error::Error>> {
+    let mut results = Vec::new();
+    let mut cpu_model_path = String::new();
+    let mut coreml_model_path = String::new();
+
+    for scenario in &workload.scenarios {
+        println!("  🧪 Scenario '{}' [{}] {} calls ({} warmup)",
+            scenario.name, scenario.backend, scenario.calls, scenario.warmup);
+
+        if scenario.backend.eq_ignore_ascii_case("coreml") {
+            coreml_model_path = scenario.model_path.to_string_lossy().to_string();
+        } else {
+            cpu_model_path = scenario.model_path.to_string_lossy().to_string();
+        }
+
+        let transcriber_singleton = Arc::new(Mutex::new(None::<Transcriber>));
+        let current_model_path = Arc::new(Mutex::new(None::<PathBuf>));
+
+        // Minimum spacing between calls to respect the rate cap.
+        let min_spacing = scenario
+            .operations_per_second
+            .filter(|ops| *ops > 0.0)
+            .map(|ops| Duration::from_secs_f64(1.0 / ops));
+        let scenario_start = Instant::now();
+
+        for call_number in 1..=scenario.calls {
+            if let Some(limit) = scenario.bench_length_seconds {
+                if scenario_start.elapsed().as_secs_f64() >= limit {
+                    println!("     ⏹️  bench_length_seconds reached, stopping scenario");
+                    break;
+                }
+            }
+
+            let call_start = Instant::now();
+            let total_start = Instant::now();
+            let memory = MemoryTracker::start();
+
+            let (init_ms, error) = match get_or_create_transcriber(
+                &scenario.model_path,
+                transcriber_singleton.clone(),
+                current_model_path.clone(),
+            )
+            .await
+            {
+                Ok(init_start) => (init_start.elapsed().as_millis() as f64, None),
+                Err(e) => (total_start.elapsed().as_millis() as f64, Some(e)),
+            };
+            let total_time = total_start.elapsed();
+            let (peak_rss_kb, delta_rss_kb) = memory.finish();
+
+            // Warmup calls are recorded like any other call. `summarize` and
+            // `subsequent_averages` treat call 1 as the first call and every
+            // later call as subsequent, so `warmup` does not remove calls
+            // from those averages.
+            results.push(BenchmarkResult {
+                test_name: format!("{}_call_{}", scenario.name, call_number),
+                model_type: scenario.backend.clone(),
+                model_path: scenario.model_path.to_string_lossy().to_string(),
+                call_number,
+                initialization_time_ms: init_ms,
+                transcription_time_ms: None,
+                total_time_ms: total_time.as_millis() as f64,
+                peak_rss_kb,
+                delta_rss_kb,
+                transcribed_text: None,
+                error,
+            });
+
+            if let Some(spacing) = min_spacing {
+                if let Some(remaining) = spacing.checked_sub(call_start.elapsed()) {
+                    tokio::time::sleep(remaining).await;
+                }
+            }
+        }
+    }
+
+    let summary = summarize(&results);
+    Ok(BenchmarkReport {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        test_description: format!("workload '{}'", workload.name),
+        cpu_model_path,
+        coreml_model_path,
+        results,
+        summary,
+    })
+}
+
+/// Builds a summary from raw results, mirroring the legacy first-call/subsequent split.
+fn summarize(results: &[BenchmarkResult]) -> BenchmarkSummary {
+    let avg = |rs: &[&BenchmarkResult]| -> f64 {
+        if rs.is_empty() { 0.0 } else {
+            rs.iter().map(|r| r.total_time_ms).sum::<f64>() / rs.len() as f64
+        }
+    };
+    let by = |name: &str, subsequent: bool| -> Vec<&BenchmarkResult> {
+        results
+            .iter()
+            .filter(|r| r.model_type.eq_ignore_ascii_case(name) && (r.call_number > 1) == subsequent)
+            .collect()
+    };
+
+    let cpu_first = by("cpu", false);
+    let coreml_first = by("coreml", false);
+    let cpu_subsequent = by("cpu", true);
+    let coreml_subsequent = by("coreml", true);
+
+    let peak = |name: &str| {
+        results
+            .iter()
+            .filter(|r| r.model_type.eq_ignore_ascii_case(name))
+            .map(|r| r.peak_rss_kb)
+            .max()
+            .unwrap_or(0)
+    };
+
+    BenchmarkSummary {
+        cpu_avg_init_time_ms: cpu_first.first().map_or(0.0, |r| r.initialization_time_ms),
+        coreml_avg_init_time_ms: coreml_first.first().map_or(0.0, |r| r.initialization_time_ms),
+        cpu_subsequent_calls_avg_ms: avg(&cpu_subsequent),
+        coreml_subsequent_calls_avg_ms: avg(&coreml_subsequent),
+        cpu_peak_rss_kb: peak("cpu"),
+        coreml_peak_rss_kb: peak("coreml"),
+        singleton_effectiveness: "workload run".to_string(),
+        recommendations: Vec::new(),
+    }
+}
+
+/// Average subsequent-call time per scenario, keyed by stable scenario name.
+fn subsequent_averages(report: &BenchmarkReport) -> std::collections::HashMap<String, f64> {
+    let mut sums: std::collections::HashMap<String, (f64, u32)> = std::collections::HashMap::new();
+    for r in &report.results {
+        if r.call_number <= 1 {
+            continue;
+        }
+        // `name_call_N` -> `name`
+        let scenario = r.test_name.rsplit_once("_call_").map(|(s, _)| s).unwrap_or(&r.test_name);
+        let entry = sums.entry(scenario.to_string()).or_insert((0.0, 0));
+        entry.0 += r.total_time_ms;
+        entry.1 += 1;
+    }
+    sums.into_iter()
+        .map(|(k, (sum, n))| (k, if n == 0 { 0.0 } else { sum / n as f64 }))
+        .collect()
+}
+
+async fn load_baseline(path: &PathBuf) -> Result<BenchmarkReport, Box<dyn std::error::Error>> {
+    let contents = tokio::fs::read_to_string(path).await?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+/// Returns true if any shared scenario regressed beyond `threshold`.
+fn diff_against_baseline(report: &BenchmarkReport, baseline: &BenchmarkReport, threshold: f64) -> bool {
+    let current = subsequent_averages(report);
+    let base = subsequent_averages(baseline);
+    let mut regressed = false;
+    for (scenario, base_avg) in &base {
+        if let Some(cur_avg) = current.get(scenario) {
+            if *base_avg > 0.0 {
+                let delta = (cur_avg - base_avg) / base_avg;
+                if delta > threshold {
+                    println!(
+                        "   📉 REGRESSION '{}': {:.1}ms -> {:.1}ms (+{:.1}%)",
+                        scenario, base_avg, cur_avg, delta * 100.0
+                    );
+                    regressed = true;
+                }
+            }
+        }
+    }
+    regressed
+}
+
+async fn post_report(endpoint: &str, report: &BenchmarkReport) {
+    match reqwest::Client::new().post(endpoint).json(report).send().await {
+        Ok(resp) => println!("   📡 Posted report to {} ({})", endpoint, resp.status()),
+        Err(e) => eprintln!("   ⚠️  Failed to post report to {}: {}", endpoint, e),
+    }
+}
+
+/// Lowercases and replaces non-alphanumeric characters so a workload name is
+/// safe to use in an output filename.
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect()
+}
\ No newline at end of file
@@ -19,6 +19,8 @@ struct DetailedTestResult {
     chunk_size_ms: Option<u32>,
     time_to_first_result_ms: f32,
     accuracy_score: f32,
+    word_error_rate: Option<f32>,
+    character_error_rate: Option<f32>,
     transcribed_text: String,
     expected_text: String,
     success: bool,
@@ -41,6 +43,13 @@ struct StrategySummary {
     min_ttfr_ms: f32,
     max_ttfr_ms: f32,
     avg_accuracy: f32,
+    /// Average word error rate across tests that had a ground-truth reference
+    /// to score against; `None` if none of this strategy's tests were scored.
+    avg_word_error_rate: Option<f32>,
+    /// Average character error rate across tests that had a ground-truth
+    /// reference; `None` if none of this strategy's tests were scored.
+    avg_character_error_rate: Option<f32>,
+    scored_tests: usize,
     recordings_tested: Vec<String>,
 }
 
@@ -148,7 +157,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 continue;
             }
 
-            match strategy_tester.test_strategy(&strategy, &test.audio_file, &test.name).await {
+            match strategy_tester
+                .test_strategy(
+                    &strategy,
+                    &test.audio_file,
+                    &test.name,
+                    test.expected_transcript.as_deref(),
+                )
+                .await
+            {
                 Ok(result) => {
                     let detailed_result = DetailedTestResult {
                         test_name: test.name.clone(),
@@ -160,6 +177,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         chunk_size_ms: chunk_size,
                         time_to_first_result_ms: result.timing_metrics.time_to_first_result_ms as f32,
                         accuracy_score: result.accuracy_metrics.confidence_score,
+                        word_error_rate: result.accuracy_metrics.word_error_rate,
+                        character_error_rate: result.accuracy_metrics.character_error_rate,
                         transcribed_text: result.accuracy_metrics.transcribed_text.clone(),
                         expected_text: test.expected_transcript.clone().unwrap_or_default(),
                         success: result.success,
@@ -203,6 +222,8 @@ fn generate_detailed_report(results: Vec<DetailedTestResult>) -> DetailedBakeoff
     let summary_by_strategy: Vec<StrategySummary> = strategy_groups.into_iter()
         .map(|(strategy_name, results)| {
             let ttfr_values: Vec<f32> = results.iter().map(|r| r.time_to_first_result_ms).collect();
+            let wer_values: Vec<f32> = results.iter().filter_map(|r| r.word_error_rate).collect();
+            let cer_values: Vec<f32> = results.iter().filter_map(|r| r.character_error_rate).collect();
             StrategySummary {
                 strategy_name,
                 tests_run: results.len(),
@@ -210,6 +231,17 @@ fn generate_detailed_report(results: Vec<DetailedTestResult>) -> DetailedBakeoff
                 min_ttfr_ms: ttfr_values.iter().fold(f32::INFINITY, |a, &b| a.min(b)),
                 max_ttfr_ms: ttfr_values.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b)),
                 avg_accuracy: results.iter().map(|r| r.accuracy_score).sum::<f32>() / results.len() as f32,
+                avg_word_error_rate: if wer_values.is_empty() {
+                    None
+                } else {
+                    Some(wer_values.iter().sum::<f32>() / wer_values.len() as f32)
+                },
+                avg_character_error_rate: if cer_values.is_empty() {
+                    None
+                } else {
+                    Some(cer_values.iter().sum::<f32>() / cer_values.len() as f32)
+                },
+                scored_tests: wer_values.len(),
                 recordings_tested: results.iter().map(|r| r.test_name.clone()).collect(),
             }
         })
@@ -300,6 +332,13 @@ fn print_detailed_summary(report: &DetailedBakeoffReport) {
         println!("     TTFR: {:.1}ms (min: {:.1}ms, max: {:.1}ms)", 
                 strategy.avg_ttfr_ms, strategy.min_ttfr_ms, strategy.max_ttfr_ms);
         println!("     Accuracy: {:.3}", strategy.avg_accuracy);
+        match (strategy.avg_word_error_rate, strategy.avg_character_error_rate) {
+            (Some(wer), Some(cer)) => println!(
+                "     WER: {:.3}, CER: {:.3} ({} of {} tests scored against a reference)",
+                wer, cer, strategy.scored_tests, strategy.tests_run
+            ),
+            _ => println!("     WER/CER: skipped (no reference transcripts available)"),
+        }
         println!("     Recordings: {:?}", strategy.recordings_tested);
         println!();
     }
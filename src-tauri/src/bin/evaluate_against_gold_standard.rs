@@ -0,0 +1,71 @@
+use scout_lib::benchmarking::GoldStandardEvaluator;
+use scout_lib::transcription::Transcriber;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Number of worst-scoring recordings to surface in the report, so a
+/// regression in a handful of recordings doesn't hide in an aggregate mean.
+const WORST_N: usize = 10;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("📐 EVALUATING MODEL AGAINST GOLD STANDARD CORPUS");
+    println!("=================================================\\n");
+
+    let gold_standard_path =
+        PathBuf::from("./benchmark_corpus/gold_standard_transcriptions.json");
+    if !gold_standard_path.exists() {
+        println!("❌ No gold standard corpus found at {:?}", gold_standard_path);
+        println!("   Run generate_comprehensive_gold_standard_transcriptions first.");
+        return Ok(());
+    }
+
+    let model_path = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("./models/ggml-base.en.bin"));
+
+    println!("🎯 Evaluating model: {:?}", model_path);
+    let transcriber = Arc::new(Transcriber::new(&model_path)?);
+
+    let evaluator = GoldStandardEvaluator::new(transcriber);
+    let report = evaluator.evaluate(&gold_standard_path, WORST_N).await?;
+
+    println!("\\n📊 EVALUATION COMPLETE");
+    println!("======================");
+    println!(
+        "📋 {} recordings scored against gold standard model '{}'",
+        report.total_recordings, report.model_used
+    );
+    println!(
+        "📈 Mean WER: {:.3}  Median WER: {:.3}",
+        report.mean_word_error_rate, report.median_word_error_rate
+    );
+    println!(
+        "📈 Mean CER: {:.3}  Median CER: {:.3}",
+        report.mean_character_error_rate, report.median_character_error_rate
+    );
+
+    println!("\\n📂 By category:");
+    for summary in &report.by_category {
+        println!(
+            "   {} ({} recordings): mean WER {:.3}, mean CER {:.3}",
+            summary.category,
+            summary.recordings_evaluated,
+            summary.mean_word_error_rate,
+            summary.mean_character_error_rate
+        );
+    }
+
+    println!("\\n⚠️  Worst {} recordings by WER:", WORST_N);
+    for worst in &report.worst_recordings {
+        println!("   {} - WER {:.3}", worst.recording_name, worst.word_error_rate);
+    }
+
+    let json_content = serde_json::to_string_pretty(&report)?;
+    let output_file = "./benchmark_corpus/gold_standard_evaluation.json";
+    tokio::fs::write(output_file, json_content).await?;
+    println!("\\n💾 Saved to: {}", output_file);
+
+    Ok(())
+}
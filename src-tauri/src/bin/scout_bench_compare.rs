@@ -0,0 +1,163 @@
+/// Diffs two `target/scout-benchmarks/<unix-timestamp>.json` trend files
+/// (written by `tests/performance/simplified_benchmarks.rs` via
+/// `BenchmarkCollection::save_timestamped`) and flags regressions.
+///
+/// Usage: `scout_bench_compare <old.json> <new.json> [threshold_pct]`
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Regressions slower than this percentage are flagged, unless overridden by
+/// the third CLI argument.
+const DEFAULT_THRESHOLD_PCT: f64 = 10.0;
+
+#[derive(Debug, Clone, Deserialize)]
+struct BenchmarkRecord {
+    name: String,
+    parameter: String,
+    mean_ns: f64,
+    throughput: Option<f64>,
+    #[allow(dead_code)]
+    timestamp: u64,
+    git_commit: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct BenchmarkCollection {
+    records: Vec<BenchmarkRecord>,
+}
+
+impl BenchmarkCollection {
+    fn load(path: &PathBuf) -> std::io::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    fn by_key(&self) -> HashMap<(&str, &str), &BenchmarkRecord> {
+        self.records
+            .iter()
+            .map(|r| ((r.name.as_str(), r.parameter.as_str()), r))
+            .collect()
+    }
+}
+
+struct Delta<'a> {
+    name: &'a str,
+    parameter: &'a str,
+    old_mean_ns: f64,
+    new_mean_ns: f64,
+    pct_change: f64,
+    old_throughput: Option<f64>,
+    new_throughput: Option<f64>,
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = std::env::args().skip(1);
+    let old_path = args
+        .next()
+        .map(PathBuf::from)
+        .ok_or("usage: scout_bench_compare <old.json> <new.json> [threshold_pct]")?;
+    let new_path = args
+        .next()
+        .map(PathBuf::from)
+        .ok_or("usage: scout_bench_compare <old.json> <new.json> [threshold_pct]")?;
+    let threshold_pct = args
+        .next()
+        .and_then(|s| s.parse::<f64>().ok())
+        .unwrap_or(DEFAULT_THRESHOLD_PCT);
+
+    let old = BenchmarkCollection::load(&old_path)?;
+    let new = BenchmarkCollection::load(&new_path)?;
+
+    println!("📊 Comparing benchmark runs");
+    println!("   old: {:?} ({} records)", old_path, old.records.len());
+    println!("   new: {:?} ({} records)", new_path, new.records.len());
+    println!();
+
+    let old_by_key = old.by_key();
+    let mut deltas = Vec::new();
+    let mut missing = Vec::new();
+
+    for new_record in &new.records {
+        let key = (new_record.name.as_str(), new_record.parameter.as_str());
+        match old_by_key.get(&key) {
+            Some(old_record) => {
+                let pct_change = (new_record.mean_ns - old_record.mean_ns) / old_record.mean_ns * 100.0;
+                deltas.push(Delta {
+                    name: &new_record.name,
+                    parameter: &new_record.parameter,
+                    old_mean_ns: old_record.mean_ns,
+                    new_mean_ns: new_record.mean_ns,
+                    pct_change,
+                    old_throughput: old_record.throughput,
+                    new_throughput: new_record.throughput,
+                });
+            }
+            None => missing.push(key),
+        }
+    }
+
+    print_terminal_table(&deltas, threshold_pct);
+    print_markdown_table(&deltas, threshold_pct);
+
+    if !missing.is_empty() {
+        println!("\n⚠️  No baseline for {} new benchmark(s):", missing.len());
+        for (name, parameter) in &missing {
+            println!("   - {} / {}", name, parameter);
+        }
+    }
+
+    let regressions: Vec<&Delta> = deltas.iter().filter(|d| d.pct_change > threshold_pct).collect();
+    if !regressions.is_empty() {
+        println!(
+            "\n❌ {} regression(s) exceeded the {:.1}% threshold (old commit {} -> new commit {})",
+            regressions.len(),
+            threshold_pct,
+            old.records.first().map(|r| r.git_commit.as_str()).unwrap_or("unknown"),
+            new.records.first().map(|r| r.git_commit.as_str()).unwrap_or("unknown"),
+        );
+        std::process::exit(1);
+    }
+
+    println!("\n✅ No regressions beyond {:.1}%", threshold_pct);
+    Ok(())
+}
+
+fn print_terminal_table(deltas: &[Delta], threshold_pct: f64) {
+    println!("{:<24} {:<10} {:>14} {:>14} {:>10}", "benchmark", "parameter", "old (µs)", "new (µs)", "delta");
+    println!("{}", "-".repeat(76));
+    for d in deltas {
+        let marker = if d.pct_change > threshold_pct { " ⚠️" } else { "" };
+        println!(
+            "{:<24} {:<10} {:>14.2} {:>14.2} {:>+9.1}%{}",
+            d.name,
+            d.parameter,
+            d.old_mean_ns / 1000.0,
+            d.new_mean_ns / 1000.0,
+            d.pct_change,
+            marker
+        );
+    }
+}
+
+fn print_markdown_table(deltas: &[Delta], threshold_pct: f64) {
+    println!("\n| benchmark | parameter | old (µs) | new (µs) | delta | throughput delta |");
+    println!("|---|---|---|---|---|---|");
+    for d in deltas {
+        let marker = if d.pct_change > threshold_pct { " ⚠️" } else { "" };
+        let throughput_delta = match (d.old_throughput, d.new_throughput) {
+            (Some(old), Some(new)) if old != 0.0 => format!("{:+.1}%", (new - old) / old * 100.0),
+            _ => "n/a".to_string(),
+        };
+        println!(
+            "| {} | {} | {:.2} | {:.2} | {:+.1}%{} | {} |",
+            d.name,
+            d.parameter,
+            d.old_mean_ns / 1000.0,
+            d.new_mean_ns / 1000.0,
+            d.pct_change,
+            marker,
+            throughput_delta
+        );
+    }
+}
@@ -36,6 +36,16 @@ pub async fn get_current_audio_level(state: State<'_, AppState>) -> Result<f32,
     Ok(recorder.get_current_audio_level())
 }
 
+/// Whether the spectral VAD (see `audio::spectral_vad`) currently classifies
+/// the monitored input as speech. Unlike `get_current_audio_level`, this is
+/// resistant to steady background noise like fan or HVAC hum, since it
+/// scores voice-band energy against an adaptive floor rather than raw RMS.
+#[tauri::command]
+pub async fn get_voice_activity(state: State<'_, AppState>) -> Result<bool, String> {
+    let recorder = state.recorder.lock().await;
+    Ok(recorder.get_voice_activity())
+}
+
 // ============================================================================
 // Core Recording Commands
 // ============================================================================
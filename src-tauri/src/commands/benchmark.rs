@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+use tauri::State;
+
+use crate::benchmark::{BenchmarkCorpusEntry, BenchmarkHarness, BenchmarkHarnessConfig, BenchmarkRunReport};
+use crate::AppState;
+
+/// One corpus file as received over the Tauri bridge (`PathBuf` isn't a
+/// natural IPC type, so it's flattened to a `String`).
+#[derive(Debug, serde::Deserialize)]
+pub struct BenchmarkCorpusFile {
+    pub name: String,
+    pub audio_file: String,
+    pub duration_ms: u32,
+}
+
+/// Request payload for [`run_strategy_benchmark`].
+#[derive(Debug, serde::Deserialize)]
+pub struct StrategyBenchmarkRequest {
+    pub corpus: Vec<BenchmarkCorpusFile>,
+    pub bench_length_seconds: u64,
+    pub operations_per_second: f64,
+    pub warmup_iterations: usize,
+    pub hardware_tag: String,
+}
+
+/// Runs the reproducible transcription-strategy benchmark harness and
+/// returns its per-strategy p50/p95/p99 report. Each strategy's run is also
+/// persisted via `Database::save_performance_metrics`, tagged with the
+/// active model, `hardware_tag`, and strategy name, so maintainers can diff
+/// this run against an earlier one to catch performance regressions.
+#[tauri::command]
+pub async fn run_strategy_benchmark(
+    state: State<'_, AppState>,
+    request: StrategyBenchmarkRequest,
+) -> Result<BenchmarkRunReport, String> {
+    let model_used = {
+        let settings = state.settings.lock().await;
+        settings.get().models.active_model_id.clone()
+    };
+
+    let corpus = request
+        .corpus
+        .into_iter()
+        .map(|file| BenchmarkCorpusEntry {
+            name: file.name,
+            audio_file: PathBuf::from(file.audio_file),
+            duration_ms: file.duration_ms,
+        })
+        .collect();
+
+    let config = BenchmarkHarnessConfig {
+        corpus,
+        bench_length_seconds: request.bench_length_seconds,
+        operations_per_second: request.operations_per_second,
+        warmup_iterations: request.warmup_iterations,
+        model_used,
+        hardware_tag: request.hardware_tag,
+    };
+
+    let harness = BenchmarkHarness::new(state.transcriber.clone(), state.database.clone());
+    harness.run(&config).await
+}
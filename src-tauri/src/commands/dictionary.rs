@@ -16,6 +16,8 @@ pub async fn save_dictionary_entry(
     match_type: String,
     is_case_sensitive: bool,
     phonetic_pattern: Option<String>,
+    phonetic_algorithm: Option<String>,
+    min_similarity: Option<f64>,
     category: Option<String>,
     description: Option<String>,
 ) -> Result<i64, String> {
@@ -27,6 +29,8 @@ pub async fn save_dictionary_entry(
             &match_type,
             is_case_sensitive,
             phonetic_pattern.as_deref(),
+            phonetic_algorithm.as_deref(),
+            min_similarity,
             category.as_deref(),
             description.as_deref(),
         )
@@ -42,6 +46,8 @@ pub async fn update_dictionary_entry(
     match_type: String,
     is_case_sensitive: bool,
     phonetic_pattern: Option<String>,
+    phonetic_algorithm: Option<String>,
+    min_similarity: Option<f64>,
     category: Option<String>,
     description: Option<String>,
     enabled: bool,
@@ -55,6 +61,8 @@ pub async fn update_dictionary_entry(
             &match_type,
             is_case_sensitive,
             phonetic_pattern.as_deref(),
+            phonetic_algorithm.as_deref(),
+            min_similarity,
             category.as_deref(),
             description.as_deref(),
             enabled,
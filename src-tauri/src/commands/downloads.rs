@@ -8,6 +8,6 @@ pub async fn download_file(app: tauri::AppHandle, url: String, dest_path: String
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {}", e))?;
     }
-    download_file_with_progress(&app, &url, &path, "file").await
+    download_file_with_progress(&app, &url, &path, "file", None).await
 }
 
@@ -63,6 +63,26 @@ pub async fn get_whisper_logs_for_transcript(state: State<'_, AppState>, transcr
     state.database.get_whisper_logs_for_transcript(transcript_id, limit).await
 }
 
+#[tauri::command]
+pub async fn search_logs(
+    state: State<'_, AppState>,
+    query: String,
+    session_id: Option<String>,
+    component: Option<String>,
+    limit: i32,
+) -> Result<Vec<serde_json::Value>, String> {
+    let filters = db::LogSearchFilters { session_id, component };
+    state.database.search_logs(&query, filters, limit).await
+}
+
+#[tauri::command]
+pub async fn query_whisper_logs(
+    state: State<'_, AppState>,
+    query: db::LogQuery,
+) -> Result<Vec<serde_json::Value>, String> {
+    state.database.query_whisper_logs(query).await
+}
+
 #[tauri::command]
 pub async fn get_llm_prompt_templates(state: State<'_, AppState>) -> Result<Vec<db::LLMPromptTemplate>, String> {
     state.database.get_llm_prompt_templates().await
@@ -27,4 +27,7 @@ pub mod process_management;
 pub use process_management::*;
 
 pub mod dev_info;
-pub use dev_info::*;
\ No newline at end of file
+pub use dev_info::*;
+
+pub mod benchmark;
+pub use benchmark::*;
\ No newline at end of file
@@ -18,7 +18,7 @@ pub async fn download_model(app: tauri::AppHandle, model_name: String, model_url
         info(Component::Transcription, &format!("Model {} already exists, skipping download", model_name));
         return Ok(());
     }
-    download_file_with_progress(&app, &model_url, &dest_path, "model").await?;
+    download_file_with_progress(&app, &model_url, &dest_path, "model", None).await?;
     let state: State<crate::AppState> = app.state();
     state.model_state_manager.mark_model_downloaded(&model_name, false).await;
     info(Component::Transcription, &format!("Model {} downloaded successfully", model_name));
@@ -43,7 +43,7 @@ async fn download_coreml_model(
     }
     info(Component::Transcription, &format!("Downloading Core ML model for {}", model_name));
     let zip_path = models_dir.join(format!("{}.zip", coreml_filename));
-    download_file_with_progress(app, &coreml_url, &zip_path, "coreml").await?;
+    download_file_with_progress(app, &coreml_url, &zip_path, "coreml", None).await?;
     extract_coreml_model(&zip_path, &coreml_path)?;
     let _ = std::fs::remove_file(&zip_path);
     info(Component::Transcription, &format!("Core ML model downloaded and extracted: {}", coreml_filename));
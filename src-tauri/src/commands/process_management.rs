@@ -1,4 +1,4 @@
-use crate::services::{ServiceManager, ProcessManager, control_plane_monitor};
+use crate::services::{ServiceManager, ServiceEvent, ProcessManager, HealthProbe, control_plane_monitor};
 use crate::AppState;
 use serde_json::json;
 use tauri::State;
@@ -52,6 +52,7 @@ pub async fn get_process_status() -> Result<serde_json::Value, String> {
             "cpu_percent": info.cpu_percent,
             "children": info.children,
             "started_at": info.started_at,
+            "exit_status": info.exit_status,
         }));
     }
     
@@ -198,7 +199,8 @@ pub async fn get_control_plane_status() -> Result<serde_json::Value, String> {
 #[tauri::command]
 pub async fn restart_unhealthy_services(state: State<'_, AppState>) -> Result<String, String> {
     let manager = ProcessManager::new();
-    let health = manager.check_service_health("transcriber", &[5555, 5556, 5557]).await;
+    let probes = [5555u16, 5556, 5557].map(|port| HealthProbe::TcpConnect { port });
+    let health = manager.check_service_health("transcriber", &probes).await;
     
     if !health.healthy {
         log::warn!("Service unhealthy, restarting: {:?}", health.error);
@@ -215,9 +217,10 @@ pub async fn restart_unhealthy_services(state: State<'_, AppState>) -> Result<St
         drop(settings);
         
         // Start the service
-        let result = ServiceManager::start_service(&config).await?;
-        
-        Ok(format!("Service restarted: {}", result))
+        let events = ServiceManager::start_service(&config).await?;
+        let summary = events.iter().map(ServiceEvent::summary).collect::<Vec<_>>().join("\n");
+
+        Ok(format!("Service restarted: {}", summary))
     } else {
         Ok("Service is healthy, no restart needed".to_string())
     }
@@ -102,7 +102,7 @@ pub async fn get_transcript_with_audio_details(
 }
 
 #[tauri::command]
-pub async fn search_transcripts(state: State<'_, AppState>, query: String) -> Result<Vec<db::Transcript>, String> {
+pub async fn search_transcripts(state: State<'_, AppState>, query: String) -> Result<Vec<crate::services::transcripts::SearchHit>, String> {
     let svc = TranscriptsService { database: state.database.clone(), performance_tracker: state.performance_tracker.clone() };
     svc.search_transcripts(query).await
 }
@@ -167,6 +167,36 @@ pub async fn export_audio_file(source_path: String, destination_path: String) ->
     Ok(())
 }
 
+/// Like `export_audio_file`, but transcodes into `format` ("wav" | "ogg" |
+/// "flac") instead of copying the raw WAV, embedding `transcript_id`'s
+/// segments as seek cue points and its text as Vorbis comments for `ogg`.
+#[tauri::command]
+pub async fn export_audio_file_as(
+    state: State<'_, AppState>,
+    source_path: String,
+    destination_path: String,
+    transcript_id: i64,
+    format: String,
+) -> Result<(), String> {
+    use crate::audio::transcode::AudioExportFormat;
+
+    let export_format = match format.as_str() {
+        "wav" => AudioExportFormat::Wav,
+        "ogg" => AudioExportFormat::Ogg,
+        "flac" => AudioExportFormat::Flac,
+        _ => return Err(format!("Invalid audio export format: {}", format)),
+    };
+
+    let transcript = state
+        .database
+        .get_transcript(transcript_id)
+        .await?
+        .ok_or_else(|| format!("Transcript {} not found", transcript_id))?;
+
+    let svc = TranscriptsService { database: state.database.clone(), performance_tracker: state.performance_tracker.clone() };
+    svc.export_audio_file_as(&source_path, &destination_path, export_format, &transcript)
+}
+
 // ============================================================================
 // Model Management Commands
 // ============================================================================
@@ -185,13 +215,40 @@ pub async fn download_model(app: tauri::AppHandle, model_name: String, model_url
         info(Component::Transcription, &format!("Model {} already exists, skipping download", model_name));
         return Ok(());
     }
-    download_file_with_progress(&app, &model_url, &dest_path, "model").await?;
+    download_file_with_progress(&app, &model_url, &dest_path, "model", None).await?;
     let state: State<crate::AppState> = app.state();
     state.model_state_manager.mark_model_downloaded(&model_name, false).await;
     info(Component::Transcription, &format!("Model {} downloaded successfully", model_name));
     Ok(())
 }
 
+/// Download a known model by id with resume, SHA-256 verification, and mirror
+/// fallback. Progress is surfaced through `download-progress` / `model-download-mirror`
+/// events; the model is only considered downloaded once its checksum verifies.
+#[tauri::command]
+pub async fn download_model_verified(app: tauri::AppHandle, state: State<'_, AppState>, model_id: String) -> Result<(), String> {
+    let models_dir = state.models_dir.clone();
+    std::fs::create_dir_all(&models_dir).map_err(|e| format!("Failed to create models directory: {}", e))?;
+
+    let model = {
+        let settings = state.settings.lock().await;
+        models::WhisperModel::all(&models_dir, settings.get())
+            .into_iter()
+            .find(|m| m.id == model_id)
+            .ok_or_else(|| format!("Unknown model: {}", model_id))?
+    };
+
+    if model.is_downloaded(&models_dir) {
+        info(Component::Transcription, &format!("Model {} already present and verified, skipping download", model_id));
+        return Ok(());
+    }
+
+    models::download_model_with_mirrors(&app, &model, &models_dir).await?;
+    state.model_state_manager.mark_model_downloaded(&model_id, false).await;
+    info(Component::Transcription, &format!("Model {} downloaded and verified", model_id));
+    Ok(())
+}
+
 #[cfg(target_os = "macos")]
 async fn download_coreml_model(
     app: &tauri::AppHandle,
@@ -210,7 +267,7 @@ async fn download_coreml_model(
     }
     info(Component::Transcription, &format!("Downloading Core ML model for {}", model_name));
     let zip_path = models_dir.join(format!("{}.zip", coreml_filename));
-    download_file_with_progress(app, &coreml_url, &zip_path, "coreml").await?;
+    download_file_with_progress(app, &coreml_url, &zip_path, "coreml", None).await?;
     extract_coreml_model(&zip_path, &coreml_path)?;
     let _ = std::fs::remove_file(&zip_path);
     info(Component::Transcription, &format!("Core ML model downloaded and extracted: {}", coreml_filename));
@@ -95,11 +95,17 @@ pub async fn get_transcript_with_audio_details(
 }
 
 #[tauri::command]
-pub async fn search_transcripts(state: State<'_, AppState>, query: String) -> Result<Vec<db::Transcript>, String> {
+pub async fn search_transcripts(state: State<'_, AppState>, query: String) -> Result<Vec<crate::services::transcripts::SearchHit>, String> {
     let svc = TranscriptsService { database: state.database.clone(), performance_tracker: state.performance_tracker.clone() };
     svc.search_transcripts(query).await
 }
 
+#[tauri::command]
+pub async fn search_transcripts_matching(state: State<'_, AppState>, query: String, limit: i32) -> Result<Vec<crate::services::transcripts::SearchHit>, String> {
+    let svc = TranscriptsService { database: state.database.clone(), performance_tracker: state.performance_tracker.clone() };
+    svc.search_transcripts_matching(query, limit).await
+}
+
 #[tauri::command]
 pub async fn delete_transcript(state: State<'_, AppState>, id: i64) -> Result<(), String> {
     let svc = TranscriptsService { database: state.database.clone(), performance_tracker: state.performance_tracker.clone() };
@@ -119,6 +125,8 @@ pub async fn export_transcripts(transcripts: Vec<db::Transcript>, format: String
         "json" => serde_json::to_string_pretty(&transcripts).map_err(|e| format!("Failed to serialize to JSON: {}", e)),
         "markdown" => export_transcripts_markdown_static(&transcripts),
         "text" => export_transcripts_text_static(&transcripts),
+        "srt" => crate::services::transcripts::export_transcripts_srt(&transcripts),
+        "vtt" => crate::services::transcripts::export_transcripts_vtt(&transcripts),
         _ => Err("Invalid export format".to_string()),
     }
 }
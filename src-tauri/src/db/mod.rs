@@ -13,6 +13,43 @@ pub struct Transcript {
     pub file_size: Option<i64>,
 }
 
+/// Structured filters applied alongside a `search_logs` full-text query.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LogSearchFilters {
+    pub session_id: Option<String>,
+    pub component: Option<String>,
+}
+
+/// Structured query for `query_whisper_logs`, in the `OptFilters` style:
+/// every field is optional and only becomes a `WHERE` clause when set.
+/// Pagination is keyset-based via `before_id` (`id < before_id`, ordered by
+/// id DESC) rather than `OFFSET`, so scrolling further back never re-scans
+/// rows the caller already has.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LogQuery {
+    pub session_id: Option<String>,
+    pub component: Option<String>,
+    pub level: Option<String>,
+    pub min_level: Option<String>,
+    pub after_ts: Option<String>,
+    pub before_ts: Option<String>,
+    pub limit: i32,
+    pub before_id: Option<i64>,
+}
+
+/// Maps a `whisper_logs.level` value to its severity ordering, so
+/// `min_level` filters can compare levels without a string enum in SQL.
+/// Unknown levels rank below `DEBUG` so they never satisfy a `min_level` filter.
+fn level_rank(level: &str) -> i32 {
+    match level {
+        "DEBUG" => 0,
+        "INFO" => 1,
+        "WARN" => 2,
+        "ERROR" => 3,
+        _ => -1,
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
 pub struct PerformanceMetrics {
     pub id: i64,
@@ -48,6 +85,43 @@ pub struct LLMOutput {
     pub metadata: Option<String>,
 }
 
+/// Maximum number of attempts a stale LLM job is requeued before the reaper
+/// gives up and marks it `failed` instead of handing it to another worker.
+const MAX_JOB_ATTEMPTS: i32 = 5;
+
+/// A queued unit of LLM work, claimed and processed by a worker.
+///
+/// `status` moves `new` -> `running` -> `done`, or back to `new` (and
+/// eventually `failed`) if a worker disappears mid-job; see
+/// [`Database::requeue_stale_jobs`].
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LLMJob {
+    pub id: i64,
+    pub transcript_id: i64,
+    pub prompt_id: String,
+    pub status: String,
+    pub attempts: i32,
+    pub claimed_by: Option<String>,
+    pub heartbeat: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TranscriptEnrichment {
+    pub id: i64,
+    pub transcript_id: i64,
+    pub action_id: String,
+    pub action_name: String,
+    pub slot: String,
+    pub output_text: String,
+    pub provider: String,
+    pub model_used: Option<String>,
+    pub tool_calls: i32,
+    pub processing_time_ms: i32,
+    pub created_at: String,
+    pub metadata: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
 pub struct LLMPromptTemplate {
     pub id: String,
@@ -61,6 +135,23 @@ pub struct LLMPromptTemplate {
     pub updated_at: String,
 }
 
+/// An immutable entry in the append-only sync log, modeled on atuin's record
+/// store. Every mutation worth syncing across devices (dictionary entries,
+/// and optionally transcripts) is captured as one of these rather than
+/// synced as mutable row state, so two hosts can reconcile by exchanging
+/// only the records each is missing.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct SyncRecord {
+    pub id: String,
+    pub host_id: String,
+    pub idx: i64,
+    pub tag: String,
+    pub version: i32,
+    pub parent_id: Option<String>,
+    pub data: String,
+    pub created_at: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
 pub struct DictionaryEntry {
     pub id: i64,
@@ -69,6 +160,8 @@ pub struct DictionaryEntry {
     pub match_type: String,
     pub is_case_sensitive: bool,
     pub phonetic_pattern: Option<String>,
+    pub phonetic_algorithm: Option<String>,
+    pub min_similarity: Option<f64>,
     pub category: Option<String>,
     pub description: Option<String>,
     pub usage_count: i32,
@@ -84,6 +177,10 @@ pub struct DictionaryMatch {
     pub replaced_with: String,
     pub position_start: usize,
     pub position_end: usize,
+    /// Similarity score (0.0-1.0) that triggered this match, for fuzzy
+    /// matches; `None` for exact/word/phrase/regex/phonetic matches, which
+    /// don't have a graded score.
+    pub similarity_score: Option<f64>,
 }
 
 pub struct Database {
@@ -139,6 +236,49 @@ impl Database {
             .execute(&pool)
             .await;
 
+        // Full-text search index over transcript text, kept in sync with the
+        // base table by triggers so inserts/updates/deletes stay consistent.
+        sqlx::query(
+            r#"
+            CREATE VIRTUAL TABLE IF NOT EXISTS transcripts_fts USING fts5(
+                text,
+                content='transcripts',
+                content_rowid='id',
+                tokenize='unicode61'
+            );
+
+            CREATE VIRTUAL TABLE IF NOT EXISTS transcripts_vocab
+                USING fts5vocab('transcripts_fts', 'row');
+
+            CREATE TRIGGER IF NOT EXISTS transcripts_fts_ai AFTER INSERT ON transcripts BEGIN
+                INSERT INTO transcripts_fts(rowid, text) VALUES (new.id, new.text);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS transcripts_fts_ad AFTER DELETE ON transcripts BEGIN
+                INSERT INTO transcripts_fts(transcripts_fts, rowid, text)
+                    VALUES ('delete', old.id, old.text);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS transcripts_fts_au AFTER UPDATE ON transcripts BEGIN
+                INSERT INTO transcripts_fts(transcripts_fts, rowid, text)
+                    VALUES ('delete', old.id, old.text);
+                INSERT INTO transcripts_fts(rowid, text) VALUES (new.id, new.text);
+            END;
+            "#
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to create FTS index: {}", e))?;
+
+        // Backfill any rows that predate the FTS index.
+        let _ = sqlx::query(
+            "INSERT INTO transcripts_fts(rowid, text)
+             SELECT id, text FROM transcripts
+             WHERE id NOT IN (SELECT rowid FROM transcripts_fts)"
+        )
+        .execute(&pool)
+        .await;
+
         // Check if performance_metrics table exists and handle migration properly
         let table_exists = sqlx::query_scalar::<_, i64>(
             "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='performance_metrics'"
@@ -245,6 +385,30 @@ impl Database {
         .await
         .map_err(|e| format!("Failed to create LLM tables: {}", e))?;
 
+        // Create llm_job_queue table: a durable queue so prompt execution can
+        // be deferred, retried, and resumed after a crash instead of running
+        // only synchronously inline with the request that triggered it.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS llm_job_queue (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                transcript_id INTEGER NOT NULL,
+                prompt_id TEXT NOT NULL,
+                status TEXT NOT NULL DEFAULT 'new' CHECK (status IN ('new', 'running', 'done', 'failed')),
+                attempts INTEGER NOT NULL DEFAULT 0,
+                claimed_by TEXT,
+                heartbeat TIMESTAMP,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                FOREIGN KEY (transcript_id) REFERENCES transcripts(id) ON DELETE CASCADE
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_llm_job_queue_status_heartbeat ON llm_job_queue(status, heartbeat);
+            "#
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to create llm_job_queue table: {}", e))?;
+
         // Insert default prompt templates
         sqlx::query(
             r#"
@@ -273,6 +437,33 @@ impl Database {
         .await
         .map_err(|e| format!("Failed to insert default prompt templates: {}", e))?;
 
+        // Create transcript_enrichments table (post-transcription LLM actions)
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS transcript_enrichments (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                transcript_id INTEGER NOT NULL,
+                action_id TEXT NOT NULL,
+                action_name TEXT NOT NULL,
+                slot TEXT NOT NULL,
+                output_text TEXT NOT NULL,
+                provider TEXT NOT NULL,
+                model_used TEXT,
+                tool_calls INTEGER NOT NULL DEFAULT 0,
+                processing_time_ms INTEGER NOT NULL,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                metadata TEXT,
+                FOREIGN KEY (transcript_id) REFERENCES transcripts(id) ON DELETE CASCADE
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_enrichments_transcript_id ON transcript_enrichments(transcript_id);
+            CREATE INDEX IF NOT EXISTS idx_enrichments_action_id ON transcript_enrichments(action_id);
+            "#
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to create transcript_enrichments table: {}", e))?;
+
         // Create whisper_logs table
         sqlx::query(
             r#"
@@ -301,9 +492,11 @@ impl Database {
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
                 original_text TEXT NOT NULL COLLATE NOCASE,
                 replacement_text TEXT NOT NULL,
-                match_type TEXT NOT NULL CHECK (match_type IN ('exact', 'word', 'phrase', 'regex')),
+                match_type TEXT NOT NULL CHECK (match_type IN ('exact', 'word', 'phrase', 'regex', 'phonetic', 'fuzzy')),
                 is_case_sensitive BOOLEAN DEFAULT 0,
                 phonetic_pattern TEXT,
+                phonetic_algorithm TEXT DEFAULT 'soundex',
+                min_similarity REAL DEFAULT 0.85,
                 category TEXT,
                 description TEXT,
                 usage_count INTEGER DEFAULT 0,
@@ -324,6 +517,7 @@ impl Database {
                 replaced_with TEXT NOT NULL,
                 position_start INTEGER NOT NULL,
                 position_end INTEGER NOT NULL,
+                similarity_score REAL,
                 created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
                 FOREIGN KEY (transcript_id) REFERENCES transcripts(id) ON DELETE CASCADE,
                 FOREIGN KEY (entry_id) REFERENCES dictionary_entries(id) ON DELETE CASCADE
@@ -337,6 +531,45 @@ impl Database {
         .await
         .map_err(|e| format!("Failed to create dictionary tables: {}", e))?;
 
+        // Add columns if they don't exist (for existing databases)
+        let _ = sqlx::query("ALTER TABLE dictionary_entries ADD COLUMN phonetic_algorithm TEXT DEFAULT 'soundex'")
+            .execute(&pool)
+            .await;
+        let _ = sqlx::query("ALTER TABLE dictionary_entries ADD COLUMN min_similarity REAL DEFAULT 0.85")
+            .execute(&pool)
+            .await;
+        let _ = sqlx::query("ALTER TABLE dictionary_match_history ADD COLUMN similarity_score REAL")
+            .execute(&pool)
+            .await;
+
+        // Create the append-only sync record log (atuin-style) and the
+        // single-row table that holds this device's host id.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS records (
+                id TEXT PRIMARY KEY,
+                host_id TEXT NOT NULL,
+                idx INTEGER NOT NULL,
+                tag TEXT NOT NULL,
+                version INTEGER NOT NULL DEFAULT 1,
+                parent_id TEXT,
+                data TEXT NOT NULL,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                UNIQUE(host_id, idx)
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_records_host_idx ON records(host_id, idx);
+            CREATE INDEX IF NOT EXISTS idx_records_tag ON records(tag);
+
+            CREATE TABLE IF NOT EXISTS sync_host (
+                id TEXT PRIMARY KEY
+            );
+            "#
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to create sync record tables: {}", e))?;
+
         // Create indexes for whisper_logs
         sqlx::query(
             r#"
@@ -350,6 +583,46 @@ impl Database {
         .await
         .map_err(|e| format!("Failed to create whisper_logs indexes: {}", e))?;
 
+        // Full-text search index over log messages, kept in sync with the base
+        // table by triggers (mirrors the transcripts_fts setup above).
+        sqlx::query(
+            r#"
+            CREATE VIRTUAL TABLE IF NOT EXISTS whisper_logs_fts USING fts5(
+                message,
+                content='whisper_logs',
+                content_rowid='id',
+                tokenize='unicode61'
+            );
+
+            CREATE TRIGGER IF NOT EXISTS whisper_logs_fts_ai AFTER INSERT ON whisper_logs BEGIN
+                INSERT INTO whisper_logs_fts(rowid, message) VALUES (new.id, new.message);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS whisper_logs_fts_ad AFTER DELETE ON whisper_logs BEGIN
+                INSERT INTO whisper_logs_fts(whisper_logs_fts, rowid, message)
+                    VALUES ('delete', old.id, old.message);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS whisper_logs_fts_au AFTER UPDATE ON whisper_logs BEGIN
+                INSERT INTO whisper_logs_fts(whisper_logs_fts, rowid, message)
+                    VALUES ('delete', old.id, old.message);
+                INSERT INTO whisper_logs_fts(rowid, message) VALUES (new.id, new.message);
+            END;
+            "#
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to create whisper_logs FTS index: {}", e))?;
+
+        // Backfill any log rows that predate the FTS index.
+        let _ = sqlx::query(
+            "INSERT INTO whisper_logs_fts(rowid, message)
+             SELECT id, message FROM whisper_logs
+             WHERE id NOT IN (SELECT rowid FROM whisper_logs_fts)"
+        )
+        .execute(&pool)
+        .await;
+
         Ok(Self { pool })
     }
 
@@ -435,6 +708,17 @@ impl Database {
         Ok(transcript)
     }
 
+    pub async fn update_transcript_metadata(&self, id: i64, metadata: &str) -> Result<(), String> {
+        sqlx::query("UPDATE transcripts SET metadata = ?1 WHERE id = ?2")
+            .bind(metadata)
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to update transcript metadata: {}", e))?;
+
+        Ok(())
+    }
+
     pub async fn get_recent_transcripts(&self, limit: i32) -> Result<Vec<Transcript>, String> {
         let transcripts = sqlx::query_as::<_, Transcript>(
             r#"
@@ -470,6 +754,212 @@ impl Database {
         Ok(transcripts)
     }
 
+    /// Full-text search over transcript text using the FTS5 index.
+    ///
+    /// Results are ranked by BM25 (lower raw score = more relevant; returned as
+    /// a positive relevance). Each hit carries a snippet with matched terms
+    /// wrapped in `[` / `]`. Query tokens are expanded with prefix matching and,
+    /// when `max_edit_distance > 0`, near-matches from the index vocabulary so
+    /// that e.g. "recordng" still finds "recording".
+    pub async fn search_transcripts_ranked(
+        &self,
+        query: &str,
+        max_edit_distance: usize,
+    ) -> Result<Vec<(Transcript, String, f64)>, String> {
+        let match_expr = self.build_fts_match(query, max_edit_distance).await?;
+        if match_expr.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = sqlx::query(
+            r#"
+            SELECT t.id, t.text, t.duration_ms, t.created_at, t.metadata, t.audio_path, t.file_size,
+                   snippet(transcripts_fts, 0, '[', ']', '…', 16) AS snippet,
+                   bm25(transcripts_fts) AS score
+            FROM transcripts_fts
+            JOIN transcripts t ON t.id = transcripts_fts.rowid
+            WHERE transcripts_fts MATCH ?1
+            ORDER BY score
+            "#
+        )
+        .bind(&match_expr)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to search transcripts: {}", e))?;
+
+        let hits = rows
+            .into_iter()
+            .map(|row| {
+                let transcript = Transcript {
+                    id: row.get("id"),
+                    text: row.get("text"),
+                    duration_ms: row.get("duration_ms"),
+                    created_at: row.get("created_at"),
+                    metadata: row.get("metadata"),
+                    audio_path: row.get("audio_path"),
+                    file_size: row.get("file_size"),
+                };
+                let snippet: String = row.get("snippet");
+                // BM25 is negative/ascending; flip the sign so higher = better.
+                let score: f64 = -row.get::<f64, _>("score");
+                (transcript, snippet, score)
+            })
+            .collect();
+
+        Ok(hits)
+    }
+
+    /// Build an FTS5 MATCH expression from a free-text query, expanding each
+    /// token into a prefix match plus any vocabulary terms within
+    /// `max_edit_distance` edits. Tokens are combined with implicit AND.
+    async fn build_fts_match(&self, query: &str, max_edit_distance: usize) -> Result<String, String> {
+        let tokens: Vec<String> = query
+            .split_whitespace()
+            .map(|t| t.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase())
+            .filter(|t| !t.is_empty())
+            .collect();
+        if tokens.is_empty() {
+            return Ok(String::new());
+        }
+
+        let vocab: Vec<String> = if max_edit_distance > 0 {
+            sqlx::query_scalar::<_, String>("SELECT term FROM transcripts_vocab")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| format!("Failed to read FTS vocabulary: {}", e))?
+        } else {
+            Vec::new()
+        };
+
+        let mut clauses = Vec::with_capacity(tokens.len());
+        for token in &tokens {
+            let mut variants = vec![format!("{}*", token)];
+            for term in &vocab {
+                if term == token {
+                    continue;
+                }
+                // Cheap length gate before the full distance computation.
+                let len_gap = (term.len() as isize - token.len() as isize).unsigned_abs();
+                if len_gap <= max_edit_distance && levenshtein(term, token) <= max_edit_distance {
+                    variants.push(format!("\"{}\"", term));
+                }
+            }
+            variants.sort();
+            variants.dedup();
+            clauses.push(format!("({})", variants.join(" OR ")));
+        }
+
+        Ok(clauses.join(" AND "))
+    }
+
+    /// Full-text search over transcript text using the query DSL (prefix,
+    /// phrase, AND/OR). Returns ranked hits with a highlighted snippet.
+    pub async fn search_transcripts_matching(
+        &self,
+        query: &str,
+        limit: i32,
+    ) -> Result<Vec<(Transcript, String, f64)>, String> {
+        let match_expr = translate_query_dsl(query);
+        if match_expr.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = sqlx::query(
+            r#"
+            SELECT t.id, t.text, t.duration_ms, t.created_at, t.metadata, t.audio_path, t.file_size,
+                   snippet(transcripts_fts, 0, '[', ']', '…', 16) AS snippet,
+                   bm25(transcripts_fts) AS score
+            FROM transcripts_fts
+            JOIN transcripts t ON t.id = transcripts_fts.rowid
+            WHERE transcripts_fts MATCH ?1
+            ORDER BY score
+            LIMIT ?2
+            "#
+        )
+        .bind(&match_expr)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to search transcripts: {}", e))?;
+
+        let hits = rows
+            .into_iter()
+            .map(|row| {
+                let transcript = Transcript {
+                    id: row.get("id"),
+                    text: row.get("text"),
+                    duration_ms: row.get("duration_ms"),
+                    created_at: row.get("created_at"),
+                    metadata: row.get("metadata"),
+                    audio_path: row.get("audio_path"),
+                    file_size: row.get("file_size"),
+                };
+                let snippet: String = row.get("snippet");
+                let score: f64 = -row.get::<f64, _>("score");
+                (transcript, snippet, score)
+            })
+            .collect();
+
+        Ok(hits)
+    }
+
+    /// Full-text search over whisper log messages, ranked by BM25 with snippet
+    /// highlighting and optional structured filters applied alongside the match.
+    pub async fn search_logs(
+        &self,
+        query: &str,
+        filters: LogSearchFilters,
+        limit: i32,
+    ) -> Result<Vec<serde_json::Value>, String> {
+        let match_expr = translate_query_dsl(query);
+        if match_expr.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = sqlx::query(
+            r#"
+            SELECT l.id, l.session_id, l.transcript_id, l.timestamp, l.level, l.component, l.message,
+                   l.metadata,
+                   snippet(whisper_logs_fts, 0, '[', ']', '…', 16) AS snippet,
+                   bm25(whisper_logs_fts) AS score
+            FROM whisper_logs_fts
+            JOIN whisper_logs l ON l.id = whisper_logs_fts.rowid
+            WHERE whisper_logs_fts MATCH ?1
+              AND (?2 IS NULL OR l.session_id = ?2)
+              AND (?3 IS NULL OR l.component = ?3)
+            ORDER BY score
+            LIMIT ?4
+            "#
+        )
+        .bind(&match_expr)
+        .bind(&filters.session_id)
+        .bind(&filters.component)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to search logs: {}", e))?;
+
+        let logs = rows
+            .into_iter()
+            .map(|row| {
+                serde_json::json!({
+                    "id": row.get::<i64, _>("id"),
+                    "session_id": row.get::<String, _>("session_id"),
+                    "transcript_id": row.get::<Option<i64>, _>("transcript_id"),
+                    "timestamp": row.get::<String, _>("timestamp"),
+                    "level": row.get::<String, _>("level"),
+                    "component": row.get::<String, _>("component"),
+                    "message": row.get::<String, _>("message"),
+                    "metadata": row.get::<Option<String>, _>("metadata").and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok()),
+                    "snippet": row.get::<String, _>("snippet"),
+                    "score": -row.get::<f64, _>("score"),
+                })
+            })
+            .collect();
+
+        Ok(logs)
+    }
+
     pub async fn delete_transcript(&self, id: i64) -> Result<(), String> {
         sqlx::query("DELETE FROM transcripts WHERE id = ?1")
             .bind(id)
@@ -706,6 +1196,58 @@ impl Database {
         Ok(logs)
     }
 
+    /// Structured, paginated query over `whisper_logs`. Unlike
+    /// `get_whisper_logs_for_session`, filters are applied in SQL (not
+    /// fetched-then-filtered in memory) and pagination is keyset-based via
+    /// `before_id`, so the UI's log viewer can scroll indefinitely without
+    /// re-reading rows it has already shown.
+    pub async fn query_whisper_logs(&self, query: LogQuery) -> Result<Vec<serde_json::Value>, String> {
+        let min_level_rank = query.min_level.as_deref().map(level_rank);
+
+        let rows = sqlx::query(
+            r#"
+            SELECT id, session_id, transcript_id, timestamp, level, component, message, metadata
+            FROM whisper_logs
+            WHERE (?1 IS NULL OR session_id = ?1)
+              AND (?2 IS NULL OR component = ?2)
+              AND (?3 IS NULL OR level = ?3)
+              AND (?4 IS NULL OR
+                   (CASE level WHEN 'DEBUG' THEN 0 WHEN 'INFO' THEN 1 WHEN 'WARN' THEN 2 WHEN 'ERROR' THEN 3 ELSE -1 END) >= ?4)
+              AND (?5 IS NULL OR timestamp >= ?5)
+              AND (?6 IS NULL OR timestamp <= ?6)
+              AND (?7 IS NULL OR id < ?7)
+            ORDER BY id DESC
+            LIMIT ?8
+            "#
+        )
+        .bind(&query.session_id)
+        .bind(&query.component)
+        .bind(&query.level)
+        .bind(min_level_rank)
+        .bind(&query.after_ts)
+        .bind(&query.before_ts)
+        .bind(query.before_id)
+        .bind(query.limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to query whisper logs: {}", e))?;
+
+        let logs: Vec<serde_json::Value> = rows.into_iter().map(|row| {
+            serde_json::json!({
+                "id": row.get::<i64, _>("id"),
+                "session_id": row.get::<String, _>("session_id"),
+                "transcript_id": row.get::<Option<i64>, _>("transcript_id"),
+                "timestamp": row.get::<String, _>("timestamp"),
+                "level": row.get::<String, _>("level"),
+                "component": row.get::<String, _>("component"),
+                "message": row.get::<String, _>("message"),
+                "metadata": row.get::<Option<String>, _>("metadata").and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok()),
+            })
+        }).collect();
+
+        Ok(logs)
+    }
+
     // Performance Timeline methods
     pub async fn save_performance_timeline_events(
         &self,
@@ -828,6 +1370,211 @@ impl Database {
         Ok(outputs)
     }
 
+    /// Enqueue an LLM prompt to run against a transcript asynchronously.
+    pub async fn enqueue_llm_job(&self, transcript_id: i64, prompt_id: &str) -> Result<i64, String> {
+        let result = sqlx::query(
+            "INSERT INTO llm_job_queue (transcript_id, prompt_id) VALUES (?1, ?2)"
+        )
+        .bind(transcript_id)
+        .bind(prompt_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to enqueue LLM job: {}", e))?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Atomically claim the oldest `new` job for `worker_id`, flipping it to
+    /// `running` and stamping the initial heartbeat in one `UPDATE ...
+    /// RETURNING` so two workers can never claim the same row.
+    pub async fn claim_next_job(&self, worker_id: &str) -> Result<Option<LLMJob>, String> {
+        let job = sqlx::query_as::<_, LLMJob>(
+            r#"
+            UPDATE llm_job_queue
+            SET status = 'running', claimed_by = ?1, heartbeat = CURRENT_TIMESTAMP
+            WHERE id = (
+                SELECT id FROM llm_job_queue
+                WHERE status = 'new'
+                ORDER BY created_at ASC
+                LIMIT 1
+            )
+            RETURNING id, transcript_id, prompt_id, status, attempts, claimed_by, heartbeat, created_at
+            "#
+        )
+        .bind(worker_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to claim LLM job: {}", e))?;
+
+        Ok(job)
+    }
+
+    /// Refresh a running job's heartbeat so the reaper doesn't consider it
+    /// abandoned. Called periodically by the worker while it holds the job.
+    pub async fn touch_heartbeat(&self, job_id: i64) -> Result<(), String> {
+        sqlx::query("UPDATE llm_job_queue SET heartbeat = CURRENT_TIMESTAMP WHERE id = ?1")
+            .bind(job_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to touch LLM job heartbeat: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Reset `running` jobs whose heartbeat is older than `max_age_secs` back
+    /// to `new` for another worker to pick up, incrementing `attempts`. Jobs
+    /// that have already exhausted `MAX_JOB_ATTEMPTS` are moved to `failed`
+    /// instead of being requeued again.
+    pub async fn requeue_stale_jobs(&self, max_age_secs: i64) -> Result<u64, String> {
+        let result = sqlx::query(
+            r#"
+            UPDATE llm_job_queue
+            SET status = 'failed', claimed_by = NULL
+            WHERE status = 'running'
+              AND heartbeat < datetime('now', '-' || ?1 || ' seconds')
+              AND attempts + 1 >= ?2
+            "#
+        )
+        .bind(max_age_secs)
+        .bind(MAX_JOB_ATTEMPTS)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to fail exhausted LLM jobs: {}", e))?;
+        let failed = result.rows_affected();
+
+        let result = sqlx::query(
+            r#"
+            UPDATE llm_job_queue
+            SET status = 'new', claimed_by = NULL, attempts = attempts + 1
+            WHERE status = 'running'
+              AND heartbeat < datetime('now', '-' || ?1 || ' seconds')
+            "#
+        )
+        .bind(max_age_secs)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to requeue stale LLM jobs: {}", e))?;
+
+        Ok(failed + result.rows_affected())
+    }
+
+    /// Record a completed job's LLM output and mark the job `done` in a
+    /// single transaction, so a crash between the two can't leave a job
+    /// stuck `running` with no corresponding output (or vice versa).
+    #[allow(clippy::too_many_arguments)]
+    pub async fn complete_llm_job(
+        &self,
+        job_id: i64,
+        transcript_id: i64,
+        prompt_id: &str,
+        prompt_name: &str,
+        prompt_template: &str,
+        input_text: &str,
+        output_text: &str,
+        model_used: &str,
+        processing_time_ms: i32,
+        temperature: f32,
+        max_tokens: i32,
+        metadata: Option<&str>,
+    ) -> Result<i64, String> {
+        let mut tx = self.pool.begin()
+            .await
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO llm_outputs (
+                transcript_id, prompt_id, prompt_name, prompt_template,
+                input_text, output_text, model_used, processing_time_ms,
+                temperature, max_tokens, metadata
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+            "#
+        )
+        .bind(transcript_id)
+        .bind(prompt_id)
+        .bind(prompt_name)
+        .bind(prompt_template)
+        .bind(input_text)
+        .bind(output_text)
+        .bind(model_used)
+        .bind(processing_time_ms)
+        .bind(temperature)
+        .bind(max_tokens)
+        .bind(metadata)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to save LLM output: {}", e))?;
+        let output_id = result.last_insert_rowid();
+
+        sqlx::query("UPDATE llm_job_queue SET status = 'done' WHERE id = ?1")
+            .bind(job_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to mark LLM job done: {}", e))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(output_id)
+    }
+
+    pub async fn save_enrichment(
+        &self,
+        transcript_id: i64,
+        action_id: &str,
+        action_name: &str,
+        slot: &str,
+        output_text: &str,
+        provider: &str,
+        model_used: Option<&str>,
+        tool_calls: i32,
+        processing_time_ms: i32,
+        metadata: Option<&str>,
+    ) -> Result<i64, String> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO transcript_enrichments (
+                transcript_id, action_id, action_name, slot, output_text,
+                provider, model_used, tool_calls, processing_time_ms, metadata
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            "#
+        )
+        .bind(transcript_id)
+        .bind(action_id)
+        .bind(action_name)
+        .bind(slot)
+        .bind(output_text)
+        .bind(provider)
+        .bind(model_used)
+        .bind(tool_calls)
+        .bind(processing_time_ms)
+        .bind(metadata)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save transcript enrichment: {}", e))?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    pub async fn get_enrichments_for_transcript(&self, transcript_id: i64) -> Result<Vec<TranscriptEnrichment>, String> {
+        let enrichments = sqlx::query_as::<_, TranscriptEnrichment>(
+            r#"
+            SELECT * FROM transcript_enrichments
+            WHERE transcript_id = ?1
+            ORDER BY created_at ASC
+            "#
+        )
+        .bind(transcript_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to get transcript enrichments: {}", e))?;
+
+        Ok(enrichments)
+    }
+
     pub async fn get_llm_prompt_templates(&self) -> Result<Vec<LLMPromptTemplate>, String> {
         let templates = sqlx::query_as::<_, LLMPromptTemplate>(
             r#"
@@ -938,6 +1685,8 @@ impl Database {
         match_type: &str,
         is_case_sensitive: bool,
         phonetic_pattern: Option<&str>,
+        phonetic_algorithm: Option<&str>,
+        min_similarity: Option<f64>,
         category: Option<&str>,
         description: Option<&str>,
     ) -> Result<i64, String> {
@@ -945,9 +1694,9 @@ impl Database {
             r#"
             INSERT INTO dictionary_entries (
                 original_text, replacement_text, match_type, is_case_sensitive,
-                phonetic_pattern, category, description
+                phonetic_pattern, phonetic_algorithm, min_similarity, category, description
             )
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
             "#
         )
         .bind(original_text)
@@ -955,13 +1704,32 @@ impl Database {
         .bind(match_type)
         .bind(is_case_sensitive)
         .bind(phonetic_pattern)
+        .bind(phonetic_algorithm)
+        .bind(min_similarity)
         .bind(category)
         .bind(description)
         .execute(&self.pool)
         .await
         .map_err(|e| format!("Failed to save dictionary entry: {}", e))?;
+        let id = result.last_insert_rowid();
 
-        Ok(result.last_insert_rowid())
+        let data = serde_json::json!({
+            "op": "upsert",
+            "id": id,
+            "original_text": original_text,
+            "replacement_text": replacement_text,
+            "match_type": match_type,
+            "is_case_sensitive": is_case_sensitive,
+            "phonetic_pattern": phonetic_pattern,
+            "phonetic_algorithm": phonetic_algorithm,
+            "min_similarity": min_similarity,
+            "category": category,
+            "description": description,
+        })
+        .to_string();
+        self.append_record("dictionary_entries", &data).await?;
+
+        Ok(id)
     }
 
     pub async fn update_dictionary_entry(
@@ -972,17 +1740,20 @@ impl Database {
         match_type: &str,
         is_case_sensitive: bool,
         phonetic_pattern: Option<&str>,
+        phonetic_algorithm: Option<&str>,
+        min_similarity: Option<f64>,
         category: Option<&str>,
         description: Option<&str>,
         enabled: bool,
     ) -> Result<(), String> {
         sqlx::query(
             r#"
-            UPDATE dictionary_entries 
+            UPDATE dictionary_entries
             SET original_text = ?1, replacement_text = ?2, match_type = ?3,
-                is_case_sensitive = ?4, phonetic_pattern = ?5, category = ?6,
-                description = ?7, enabled = ?8, updated_at = CURRENT_TIMESTAMP
-            WHERE id = ?9
+                is_case_sensitive = ?4, phonetic_pattern = ?5, phonetic_algorithm = ?6,
+                min_similarity = ?7, category = ?8, description = ?9, enabled = ?10,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = ?11
             "#
         )
         .bind(original_text)
@@ -990,6 +1761,8 @@ impl Database {
         .bind(match_type)
         .bind(is_case_sensitive)
         .bind(phonetic_pattern)
+        .bind(phonetic_algorithm)
+        .bind(min_similarity)
         .bind(category)
         .bind(description)
         .bind(enabled)
@@ -998,6 +1771,22 @@ impl Database {
         .await
         .map_err(|e| format!("Failed to update dictionary entry: {}", e))?;
 
+        let data = serde_json::json!({
+            "op": "upsert",
+            "id": id,
+            "original_text": original_text,
+            "replacement_text": replacement_text,
+            "match_type": match_type,
+            "is_case_sensitive": is_case_sensitive,
+            "phonetic_pattern": phonetic_pattern,
+            "phonetic_algorithm": phonetic_algorithm,
+            "min_similarity": min_similarity,
+            "category": category,
+            "description": description,
+        })
+        .to_string();
+        self.append_record("dictionary_entries", &data).await?;
+
         Ok(())
     }
 
@@ -1008,9 +1797,118 @@ impl Database {
             .await
             .map_err(|e| format!("Failed to delete dictionary entry: {}", e))?;
 
+        let data = serde_json::json!({ "op": "delete", "id": id }).to_string();
+        self.append_record("dictionary_entries", &data).await?;
+
         Ok(())
     }
 
+    /// Return this device's sync host id, generating and persisting a new
+    /// UUID on first use.
+    async fn local_host_id(&self) -> Result<String, String> {
+        if let Some(id) = sqlx::query_scalar::<_, String>("SELECT id FROM sync_host LIMIT 1")
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to read sync host id: {}", e))?
+        {
+            return Ok(id);
+        }
+
+        let id = uuid::Uuid::new_v4().to_string();
+        sqlx::query("INSERT INTO sync_host (id) VALUES (?1)")
+            .bind(&id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to persist sync host id: {}", e))?;
+
+        Ok(id)
+    }
+
+    /// Append an immutable record to this device's sync log, assigning the
+    /// next per-host `idx` and chaining `parent_id` to the previous record
+    /// for that host so gaps in the log are detectable during reconciliation.
+    async fn append_record(&self, tag: &str, data: &str) -> Result<SyncRecord, String> {
+        let host_id = self.local_host_id().await?;
+
+        let parent_id = sqlx::query_scalar::<_, String>(
+            "SELECT id FROM records WHERE host_id = ?1 ORDER BY idx DESC LIMIT 1"
+        )
+        .bind(&host_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to read previous sync record: {}", e))?;
+
+        let next_idx: i64 = sqlx::query_scalar::<_, Option<i64>>(
+            "SELECT MAX(idx) FROM records WHERE host_id = ?1"
+        )
+        .bind(&host_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to compute next sync record index: {}", e))?
+        .map_or(0, |idx| idx + 1);
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let record = sqlx::query_as::<_, SyncRecord>(
+            r#"
+            INSERT INTO records (id, host_id, idx, tag, version, parent_id, data)
+            VALUES (?1, ?2, ?3, ?4, 1, ?5, ?6)
+            RETURNING id, host_id, idx, tag, version, parent_id, data, created_at
+            "#
+        )
+        .bind(&id)
+        .bind(&host_id)
+        .bind(next_idx)
+        .bind(tag)
+        .bind(&parent_id)
+        .bind(data)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to append sync record: {}", e))?;
+
+        Ok(record)
+    }
+
+    /// Fetch up to `limit` records for `host_id` with `idx > after_idx`, in
+    /// `idx` order, for a peer doing an incremental pull.
+    pub async fn next_records_since(
+        &self,
+        host_id: &str,
+        after_idx: i64,
+        limit: i32,
+    ) -> Result<Vec<SyncRecord>, String> {
+        let records = sqlx::query_as::<_, SyncRecord>(
+            r#"
+            SELECT * FROM records
+            WHERE host_id = ?1 AND idx > ?2
+            ORDER BY idx ASC
+            LIMIT ?3
+            "#
+        )
+        .bind(host_id)
+        .bind(after_idx)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to read sync records: {}", e))?;
+
+        Ok(records)
+    }
+
+    /// Return each known host's high-water mark (its highest `idx`), so a
+    /// peer can diff this against its own status map to find what it's
+    /// missing from each host.
+    pub async fn record_status(&self) -> Result<std::collections::HashMap<String, i64>, String> {
+        let rows = sqlx::query("SELECT host_id, MAX(idx) AS max_idx FROM records GROUP BY host_id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to read sync record status: {}", e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.get::<String, _>("host_id"), row.get::<i64, _>("max_idx")))
+            .collect())
+    }
+
     pub async fn increment_dictionary_usage(&self, entry_id: i64) -> Result<(), String> {
         sqlx::query(
             r#"
@@ -1041,9 +1939,9 @@ impl Database {
                 r#"
                 INSERT INTO dictionary_match_history (
                     transcript_id, entry_id, matched_text, replaced_with,
-                    position_start, position_end
+                    position_start, position_end, similarity_score
                 )
-                VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
                 "#
             )
             .bind(transcript_id)
@@ -1052,6 +1950,7 @@ impl Database {
             .bind(&m.replaced_with)
             .bind(m.position_start as i64)
             .bind(m.position_end as i64)
+            .bind(m.similarity_score)
             .execute(&mut *tx)
             .await
             .map_err(|e| format!("Failed to save dictionary match: {}", e))?;
@@ -1100,6 +1999,7 @@ impl Database {
                 "replaced_with": row.get::<String, _>("replaced_with"),
                 "position_start": row.get::<i64, _>("position_start"),
                 "position_end": row.get::<i64, _>("position_end"),
+                "similarity_score": row.get::<Option<f64>, _>("similarity_score"),
                 "original_text": row.get::<String, _>("original_text"),
                 "replacement_text": row.get::<String, _>("replacement_text"),
                 "category": row.get::<Option<String>, _>("category"),
@@ -1109,4 +2009,73 @@ impl Database {
 
         Ok(matches)
     }
+}
+
+/// Translate a small, atuin-style search DSL into an FTS5 `MATCH` expression.
+///
+/// Supports phrase matches (`"exact phrase"`), prefix matches (`term*`), and
+/// explicit `AND`/`OR` between terms; terms are otherwise combined with FTS5's
+/// implicit AND. Bare terms are stripped of punctuation and quoted so
+/// user input can't break out into arbitrary FTS5 query syntax.
+fn translate_query_dsl(query: &str) -> String {
+    let mut clauses: Vec<String> = Vec::new();
+    let mut chars = query.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        if c == '"' {
+            chars.next();
+            let phrase: String = chars.by_ref().take_while(|&c| c != '"').collect();
+            let phrase = phrase.replace('"', "");
+            if !phrase.is_empty() {
+                clauses.push(format!("\"{}\"", phrase));
+            }
+            continue;
+        }
+
+        let word: String = chars
+            .by_ref()
+            .take_while(|c| !c.is_whitespace())
+            .collect();
+
+        match word.to_uppercase().as_str() {
+            "AND" => clauses.push("AND".to_string()),
+            "OR" => clauses.push("OR".to_string()),
+            _ => {
+                let prefix = word.ends_with('*');
+                let term: String = word.chars().filter(|c| c.is_alphanumeric()).collect();
+                if !term.is_empty() {
+                    clauses.push(if prefix {
+                        format!("{}*", term)
+                    } else {
+                        format!("\"{}\"", term)
+                    });
+                }
+            }
+        }
+    }
+
+    clauses.join(" ")
+}
+
+/// Levenshtein edit distance between two strings, used to expand query tokens
+/// into near-matches for typo-tolerant search.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
 }
\ No newline at end of file
@@ -53,6 +53,10 @@ pub struct ProcessingResponse {
     pub result: Option<String>,
     pub error: Option<String>,
     pub processing_time_ms: u64,
+    /// Parsed JSON for `ExtractStructured`; `None` for operations that
+    /// return plain text (including `Format`, which returns formatted
+    /// prose rather than data).
+    pub structured: Option<serde_json::Value>,
 }
 
 /// Types of processing operations available
@@ -71,6 +75,47 @@ pub enum ProcessingOperation {
     Format { document_type: String },
 }
 
+/// Named `format` presets accepted by `ProcessingOperation::ExtractStructured`
+/// in addition to a literal JSON Schema document, so common transcript
+/// extraction shapes don't require callers to hand-author one.
+fn resolve_extraction_schema(format: &str) -> String {
+    match format {
+        "action_items" => {
+            r#"{"type":"object","required":["items"],"properties":{"items":{"type":"array","items":{"type":"object","required":["task"]}}}}"#.to_string()
+        }
+        "meeting_notes" => {
+            r#"{"type":"object","required":["summary","decisions","action_items"]}"#.to_string()
+        }
+        "json" => r#"{"type":"object"}"#.to_string(),
+        literal_schema => literal_schema.to_string(),
+    }
+}
+
+/// `Format` document types with built-in templates on the Swift side.
+const KNOWN_DOCUMENT_TYPES: &[&str] = &["meeting_minutes", "action_items", "qa_transcript"];
+
+/// Minimal structural check: confirms `value` is a JSON object containing
+/// every key `schema` lists under `required`. This intentionally isn't a
+/// full JSON Schema validator (no type/format/nested checks) — guided
+/// generation on the Swift side is what actually constrains the shape;
+/// this just catches the model drifting off the requested top-level keys.
+fn validate_against_schema(value: &serde_json::Value, schema: &serde_json::Value) -> Result<(), String> {
+    let Some(required) = schema.get("required").and_then(|r| r.as_array()) else {
+        return Ok(());
+    };
+    let Some(obj) = value.as_object() else {
+        return Err("expected a JSON object".to_string());
+    };
+    for key in required {
+        if let Some(key) = key.as_str() {
+            if !obj.contains_key(key) {
+                return Err(format!("missing required field `{}`", key));
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Foundation Models processor using native Swift bridge
 pub struct FoundationModelsProcessor {
     config: FoundationModelsConfig,
@@ -118,8 +163,19 @@ impl FoundationModelsProcessor {
                 ProcessingOperation::Summarize { max_sentences } => {
                     FoundationModels::summarize_text(text, max_sentences as u32)
                 }
-                _ => {
-                    Err("Operation not supported".to_string())
+                ProcessingOperation::ExtractStructured { format } => {
+                    let schema = resolve_extraction_schema(&format);
+                    FoundationModels::extract_structured(text, &schema)
+                }
+                ProcessingOperation::Format { document_type } => {
+                    if !KNOWN_DOCUMENT_TYPES.contains(&document_type.as_str()) {
+                        Err(format!(
+                            "Unknown document type `{}`; expected one of {:?}",
+                            document_type, KNOWN_DOCUMENT_TYPES
+                        ))
+                    } else {
+                        FoundationModels::format_transcript(text, &document_type)
+                    }
                 }
             };
 
@@ -146,6 +202,63 @@ impl FoundationModelsProcessor {
         }
     }
 
+    /// Extract structured data matching `format` (a named preset or a
+    /// literal JSON Schema; see [`resolve_extraction_schema`]). Validates
+    /// the model's output against the schema and retries once if it fails
+    /// to parse or is missing required fields, since guided generation is
+    /// a strong constraint but not an infallible one.
+    pub async fn process_structured(&self, text: &str, format: &str) -> Result<ProcessingResponse, String> {
+        let start_time = std::time::Instant::now();
+        let schema_str = resolve_extraction_schema(format);
+        let schema: serde_json::Value = serde_json::from_str(&schema_str)
+            .map_err(|e| format!("Invalid schema for `{}`: {}", format, e))?;
+
+        let mut last_err = String::new();
+        for attempt in 1..=2 {
+            let raw = self
+                .process_text(text, ProcessingOperation::ExtractStructured { format: format.to_string() })
+                .await?;
+
+            match serde_json::from_str::<serde_json::Value>(&raw) {
+                Ok(parsed) => match validate_against_schema(&parsed, &schema) {
+                    Ok(()) => {
+                        return Ok(ProcessingResponse {
+                            success: true,
+                            result: Some(raw),
+                            error: None,
+                            processing_time_ms: start_time.elapsed().as_millis() as u64,
+                            structured: Some(parsed),
+                        });
+                    }
+                    Err(e) => last_err = format!("attempt {}: {}", attempt, e),
+                },
+                Err(e) => {
+                    last_err = format!("attempt {}: failed to parse model output as JSON: {}", attempt, e)
+                }
+            }
+        }
+
+        warn(Component::Enhancement, &format!("Structured extraction failed: {}", last_err));
+        Err(last_err)
+    }
+
+    /// Format `text` as `document_type` using one of the built-in templates
+    /// (see [`KNOWN_DOCUMENT_TYPES`]).
+    pub async fn process_format(&self, text: &str, document_type: &str) -> Result<ProcessingResponse, String> {
+        let start_time = std::time::Instant::now();
+        let result = self
+            .process_text(text, ProcessingOperation::Format { document_type: document_type.to_string() })
+            .await?;
+
+        Ok(ProcessingResponse {
+            success: true,
+            result: Some(result),
+            error: None,
+            processing_time_ms: start_time.elapsed().as_millis() as u64,
+            structured: None,
+        })
+    }
+
     /// Update configuration
     pub fn update_config(&mut self, config: FoundationModelsConfig) {
         self.config = config;
@@ -159,15 +272,23 @@ impl FoundationModelsProcessor {
 pub async fn enhance_transcript(
     text: String,
     config: Option<FoundationModelsConfig>,
+    vocabulary: Option<crate::transcription::VocabularyConfig>,
 ) -> Result<String, String> {
     let processor_config = config.unwrap_or_default();
     let processor = FoundationModelsProcessor::new(processor_config)?;
-    
+
     if !processor.is_available().await {
         return Err("Foundation Models not available on this system".to_string());
     }
 
-    processor.process_text(&text, ProcessingOperation::Enhance).await
+    // Correct custom-vocabulary terms before enhancement so the grammar
+    // cleanup pass doesn't re-mangle a term it just got rewritten into.
+    let corrected_text = match &vocabulary {
+        Some(vocabulary) => crate::transcription::vocabulary::apply_corrections(&text, vocabulary),
+        None => text,
+    };
+
+    processor.process_text(&corrected_text, ProcessingOperation::Enhance).await
 }
 
 #[command]
@@ -210,16 +331,15 @@ pub async fn extract_structured_data(
     text: String,
     format: String,
     config: Option<FoundationModelsConfig>,
-) -> Result<String, String> {
+) -> Result<ProcessingResponse, String> {
     let processor_config = config.unwrap_or_default();
     let processor = FoundationModelsProcessor::new(processor_config)?;
-    
+
     if !processor.is_available().await {
         return Err("Foundation Models not available on this system".to_string());
     }
 
-    let operation = ProcessingOperation::ExtractStructured { format };
-    processor.process_text(&text, operation).await
+    processor.process_structured(&text, &format).await
 }
 
 #[command]
@@ -227,16 +347,15 @@ pub async fn format_transcript(
     text: String,
     document_type: String,
     config: Option<FoundationModelsConfig>,
-) -> Result<String, String> {
+) -> Result<ProcessingResponse, String> {
     let processor_config = config.unwrap_or_default();
     let processor = FoundationModelsProcessor::new(processor_config)?;
-    
+
     if !processor.is_available().await {
         return Err("Foundation Models not available on this system".to_string());
     }
 
-    let operation = ProcessingOperation::Format { document_type };
-    processor.process_text(&text, operation).await
+    processor.process_format(&text, &document_type).await
 }
 
 #[command]
@@ -249,4 +368,72 @@ pub async fn check_foundation_models_availability() -> Result<bool, String> {
     {
         Ok(false)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_TRANSCRIPT: &str = "Alright team, let's kick off. Sarah will own the \
+        migration doc and get it to review by Friday. Tom, can you follow up with the \
+        vendor about pricing? We decided to delay the launch by a week.";
+
+    #[test]
+    fn resolve_extraction_schema_presets_are_valid_json_schema_documents() {
+        for preset in ["action_items", "meeting_notes", "json"] {
+            let schema_str = resolve_extraction_schema(preset);
+            let schema: serde_json::Value =
+                serde_json::from_str(&schema_str).expect("preset schema must be valid JSON");
+            assert_eq!(schema["type"], "object");
+        }
+    }
+
+    #[test]
+    fn resolve_extraction_schema_passes_through_literal_schema_unchanged() {
+        let literal = r#"{"type":"object","required":["title"]}"#;
+        assert_eq!(resolve_extraction_schema(literal), literal);
+    }
+
+    #[test]
+    fn validate_against_schema_accepts_action_items_extracted_from_transcript() {
+        assert!(SAMPLE_TRANSCRIPT.contains("Sarah"));
+        assert!(SAMPLE_TRANSCRIPT.contains("vendor"));
+
+        // What a guided-generation pass over SAMPLE_TRANSCRIPT should yield.
+        let extracted = serde_json::json!({
+            "items": [
+                { "task": "Own the migration doc and send for review", "owner": "Sarah", "due": "Friday" },
+                { "task": "Follow up with the vendor about pricing", "owner": "Tom" },
+            ]
+        });
+        let schema: serde_json::Value =
+            serde_json::from_str(&resolve_extraction_schema("action_items")).unwrap();
+
+        assert!(validate_against_schema(&extracted, &schema).is_ok());
+    }
+
+    #[test]
+    fn validate_against_schema_rejects_missing_required_field() {
+        let extracted = serde_json::json!({ "notes": "no items key present" });
+        let schema: serde_json::Value =
+            serde_json::from_str(&resolve_extraction_schema("action_items")).unwrap();
+
+        let err = validate_against_schema(&extracted, &schema).unwrap_err();
+        assert!(err.contains("items"));
+    }
+
+    #[test]
+    fn validate_against_schema_rejects_non_object_output() {
+        let extracted = serde_json::json!(["not", "an", "object"]);
+        let schema: serde_json::Value =
+            serde_json::from_str(&resolve_extraction_schema("meeting_notes")).unwrap();
+
+        assert!(validate_against_schema(&extracted, &schema).is_err());
+    }
+
+    #[test]
+    fn format_rejects_unknown_document_type() {
+        assert!(!KNOWN_DOCUMENT_TYPES.contains(&"executive_summary"));
+        assert!(KNOWN_DOCUMENT_TYPES.contains(&"meeting_minutes"));
+    }
 }
\ No newline at end of file
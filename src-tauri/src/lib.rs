@@ -1,4 +1,6 @@
 // Public API modules
+pub mod bench;
+pub mod benchmark;
 pub mod benchmarking;
 pub mod commands;
 pub mod db;
@@ -14,7 +16,7 @@ pub use core::recording_progress;
 pub use core::transcription_context;
 pub use monitoring::whisper_log_interceptor;
 pub use monitoring::whisper_logger;
-pub use post_processing::{dictionary_processor, profanity_filter, PostProcessingHooks};
+pub use post_processing::{dictionary_processor, vocabulary_filter, PostProcessingHooks};
 
 // Internal modules - organized by domain
 mod audio;
@@ -51,6 +53,9 @@ mod utils;
 // External services
 mod webhooks;
 
+// Pluggable output transports for streaming results and transcript exports
+mod transport;
+
 #[cfg(target_os = "macos")]
 mod macos;
 
@@ -617,6 +622,7 @@ pub fn run() {
             crate::commands::start_audio_level_monitoring,
             crate::commands::stop_audio_level_monitoring,
             crate::commands::get_current_audio_level,
+            crate::commands::get_voice_activity,
             crate::commands::transcribe_audio,
             crate::commands::transcribe_file,
             crate::commands::save_transcript,
@@ -624,10 +630,12 @@ pub fn run() {
             crate::commands::get_transcript_with_audio_details,
             crate::commands::get_recent_transcripts,
             crate::commands::search_transcripts,
+            crate::commands::search_transcripts_matching,
             crate::commands::delete_transcript,
             crate::commands::delete_transcripts,
             crate::commands::export_transcripts,
             crate::commands::export_audio_file,
+            crate::commands::export_audio_file_as,
             crate::commands::start_recording_no_transcription,
             crate::commands::stop_recording_no_transcription,
             crate::commands::start_recording_classic_strategy,
@@ -652,6 +660,7 @@ pub fn run() {
             crate::commands::update_global_shortcut,
             crate::commands::subscribe_to_progress,
             crate::commands::download_model,
+            crate::commands::download_model_verified,
             crate::commands::check_and_download_missing_coreml_models,
             crate::commands::download_coreml_for_model,
             crate::commands::get_model_coreml_status,
@@ -679,6 +688,8 @@ pub fn run() {
             crate::commands::get_llm_outputs_for_transcript,
             crate::commands::get_whisper_logs_for_session,
             crate::commands::get_whisper_logs_for_transcript,
+            crate::commands::search_logs,
+            crate::commands::query_whisper_logs,
             crate::commands::get_llm_prompt_templates,
             crate::commands::save_llm_prompt_template,
             crate::commands::delete_llm_prompt_template,
@@ -716,6 +727,7 @@ pub fn run() {
             crate::commands::generate_sample_data,
             crate::commands::get_performance_metrics_for_transcript,
             crate::commands::get_performance_timeline_for_transcript,
+            crate::commands::run_strategy_benchmark,
             foundation_models::enhance_transcript,
             foundation_models::summarize_transcript,
             foundation_models::clean_speech_patterns,
@@ -0,0 +1,470 @@
+//! Post-transcription LLM actions pipeline.
+//!
+//! An *action* runs after a transcript is saved and pipes the text through a
+//! configurable LLM step — punctuation/grammar cleanup, summarization, action
+//! item extraction, and so on. Actions are registered by name in an
+//! [`ActionRegistry`] and can declare callable functions ("tools"); when the
+//! model replies with a function call the mapped [`ToolHandler`] runs and its
+//! result is fed back until the model produces a final answer. The final answer
+//! is always saved as a row in `transcript_enrichments`; an action whose
+//! [`OutputSlot`] is `AudioMetadata` also merges it into the transcript's
+//! `metadata` JSON under the action's id. The existing webhook is fired on
+//! completion.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::logger::{debug, error, info, Component};
+
+/// Where the final output of an action is stored.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputSlot {
+    /// Also merge the result into the transcript's `metadata` JSON, under the
+    /// action's id; `key` is not used when the result is persisted.
+    AudioMetadata { key: String },
+    /// Store the result as a row in `transcript_enrichments`.
+    Enrichment,
+}
+
+impl OutputSlot {
+    fn label(&self) -> &'static str {
+        match self {
+            OutputSlot::AudioMetadata { .. } => "audio_metadata",
+            OutputSlot::Enrichment => "enrichment",
+        }
+    }
+}
+
+/// JSON-schema description of a function the model may call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolSpec {
+    pub name: String,
+    pub description: String,
+    /// JSON schema for the function's arguments object.
+    pub parameters: Value,
+}
+
+/// A named post-transcription action.
+#[derive(Clone)]
+pub struct ActionDefinition {
+    pub id: String,
+    pub name: String,
+    /// Prompt template; `{transcript}` is replaced with the transcript text.
+    pub prompt_template: String,
+    pub slot: OutputSlot,
+    /// Tools this action exposes to the model.
+    pub tools: Vec<ToolSpec>,
+}
+
+impl ActionDefinition {
+    pub fn render_prompt(&self, transcript: &str) -> String {
+        self.prompt_template.replace("{transcript}", transcript)
+    }
+}
+
+/// A function call requested by the model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionCall {
+    pub id: String,
+    pub name: String,
+    pub arguments: Value,
+}
+
+/// One turn of the provider's response: either a final answer or a batch of
+/// function calls to execute before continuing.
+#[derive(Debug, Clone)]
+pub enum ProviderTurn {
+    Final(String),
+    Calls(Vec<FunctionCall>),
+}
+
+/// A message exchanged with the provider during the tool-calling loop.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Message {
+    pub role: String,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+}
+
+impl Message {
+    pub fn user(content: impl Into<String>) -> Self {
+        Self { role: "user".into(), content: content.into(), tool_call_id: None }
+    }
+
+    pub fn assistant(content: impl Into<String>) -> Self {
+        Self { role: "assistant".into(), content: content.into(), tool_call_id: None }
+    }
+
+    pub fn tool(call_id: impl Into<String>, content: impl Into<String>) -> Self {
+        Self { role: "tool".into(), content: content.into(), tool_call_id: Some(call_id.into()) }
+    }
+}
+
+/// A pluggable LLM backend — a local HTTP endpoint or a remote API.
+#[async_trait]
+pub trait ActionProvider: Send + Sync {
+    /// Short identifier recorded alongside enrichments (e.g. `"local-http"`).
+    fn name(&self) -> &str;
+
+    /// Model identifier, if the provider exposes one.
+    fn model(&self) -> Option<String> {
+        None
+    }
+
+    /// Run one completion turn given the conversation so far and the tools the
+    /// current action exposes.
+    async fn complete(&self, messages: &[Message], tools: &[ToolSpec]) -> Result<ProviderTurn>;
+}
+
+/// Handler invoked when the model calls a function.
+#[async_trait]
+pub trait ToolHandler: Send + Sync {
+    async fn call(&self, arguments: Value) -> Result<Value>;
+}
+
+/// Registry of actions, tool handlers, and the active provider.
+pub struct ActionRegistry {
+    provider: Arc<dyn ActionProvider>,
+    actions: HashMap<String, ActionDefinition>,
+    handlers: HashMap<String, Arc<dyn ToolHandler>>,
+    max_tool_iterations: usize,
+}
+
+/// Result of running a single action against a transcript.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionOutcome {
+    pub action_id: String,
+    pub action_name: String,
+    pub slot: String,
+    pub output_text: String,
+    pub provider: String,
+    pub model_used: Option<String>,
+    pub tool_calls: i32,
+    pub processing_time_ms: u64,
+}
+
+impl ActionRegistry {
+    pub fn new(provider: Arc<dyn ActionProvider>) -> Self {
+        Self {
+            provider,
+            actions: HashMap::new(),
+            handlers: HashMap::new(),
+            max_tool_iterations: 8,
+        }
+    }
+
+    pub fn register_action(&mut self, action: ActionDefinition) {
+        self.actions.insert(action.id.clone(), action);
+    }
+
+    pub fn register_handler(&mut self, name: impl Into<String>, handler: Arc<dyn ToolHandler>) {
+        self.handlers.insert(name.into(), handler);
+    }
+
+    pub fn get_action(&self, id: &str) -> Option<&ActionDefinition> {
+        self.actions.get(id)
+    }
+
+    pub fn action_ids(&self) -> Vec<String> {
+        self.actions.keys().cloned().collect()
+    }
+
+    /// Run a single action through the tool-calling loop and return its outcome.
+    pub async fn run_action(&self, action_id: &str, transcript: &str) -> Result<ActionOutcome> {
+        let action = self
+            .actions
+            .get(action_id)
+            .ok_or_else(|| anyhow!("Unknown action: {}", action_id))?;
+
+        let start = std::time::Instant::now();
+        let mut messages = vec![Message::user(action.render_prompt(transcript))];
+        let mut tool_calls = 0i32;
+
+        for iteration in 0..self.max_tool_iterations {
+            match self.provider.complete(&messages, &action.tools).await? {
+                ProviderTurn::Final(text) => {
+                    let processing_time_ms = start.elapsed().as_millis() as u64;
+                    debug(
+                        Component::Processing,
+                        &format!(
+                            "Action '{}' completed in {}ms ({} tool call(s))",
+                            action.name, processing_time_ms, tool_calls
+                        ),
+                    );
+                    return Ok(ActionOutcome {
+                        action_id: action.id.clone(),
+                        action_name: action.name.clone(),
+                        slot: action.slot.label().to_string(),
+                        output_text: text,
+                        provider: self.provider.name().to_string(),
+                        model_used: self.provider.model(),
+                        tool_calls,
+                        processing_time_ms,
+                    });
+                }
+                ProviderTurn::Calls(calls) => {
+                    if calls.is_empty() {
+                        return Err(anyhow!("Provider returned an empty tool-call batch"));
+                    }
+                    for call in calls {
+                        tool_calls += 1;
+                        debug(
+                            Component::Processing,
+                            &format!("Action '{}' calling tool '{}'", action.name, call.name),
+                        );
+                        let result = match self.handlers.get(&call.name) {
+                            Some(handler) => handler
+                                .call(call.arguments.clone())
+                                .await
+                                .unwrap_or_else(|e| json!({ "error": e.to_string() })),
+                            None => json!({ "error": format!("No handler for tool '{}'", call.name) }),
+                        };
+                        // Echo the assistant's call, then the tool result, so the
+                        // provider sees the full exchange on the next turn.
+                        messages.push(Message::assistant(
+                            json!({ "tool_call": { "id": call.id, "name": call.name, "arguments": call.arguments } })
+                                .to_string(),
+                        ));
+                        messages.push(Message::tool(call.id, result.to_string()));
+                    }
+                    debug(
+                        Component::Processing,
+                        &format!("Action '{}' tool iteration {} complete", action.name, iteration + 1),
+                    );
+                }
+            }
+        }
+
+        Err(anyhow!(
+            "Action '{}' exceeded max tool iterations ({})",
+            action.name,
+            self.max_tool_iterations
+        ))
+    }
+}
+
+/// HTTP-backed provider speaking an OpenAI-style chat-completions protocol.
+/// Works against a local endpoint (e.g. Ollama/llama.cpp server) or a remote
+/// API depending on the configured `base_url` and optional bearer token.
+pub struct HttpActionProvider {
+    client: reqwest::Client,
+    base_url: String,
+    model: String,
+    api_key: Option<String>,
+    name: String,
+}
+
+impl HttpActionProvider {
+    pub fn new(base_url: impl Into<String>, model: impl Into<String>, api_key: Option<String>) -> Self {
+        let base_url = base_url.into();
+        let name = if api_key.is_some() { "remote-api" } else { "local-http" };
+        Self {
+            client: reqwest::Client::new(),
+            base_url,
+            model: model.into(),
+            api_key,
+            name: name.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl ActionProvider for HttpActionProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn model(&self) -> Option<String> {
+        Some(self.model.clone())
+    }
+
+    async fn complete(&self, messages: &[Message], tools: &[ToolSpec]) -> Result<ProviderTurn> {
+        let tools_json: Vec<Value> = tools
+            .iter()
+            .map(|t| {
+                json!({
+                    "type": "function",
+                    "function": {
+                        "name": t.name,
+                        "description": t.description,
+                        "parameters": t.parameters,
+                    }
+                })
+            })
+            .collect();
+
+        let mut body = json!({
+            "model": self.model,
+            "messages": messages,
+        });
+        if !tools_json.is_empty() {
+            body["tools"] = Value::Array(tools_json);
+        }
+
+        let mut request = self
+            .client
+            .post(format!("{}/chat/completions", self.base_url.trim_end_matches('/')))
+            .json(&body);
+        if let Some(key) = &self.api_key {
+            request = request.bearer_auth(key);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| anyhow!("Provider request failed: {}", e))?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(anyhow!("Provider returned {}: {}", status, text));
+        }
+
+        let payload: Value = response
+            .json()
+            .await
+            .map_err(|e| anyhow!("Failed to parse provider response: {}", e))?;
+        let message = &payload["choices"][0]["message"];
+
+        if let Some(calls) = message["tool_calls"].as_array() {
+            if !calls.is_empty() {
+                let parsed = calls
+                    .iter()
+                    .filter_map(|c| {
+                        let args = c["function"]["arguments"]
+                            .as_str()
+                            .and_then(|s| serde_json::from_str(s).ok())
+                            .unwrap_or_else(|| c["function"]["arguments"].clone());
+                        Some(FunctionCall {
+                            id: c["id"].as_str()?.to_string(),
+                            name: c["function"]["name"].as_str()?.to_string(),
+                            arguments: args,
+                        })
+                    })
+                    .collect();
+                return Ok(ProviderTurn::Calls(parsed));
+            }
+        }
+
+        let content = message["content"].as_str().unwrap_or_default().to_string();
+        Ok(ProviderTurn::Final(content))
+    }
+}
+
+/// Built-in handler that records a reminder requested by the model.
+pub struct CreateReminderHandler;
+
+#[async_trait]
+impl ToolHandler for CreateReminderHandler {
+    async fn call(&self, arguments: Value) -> Result<Value> {
+        let title = arguments["title"].as_str().unwrap_or("Reminder");
+        info(Component::Processing, &format!("create_reminder: {}", title));
+        Ok(json!({ "status": "created", "title": title, "due": arguments.get("due") }))
+    }
+}
+
+/// Built-in handler that expands a named snippet.
+pub struct InsertSnippetHandler {
+    snippets: HashMap<String, String>,
+}
+
+impl InsertSnippetHandler {
+    pub fn new(snippets: HashMap<String, String>) -> Self {
+        Self { snippets }
+    }
+}
+
+#[async_trait]
+impl ToolHandler for InsertSnippetHandler {
+    async fn call(&self, arguments: Value) -> Result<Value> {
+        let name = arguments["name"].as_str().unwrap_or_default();
+        match self.snippets.get(name) {
+            Some(text) => Ok(json!({ "status": "ok", "text": text })),
+            None => Ok(json!({ "status": "not_found", "name": name })),
+        }
+    }
+}
+
+/// Run every registered action against a saved transcript, persisting each
+/// result and firing the existing webhook on completion. Spawns a detached
+/// background task so it never blocks transcription completion — mirroring the
+/// webhook delivery path.
+pub fn run_actions_async(
+    database: Arc<crate::db::Database>,
+    registry: Arc<ActionRegistry>,
+    transcript: crate::db::Transcript,
+) {
+    debug(
+        Component::Processing,
+        &format!("Scheduling post-transcription actions for transcript_id={}", transcript.id),
+    );
+
+    let task = tokio::spawn(async move {
+        for action_id in registry.action_ids() {
+            match registry.run_action(&action_id, &transcript.text).await {
+                Ok(outcome) => {
+                    if let Err(e) = persist_outcome(&database, transcript.id, &outcome).await {
+                        error(
+                            Component::Processing,
+                            &format!("Failed to persist action '{}' output: {}", action_id, e),
+                        );
+                    }
+                }
+                Err(e) => {
+                    error(
+                        Component::Processing,
+                        &format!("Action '{}' failed for transcript {}: {}", action_id, transcript.id, e),
+                    );
+                }
+            }
+        }
+
+        // Reuse the existing webhook delivery path to notify downstream
+        // consumers that enrichment is complete.
+        crate::webhooks::events::trigger_webhook_delivery_async(database, transcript);
+    });
+
+    std::mem::drop(task);
+}
+
+async fn persist_outcome(
+    database: &crate::db::Database,
+    transcript_id: i64,
+    outcome: &ActionOutcome,
+) -> Result<(), String> {
+    if outcome.slot == "audio_metadata" {
+        // Merge the result under the action's id without clobbering other
+        // metadata already stored on the transcript.
+        let existing = database.get_transcript(transcript_id).await?;
+        let mut metadata: Value = existing
+            .and_then(|t| t.metadata)
+            .and_then(|m| serde_json::from_str(&m).ok())
+            .unwrap_or_else(|| json!({}));
+        metadata[outcome.action_id.clone()] = json!(outcome.output_text);
+        database
+            .update_transcript_metadata(transcript_id, &metadata.to_string())
+            .await?;
+    }
+
+    database
+        .save_enrichment(
+            transcript_id,
+            &outcome.action_id,
+            &outcome.action_name,
+            &outcome.slot,
+            &outcome.output_text,
+            &outcome.provider,
+            outcome.model_used.as_deref(),
+            outcome.tool_calls,
+            outcome.processing_time_ms as i32,
+            None,
+        )
+        .await?;
+    Ok(())
+}
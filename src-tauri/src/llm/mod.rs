@@ -2,6 +2,7 @@ pub mod engine;
 pub mod models;
 pub mod prompts;
 pub mod pipeline;
+pub mod actions;
 
 use anyhow::Result;
 use std::path::Path;
@@ -7,6 +7,13 @@ extern "C" {
     fn enhance_text_sync(text: *const c_char) -> *const c_char;
     fn clean_speech_sync(text: *const c_char) -> *const c_char;
     fn summarize_text_sync(text: *const c_char, max_sentences: i32) -> *const c_char;
+    /// Runs guided/constrained generation against `schema_json` (a JSON
+    /// Schema) so the returned string is guaranteed to parse as JSON
+    /// matching it.
+    fn extract_structured_sync(text: *const c_char, schema_json: *const c_char) -> *const c_char;
+    /// Formats `text` as `document_type` (e.g. "meeting_minutes",
+    /// "action_items", "qa_transcript").
+    fn format_transcript_sync(text: *const c_char, document_type: *const c_char) -> *const c_char;
     fn free_foundation_models_string(ptr: *const c_char);
 }
 
@@ -111,4 +118,68 @@ impl FoundationModels {
             Err("Foundation Models not available on this platform".to_string())
         }
     }
+
+    /// Extract structured data from `text`, constrained to `schema_json` (a
+    /// JSON Schema document). The native bridge drives guided generation, so
+    /// a successful result is guaranteed to parse as JSON matching it.
+    pub fn extract_structured(text: &str, schema_json: &str) -> Result<String, String> {
+        #[cfg(target_os = "macos")]
+        {
+            let c_text = CString::new(text).map_err(|e| format!("Invalid text: {}", e))?;
+            let c_schema = CString::new(schema_json).map_err(|e| format!("Invalid schema: {}", e))?;
+
+            unsafe {
+                let result_ptr = extract_structured_sync(c_text.as_ptr(), c_schema.as_ptr());
+                if result_ptr.is_null() {
+                    return Err("Foundation Models structured extraction failed".to_string());
+                }
+
+                let result_cstr = CStr::from_ptr(result_ptr);
+                let result = result_cstr.to_str()
+                    .map_err(|e| format!("Invalid UTF-8: {}", e))?
+                    .to_string();
+
+                // Free the allocated string
+                free_foundation_models_string(result_ptr);
+
+                Ok(result)
+            }
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            Err("Foundation Models not available on this platform".to_string())
+        }
+    }
+
+    /// Format `text` as `document_type` (e.g. "meeting_minutes",
+    /// "action_items", "qa_transcript").
+    pub fn format_transcript(text: &str, document_type: &str) -> Result<String, String> {
+        #[cfg(target_os = "macos")]
+        {
+            let c_text = CString::new(text).map_err(|e| format!("Invalid text: {}", e))?;
+            let c_document_type = CString::new(document_type)
+                .map_err(|e| format!("Invalid document type: {}", e))?;
+
+            unsafe {
+                let result_ptr = format_transcript_sync(c_text.as_ptr(), c_document_type.as_ptr());
+                if result_ptr.is_null() {
+                    return Err("Foundation Models formatting failed".to_string());
+                }
+
+                let result_cstr = CStr::from_ptr(result_ptr);
+                let result = result_cstr.to_str()
+                    .map_err(|e| format!("Invalid UTF-8: {}", e))?
+                    .to_string();
+
+                // Free the allocated string
+                free_foundation_models_string(result_ptr);
+
+                Ok(result)
+            }
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            Err("Foundation Models not available on this platform".to_string())
+        }
+    }
 }
\ No newline at end of file
@@ -36,6 +36,15 @@ pub struct WhisperModel {
     #[serde(default)]
     pub coreml_downloaded: bool,
     pub active: bool,
+    /// Expected SHA-256 of `filename`. When present, a model counts as
+    /// `downloaded` only if the file on disk hashes to this value, so
+    /// truncated or corrupt downloads are re-fetched rather than trusted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_sha256: Option<String>,
+    /// Additional base-URLs (directories) to try, in order, when the primary
+    /// `url` fails. Each is joined with `filename` to form a full download URL.
+    #[serde(default)]
+    pub mirrors: Vec<String>,
 }
 
 impl WhisperModel {
@@ -55,6 +64,10 @@ impl WhisperModel {
                 downloaded: false,
                 coreml_downloaded: false,
                 active: false,
+                expected_sha256: None,
+                mirrors: vec![
+                    "https://hf-mirror.com/ggerganov/whisper.cpp/resolve/main".to_string(),
+                ],
             },
             WhisperModel {
                 id: "base.en".to_string(),
@@ -70,6 +83,10 @@ impl WhisperModel {
                 downloaded: false,
                 coreml_downloaded: false,
                 active: false,
+                expected_sha256: None,
+                mirrors: vec![
+                    "https://hf-mirror.com/ggerganov/whisper.cpp/resolve/main".to_string(),
+                ],
             },
             WhisperModel {
                 id: "small.en".to_string(),
@@ -85,6 +102,10 @@ impl WhisperModel {
                 downloaded: false,
                 coreml_downloaded: false,
                 active: false,
+                expected_sha256: None,
+                mirrors: vec![
+                    "https://hf-mirror.com/ggerganov/whisper.cpp/resolve/main".to_string(),
+                ],
             },
             WhisperModel {
                 id: "medium.en".to_string(),
@@ -100,6 +121,10 @@ impl WhisperModel {
                 downloaded: false,
                 coreml_downloaded: false,
                 active: false,
+                expected_sha256: None,
+                mirrors: vec![
+                    "https://hf-mirror.com/ggerganov/whisper.cpp/resolve/main".to_string(),
+                ],
             },
             WhisperModel {
                 id: "large-v3-turbo".to_string(),
@@ -115,6 +140,10 @@ impl WhisperModel {
                 downloaded: false,
                 coreml_downloaded: false,
                 active: false,
+                expected_sha256: None,
+                mirrors: vec![
+                    "https://hf-mirror.com/ggerganov/whisper.cpp/resolve/main".to_string(),
+                ],
             },
             WhisperModel {
                 id: "large-v3".to_string(),
@@ -130,6 +159,10 @@ impl WhisperModel {
                 downloaded: false,
                 coreml_downloaded: false,
                 active: false,
+                expected_sha256: None,
+                mirrors: vec![
+                    "https://hf-mirror.com/ggerganov/whisper.cpp/resolve/main".to_string(),
+                ],
             },
         ];
 
@@ -162,6 +195,8 @@ impl WhisperModel {
                             downloaded: true, // Already exists
                             coreml_downloaded: false,
                             active: false,
+                            expected_sha256: None,
+                            mirrors: Vec::new(),
                         });
                     }
                 }
@@ -174,8 +209,7 @@ impl WhisperModel {
         models
             .into_iter()
             .map(|mut model| {
-                let model_path = models_dir.join(&model.filename);
-                model.downloaded = model_path.exists();
+                model.downloaded = model.is_downloaded(models_dir);
 
                 // Check if Core ML model is downloaded (only on macOS)
                 #[cfg(target_os = "macos")]
@@ -218,4 +252,111 @@ impl WhisperModel {
         let fallback = models_dir.join("ggml-tiny.en.bin");
         fallback
     }
+
+    /// Whether the model's weights are present and trustworthy. When an
+    /// `expected_sha256` is known the file must both exist and hash to that
+    /// digest; otherwise a plain existence check is used.
+    pub fn is_downloaded(&self, models_dir: &Path) -> bool {
+        let model_path = models_dir.join(&self.filename);
+        if !model_path.exists() {
+            return false;
+        }
+        match &self.expected_sha256 {
+            Some(expected) => match sha256_file(&model_path) {
+                Ok(actual) => actual.eq_ignore_ascii_case(expected),
+                Err(_) => false,
+            },
+            None => true,
+        }
+    }
+
+    /// Full download URLs to try in order: the primary `url` first, then each
+    /// mirror base-URL joined with `filename`.
+    pub fn download_urls(&self) -> Vec<String> {
+        let mut urls = Vec::with_capacity(self.mirrors.len() + 1);
+        if !self.url.is_empty() {
+            urls.push(self.url.clone());
+        }
+        for base in &self.mirrors {
+            urls.push(format!("{}/{}", base.trim_end_matches('/'), self.filename));
+        }
+        urls
+    }
+}
+
+/// Download `model` into `models_dir`, trying each mirror in order and
+/// resuming/verifying via [`crate::services::downloads::download_file_with_progress`].
+///
+/// A `model-download-mirror` event is emitted before each attempt so the UI
+/// can show which source is being used; on success the verified file is left
+/// in place. Returns an error only when every mirror has been exhausted.
+pub async fn download_model_with_mirrors(
+    app: &tauri::AppHandle,
+    model: &WhisperModel,
+    models_dir: &Path,
+) -> Result<(), String> {
+    use tauri::Emitter;
+
+    let dest_path = models_dir.join(&model.filename);
+    let urls = model.download_urls();
+    if urls.is_empty() {
+        return Err(format!("No download URL available for model {}", model.id));
+    }
+
+    let total = urls.len();
+    let mut last_err = String::new();
+    for (index, url) in urls.iter().enumerate() {
+        app.emit(
+            "model-download-mirror",
+            serde_json::json!({
+                "modelId": model.id,
+                "url": url,
+                "mirror": index + 1,
+                "mirrorCount": total,
+            }),
+        )
+        .ok();
+
+        match crate::services::downloads::download_file_with_progress(
+            app,
+            url,
+            &dest_path,
+            "model",
+            model.expected_sha256.as_deref(),
+        )
+        .await
+        {
+            Ok(()) => return Ok(()),
+            Err(e) => {
+                log::warn!(
+                    "Model {} download from {} failed ({}), trying next mirror ({}/{})",
+                    model.id, url, e, index + 1, total
+                );
+                last_err = e;
+            }
+        }
+    }
+
+    Err(format!(
+        "All {} sources failed for model {}: {}",
+        total, model.id, last_err
+    ))
+}
+
+/// Compute the hex-encoded SHA-256 of a file, streaming it in fixed-size chunks.
+fn sha256_file(path: &Path) -> std::io::Result<String> {
+    use sha2::{Digest, Sha256};
+    use std::io::Read;
+
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 8192];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
 }
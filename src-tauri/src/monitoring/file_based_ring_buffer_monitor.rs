@@ -1,11 +1,48 @@
 use crate::logger::{debug, error, info, warn, Component};
+use crate::performance_logger::PerformanceLogger;
 use crate::transcription::file_based_ring_buffer_transcriber::FileBasedRingBufferTranscriber;
+use crate::transcription::silero_vad::SileroVad;
+use std::collections::VecDeque;
 use std::path::PathBuf;
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
 use tokio::sync::mpsc;
 use tokio::time;
 
+/// How many of the most recent chunks' CPU utilization feed the "sustained
+/// near real-time" check - short enough to react within a few chunks, long
+/// enough that one unusually fast/slow chunk doesn't flip the warning.
+const CPU_UTILIZATION_WINDOW: usize = 5;
+/// Minimum window fill before the sustained-utilization check engages, so a
+/// single early chunk can't trigger it.
+const CPU_UTILIZATION_MIN_SAMPLES: usize = 3;
+/// Parked percentage below which the worker is considered to be sustained
+/// near real-time limits (i.e. CPU utilization sustained above 80%).
+const SUSTAINED_LOW_PARKED_PCT: f64 = 20.0;
+
+/// Minimum speech probability (from the Silero VAD) for a frame to count as
+/// speech rather than silence.
+const SPEECH_PROBABILITY_THRESHOLD: f32 = 0.5;
+
+/// VAD-driven alternative to the fixed `chunk_interval` timer: cuts chunks at
+/// natural pauses in speech instead of an arbitrary fixed duration.
+struct VadSegmentation {
+    vad: SileroVad,
+    /// Duration of one `chunk_size`-sample frame at `sample_rate`.
+    frame_duration: Duration,
+    min_silence_ms: u32,
+    max_chunk_ms: u32,
+    /// How much of the unflushed audio (past the transcriber's current
+    /// position) has already been scored by the VAD.
+    scanned: Duration,
+    /// Length of the in-progress speech segment, from the first speech frame.
+    segment_len: Duration,
+    /// Whether the in-progress segment has seen any speech yet.
+    speech_started: bool,
+    /// Length of the current run of consecutive silence frames.
+    silence_run: Duration,
+}
+
 /// File-based ring buffer monitor that reads chunks from a growing WAV file
 /// This provides clean separation between recording and transcription
 pub struct FileBasedRingBufferMonitor {
@@ -19,6 +56,32 @@ pub struct FileBasedRingBufferMonitor {
     completed_chunks: Vec<String>,
     /// App handle for emitting events
     app_handle: Option<AppHandle>,
+    /// VAD-driven segmentation, when enabled via `with_vad`
+    vad: Option<VadSegmentation>,
+    /// When the worker last finished actively decoding a chunk - the start
+    /// point for measuring how long it then sat parked waiting for more
+    /// audio before the next chunk was ready.
+    last_active_end: Instant,
+    /// CPU utilization percentage (active decode time / total chunk
+    /// interval) of the last [`CPU_UTILIZATION_WINDOW`] processed chunks,
+    /// used to detect sustained near-real-time load.
+    recent_cpu_utilization_pct: VecDeque<f64>,
+    /// Running sum/count of every processed chunk's CPU utilization
+    /// percentage, for the recording-wide average returned by
+    /// [`Self::recording_complete`].
+    cpu_utilization_sum: f64,
+    cpu_utilization_count: u32,
+}
+
+/// Outcome of [`FileBasedRingBufferMonitor::recording_complete`]: the
+/// collected chunk transcripts plus how much of the worker's time was spent
+/// actively decoding versus parked waiting for new audio.
+pub struct FileBasedMonitorResult {
+    pub chunks: Vec<String>,
+    /// Average CPU utilization percentage across all chunks processed this
+    /// recording, or `None` if no chunk was timed (e.g. VAD-driven runs,
+    /// which don't go through the fixed-interval timing path).
+    pub avg_cpu_utilization_pct: Option<f64>,
 }
 
 impl FileBasedRingBufferMonitor {
@@ -30,6 +93,11 @@ impl FileBasedRingBufferMonitor {
             recording_start_time: Instant::now(),
             completed_chunks: Vec::new(),
             app_handle: None,
+            vad: None,
+            last_active_end: Instant::now(),
+            recent_cpu_utilization_pct: VecDeque::with_capacity(CPU_UTILIZATION_WINDOW),
+            cpu_utilization_sum: 0.0,
+            cpu_utilization_count: 0,
         }
     }
 
@@ -39,6 +107,173 @@ impl FileBasedRingBufferMonitor {
         self
     }
 
+    /// Switch from the fixed timer to VAD-driven chunk boundaries: feed
+    /// `chunk_size`-sample frames at `sample_rate` to a Silero VAD and
+    /// accumulate a speech segment until a run of silence longer than
+    /// `min_silence_ms` is observed, or the segment hits `max_chunk_ms`, then
+    /// flush exactly that span. Falls back to the fixed timer if not called.
+    pub fn with_vad(
+        mut self,
+        chunk_size: usize,
+        sample_rate: u32,
+        min_silence_ms: u32,
+        max_chunk_ms: u32,
+    ) -> Result<Self, String> {
+        let models_dir = dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("scout")
+            .join("models");
+        let model_path = SileroVad::default_model_path(&models_dir);
+        let vad = SileroVad::new(&model_path, chunk_size, sample_rate)?;
+        let frame_duration = Duration::from_secs_f64(chunk_size as f64 / sample_rate as f64);
+
+        self.vad = Some(VadSegmentation {
+            vad,
+            frame_duration,
+            min_silence_ms,
+            max_chunk_ms,
+            scanned: Duration::ZERO,
+            segment_len: Duration::ZERO,
+            speech_started: false,
+            silence_run: Duration::ZERO,
+        });
+
+        Ok(self)
+    }
+
+    /// Score the next available frame(s) against the Silero VAD and, once a
+    /// speech segment's boundary is found (a long enough run of trailing
+    /// silence, or the `max_chunk_ms` cap), flush exactly that span to the
+    /// transcriber. Returns `Ok(None)` when there isn't enough new audio yet
+    /// for another frame.
+    async fn next_vad_chunk(&mut self) -> Result<Option<String>, String> {
+        loop {
+            let (frame_duration, chunk_size, scanned) = match &self.vad {
+                Some(vad_state) => (vad_state.frame_duration, vad_state.vad.chunk_size(), vad_state.scanned),
+                None => return Ok(None),
+            };
+
+            let transcriber = match self.transcriber.as_ref() {
+                Some(t) => t,
+                None => return Ok(None),
+            };
+
+            if transcriber.unprocessed_duration()? < scanned + frame_duration {
+                // Not enough new audio for another frame yet
+                return Ok(None);
+            }
+
+            let mut frame = transcriber.peek_samples_at(scanned, frame_duration)?;
+            if frame.len() < chunk_size {
+                // The WAV file hasn't caught up to this span yet
+                return Ok(None);
+            }
+            frame.truncate(chunk_size);
+
+            let vad_state = self.vad.as_mut().unwrap();
+            let speech_prob = vad_state.vad.process(&frame)?;
+
+            vad_state.scanned += frame_duration;
+            vad_state.segment_len += frame_duration;
+
+            if speech_prob >= SPEECH_PROBABILITY_THRESHOLD {
+                vad_state.speech_started = true;
+                vad_state.silence_run = Duration::ZERO;
+            } else if vad_state.speech_started {
+                vad_state.silence_run += frame_duration;
+            }
+
+            let hit_silence =
+                vad_state.speech_started && vad_state.silence_run.as_millis() as u32 >= vad_state.min_silence_ms;
+            let hit_cap = vad_state.segment_len.as_millis() as u32 >= vad_state.max_chunk_ms;
+
+            if !hit_silence && !hit_cap {
+                continue;
+            }
+
+            let segment_len = vad_state.segment_len;
+            vad_state.scanned = Duration::ZERO;
+            vad_state.segment_len = Duration::ZERO;
+            vad_state.speech_started = false;
+            vad_state.silence_run = Duration::ZERO;
+
+            let transcriber = self.transcriber.as_mut().unwrap();
+            match transcriber.process_span(segment_len).await? {
+                Some(text) if !text.is_empty() => return Ok(Some(text)),
+                _ => continue,
+            }
+        }
+    }
+
+    /// Record a completed chunk and emit a `transcription-chunk` event for
+    /// the frontend, shared by both the fixed-timer and VAD-driven paths.
+    fn emit_chunk(&mut self, text: String) {
+        info(
+            Component::RingBuffer,
+            &format!("File-based chunk completed: \"{}\"", text),
+        );
+        self.completed_chunks.push(text.clone());
+
+        if let Some(ref app) = self.app_handle {
+            let chunk_data = serde_json::json!({
+                "id": self.completed_chunks.len() - 1,
+                "text": text,
+                "timestamp": chrono::Utc::now().timestamp_millis(),
+                "isPartial": false
+            });
+            if let Err(e) = app.emit("transcription-chunk", &chunk_data) {
+                warn(
+                    Component::RingBuffer,
+                    &format!("Failed to emit transcription chunk: {}", e),
+                );
+            } else {
+                debug(
+                    Component::RingBuffer,
+                    &format!("Emitted file-based transcription chunk"),
+                );
+            }
+        }
+    }
+
+    /// Record one fixed-timer chunk's CPU utilization (`active` decode time
+    /// as a percentage of `parked + active`), log it via
+    /// [`PerformanceLogger::log_chunk_progress`], and warn if utilization
+    /// has stayed high (parked percentage under [`SUSTAINED_LOW_PARKED_PCT`])
+    /// across the last [`CPU_UTILIZATION_WINDOW`] chunks.
+    fn record_chunk_cpu_utilization(&mut self, chunk_id: usize, parked: Duration, active: Duration, text: &str) {
+        let total = parked + active;
+        if total.is_zero() {
+            return;
+        }
+        let cpu_utilization_pct = active.as_secs_f64() / total.as_secs_f64() * 100.0;
+
+        PerformanceLogger::log_chunk_progress(chunk_id, total, active, text, cpu_utilization_pct);
+
+        self.cpu_utilization_sum += cpu_utilization_pct;
+        self.cpu_utilization_count += 1;
+
+        if self.recent_cpu_utilization_pct.len() == CPU_UTILIZATION_WINDOW {
+            self.recent_cpu_utilization_pct.pop_front();
+        }
+        self.recent_cpu_utilization_pct.push_back(cpu_utilization_pct);
+
+        if self.recent_cpu_utilization_pct.len() >= CPU_UTILIZATION_MIN_SAMPLES {
+            let avg_utilization_pct = self.recent_cpu_utilization_pct.iter().sum::<f64>()
+                / self.recent_cpu_utilization_pct.len() as f64;
+            let avg_parked_pct = 100.0 - avg_utilization_pct;
+            if avg_parked_pct < SUSTAINED_LOW_PARKED_PCT {
+                warn(
+                    Component::RingBuffer,
+                    &format!(
+                        "Sustained low parked time ({:.1}% over last {} chunks) - current strategy/model is near real-time limits and likely to fall behind on faster speech or slower machines",
+                        avg_parked_pct,
+                        self.recent_cpu_utilization_pct.len()
+                    ),
+                );
+            }
+        }
+    }
+
     /// Start monitoring the WAV file with the given transcriber
     pub async fn start_monitoring(
         mut self,
@@ -71,37 +306,39 @@ impl FileBasedRingBufferMonitor {
                     continue;
                 }
 
-                // Process next chunk if available
-                if let Some(ref mut transcriber) = self.transcriber {
-                    match transcriber.process_next_chunk().await {
-                        Ok(Some(text)) => {
-                            if !text.is_empty() {
-                                info(
+                // Process next chunk if available, either on the fixed timer
+                // or, when VAD segmentation is enabled, at natural pauses
+                // in speech.
+                if self.vad.is_some() {
+                    loop {
+                        match self.next_vad_chunk().await {
+                            Ok(Some(text)) => self.emit_chunk(text),
+                            Ok(None) => break,
+                            Err(e) => {
+                                error(
                                     Component::RingBuffer,
-                                    &format!("File-based chunk completed: \"{}\"", text),
+                                    &format!("Failed to process VAD-driven chunk: {}", e),
                                 );
-                                self.completed_chunks.push(text.clone());
-
-                                // Emit real-time transcription chunk event
-                                if let Some(ref app) = self.app_handle {
-                                    let chunk_data = serde_json::json!({
-                                        "id": self.completed_chunks.len() - 1,
-                                        "text": text,
-                                        "timestamp": chrono::Utc::now().timestamp_millis(),
-                                        "isPartial": false
-                                    });
-                                    if let Err(e) = app.emit("transcription-chunk", &chunk_data) {
-                                        warn(
-                                            Component::RingBuffer,
-                                            &format!("Failed to emit transcription chunk: {}", e),
-                                        );
-                                    } else {
-                                        debug(
-                                            Component::RingBuffer,
-                                            &format!("Emitted file-based transcription chunk"),
-                                        );
-                                    }
-                                }
+                                break;
+                            }
+                        }
+                    }
+                } else if let Some(ref mut transcriber) = self.transcriber {
+                    // Parked time is everything since the worker finished
+                    // its last active decode; active time is this
+                    // `process_next_chunk` call itself.
+                    let parked = self.last_active_end.elapsed();
+                    let decode_start = Instant::now();
+                    let result = transcriber.process_next_chunk().await;
+                    let active = decode_start.elapsed();
+                    self.last_active_end = Instant::now();
+
+                    match result {
+                        Ok(Some(text)) => {
+                            if !text.is_empty() {
+                                let chunk_id = self.completed_chunks.len();
+                                self.emit_chunk(text.clone());
+                                self.record_chunk_cpu_utilization(chunk_id, parked, active, &text);
                             }
                         }
                         Ok(None) => {
@@ -138,7 +375,7 @@ impl FileBasedRingBufferMonitor {
     }
 
     /// Signal that recording is complete and collect all results
-    pub async fn recording_complete(mut self) -> Result<Vec<String>, String> {
+    pub async fn recording_complete(mut self) -> Result<FileBasedMonitorResult, String> {
         info(
             Component::RingBuffer,
             "File-based recording complete, processing final chunk...",
@@ -181,7 +418,16 @@ impl FileBasedRingBufferMonitor {
             debug(Component::RingBuffer, &format!("File chunk {}: {}", i, chunk));
         }
 
-        Ok(self.completed_chunks)
+        let avg_cpu_utilization_pct = if self.cpu_utilization_count > 0 {
+            Some(self.cpu_utilization_sum / self.cpu_utilization_count as f64)
+        } else {
+            None
+        };
+
+        Ok(FileBasedMonitorResult {
+            chunks: self.completed_chunks,
+            avg_cpu_utilization_pct,
+        })
     }
 }
 
@@ -202,6 +448,7 @@ mod tests {
         assert_eq!(monitor.completed_chunks.len(), 0);
         assert!(monitor.transcriber.is_none());
         assert!(monitor.app_handle.is_none());
+        assert!(monitor.vad.is_none());
     }
 
     #[test]
@@ -229,9 +476,10 @@ mod tests {
         // Test completing recording with no transcriber
         let result = monitor.recording_complete().await;
         assert!(result.is_ok());
-        
-        let chunks = result.unwrap();
-        assert_eq!(chunks.len(), 0);
+
+        let result = result.unwrap();
+        assert_eq!(result.chunks.len(), 0);
+        assert_eq!(result.avg_cpu_utilization_pct, None);
     }
 
     #[test]
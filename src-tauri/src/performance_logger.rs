@@ -42,7 +42,12 @@ impl PerformanceLogger {
                     Some(transcription_result.processing_time_ms / transcription_result.chunks_processed as u64)
                 } else {
                     None
-                }
+                },
+                "partial_update_count": transcription_result.chunks_processed,
+                "time_to_first_partial_ms": transcription_result.time_to_first_partial_ms,
+                "stabilization_latency_ms": transcription_result.stabilization_latency_ms,
+                "streaming_latency_ms": transcription_result.streaming_latency_ms,
+                "avg_cpu_utilization_pct": transcription_result.avg_cpu_utilization_pct,
             }
         });
 
@@ -158,6 +163,49 @@ impl PerformanceLogger {
                         "SUGGESTION: Short recording - classic strategy might be more efficient",
                     );
                 }
+                if let Some(avg_cpu_utilization_pct) = transcription_result.avg_cpu_utilization_pct {
+                    info(
+                        Component::Transcription,
+                        &format!("Average CPU Utilization: {:.1}%", avg_cpu_utilization_pct),
+                    );
+                    if 100.0 - avg_cpu_utilization_pct < 20.0 {
+                        warn(
+                            Component::Transcription,
+                            "Sustained low parked time - current strategy/model is near real-time limits",
+                        );
+                    }
+                }
+            }
+            "streaming" => {
+                info(
+                    Component::Transcription,
+                    "Streaming Strategy: timestamp-stabilized partial results",
+                );
+                info(
+                    Component::Transcription,
+                    &format!(
+                        "Partial Updates: {}",
+                        transcription_result.chunks_processed
+                    ),
+                );
+                if let Some(ttfp_ms) = transcription_result.time_to_first_partial_ms {
+                    info(
+                        Component::Transcription,
+                        &format!("Time To First Partial: {}ms", ttfp_ms),
+                    );
+                }
+                if let Some(stabilization_ms) = transcription_result.stabilization_latency_ms {
+                    info(
+                        Component::Transcription,
+                        &format!("Final Stabilization Latency: {}ms", stabilization_ms),
+                    );
+                }
+                if let Some(latency_ms) = transcription_result.streaming_latency_ms {
+                    info(
+                        Component::Transcription,
+                        &format!("Configured Target Latency: {}ms", latency_ms),
+                    );
+                }
             }
             strategy => {
                 info(Component::Transcription, &format!("Strategy: {}", strategy));
@@ -256,20 +304,27 @@ impl PerformanceLogger {
         );
     }
 
-    /// Log real-time performance during chunked transcription
+    /// Log real-time performance during chunked transcription, including
+    /// the worker's CPU utilization for this chunk - the fraction of
+    /// `chunk_duration` spent actively decoding (`processing_time`) rather
+    /// than parked waiting for new audio. A low utilization means the
+    /// worker had headroom to spare; a utilization close to 100% means it
+    /// was busy almost the entire interval.
     pub fn log_chunk_progress(
         chunk_id: usize,
         chunk_duration: Duration,
         processing_time: Duration,
         current_text: &str,
+        cpu_utilization_pct: f64,
     ) {
         let efficiency = chunk_duration.as_secs_f64() / processing_time.as_secs_f64();
 
-        info(Component::RingBuffer, &format!("Chunk {} | Duration: {:.1}s | Processed: {:.2}s | Efficiency: {:.2}x | Text: \"{}...\"",
+        info(Component::RingBuffer, &format!("Chunk {} | Duration: {:.1}s | Processed: {:.2}s | Efficiency: {:.2}x | CPU: {:.1}% | Text: \"{}...\"",
                  chunk_id,
                  chunk_duration.as_secs_f64(),
                  processing_time.as_secs_f64(),
                  efficiency,
+                 cpu_utilization_pct,
                  current_text.chars().take(50).collect::<String>()
         ));
     }
@@ -1,15 +1,19 @@
 use std::sync::Arc;
 use tokio::time::{sleep, Duration};
 use crate::settings::SettingsManager;
-use crate::profanity_filter::ProfanityFilter;
 use crate::performance_metrics_service::{PerformanceMetricsService, TranscriptionPerformanceData};
 use crate::db::Database;
 use crate::logger::{info, error, debug, Component};
 use crate::llm::{CandleEngine, LLMEngine, GenerationOptions, ModelManager, PromptManager};
 use crate::llm::pipeline::LLMPipeline;
-use crate::dictionary_processor::DictionaryProcessor;
+use self::dictionary_processor::DictionaryProcessor;
+use self::vocabulary_filter::VocabularyFilter;
 use std::path::PathBuf;
 
+pub mod dictionary_processor;
+mod dictionary_matcher;
+pub mod vocabulary_filter;
+
 /// Post-processing hooks that run after successful transcription
 pub struct PostProcessingHooks {
     settings: Arc<tokio::sync::Mutex<SettingsManager>>,
@@ -39,7 +43,7 @@ impl PostProcessingHooks {
 
     /// Execute all post-processing hooks for a completed transcription
     /// Returns (filtered_transcript, original_transcript, analysis_logs)
-    pub async fn execute_hooks(&self, transcript: &str, source: &str, recording_duration_ms: Option<i32>, transcript_id: Option<i64>) -> (String, String, Vec<String>) {
+    pub async fn execute_hooks(&self, transcript: &str, source: &str, _recording_duration_ms: Option<i32>, transcript_id: Option<i64>) -> (String, String, Vec<String>) {
         info(Component::Processing, &format!("🎯 {} transcription successful - executing post-processing hooks", source));
         
         let original_transcript = transcript.to_string();
@@ -59,8 +63,8 @@ impl PostProcessingHooks {
             }
         };
         
-        // Execute profanity filtering on dictionary-processed transcript
-        let (filtered_transcript, analysis_logs) = self.execute_profanity_filter(&dict_processed_transcript, recording_duration_ms).await;
+        // Execute vocabulary filtering on dictionary-processed transcript
+        let (filtered_transcript, analysis_logs) = self.execute_vocabulary_filter(&dict_processed_transcript).await;
         
         // Execute auto-copy/paste hooks with filtered transcript
         self.execute_clipboard_hooks(&filtered_transcript).await;
@@ -74,43 +78,35 @@ impl PostProcessingHooks {
         (filtered_transcript, original_transcript, analysis_logs)
     }
 
-    /// Execute profanity filtering on the transcript
-    async fn execute_profanity_filter(&self, transcript: &str, recording_duration_ms: Option<i32>) -> (String, Vec<String>) {
+    /// Execute vocabulary filtering (mask/remove/tag word lists, replacing
+    /// the old single profanity toggle) on the transcript.
+    async fn execute_vocabulary_filter(&self, transcript: &str) -> (String, Vec<String>) {
         let settings_guard = self.settings.lock().await;
-        let profanity_filter_enabled = settings_guard.get().ui.profanity_filter_enabled;
-        let profanity_filter_aggressive = settings_guard.get().ui.profanity_filter_aggressive;
+        let config = settings_guard.get().ui.vocabulary_filter.clone();
         drop(settings_guard);
-        
-        if !profanity_filter_enabled {
-            info(Component::Processing, "🔍 Profanity filter is disabled");
+
+        if !config.enabled {
+            info(Component::Processing, "🔍 Vocabulary filter is disabled");
             return (transcript.to_string(), vec![]);
         }
-        
-        info(Component::Processing, &format!("🔍 Profanity filter enabled (aggressive: {}) - scanning transcript", profanity_filter_aggressive));
-        
-        let filter = ProfanityFilter::new();
-        let result = filter.filter_transcript(transcript, recording_duration_ms);
-        
-        if result.profanity_detected {
-            if result.likely_hallucination {
-                info(Component::Processing, &format!("🚫 Filtered likely hallucination: {} items removed", result.flagged_words.len()));
-                // Keep detailed comparison in debug logs only
-                debug(Component::Processing, &format!(
-                    "Profanity filter details - Original: '{}' → Filtered: '{}' | Flagged: {:?}",
-                    transcript, result.filtered_text, result.flagged_words
-                ));
-            } else {
-                info(Component::Processing, &format!("✅ Preserved intentional profanity: {} items detected", result.flagged_words.len()));
-                if profanity_filter_aggressive {
-                    info(Component::Processing, "🚫 Aggressive filtering enabled - filtering anyway");
-                    return (result.filtered_text, result.analysis_logs);
-                }
-            }
-        } else {
-            info(Component::Processing, "✅ No profanity detected in transcript");
+
+        info(
+            Component::Processing,
+            &format!("🔍 Vocabulary filter enabled ({} list(s)) - scanning transcript", config.lists.len()),
+        );
+
+        let result = VocabularyFilter::new(config).apply(transcript);
+
+        if !result.matches.is_empty() {
+            let logs: Vec<String> = result
+                .matches
+                .iter()
+                .map(|m| format!("'{}' matched list '{}'", m.word, m.list_name))
+                .collect();
+            return (result.filtered_text, logs);
         }
-        
-        (result.filtered_text, result.analysis_logs)
+
+        (result.filtered_text, vec![])
     }
 
     /// Handle auto-copy and auto-paste functionality
@@ -0,0 +1,427 @@
+use aho_corasick::{AhoCorasick, MatchKind};
+
+use crate::db::{DictionaryEntry, DictionaryMatch};
+
+/// Single-pass dictionary matcher for `exact`/`word` entries, backed by an
+/// Aho-Corasick automaton so applying hundreds of entries to a transcript
+/// costs one scan instead of one pass per entry.
+///
+/// The automaton itself is built case-insensitively (so a single pass can
+/// cover both case-sensitive and case-insensitive entries); `is_case_sensitive`
+/// entries are re-checked against the literal matched bytes before being
+/// accepted, and `word`-type entries are checked for surrounding word
+/// boundaries. Overlap between candidates is resolved leftmost-longest via
+/// the automaton's own match kind.
+pub struct DictionaryMatcher {
+    automaton: AhoCorasick,
+    entries: Vec<DictionaryEntry>,
+}
+
+impl DictionaryMatcher {
+    /// Build a matcher from the entries returned by
+    /// `Database::get_enabled_dictionary_entries`, keeping only `exact` and
+    /// `word` match types. `phrase`/`regex` entries are left for the
+    /// existing per-entry handling; `phonetic` entries are matched
+    /// separately via [`apply_phonetic_corrections`].
+    pub fn build(entries: &[DictionaryEntry]) -> Option<Self> {
+        let candidates: Vec<DictionaryEntry> = entries
+            .iter()
+            .filter(|e| e.match_type == "exact" || e.match_type == "word")
+            .cloned()
+            .collect();
+
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let needles: Vec<&str> = candidates.iter().map(|e| e.original_text.as_str()).collect();
+        let automaton = AhoCorasick::builder()
+            .ascii_case_insensitive(true)
+            .match_kind(MatchKind::LeftmostLongest)
+            .build(&needles)
+            .ok()?;
+
+        Some(Self { automaton, entries: candidates })
+    }
+
+    /// Scan `text` once, apply every accepted match's replacement, and
+    /// return the rewritten text alongside the matches that were applied.
+    pub fn apply(&self, text: &str) -> (String, Vec<DictionaryMatch>) {
+        let mut result = String::with_capacity(text.len());
+        let mut matches = Vec::new();
+        let mut last_end = 0;
+
+        for m in self.automaton.find_iter(text) {
+            let start = m.start();
+            let end = m.end();
+            if start < last_end {
+                // A leftmost-longest match that starts inside a match we've
+                // already applied; the earlier one wins.
+                continue;
+            }
+
+            let entry = &self.entries[m.pattern().as_usize()];
+            let matched_text = &text[start..end];
+
+            if entry.is_case_sensitive && matched_text != entry.original_text {
+                continue;
+            }
+            if entry.match_type == "word" && !has_word_boundaries(text, start, end) {
+                continue;
+            }
+
+            result.push_str(&text[last_end..start]);
+            result.push_str(&entry.replacement_text);
+            matches.push(DictionaryMatch {
+                entry_id: entry.id,
+                matched_text: matched_text.to_string(),
+                replaced_with: entry.replacement_text.clone(),
+                position_start: start,
+                position_end: end,
+                similarity_score: None,
+            });
+
+            last_end = end;
+        }
+
+        result.push_str(&text[last_end..]);
+        (result, matches)
+    }
+}
+
+fn has_word_boundaries(text: &str, start: usize, end: usize) -> bool {
+    let before_ok = text[..start].chars().next_back().map_or(true, |c| !c.is_alphanumeric());
+    let after_ok = text[end..].chars().next().map_or(true, |c| !c.is_alphanumeric());
+    before_ok && after_ok
+}
+
+/// Correct homophones and misheard proper nouns that have no exact spelling
+/// match by comparing a phonetic code of each transcript token against the
+/// precomputed code of each `phonetic`-type entry's `phonetic_pattern`, so
+/// e.g. "Kubernetties" (misheard) and "Kubernetes" resolve to the same code
+/// even though they share no substring an Aho-Corasick pass would find.
+///
+/// Each entry picks its own coding scheme via `phonetic_algorithm`
+/// ("soundex", the default, or "metaphone"); this lets a future Double
+/// Metaphone implementation be slotted in as another named option without
+/// disturbing entries already tuned for one of the other two.
+pub fn apply_phonetic_corrections(text: &str, entries: &[DictionaryEntry]) -> (String, Vec<DictionaryMatch>) {
+    let phonetic_entries: Vec<(&DictionaryEntry, String)> = entries
+        .iter()
+        .filter(|e| e.match_type == "phonetic")
+        .filter_map(|e| {
+            e.phonetic_pattern.as_deref().map(|p| {
+                let code = match e.phonetic_algorithm.as_deref() {
+                    Some("metaphone") => metaphone(p),
+                    _ => soundex(p),
+                };
+                (e, code)
+            })
+        })
+        .collect();
+
+    if phonetic_entries.is_empty() {
+        return (text.to_string(), Vec::new());
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut matches = Vec::new();
+    let mut last_end = 0;
+
+    for (start, end) in token_spans(text) {
+        let token = &text[start..end];
+        let soundex_code = soundex(token);
+        let metaphone_code = metaphone(token);
+        if soundex_code.is_empty() && metaphone_code.is_empty() {
+            continue;
+        }
+
+        let found = phonetic_entries.iter().find(|(e, code)| {
+            let token_code = match e.phonetic_algorithm.as_deref() {
+                Some("metaphone") => &metaphone_code,
+                _ => &soundex_code,
+            };
+            *code == *token_code
+        });
+
+        if let Some((entry, _)) = found {
+            if entry.original_text.eq_ignore_ascii_case(token) {
+                continue; // Already spelled as the entry's own original text.
+            }
+
+            result.push_str(&text[last_end..start]);
+            result.push_str(&entry.replacement_text);
+            matches.push(DictionaryMatch {
+                entry_id: entry.id,
+                matched_text: token.to_string(),
+                replaced_with: entry.replacement_text.clone(),
+                position_start: start,
+                position_end: end,
+                similarity_score: None,
+            });
+            last_end = end;
+        }
+    }
+
+    result.push_str(&text[last_end..]);
+    (result, matches)
+}
+
+/// Default similarity threshold for `fuzzy` entries that don't set their own
+/// `min_similarity`.
+const DEFAULT_MIN_SIMILARITY: f64 = 0.85;
+
+/// Correct near-miss spellings (e.g. ASR mangling a proper noun) that are
+/// close to, but don't exactly or phonetically match, a `fuzzy`-type entry's
+/// `original_text`.
+///
+/// Scoring every token against every entry with Levenshtein distance would
+/// be wasteful, so each entry precomputes a "char bag": a bitmask of the
+/// distinct lowercased characters in its `original_text`. A token can only
+/// match if its own bag is a superset of the entry's bag, which is a cheap
+/// check (one AND) that rules out most token/entry pairs before the O(n*m)
+/// distance computation runs - the same prefilter fuzzy file-finders use.
+pub fn apply_fuzzy_corrections(text: &str, entries: &[DictionaryEntry]) -> (String, Vec<DictionaryMatch>) {
+    let fuzzy_entries: Vec<(&DictionaryEntry, u64, f64)> = entries
+        .iter()
+        .filter(|e| e.match_type == "fuzzy")
+        .map(|e| {
+            let bag = char_bag(&e.original_text);
+            let threshold = e.min_similarity.unwrap_or(DEFAULT_MIN_SIMILARITY);
+            (e, bag, threshold)
+        })
+        .collect();
+
+    if fuzzy_entries.is_empty() {
+        return (text.to_string(), Vec::new());
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut matches = Vec::new();
+    let mut last_end = 0;
+
+    for (start, end) in token_spans(text) {
+        let token = &text[start..end];
+        let token_bag = char_bag(token);
+
+        let mut best: Option<(&DictionaryEntry, f64)> = None;
+        for (entry, bag, threshold) in &fuzzy_entries {
+            if token_bag & bag != *bag {
+                continue; // Token is missing a character the pattern requires.
+            }
+            if entry.original_text.eq_ignore_ascii_case(token) {
+                continue; // Already spelled as the entry's own original text.
+            }
+
+            let similarity = normalized_similarity(token, &entry.original_text);
+            if similarity < *threshold {
+                continue;
+            }
+            if best.map_or(true, |(_, best_score)| similarity > best_score) {
+                best = Some((entry, similarity));
+            }
+        }
+
+        if let Some((entry, score)) = best {
+            result.push_str(&text[last_end..start]);
+            result.push_str(&entry.replacement_text);
+            matches.push(DictionaryMatch {
+                entry_id: entry.id,
+                matched_text: token.to_string(),
+                replaced_with: entry.replacement_text.clone(),
+                position_start: start,
+                position_end: end,
+                similarity_score: Some(score),
+            });
+            last_end = end;
+        }
+    }
+
+    result.push_str(&text[last_end..]);
+    (result, matches)
+}
+
+/// Bitmask of the distinct lowercased alphanumeric characters in `s`
+/// (a-z in bits 0-25, 0-9 in bits 26-35): the cheap prefilter for fuzzy
+/// matching. A token missing any character the pattern has is skipped
+/// without scoring, even when its edit distance alone would meet the
+/// threshold.
+fn char_bag(s: &str) -> u64 {
+    let mut bag = 0u64;
+    for c in s.chars() {
+        let c = c.to_ascii_lowercase();
+        if c.is_ascii_lowercase() {
+            bag |= 1u64 << (c as u8 - b'a') as u32;
+        } else if c.is_ascii_digit() {
+            bag |= 1u64 << (26 + (c as u8 - b'0') as u32);
+        }
+    }
+    bag
+}
+
+/// `1 - levenshtein(a, b) / max(len(a), len(b))`, compared char-wise (not
+/// byte-wise) so multi-byte UTF-8 doesn't inflate the distance.
+fn normalized_similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let max_len = a.len().max(b.len());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - (levenshtein(&a, &b) as f64 / max_len as f64)
+}
+
+/// Classic O(n*m) edit-distance dynamic program.
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// Byte ranges of alphanumeric "words" in `text`, in order.
+fn token_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start: Option<usize> = None;
+    for (i, c) in text.char_indices() {
+        if c.is_alphanumeric() {
+            start.get_or_insert(i);
+        } else if let Some(s) = start.take() {
+            spans.push((s, i));
+        }
+    }
+    if let Some(s) = start {
+        spans.push((s, text.len()));
+    }
+    spans
+}
+
+/// Classic Soundex: keep the uppercased first letter, map the remaining
+/// consonants to digits (b,f,p,v->1; c,g,j,k,q,s,x,z->2; d,t->3; l->4; m,n->5;
+/// r->6), drop vowels plus h/w/y, collapse consecutive identical digits
+/// (including across a dropped h/w so e.g. "Ashcraft" doesn't double-count
+/// the s/c pair), then truncate/zero-pad to `L###`.
+fn soundex(word: &str) -> String {
+    let letters: Vec<char> = word
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if letters.is_empty() {
+        return String::new();
+    }
+
+    let code_for = |c: char| -> Option<char> {
+        match c {
+            'B' | 'F' | 'P' | 'V' => Some('1'),
+            'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+            'D' | 'T' => Some('3'),
+            'L' => Some('4'),
+            'M' | 'N' => Some('5'),
+            'R' => Some('6'),
+            _ => None,
+        }
+    };
+
+    let first = letters[0];
+    let mut code = String::new();
+    code.push(first);
+    let mut last_code = code_for(first);
+
+    for &c in &letters[1..] {
+        match code_for(c) {
+            Some(d) => {
+                if Some(d) != last_code {
+                    code.push(d);
+                }
+                last_code = Some(d);
+            }
+            None if c == 'H' || c == 'W' => {
+                // Skip without resetting last_code, so a digit separated from
+                // an identical one only by h/w still collapses.
+            }
+            None => last_code = None, // Vowel (or Y): breaks the collapse run.
+        }
+
+        if code.len() >= 4 {
+            break;
+        }
+    }
+
+    while code.len() < 4 {
+        code.push('0');
+    }
+    code.truncate(4);
+    code
+}
+
+/// A simplified phonetic coding loosely modeled on Double Metaphone: common
+/// silent digraphs collapse to a single symbol and non-initial vowels are
+/// dropped, so homophones map to the same code without needing the full
+/// Double Metaphone rule set.
+fn metaphone(word: &str) -> String {
+    let letters: Vec<char> = word
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if letters.is_empty() {
+        return String::new();
+    }
+
+    let is_vowel = |c: char| matches!(c, 'A' | 'E' | 'I' | 'O' | 'U');
+    let mut code = String::new();
+    let mut i = 0;
+
+    while i < letters.len() {
+        if i > 0 && letters[i] == letters[i - 1] && letters[i] != 'C' {
+            i += 1;
+            continue;
+        }
+
+        let c = letters[i];
+        let next = letters.get(i + 1).copied();
+
+        match (c, next) {
+            ('T', Some('H')) => { code.push('0'); i += 2; continue; }
+            ('S', Some('H')) => { code.push('X'); i += 2; continue; }
+            ('C', Some('H')) => { code.push('X'); i += 2; continue; }
+            ('P', Some('H')) => { code.push('F'); i += 2; continue; }
+            ('W', Some('H')) => { code.push('W'); i += 2; continue; }
+            _ => {}
+        }
+
+        if is_vowel(c) {
+            if i == 0 {
+                code.push(c);
+            }
+            i += 1;
+            continue;
+        }
+
+        let mapped = match c {
+            'C' => if matches!(next, Some('I') | Some('E') | Some('Y')) { 'S' } else { 'K' },
+            'G' => if matches!(next, Some('I') | Some('E') | Some('Y')) { 'J' } else { 'K' },
+            'Q' => 'K',
+            'V' => 'F',
+            'X' => 'S',
+            'Z' => 'S',
+            'Y' | 'W' => { i += 1; continue; }
+            other => other,
+        };
+
+        code.push(mapped);
+        i += 1;
+    }
+
+    code
+}
@@ -1,14 +1,21 @@
+use super::dictionary_matcher::{self, DictionaryMatcher};
 use crate::db::{Database, DictionaryEntry, DictionaryMatch};
 use crate::logger::{debug, error, info, Component};
 use once_cell::sync::Lazy;
-use regex::Regex;
-use std::collections::HashMap;
+use regex::{Regex, RegexSet};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Cache compiled regex patterns for performance
 static REGEX_CACHE: Lazy<Arc<tokio::sync::Mutex<HashMap<String, Regex>>>> =
     Lazy::new(|| Arc::new(tokio::sync::Mutex::new(HashMap::new())));
 
+/// Cache compiled `RegexSet`s (one membership automaton over all enabled
+/// regex entries) keyed by the sorted ids of the entries it covers, so the
+/// set is rebuilt only when which regex entries are enabled actually changes.
+static REGEX_SET_CACHE: Lazy<Arc<tokio::sync::Mutex<HashMap<Vec<i64>, RegexSet>>>> =
+    Lazy::new(|| Arc::new(tokio::sync::Mutex::new(HashMap::new())));
+
 /// Dictionary processor for applying custom replacements to transcripts
 pub struct DictionaryProcessor {
     database: Arc<Database>,
@@ -42,16 +49,53 @@ impl DictionaryProcessor {
             ),
         );
 
-        let mut processed_text = transcript.to_string();
         let mut all_matches = Vec::new();
-        let mut offset_adjustment = 0i64;
 
-        // Sort entries by position to handle overlapping replacements correctly
-        // Process longer matches first to avoid partial replacements
-        let mut sorted_entries = entries;
+        // Exact/word entries are applied in a single Aho-Corasick pass
+        // instead of one regex pass per entry.
+        let (mut processed_text, mut offset_adjustment) = match DictionaryMatcher::build(&entries) {
+            Some(matcher) => {
+                let (new_text, matches) = matcher.apply(transcript);
+                let diff = new_text.len() as i64 - transcript.len() as i64;
+                all_matches.extend(matches);
+                (new_text, diff)
+            }
+            None => (transcript.to_string(), 0i64),
+        };
+
+        // Sort remaining (phrase/regex) entries by length to handle
+        // overlapping replacements correctly; process longer matches first
+        // to avoid partial replacements.
+        let mut sorted_entries: Vec<DictionaryEntry> = entries
+            .iter()
+            .filter(|e| e.match_type == "phrase" || e.match_type == "regex")
+            .cloned()
+            .collect();
         sorted_entries.sort_by(|a, b| b.original_text.len().cmp(&a.original_text.len()));
 
+        // Prefilter regex entries through a single RegexSet membership scan
+        // instead of running the full engine for every one of them: a
+        // pattern that can't match anywhere in the text is skipped entirely.
+        let regex_entries: Vec<&DictionaryEntry> =
+            entries.iter().filter(|e| e.match_type == "regex").collect();
+        let matched_regex_ids: HashSet<i64> = if regex_entries.is_empty() {
+            HashSet::new()
+        } else {
+            let regex_set = Self::get_or_build_regex_set(&regex_entries).await?;
+            let set_matches = regex_set.matches(&processed_text);
+            regex_entries
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| set_matches.matched(*i))
+                .map(|(_, e)| e.id)
+                .collect()
+        };
+
         for entry in sorted_entries {
+            if entry.match_type == "regex" && !matched_regex_ids.contains(&entry.id) {
+                continue;
+            }
+
             match self
                 .apply_dictionary_entry(&processed_text, &entry, offset_adjustment)
                 .await?
@@ -68,6 +112,23 @@ impl DictionaryProcessor {
             }
         }
 
+        // Phonetic fallback: catch homophones with no exact spelling match.
+        let (phon_text, mut phon_matches) =
+            dictionary_matcher::apply_phonetic_corrections(&processed_text, &entries);
+        if !phon_matches.is_empty() {
+            processed_text = phon_text;
+            all_matches.append(&mut phon_matches);
+        }
+
+        // Fuzzy fallback: catch near-miss spellings phonetic coding wouldn't
+        // consider alike (e.g. "Kubernetties" for "Kubernetes").
+        let (fuzzy_text, mut fuzzy_matches) =
+            dictionary_matcher::apply_fuzzy_corrections(&processed_text, &entries);
+        if !fuzzy_matches.is_empty() {
+            processed_text = fuzzy_text;
+            all_matches.append(&mut fuzzy_matches);
+        }
+
         // Save matches to database if transcript_id is provided
         if let Some(id) = transcript_id {
             if !all_matches.is_empty() {
@@ -97,8 +158,6 @@ impl DictionaryProcessor {
     ) -> Result<Option<(String, Vec<DictionaryMatch>)>, String> {
         let mut matches = Vec::new();
         let new_text = match entry.match_type.as_str() {
-            "exact" => self.apply_exact_match(text, entry, &mut matches)?,
-            "word" => self.apply_word_match(text, entry, &mut matches)?,
             "phrase" => self.apply_phrase_match(text, entry, &mut matches)?,
             "regex" => self.apply_regex_match(text, entry, &mut matches).await?,
             _ => {
@@ -123,102 +182,6 @@ impl DictionaryProcessor {
         Ok(Some((new_text, matches)))
     }
 
-    /// Apply exact string matching (case-sensitive or insensitive)
-    fn apply_exact_match(
-        &self,
-        text: &str,
-        entry: &DictionaryEntry,
-        matches: &mut Vec<DictionaryMatch>,
-    ) -> Result<String, String> {
-        let mut result = String::with_capacity(text.len());
-        let mut last_end = 0;
-
-        let search_text = if entry.is_case_sensitive {
-            text.to_string()
-        } else {
-            text.to_lowercase()
-        };
-
-        let search_pattern = if entry.is_case_sensitive {
-            entry.original_text.clone()
-        } else {
-            entry.original_text.to_lowercase()
-        };
-
-        let mut search_start = 0;
-        while let Some(pos) = search_text[search_start..].find(&search_pattern) {
-            let actual_pos = search_start + pos;
-
-            // Add text before the match
-            result.push_str(&text[last_end..actual_pos]);
-
-            // Add the replacement
-            result.push_str(&entry.replacement_text);
-
-            // Record the match
-            matches.push(DictionaryMatch {
-                entry_id: entry.id,
-                matched_text: text[actual_pos..actual_pos + entry.original_text.len()].to_string(),
-                replaced_with: entry.replacement_text.clone(),
-                position_start: actual_pos,
-                position_end: actual_pos + entry.original_text.len(),
-            });
-
-            last_end = actual_pos + entry.original_text.len();
-            search_start = last_end;
-        }
-
-        // Add remaining text
-        result.push_str(&text[last_end..]);
-
-        Ok(result)
-    }
-
-    /// Apply word boundary matching
-    fn apply_word_match(
-        &self,
-        text: &str,
-        entry: &DictionaryEntry,
-        matches: &mut Vec<DictionaryMatch>,
-    ) -> Result<String, String> {
-        // Build regex pattern with word boundaries
-        let pattern = if entry.is_case_sensitive {
-            format!(r"\b{}\b", regex::escape(&entry.original_text))
-        } else {
-            format!(r"(?i)\b{}\b", regex::escape(&entry.original_text))
-        };
-
-        let regex = Regex::new(&pattern)
-            .map_err(|e| format!("Failed to compile word match regex: {}", e))?;
-
-        let mut result = String::with_capacity(text.len());
-        let mut last_end = 0;
-
-        for mat in regex.find_iter(text) {
-            // Add text before the match
-            result.push_str(&text[last_end..mat.start()]);
-
-            // Add the replacement
-            result.push_str(&entry.replacement_text);
-
-            // Record the match
-            matches.push(DictionaryMatch {
-                entry_id: entry.id,
-                matched_text: mat.as_str().to_string(),
-                replaced_with: entry.replacement_text.clone(),
-                position_start: mat.start(),
-                position_end: mat.end(),
-            });
-
-            last_end = mat.end();
-        }
-
-        // Add remaining text
-        result.push_str(&text[last_end..]);
-
-        Ok(result)
-    }
-
     /// Apply phrase matching (considers surrounding context)
     fn apply_phrase_match(
         &self,
@@ -268,6 +231,7 @@ impl DictionaryProcessor {
                 replaced_with: entry.replacement_text.clone(),
                 position_start: phrase_match.start(),
                 position_end: phrase_match.end(),
+                similarity_score: None,
             });
 
             last_end = full_match.end();
@@ -279,6 +243,36 @@ impl DictionaryProcessor {
         Ok(result)
     }
 
+    /// Build (or reuse a cached) `RegexSet` covering all of the given regex
+    /// entries, keyed by their sorted ids so the automaton is rebuilt only
+    /// when the set of enabled regex entries changes.
+    async fn get_or_build_regex_set(entries: &[&DictionaryEntry]) -> Result<RegexSet, String> {
+        let mut ids: Vec<i64> = entries.iter().map(|e| e.id).collect();
+        ids.sort_unstable();
+
+        let mut cache = REGEX_SET_CACHE.lock().await;
+        if let Some(set) = cache.get(&ids) {
+            return Ok(set.clone());
+        }
+
+        let patterns: Vec<String> = entries
+            .iter()
+            .map(|e| {
+                if e.is_case_sensitive {
+                    e.original_text.clone()
+                } else {
+                    format!("(?i){}", e.original_text)
+                }
+            })
+            .collect();
+
+        let regex_set = RegexSet::new(&patterns)
+            .map_err(|e| format!("Failed to compile regex set: {}", e))?;
+
+        cache.insert(ids, regex_set.clone());
+        Ok(regex_set)
+    }
+
     /// Apply regex pattern matching
     async fn apply_regex_match(
         &self,
@@ -312,21 +306,29 @@ impl DictionaryProcessor {
 
         let mut result = String::with_capacity(text.len());
         let mut last_end = 0;
+        let mut expanded = String::new();
+
+        for caps in regex.captures_iter(text) {
+            let mat = caps.get(0).unwrap();
 
-        for mat in regex.find_iter(text) {
             // Add text before the match
             result.push_str(&text[last_end..mat.start()]);
 
-            // Add the replacement (could support capture groups in the future)
-            result.push_str(&entry.replacement_text);
+            // Expand $1, ${name}, $0, and the $$ literal-escape against this
+            // match's capture groups, so replacement_text can reorder or
+            // transform matched groups instead of always being inserted verbatim.
+            expanded.clear();
+            caps.expand(&entry.replacement_text, &mut expanded);
+            result.push_str(&expanded);
 
             // Record the match
             matches.push(DictionaryMatch {
                 entry_id: entry.id,
                 matched_text: mat.as_str().to_string(),
-                replaced_with: entry.replacement_text.clone(),
+                replaced_with: expanded.clone(),
                 position_start: mat.start(),
                 position_end: mat.end(),
+                similarity_score: None,
             });
 
             last_end = mat.end();
@@ -338,18 +340,6 @@ impl DictionaryProcessor {
         Ok(result)
     }
 
-    /// Apply phonetic matching using soundex or similar algorithm
-    /// This is a placeholder for future implementation
-    pub async fn apply_phonetic_corrections(
-        &self,
-        text: &str,
-        _entries: &[DictionaryEntry],
-    ) -> Result<String, String> {
-        // TODO: Implement phonetic matching algorithm
-        // This could use soundex, metaphone, or a custom algorithm
-        // For now, return the original text
-        Ok(text.to_string())
-    }
 }
 
 #[cfg(test)]
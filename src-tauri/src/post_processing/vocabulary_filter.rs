@@ -0,0 +1,259 @@
+use crate::logger::{debug, info, Component};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+
+/// Built-in profanity words, kept as the default `Profanity` list so existing
+/// behavior survives the move from the old boolean profanity filter to this
+/// more general vocabulary-filter subsystem.
+const DEFAULT_PROFANITY_WORDS: &[&str] = &[
+    "fuck", "fucking", "shit", "damn", "hell", "ass", "bitch", "bastard",
+];
+
+/// How a [`VocabularyList`]'s matched words are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VocabularyFilterMethod {
+    /// Replace the matched word with asterisks of equal length.
+    Mask,
+    /// Delete the matched word and collapse the surrounding whitespace.
+    Remove,
+    /// Wrap the matched word in `VocabularyFilterConfig::tag_template` for
+    /// downstream highlighting.
+    Tag,
+}
+
+/// A named word list and the method applied to anything in it that matches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VocabularyList {
+    pub name: String,
+    pub words: Vec<String>,
+    pub method: VocabularyFilterMethod,
+}
+
+/// Settings for [`VocabularyFilter`], surfaced under `UISettings`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VocabularyFilterConfig {
+    pub enabled: bool,
+    /// Template a matched word is substituted into under `Tag`; must contain
+    /// a single `{}` placeholder.
+    pub tag_template: String,
+    pub lists: Vec<VocabularyList>,
+}
+
+impl Default for VocabularyFilterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            tag_template: "[{}]".to_string(),
+            lists: vec![VocabularyList {
+                name: "Profanity".to_string(),
+                words: DEFAULT_PROFANITY_WORDS
+                    .iter()
+                    .map(|w| w.to_string())
+                    .collect(),
+                method: VocabularyFilterMethod::Mask,
+            }],
+        }
+    }
+}
+
+/// One matched word, recorded for logging/analysis.
+#[derive(Debug, Clone)]
+pub struct VocabularyMatch {
+    pub list_name: String,
+    pub word: String,
+}
+
+/// Result of running [`VocabularyFilter::apply`].
+#[derive(Debug, Clone)]
+pub struct VocabularyFilterResult {
+    pub filtered_text: String,
+    pub matches: Vec<VocabularyMatch>,
+}
+
+/// Applies named word lists to a transcript as a post-processing stage, each
+/// list replacing its whole-word, case-insensitive matches via its own
+/// `Mask`/`Remove`/`Tag` method. Modeled on AWS Transcribe's vocabulary
+/// filtering, this generalizes the old single boolean profanity filter so
+/// users can redact client names, PII, or custom jargon the same way.
+pub struct VocabularyFilter {
+    config: VocabularyFilterConfig,
+}
+
+impl VocabularyFilter {
+    pub fn new(config: VocabularyFilterConfig) -> Self {
+        Self { config }
+    }
+
+    /// Apply every configured list to `text` in order, returning the
+    /// resulting text and every match found. Returns `text` unchanged (with
+    /// no matches) when the filter is disabled.
+    pub fn apply(&self, text: &str) -> VocabularyFilterResult {
+        if !self.config.enabled || text.is_empty() {
+            return VocabularyFilterResult {
+                filtered_text: text.to_string(),
+                matches: Vec::new(),
+            };
+        }
+
+        let mut result = text.to_string();
+        let mut matches = Vec::new();
+        let mut removed_any = false;
+
+        for list in &self.config.lists {
+            let Some(pattern) = Self::build_pattern(&list.words) else {
+                continue;
+            };
+
+            result = pattern
+                .replace_all(&result, |caps: &regex::Captures| {
+                    let matched = caps.get(0).unwrap().as_str();
+                    matches.push(VocabularyMatch {
+                        list_name: list.name.clone(),
+                        word: matched.to_string(),
+                    });
+
+                    match list.method {
+                        VocabularyFilterMethod::Mask => "*".repeat(matched.chars().count()),
+                        VocabularyFilterMethod::Remove => {
+                            removed_any = true;
+                            String::new()
+                        }
+                        VocabularyFilterMethod::Tag => {
+                            self.config.tag_template.replacen("{}", matched, 1)
+                        }
+                    }
+                })
+                .into_owned();
+        }
+
+        if !matches.is_empty() {
+            info(
+                Component::Processing,
+                &format!("🔍 Vocabulary filter matched {} word(s)", matches.len()),
+            );
+            debug(
+                Component::Processing,
+                &format!("Vocabulary filter details - Original: '{}' -> Filtered: '{}'", text, result),
+            );
+        }
+
+        // `Remove` can leave doubled-up whitespace where a word used to sit;
+        // `Mask`/`Tag` preserve width, so only clean up when something was
+        // actually deleted.
+        let filtered_text = if removed_any {
+            result.split_whitespace().collect::<Vec<_>>().join(" ")
+        } else {
+            result
+        };
+
+        VocabularyFilterResult {
+            filtered_text,
+            matches,
+        }
+    }
+
+    /// Builds a single case-insensitive, word-boundary-respecting
+    /// alternation over `words`, so one pass of the regex engine handles an
+    /// entire list. Returns `None` for an empty or all-invalid word list.
+    fn build_pattern(words: &[String]) -> Option<Regex> {
+        let escaped: Vec<String> = words
+            .iter()
+            .filter(|w| !w.trim().is_empty())
+            .map(|w| regex::escape(w.trim()))
+            .collect();
+        if escaped.is_empty() {
+            return None;
+        }
+
+        let pattern = format!(r"\b(?:{})\b", escaped.join("|"));
+        RegexBuilder::new(&pattern)
+            .case_insensitive(true)
+            .build()
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_words_with_equal_length_asterisks() {
+        let config = VocabularyFilterConfig {
+            enabled: true,
+            tag_template: "[{}]".to_string(),
+            lists: vec![VocabularyList {
+                name: "Clients".to_string(),
+                words: vec!["acme".to_string()],
+                method: VocabularyFilterMethod::Mask,
+            }],
+        };
+
+        let result = VocabularyFilter::new(config).apply("Call Acme about the invoice");
+        assert_eq!(result.filtered_text, "Call **** about the invoice");
+        assert_eq!(result.matches.len(), 1);
+    }
+
+    #[test]
+    fn removes_words_and_collapses_whitespace() {
+        let config = VocabularyFilterConfig {
+            enabled: true,
+            tag_template: "[{}]".to_string(),
+            lists: vec![VocabularyList {
+                name: "Filler".to_string(),
+                words: vec!["um".to_string()],
+                method: VocabularyFilterMethod::Remove,
+            }],
+        };
+
+        let result = VocabularyFilter::new(config).apply("So um I think um that works");
+        assert_eq!(result.filtered_text, "So I think that works");
+    }
+
+    #[test]
+    fn tags_words_with_the_configured_template() {
+        let config = VocabularyFilterConfig {
+            enabled: true,
+            tag_template: "<<{}>>".to_string(),
+            lists: vec![VocabularyList {
+                name: "PII".to_string(),
+                words: vec!["jane doe".to_string()],
+                method: VocabularyFilterMethod::Tag,
+            }],
+        };
+
+        let result = VocabularyFilter::new(config).apply("My name is Jane Doe");
+        assert_eq!(result.filtered_text, "My name is <<Jane Doe>>");
+    }
+
+    #[test]
+    fn whole_word_matching_ignores_substrings() {
+        let config = VocabularyFilterConfig {
+            enabled: true,
+            tag_template: "[{}]".to_string(),
+            lists: vec![VocabularyList {
+                name: "Test".to_string(),
+                words: vec!["ass".to_string()],
+                method: VocabularyFilterMethod::Mask,
+            }],
+        };
+
+        let result = VocabularyFilter::new(config).apply("Let's assess the situation");
+        assert_eq!(result.filtered_text, "Let's assess the situation");
+        assert!(result.matches.is_empty());
+    }
+
+    #[test]
+    fn disabled_filter_leaves_text_untouched() {
+        let config = VocabularyFilterConfig {
+            enabled: false,
+            ..VocabularyFilterConfig::default()
+        };
+
+        let result = VocabularyFilter::new(config).apply("Oh fuck that hurts");
+        assert_eq!(result.filtered_text, "Oh fuck that hurts");
+        assert!(result.matches.is_empty());
+    }
+}
@@ -184,7 +184,7 @@ impl ProcessingQueue {
                                                     .map(|m| m.len() as i64)
                                                     .ok();
                                                 
-                                                // Execute post-processing hooks (profanity filter, auto-copy, auto-paste, etc.)
+                                                // Execute post-processing hooks (vocabulary filter, auto-copy, auto-paste, etc.)
                                                 let post_processing = crate::post_processing::PostProcessingHooks::new(settings.clone());
                                                 let (filtered_transcript, original_transcript, analysis_logs) = post_processing.execute_hooks(&transcript, "Processing Queue", Some(job.duration_ms)).await;
                                                 
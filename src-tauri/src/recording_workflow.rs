@@ -130,7 +130,19 @@ impl RecordingWorkflow {
                         let settings_guard = settings_for_iter.lock().await;
                         let current_settings = settings_guard.get().clone();
                         drop(settings_guard);
-                        
+
+                        // Apply any device/backend override from settings before
+                        // the stream is opened below.
+                        {
+                            let recorder = recorder.lock().await;
+                            if let Err(e) = recorder.set_custom_device_config(current_settings.audio.custom_device.clone()) {
+                                warn(Component::Recording, &format!("Failed to apply custom audio device config: {}", e));
+                            }
+                            if let Err(e) = recorder.set_vad_config(current_settings.audio.voice_activity.clone()) {
+                                warn(Component::Recording, &format!("Failed to apply voice activity config: {}", e));
+                            }
+                        }
+
                         // Initialize transcription context for real-time chunking
                         let transcription_context = match TranscriptionContext::new_from_db(
                             database_for_iter.clone(),
@@ -457,7 +469,7 @@ impl RecordingWorkflow {
                                             info(Component::Transcription, &format!("🚀 Fast transcription: {:.2}x speed", speed_ratio));
                                         }
                                         
-                                        // Execute post-processing hooks (profanity filter, auto-copy, auto-paste, etc.)
+                                        // Execute post-processing hooks (vocabulary filter, auto-copy, auto-paste, etc.)
                                         perf_tracker_clone.track_event("post_processing", "Starting post-processing hooks").await;
                                         let post_processing = crate::post_processing::PostProcessingHooks::new(settings_clone.clone(), database_clone.clone());
                                         let (filtered_transcript, original_transcript, analysis_logs) = post_processing.execute_hooks(&transcription_result.text, "Ring Buffer", Some(duration_ms), None).await;
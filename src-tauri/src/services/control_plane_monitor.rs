@@ -1,16 +1,20 @@
 /// Control plane monitor for receiving status updates from external services
 /// This module binds to port 5557 and receives status messages from the Python worker
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::sync::Mutex as StdMutex;
 use std::time::{Duration, Instant};
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 use serde::{Deserialize, Serialize};
-use zmq;
+use async_trait::async_trait;
 use anyhow::{Result, Context};
 
 /// Maximum number of status messages to keep in history
 const MAX_STATUS_HISTORY: usize = 100;
 
+/// Default endpoint the control plane binds to for worker status updates
+const DEFAULT_ENDPOINT: &str = "tcp://127.0.0.1:5557";
+
 /// Status message from the worker
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StatusMessage {
@@ -40,34 +44,167 @@ pub struct WorkerHealth {
     pub worker_id: Option<String>,
 }
 
+/// Source of raw status frames for the control plane.
+///
+/// Abstracting the transport lets `ControlPlaneMonitor` be exercised without a
+/// live ZMQ socket or a Python worker: tests drive a [`MockTransport`] backed by
+/// a channel, while production uses [`ZmqTransport`].
+#[async_trait]
+pub trait StatusTransport: Send + Sync {
+    /// Receive the next raw status frame. Blocks until a frame is available or
+    /// the transport is shut down (in which case it returns an error).
+    async fn recv(&self) -> Result<Vec<u8>>;
+
+    /// Tear the transport down so a pending `recv` returns and any bound
+    /// endpoint is released.
+    async fn shutdown(&self) -> Result<()>;
+}
+
+/// ZeroMQ PULL transport bound to the control-plane endpoint.
+pub struct ZmqTransport {
+    endpoint: String,
+    socket: Arc<StdMutex<zmq::Socket>>,
+}
+
+impl ZmqTransport {
+    /// Bind a PULL socket to `endpoint` and return a transport over it.
+    pub fn bind(endpoint: &str) -> Result<Self> {
+        let context = zmq::Context::new();
+        let socket = context
+            .socket(zmq::PULL)
+            .context("Failed to create PULL socket")?;
+        // Short receive timeout so the loop can observe shutdown promptly.
+        socket
+            .set_rcvtimeo(100)
+            .context("Failed to set receive timeout")?;
+        socket
+            .bind(endpoint)
+            .with_context(|| format!("Failed to bind to {}", endpoint))?;
+        log::info!("Control plane monitor bound to {}", endpoint);
+        Ok(Self {
+            endpoint: endpoint.to_string(),
+            socket: Arc::new(StdMutex::new(socket)),
+        })
+    }
+}
+
+#[async_trait]
+impl StatusTransport for ZmqTransport {
+    async fn recv(&self) -> Result<Vec<u8>> {
+        let socket = self.socket.clone();
+        // ZMQ recv is blocking, so keep it off the async runtime.
+        tokio::task::spawn_blocking(move || {
+            let socket = socket.lock().expect("status socket mutex poisoned");
+            socket.recv_bytes(0).map_err(anyhow::Error::from)
+        })
+        .await
+        .context("status recv task panicked")?
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        let socket = self.socket.lock().expect("status socket mutex poisoned");
+        if let Err(e) = socket.unbind(&self.endpoint) {
+            log::warn!("Failed to unbind control plane socket: {}", e);
+        }
+        Ok(())
+    }
+}
+
+/// In-memory transport backed by a channel, for deterministic tests.
+pub struct MockTransport {
+    rx: Mutex<tokio::sync::mpsc::UnboundedReceiver<Vec<u8>>>,
+}
+
+impl MockTransport {
+    /// Create a mock transport together with a sender used to push frames.
+    pub fn new() -> (Self, tokio::sync::mpsc::UnboundedSender<Vec<u8>>) {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        (
+            Self {
+                rx: Mutex::new(rx),
+            },
+            tx,
+        )
+    }
+}
+
+#[async_trait]
+impl StatusTransport for MockTransport {
+    async fn recv(&self) -> Result<Vec<u8>> {
+        let mut rx = self.rx.lock().await;
+        rx.recv().await.context("mock transport closed")
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
 /// Control plane monitor that receives and tracks worker status
 pub struct ControlPlaneMonitor {
+    transport: Arc<dyn StatusTransport>,
     latest_heartbeat: Arc<RwLock<Option<Instant>>>,
     status_history: Arc<RwLock<VecDeque<StatusMessage>>>,
-    worker_stats: Arc<RwLock<WorkerStats>>,
+    /// Per-worker statistics, keyed by worker id.
+    workers: Arc<RwLock<HashMap<String, WorkerStats>>>,
     running: Arc<RwLock<bool>>,
+    /// Sender used to signal the receive loop to stop immediately.
+    stop_tx: Mutex<Option<tokio::sync::mpsc::Sender<()>>>,
+    /// Handle of the running receive loop, awaited on `stop`.
+    handle: Mutex<Option<tokio::task::JoinHandle<()>>>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 struct WorkerStats {
     messages_processed: u64,
     errors: u64,
     uptime_seconds: Option<u64>,
     last_error: Option<String>,
-    worker_id: Option<String>,
+    last_heartbeat: Option<Instant>,
+}
+
+impl WorkerStats {
+    fn to_health(&self, worker_id: &str) -> WorkerHealth {
+        let (is_healthy, last_heartbeat_seconds_ago) = match self.last_heartbeat {
+            Some(hb) => {
+                let elapsed = hb.elapsed();
+                (elapsed < Duration::from_secs(60), Some(elapsed.as_secs()))
+            }
+            None => (false, None),
+        };
+        WorkerHealth {
+            is_healthy,
+            last_heartbeat_seconds_ago,
+            uptime_seconds: self.uptime_seconds,
+            messages_processed: self.messages_processed,
+            errors: self.errors,
+            last_error: self.last_error.clone(),
+            worker_id: Some(worker_id.to_string()),
+        }
+    }
 }
 
 impl ControlPlaneMonitor {
-    /// Create a new control plane monitor
+    /// Create a monitor over the default ZMQ transport.
     pub fn new() -> Result<Self> {
-        Ok(Self {
+        let transport = ZmqTransport::bind(DEFAULT_ENDPOINT)?;
+        Ok(Self::with_transport(Arc::new(transport)))
+    }
+
+    /// Create a monitor over any status transport. Tests pass a
+    /// [`MockTransport`] here to drive `process_message` deterministically.
+    pub fn with_transport(transport: Arc<dyn StatusTransport>) -> Self {
+        Self {
+            transport,
             latest_heartbeat: Arc::new(RwLock::new(None)),
             status_history: Arc::new(RwLock::new(VecDeque::with_capacity(MAX_STATUS_HISTORY))),
-            worker_stats: Arc::new(RwLock::new(WorkerStats::default())),
+            workers: Arc::new(RwLock::new(HashMap::new())),
             running: Arc::new(RwLock::new(false)),
-        })
+            stop_tx: Mutex::new(None),
+            handle: Mutex::new(None),
+        }
     }
-    
+
     /// Start monitoring for status messages
     pub async fn start_monitoring(self: Arc<Self>) {
         // Check if already running
@@ -78,181 +215,144 @@ impl ControlPlaneMonitor {
         }
         *running = true;
         drop(running);
-        
+
         log::info!("Starting control plane monitor");
-        
-        // Clone for the spawned task
+
+        // A one-slot channel we can select on, so `stop()` signals the loop
+        // immediately instead of waiting for the next poll tick.
+        let (stop_tx, mut stop_rx) = tokio::sync::mpsc::channel::<()>(1);
+        *self.stop_tx.lock().await = Some(stop_tx);
+
         let monitor = self.clone();
-        
-        // Spawn a blocking task since ZMQ operations are blocking
-        tokio::task::spawn_blocking(move || {
-            // Create ZMQ context and socket in the thread that will use them
-            let context = zmq::Context::new();
-            
-            let pull_socket = match context.socket(zmq::PULL) {
-                Ok(sock) => sock,
-                Err(e) => {
-                    log::error!("Failed to create PULL socket: {}", e);
-                    return;
-                }
-            };
-            
-            // Set receive timeout to avoid blocking forever
-            if let Err(e) = pull_socket.set_rcvtimeo(100) {
-                log::error!("Failed to set receive timeout: {}", e);
-                return;
-            }
-            
-            // Bind to the control plane port
-            if let Err(e) = pull_socket.bind("tcp://127.0.0.1:5557") {
-                log::error!("Failed to bind to port 5557: {}", e);
-                return;
-            }
-            
-            log::info!("Control plane monitor bound to port 5557");
-            
-            // Use a runtime handle for async operations within the blocking thread
-            let runtime = tokio::runtime::Handle::current();
-            
+        let handle = tokio::spawn(async move {
             loop {
-                // Check if we should stop
-                let should_stop = runtime.block_on(async {
-                    !*monitor.running.read().await
-                });
-                
-                if should_stop {
-                    log::info!("Control plane monitor stopping");
-                    break;
-                }
-                
-                // Try to receive a message (non-blocking due to timeout)
-                match pull_socket.recv_bytes(0) {
-                    Ok(msg) => {
-                        // Parse the MessagePack message
-                        let monitor_clone = monitor.clone();
-                        runtime.block_on(async move {
-                            if let Err(e) = monitor_clone.process_message(msg.as_slice()).await {
+                tokio::select! {
+                    biased;
+                    _ = stop_rx.recv() => {
+                        log::info!("Control plane monitor stopping");
+                        break;
+                    }
+                    recv = monitor.transport.recv() => match recv {
+                        Ok(msg) => {
+                            if let Err(e) = monitor.process_message(msg.as_slice()).await {
                                 log::error!("Failed to process status message: {}", e);
                             }
-                        });
-                    }
-                    Err(zmq::Error::EAGAIN) => {
-                        // Timeout - no message available, this is normal
-                        std::thread::sleep(Duration::from_millis(10));
-                    }
-                    Err(e) => {
-                        log::error!("Error receiving status message: {}", e);
-                        std::thread::sleep(Duration::from_millis(100));
+                        }
+                        Err(e) => {
+                            // Timeouts surface here as errors too; back off briefly.
+                            log::trace!("No status message: {}", e);
+                            tokio::time::sleep(Duration::from_millis(10)).await;
+                        }
                     }
                 }
             }
-            
-            // Clean up
-            if let Err(e) = pull_socket.unbind("tcp://127.0.0.1:5557") {
-                log::error!("Failed to unbind socket: {}", e);
+
+            if let Err(e) = monitor.transport.shutdown().await {
+                log::error!("Failed to shut down status transport: {}", e);
             }
         });
+
+        *self.handle.lock().await = Some(handle);
     }
-    
+
     /// Process a received status message
     async fn process_message(&self, msg: &[u8]) -> Result<()> {
         // Deserialize MessagePack message
         let status: StatusMessage = rmp_serde::from_slice(msg)
             .context("Failed to deserialize status message")?;
-        
-        log::debug!("Received status: {} from worker {}", 
+
+        log::debug!("Received status: {} from worker {}",
             status.status.status_type, status.worker_id);
-        
-        // Update worker ID
-        {
-            let mut stats = self.worker_stats.write().await;
-            stats.worker_id = Some(status.worker_id.clone());
-        }
-        
+
         // Process based on status type
-        match status.status.status_type.as_str() {
-            "Heartbeat" => {
-                // Update heartbeat timestamp
-                *self.latest_heartbeat.write().await = Some(Instant::now());
-                
-                // Extract stats from heartbeat data
-                if let Ok(data) = serde_json::from_value::<serde_json::Map<String, serde_json::Value>>(status.status.data.clone()) {
-                    let mut stats = self.worker_stats.write().await;
-                    
-                    if let Some(processed) = data.get("messages_processed").and_then(|v| v.as_u64()) {
-                        stats.messages_processed = processed;
-                    }
-                    if let Some(uptime) = data.get("uptime_seconds").and_then(|v| v.as_u64()) {
-                        stats.uptime_seconds = Some(uptime);
+        {
+            let mut workers = self.workers.write().await;
+            let stats = workers.entry(status.worker_id.clone()).or_default();
+
+            match status.status.status_type.as_str() {
+                "Heartbeat" => {
+                    *self.latest_heartbeat.write().await = Some(Instant::now());
+                    stats.last_heartbeat = Some(Instant::now());
+
+                    if let Ok(data) = serde_json::from_value::<serde_json::Map<String, serde_json::Value>>(status.status.data.clone()) {
+                        if let Some(processed) = data.get("messages_processed").and_then(|v| v.as_u64()) {
+                            stats.messages_processed = processed;
+                        }
+                        if let Some(uptime) = data.get("uptime_seconds").and_then(|v| v.as_u64()) {
+                            stats.uptime_seconds = Some(uptime);
+                        }
                     }
+
+                    log::trace!("Heartbeat received from worker {}", status.worker_id);
                 }
-                
-                log::trace!("Heartbeat received from worker {}", status.worker_id);
-            }
-            
-            "Started" => {
-                log::info!("Worker {} started", status.worker_id);
-                *self.latest_heartbeat.write().await = Some(Instant::now());
-                
-                // Reset stats for new worker
-                let mut stats = self.worker_stats.write().await;
-                *stats = WorkerStats {
-                    worker_id: Some(status.worker_id.clone()),
-                    ..Default::default()
-                };
-            }
-            
-            "Error" => {
-                log::error!("Worker {} reported error: {:?}", status.worker_id, status.status.data);
-                
-                let mut stats = self.worker_stats.write().await;
-                stats.errors += 1;
-                if let Ok(data) = serde_json::from_value::<serde_json::Map<String, serde_json::Value>>(status.status.data.clone()) {
-                    if let Some(message) = data.get("message").and_then(|v| v.as_str()) {
-                        stats.last_error = Some(message.to_string());
+
+                "Started" => {
+                    log::info!("Worker {} started", status.worker_id);
+                    *self.latest_heartbeat.write().await = Some(Instant::now());
+                    // Reset stats for the (re)started worker.
+                    *stats = WorkerStats {
+                        last_heartbeat: Some(Instant::now()),
+                        ..Default::default()
+                    };
+                }
+
+                "Error" => {
+                    log::error!("Worker {} reported error: {:?}", status.worker_id, status.status.data);
+                    stats.errors += 1;
+                    if let Ok(data) = serde_json::from_value::<serde_json::Map<String, serde_json::Value>>(status.status.data.clone()) {
+                        if let Some(message) = data.get("message").and_then(|v| v.as_str()) {
+                            stats.last_error = Some(message.to_string());
+                        }
                     }
                 }
-            }
-            
-            "MessageCompleted" => {
-                // Track successful message processing
-                if let Ok(data) = serde_json::from_value::<serde_json::Map<String, serde_json::Value>>(status.status.data.clone()) {
-                    if let Some(success) = data.get("success").and_then(|v| v.as_bool()) {
-                        if success {
-                            let mut stats = self.worker_stats.write().await;
+
+                "MessageCompleted" => {
+                    if let Ok(data) = serde_json::from_value::<serde_json::Map<String, serde_json::Value>>(status.status.data.clone()) {
+                        if data.get("success").and_then(|v| v.as_bool()).unwrap_or(false) {
                             stats.messages_processed += 1;
                         }
                     }
                 }
-            }
-            
-            "Stopping" => {
-                log::info!("Worker {} is stopping", status.worker_id);
-            }
-            
-            _ => {
-                log::debug!("Received status type: {}", status.status.status_type);
+
+                "Stopping" => {
+                    log::info!("Worker {} is stopping", status.worker_id);
+                }
+
+                other => {
+                    log::debug!("Received status type: {}", other);
+                }
             }
         }
-        
+
         // Add to history
         let mut history = self.status_history.write().await;
         history.push_back(status);
-        
-        // Keep history size limited
         while history.len() > MAX_STATUS_HISTORY {
             history.pop_front();
         }
-        
+
         Ok(())
     }
-    
-    /// Stop monitoring
+
+    /// Stop monitoring and wait for the receive loop to finish so the bound
+    /// endpoint is fully released before returning.
     pub async fn stop(&self) {
         log::info!("Stopping control plane monitor");
         *self.running.write().await = false;
+
+        // Signal the loop to wake up immediately.
+        if let Some(tx) = self.stop_tx.lock().await.take() {
+            let _ = tx.send(()).await;
+        }
+
+        // Await teardown so unbind actually completes.
+        if let Some(handle) = self.handle.lock().await.take() {
+            if let Err(e) = handle.await {
+                log::warn!("Control plane monitor task did not join cleanly: {}", e);
+            }
+        }
     }
-    
+
     /// Check if the worker is healthy based on recent heartbeats
     pub async fn is_healthy(&self) -> bool {
         if let Some(last_heartbeat) = *self.latest_heartbeat.read().await {
@@ -262,35 +362,97 @@ impl ControlPlaneMonitor {
             false
         }
     }
-    
-    /// Get detailed health information
+
+    /// Get detailed health information for the most recently active worker.
     pub async fn get_health(&self) -> WorkerHealth {
-        let last_heartbeat = *self.latest_heartbeat.read().await;
-        let (is_healthy, last_heartbeat_seconds_ago) = if let Some(hb) = last_heartbeat {
-            let elapsed = hb.elapsed();
-            (elapsed < Duration::from_secs(60), Some(elapsed.as_secs()))
-        } else {
-            (false, None)
-        };
-        
-        let stats = self.worker_stats.read().await;
-        
-        WorkerHealth {
-            is_healthy,
-            last_heartbeat_seconds_ago,
-            uptime_seconds: stats.uptime_seconds,
-            messages_processed: stats.messages_processed,
-            errors: stats.errors,
-            last_error: stats.last_error.clone(),
-            worker_id: stats.worker_id.clone(),
+        let workers = self.workers.read().await;
+        workers
+            .iter()
+            .max_by_key(|(_, s)| s.last_heartbeat)
+            .map(|(id, stats)| stats.to_health(id))
+            .unwrap_or_else(|| WorkerStats::default().to_health(""))
+    }
+
+    /// Get health information for every known worker.
+    pub async fn list_workers(&self) -> Vec<WorkerHealth> {
+        let workers = self.workers.read().await;
+        let mut out: Vec<WorkerHealth> = workers
+            .iter()
+            .map(|(id, stats)| stats.to_health(id))
+            .collect();
+        out.sort_by(|a, b| a.worker_id.cmp(&b.worker_id));
+        out
+    }
+
+    /// Render the current worker state in Prometheus text exposition format.
+    ///
+    /// Exposes `scout_worker_up`, `scout_worker_last_heartbeat_seconds`,
+    /// `scout_worker_uptime_seconds`, `scout_worker_messages_processed_total`,
+    /// and `scout_worker_errors_total`, each labelled by `worker_id`, so an
+    /// external scraper can alert on a stalled or crash-looping worker.
+    pub async fn gather(&self) -> String {
+        let workers = self.workers.read().await;
+        let mut out = String::new();
+
+        out.push_str("# HELP scout_worker_up Whether the worker has a recent heartbeat (1) or not (0)\n");
+        out.push_str("# TYPE scout_worker_up gauge\n");
+        for (id, stats) in workers.iter() {
+            let up = stats
+                .last_heartbeat
+                .map(|hb| hb.elapsed() < Duration::from_secs(60))
+                .unwrap_or(false);
+            out.push_str(&format!("scout_worker_up{{worker_id=\"{}\"}} {}\n", id, up as u8));
+        }
+
+        out.push_str("# HELP scout_worker_last_heartbeat_seconds Seconds since the last heartbeat\n");
+        out.push_str("# TYPE scout_worker_last_heartbeat_seconds gauge\n");
+        for (id, stats) in workers.iter() {
+            if let Some(hb) = stats.last_heartbeat {
+                out.push_str(&format!(
+                    "scout_worker_last_heartbeat_seconds{{worker_id=\"{}\"}} {}\n",
+                    id,
+                    hb.elapsed().as_secs()
+                ));
+            }
+        }
+
+        out.push_str("# HELP scout_worker_uptime_seconds Reported worker uptime\n");
+        out.push_str("# TYPE scout_worker_uptime_seconds gauge\n");
+        for (id, stats) in workers.iter() {
+            if let Some(uptime) = stats.uptime_seconds {
+                out.push_str(&format!(
+                    "scout_worker_uptime_seconds{{worker_id=\"{}\"}} {}\n",
+                    id, uptime
+                ));
+            }
+        }
+
+        out.push_str("# HELP scout_worker_messages_processed_total Messages processed by the worker\n");
+        out.push_str("# TYPE scout_worker_messages_processed_total counter\n");
+        for (id, stats) in workers.iter() {
+            out.push_str(&format!(
+                "scout_worker_messages_processed_total{{worker_id=\"{}\"}} {}\n",
+                id, stats.messages_processed
+            ));
+        }
+
+        out.push_str("# HELP scout_worker_errors_total Errors reported by the worker\n");
+        out.push_str("# TYPE scout_worker_errors_total counter\n");
+        for (id, stats) in workers.iter() {
+            out.push_str(&format!(
+                "scout_worker_errors_total{{worker_id=\"{}\"}} {}\n",
+                id, stats.errors
+            ));
         }
+
+        out
     }
-    
+
     /// Get recent status messages
     pub async fn get_status_history(&self) -> Vec<StatusMessage> {
         self.status_history.read().await.iter().cloned().collect()
     }
-    
+
     /// Clear status history
     pub async fn clear_history(&self) {
         self.status_history.write().await.clear();
@@ -299,21 +461,167 @@ impl ControlPlaneMonitor {
 
 
 /// Global control plane monitor instance
-pub static CONTROL_PLANE_MONITOR: once_cell::sync::Lazy<Arc<RwLock<Option<Arc<ControlPlaneMonitor>>>>> = 
+pub static CONTROL_PLANE_MONITOR: once_cell::sync::Lazy<Arc<RwLock<Option<Arc<ControlPlaneMonitor>>>>> =
     once_cell::sync::Lazy::new(|| Arc::new(RwLock::new(None)));
 
 /// Initialize the global control plane monitor
 pub async fn init_control_plane_monitor() -> Result<()> {
     let monitor = Arc::new(ControlPlaneMonitor::new()?);
     monitor.clone().start_monitoring().await;
-    
+
     let mut global = CONTROL_PLANE_MONITOR.write().await;
     *global = Some(monitor);
-    
+
     Ok(())
 }
 
 /// Get the global control plane monitor
 pub async fn get_control_plane_monitor() -> Option<Arc<ControlPlaneMonitor>> {
     CONTROL_PLANE_MONITOR.read().await.clone()
-}
\ No newline at end of file
+}
+
+/// Spawn a tiny HTTP server that serves the monitor's metrics at `/metrics`.
+///
+/// Intentionally dependency-free: it speaks just enough HTTP/1.1 for a
+/// Prometheus scraper. Returns the bound address.
+pub async fn spawn_metrics_server(
+    monitor: Arc<ControlPlaneMonitor>,
+    addr: &str,
+) -> Result<std::net::SocketAddr> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind metrics server to {}", addr))?;
+    let local_addr = listener.local_addr()?;
+    log::info!("Worker metrics available at http://{}/metrics", local_addr);
+
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = match listener.accept().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    log::warn!("Metrics server accept failed: {}", e);
+                    continue;
+                }
+            };
+
+            let monitor = monitor.clone();
+            tokio::spawn(async move {
+                // Drain the request line/headers; we only serve GET /metrics.
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf).await;
+
+                let body = monitor.gather().await;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                if let Err(e) = stream.write_all(response.as_bytes()).await {
+                    log::trace!("Metrics response write failed: {}", e);
+                }
+            });
+        }
+    });
+
+    Ok(local_addr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(worker_id: &str, status_type: &str, data: serde_json::Value) -> Vec<u8> {
+        let msg = StatusMessage {
+            worker_id: worker_id.to_string(),
+            status: StatusDetails {
+                status_type: status_type.to_string(),
+                data,
+            },
+            timestamp: "2024-01-01T00:00:00Z".to_string(),
+            metadata: None,
+        };
+        rmp_serde::to_vec_named(&msg).unwrap()
+    }
+
+    #[tokio::test]
+    async fn heartbeat_marks_worker_healthy() {
+        let (transport, _tx) = MockTransport::new();
+        let monitor = ControlPlaneMonitor::with_transport(Arc::new(transport));
+
+        monitor
+            .process_message(&frame(
+                "w1",
+                "Heartbeat",
+                serde_json::json!({ "messages_processed": 7, "uptime_seconds": 42 }),
+            ))
+            .await
+            .unwrap();
+
+        let health = monitor.get_health().await;
+        assert!(health.is_healthy);
+        assert_eq!(health.worker_id.as_deref(), Some("w1"));
+        assert_eq!(health.messages_processed, 7);
+        assert_eq!(health.uptime_seconds, Some(42));
+    }
+
+    #[tokio::test]
+    async fn errors_are_counted_per_worker() {
+        let (transport, _tx) = MockTransport::new();
+        let monitor = ControlPlaneMonitor::with_transport(Arc::new(transport));
+
+        monitor
+            .process_message(&frame("w1", "Error", serde_json::json!({ "message": "boom" })))
+            .await
+            .unwrap();
+        monitor
+            .process_message(&frame("w1", "Error", serde_json::json!({ "message": "boom again" })))
+            .await
+            .unwrap();
+
+        let workers = monitor.list_workers().await;
+        assert_eq!(workers.len(), 1);
+        assert_eq!(workers[0].errors, 2);
+        assert_eq!(workers[0].last_error.as_deref(), Some("boom again"));
+    }
+
+    #[tokio::test]
+    async fn gather_emits_prometheus_counters() {
+        let (transport, _tx) = MockTransport::new();
+        let monitor = ControlPlaneMonitor::with_transport(Arc::new(transport));
+
+        monitor
+            .process_message(&frame("w1", "Heartbeat", serde_json::json!({ "messages_processed": 3 })))
+            .await
+            .unwrap();
+        monitor
+            .process_message(&frame("w1", "Error", serde_json::json!({ "message": "x" })))
+            .await
+            .unwrap();
+
+        let text = monitor.gather().await;
+        assert!(text.contains("scout_worker_up{worker_id=\"w1\"} 1"));
+        assert!(text.contains("scout_worker_messages_processed_total{worker_id=\"w1\"} 3"));
+        assert!(text.contains("scout_worker_errors_total{worker_id=\"w1\"} 1"));
+    }
+
+    #[tokio::test]
+    async fn list_workers_tracks_each_worker_independently() {
+        let (transport, _tx) = MockTransport::new();
+        let monitor = ControlPlaneMonitor::with_transport(Arc::new(transport));
+
+        monitor
+            .process_message(&frame("a", "Heartbeat", serde_json::json!({})))
+            .await
+            .unwrap();
+        monitor
+            .process_message(&frame("b", "Heartbeat", serde_json::json!({})))
+            .await
+            .unwrap();
+
+        let workers = monitor.list_workers().await;
+        let ids: Vec<_> = workers.iter().filter_map(|w| w.worker_id.clone()).collect();
+        assert_eq!(ids, vec!["a".to_string(), "b".to_string()]);
+    }
+}
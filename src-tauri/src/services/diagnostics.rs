@@ -24,6 +24,165 @@ pub struct VoiceRecording {
     pub description: String,
 }
 
+/// Width of one FFT analysis frame, in samples.
+const SPECTRAL_FRAME_SIZE: usize = 1024;
+/// 50% overlap between consecutive frames.
+const SPECTRAL_HOP_SIZE: usize = SPECTRAL_FRAME_SIZE / 2;
+/// RMS energy below which a frame is considered near-silent (a dropout).
+const SPECTRAL_DROPOUT_RMS: f32 = 0.001;
+/// Fraction of a frame's samples at +/- full-scale before it's flagged as a clipping burst.
+const SPECTRAL_CLIPPING_BURST_RATIO: f32 = 0.05;
+/// Spectral flatness above which a frame looks like broadband noise or a digital glitch rather
+/// than tonal speech.
+const SPECTRAL_FLATNESS_ANOMALY_THRESHOLD: f32 = 0.8;
+
+/// Per-frame summary of a [`spectral_frame_analysis`] pass, kept alongside the JSON artifact so
+/// callers can fold counts into the existing corruption/noise indicator lists without re-parsing
+/// the JSON.
+struct SpectralAnalysisResult {
+    json: serde_json::Value,
+    dropout_range_count: usize,
+    clipping_burst_range_count: usize,
+    flat_spectrum_range_count: usize,
+}
+
+/// Real-to-complex FFT pass over overlapping Hann-windowed frames, computing per-frame clipping
+/// ratio, DC offset, RMS, and spectral flatness (geometric mean of the magnitude spectrum over
+/// its arithmetic mean - low for tonal speech, high for broadband noise or glitches). Frames
+/// that trip a threshold are merged into contiguous timestamp ranges so the report pinpoints
+/// where dropouts, clipping bursts, and anomalous flat-spectrum regions actually are.
+fn spectral_frame_analysis(samples: &[f32], sample_rate: u32) -> SpectralAnalysisResult {
+    use realfft::RealFftPlanner;
+
+    if samples.len() < SPECTRAL_FRAME_SIZE || sample_rate == 0 {
+        return SpectralAnalysisResult {
+            json: serde_json::json!({
+                "frame_size": SPECTRAL_FRAME_SIZE,
+                "hop_size": SPECTRAL_HOP_SIZE,
+                "frames_analyzed": 0,
+                "note": "Audio shorter than one FFT frame; spectral analysis skipped",
+            }),
+            dropout_range_count: 0,
+            clipping_burst_range_count: 0,
+            flat_spectrum_range_count: 0,
+        };
+    }
+
+    let mut planner = RealFftPlanner::<f32>::new();
+    let fft = planner.plan_fft_forward(SPECTRAL_FRAME_SIZE);
+    let mut scratch = fft.make_scratch_vec();
+    let mut spectrum = fft.make_output_vec();
+
+    let window: Vec<f32> = (0..SPECTRAL_FRAME_SIZE)
+        .map(|i| {
+            0.5 - 0.5
+                * (2.0 * std::f32::consts::PI * i as f32 / (SPECTRAL_FRAME_SIZE - 1) as f32).cos()
+        })
+        .collect();
+
+    let mut dropout_frames = Vec::new();
+    let mut clipping_frames = Vec::new();
+    let mut flat_spectrum_frames = Vec::new();
+    let mut dc_offset_sum = 0.0f32;
+    let mut frames_analyzed = 0;
+
+    let mut start = 0;
+    while start + SPECTRAL_FRAME_SIZE <= samples.len() {
+        let frame = &samples[start..start + SPECTRAL_FRAME_SIZE];
+
+        let clipped = frame.iter().filter(|s| s.abs() >= 0.99).count();
+        let clipping_ratio = clipped as f32 / SPECTRAL_FRAME_SIZE as f32;
+        let dc_offset = frame.iter().sum::<f32>() / SPECTRAL_FRAME_SIZE as f32;
+        let rms = (frame.iter().map(|s| s * s).sum::<f32>() / SPECTRAL_FRAME_SIZE as f32).sqrt();
+
+        let mut windowed: Vec<f32> = frame.iter().zip(&window).map(|(s, w)| s * w).collect();
+        let flatness = match fft.process_with_scratch(&mut windowed, &mut spectrum, &mut scratch) {
+            Ok(()) => spectral_flatness(&spectrum),
+            Err(_) => 0.0,
+        };
+
+        let start_ms = (start as f64 * 1000.0 / sample_rate as f64) as u64;
+        let end_ms = ((start + SPECTRAL_FRAME_SIZE) as f64 * 1000.0 / sample_rate as f64) as u64;
+
+        if rms < SPECTRAL_DROPOUT_RMS {
+            dropout_frames.push((start_ms, end_ms));
+        }
+        if clipping_ratio > SPECTRAL_CLIPPING_BURST_RATIO {
+            clipping_frames.push((start_ms, end_ms));
+        }
+        if flatness > SPECTRAL_FLATNESS_ANOMALY_THRESHOLD {
+            flat_spectrum_frames.push((start_ms, end_ms));
+        }
+
+        dc_offset_sum += dc_offset.abs();
+        frames_analyzed += 1;
+        start += SPECTRAL_HOP_SIZE;
+    }
+
+    let dropout_ranges = merge_adjacent_ms_ranges(&dropout_frames);
+    let clipping_ranges = merge_adjacent_ms_ranges(&clipping_frames);
+    let flat_spectrum_ranges = merge_adjacent_ms_ranges(&flat_spectrum_frames);
+    let mean_dc_offset = dc_offset_sum / frames_analyzed as f32;
+
+    SpectralAnalysisResult {
+        dropout_range_count: dropout_ranges.len(),
+        clipping_burst_range_count: clipping_ranges.len(),
+        flat_spectrum_range_count: flat_spectrum_ranges.len(),
+        json: serde_json::json!({
+            "frame_size": SPECTRAL_FRAME_SIZE,
+            "hop_size": SPECTRAL_HOP_SIZE,
+            "frames_analyzed": frames_analyzed,
+            "mean_dc_offset": mean_dc_offset,
+            "dropout_ranges_ms": dropout_ranges,
+            "clipping_burst_ranges_ms": clipping_ranges,
+            "flat_spectrum_ranges_ms": flat_spectrum_ranges,
+        }),
+    }
+}
+
+/// Geometric mean of the magnitude spectrum divided by its arithmetic mean, ignoring the DC bin.
+/// Close to 1.0 for flat/noisy spectra, close to 0.0 for the few strong harmonics typical of
+/// tonal speech.
+fn spectral_flatness(spectrum: &[realfft::num_complex::Complex<f32>]) -> f32 {
+    let magnitudes: Vec<f32> = spectrum
+        .iter()
+        .skip(1) // drop the DC bin, which otherwise dominates the arithmetic mean
+        .map(|c| c.norm())
+        .filter(|m| *m > 1e-10)
+        .collect();
+
+    if magnitudes.is_empty() {
+        return 0.0;
+    }
+
+    let log_sum: f32 = magnitudes.iter().map(|m| m.ln()).sum();
+    let geometric_mean = (log_sum / magnitudes.len() as f32).exp();
+    let arithmetic_mean = magnitudes.iter().sum::<f32>() / magnitudes.len() as f32;
+
+    if arithmetic_mean <= 0.0 {
+        0.0
+    } else {
+        (geometric_mean / arithmetic_mean).clamp(0.0, 1.0)
+    }
+}
+
+/// Coalesces a time-ordered list of flagged-frame `(start_ms, end_ms)` spans into contiguous
+/// ranges, so overlapping/adjacent frames (guaranteed by the 50% hop) collapse into one range
+/// per dropout/burst/anomaly instead of one entry per frame.
+fn merge_adjacent_ms_ranges(frames: &[(u64, u64)]) -> Vec<serde_json::Value> {
+    let mut merged: Vec<(u64, u64)> = Vec::new();
+    for &(start, end) in frames {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+        .into_iter()
+        .map(|(start_ms, end_ms)| serde_json::json!({ "start_ms": start_ms, "end_ms": end_ms }))
+        .collect()
+}
+
 pub async fn analyze_audio_corruption(file_path: &str) -> Result<serde_json::Value, String> {
     use hound::WavReader;
     use std::collections::HashMap;
@@ -138,6 +297,26 @@ pub async fn analyze_audio_corruption(file_path: &str) -> Result<serde_json::Val
         ));
     }
 
+    let spectral = spectral_frame_analysis(&samples, spec.sample_rate);
+    if spectral.dropout_range_count > 0 {
+        corruption_indicators.push(format!(
+            "Spectral analysis found {} dropout region(s)",
+            spectral.dropout_range_count
+        ));
+    }
+    if spectral.clipping_burst_range_count > 0 {
+        corruption_indicators.push(format!(
+            "Spectral analysis found {} clipping burst region(s)",
+            spectral.clipping_burst_range_count
+        ));
+    }
+    if spectral.flat_spectrum_range_count > 0 {
+        noise_indicators.push(format!(
+            "Spectral analysis found {} anomalous flat-spectrum region(s) (possible broadband noise or digital glitch)",
+            spectral.flat_spectrum_range_count
+        ));
+    }
+
     let analysis = serde_json::json!({
         "file_path": file_path,
         "basic_info": {
@@ -160,6 +339,7 @@ pub async fn analyze_audio_corruption(file_path: &str) -> Result<serde_json::Val
         },
         "corruption_indicators": corruption_indicators,
         "noise_indicators": noise_indicators,
+        "spectral_analysis": spectral.json,
         "health_score": {
             "overall": if corruption_indicators.is_empty() && noise_indicators.is_empty() { "HEALTHY" } else { "CORRUPTED" },
             "corruption_count": corruption_indicators.len(),
@@ -380,3 +560,108 @@ fn corrupt_wav_sample_rate(wav_path: &std::path::Path, speed_factor: f32) -> Res
     Ok(())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::signal_gen::SignalKind;
+    use crate::audio::synthetic_source::{write_mismatched_header_wav, write_synthetic_wav};
+    use tempfile::tempdir;
+
+    /// A synthetic impulse train recorded and resampled at its own rate
+    /// (no mismatch) should read back as healthy: its zero crossing rate
+    /// tracks the fundamental, not some aliased artifact, and no
+    /// discontinuity indicators should fire.
+    #[tokio::test]
+    async fn synthetic_impulse_train_survives_matched_resample() {
+        let temp_dir = tempdir().unwrap();
+        let wav_path = temp_dir.path().join("impulse_train.wav");
+        let fundamental_hz = 440.0;
+
+        write_synthetic_wav(
+            &wav_path,
+            SignalKind::ImpulseTrain { fundamental_hz, amplitude: 0.9 },
+            48000,
+            16000,
+            1,
+            1.0,
+            42,
+        )
+        .unwrap();
+
+        let analysis = analyze_audio_corruption(wav_path.to_str().unwrap()).await.unwrap();
+
+        assert_eq!(analysis["health_score"]["overall"], "HEALTHY");
+        assert_eq!(analysis["corruption_indicators"].as_array().unwrap().len(), 0);
+        assert_eq!(analysis["basic_info"]["sample_rate"], 16000);
+    }
+
+    /// A sine generated at one rate but written under a header claiming a
+    /// different one is exactly the corruption `corrupt_wav_sample_rate`
+    /// reproduces post-hoc - except here it's deterministic from the start.
+    /// The zero crossing rate (computed against the header's claimed rate)
+    /// should shift away from the true fundamental in proportion to the
+    /// header/actual rate mismatch, which is how `analyze_audio_corruption`
+    /// flags abnormal zero crossing rates.
+    #[tokio::test]
+    async fn mismatched_header_shifts_detected_frequency() {
+        let temp_dir = tempdir().unwrap();
+        let matched_path = temp_dir.path().join("matched.wav");
+        let mismatched_path = temp_dir.path().join("mismatched.wav");
+        let frequency_hz = 1000.0;
+        let sine = SignalKind::Sine { frequency_hz, amplitude: 0.5 };
+
+        write_synthetic_wav(&matched_path, sine, 44100, 44100, 1, 1.0, 7).unwrap();
+        write_mismatched_header_wav(&mismatched_path, sine, 44100, 36000, 1.0, 7).unwrap();
+
+        let matched = analyze_audio_corruption(matched_path.to_str().unwrap()).await.unwrap();
+        let mismatched = analyze_audio_corruption(mismatched_path.to_str().unwrap()).await.unwrap();
+
+        let matched_rate = matched["signal_analysis"]["zero_crossing_rate"].as_f64().unwrap();
+        let mismatched_rate = mismatched["signal_analysis"]["zero_crossing_rate"].as_f64().unwrap();
+
+        // Declaring a 44100Hz-generated signal's duration as if it were
+        // 36000Hz stretches its apparent duration by 44100/36000, which
+        // divides the computed zero crossing rate by the same factor.
+        let expected_ratio = 36000.0 / 44100.0;
+        let actual_ratio = mismatched_rate / matched_rate;
+        assert!(
+            (actual_ratio - expected_ratio).abs() < 0.05,
+            "expected zero crossing rate ratio near {:.3}, got {:.3}",
+            expected_ratio,
+            actual_ratio
+        );
+    }
+
+    /// White noise run through the matched recorder/resampler path should
+    /// not introduce the long runs of identical samples that flag as a
+    /// discontinuity - a clipped or stuck-sample bug would show up here as
+    /// a spike in `max_consecutive_identical`.
+    #[tokio::test]
+    async fn synthetic_white_noise_has_no_discontinuities() {
+        let temp_dir = tempdir().unwrap();
+        let wav_path = temp_dir.path().join("white_noise.wav");
+
+        write_synthetic_wav(
+            &wav_path,
+            SignalKind::WhiteNoise { amplitude: 0.6 },
+            48000,
+            16000,
+            1,
+            1.0,
+            99,
+        )
+        .unwrap();
+
+        let analysis = analyze_audio_corruption(wav_path.to_str().unwrap()).await.unwrap();
+        let max_consecutive_identical = analysis["signal_analysis"]["max_consecutive_identical"]
+            .as_u64()
+            .unwrap();
+
+        assert!(
+            max_consecutive_identical < 1000,
+            "unexpected run of identical samples: {}",
+            max_consecutive_identical
+        );
+    }
+}
+
@@ -1,33 +1,157 @@
 use std::path::Path;
+use std::time::Instant;
 
+/// Maximum number of resume attempts after a transient mid-stream error.
+const MAX_RETRIES: u32 = 5;
+
+/// Download `url` to `dest_path`, emitting `download-progress` events as it goes.
+///
+/// The download is resumable (HTTP range requests), retried with exponential
+/// backoff on transient network errors, and — when `expected_sha256` is
+/// supplied — verified against that digest on completion, emitting
+/// `download-verified` or `download-failed` and deleting the file on mismatch.
 pub async fn download_file_with_progress(
     app: &tauri::AppHandle,
     url: &str,
     dest_path: &Path,
     file_type: &str,
+    expected_sha256: Option<&str>,
+) -> Result<(), String> {
+    use tauri::Emitter;
+
+    let client = reqwest::Client::new();
+    let mut attempt = 0u32;
+
+    // Resume from whatever is already on disk on each retry.
+    loop {
+        match download_once(app, &client, url, dest_path, file_type).await {
+            Ok(()) => break,
+            Err(e) if attempt < MAX_RETRIES => {
+                attempt += 1;
+                let backoff = std::time::Duration::from_millis(500 * 2u64.pow(attempt - 1));
+                log::warn!(
+                    "Download of {} failed ({}), retrying in {:?} (attempt {}/{})",
+                    file_type, e, backoff, attempt, MAX_RETRIES
+                );
+                tokio::time::sleep(backoff).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    // Optional integrity check against the expected digest.
+    if let Some(expected) = expected_sha256 {
+        let actual = sha256_file(dest_path)
+            .map_err(|e| format!("Failed to hash {}: {}", file_type, e))?;
+        if !actual.eq_ignore_ascii_case(expected) {
+            let _ = std::fs::remove_file(dest_path);
+            app.emit(
+                "download-failed",
+                serde_json::json!({
+                    "url": url,
+                    "fileType": file_type,
+                    "reason": "checksum mismatch",
+                    "expected": expected,
+                    "actual": actual,
+                }),
+            )
+            .ok();
+            return Err(format!(
+                "Checksum mismatch for {}: expected {}, got {}",
+                file_type, expected, actual
+            ));
+        }
+    }
+
+    app.emit(
+        "download-verified",
+        serde_json::json!({
+            "url": url,
+            "fileType": file_type,
+            "path": dest_path.to_string_lossy(),
+        }),
+    )
+    .ok();
+
+    Ok(())
+}
+
+/// Perform a single (possibly resumed) download pass. Any partial bytes already
+/// on disk are kept so the caller can resume by retrying.
+async fn download_once(
+    app: &tauri::AppHandle,
+    client: &reqwest::Client,
+    url: &str,
+    dest_path: &Path,
+    file_type: &str,
 ) -> Result<(), String> {
     use futures_util::StreamExt;
     use std::io::Write;
     use tauri::Emitter;
 
-    let response = reqwest::get(url)
+    // Bytes already present from a previous (partial) attempt.
+    let resumed_from = std::fs::metadata(dest_path).map(|m| m.len()).unwrap_or(0);
+
+    let mut request = client.get(url);
+    if resumed_from > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={}-", resumed_from));
+    }
+
+    let response = request
+        .send()
         .await
         .map_err(|e| format!("Failed to download {}: {}", file_type, e))?;
 
-    let total_size = response.content_length().unwrap_or(0);
-    let mut downloaded = 0u64;
-    let mut file = std::fs::File::create(dest_path).map_err(|e| format!("Failed to create file: {}", e))?;
+    let status = response.status();
+
+    // `206 Partial Content` means the server honoured our range, so append;
+    // anything else (including a plain `200`) starts the file from scratch.
+    let (mut file, mut downloaded) =
+        if status == reqwest::StatusCode::PARTIAL_CONTENT && resumed_from > 0 {
+            let file = std::fs::OpenOptions::new()
+                .append(true)
+                .open(dest_path)
+                .map_err(|e| format!("Failed to open file for append: {}", e))?;
+            (file, resumed_from)
+        } else {
+            if !status.is_success() {
+                return Err(format!("Download of {} failed with status {}", file_type, status));
+            }
+            let file = std::fs::File::create(dest_path)
+                .map_err(|e| format!("Failed to create file: {}", e))?;
+            (file, 0)
+        };
+
+    // On a 206 `content_length` is the remaining bytes; add what we already have.
+    let total_size = response.content_length().map(|c| c + downloaded).unwrap_or(0);
+
+    let start = Instant::now();
     let mut stream = response.bytes_stream();
     while let Some(chunk) = stream.next().await {
         let chunk = chunk.map_err(|e| format!("Failed to read chunk: {}", e))?;
         file.write_all(&chunk)
             .map_err(|e| format!("Failed to write chunk: {}", e))?;
         downloaded += chunk.len() as u64;
+
         let progress = if total_size > 0 {
             (downloaded as f32 / total_size as f32 * 100.0) as u32
         } else {
             0
         };
+
+        // ETA from a rolling throughput average over this attempt's bytes.
+        let elapsed = start.elapsed().as_secs_f64();
+        let eta_seconds = if elapsed > 0.0 && total_size > downloaded {
+            let rate = downloaded.saturating_sub(resumed_from) as f64 / elapsed;
+            if rate > 0.0 {
+                ((total_size - downloaded) as f64 / rate) as u64
+            } else {
+                0
+            }
+        } else {
+            0
+        };
+
         app.emit(
             "download-progress",
             serde_json::json!({
@@ -36,10 +160,31 @@ pub async fn download_file_with_progress(
                 "downloaded": downloaded,
                 "total": total_size,
                 "fileType": file_type,
+                "resumed_from": resumed_from,
+                "eta_seconds": eta_seconds,
             }),
         )
         .ok();
     }
+
+    file.flush().map_err(|e| format!("Failed to flush file: {}", e))?;
     Ok(())
 }
 
+/// Compute the hex-encoded SHA-256 of a file, streaming it in fixed-size chunks.
+fn sha256_file(path: &Path) -> std::io::Result<String> {
+    use sha2::{Digest, Sha256};
+    use std::io::Read;
+
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 8192];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
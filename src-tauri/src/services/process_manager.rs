@@ -1,13 +1,277 @@
 /// Enhanced process manager for external services
 /// Handles proper process lifecycle, cleanup, and monitoring
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::process::{Command, Stdio};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use serde::{Serialize, Deserialize};
 use sysinfo::System;
 
+/// A signal that can be broadcast to a managed process group, abstracted so
+/// the same [`ShutdownPolicy`] (or an ad hoc [`ProcessManager::signal_process_group`]
+/// call) describes behavior on Unix and Windows alike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    /// Ask the process to terminate gracefully (Unix: `SIGTERM` to the
+    /// process group; Windows: closes windows owned by the process tree).
+    Term,
+    /// Ask the process to interrupt (Unix: `SIGINT`; Windows: same as `Term`
+    /// — Windows has no separate interrupt-vs-terminate request outside a
+    /// console process group).
+    Interrupt,
+    /// Force-terminate unconditionally (Unix: `SIGKILL`; Windows:
+    /// force-terminates the process tree).
+    Kill,
+    /// Ask the process to reload its configuration (Unix: `SIGHUP`; no
+    /// Windows equivalent).
+    Hangup,
+    /// Ask the process to perform a user-defined action, e.g. rotating logs
+    /// (Unix: `SIGUSR1`; no Windows equivalent).
+    User1,
+    /// A second user-defined signal (Unix: `SIGUSR2`; no Windows equivalent).
+    User2,
+    /// Suspend the process without killing it (Unix: `SIGSTOP`; no Windows
+    /// equivalent).
+    Stop,
+    /// Resume a process suspended with `Stop` (Unix: `SIGCONT`; no Windows
+    /// equivalent).
+    Continue,
+}
+
+#[cfg(unix)]
+impl Signal {
+    fn unix_signal_number(self) -> libc::c_int {
+        match self {
+            Signal::Term => libc::SIGTERM,
+            Signal::Interrupt => libc::SIGINT,
+            Signal::Kill => libc::SIGKILL,
+            Signal::Hangup => libc::SIGHUP,
+            Signal::User1 => libc::SIGUSR1,
+            Signal::User2 => libc::SIGUSR2,
+            Signal::Stop => libc::SIGSTOP,
+            Signal::Continue => libc::SIGCONT,
+        }
+    }
+}
+
+/// How long to wait between exit polls while honoring a [`ShutdownPolicy`]'s
+/// grace period. Not part of the policy itself since there's no reason a
+/// caller would ever need to tune it independently of the grace period.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Describes how `stop_managed_process` should escalate through signals
+/// while waiting for a process (group) to exit.
+#[derive(Debug, Clone)]
+pub struct ShutdownPolicy {
+    /// How long to wait for the process to exit after each signal before
+    /// escalating to the next one.
+    pub grace_period: Duration,
+    /// Signals to send in order. The last one is expected to be
+    /// unconditional (`Signal::Kill`) so shutdown always terminates.
+    pub signals: Vec<Signal>,
+}
+
+impl Default for ShutdownPolicy {
+    fn default() -> Self {
+        Self {
+            grace_period: Duration::from_secs(2),
+            signals: vec![Signal::Term, Signal::Kill],
+        }
+    }
+}
+
+/// Sends `signal` to the process group rooted at `pid` (Unix) or the
+/// process tree rooted at `pid` (Windows). Ignores "already gone" errors
+/// (Unix `ESRCH`) since that just means shutdown already succeeded.
+fn send_signal(pid: u32, signal: Signal) -> Result<(), String> {
+    #[cfg(unix)]
+    {
+        // Negative PID targets the whole process group, which
+        // `start_managed_process` puts the child in via `process_group(0)`.
+        let result = unsafe { libc::kill(-(pid as libc::pid_t), signal.unix_signal_number()) };
+        if result != 0 {
+            let err = std::io::Error::last_os_error();
+            if err.raw_os_error() != Some(libc::ESRCH) {
+                return Err(format!(
+                    "Failed to send {:?} to process group {}: {}",
+                    signal, pid, err
+                ));
+            }
+        }
+        Ok(())
+    }
+    #[cfg(windows)]
+    {
+        match signal {
+            // Windows has no SIGTERM/SIGKILL equivalent exposed without a
+            // Job Object + WinAPI dependency this crate doesn't otherwise
+            // need; `taskkill` is the practical portable stand-in. Without
+            // `/F` it asks windows owned by the process tree to close (the
+            // closest analogue to a graceful term/interrupt); `/F`
+            // force-terminates. `/T` targets the whole process tree,
+            // mirroring the Unix process-group send above.
+            Signal::Term | Signal::Interrupt | Signal::Kill => {
+                let mut cmd = Command::new("taskkill");
+                cmd.arg("/PID").arg(pid.to_string()).arg("/T");
+                if matches!(signal, Signal::Kill) {
+                    cmd.arg("/F");
+                }
+                cmd.output().map_err(|e| format!("Failed to run taskkill: {}", e))?;
+                Ok(())
+            }
+            // Config reload, log rotation, and suspend/resume are POSIX
+            // signal-disposition conventions a Windows process has no
+            // standard way to receive; fail honestly rather than silently
+            // no-op.
+            Signal::Hangup | Signal::User1 | Signal::User2 | Signal::Stop | Signal::Continue => {
+                Err(format!("{:?} has no Windows equivalent", signal))
+            }
+        }
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = (pid, signal);
+        Err("Signal delivery is not supported on this platform".to_string())
+    }
+}
+
+/// Minimal hand-rolled Win32 Job Object bindings. Mirrors the
+/// `extern "C"` bridge convention in `macos/foundation_models_ffi.rs`
+/// (declare just the symbols/structs needed, rather than pulling in a
+/// `winapi`/`windows-sys` dependency this crate doesn't otherwise have).
+///
+/// A Job Object with `JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE` set gives Windows
+/// the same "the whole tree dies together" guarantee a Unix process group
+/// gets from `process_group(0)` plus a negative-PID `kill()` — closing (or
+/// explicitly terminating) the job takes every process assigned to it down
+/// atomically, including descendants that have since forked.
+#[cfg(windows)]
+mod win_job {
+    use std::os::raw::c_void;
+
+    type Handle = *mut c_void;
+    type Bool = i32;
+    type DWord = u32;
+
+    #[repr(C)]
+    struct LargeInteger {
+        quad_part: i64,
+    }
+
+    #[repr(C)]
+    struct JobObjectBasicLimitInformation {
+        per_process_user_time_limit: LargeInteger,
+        per_job_user_time_limit: LargeInteger,
+        limit_flags: DWord,
+        minimum_working_set_size: usize,
+        maximum_working_set_size: usize,
+        active_process_limit: DWord,
+        affinity: usize,
+        priority_class: DWord,
+        scheduling_class: DWord,
+    }
+
+    #[repr(C)]
+    struct IoCounters {
+        read_operation_count: u64,
+        write_operation_count: u64,
+        other_operation_count: u64,
+        read_transfer_count: u64,
+        write_transfer_count: u64,
+        other_transfer_count: u64,
+    }
+
+    #[repr(C)]
+    struct JobObjectExtendedLimitInformation {
+        basic_limit_information: JobObjectBasicLimitInformation,
+        io_info: IoCounters,
+        process_memory_limit: usize,
+        job_memory_limit: usize,
+        peak_process_memory_used: usize,
+        peak_job_memory_used: usize,
+    }
+
+    const JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE: DWord = 0x2000;
+    const JOB_OBJECT_EXTENDED_LIMIT_INFORMATION: i32 = 9;
+    const PROCESS_ALL_ACCESS: DWord = 0x1F0FFF;
+
+    extern "system" {
+        fn CreateJobObjectW(lp_job_attributes: *mut c_void, lp_name: *const u16) -> Handle;
+        fn SetInformationJobObject(
+            job: Handle,
+            job_object_information_class: i32,
+            job_object_information: *const c_void,
+            job_object_information_length: u32,
+        ) -> Bool;
+        fn OpenProcess(desired_access: DWord, inherit_handle: Bool, process_id: u32) -> Handle;
+        fn AssignProcessToJobObject(job: Handle, process: Handle) -> Bool;
+        fn TerminateJobObject(job: Handle, exit_code: u32) -> Bool;
+        fn CloseHandle(handle: Handle) -> Bool;
+    }
+
+    /// Creates a job with `JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE` and assigns
+    /// `pid` to it. Returns the job handle, encoded as `usize` so it can be
+    /// stored in a plain `HashMap` alongside the rest of `ProcessManager`'s
+    /// tracking state without fighting `HANDLE`'s lack of `Send`/`Sync`
+    /// (the raw value is just a kernel handle number; it's only ever
+    /// reinterpreted back into a `HANDLE` inside this module).
+    pub fn create_and_assign(pid: u32) -> Result<usize, String> {
+        unsafe {
+            let job = CreateJobObjectW(std::ptr::null_mut(), std::ptr::null());
+            if job.is_null() {
+                return Err("CreateJobObjectW failed".to_string());
+            }
+
+            let mut info: JobObjectExtendedLimitInformation = std::mem::zeroed();
+            info.basic_limit_information.limit_flags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+
+            if SetInformationJobObject(
+                job,
+                JOB_OBJECT_EXTENDED_LIMIT_INFORMATION,
+                &info as *const _ as *const c_void,
+                std::mem::size_of::<JobObjectExtendedLimitInformation>() as u32,
+            ) == 0
+            {
+                CloseHandle(job);
+                return Err("SetInformationJobObject failed".to_string());
+            }
+
+            let process = OpenProcess(PROCESS_ALL_ACCESS, 0, pid);
+            if process.is_null() {
+                CloseHandle(job);
+                return Err(format!("OpenProcess failed for PID {}", pid));
+            }
+
+            let assigned = AssignProcessToJobObject(job, process);
+            CloseHandle(process);
+            if assigned == 0 {
+                CloseHandle(job);
+                return Err(format!("AssignProcessToJobObject failed for PID {}", pid));
+            }
+
+            Ok(job as usize)
+        }
+    }
+
+    /// Terminates every process still assigned to `job` and closes the
+    /// handle. This is the atomic "kill the whole tree" operation
+    /// `stop_managed_process`/`kill_all_matching` prefer over walking
+    /// children individually.
+    pub fn terminate(job: usize) -> Result<(), String> {
+        unsafe {
+            let handle = job as Handle;
+            let ok = TerminateJobObject(handle, 1);
+            CloseHandle(handle);
+            if ok == 0 {
+                return Err("TerminateJobObject failed".to_string());
+            }
+            Ok(())
+        }
+    }
+}
+
 /// Process information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessInfo {
@@ -18,6 +282,32 @@ pub struct ProcessInfo {
     pub memory_mb: f32,
     pub cpu_percent: f32,
     pub children: Vec<u32>,
+    /// Set once the reaper collects this process's exit status;
+    /// `None` while it's still running (or on platforms without a
+    /// reaper, e.g. if it exited before reaping ever ran).
+    pub exit_status: Option<String>,
+    /// PID of the process group leader `start_managed_process` put this
+    /// process in (via `process_group(0)`, which makes the spawned process
+    /// its own group leader). Tracked separately from `pid` so the group
+    /// stays addressable via [`ProcessManager::signal_process_group`] even
+    /// if `pid` is ever repointed at a different member of the tree.
+    pub group_leader_pid: u32,
+}
+
+/// Decodes a POSIX `wait()` status into a human-readable summary. These
+/// mirror the standard `WIFEXITED`/`WEXITSTATUS`/`WIFSIGNALED`/`WTERMSIG`
+/// macros (the encoding is the same across Linux and macOS); the `libc`
+/// crate doesn't expose them as functions since they're C preprocessor
+/// macros, not real symbols.
+#[cfg(unix)]
+fn describe_exit_status(status: libc::c_int) -> String {
+    if status & 0x7f == 0 {
+        format!("exited with code {}", (status >> 8) & 0xff)
+    } else if (((status & 0x7f) + 1) as i8 >> 1) > 0 {
+        format!("killed by signal {}", status & 0x7f)
+    } else {
+        format!("unknown wait status {}", status)
+    }
 }
 
 /// Service health status
@@ -29,27 +319,269 @@ pub struct HealthStatus {
     pub details: HashMap<String, String>,
 }
 
+/// A single readiness check `check_service_health` can run against a
+/// service. Plain TCP connects catch "nothing is listening" but not "the
+/// process is listening but wedged"; the other two variants let callers
+/// probe deeper without `ProcessManager` knowing anything about the
+/// service's actual protocol.
+#[derive(Debug, Clone)]
+pub enum HealthProbe {
+    /// Open (and immediately drop) a TCP connection to `127.0.0.1:port`.
+    TcpConnect { port: u16 },
+    /// `GET http://127.0.0.1:{port}{path}` and compare the response status
+    /// code against `expect_status`.
+    HttpGet {
+        port: u16,
+        path: String,
+        expect_status: u16,
+    },
+    /// Run `cmd args...` to completion and compare whether it exited zero
+    /// against `expect_exit_0` (most checks will pass `true`; `false` lets a
+    /// probe assert a command is expected to fail, e.g. a "not ready yet"
+    /// sentinel).
+    Command {
+        cmd: String,
+        args: Vec<String>,
+        expect_exit_0: bool,
+    },
+}
+
+impl HealthProbe {
+    /// Key this probe's result is recorded under in `HealthStatus::details`.
+    fn detail_key(&self) -> String {
+        match self {
+            HealthProbe::TcpConnect { port } => format!("port_{}", port),
+            HealthProbe::HttpGet { port, path, .. } => format!("http_{}{}", port, path),
+            HealthProbe::Command { cmd, .. } => format!("cmd_{}", cmd),
+        }
+    }
+
+    /// Runs the probe, returning a short human-readable detail string on
+    /// success and an error message on failure.
+    async fn run(&self) -> Result<String, String> {
+        match self {
+            HealthProbe::TcpConnect { port } => {
+                use std::net::{SocketAddr, TcpStream};
+
+                let addr: SocketAddr = format!("127.0.0.1:{}", port)
+                    .parse()
+                    .map_err(|e| format!("Invalid address for port {}: {}", port, e))?;
+                TcpStream::connect_timeout(&addr, Duration::from_millis(500))
+                    .map(|_| "open".to_string())
+                    .map_err(|e| format!("Port {} not accessible: {}", port, e))
+            }
+            HealthProbe::HttpGet {
+                port,
+                path,
+                expect_status,
+            } => {
+                let url = format!("http://127.0.0.1:{}{}", port, path);
+                let client = reqwest::Client::builder()
+                    .timeout(Duration::from_secs(2))
+                    .build()
+                    .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+
+                let response = client
+                    .get(&url)
+                    .send()
+                    .await
+                    .map_err(|e| format!("GET {} failed: {}", url, e))?;
+
+                let status_code = response.status().as_u16();
+                if status_code == *expect_status {
+                    Ok(status_code.to_string())
+                } else {
+                    Err(format!(
+                        "GET {} returned {}, expected {}",
+                        url, status_code, expect_status
+                    ))
+                }
+            }
+            HealthProbe::Command {
+                cmd,
+                args,
+                expect_exit_0,
+            } => {
+                let output = tokio::process::Command::new(cmd)
+                    .args(args)
+                    .output()
+                    .await
+                    .map_err(|e| format!("Failed to run `{}`: {}", cmd, e))?;
+
+                if output.status.success() == *expect_exit_0 {
+                    Ok(format!("exit_code={:?}", output.status.code()))
+                } else {
+                    Err(format!(
+                        "`{} {}` exited {:?}, expected exit-0={}",
+                        cmd,
+                        args.join(" "),
+                        output.status.code(),
+                        expect_exit_0
+                    ))
+                }
+            }
+        }
+    }
+}
+
 /// Process manager for handling external services
 pub struct ProcessManager {
     system: Arc<RwLock<System>>,
     processes: Arc<RwLock<HashMap<String, ProcessInfo>>>,
     health_checks: Arc<RwLock<HashMap<String, HealthStatus>>>,
+    /// PIDs of children spawned by `start_managed_process`. The reaper only
+    /// ever calls `waitpid` on PIDs tracked here, so it can never interfere
+    /// with some other process on the host (or even another child of ours
+    /// started ad hoc via `Command::output`, e.g. the `pkill` fallback
+    /// below, which waits on itself synchronously).
+    spawned_pids: Arc<RwLock<HashSet<u32>>>,
+    /// Exit statuses the reaper has collected but `cleanup_zombies` hasn't
+    /// drained yet.
+    reaped: Arc<RwLock<HashMap<u32, String>>>,
+    /// Consecutive failed-health-check count per service name, tracked by
+    /// `monitor_and_restart` so a single transient probe blip doesn't
+    /// trigger a restart.
+    failure_streaks: Arc<RwLock<HashMap<String, u32>>>,
+    /// Windows Job Object handles (see `win_job`), keyed by managed process
+    /// name. Kept as a parallel map rather than a field on `ProcessInfo`
+    /// itself — same precedent as `spawned_pids`/`reaped` — so the
+    /// cross-platform, serializable `ProcessInfo` struct doesn't need a
+    /// Windows-only field.
+    #[cfg(windows)]
+    job_handles: Arc<RwLock<HashMap<String, usize>>>,
 }
 
 impl ProcessManager {
     pub fn new() -> Self {
-        Self {
+        let manager = Self {
             system: Arc::new(RwLock::new(System::new_all())),
             processes: Arc::new(RwLock::new(HashMap::new())),
             health_checks: Arc::new(RwLock::new(HashMap::new())),
+            spawned_pids: Arc::new(RwLock::new(HashSet::new())),
+            reaped: Arc::new(RwLock::new(HashMap::new())),
+            failure_streaks: Arc::new(RwLock::new(HashMap::new())),
+            #[cfg(windows)]
+            job_handles: Arc::new(RwLock::new(HashMap::new())),
+        };
+        manager.spawn_reaper();
+        manager
+    }
+
+    /// Spawns a background task that reaps managed children as soon as they
+    /// exit on their own, so a worker that dies unexpectedly doesn't linger
+    /// as a zombie (which `kill_all_matching` can't clear — you can't signal
+    /// a `<defunct>` process) until the next `cleanup_zombies` poll.
+    #[cfg(unix)]
+    fn spawn_reaper(&self) {
+        let spawned_pids = self.spawned_pids.clone();
+        let reaped = self.reaped.clone();
+        let processes = self.processes.clone();
+
+        tokio::spawn(async move {
+            let mut sigchld =
+                match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::child()) {
+                    Ok(signal) => signal,
+                    Err(e) => {
+                        log::error!(
+                            "Failed to install SIGCHLD handler, zombies will only be reaped by explicit cleanup_zombies calls: {}",
+                            e
+                        );
+                        return;
+                    }
+                };
+
+            loop {
+                sigchld.recv().await;
+                Self::reap_spawned_children(&spawned_pids, &reaped, &processes).await;
+            }
+        });
+    }
+
+    #[cfg(not(unix))]
+    fn spawn_reaper(&self) {}
+
+    /// Drains every tracked spawned PID that has already exited via
+    /// `waitpid(pid, WNOHANG)`, looping until none are left reapable —
+    /// SIGCHLD delivery can coalesce multiple exits into a single signal,
+    /// so a single `waitpid` call per wakeup isn't enough.
+    #[cfg(unix)]
+    async fn reap_spawned_children(
+        spawned_pids: &Arc<RwLock<HashSet<u32>>>,
+        reaped: &Arc<RwLock<HashMap<u32, String>>>,
+        processes: &Arc<RwLock<HashMap<String, ProcessInfo>>>,
+    ) {
+        let candidates: Vec<u32> = spawned_pids.read().await.iter().copied().collect();
+
+        for pid in candidates {
+            let mut status: libc::c_int = 0;
+            // SAFETY: `pid` came from `spawned_pids`, which is only ever
+            // populated with PIDs this process spawned itself in
+            // `start_managed_process`, so this always waits on our own child.
+            let result = unsafe { libc::waitpid(pid as libc::pid_t, &mut status, libc::WNOHANG) };
+
+            if result == pid as libc::pid_t {
+                let exit_status = describe_exit_status(status);
+                log::info!("Reaped managed child PID {}: {}", pid, exit_status);
+
+                spawned_pids.write().await.remove(&pid);
+                reaped.write().await.insert(pid, exit_status.clone());
+
+                let mut processes = processes.write().await;
+                for info in processes.values_mut() {
+                    if info.pid == pid {
+                        info.exit_status = Some(exit_status.clone());
+                    }
+                }
+            } else if result < 0 {
+                // ECHILD (not our child anymore) or another error: stop tracking it.
+                spawned_pids.write().await.remove(&pid);
+            }
+            // result == 0: still running, leave it tracked.
         }
     }
-    
+
     /// Kill all processes matching a pattern (including orphans)
     pub async fn kill_all_matching(&self, pattern: &str) -> Result<Vec<u32>, String> {
         let mut killed_pids = Vec::new();
+
+        // On Windows, any managed process whose name matches `pattern` and
+        // still has a Job Object gets torn down atomically via that job
+        // instead of the best-effort sysinfo child-walk below — the same
+        // "prefer the group primitive" preference `stop_managed_process`
+        // makes.
+        #[cfg(windows)]
+        {
+            let matching_names: Vec<String> = self
+                .job_handles
+                .read()
+                .await
+                .keys()
+                .filter(|managed_name| managed_name.contains(pattern) || pattern.contains(managed_name.as_str()))
+                .cloned()
+                .collect();
+
+            for managed_name in matching_names {
+                if let Some(job) = self.job_handles.write().await.remove(&managed_name) {
+                    let pid = self.processes.read().await.get(&managed_name).map(|info| info.pid);
+                    if let Err(e) = win_job::terminate(job) {
+                        log::warn!("Failed to terminate Job Object for '{}': {}", managed_name, e);
+                        continue;
+                    }
+                    if let Some(pid) = pid {
+                        killed_pids.push(pid);
+                    }
+                    self.processes.write().await.remove(&managed_name);
+                    log::info!("Terminated '{}' and its whole process tree via Job Object", managed_name);
+                }
+            }
+        }
+
         let mut system = self.system.write().await;
-        system.refresh_all();
+        // Matching by name/cmd means every process must be scanned, so this
+        // is the one place a broad refresh is unavoidable — but it's still
+        // process-only, skipping the CPU/memory/disk/network globals
+        // `refresh_all()` would also collect.
+        system.refresh_processes(sysinfo::ProcessesToUpdate::All);
         
         // Find all matching processes
         for (pid, process) in system.processes() {
@@ -138,12 +670,35 @@ impl ProcessManager {
             use std::os::unix::process::CommandExt;
             cmd.process_group(0);
         }
-        
+
         let child = cmd.spawn()
             .map_err(|e| format!("Failed to start process: {}", e))?;
-        
+
         let pid = child.id();
-        
+
+        // Track the PID so the reaper can collect its exit status once it
+        // exits, instead of letting it linger as a zombie.
+        self.spawned_pids.write().await.insert(pid);
+
+        // On Windows, the equivalent of the Unix process group is a Job
+        // Object with KILL_ON_JOB_CLOSE — assign the child to one now so
+        // `stop_managed_process`/`kill_all_matching` can tear down the
+        // whole tree atomically later instead of walking children by hand.
+        #[cfg(windows)]
+        {
+            match win_job::create_and_assign(pid) {
+                Ok(job) => {
+                    self.job_handles.write().await.insert(name.to_string(), job);
+                }
+                Err(e) => {
+                    log::warn!(
+                        "Failed to create Job Object for '{}' (PID {}); falling back to best-effort child cleanup: {}",
+                        name, pid, e
+                    );
+                }
+            }
+        }
+
         // Store process info
         let mut processes = self.processes.write().await;
         processes.insert(name.to_string(), ProcessInfo {
@@ -154,123 +709,215 @@ impl ProcessManager {
             memory_mb: 0.0,
             cpu_percent: 0.0,
             children: Vec::new(),
+            exit_status: None,
+            // `process_group(0)` above makes the spawned process its own
+            // group leader, so its PID doubles as the group's PID.
+            group_leader_pid: pid,
         });
-        
+
         log::info!("Started managed process '{}' with PID {}", name, pid);
         
         Ok(pid)
     }
     
-    /// Stop a managed process and all its children
+    /// Stop a managed process using the default [`ShutdownPolicy`]
+    /// (`SIGTERM`/close, then `SIGKILL`/force-terminate after a 2s grace
+    /// period).
     pub async fn stop_managed_process(&self, name: &str) -> Result<(), String> {
-        let processes = self.processes.read().await;
-        
-        if let Some(info) = processes.get(name) {
-            let pid = info.pid;
-            drop(processes); // Release the lock
-            
-            // Kill the process group using system command
-            #[cfg(unix)]
-            {
-                // Try to kill the entire process group
-                let _ = Command::new("kill")
-                    .arg("-TERM")
-                    .arg(format!("-{}", pid))
-                    .output();
-                
-                tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
-                
-                let _ = Command::new("kill")
-                    .arg("-KILL")
-                    .arg(format!("-{}", pid))
-                    .output();
+        self.stop_managed_process_with_policy(name, &ShutdownPolicy::default()).await
+    }
+
+    /// Stop a managed process and all its children, escalating through
+    /// `policy.signals` in order. Each signal is sent to the whole process
+    /// group/tree; the tracked PID is then polled via sysinfo (not slept on
+    /// blindly) until it exits or `policy.grace_period` elapses, at which
+    /// point the next signal is sent.
+    pub async fn stop_managed_process_with_policy(
+        &self,
+        name: &str,
+        policy: &ShutdownPolicy,
+    ) -> Result<(), String> {
+        let pid = match self.processes.read().await.get(name) {
+            Some(info) => info.pid,
+            None => return Ok(()),
+        };
+
+        // On Windows, terminating the process's Job Object (if it has one)
+        // kills the whole tree atomically in one call, so there's no need
+        // to escalate through `policy.signals` at all.
+        #[cfg(windows)]
+        if let Some(job) = self.job_handles.write().await.remove(name) {
+            win_job::terminate(job)?;
+            self.processes.write().await.remove(name);
+            self.spawned_pids.write().await.remove(&pid);
+            log::info!("Stopped managed process '{}' via Job Object termination", name);
+            return Ok(());
+        }
+
+        let mut exited = false;
+        for (index, signal) in policy.signals.iter().enumerate() {
+            send_signal(pid, *signal)?;
+
+            if self.wait_for_exit(pid, policy.grace_period).await {
+                exited = true;
+                break;
             }
-            
-            // Fallback: kill individual process and children
-            let mut system = self.system.write().await;
-            system.refresh_all();
-            
-            if let Some(process) = system.process(sysinfo::Pid::from_u32(pid)) {
-                // Kill children first
-                let children = Self::get_child_processes(&system, sysinfo::Pid::from_u32(pid));
-                for child_pid in children {
-                    if let Some(child) = system.process(child_pid) {
-                        child.kill();
-                    }
-                }
-                
-                // Kill the main process
-                process.kill();
+
+            let is_last_signal = index == policy.signals.len() - 1;
+            if is_last_signal {
+                log::warn!(
+                    "Process '{}' (PID {}) did not exit within the grace period even after {:?}",
+                    name, pid, signal
+                );
             }
-            
-            // Remove from tracking
-            let mut processes = self.processes.write().await;
-            processes.remove(name);
-            
-            log::info!("Stopped managed process '{}'", name);
         }
-        
+
+        if !exited {
+            log::error!("Process '{}' (PID {}) could not be confirmed stopped", name, pid);
+        }
+
+        self.processes.write().await.remove(name);
+        self.spawned_pids.write().await.remove(&pid);
+
+        log::info!("Stopped managed process '{}'", name);
         Ok(())
     }
+
+    /// Broadcasts `signal` to every process in `name`'s managed group,
+    /// addressed via its tracked `group_leader_pid` rather than its current
+    /// `pid` so the whole tree still receives it even if children have
+    /// forked since it was spawned. Unlocks operations beyond tearing the
+    /// service down — e.g. `Signal::Hangup` to trigger a config reload,
+    /// `Signal::User1` to rotate logs, or `Signal::Stop`/`Signal::Continue`
+    /// to pause and resume a heavy worker during a burst.
+    pub async fn signal_process_group(&self, name: &str, signal: Signal) -> Result<(), String> {
+        let group_leader_pid = match self.processes.read().await.get(name) {
+            Some(info) => info.group_leader_pid,
+            None => return Err(format!("No managed process named '{}'", name)),
+        };
+
+        send_signal(group_leader_pid, signal)
+    }
+
+    /// Polls `pid` via sysinfo until it's gone or `grace_period` elapses.
+    /// Returns `true` if the process exited within the grace period.
+    async fn wait_for_exit(&self, pid: u32, grace_period: Duration) -> bool {
+        let pid_obj = sysinfo::Pid::from_u32(pid);
+        let deadline = tokio::time::Instant::now() + grace_period;
+        loop {
+            {
+                let mut system = self.system.write().await;
+                // Targeted refresh of just this PID; the returned count
+                // tells us whether it's still alive without a second
+                // `system.process(pid)` lookup.
+                let updated = system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid_obj]));
+                if updated == 0 {
+                    return true;
+                }
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                return false;
+            }
+            tokio::time::sleep(SHUTDOWN_POLL_INTERVAL).await;
+        }
+    }
     
-    /// Check health of a service by testing its ports/endpoints
+    /// Check health of a service by running each of `probes` against it.
     pub async fn check_service_health(
         &self,
         name: &str,
-        ports: &[u16],
+        probes: &[HealthProbe],
     ) -> HealthStatus {
-        use std::net::{TcpStream, SocketAddr};
-        use std::time::Duration;
-        
         let mut status = HealthStatus {
             healthy: true,
             last_check: chrono::Utc::now().timestamp(),
             error: None,
             details: HashMap::new(),
         };
-        
-        // Don't require the process to be in our managed list
-        // Just check if the ports are actually accessible
-        
-        // Check ports
-        for port in ports {
-            let addr_str = format!("127.0.0.1:{}", port);
-            status.details.insert(format!("port_{}", port), "checking".to_string());
-            
-            if let Ok(addr) = addr_str.parse::<SocketAddr>() {
-                match TcpStream::connect_timeout(&addr, Duration::from_millis(500)) {
-                    Ok(_) => {
-                        status.details.insert(format!("port_{}", port), "open".to_string());
-                    }
-                    Err(e) => {
-                        status.healthy = false;
-                        status.details.insert(format!("port_{}", port), "closed".to_string());
-                        status.error = Some(format!("Port {} not accessible: {}", port, e));
-                    }
+
+        for probe in probes {
+            match probe.run().await {
+                Ok(detail) => {
+                    status.details.insert(probe.detail_key(), detail);
+                }
+                Err(e) => {
+                    status.healthy = false;
+                    status.details.insert(probe.detail_key(), "failed".to_string());
+                    status.error = Some(e);
                 }
             }
         }
-        
+
+        // Flag a managed worker that died and left a zombie behind, even if
+        // its ports still appear open (e.g. a lingering listen socket).
+        if let Some(info) = self.processes.read().await.get(name) {
+            let pid_obj = sysinfo::Pid::from_u32(info.pid);
+            let mut system = self.system.write().await;
+            system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid_obj]));
+
+            if let Some(process) = system.process(pid_obj) {
+                if process.status() == sysinfo::ProcessStatus::Zombie {
+                    status.healthy = false;
+                    status.details.insert("zombie_pid".to_string(), info.pid.to_string());
+                    status.error = Some(format!(
+                        "Process '{}' (PID {}) is a zombie; its worker exited but hasn't been reaped",
+                        name, info.pid
+                    ));
+                }
+            }
+        }
+
         // Store health check result
         let mut health_checks = self.health_checks.write().await;
         health_checks.insert(name.to_string(), status.clone());
-        
+
         status
     }
     
-    /// Monitor and restart unhealthy services
-    pub async fn monitor_and_restart(&self, name: &str, restart_cmd: impl Fn() -> Result<(), String>) {
-        let health = self.check_service_health(name, &[5555, 5556, 5557]).await;
-        
-        if !health.healthy {
-            log::warn!("Service '{}' is unhealthy: {:?}", name, health.error);
-            
-            // Attempt restart
-            if let Err(e) = restart_cmd() {
-                log::error!("Failed to restart service '{}': {}", name, e);
+    /// Monitor and restart unhealthy services. A restart only fires once
+    /// `probes` have failed `consecutive_failure_threshold` times in a row,
+    /// so a single transient blip doesn't flap the service; the running
+    /// streak is recorded in the stored `HealthStatus::details` under
+    /// `"consecutive_failures"`.
+    pub async fn monitor_and_restart(
+        &self,
+        name: &str,
+        probes: &[HealthProbe],
+        consecutive_failure_threshold: u32,
+        restart_cmd: impl Fn() -> Result<(), String>,
+    ) {
+        let mut health = self.check_service_health(name, probes).await;
+
+        let streak = {
+            let mut streaks = self.failure_streaks.write().await;
+            let entry = streaks.entry(name.to_string()).or_insert(0);
+            if health.healthy {
+                *entry = 0;
             } else {
-                log::info!("Successfully restarted service '{}'", name);
+                *entry += 1;
             }
+            *entry
+        };
+
+        health.details.insert("consecutive_failures".to_string(), streak.to_string());
+        self.health_checks.write().await.insert(name.to_string(), health.clone());
+
+        if health.healthy || streak < consecutive_failure_threshold {
+            return;
+        }
+
+        log::warn!(
+            "Service '{}' failed {} consecutive health checks: {:?}",
+            name, streak, health.error
+        );
+
+        self.failure_streaks.write().await.insert(name.to_string(), 0);
+
+        if let Err(e) = restart_cmd() {
+            log::error!("Failed to restart service '{}': {}", name, e);
+        } else {
+            log::info!("Successfully restarted service '{}'", name);
         }
     }
     
@@ -295,12 +942,15 @@ impl ProcessManager {
         
         #[cfg(not(target_os = "macos"))]
         {
+            let pid_obj = sysinfo::Pid::from_u32(pid);
             let mut system = self.system.write().await;
-            if let Some(process) = system.process(sysinfo::Pid::from_u32(pid)) {
-                process.kill();
-            } else {
+            let updated = system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid_obj]));
+            if updated == 0 {
                 return Err(format!("Process {} not found", pid));
             }
+            if let Some(process) = system.process(pid_obj) {
+                process.kill();
+            }
         }
         
         Ok(())
@@ -308,18 +958,28 @@ impl ProcessManager {
     
     /// Get current status of all managed processes
     pub async fn get_all_status(&self) -> HashMap<String, ProcessInfo> {
+        let tracked_pids: Vec<sysinfo::Pid> = {
+            let processes = self.processes.read().await;
+            processes.values().map(|info| sysinfo::Pid::from_u32(info.pid)).collect()
+        };
+
         let mut system = self.system.write().await;
-        system.refresh_all();
-        
+        // Targeted refresh of exactly the PIDs we manage, instead of every
+        // process on the host plus CPU/memory/disk/network globals.
+        system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&tracked_pids));
+
         let mut processes = self.processes.write().await;
-        
+
         // Update process info with current stats
         for (_name, info) in processes.iter_mut() {
             if let Some(process) = system.process(sysinfo::Pid::from_u32(info.pid)) {
                 info.memory_mb = process.memory() as f32 / 1024.0;
                 info.cpu_percent = process.cpu_usage();
-                
-                // Update children list
+
+                // Child discovery reads whatever's already cached in the
+                // process table rather than forcing a full scan here; it
+                // reflects the last `kill_all_matching` (or other full-scan)
+                // call rather than always being perfectly fresh.
                 info.children = Self::get_child_processes(&system, sysinfo::Pid::from_u32(info.pid))
                     .iter()
                     .map(|p| p.as_u32())
@@ -388,10 +1048,33 @@ impl ProcessManager {
     
     /// Get stats for a specific process by PID
     pub async fn get_process_stats(&self, pid: u32) -> Result<ProcessInfo, String> {
-        let mut system = self.system.write().await;
-        system.refresh_all();
-        
         let pid_obj = sysinfo::Pid::from_u32(pid);
+
+        // Reuse whichever children we already know about (from a prior
+        // `get_all_status`/`get_process_stats` call tracking this same PID)
+        // so the whole tree can be refreshed in one targeted call instead of
+        // scanning every process on the host to rediscover parent/child
+        // links we likely already have.
+        // Not necessarily a managed process group leader when queried by raw
+        // PID; reuse whatever we already track under this PID, otherwise
+        // assume it's its own leader.
+        let (known_children, group_leader_pid): (Vec<u32>, u32) = {
+            let processes = self.processes.read().await;
+            match processes.values().find(|info| info.pid == pid) {
+                Some(info) => (info.children.clone(), info.group_leader_pid),
+                None => (Vec::new(), pid),
+            }
+        };
+
+        let mut refresh_targets = vec![pid_obj];
+        refresh_targets.extend(known_children.iter().map(|p| sysinfo::Pid::from_u32(*p)));
+
+        let mut system = self.system.write().await;
+        let updated = system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&refresh_targets));
+        if updated == 0 {
+            return Err(format!("Process with PID {} not found", pid));
+        }
+
         if let Some(process) = system.process(pid_obj) {
             // Get child processes
             let child_pids = Self::get_child_processes(&system, pid_obj);
@@ -431,6 +1114,8 @@ impl ProcessManager {
                     .iter()
                     .map(|p| p.as_u32())
                     .collect(),
+                exit_status: None,
+                group_leader_pid,
             };
             Ok(info)
         } else {
@@ -438,10 +1123,23 @@ impl ProcessManager {
         }
     }
     
-    /// Clean up all zombie processes
+    /// Drain every managed child that has exited since the last call and
+    /// return how many were reaped. Proactively reaps before draining (in
+    /// case a SIGCHLD delivery was missed or coalesced) rather than relying
+    /// solely on the background reaper, so callers get an accurate count on
+    /// demand.
+    #[cfg(unix)]
+    pub async fn cleanup_zombies(&self) -> Result<u32, String> {
+        Self::reap_spawned_children(&self.spawned_pids, &self.reaped, &self.processes).await;
+
+        let mut reaped = self.reaped.write().await;
+        let count = reaped.len() as u32;
+        reaped.clear();
+        Ok(count)
+    }
+
+    #[cfg(not(unix))]
     pub async fn cleanup_zombies(&self) -> Result<u32, String> {
-        // TODO: Implement actual zombie detection for sysinfo 0.30
-        // This requires platform-specific code
         Ok(0)
     }
 }
@@ -459,7 +1157,9 @@ mod tests {
         println!("Killed {} processes", killed.len());
         
         // Test health check
-        let health = manager.check_service_health("test", &[5555]).await;
+        let health = manager
+            .check_service_health("test", &[HealthProbe::TcpConnect { port: 5555 }])
+            .await;
         assert!(!health.healthy); // Should fail since no service running
     }
 }
\ No newline at end of file
@@ -2,10 +2,28 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use serde::{Serialize, Deserialize};
-use crate::settings::ExternalServiceConfig;
+use tokio::sync::mpsc;
+use crate::settings::{ExternalServiceConfig, RestartPolicy, TranscriberConfigFormat};
 
 const SERVICE_LABEL: &str = "com.scout.transcriber";
 const PID_FILE: &str = "/tmp/transcriber.pid";
+const TRANSCRIBER_LOG_PATH: &str = "/tmp/transcriber.log";
+const TRANSCRIBER_ERROR_LOG_PATH: &str = "/tmp/transcriber.error.log";
+const LOG_POLL_INTERVAL_MS: u64 = 300;
+
+/// Env var that overrides where the transcriber config file is read from
+/// and written to, instead of the default per-OS app-data directory.
+/// Checked directly, and also set by [`ServiceManager::write_config`] when
+/// `ExternalServiceConfig.config_path_override` is provided, so every other
+/// method that needs the config's location (e.g. reading it back to learn
+/// the ZeroMQ ports) picks up the same override without it being threaded
+/// through every call.
+const CONFIG_PATH_ENV_VAR: &str = "SCOUT_TRANSCRIBER_CONFIG";
+
+/// Protocol version Scout speaks when pinging the transcriber's control
+/// port. Bump this whenever the ping/pong message shape changes in a way
+/// that isn't backwards compatible.
+const TRANSCRIBER_PROTOCOL_VERSION: u32 = 1;
 
 #[derive(Debug, Serialize)]
 pub struct ServiceStatus {
@@ -13,6 +31,66 @@ pub struct ServiceStatus {
     pub pid: Option<u32>,
     pub healthy: bool,
     pub error: Option<String>,
+    /// Protocol version the transcriber reported in its control-port pong,
+    /// if a handshake completed.
+    pub protocol_version: Option<u32>,
+    /// Model name the transcriber reported it has loaded.
+    pub model: Option<String>,
+}
+
+/// Machine-readable progress events emitted by [`ServiceManager::start_service`],
+/// in the order they occur, so a caller (the Tauri command layer, a test) can
+/// render live progress and tell success from a degraded/failed state
+/// programmatically instead of pattern-matching a joined log string.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ServiceEvent {
+    /// The transcriber config file was written to `path`.
+    ConfigWritten { path: String },
+    /// The OS-specific service descriptor (plist/systemd unit) was installed.
+    PlistInstalled,
+    /// The service was confirmed running, with the PID the OS reports (if any).
+    Started { pid: Option<u32> },
+    /// The ZeroMQ handshake succeeded; the service is healthy.
+    HealthOk,
+    /// The service is running but the ZeroMQ handshake failed or reported
+    /// an incompatible protocol version.
+    HealthDegraded { error: Option<String> },
+    /// The post-start smoke-test transcription succeeded.
+    TranscriptionTestOk { result: String },
+    /// The post-start smoke-test transcription failed.
+    TranscriptionTestFailed { error: String },
+    /// The service did not come up; `error` carries the crash reason if one
+    /// could be read from the error log.
+    NotRunning { error: Option<String> },
+}
+
+impl ServiceEvent {
+    /// Renders a single event as the `✓`/`⚠`-prefixed line the old joined
+    /// log string used, for callers that still want a human summary.
+    pub fn summary(&self) -> String {
+        match self {
+            Self::ConfigWritten { path } => format!("✓ Wrote config to {}", path),
+            Self::PlistInstalled => "✓ Installed service".to_string(),
+            Self::Started { pid } => format!(
+                "✓ Service running (PID: {})",
+                pid.map_or("unknown".to_string(), |p| p.to_string())
+            ),
+            Self::HealthOk => "✓ All ZeroMQ ports responding".to_string(),
+            Self::HealthDegraded { error } => match error {
+                Some(error) => format!("⚠ Service running but ports not responding\n  Error: {}", error),
+                None => "⚠ Service running but ports not responding".to_string(),
+            },
+            Self::TranscriptionTestOk { result } => {
+                format!("✓ Transcription test successful: \"{}\"", result)
+            }
+            Self::TranscriptionTestFailed { error } => format!("⚠ Transcription test failed: {}", error),
+            Self::NotRunning { error } => match error {
+                Some(error) => format!("⚠ Service not running - {}", error),
+                None => "⚠ Service not running - check /tmp/transcriber.error.log".to_string(),
+            },
+        }
+    }
 }
 
 /// Transcriber service configuration that gets written to JSON file
@@ -39,56 +117,162 @@ impl From<&ExternalServiceConfig> for TranscriberConfig {
     }
 }
 
-pub struct ServiceManager;
+/// Raw running/pid state as reported by the OS's own service manager, before
+/// [`ServiceManager::check_status`] layers the ZeroMQ port health check on
+/// top - a backend only knows whether its service unit is up, not whether
+/// the transcriber inside it is actually responding.
+struct BackendStatus {
+    running: bool,
+    pid: Option<u32>,
+}
 
-impl ServiceManager {
-    /// Get the path to the transcriber config directory
-    fn config_dir() -> PathBuf {
+/// Result of [`ServiceManager::zmq_control_handshake`]: whether the
+/// transcriber answered the ping with a well-formed, compatible pong.
+struct ZmqHandshake {
+    healthy: bool,
+    protocol_version: Option<u32>,
+    model: Option<String>,
+    error: Option<String>,
+}
+
+impl ZmqHandshake {
+    fn unhealthy(error: String) -> Self {
+        Self {
+            healthy: false,
+            protocol_version: None,
+            model: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// OS-specific half of service lifecycle management: registering the
+/// transcriber as a service the OS will track and drive (`install`), and
+/// starting/stopping/querying that registration. Everything that doesn't
+/// depend on the OS's service manager (writing the transcriber's own JSON
+/// config, the ZeroMQ health check, the smoke-test transcription) lives in
+/// [`ServiceManager`] instead and is shared by every backend.
+trait ServiceBackend {
+    /// Writes the OS-specific service descriptor (plist, systemd unit, ...)
+    /// and registers it with the OS's service manager.
+    fn install(&self, config: &ExternalServiceConfig) -> Result<(), String>;
+    /// Starts the installed service.
+    fn start(&self) -> Result<(), String>;
+    /// Stops the running service.
+    fn stop(&self) -> Result<(), String>;
+    /// Queries the OS's service manager for the service's running/pid state.
+    fn status(&self) -> BackendStatus;
+
+    /// Streams the transcriber's stdout/stderr as they're written. When
+    /// `follow` is true, lines already in the log are skipped and only new
+    /// lines appearing from now on are sent; when false, the log's current
+    /// contents are sent once and the stream ends.
+    ///
+    /// Default implementation poll-tails the flat files launchd/the Windows
+    /// backend redirect stdout/stderr to. [`SystemdBackend`] overrides this
+    /// to stream from `journalctl` instead, since systemd captures unit
+    /// output there rather than in these files.
+    fn tail_logs(&self, follow: bool) -> mpsc::Receiver<String> {
+        let (tx, rx) = mpsc::channel(256);
+        tokio::spawn(tail_file(PathBuf::from(TRANSCRIBER_LOG_PATH), "out", follow, tx.clone()));
+        tokio::spawn(tail_file(PathBuf::from(TRANSCRIBER_ERROR_LOG_PATH), "err", follow, tx));
+        rx
+    }
+}
+
+/// Poll-tails a single log file, sending `"[label] <line>"` for each
+/// complete line over `tx`. When `follow` is true, starts from the current
+/// end of the file (so only lines written after this call are sent) and
+/// keeps polling every [`LOG_POLL_INTERVAL_MS`]; when false, sends the
+/// file's current contents once and returns. A file size smaller than the
+/// last recorded offset is treated as truncation/rotation and restarts the
+/// read from the beginning.
+async fn tail_file(path: PathBuf, label: &'static str, follow: bool, tx: mpsc::Sender<String>) {
+    use tokio::io::{AsyncReadExt, AsyncSeekExt, SeekFrom};
+    use std::time::Duration;
+
+    let mut offset: u64 = if follow {
+        tokio::fs::metadata(&path).await.map(|m| m.len()).unwrap_or(0)
+    } else {
+        0
+    };
+    let mut pending = String::new();
+
+    loop {
+        let len = match tokio::fs::metadata(&path).await {
+            Ok(metadata) => metadata.len(),
+            Err(_) => {
+                if !follow {
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(LOG_POLL_INTERVAL_MS)).await;
+                continue;
+            }
+        };
+
+        if len < offset {
+            // The file shrank - it was truncated or rotated. Start over.
+            offset = 0;
+            pending.clear();
+        }
+
+        if len > offset {
+            if let Ok(mut file) = tokio::fs::File::open(&path).await {
+                if file.seek(SeekFrom::Start(offset)).await.is_ok() {
+                    let mut buf = Vec::new();
+                    if file.read_to_end(&mut buf).await.is_ok() {
+                        offset += buf.len() as u64;
+                        pending.push_str(&String::from_utf8_lossy(&buf));
+
+                        while let Some(pos) = pending.find('\n') {
+                            let line = pending[..pos].trim_end_matches('\r').to_string();
+                            pending.drain(..=pos);
+                            if tx.send(format!("[{}] {}", label, line)).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if !follow {
+            return;
+        }
+
+        tokio::time::sleep(Duration::from_millis(LOG_POLL_INTERVAL_MS)).await;
+    }
+}
+
+/// macOS backend: drives `launchd` via a per-user `LaunchAgents` plist and
+/// `launchctl load/start/stop`.
+struct LaunchdBackend;
+
+impl LaunchdBackend {
+    /// Get the path to the launchd plist file
+    fn plist_path() -> PathBuf {
         let home = std::env::var("HOME").unwrap_or_else(|_| "/tmp".to_string());
         PathBuf::from(home)
             .join("Library")
-            .join("Application Support")
-            .join("com.scout.transcriber")
-    }
-    
-    /// Get the path to the transcriber config file
-    fn config_path() -> PathBuf {
-        Self::config_dir().join("config.json")
-    }
-    
-    /// Write the transcriber configuration to a JSON file
-    fn write_config(config: &ExternalServiceConfig) -> Result<(), String> {
-        let config_dir = Self::config_dir();
-        let config_path = Self::config_path();
-        
-        // Ensure the directory exists
-        fs::create_dir_all(&config_dir)
-            .map_err(|e| format!("Failed to create config directory: {}", e))?;
-        
-        // Convert to transcriber config format
-        let transcriber_config = TranscriberConfig::from(config);
-        
-        // Write the config as JSON
-        let json = serde_json::to_string_pretty(&transcriber_config)
-            .map_err(|e| format!("Failed to serialize config: {}", e))?;
-        
-        fs::write(&config_path, json)
-            .map_err(|e| format!("Failed to write config file: {}", e))?;
-        
-        Ok(())
+            .join("LaunchAgents")
+            .join(format!("{}.plist", SERVICE_LABEL))
     }
-    
+
     /// Generate launchd plist content for the transcriber service
     fn generate_plist(config: &ExternalServiceConfig) -> String {
         // Use transcriber as the binary name, with full path
         let binary_path = config.binary_path.as_ref()
             .map(|p| p.to_string())
             .unwrap_or_else(|| "/usr/local/bin/transcriber".to_string());
-        
+
         // Simple plist - just run the transcriber binary
         // It will load its config from the default location
         let program_args = format!("        <string>{}</string>", binary_path);
-        
+        let keep_alive = Self::keep_alive_stanza(config.restart_policy);
+        let working_dir = ServiceManager::resolve_working_dir(config);
+        let home = std::env::var("HOME").unwrap_or_else(|_| "/tmp".to_string());
+        let path_env = format!("{}/.local/bin:/usr/local/bin:/usr/bin:/bin:/usr/sbin:/sbin", home);
+
         format!(r#"<?xml version="1.0" encoding="UTF-8"?>
 <!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
 <plist version="1.0">
@@ -102,136 +286,564 @@ impl ServiceManager {
     <key>RunAtLoad</key>
     <false/>
     <key>KeepAlive</key>
-    <false/>
+    {}
+    <key>ThrottleInterval</key>
+    <integer>{}</integer>
     <key>StandardOutPath</key>
     <string>/tmp/transcriber.log</string>
     <key>StandardErrorPath</key>
     <string>/tmp/transcriber.error.log</string>
     <key>WorkingDirectory</key>
-    <string>/Users/arach/dev/scout/transcriber</string>
+    <string>{}</string>
     <key>EnvironmentVariables</key>
     <dict>
         <key>PATH</key>
-        <string>/Users/arach/.local/bin:/usr/local/bin:/usr/bin:/bin:/usr/sbin:/sbin</string>
+        <string>{}</string>
     </dict>
 </dict>
-</plist>"#, SERVICE_LABEL, program_args)
+</plist>"#, SERVICE_LABEL, program_args, keep_alive, config.throttle_seconds, working_dir.display(), path_env)
     }
-    
-    /// Get the path to the launchd plist file
-    fn plist_path() -> PathBuf {
-        let home = std::env::var("HOME").unwrap_or_else(|_| "/tmp".to_string());
-        PathBuf::from(home)
-            .join("Library")
-            .join("LaunchAgents")
-            .join(format!("{}.plist", SERVICE_LABEL))
+
+    /// Maps a [`RestartPolicy`] to launchd's `KeepAlive` stanza: a bare
+    /// boolean for `never`/`always`, or a `SuccessfulExit`/`Crashed`
+    /// dictionary for `on_failure` so launchd only restarts a crash, not a
+    /// clean exit.
+    fn keep_alive_stanza(policy: RestartPolicy) -> String {
+        match policy {
+            RestartPolicy::Never => "<false/>".to_string(),
+            RestartPolicy::Always => "<true/>".to_string(),
+            RestartPolicy::OnFailure => r#"<dict>
+        <key>SuccessfulExit</key>
+        <false/>
+        <key>Crashed</key>
+        <true/>
+    </dict>"#
+                .to_string(),
+        }
     }
-    
-    /// Start the transcriber service using launchctl
-    pub async fn start_service(config: &ExternalServiceConfig) -> Result<String, String> {
+}
+
+impl ServiceBackend for LaunchdBackend {
+    fn install(&self, config: &ExternalServiceConfig) -> Result<(), String> {
         let plist_path = Self::plist_path();
-        let config_path = Self::config_path();
-        let mut output_log = Vec::new();
-        
-        // Write the config file
-        Self::write_config(config)?;
-        output_log.push(format!("✓ Wrote config to {}", config_path.display()));
-        
+
         // Ensure LaunchAgents directory exists
         if let Some(parent) = plist_path.parent() {
             fs::create_dir_all(parent)
                 .map_err(|e| format!("Failed to create LaunchAgents directory: {}", e))?;
         }
-        
+
         // Write the plist file
         let plist_content = Self::generate_plist(config);
         fs::write(&plist_path, plist_content)
             .map_err(|e| format!("Failed to write plist file: {}", e))?;
-        output_log.push(format!("✓ Created launchd plist"));
-        
-        // Silently unload if already loaded
-        let _ = Command::new("launchctl")
-            .arg("unload")
-            .arg(&plist_path)
-            .output();
-        
-        // Load the service
+
+        // Silently unload if already loaded, so a reinstall with a new
+        // binary path isn't rejected as a duplicate.
+        let _ = Command::new("launchctl").arg("unload").arg(&plist_path).output();
+
         let output = Command::new("launchctl")
             .arg("load")
             .arg(&plist_path)
             .output()
             .map_err(|e| format!("Failed to run launchctl: {}", e))?;
-        
+
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
             return Err(format!("Failed to load service: {}", stderr.trim()));
         }
-        
-        // Start the service
-        let _ = Command::new("launchctl")
+
+        Ok(())
+    }
+
+    fn start(&self) -> Result<(), String> {
+        let output = Command::new("launchctl")
             .arg("start")
             .arg(SERVICE_LABEL)
+            .output()
+            .map_err(|e| format!("Failed to run launchctl: {}", e))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Failed to start service: {}", stderr.trim()));
+        }
+
+        Ok(())
+    }
+
+    fn stop(&self) -> Result<(), String> {
+        let plist_path = Self::plist_path();
+
+        let _ = Command::new("launchctl").arg("stop").arg(SERVICE_LABEL).output();
+
+        let output = Command::new("launchctl")
+            .arg("unload")
+            .arg(&plist_path)
+            .output()
+            .map_err(|e| format!("Failed to run launchctl: {}", e))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            // Ignore "not loaded" errors
+            if !stderr.contains("Could not find specified service") && !stderr.contains("No such file") {
+                return Err(format!("Failed to unload service: {}", stderr));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn status(&self) -> BackendStatus {
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(format!("launchctl list | grep {}", SERVICE_LABEL))
+            .output();
+
+        let mut status = BackendStatus { running: false, pid: None };
+
+        if let Ok(output) = output {
+            if output.status.success() {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                // Parse output: "PID Status Label" or "- Status Label" if not running
+                let parts: Vec<&str> = stdout.split_whitespace().collect();
+                if parts.len() >= 2 {
+                    if let Ok(pid) = parts[0].parse::<u32>() {
+                        status.pid = Some(pid);
+                        status.running = true;
+                    }
+                }
+            }
+        }
+
+        status
+    }
+}
+
+/// Linux backend: drives a per-user `systemd` unit via `systemctl --user`.
+struct SystemdBackend;
+
+const SYSTEMD_UNIT_NAME: &str = "scout-transcriber";
+
+impl SystemdBackend {
+    fn unit_path() -> PathBuf {
+        let home = std::env::var("HOME").unwrap_or_else(|_| "/tmp".to_string());
+        PathBuf::from(home)
+            .join(".config")
+            .join("systemd")
+            .join("user")
+            .join(format!("{}.service", SYSTEMD_UNIT_NAME))
+    }
+
+    fn generate_unit(config: &ExternalServiceConfig) -> String {
+        let binary_path = config.binary_path.as_ref()
+            .map(|p| p.to_string())
+            .unwrap_or_else(|| "/usr/local/bin/transcriber".to_string());
+
+        let restart = match config.restart_policy {
+            RestartPolicy::Never => "no",
+            RestartPolicy::OnFailure => "on-failure",
+            RestartPolicy::Always => "always",
+        };
+
+        format!(
+            r#"[Unit]
+Description=Scout transcriber service
+
+[Service]
+ExecStart={}
+StandardOutput=append:/tmp/transcriber.log
+StandardError=append:/tmp/transcriber.error.log
+Restart={}
+RestartSec={}
+
+[Install]
+WantedBy=default.target
+"#,
+            binary_path, restart, config.throttle_seconds
+        )
+    }
+}
+
+impl ServiceBackend for SystemdBackend {
+    fn install(&self, config: &ExternalServiceConfig) -> Result<(), String> {
+        let unit_path = Self::unit_path();
+
+        if let Some(parent) = unit_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create systemd user unit directory: {}", e))?;
+        }
+
+        fs::write(&unit_path, Self::generate_unit(config))
+            .map_err(|e| format!("Failed to write systemd unit file: {}", e))?;
+
+        let output = Command::new("systemctl")
+            .args(["--user", "daemon-reload"])
+            .output()
+            .map_err(|e| format!("Failed to run systemctl daemon-reload: {}", e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "systemctl daemon-reload failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let output = Command::new("systemctl")
+            .args(["--user", "enable", SYSTEMD_UNIT_NAME])
+            .output()
+            .map_err(|e| format!("Failed to run systemctl enable: {}", e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "systemctl enable failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn start(&self) -> Result<(), String> {
+        let output = Command::new("systemctl")
+            .args(["--user", "start", SYSTEMD_UNIT_NAME])
+            .output()
+            .map_err(|e| format!("Failed to run systemctl start: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Failed to start service: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn stop(&self) -> Result<(), String> {
+        let output = Command::new("systemctl")
+            .args(["--user", "stop", SYSTEMD_UNIT_NAME])
+            .output()
+            .map_err(|e| format!("Failed to run systemctl stop: {}", e))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if !stderr.contains("not loaded") && !stderr.contains("not found") {
+                return Err(format!("Failed to stop service: {}", stderr.trim()));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn status(&self) -> BackendStatus {
+        let output = Command::new("systemctl")
+            .args(["--user", "show", SYSTEMD_UNIT_NAME, "-p", "MainPID,ActiveState"])
             .output();
-        
-        output_log.push(format!("✓ Started transcriber service"));
-        
+
+        let Ok(output) = output else {
+            return BackendStatus { running: false, pid: None };
+        };
+        if !output.status.success() {
+            return BackendStatus { running: false, pid: None };
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut active_state = None;
+        let mut pid = None;
+        for line in stdout.lines() {
+            if let Some(value) = line.strip_prefix("MainPID=") {
+                pid = value.trim().parse::<u32>().ok().filter(|&p| p != 0);
+            } else if let Some(value) = line.strip_prefix("ActiveState=") {
+                active_state = Some(value.trim().to_string());
+            }
+        }
+
+        BackendStatus {
+            running: active_state.as_deref() == Some("active") && pid.is_some(),
+            pid,
+        }
+    }
+
+    fn tail_logs(&self, follow: bool) -> mpsc::Receiver<String> {
+        use tokio::io::{AsyncBufReadExt, BufReader};
+
+        let (tx, rx) = mpsc::channel(256);
+
+        let mut args = vec!["--user".to_string(), "-u".to_string(), SYSTEMD_UNIT_NAME.to_string()];
+        if follow {
+            args.push("-f".to_string());
+        } else {
+            args.push("--no-pager".to_string());
+        }
+
+        tokio::spawn(async move {
+            let mut child = match tokio::process::Command::new("journalctl")
+                .args(&args)
+                .stdout(std::process::Stdio::piped())
+                .spawn()
+            {
+                Ok(child) => child,
+                Err(_) => return,
+            };
+
+            if let Some(stdout) = child.stdout.take() {
+                let mut lines = BufReader::new(stdout).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if tx.send(line).await.is_err() {
+                        break;
+                    }
+                }
+            }
+
+            let _ = child.wait().await;
+        });
+
+        rx
+    }
+}
+
+/// Windows backend: registers the transcriber as a Windows service via the
+/// built-in `sc.exe` tool.
+struct WindowsBackend;
+
+const WINDOWS_SERVICE_NAME: &str = "ScoutTranscriber";
+
+impl ServiceBackend for WindowsBackend {
+    fn install(&self, config: &ExternalServiceConfig) -> Result<(), String> {
+        let binary_path = config.binary_path.as_ref()
+            .map(|p| p.to_string())
+            .unwrap_or_else(|| "C:\\Program Files\\Scout\\transcriber.exe".to_string());
+
+        // Remove any previous registration so reinstalling with a new
+        // binary path isn't rejected as a duplicate.
+        let _ = Command::new("sc").args(["delete", WINDOWS_SERVICE_NAME]).output();
+
+        let bin_path_arg = format!("binPath={}", binary_path);
+        let output = Command::new("sc")
+            .args([
+                "create",
+                WINDOWS_SERVICE_NAME,
+                bin_path_arg.as_str(),
+                "start=",
+                "demand",
+            ])
+            .output()
+            .map_err(|e| format!("Failed to run sc create: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Failed to register Windows service: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn start(&self) -> Result<(), String> {
+        let output = Command::new("sc")
+            .args(["start", WINDOWS_SERVICE_NAME])
+            .output()
+            .map_err(|e| format!("Failed to run sc start: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Failed to start service: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn stop(&self) -> Result<(), String> {
+        let output = Command::new("sc")
+            .args(["stop", WINDOWS_SERVICE_NAME])
+            .output()
+            .map_err(|e| format!("Failed to run sc stop: {}", e))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            // Ignore "service does not exist" / "not started" errors
+            if !stderr.contains("1062") && !stderr.contains("does not exist") {
+                return Err(format!("Failed to stop service: {}", stderr.trim()));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn status(&self) -> BackendStatus {
+        let output = Command::new("sc").args(["query", WINDOWS_SERVICE_NAME]).output();
+
+        let Ok(output) = output else {
+            return BackendStatus { running: false, pid: None };
+        };
+        if !output.status.success() {
+            return BackendStatus { running: false, pid: None };
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        // `sc query` reports state as text (e.g. "4  RUNNING") but not a PID;
+        // getting that requires a separate WMI/tasklist lookup, which isn't
+        // worth the complexity just for a presence check.
+        BackendStatus {
+            running: stdout.contains("RUNNING"),
+            pid: None,
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+type ActiveBackend = LaunchdBackend;
+#[cfg(target_os = "linux")]
+type ActiveBackend = SystemdBackend;
+#[cfg(target_os = "windows")]
+type ActiveBackend = WindowsBackend;
+
+pub struct ServiceManager;
+
+impl ServiceManager {
+    /// Default directory for the transcriber config file, used when neither
+    /// [`CONFIG_PATH_ENV_VAR`] nor `ExternalServiceConfig.config_path_override`
+    /// is set.
+    fn default_config_dir() -> PathBuf {
+        dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("/tmp"))
+            .join("com.scout.transcriber")
+    }
+
+    /// Get the path to the transcriber config file: `config_path_override`
+    /// when [`write_config`](Self::write_config) was last called with one
+    /// (recorded via [`CONFIG_PATH_ENV_VAR`]), then that env var set
+    /// directly, then the default per-OS app-data path.
+    fn config_path() -> PathBuf {
+        if let Ok(path) = std::env::var(CONFIG_PATH_ENV_VAR) {
+            return PathBuf::from(path);
+        }
+        Self::default_config_dir().join(format!("config.{}", TranscriberConfigFormat::Json.extension()))
+    }
+
+    /// Write the transcriber configuration, in JSON, YAML, or TOML
+    /// depending on `config.config_format` (or the resolved path's
+    /// extension if unset), to the resolved config path.
+    fn write_config(config: &ExternalServiceConfig) -> Result<(), String> {
+        if let Some(path) = &config.config_path_override {
+            std::env::set_var(CONFIG_PATH_ENV_VAR, path);
+        }
+
+        let config_path = Self::config_path();
+        if let Some(parent) = config_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        }
+
+        let transcriber_config = TranscriberConfig::from(config);
+        let format = config
+            .config_format
+            .unwrap_or_else(|| TranscriberConfigFormat::from_extension(&config_path));
+        let serialized = Self::serialize_config(&transcriber_config, format)?;
+
+        fs::write(&config_path, serialized)
+            .map_err(|e| format!("Failed to write config file: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Serializes a `TranscriberConfig` in the given format.
+    fn serialize_config(config: &TranscriberConfig, format: TranscriberConfigFormat) -> Result<String, String> {
+        match format {
+            TranscriberConfigFormat::Json => serde_json::to_string_pretty(config)
+                .map_err(|e| format!("Failed to serialize config as JSON: {}", e)),
+            TranscriberConfigFormat::Yaml => serde_yaml::to_string(config)
+                .map_err(|e| format!("Failed to serialize config as YAML: {}", e)),
+            TranscriberConfigFormat::Toml => toml::to_string_pretty(config)
+                .map_err(|e| format!("Failed to serialize config as TOML: {}", e)),
+        }
+    }
+
+    /// Deserializes a `TranscriberConfig` in the given format.
+    fn deserialize_config(contents: &str, format: TranscriberConfigFormat) -> Result<TranscriberConfig, String> {
+        match format {
+            TranscriberConfigFormat::Json => serde_json::from_str(contents)
+                .map_err(|e| format!("Failed to parse config as JSON: {}", e)),
+            TranscriberConfigFormat::Yaml => serde_yaml::from_str(contents)
+                .map_err(|e| format!("Failed to parse config as YAML: {}", e)),
+            TranscriberConfigFormat::Toml => toml::from_str(contents)
+                .map_err(|e| format!("Failed to parse config as TOML: {}", e)),
+        }
+    }
+
+    /// Start the transcriber service via the active platform backend
+    /// ([`LaunchdBackend`] on macOS, [`SystemdBackend`] on Linux,
+    /// [`WindowsBackend`] on Windows).
+    pub async fn start_service(config: &ExternalServiceConfig) -> Result<Vec<ServiceEvent>, String> {
+        let config_path = Self::config_path();
+        let mut events = Vec::new();
+        let backend = ActiveBackend;
+
+        // Write the config file
+        Self::write_config(config)?;
+        events.push(ServiceEvent::ConfigWritten { path: config_path.display().to_string() });
+
+        backend.install(config)?;
+        events.push(ServiceEvent::PlistInstalled);
+
+        backend.start()?;
+
         // Wait a moment for the service to initialize
         tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
-        
+
         // Check the actual status
         let status = Self::check_status().await;
         if status.running {
-            output_log.push(format!("✓ Service running (PID: {})", 
-                status.pid.map_or("unknown".to_string(), |p| p.to_string())));
-            
+            events.push(ServiceEvent::Started { pid: status.pid });
+
             if status.healthy {
-                output_log.push(format!("✓ All ZeroMQ ports responding"));
-                
-                // Run a quick transcription test
-                output_log.push("Running transcription test...".to_string());
-                match Self::run_transcription_test().await {
-                    Ok(result) => {
-                        output_log.push(format!("✓ Transcription test successful: \"{}\"", result));
-                    }
-                    Err(e) => {
-                        output_log.push(format!("⚠ Transcription test failed: {}", e));
-                    }
+                events.push(ServiceEvent::HealthOk);
+
+                let working_dir = Self::resolve_working_dir(config);
+                match Self::run_transcription_test(&working_dir).await {
+                    Ok(result) => events.push(ServiceEvent::TranscriptionTestOk { result }),
+                    Err(error) => events.push(ServiceEvent::TranscriptionTestFailed { error }),
                 }
             } else {
-                output_log.push(format!("⚠ Service running but ports not responding"));
-                if let Some(error) = status.error {
-                    output_log.push(format!("  Error: {}", error));
-                }
+                events.push(ServiceEvent::HealthDegraded { error: status.error });
             }
         } else {
-            output_log.push(format!("⚠ Service not running - check /tmp/transcriber.error.log"));
+            events.push(ServiceEvent::NotRunning { error: status.error });
         }
-        
-        Ok(output_log.join("\n"))
+
+        Ok(events)
     }
-    
+
+    /// Serializes `start_service`'s events as a JSON array, so the Tauri
+    /// command layer can hand them straight to the frontend instead of a
+    /// joined human-readable string.
+    pub fn events_to_json(events: &[ServiceEvent]) -> Result<String, String> {
+        serde_json::to_string(events).map_err(|e| format!("Failed to serialize service events: {}", e))
+    }
+
     /// Run a quick transcription test using the test_audio.py script
-    async fn run_transcription_test() -> Result<String, String> {
+    async fn run_transcription_test(working_dir: &Path) -> Result<String, String> {
         use tokio::time::{timeout, Duration};
-        
+
         // Check if test_audio.py exists
-        let test_script = PathBuf::from("/Users/arach/dev/scout/transcriber/test_audio.py");
+        let test_script = working_dir.join("test_audio.py");
         if !test_script.exists() {
-            return Err("test_audio.py not found".to_string());
+            return Err(format!("test_audio.py not found in {}", working_dir.display()));
         }
-        
+
         // Run the test script with timeout
         let output_future = tokio::process::Command::new("uv")
             .arg("run")
             .arg("test_audio.py")
-            .current_dir("/Users/arach/dev/scout/transcriber")
+            .current_dir(working_dir)
             .output();
-            
+
         let output = timeout(Duration::from_secs(10), output_future)
             .await
             .map_err(|_| "Test timed out after 10 seconds".to_string())?
             .map_err(|e| format!("Failed to run test: {}", e))?;
-        
+
         if output.status.success() {
             let stdout = String::from_utf8_lossy(&output.stdout);
             // Extract the transcription result from the output
@@ -246,140 +858,234 @@ impl ServiceManager {
             Err(format!("Test failed: {}", stderr.trim()))
         }
     }
-    
-    /// Stop the transcriber service using launchctl
+
+    /// Stop the transcriber service via the active platform backend
     pub async fn stop_service() -> Result<(), String> {
-        let plist_path = Self::plist_path();
-        
-        // Stop the service
-        let _ = Command::new("launchctl")
-            .arg("stop")
-            .arg(SERVICE_LABEL)
-            .output();
-        
-        // Unload the service
-        let output = Command::new("launchctl")
-            .arg("unload")
-            .arg(&plist_path)
-            .output()
-            .map_err(|e| format!("Failed to run launchctl: {}", e))?;
-        
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            // Ignore "not loaded" errors
-            if !stderr.contains("Could not find specified service") && !stderr.contains("No such file") {
-                return Err(format!("Failed to unload service: {}", stderr));
-            }
-        }
-        
+        ActiveBackend.stop()?;
+
         // Clean up PID file if it exists
         let _ = fs::remove_file(PID_FILE);
-        
+
         Ok(())
     }
-    
-    /// Check if the service is running and get its status
+
+    /// Stream the transcriber's stdout/stderr via the active platform
+    /// backend. With `follow` true, only lines written from now on are
+    /// sent; with `follow` false, the log's current contents are sent once
+    /// and the returned channel then closes.
+    pub fn tail_logs(follow: bool) -> mpsc::Receiver<String> {
+        ActiveBackend.tail_logs(follow)
+    }
+
+    /// Check if the service is running and get its status, via the active
+    /// platform backend plus the (platform-independent) ZeroMQ handshake
     pub async fn check_status() -> ServiceStatus {
+        let BackendStatus { running, pid } = ActiveBackend.status();
+
         let mut status = ServiceStatus {
-            running: false,
-            pid: None,
+            running,
+            pid,
             healthy: false,
             error: None,
+            protocol_version: None,
+            model: None,
         };
-        
-        // Use launchctl list to check status
-        let output = Command::new("sh")
-            .arg("-c")
-            .arg(format!("launchctl list | grep {}", SERVICE_LABEL))
-            .output();
-        
-        if let Ok(output) = output {
-            if output.status.success() {
-                let stdout = String::from_utf8_lossy(&output.stdout);
-                // Parse output: "PID Status Label" or "- Status Label" if not running
-                let parts: Vec<&str> = stdout.split_whitespace().collect();
-                if parts.len() >= 2 {
-                    // First field is PID or "-"
-                    if let Ok(pid) = parts[0].parse::<u32>() {
-                        status.pid = Some(pid);
-                        status.running = true;
-                        
-                        // Now check if ZeroMQ ports are actually listening
-                        // This verifies the service is not just running but actually functional
-                        let ports_healthy = Self::check_zeromq_ports().await;
-                        status.healthy = ports_healthy;
-                        
-                        if !ports_healthy {
-                            status.error = Some("Service running but ZeroMQ ports not responding".to_string());
-                        }
-                    }
-                    // If first field is "-", service exited
-                    // Second field is exit code
-                }
-            }
+
+        if status.running {
+            let ports = Self::read_written_config()
+                .await
+                .unwrap_or_else(|| TranscriberConfig::from(&ExternalServiceConfig::default()));
+
+            let handshake = Self::check_zeromq_ports(&ports).await;
+            status.healthy = handshake.healthy;
+            status.protocol_version = handshake.protocol_version;
+            status.model = handshake.model;
+            status.error = handshake.error;
+        } else {
+            status.error = Self::tail_crash_reason().await;
         }
-        
+
         status
     }
-    
-    /// Check if ZeroMQ ports are listening
-    async fn check_zeromq_ports() -> bool {
+
+    /// Read back the `TranscriberConfig` [`Self::write_config`] last wrote,
+    /// so callers that need the service's ports don't have to hardcode them.
+    async fn read_written_config() -> Option<TranscriberConfig> {
+        let config_path = Self::config_path();
+        let contents = tokio::fs::read_to_string(&config_path).await.ok()?;
+        let format = TranscriberConfigFormat::from_extension(&config_path);
+        Self::deserialize_config(&contents, format).ok()
+    }
+
+    /// When the service isn't running, reads the tail of the transcriber's
+    /// error log so `ServiceStatus.error` can show the actual crash reason
+    /// instead of just telling the user to go check the log file.
+    async fn tail_crash_reason() -> Option<String> {
+        const TAIL_LINES: usize = 20;
+
+        let contents = tokio::fs::read_to_string(TRANSCRIBER_ERROR_LOG_PATH).await.ok()?;
+        let lines: Vec<&str> = contents.lines().filter(|line| !line.trim().is_empty()).collect();
+        if lines.is_empty() {
+            return None;
+        }
+
+        let tail = &lines[lines.len().saturating_sub(TAIL_LINES)..];
+        Some(tail.join("\n"))
+    }
+
+    /// Confirm the transcriber's ZeroMQ ports are actually serving the
+    /// expected protocol rather than just accepting TCP connections: the
+    /// push/pull job ports are checked for plain reachability (PUSH/PULL
+    /// sockets can't answer an application-level ping), while the control
+    /// port is probed with a real ping/pong handshake that also negotiates
+    /// protocol/model compatibility.
+    async fn check_zeromq_ports(config: &TranscriberConfig) -> ZmqHandshake {
         use std::net::{TcpStream, SocketAddr};
         use std::time::Duration;
-        
-        let ports = [5555, 5556, 5557];
+
         let timeout = Duration::from_millis(500);
-        
-        for port in &ports {
-            let addr_str = format!("127.0.0.1:{}", port);
-            if let Ok(addr) = addr_str.parse::<SocketAddr>() {
-                match TcpStream::connect_timeout(&addr, timeout) {
-                    Ok(_) => {
-                        // Port is open, connection succeeded
-                        continue;
-                    }
-                    Err(_) => {
-                        // Port is not accessible
-                        return false;
-                    }
-                }
-            } else {
-                // Failed to parse address
-                return false;
+        for port in [config.zmq_push_port, config.zmq_pull_port] {
+            let reachable = format!("127.0.0.1:{}", port)
+                .parse::<SocketAddr>()
+                .map(|addr| TcpStream::connect_timeout(&addr, timeout).is_ok())
+                .unwrap_or(false);
+
+            if !reachable {
+                return ZmqHandshake {
+                    healthy: false,
+                    protocol_version: None,
+                    model: None,
+                    error: Some(format!("Port {} not responding", port)),
+                };
             }
         }
-        
-        // All ports are accessible
-        true
+
+        Self::zmq_control_handshake(config.zmq_control_port).await
     }
-    
-    /// Check if transcriber binary is installed
-    pub async fn check_installed() -> bool {
-        // Check if transcriber is in PATH
-        let output = Command::new("which")
-            .arg("transcriber")
-            .output();
-        
-        if let Ok(output) = output {
+
+    /// Send a `{"op":"ping","protocol_version":N}` request to the
+    /// transcriber's ZeroMQ control (REQ/REP) port and require a
+    /// well-formed pong carrying a compatible `protocol_version` within the
+    /// timeout. ZMQ's synchronous API blocks, so the round trip runs on a
+    /// blocking task rather than the async runtime (mirroring
+    /// [`crate::services::control_plane_monitor::ZmqTransport`]).
+    async fn zmq_control_handshake(control_port: u16) -> ZmqHandshake {
+        tokio::task::spawn_blocking(move || {
+            let endpoint = format!("tcp://127.0.0.1:{}", control_port);
+
+            let context = zmq::Context::new();
+            let socket = match context.socket(zmq::REQ) {
+                Ok(socket) => socket,
+                Err(e) => return ZmqHandshake::unhealthy(format!("Failed to create control socket: {}", e)),
+            };
+            if socket.set_rcvtimeo(500).is_err() || socket.set_sndtimeo(500).is_err() {
+                return ZmqHandshake::unhealthy("Failed to configure control socket timeout".to_string());
+            }
+            if let Err(e) = socket.connect(&endpoint) {
+                return ZmqHandshake::unhealthy(format!("Failed to connect to control port {}: {}", control_port, e));
+            }
+
+            let ping = serde_json::json!({
+                "op": "ping",
+                "protocol_version": TRANSCRIBER_PROTOCOL_VERSION,
+            });
+            let Ok(payload) = serde_json::to_vec(&ping) else {
+                return ZmqHandshake::unhealthy("Failed to serialize ping".to_string());
+            };
+            if let Err(e) = socket.send(payload, 0) {
+                return ZmqHandshake::unhealthy(format!("Failed to send ping: {}", e));
+            }
+
+            let reply = match socket.recv_bytes(0) {
+                Ok(bytes) => bytes,
+                Err(e) => return ZmqHandshake::unhealthy(format!("No pong from transcriber: {}", e)),
+            };
+            let pong: serde_json::Value = match serde_json::from_slice(&reply) {
+                Ok(value) => value,
+                Err(e) => return ZmqHandshake::unhealthy(format!("Malformed pong: {}", e)),
+            };
+
+            let protocol_version = pong.get("protocol_version").and_then(|v| v.as_u64()).map(|v| v as u32);
+            let model = pong.get("model").and_then(|v| v.as_str()).map(|s| s.to_string());
+
+            match protocol_version {
+                Some(version) if version == TRANSCRIBER_PROTOCOL_VERSION => ZmqHandshake {
+                    healthy: true,
+                    protocol_version: Some(version),
+                    model,
+                    error: None,
+                },
+                Some(version) => ZmqHandshake {
+                    healthy: false,
+                    protocol_version: Some(version),
+                    model,
+                    error: Some(format!(
+                        "Transcriber reports protocol version {} but Scout expects {}",
+                        version, TRANSCRIBER_PROTOCOL_VERSION
+                    )),
+                },
+                None => ZmqHandshake {
+                    healthy: false,
+                    protocol_version: None,
+                    model,
+                    error: Some("Pong missing protocol_version".to_string()),
+                },
+            }
+        })
+        .await
+        .unwrap_or_else(|e| ZmqHandshake::unhealthy(format!("Handshake task panicked: {}", e)))
+    }
+
+    /// Check if the transcriber binary is installed, returning its resolved
+    /// path so callers (the plist/unit generators, the smoke test) can
+    /// derive the install root from it instead of hardcoding one.
+    pub async fn check_installed() -> Option<PathBuf> {
+        Self::locate_binary()
+    }
+
+    /// Locates the installed `transcriber` binary: first via `which`, then
+    /// by checking common installation directories.
+    fn locate_binary() -> Option<PathBuf> {
+        if let Ok(output) = Command::new("which").arg("transcriber").output() {
             if output.status.success() {
-                return true;
+                let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                if !path.is_empty() {
+                    return Some(PathBuf::from(path));
+                }
             }
         }
-        
-        // Check common installation paths
-        let paths = [
-            "/usr/local/bin/transcriber",
-            "/opt/homebrew/bin/transcriber",
-            "~/.local/bin/transcriber",
-        ];
-        
-        for path in &paths {
-            let expanded = shellexpand::tilde(path);
-            if Path::new(expanded.as_ref()).exists() {
-                return true;
+
+        let candidates = ["/usr/local/bin/transcriber", "/opt/homebrew/bin/transcriber", "~/.local/bin/transcriber"];
+
+        for candidate in candidates {
+            let expanded = shellexpand::tilde(candidate);
+            let path = Path::new(expanded.as_ref());
+            if path.exists() {
+                return Some(path.to_path_buf());
             }
         }
-        
-        false
+
+        None
+    }
+
+    /// Resolves the transcriber's working/install directory: an explicit
+    /// `ExternalServiceConfig.working_dir` override if set, otherwise the
+    /// parent directory of `config.binary_path` (or, failing that, the
+    /// binary [`Self::locate_binary`] discovers), falling back to
+    /// `/usr/local/bin` if none of those resolve to anything.
+    fn resolve_working_dir(config: &ExternalServiceConfig) -> PathBuf {
+        if let Some(dir) = &config.working_dir {
+            return PathBuf::from(dir);
+        }
+
+        let binary_path = config
+            .binary_path
+            .as_ref()
+            .map(PathBuf::from)
+            .or_else(Self::locate_binary);
+
+        binary_path
+            .and_then(|path| path.parent().map(|parent| parent.to_path_buf()))
+            .unwrap_or_else(|| PathBuf::from("/usr/local/bin"))
     }
-}
\ No newline at end of file
+}
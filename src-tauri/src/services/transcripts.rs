@@ -18,6 +18,153 @@ fn format_duration_ms(ms: i32) -> String {
     }
 }
 
+/// Default edit-distance tolerance applied when expanding search tokens.
+const DEFAULT_SEARCH_EDIT_DISTANCE: usize = 1;
+
+/// A single ranked full-text search result.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SearchHit {
+    pub transcript: db::Transcript,
+    /// Snippet of the matching text with matched terms wrapped in `[` / `]`.
+    pub snippet: String,
+    /// Relevance score; higher is more relevant.
+    pub score: f64,
+}
+
+/// A timed slice of a transcript, used to produce subtitle exports.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TranscriptSegment {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String,
+}
+
+/// Return the timed segments for a transcript. Segments are read from a
+/// `"segments"` array in the transcript's `metadata` JSON when present;
+/// otherwise the text is split on sentence boundaries and spread across
+/// `duration_ms` proportionally to each sentence's length.
+pub fn transcript_segments(transcript: &db::Transcript) -> Vec<TranscriptSegment> {
+    if let Some(meta) = &transcript.metadata {
+        if let Ok(value) = serde_json::from_str::<serde_json::Value>(meta) {
+            if let Some(segments) = value.get("segments") {
+                if let Ok(parsed) = serde_json::from_value::<Vec<TranscriptSegment>>(segments.clone()) {
+                    if !parsed.is_empty() {
+                        return parsed;
+                    }
+                }
+            }
+        }
+    }
+    split_into_segments(&transcript.text, transcript.duration_ms.max(0) as i64)
+}
+
+/// Split text into sentence-sized segments, allocating `duration_ms`
+/// proportionally to each sentence's character count.
+fn split_into_segments(text: &str, duration_ms: i64) -> Vec<TranscriptSegment> {
+    let sentences = split_sentences(text);
+    if sentences.is_empty() {
+        return Vec::new();
+    }
+
+    let total_chars: usize = sentences.iter().map(|s| s.chars().count().max(1)).sum();
+    let mut segments = Vec::with_capacity(sentences.len());
+    let mut cursor_ms = 0i64;
+    for sentence in sentences {
+        let weight = sentence.chars().count().max(1) as i64;
+        let span = duration_ms * weight / total_chars as i64;
+        let start_ms = cursor_ms;
+        let end_ms = (cursor_ms + span).min(duration_ms);
+        segments.push(TranscriptSegment {
+            start_ms,
+            end_ms,
+            text: sentence,
+        });
+        cursor_ms = end_ms;
+    }
+    // Make sure the last segment reaches the end of the recording.
+    if let Some(last) = segments.last_mut() {
+        last.end_ms = duration_ms;
+    }
+    segments
+}
+
+/// Split text into sentences on terminal punctuation, keeping the punctuation.
+fn split_sentences(text: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+    for ch in text.trim().chars() {
+        current.push(ch);
+        if matches!(ch, '.' | '!' | '?') {
+            let trimmed = current.trim();
+            if !trimmed.is_empty() {
+                sentences.push(trimmed.to_string());
+            }
+            current.clear();
+        }
+    }
+    let trimmed = current.trim();
+    if !trimmed.is_empty() {
+        sentences.push(trimmed.to_string());
+    }
+    sentences
+}
+
+/// Format a millisecond offset as an SRT timestamp (`HH:MM:SS,mmm`).
+fn format_srt_timestamp(ms: i64) -> String {
+    let (h, m, s, millis) = split_hms(ms);
+    format!("{:02}:{:02}:{:02},{:03}", h, m, s, millis)
+}
+
+/// Format a millisecond offset as a WebVTT timestamp (`HH:MM:SS.mmm`).
+fn format_vtt_timestamp(ms: i64) -> String {
+    let (h, m, s, millis) = split_hms(ms);
+    format!("{:02}:{:02}:{:02}.{:03}", h, m, s, millis)
+}
+
+fn split_hms(ms: i64) -> (i64, i64, i64, i64) {
+    let ms = ms.max(0);
+    let millis = ms % 1000;
+    let total_secs = ms / 1000;
+    (total_secs / 3600, (total_secs % 3600) / 60, total_secs % 60, millis)
+}
+
+/// Render transcripts as an SRT subtitle document.
+pub fn export_transcripts_srt(transcripts: &[db::Transcript]) -> Result<String, String> {
+    let mut output = String::new();
+    let mut index = 1;
+    for transcript in transcripts {
+        for segment in transcript_segments(transcript) {
+            output.push_str(&format!("{}\n", index));
+            output.push_str(&format!(
+                "{} --> {}\n",
+                format_srt_timestamp(segment.start_ms),
+                format_srt_timestamp(segment.end_ms)
+            ));
+            output.push_str(&segment.text);
+            output.push_str("\n\n");
+            index += 1;
+        }
+    }
+    Ok(output)
+}
+
+/// Render transcripts as a WebVTT subtitle document.
+pub fn export_transcripts_vtt(transcripts: &[db::Transcript]) -> Result<String, String> {
+    let mut output = String::from("WEBVTT\n\n");
+    for transcript in transcripts {
+        for segment in transcript_segments(transcript) {
+            output.push_str(&format!(
+                "{} --> {}\n",
+                format_vtt_timestamp(segment.start_ms),
+                format_vtt_timestamp(segment.end_ms)
+            ));
+            output.push_str(&segment.text);
+            output.push_str("\n\n");
+        }
+    }
+    Ok(output)
+}
+
 pub struct TranscriptsService {
     pub database: Arc<db::Database>,
     pub performance_tracker: Arc<performance_tracker::PerformanceTracker>,
@@ -42,8 +189,25 @@ impl TranscriptsService {
     //     Err("Use command layer to compose details".to_string())
     // }
 
-    pub async fn search_transcripts(&self, query: String) -> Result<Vec<db::Transcript>, String> {
-        self.database.search_transcripts(&query).await
+    pub async fn search_transcripts(&self, query: String) -> Result<Vec<SearchHit>, String> {
+        let hits = self
+            .database
+            .search_transcripts_ranked(&query, DEFAULT_SEARCH_EDIT_DISTANCE)
+            .await?;
+        Ok(hits
+            .into_iter()
+            .map(|(transcript, snippet, score)| SearchHit { transcript, snippet, score })
+            .collect())
+    }
+
+    /// Full-text search using the query DSL (prefix matches, phrases, AND/OR)
+    /// instead of typo-tolerant token expansion.
+    pub async fn search_transcripts_matching(&self, query: String, limit: i32) -> Result<Vec<SearchHit>, String> {
+        let hits = self.database.search_transcripts_matching(&query, limit).await?;
+        Ok(hits
+            .into_iter()
+            .map(|(transcript, snippet, score)| SearchHit { transcript, snippet, score })
+            .collect())
     }
 
     pub async fn delete_transcript(&self, id: i64) -> Result<(), String> {
@@ -58,6 +222,15 @@ impl TranscriptsService {
         serde_json::to_string_pretty(transcripts).map_err(|e| format!("Failed to serialize to JSON: {}", e))
     }
 
+    /// Same as `export_transcripts_json`, but encrypted at rest with the
+    /// same keystream-XOR wrapper `transport::ZmqSink`/`TcpSink` use for
+    /// results in flight, so an export written to disk is unreadable without
+    /// `secret`.
+    pub fn export_transcripts_json_encrypted(&self, transcripts: &[db::Transcript], secret: &str) -> Result<Vec<u8>, String> {
+        let json = self.export_transcripts_json(transcripts)?;
+        Ok(crate::transport::encryption::encrypt_bytes(json.as_bytes(), secret))
+    }
+
     pub fn export_transcripts_markdown(&self, transcripts: &[db::Transcript]) -> Result<String, String> {
         let mut output = String::from("# Scout Transcripts\n\n");
         for transcript in transcripts {
@@ -84,6 +257,14 @@ impl TranscriptsService {
         Ok(output)
     }
 
+    pub fn export_transcripts_srt(&self, transcripts: &[db::Transcript]) -> Result<String, String> {
+        export_transcripts_srt(transcripts)
+    }
+
+    pub fn export_transcripts_vtt(&self, transcripts: &[db::Transcript]) -> Result<String, String> {
+        export_transcripts_vtt(transcripts)
+    }
+
     pub fn export_audio_file(&self, source_path: &str, destination_path: &str) -> Result<(), String> {
         let source = Path::new(source_path);
         if !source.exists() {
@@ -92,5 +273,24 @@ impl TranscriptsService {
         std::fs::copy(source_path, destination_path).map_err(|e| format!("Failed to copy audio file: {}", e))?;
         Ok(())
     }
+
+    /// Transcodes `source_path` into `destination_path` as `format` instead
+    /// of copying the raw WAV. For `Ogg`, `transcript`'s segments become cue
+    /// points and its full text is embedded as Vorbis comments, so the
+    /// exported file is self-describing.
+    pub fn export_audio_file_as(
+        &self,
+        source_path: &str,
+        destination_path: &str,
+        format: crate::audio::transcode::AudioExportFormat,
+        transcript: &db::Transcript,
+    ) -> Result<(), String> {
+        let source = Path::new(source_path);
+        if !source.exists() {
+            return Err("Source audio file not found".to_string());
+        }
+        let segments = transcript_segments(transcript);
+        crate::audio::transcode::transcode(source, Path::new(destination_path), format, &transcript.text, &segments)
+    }
 }
 
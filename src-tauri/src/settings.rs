@@ -1,11 +1,23 @@
+use crate::audio::{AudioBackend, CustomAudioDeviceConfig, SilenceTrimmerConfig, SpectralVadConfig};
 use crate::logger::{error, Component};
+use crate::post_processing::vocabulary_filter::VocabularyFilterConfig;
+use crate::transcription::{StabilityLevel, TranscriptionBackendKind, VocabularyConfig};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+/// Current on-disk shape of [`AppSettings`]. Bump this and add a matching
+/// entry to [`MIGRATIONS`] whenever a field is renamed, moved, or removed,
+/// so `SettingsManager::new` can carry old `settings.json` files forward
+/// instead of falling back to defaults.
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct AppSettings {
+    #[serde(default = "current_schema_version")]
+    pub schema_version: u32,
+
     // Audio settings
     pub audio: AudioSettings,
 
@@ -23,6 +35,9 @@ pub struct AppSettings {
     
     // External service settings
     pub external_service: ExternalServiceConfig,
+
+    // Streaming partial-result settings
+    pub streaming: StreamingSettings,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,6 +47,24 @@ pub struct AudioSettings {
     pub channels: u16,
     pub buffer_size: usize,
     pub min_recording_duration_ms: u64,
+
+    /// Preferred audio backend (most relevant on Linux, where ALSA,
+    /// PulseAudio, and JACK all coexist). `Auto` keeps the existing
+    /// OS-default behavior.
+    pub backend: AudioBackend,
+
+    /// Pin recording to a specific device and/or force a sample rate or
+    /// buffer size, overriding the OS default device and its native format.
+    pub custom_device: Option<CustomAudioDeviceConfig>,
+
+    /// Thresholds for the spectral voice-activity detector backing
+    /// `get_voice_activity`, so users can tune sensitivity per environment.
+    pub voice_activity: SpectralVadConfig,
+
+    /// Thresholds for trimming leading/trailing/internal silence out of a
+    /// recording before it reaches the transcriber. See
+    /// `audio::silence_trimmer`.
+    pub trim_silence: SilenceTrimmerConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +74,16 @@ pub struct ModelSettings {
     pub fallback_model_id: String,
     pub auto_download_models: Vec<String>,
     pub model_preferences: serde_json::Value,
+
+    /// Which runtime actually decodes the model: whisper.cpp (default) or
+    /// the pure-Rust Candle backend. See
+    /// `transcription::backend::TranscriptionBackend`.
+    pub backend: TranscriptionBackendKind,
+
+    /// Custom vocabulary phrases and substitutions used to bias decoding and
+    /// correct domain terms, names, and acronyms. See
+    /// `transcription::vocabulary`.
+    pub vocabulary: VocabularyConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -58,8 +101,7 @@ pub struct UISettings {
     pub completion_sound_threshold_ms: u64,
     pub auto_copy: bool,
     pub auto_paste: bool,
-    pub profanity_filter_enabled: bool,
-    pub profanity_filter_aggressive: bool,
+    pub vocabulary_filter: VocabularyFilterConfig,
     pub foundation_models_enabled: Option<bool>,
     pub foundation_models_mode: Option<String>,
     pub foundation_models_temperature: Option<f64>,
@@ -88,6 +130,15 @@ pub struct LLMSettings {
     pub enabled_prompts: Vec<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StreamingSettings {
+    pub enabled: bool,
+    /// How many consecutive matching partial decodes are required before a
+    /// word is committed; see `transcription::partial_stability`.
+    pub stability: StabilityLevel,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ExternalServiceConfig {
@@ -99,17 +150,87 @@ pub struct ExternalServiceConfig {
     pub zmq_control_port: u16,
     pub workers: usize,
     pub model: String,
+    /// Overrides where the transcriber config file is read from and
+    /// written to, instead of the default per-OS app-data directory (e.g.
+    /// for users who keep a hand-edited config elsewhere). Equivalent to
+    /// setting the `SCOUT_TRANSCRIBER_CONFIG` environment variable.
+    pub config_path_override: Option<String>,
+    /// Serialization format for the transcriber config file. When `None`,
+    /// the format is inferred from `config_path_override`'s extension (or
+    /// defaults to JSON if there's no override or no recognized extension).
+    pub config_format: Option<TranscriberConfigFormat>,
+    /// When the OS service manager should restart a stopped transcriber.
+    pub restart_policy: RestartPolicy,
+    /// Minimum seconds the service must stay up before a crash counts
+    /// toward the restart throttle (launchd's `ThrottleInterval`, systemd's
+    /// `RestartSec`).
+    pub throttle_seconds: u32,
+    /// Overrides the transcriber's working directory, instead of deriving
+    /// it from the discovered/configured binary's parent directory. Useful
+    /// when the binary and its supporting files (e.g. `test_audio.py`)
+    /// don't live alongside each other.
+    pub working_dir: Option<String>,
+}
+
+/// Supervision policy for the OS-managed transcriber process, mapped to
+/// launchd's `KeepAlive` dictionary by [`LaunchdBackend::generate_plist`]
+/// and to systemd's `Restart=` by [`SystemdBackend::generate_unit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestartPolicy {
+    /// Never restart automatically; a crash stays down until the user (or
+    /// Scout's health check) starts it again.
+    Never,
+    /// Restart only when the process exits with a non-zero/crash status.
+    OnFailure,
+    /// Restart unconditionally, even after a clean exit.
+    Always,
+}
+
+/// On-disk format for the transcriber's config file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriberConfigFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl TranscriberConfigFormat {
+    /// Infers the format from a config file's extension, defaulting to JSON
+    /// for an unrecognized or missing extension.
+    pub fn from_extension(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml") | Some("yml") => Self::Yaml,
+            Some("toml") => Self::Toml,
+            _ => Self::Json,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+            Self::Toml => "toml",
+        }
+    }
+}
+
+fn current_schema_version() -> u32 {
+    CURRENT_SCHEMA_VERSION
 }
 
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
+            schema_version: CURRENT_SCHEMA_VERSION,
             audio: AudioSettings::default(),
             models: ModelSettings::default(),
             ui: UISettings::default(),
             processing: ProcessingSettings::default(),
             llm: LLMSettings::default(),
             external_service: ExternalServiceConfig::default(),
+            streaming: StreamingSettings::default(),
         }
     }
 }
@@ -121,6 +242,10 @@ impl Default for AudioSettings {
             channels: 1,
             buffer_size: 1024,
             min_recording_duration_ms: 500,
+            backend: AudioBackend::Auto,
+            custom_device: None,
+            voice_activity: SpectralVadConfig::default(),
+            trim_silence: SilenceTrimmerConfig::default(),
         }
     }
 }
@@ -132,6 +257,8 @@ impl Default for ModelSettings {
             fallback_model_id: "tiny.en".to_string(),
             auto_download_models: vec!["tiny.en".to_string(), "base.en".to_string()],
             model_preferences: serde_json::json!({}),
+            backend: TranscriptionBackendKind::default(),
+            vocabulary: VocabularyConfig::default(),
         }
     }
 }
@@ -151,8 +278,7 @@ impl Default for UISettings {
             completion_sound_threshold_ms: 1000,
             auto_copy: false,
             auto_paste: false,
-            profanity_filter_enabled: true,
-            profanity_filter_aggressive: false,
+            vocabulary_filter: VocabularyFilterConfig::default(),
             foundation_models_enabled: Some(false), // Disabled by default
             foundation_models_mode: Some("enhance".to_string()),
             foundation_models_temperature: Some(0.1),
@@ -191,6 +317,15 @@ impl Default for LLMSettings {
     }
 }
 
+impl Default for StreamingSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            stability: StabilityLevel::Medium,
+        }
+    }
+}
+
 impl Default for ExternalServiceConfig {
     fn default() -> Self {
         Self {
@@ -202,8 +337,63 @@ impl Default for ExternalServiceConfig {
             zmq_control_port: 5557,
             workers: 2,
             model: "whisper".to_string(),
+            config_path_override: None,
+            config_format: None,
+            restart_policy: RestartPolicy::OnFailure,
+            throttle_seconds: 10,
+            working_dir: None,
+        }
+    }
+}
+
+/// One step of the migration chain: rewrites a raw settings document from
+/// `from_version` to `from_version + 1` in place. Operating on
+/// `serde_json::Value` (rather than typed structs) lets a migration move or
+/// rename fields that no longer exist on the current `AppSettings`.
+type Migration = fn(&mut serde_json::Value);
+
+/// Ordered `(from_version, migration)` chain, applied in order starting from
+/// whatever version the on-disk document reports. Append to this, never
+/// rewrite past entries, when `CURRENT_SCHEMA_VERSION` is bumped.
+const MIGRATIONS: &[(u32, Migration)] = &[(1, migrate_v1_to_v2)];
+
+/// v1 -> v2: folds the old boolean `profanity_filter_enabled` /
+/// `profanity_filter_aggressive` flags into the `vocabulary_filter`
+/// structure introduced alongside it, preserving the enabled bit on the
+/// built-in `Profanity` list.
+fn migrate_v1_to_v2(value: &mut serde_json::Value) {
+    if let Some(ui) = value.get_mut("ui").and_then(|v| v.as_object_mut()) {
+        let enabled = ui
+            .remove("profanity_filter_enabled")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+        ui.remove("profanity_filter_aggressive");
+
+        if !ui.contains_key("vocabulary_filter") {
+            let mut default_config = serde_json::to_value(VocabularyFilterConfig::default())
+                .expect("VocabularyFilterConfig serializes");
+            if let Some(config) = default_config.as_object_mut() {
+                config.insert("enabled".to_string(), serde_json::Value::Bool(enabled));
+            }
+            ui.insert("vocabulary_filter".to_string(), default_config);
+        }
+    }
+
+    if let Some(root) = value.as_object_mut() {
+        root.insert("schema_version".to_string(), serde_json::Value::from(2));
+    }
+}
+
+/// Runs every migration whose `from_version` matches the document's current
+/// version, in order, until it reaches `CURRENT_SCHEMA_VERSION`.
+fn migrate_to_current(mut value: serde_json::Value, mut version: u32) -> serde_json::Value {
+    for (from_version, migration) in MIGRATIONS {
+        if version == *from_version {
+            migration(&mut value);
+            version = from_version + 1;
         }
     }
+    value
 }
 
 pub struct SettingsManager {
@@ -215,9 +405,9 @@ impl SettingsManager {
     pub fn new(app_data_dir: &Path) -> Result<Self, String> {
         let settings_path = app_data_dir.join("settings.json");
 
-        // Load settings or create default
+        // Load settings, migrating an older schema forward, or create default
         let settings = match fs::read_to_string(&settings_path) {
-            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+            Ok(contents) => Self::load_and_migrate(&settings_path, &contents).unwrap_or_else(|e| {
                 error(
                     Component::UI,
                     &format!("Failed to parse settings.json: {}, using defaults", e),
@@ -242,6 +432,67 @@ impl SettingsManager {
         })
     }
 
+    /// Parses `contents` as raw JSON, migrates it forward if its
+    /// `schema_version` is behind `CURRENT_SCHEMA_VERSION`, and only then
+    /// deserializes into `AppSettings`. A document with no `schema_version`
+    /// field predates this mechanism and is treated as version 1.
+    ///
+    /// On a successful migration, the pre-migration file is backed up
+    /// alongside `settings.json` and the migrated document is written back,
+    /// so a later release that can't parse some *other* part of the file
+    /// still degrades to "keep what we migrated" instead of a full reset.
+    fn load_and_migrate(settings_path: &Path, contents: &str) -> Result<AppSettings, String> {
+        let raw: serde_json::Value = serde_json::from_str(contents).map_err(|e| e.to_string())?;
+        let version = raw
+            .get("schema_version")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(1) as u32;
+
+        if version >= CURRENT_SCHEMA_VERSION {
+            return serde_json::from_value(raw).map_err(|e| e.to_string());
+        }
+
+        crate::logger::info(
+            Component::UI,
+            &format!(
+                "Migrating settings.json from schema v{} to v{}",
+                version, CURRENT_SCHEMA_VERSION
+            ),
+        );
+
+        let migrated = migrate_to_current(raw, version);
+        let settings: AppSettings = serde_json::from_value(migrated.clone())
+            .map_err(|e| format!("Migrated settings still failed to parse: {}", e))?;
+
+        if let Err(e) = Self::backup_and_replace(settings_path, contents, &migrated) {
+            error(
+                Component::UI,
+                &format!("Migrated settings in memory but failed to persist them: {}", e),
+            );
+        }
+
+        Ok(settings)
+    }
+
+    /// Writes `original_contents` to a timestamped `settings.json.bak-*`
+    /// file next to `settings_path`, then overwrites `settings_path` with
+    /// `migrated`. Only called after `migrated` has already been confirmed
+    /// to deserialize into `AppSettings`.
+    fn backup_and_replace(
+        settings_path: &Path,
+        original_contents: &str,
+        migrated: &serde_json::Value,
+    ) -> Result<(), String> {
+        let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+        let backup_path = settings_path.with_extension(format!("json.bak-{}", timestamp));
+        fs::write(&backup_path, original_contents)
+            .map_err(|e| format!("Failed to write settings backup: {}", e))?;
+
+        let json = serde_json::to_string_pretty(migrated)
+            .map_err(|e| format!("Failed to serialize migrated settings: {}", e))?;
+        fs::write(settings_path, json).map_err(|e| format!("Failed to write settings.json: {}", e))
+    }
+
     pub fn get(&self) -> &AppSettings {
         &self.settings
     }
@@ -272,8 +523,7 @@ impl SettingsManager {
     pub fn reload(&mut self) -> Result<(), String> {
         match fs::read_to_string(&self.settings_path) {
             Ok(contents) => {
-                self.settings = serde_json::from_str(&contents)
-                    .map_err(|e| format!("Failed to parse settings: {}", e))?;
+                self.settings = Self::load_and_migrate(&self.settings_path, &contents)?;
                 Ok(())
             }
             Err(e) => Err(format!("Failed to read settings: {}", e)),
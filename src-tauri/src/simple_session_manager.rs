@@ -1,17 +1,26 @@
 use crate::audio::simple_recorder::{SimpleAudioRecorder, RecorderState, RecordingInfo};
 use crate::audio::recorder::AudioRecorder;
+use crate::audio::wav_file_reader::WavFileReader;
+use crate::transcription::partial_stability::{PartialResultStabilizer, StabilityLevel};
 use crate::transcription::simple_transcriber::{SimpleTranscriptionService, TranscriptionRequest, TranscriptionResponse};
 use crate::logger::{debug, error, info, warn, Component};
 use crate::sound::SoundPlayer;
 use crate::db::Database;
+use serde::Serialize;
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Instant;
-use tokio::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, Mutex};
 use uuid::Uuid;
 use tauri::{AppHandle, Emitter};
 use serde_json::json;
 
+/// How often a streaming session re-decodes its trailing audio window.
+const STREAMING_DECODE_INTERVAL: Duration = Duration::from_millis(500);
+/// How much trailing audio each streaming decode re-runs whisper over, so
+/// cost stays bounded no matter how long the session has been recording.
+const STREAMING_WINDOW: Duration = Duration::from_secs(15);
+
 /// Simplified, high-performance session manager that replaces the complex workflow system
 /// 
 /// This integrates the simplified audio recorder and transcription service:
@@ -76,6 +85,19 @@ pub struct SessionResult {
     pub total_duration_ms: u64,
 }
 
+/// One incremental transcript emitted during a streaming session.
+///
+/// `text` is the full hypothesis so far; `stable_until_char` is the byte
+/// offset (into `text`) up to which the prefix has been committed by the
+/// LocalAgreement-2 stabilizer and will never be rewritten by a later
+/// update. The UI can render `text[..stable_until_char]` as final and the
+/// remainder as volatile, still-revising text.
+#[derive(Debug, Clone, Serialize)]
+pub struct PartialTranscript {
+    pub text: String,
+    pub stable_until_char: usize,
+}
+
 impl SimpleSessionManager {
     /// Create a new simple session manager
     pub async fn new(
@@ -115,20 +137,25 @@ impl SimpleSessionManager {
         let simple_recorder = SimpleAudioRecorder::new(wav_spec);
         
         // Get the active model from settings
-        let (active_model_path, model_name) = {
+        let (active_model_path, model_name, trim_config, vocabulary_config) = {
             let settings = settings_manager.lock().await;
             let settings_data = settings.get();
             let active_model_id = &settings_data.models.active_model_id;
-            
+
             // Get all models and find the active one
             let models = crate::models::WhisperModel::all(&models_dir, &settings_data);
             let active_model = models.iter()
                 .find(|m| &m.id == active_model_id)
                 .ok_or_else(|| format!("Active model '{}' not found", active_model_id))?;
-            
+
             let model_path = models_dir.join(&active_model.filename);
             let model_name = active_model.id.clone();
-            (model_path, model_name)
+            (
+                model_path,
+                model_name,
+                settings_data.audio.trim_silence.clone(),
+                settings_data.models.vocabulary.clone(),
+            )
         };
         
         info(
@@ -141,10 +168,12 @@ impl SimpleSessionManager {
             Some(model_state_manager.clone()),
         ).await?;
         
-        let transcription_service = SimpleTranscriptionService::new(
+        let mut transcription_service = SimpleTranscriptionService::new(
             transcriber,
             model_name,
         );
+        transcription_service.set_trim_config(trim_config);
+        transcription_service.set_vocabulary_config(vocabulary_config);
         
         info(
             Component::Recording,
@@ -299,6 +328,103 @@ impl SimpleSessionManager {
         Ok(session_id)
     }
 
+    /// Start a recording session that also emits incremental partial
+    /// transcripts as audio arrives, so the UI can show live text instead
+    /// of waiting for [`Self::stop_recording`].
+    ///
+    /// Every [`STREAMING_DECODE_INTERVAL`], the trailing [`STREAMING_WINDOW`]
+    /// of the still-growing recording is re-decoded from scratch and fed
+    /// through a [`PartialResultStabilizer`] configured for LocalAgreement-2
+    /// (`StabilityLevel::Low`, window of 2): the longest word prefix shared
+    /// by the two most recent hypotheses is committed and never revised
+    /// again, while the remainder is re-emitted each cycle as still-volatile
+    /// text. Returns the session id plus a channel of [`PartialTranscript`]
+    /// updates; the final volatile suffix is finalized by
+    /// [`Self::stop_recording`] as usual.
+    pub async fn start_streaming_session(
+        &self,
+        device_name: Option<String>,
+    ) -> Result<(String, mpsc::UnboundedReceiver<PartialTranscript>), String> {
+        let session_id = self.start_recording(device_name).await?;
+
+        let file_path = {
+            let session_guard = self.current_session.lock().await;
+            session_guard
+                .as_ref()
+                .map(|s| s.file_path.clone())
+                .ok_or("Session disappeared immediately after starting")?
+        };
+
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let transcription_service = self.transcription_service.clone();
+        let current_session = self.current_session.clone();
+        let app_handle = self.app_handle.clone();
+        let session_id_for_task = session_id.clone();
+
+        tauri::async_runtime::spawn(async move {
+            let mut stabilizer = PartialResultStabilizer::new(StabilityLevel::Low);
+
+            loop {
+                tokio::time::sleep(STREAMING_DECODE_INTERVAL).await;
+
+                let still_recording = {
+                    let session_guard = current_session.lock().await;
+                    matches!(
+                        session_guard.as_ref(),
+                        Some(session)
+                            if session.id == session_id_for_task
+                                && matches!(session.state, SessionState::Recording)
+                    )
+                };
+                if !still_recording {
+                    break;
+                }
+
+                let reader = match WavFileReader::new(&file_path) {
+                    Ok(reader) => reader,
+                    Err(_) => continue, // header not flushed to disk yet
+                };
+                let available = match reader.get_available_duration() {
+                    Ok(duration) if duration > Duration::ZERO => duration,
+                    _ => continue,
+                };
+
+                let window = STREAMING_WINDOW.min(available);
+                let start_offset = available.saturating_sub(window);
+                let samples = match reader.extract_chunk(start_offset, window) {
+                    Ok(samples) if !samples.is_empty() => samples,
+                    _ => continue,
+                };
+
+                let partial_text = {
+                    let service = transcription_service.lock().await;
+                    service.transcribe_preview(&samples).await
+                };
+
+                let partial_text = match partial_text {
+                    Ok(text) => text,
+                    Err(e) => {
+                        warn(
+                            Component::Transcription,
+                            &format!("Streaming partial decode failed: {}", e),
+                        );
+                        continue;
+                    }
+                };
+
+                let partial = Self::partial_transcript_from_update(stabilizer.push_partial(&partial_text));
+
+                let _ = app_handle.emit("streaming-partial-transcript", &partial);
+                if tx.send(partial).is_err() {
+                    break; // receiver dropped
+                }
+            }
+        });
+
+        Ok((session_id, rx))
+    }
+
     /// Stop the current recording session and start transcription
     pub async fn stop_recording(&self) -> Result<SessionResult, String> {
         let stop_time = Instant::now();
@@ -662,6 +788,27 @@ impl SimpleSessionManager {
         let recorder = self.main_recorder.lock().await;
         recorder.get_current_audio_level()
     }
+
+    /// Flatten a stabilizer's `{ committed, tentative }` split into the
+    /// single `text` + `stable_until_char` shape the streaming channel
+    /// emits. Factored out so the conversion can be unit-tested without
+    /// spinning up a real transcriber.
+    fn partial_transcript_from_update(
+        update: crate::transcription::partial_stability::StreamingUpdate,
+    ) -> PartialTranscript {
+        let text = if update.tentative.is_empty() {
+            update.committed.clone()
+        } else {
+            format!("{} {}", update.committed, update.tentative)
+                .trim_start()
+                .to_string()
+        };
+
+        PartialTranscript {
+            stable_until_char: update.committed.len(),
+            text,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -709,6 +856,9 @@ mod tests {
             duration_seconds: 1.0,
             sample_rate: 44100,
             channels: 1,
+            discontinuity_count: 0,
+            lost_audio_ms: 0.0,
+            average_parked_ratio: 0.0,
         };
 
         // Test state transitions
@@ -724,4 +874,35 @@ mod tests {
         assert_eq!(session.id, "test-session");
         assert_eq!(session.device_name, Some("Test Device".to_string()));
     }
+
+    #[test]
+    fn test_streaming_committed_prefix_is_monotonic_and_never_rewritten() {
+        let mut stabilizer = PartialResultStabilizer::new(StabilityLevel::Low); // window = 2
+
+        let hypotheses = [
+            "the quick",
+            "the quick brown",
+            "the quick brown fox",
+            "the quick brown fox jumps",
+        ];
+
+        let mut previous_stable: Option<String> = None;
+        for hypothesis in hypotheses {
+            let update = stabilizer.push_partial(hypothesis);
+            let partial = SimpleSessionManager::partial_transcript_from_update(update);
+            let stable_text = &partial.text[..partial.stable_until_char];
+
+            if let Some(prev) = &previous_stable {
+                assert!(
+                    stable_text.starts_with(prev.as_str()),
+                    "committed prefix was rewritten: {:?} is not a continuation of {:?}",
+                    stable_text,
+                    prev
+                );
+            }
+            previous_stable = Some(stable_text.to_string());
+        }
+
+        assert_eq!(previous_stable.unwrap(), "the quick brown fox");
+    }
 }
\ No newline at end of file
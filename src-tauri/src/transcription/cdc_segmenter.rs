@@ -0,0 +1,201 @@
+/// Content-defined chunking over a PCM audio stream, using a FastCDC-style
+/// gear hash: cuts are a property of the audio content itself rather than a
+/// fixed wall-clock grid, so re-processing a recording that shares a long
+/// prefix with a prior one (e.g. a re-recording with a bit of leading
+/// silence trimmed or added) reproduces most of the same cut points. That
+/// lets chunk-level transcription caching skip chunks that didn't change,
+/// which a fixed-size grid can't do once a single sample is inserted or
+/// removed upstream of a boundary.
+///
+/// Unlike [`super::vad_segmenter::VadSegmenter`] (which cuts on detected
+/// silence) this has no notion of speech at all — it only reacts to the
+/// PCM bytes, so it is the only one of the three segmenters in this module
+/// whose cut points are stable under arbitrary content shifts.
+
+/// 256-entry table of pseudo-random 64-bit values used by the gear hash,
+/// one per possible byte value. Generated once with a seeded splitmix64
+/// generator (seed `0x5CAE5CAE5CAE5CAE`) for reproducibility — any fixed
+/// table works equally well for FastCDC's statistical cut-point properties,
+/// it doesn't need to be cryptographically random.
+#[rustfmt::skip]
+const GEAR: [u64; 256] = [
+    0x3606147649BFAFB8, 0x238A18866DB4E086, 0x8449D94EFC30BB64, 0x092F760D67B92865,
+    0x28A2055A2648C2C8, 0x5F750387FB84B00C, 0xCFD2C6FE123F2479, 0xC514614E1C15EB21,
+    0x74CDD9E8D0B5D8D6, 0x21A37D9C399530A2, 0x43006A73752CD53C, 0x115B1E953D67D822,
+    0x29D92776350F0B9B, 0x4DA46E7FD0D0908E, 0xF50B1B72E83A473C, 0xE8B5E63016576107,
+    0x4FDBFBC5ABE9D965, 0xCA44EE711ACD3F7B, 0xAC93F10323423292, 0x7796EA87F1D7B29A,
+    0x106F99163BE0FB7E, 0x140414B182DD9F1D, 0xDCC3513CF48D861B, 0x98A7B1B181EDB646,
+    0x2DC3CF98468C08D2, 0x546B9C7D8B85A06A, 0xF595AE2673B545A2, 0xF201F1B01641BD16,
+    0xDB9DB99BB21148A9, 0x2847404C2ADCFF16, 0x184C48209FE59BA9, 0xFC1DDBB22DDB1D76,
+    0x546FC97BDE03CEB9, 0x5982261D9EBFA69B, 0x316606119C65567D, 0x97AA5CC11B51BE96,
+    0x322BCA6AEFD889CB, 0x5182880D710D227C, 0x33A63BA6B92FC8BE, 0xC610AB4A67AC7B8A,
+    0x59C56569BA3E801B, 0xA076716B119507B8, 0x68698B41B9B520CF, 0x5BA3FE68B6092D4F,
+    0x8A17C4B3FC93AEED, 0x51B6E2D077E6185E, 0x0E75FCF976196F46, 0x8B5FDF9A7FA361BB,
+    0x949756F3C0776A2F, 0xAA1E62C6FE0E61DA, 0x2E1BBD30DB37AC96, 0x6C7502F8B79D09FB,
+    0xB919429D31E6D90B, 0xB0B6D9334DFE9F4C, 0xF381F763CC28EEBD, 0xAD4B5F34D63850C6,
+    0xD9F68ED71DA01B82, 0xDADE3981D640FFD7, 0x9C1F99CC8B8671BA, 0x05063E65FB1406E7,
+    0xCF14CC7E367EE620, 0x2C4C47EBD0DBDC78, 0x62EB70B387B3E860, 0x1F9BF31DDBBE9FDA,
+    0xBCB9F5F957B244B1, 0xE4C32EAD6179B49D, 0x926B66339379618E, 0x8A22FB4EAA415EDC,
+    0xB6C29C98B0F68FD8, 0x35DF2EDACD5B2146, 0x438BF2EDAC6CE534, 0x3A40E278BF1F249D,
+    0x8BF364C47DEFB8CF, 0xC8C28FF89AB217B5, 0x9AC76779811114D8, 0x210CA6F0E5642AE4,
+    0x938AE38BA9552732, 0x3FDDB94630BBD5C4, 0x9D0883710BA27E22, 0xDE57D12971A99CBB,
+    0xC106B3D8E862A176, 0x4B6F4E1295978356, 0x754BD0DD3FAEDF8B, 0x3B0F0EE4E4EF9C13,
+    0xCE34D87038FC2657, 0x592A46536C94FD7B, 0xF4B7F2A536E472A5, 0xE125E9FCD553A76E,
+    0x5DBD4EB8F991A3CE, 0x4000138255B99C29, 0x766D20506ACEB9DD, 0xC70834314D30503A,
+    0xA9D0CDDCDAFE2A6B, 0x8908FCD21C2F6A7B, 0xCDCB451C5D95A69E, 0xE424872CA6EF9732,
+    0xD09ACAB2F977EBC2, 0xCCAB0529C46312BC, 0xDF10DB7CFBBD5879, 0x825CF4214BF5CC98,
+    0x096EC92BC12ACD15, 0x6162F68595D55004, 0x2B334DF82DC5EB2D, 0x80F0E5414790F7E9,
+    0x52A021DF33B09A8D, 0x0C41F2E11EEB5524, 0x2A391559050DB742, 0xE774247223EF2B81,
+    0xBE898CD9C2B29B57, 0xAC5C14D1D6AA91E2, 0x2DE989E43DD3CC50, 0xECA15B9E2305D669,
+    0xE6191BEFBF8EE3A6, 0xE8360A3F1A401D15, 0x2F430AEE1763A8AD, 0xF8CBF5BE8C4FF584,
+    0xEF14EF6EB2CD01D8, 0x0E0AC7E85AAC73AB, 0xD9F9F8947D34D36B, 0x5DF44C97E8184C34,
+    0xAE318AF4E4D7E834, 0x9EEA7337636F91EC, 0x70DBF51D49C5CAA1, 0x1866656FBB38C15A,
+    0x8E313F9BE4A19AF4, 0x84CDC5F5425CD2B5, 0x8928AD7D9B27586C, 0x6BA043E97EDE6750,
+    0x35B1E3A64A6321F4, 0x0391FC2F1D93CFC5, 0x14F226CA16ED35CA, 0x6D3CA253C9C68AE9,
+    0x57C5B60EB753DFF1, 0x2EBC9C169AD436C4, 0xDCECF58C881F402D, 0x8E8085BF73C0285F,
+    0xCC216D0903B1328E, 0x6389F59140A0BD37, 0x6802B9929CDDABF6, 0xB7D6604874E2104A,
+    0x6E5855F6E5F5CBD6, 0xF13AE00508174804, 0xD42397080DB26CB5, 0x282EA6C722AF6E58,
+    0x6E754F95067A890C, 0xA19087A8E572C582, 0x5902EE14AC2648E2, 0xB3BAB3E1010C1B34,
+    0x4678AECA18C7A954, 0xAC510CA84165BAB6, 0xA48094C852F5E8D3, 0xE5B227867195973F,
+    0x7CEA381028F2FB46, 0xE00B0369B1851D90, 0xDA1D8D76B8523621, 0x0D7A6A84DC90E920,
+    0x8FF1D6259F17E0B0, 0xB96DB8FEBA6BC7BC, 0x4C31FAE971EC3CB1, 0x583C45F98AD723CA,
+    0x957ED08F74AA4A7E, 0xE08160CC1F8F5838, 0x839347905C6AB78B, 0x665B0E7E5CC2FA58,
+    0xA573F5965D74E810, 0xCCED75C30EB7F28B, 0x4F690DC9EA883717, 0xDC9B5900BDAB2330,
+    0x25B133224D11EFB4, 0xC642C6751C27FE11, 0xEA038CB14959123A, 0xD936C2F055A96C24,
+    0x5E5EB4DCF38A5F92, 0x10CEB7BE28E49EFF, 0xE38854E3676C9981, 0x80485B5123B76004,
+    0x85FA65A918A22186, 0x3DA4DBA489C7CCD1, 0xE6BA319B7F965CDD, 0x377C7556DF325636,
+    0x7A77440D3DAE18F5, 0xBA5217308FE3193E, 0x764EEF50B777359F, 0x9C81CD32071D392E,
+    0x00EA04F9E24860BC, 0x9BA02821F4E5FDC5, 0xC173CBEB0FCE1C1B, 0xD3F35AAFABCCB672,
+    0x99E5B67C5AA8667D, 0x3EBA42A511D9CFE1, 0xC8FE8485D2F60560, 0x558D1EF9EF3982FF,
+    0x41F4F1E0AB020B58, 0x4B26AA45934A4445, 0xF8B4C7F42EDECA84, 0x1FBD7B462E5B8113,
+    0x003565BE6CAC4BF3, 0xB5E86A2633904AE2, 0xB4C0CCF61FF22AC5, 0xEEF6872F9B7965FA,
+    0x858D1623BD86A05F, 0xD8D48AA14C0FCA66, 0xA53FE41F9A2751FF, 0x53C96948002E7775,
+    0xA4F1B20B353198A5, 0xDCC10343E4A71920, 0xB75DDC80321CFB35, 0x8B9A762A1E0D305B,
+    0x99FE13D95F59734C, 0x8B1CAA0DDC8FD594, 0xE3AA39A906D6955C, 0x67E0958E6EDEF381,
+    0xE07C8B46BC358DA2, 0x15BD1EEA6F2EA57E, 0x57058DBB531374DC, 0x279723F225F5AC7F,
+    0xBE847E29438D0C54, 0xE097F313757C651C, 0xBA220F5254BE0BBA, 0xA54C4D11B9384AB7,
+    0x8A7584B5CF6A9276, 0xE9AA8CE24B3241CC, 0xE0777DE831C61389, 0xA7423C4781D7C510,
+    0x399C03CC4D6D2440, 0x560FF5F94361F3BF, 0x3849E99739B68C7D, 0x96FD1F353057CEFC,
+    0x4424BB64127C636D, 0x97CAFD7E0D779FA3, 0x38D50A3905CA1FE3, 0x5C12BFF387CEC251,
+    0xF89872E4555D0E4F, 0x08ED303C788F6C3B, 0x293615021944BD93, 0x852D177A2C3320D1,
+    0x98FBFBC272E856EC, 0x4EA10D001A9856F3, 0xE83D8A11930E2ADD, 0x0A466B35661ED55D,
+    0xF73CEF3D400CCD7A, 0x103BC62EF9096242, 0x3D6E62844304E7EB, 0xA41F60FD3A86D5BB,
+    0x6F7EE12076F7780E, 0x67C39825A0D4CB21, 0xC9B47AB96EE49E4F, 0x7F5CBF0EE567F9A9,
+    0xD0298B81A1D5F892, 0x3E747EE075304663, 0x7000EF4EB72758C9, 0x5D632DF41A0BFD05,
+    0xB6EF62FA8C60F1E8, 0x35C3192FCFBAA4C8, 0x97C21707229DE85E, 0xA89D38C4003BE2B0,
+];
+
+/// Configuration for [`CdcSegmenter`].
+#[derive(Debug, Clone)]
+pub struct CdcSegmenterConfig {
+    /// Target chunk size in milliseconds; actual chunks fall in
+    /// `[0.5x, 2x]` of this by construction.
+    pub target_chunk_ms: u32,
+    pub sample_rate: u32,
+}
+
+/// Cuts a stream of audio samples at content-defined boundaries with a
+/// FastCDC-style gear hash, rather than a fixed wall-clock grid or a
+/// silence detector.
+///
+/// Samples are quantized to 16-bit PCM bytes (matching the WAVs this
+/// benchmark writes everywhere else) before hashing, so two renders of the
+/// same audio through different float rounding paths still cut identically.
+/// A rolling gear hash is updated one byte at a time
+/// (`hash = (hash << 1).wrapping_add(GEAR[byte])`), and a cut is declared
+/// once the chunk has reached its minimum size and `hash & mask == 0` for
+/// the mask appropriate to how far past the minimum the chunk already is
+/// (FastCDC's "normalized chunking": a stricter mask before the target
+/// discourages early cuts, a looser mask after it encourages a cut soon
+/// after target), or once the chunk hits its hard maximum regardless of the
+/// hash.
+pub struct CdcSegmenter {
+    min_bytes: usize,
+    max_bytes: usize,
+    target_bytes: usize,
+    mask_small: u64,
+    mask_large: u64,
+    hash: u64,
+    /// Bytes hashed so far in the chunk currently being accumulated.
+    chunk_byte_count: usize,
+    current_chunk: Vec<f32>,
+}
+
+impl CdcSegmenter {
+    pub fn new(config: CdcSegmenterConfig) -> Self {
+        let target_bytes = ((config.target_chunk_ms as u64 * config.sample_rate as u64 * 2) / 1000).max(4) as usize;
+        let min_bytes = target_bytes / 2;
+        let max_bytes = target_bytes * 2;
+
+        // log2(target_bytes), used to derive a stricter/looser mask around
+        // it per FastCDC's normalized chunking.
+        let target_bits = (usize::BITS - target_bytes.max(1).leading_zeros()).max(2);
+        let mask_small = (1u64 << (target_bits + 1)) - 1; // more bits set -> harder to hit -> discourages early cuts
+        let mask_large = (1u64 << (target_bits.saturating_sub(1))) - 1; // fewer bits set -> easier to hit -> encourages a cut soon after target
+
+        Self {
+            min_bytes,
+            max_bytes,
+            target_bytes,
+            mask_small,
+            mask_large,
+            hash: 0,
+            chunk_byte_count: 0,
+            current_chunk: Vec::new(),
+        }
+    }
+
+    /// Feed newly arrived samples. Returns any chunks that were closed out
+    /// by a content-defined cut during this call, in order.
+    pub fn process(&mut self, samples: &[f32]) -> Vec<Vec<f32>> {
+        let mut closed_chunks = Vec::new();
+
+        for &sample in samples {
+            self.current_chunk.push(sample);
+
+            for byte in quantize_sample(sample) {
+                self.hash = (self.hash << 1).wrapping_add(GEAR[byte as usize]);
+                self.chunk_byte_count += 1;
+
+                if self.chunk_byte_count < self.min_bytes {
+                    continue;
+                }
+                if self.chunk_byte_count >= self.max_bytes {
+                    closed_chunks.push(self.cut());
+                    continue;
+                }
+                let mask = if self.chunk_byte_count < self.target_bytes { self.mask_small } else { self.mask_large };
+                if self.hash & mask == 0 {
+                    closed_chunks.push(self.cut());
+                }
+            }
+        }
+
+        closed_chunks
+    }
+
+    fn cut(&mut self) -> Vec<f32> {
+        self.hash = 0;
+        self.chunk_byte_count = 0;
+        std::mem::take(&mut self.current_chunk)
+    }
+
+    /// Take whatever samples have accumulated since the last cut, e.g. to
+    /// flush a trailing chunk at end of stream. Returns `None` if nothing
+    /// has accumulated.
+    pub fn flush(&mut self) -> Option<Vec<f32>> {
+        if self.current_chunk.is_empty() {
+            None
+        } else {
+            Some(self.cut())
+        }
+    }
+}
+
+/// Quantizes one sample to 16-bit signed PCM, little-endian, matching
+/// `hound`'s default byte order for `SampleFormat::Int` WAVs.
+fn quantize_sample(sample: f32) -> [u8; 2] {
+    let clamped = sample.clamp(-1.0, 1.0);
+    let quantized = (clamped * i16::MAX as f32) as i16;
+    quantized.to_le_bytes()
+}
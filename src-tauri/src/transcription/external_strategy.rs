@@ -208,6 +208,7 @@ impl TranscriptionStrategy for ExternalServiceStrategy {
                         processing_time_ms,
                         strategy_used: "ExternalService".to_string(),
                         chunks_processed: 1,
+                        ..Default::default()
                     })
                 } else if let Some(err_value) = result.get("Err") {
                     Err(format!("External service error: {:?}", err_value))
@@ -214,11 +214,89 @@ impl FileBasedRingBufferTranscriber {
         Ok(Some(text))
     }
 
+    /// Process exactly `span` of audio starting at the current position, then
+    /// advance the position by that span. Unlike `process_next_chunk`, which
+    /// always consumes the fixed `chunk_duration`, this lets a caller (e.g.
+    /// VAD-driven segmentation) flush a variable-length speech segment.
+    pub async fn process_span(&mut self, span: Duration) -> Result<Option<String>, String> {
+        let available_duration = self.wav_reader.get_available_duration()?;
+        let required_duration = self.current_position + span;
+
+        if available_duration < required_duration {
+            return Ok(None);
+        }
+
+        let chunk_data = self.wav_reader.extract_chunk(self.current_position, span)?;
+
+        if chunk_data.is_empty() {
+            self.current_position += span;
+            self.next_chunk_id += 1;
+            return Ok(None);
+        }
+
+        let chunk_filename = format!(
+            "file_vad_chunk_{}_{}.wav",
+            self.next_chunk_id,
+            self.current_position.as_millis()
+        );
+        let chunk_path = self.temp_dir.join(chunk_filename);
+
+        self.wav_reader.save_chunk_to_file(&chunk_data, &chunk_path)?;
+
+        let text = {
+            let transcriber = self.transcriber.lock().await;
+            transcriber
+                .transcribe_file(&chunk_path)
+                .map_err(|e| format!("Transcription failed: {}", e))?
+        };
+
+        if chunk_path.exists() {
+            if let Err(e) = std::fs::remove_file(&chunk_path) {
+                warn(
+                    Component::RingBuffer,
+                    &format!("Failed to clean up chunk file: {}", e),
+                );
+            }
+        }
+
+        info(
+            Component::RingBuffer,
+            &format!(
+                "VAD-driven chunk {} completed ({:?}): \"{}\"",
+                self.next_chunk_id, span, text
+            ),
+        );
+
+        self.current_position += span;
+        self.next_chunk_id += 1;
+
+        Ok(Some(text))
+    }
+
+    /// Peek at `duration` of audio starting `offset` past the current
+    /// position, without advancing the position. Used by VAD-driven
+    /// segmentation to score audio ahead of committing to a chunk boundary.
+    pub fn peek_samples_at(&self, offset: Duration, duration: Duration) -> Result<Vec<f32>, String> {
+        self.wav_reader.extract_chunk(self.current_position + offset, duration)
+    }
+
+    /// Duration of audio available past the current position that hasn't
+    /// been flushed to a chunk yet.
+    pub fn unprocessed_duration(&self) -> Result<Duration, String> {
+        let available_duration = self.wav_reader.get_available_duration()?;
+        Ok(available_duration.saturating_sub(self.current_position))
+    }
+
+    /// Sample rate of the underlying WAV file, if known.
+    pub fn sample_rate(&self) -> Option<u32> {
+        self.wav_reader.get_spec().map(|spec| spec.sample_rate)
+    }
+
     /// Check if more audio data is available for processing
     pub fn has_new_data(&self) -> Result<bool, String> {
         let available_duration = self.wav_reader.get_available_duration()?;
         let next_chunk_end = self.current_position + self.chunk_duration;
-        
+
         Ok(available_duration >= next_chunk_end)
     }
 
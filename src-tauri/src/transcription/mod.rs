@@ -7,14 +7,30 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
 
+pub mod backend;
+pub mod candle_backend;
+pub mod cdc_segmenter;
 pub mod file_based_ring_buffer_transcriber;
 pub mod ring_buffer_transcriber;
 pub mod streaming_transcriber;
 pub mod native_streaming_strategy;
+pub mod stability_tracker;
 pub mod strategy;
 pub mod external_service;
 pub mod external_strategy;
-
+pub mod overlap_stitcher;
+pub mod partial_stability;
+pub mod silero_vad;
+pub mod timestamp_stability;
+pub mod vad;
+pub mod vad_segmenter;
+pub mod vocabulary;
+
+pub use backend::{TranscriptionBackend, TranscriptionBackendKind};
+pub use cdc_segmenter::{CdcSegmenter, CdcSegmenterConfig};
+pub use partial_stability::{PartialResultStabilizer, StabilityLevel, StreamingUpdate};
+pub use vad_segmenter::{VadSegmenter, VadSegmenterConfig};
+pub use vocabulary::VocabularyConfig;
 pub use strategy::{
     TranscriptionConfig, TranscriptionResult, TranscriptionStrategy, TranscriptionStrategySelector,
 };
@@ -280,7 +296,40 @@ impl Transcriber {
     pub fn transcribe(&self, audio_path: &Path) -> Result<String, String> {
         // Load audio file
         let audio_data = self.load_audio(audio_path)?;
-        
+        self.transcribe_samples(&audio_data, &vocabulary::VocabularyConfig::default())
+    }
+
+    /// Like [`Self::transcribe`], but first drops leading/trailing/internal
+    /// silence via [`crate::audio::trim_silence`] so Whisper only decodes
+    /// audio the VAD actually considers speech, and biases/corrects decoding
+    /// toward `vocabulary`'s phrases (see [`vocabulary::build_initial_prompt`]
+    /// and [`vocabulary::apply_corrections`]). Returns the trim stats
+    /// alongside the text so callers can report how much was cut.
+    pub fn transcribe_trimmed(
+        &self,
+        audio_path: &Path,
+        trimmer_config: &crate::audio::SilenceTrimmerConfig,
+        vocabulary: &vocabulary::VocabularyConfig,
+    ) -> Result<(String, crate::audio::TrimStats), String> {
+        let audio_data = self.load_audio(audio_path)?;
+        let (trimmed, stats) = crate::audio::trim_silence(
+            &audio_data,
+            crate::audio::resample::WHISPER_SAMPLE_RATE,
+            trimmer_config,
+        );
+        let text = self.transcribe_samples(&trimmed, vocabulary)?;
+        Ok((text, stats))
+    }
+
+    /// One-shot whisper decode of already-loaded 16kHz mono samples. Factored
+    /// out of `transcribe` so `transcribe_streaming` can re-run this on
+    /// successive growing prefixes of a file's audio without re-reading or
+    /// re-resampling it each time.
+    fn transcribe_samples(
+        &self,
+        audio_data: &[f32],
+        vocabulary: &vocabulary::VocabularyConfig,
+    ) -> Result<String, String> {
         // Check audio duration and warn if very short
         let duration_seconds = audio_data.len() as f32 / 16000.0; // 16kHz after conversion
         if duration_seconds < 0.5 {
@@ -366,7 +415,10 @@ impl Transcriber {
         
         // Set initial prompt to help with common short utterances
         // This helps Whisper understand context for single words or brief phrases
-        params.set_initial_prompt("Speech transcription of a brief utterance or command:");
+        params.set_initial_prompt(&vocabulary::build_initial_prompt(
+            "Speech transcription of a brief utterance or command:",
+            vocabulary,
+        ));
 
         // Run the transcription
         // CRITICAL: Serialize state creation to prevent CoreML initialization deadlocks
@@ -401,7 +453,7 @@ impl Transcriber {
         log::info!(target: "whisper", "Starting transcription of {} samples", audio_data.len());
 
         state
-            .full(params, &audio_data)
+            .full(params, audio_data)
             .map_err(|e| format!("Failed to transcribe: {}", e))?;
 
         // Log transcription complete
@@ -422,7 +474,7 @@ impl Transcriber {
             transcription.push(' ');
         }
 
-        Ok(transcription.trim().to_string())
+        Ok(vocabulary::apply_corrections(transcription.trim(), vocabulary))
     }
 
     fn load_audio(&self, audio_path: &Path) -> Result<Vec<f32>, String> {
@@ -430,6 +482,56 @@ impl Transcriber {
         WhisperAudioConverter::convert_wav_file_for_whisper(audio_path)
     }
 
+    /// Simulates streaming transcription over a file: re-decodes growing
+    /// prefixes of `audio_path`'s audio (as if that much had "arrived" so
+    /// far) and feeds each successive hypothesis through a
+    /// [`PartialResultStabilizer`], emitting a `StreamingUpdate` after every
+    /// chunk on the returned channel. Unlike [`streaming_transcriber::StreamingTranscriber`],
+    /// which decodes live microphone chunks and stitches them by audio
+    /// overlap, each partial here is a full re-decode of everything so far,
+    /// which is what lets word-level prefix stability work the way AWS
+    /// Transcribe's does. Consumes `self` via `Arc` so the decode loop can
+    /// run on its own thread while this call returns immediately.
+    pub fn transcribe_streaming(
+        self: Arc<Self>,
+        audio_path: &Path,
+        stability: partial_stability::StabilityLevel,
+    ) -> Result<std::sync::mpsc::Receiver<partial_stability::StreamingUpdate>, String> {
+        let audio_data = self.load_audio(audio_path)?;
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        std::thread::spawn(move || {
+            const CHUNK_SAMPLES: usize = 16_000; // ~1s of audio "arriving" per partial decode
+            let mut stabilizer = partial_stability::PartialResultStabilizer::new(stability);
+            let mut end = CHUNK_SAMPLES.min(audio_data.len());
+
+            while end > 0 {
+                match self.transcribe_samples(&audio_data[..end], &vocabulary::VocabularyConfig::default()) {
+                    Ok(partial_text) => {
+                        let update = stabilizer.push_partial(&partial_text);
+                        if tx.send(update).is_err() {
+                            break; // receiver dropped
+                        }
+                    }
+                    Err(e) => {
+                        warn(
+                            Component::Transcription,
+                            &format!("Streaming partial decode failed: {}", e),
+                        );
+                        break;
+                    }
+                }
+
+                if end >= audio_data.len() {
+                    break;
+                }
+                end = (end + CHUNK_SAMPLES).min(audio_data.len());
+            }
+        });
+
+        Ok(rx)
+    }
+
     // Resampling is now handled by WhisperAudioConverter
     // Keeping this for backward compatibility if needed
     #[allow(dead_code)]
@@ -455,6 +557,15 @@ impl Transcriber {
     }
 }
 
+impl backend::TranscriptionBackend for Transcriber {
+    fn transcribe_samples(&mut self, samples: &[f32]) -> Result<String, String> {
+        // whisper.cpp's `WhisperState` is created fresh per call (see
+        // `transcribe_samples` above), so there's no per-instance state to
+        // mutate here - `&mut self` is only required to satisfy the trait.
+        Transcriber::transcribe_samples(self, samples, &vocabulary::VocabularyConfig::default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
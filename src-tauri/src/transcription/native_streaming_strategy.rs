@@ -187,7 +187,10 @@ impl TranscriptionStrategy for NativeStreamingTranscriptionStrategy {
         // Create streaming pipeline
         let pipeline = StreamingTranscriptionPipeline::new(
             &self.model_path,
-            self.recorder_config.clone(),
+            vec![crate::audio::streaming_mixer::AudioSourceConfig {
+                recorder_config: self.recorder_config.clone(),
+                gain: 1.0,
+            }],
             self.transcriber_config.clone(),
         ).await?;
 
@@ -327,6 +330,7 @@ impl TranscriptionStrategy for NativeStreamingTranscriptionStrategy {
             processing_time_ms: total_processing_time.as_millis() as u64,
             strategy_used: format!("{} (16kHz mono streaming)", self.name()),
             chunks_processed: total_chunks,
+            ..Default::default()
         })
     }
 
@@ -0,0 +1,102 @@
+/// Stitches together transcripts from overlapping audio chunks into one
+/// continuous stream of text.
+///
+/// `overlap_duration_secs` used to be forced to `0.0` because re-transcribing
+/// the tail of the previous chunk just repeated its words verbatim. This
+/// aligns each new chunk's leading tokens against the previous chunk's
+/// trailing tokens (a case- and punctuation-insensitive suffix/prefix match)
+/// and drops the restated prefix. It also holds back each chunk's last
+/// `CONFIRM_MARGIN_TOKENS` words: they're reported back as the tentative
+/// tail so the caller can mark them `is_partial`, and are committed when the
+/// next chunk arrives. That commit does not check whether the next chunk's
+/// alignment re-covered or agreed with those words.
+
+/// How many trailing tokens of a chunk are held back as unconfirmed until
+/// the next chunk is stitched.
+const CONFIRM_MARGIN_TOKENS: usize = 3;
+/// Bounds how far back the suffix/prefix search looks, so a long chunk
+/// doesn't make every alignment an O(n) scan over the whole transcript.
+const MAX_ALIGN_TOKENS: usize = 24;
+
+/// Text returned by one call to [`OverlapStitcher::stitch`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StitchedText {
+    /// The previous chunk's tentative words plus this chunk's settled words;
+    /// stable and won't be revised by a later chunk.
+    pub confirmed_text: String,
+    /// This chunk's trailing words, not yet confirmed by the next window.
+    pub tentative_text: String,
+}
+
+pub struct OverlapStitcher {
+    /// Normalized trailing tokens of the last chunk processed, used to find
+    /// how much of the next chunk restates them.
+    previous_tail_normalized: Vec<String>,
+    /// Raw (original casing/punctuation) tokens held back from the previous
+    /// chunk, committed on the next `stitch` call.
+    tentative_tokens: Vec<String>,
+}
+
+impl OverlapStitcher {
+    pub fn new() -> Self {
+        Self {
+            previous_tail_normalized: Vec::new(),
+            tentative_tokens: Vec::new(),
+        }
+    }
+
+    /// Feed the raw transcript of a newly-processed overlapping chunk.
+    pub fn stitch(&mut self, raw_text: &str) -> StitchedText {
+        let raw_tokens = tokenize(raw_text);
+        let normalized: Vec<String> = raw_tokens.iter().map(|t| normalize_token(t)).collect();
+
+        let overlap_len = longest_suffix_prefix_match(&self.previous_tail_normalized, &normalized);
+        let new_tokens = &raw_tokens[overlap_len.min(raw_tokens.len())..];
+
+        // The previous chunk's tentative tail is confirmed now: this next
+        // overlapping window has moved past it, so nothing further will
+        // revise it.
+        let mut confirmed_tokens = std::mem::take(&mut self.tentative_tokens);
+
+        let hold_back = CONFIRM_MARGIN_TOKENS.min(new_tokens.len());
+        let settle_point = new_tokens.len() - hold_back;
+        let (settled, tentative) = new_tokens.split_at(settle_point);
+
+        confirmed_tokens.extend_from_slice(settled);
+        self.tentative_tokens = tentative.to_vec();
+        self.previous_tail_normalized = normalized
+            .iter()
+            .rev()
+            .take(MAX_ALIGN_TOKENS)
+            .rev()
+            .cloned()
+            .collect();
+
+        StitchedText {
+            confirmed_text: confirmed_tokens.join(" "),
+            tentative_text: tentative.join(" "),
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split_whitespace().map(|s| s.to_string()).collect()
+}
+
+/// Lowercases and strips punctuation so "Hello," and "hello" align.
+fn normalize_token(token: &str) -> String {
+    token.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase()
+}
+
+/// Finds the longest `k` such that the last `k` tokens of `previous_tail`
+/// equal the first `k` tokens of `next_tokens` - how much of `next_tokens`
+/// restates the end of the previous chunk because of window overlap.
+fn longest_suffix_prefix_match(previous_tail: &[String], next_tokens: &[String]) -> usize {
+    let max_k = previous_tail.len().min(next_tokens.len());
+    for k in (1..=max_k).rev() {
+        if previous_tail[previous_tail.len() - k..] == next_tokens[..k] {
+            return k;
+        }
+    }
+    0
+}
@@ -0,0 +1,171 @@
+/// Word-prefix stability for streaming transcription, AWS-Transcribe style.
+///
+/// Each successive partial is a *full re-decode* of everything transcribed so
+/// far (not a diff of one audio window against the next, which is what
+/// [`super::stability_tracker::StabilityTracker`] handles). This tracker keeps
+/// a ring buffer of the last K such partials, tokenizes each by word, and
+/// looks for the longest word prefix that is identical across all K buffered
+/// hypotheses. Any of those words beyond what's already committed are
+/// promoted into `committed`; the remainder of the latest partial is
+/// `tentative`. Once a word is committed it is never retracted, so the UI can
+/// render committed text immediately and only the tentative tail is ever
+/// rewritten.
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// How many consecutive matching partials are required before a word is
+/// committed. Higher levels commit later but are less prone to flicker from
+/// whisper revising its own output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StabilityLevel {
+    Low,
+    Medium,
+    High,
+}
+
+impl StabilityLevel {
+    fn window_size(&self) -> usize {
+        match self {
+            StabilityLevel::Low => 2,
+            StabilityLevel::Medium => 4,
+            StabilityLevel::High => 6,
+        }
+    }
+}
+
+impl Default for StabilityLevel {
+    fn default() -> Self {
+        StabilityLevel::Medium
+    }
+}
+
+/// A single `{ committed, tentative }` update emitted after a new partial is
+/// pushed into a [`PartialResultStabilizer`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StreamingUpdate {
+    pub committed: String,
+    pub tentative: String,
+}
+
+/// Buffers the last K full-hypothesis partials and tracks which word prefix
+/// has stabilized across all of them.
+pub struct PartialResultStabilizer {
+    window_size: usize,
+    recent_partials: VecDeque<Vec<String>>,
+    committed_words: Vec<String>,
+}
+
+impl PartialResultStabilizer {
+    pub fn new(level: StabilityLevel) -> Self {
+        Self {
+            window_size: level.window_size(),
+            recent_partials: VecDeque::new(),
+            committed_words: Vec::new(),
+        }
+    }
+
+    /// Feed in the latest full-hypothesis partial and return the resulting
+    /// `{ committed, tentative }` split.
+    pub fn push_partial(&mut self, text: &str) -> StreamingUpdate {
+        let words: Vec<String> = text.split_whitespace().map(|w| w.to_string()).collect();
+
+        if self.recent_partials.len() >= self.window_size {
+            self.recent_partials.pop_front();
+        }
+        self.recent_partials.push_back(words.clone());
+
+        if self.recent_partials.len() == self.window_size {
+            let stable_prefix_len = self.longest_common_word_prefix();
+            if stable_prefix_len > self.committed_words.len() {
+                let newest = self.recent_partials.back().unwrap();
+                self.committed_words
+                    .extend_from_slice(&newest[self.committed_words.len()..stable_prefix_len]);
+            }
+        }
+
+        let tentative = if words.len() > self.committed_words.len() {
+            words[self.committed_words.len()..].join(" ")
+        } else {
+            String::new()
+        };
+
+        StreamingUpdate {
+            committed: self.committed_words.join(" "),
+            tentative,
+        }
+    }
+
+    /// Length of the longest word prefix shared by every buffered partial.
+    fn longest_common_word_prefix(&self) -> usize {
+        let shortest_len = self
+            .recent_partials
+            .iter()
+            .map(|p| p.len())
+            .min()
+            .unwrap_or(0);
+
+        let mut len = 0;
+        'words: while len < shortest_len {
+            let word = &self.recent_partials[0][len];
+            for partial in self.recent_partials.iter().skip(1) {
+                if &partial[len] != word {
+                    break 'words;
+                }
+            }
+            len += 1;
+        }
+        len
+    }
+
+    /// Clears all buffered state, e.g. when a new utterance starts.
+    pub fn reset(&mut self) {
+        self.recent_partials.clear();
+        self.committed_words.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commits_words_once_stable_across_the_window() {
+        let mut stabilizer = PartialResultStabilizer::new(StabilityLevel::Low); // window = 2
+
+        let update = stabilizer.push_partial("hello");
+        assert_eq!(update.committed, "");
+        assert_eq!(update.tentative, "hello");
+
+        let update = stabilizer.push_partial("hello world");
+        assert_eq!(update.committed, "hello");
+        assert_eq!(update.tentative, "world");
+
+        let update = stabilizer.push_partial("hello world today");
+        assert_eq!(update.committed, "hello world");
+        assert_eq!(update.tentative, "today");
+    }
+
+    #[test]
+    fn never_retracts_committed_words_on_a_late_correction() {
+        let mut stabilizer = PartialResultStabilizer::new(StabilityLevel::Low); // window = 2
+
+        stabilizer.push_partial("hello world");
+        stabilizer.push_partial("hello world");
+        let update = stabilizer.push_partial("hello wold"); // whisper revises "world" -> "wold"
+
+        assert_eq!(update.committed, "hello world");
+    }
+
+    #[test]
+    fn higher_stability_requires_a_longer_matching_run() {
+        let mut stabilizer = PartialResultStabilizer::new(StabilityLevel::High); // window = 6
+
+        for _ in 0..5 {
+            let update = stabilizer.push_partial("hello world");
+            assert_eq!(update.committed, "");
+        }
+        let update = stabilizer.push_partial("hello world");
+        assert_eq!(update.committed, "hello world");
+    }
+}
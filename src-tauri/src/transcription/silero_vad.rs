@@ -0,0 +1,119 @@
+use crate::logger::{debug, info, Component};
+use ort::{GraphOptimizationLevel, Session};
+use std::path::{Path, PathBuf};
+
+/// Number of floats in each of Silero's recurrent state tensors
+/// (`[num_layers=2, batch=1, hidden_size=64]`).
+const STATE_SIZE: usize = 2 * 1 * 64;
+
+/// Streaming speech-probability detector backed by a Silero VAD ONNX model.
+///
+/// Unlike the energy-based `VoiceActivityDetector` in `transcription::vad`,
+/// this carries the model's recurrent hidden/cell state (`h`/`c`) between
+/// calls so each `process()` is scored as a continuation of the same audio
+/// stream rather than in isolation.
+pub struct SileroVad {
+    session: Session,
+    chunk_size: usize,
+    sample_rate: u32,
+    h: Vec<f32>,
+    c: Vec<f32>,
+}
+
+impl SileroVad {
+    /// Load the Silero VAD ONNX model and prepare a fresh streaming session.
+    /// `chunk_size` is the number of samples every `process()` call expects.
+    pub fn new(model_path: &Path, chunk_size: usize, sample_rate: u32) -> Result<Self, String> {
+        let session = Session::builder()
+            .map_err(|e| format!("Failed to create ONNX session builder: {}", e))?
+            .with_optimization_level(GraphOptimizationLevel::Level3)
+            .map_err(|e| format!("Failed to set ONNX optimization level: {}", e))?
+            .with_model_from_file(model_path)
+            .map_err(|e| format!("Failed to load Silero VAD model from {:?}: {}", model_path, e))?;
+
+        info(
+            Component::RingBuffer,
+            &format!(
+                "Silero VAD initialized from {:?}: chunk_size={}, sample_rate={}",
+                model_path, chunk_size, sample_rate
+            ),
+        );
+
+        Ok(Self {
+            session,
+            chunk_size,
+            sample_rate,
+            h: vec![0.0; STATE_SIZE],
+            c: vec![0.0; STATE_SIZE],
+        })
+    }
+
+    /// Resolve the conventional on-disk location for the bundled Silero VAD
+    /// model, alongside the Whisper models the rest of the app uses.
+    pub fn default_model_path(models_dir: &Path) -> PathBuf {
+        models_dir.join("silero_vad.onnx")
+    }
+
+    /// Score one fixed-size frame of audio, returning the probability that it
+    /// contains speech and advancing the model's recurrent state so the next
+    /// call is scored as a continuation of this one.
+    pub fn process(&mut self, frame: &[f32]) -> Result<f32, String> {
+        if frame.len() != self.chunk_size {
+            return Err(format!(
+                "Silero VAD frame must be exactly {} samples, got {}",
+                self.chunk_size,
+                frame.len()
+            ));
+        }
+
+        let input = ort::Value::from_array(([1usize, frame.len()], frame.to_vec()))
+            .map_err(|e| format!("Failed to build VAD input tensor: {}", e))?;
+        let sr_input = ort::Value::from_array(([1usize], vec![self.sample_rate as i64]))
+            .map_err(|e| format!("Failed to build VAD sample-rate tensor: {}", e))?;
+        let h_input = ort::Value::from_array(([2usize, 1usize, 64usize], self.h.clone()))
+            .map_err(|e| format!("Failed to build VAD hidden-state tensor: {}", e))?;
+        let c_input = ort::Value::from_array(([2usize, 1usize, 64usize], self.c.clone()))
+            .map_err(|e| format!("Failed to build VAD cell-state tensor: {}", e))?;
+
+        let outputs = self
+            .session
+            .run(ort::inputs![input, sr_input, h_input, c_input]
+                .map_err(|e| format!("Failed to bind VAD inputs: {}", e))?)
+            .map_err(|e| format!("Silero VAD inference failed: {}", e))?;
+
+        let speech_prob = outputs[0]
+            .try_extract_tensor::<f32>()
+            .map_err(|e| format!("Failed to read VAD output: {}", e))?
+            .1[0];
+
+        self.h = outputs[1]
+            .try_extract_tensor::<f32>()
+            .map_err(|e| format!("Failed to read VAD hidden state: {}", e))?
+            .1
+            .to_vec();
+        self.c = outputs[2]
+            .try_extract_tensor::<f32>()
+            .map_err(|e| format!("Failed to read VAD cell state: {}", e))?
+            .1
+            .to_vec();
+
+        debug(
+            Component::RingBuffer,
+            &format!("Silero VAD frame probability: {:.3}", speech_prob),
+        );
+
+        Ok(speech_prob)
+    }
+
+    /// Number of samples expected per `process()` call.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Reset the recurrent state to silence, e.g. after a long gap between
+    /// recordings where carrying stale state over would bias detection.
+    pub fn reset_state(&mut self) {
+        self.h = vec![0.0; STATE_SIZE];
+        self.c = vec![0.0; STATE_SIZE];
+    }
+}
@@ -1,5 +1,6 @@
+use crate::audio::{SilenceTrimmerConfig, TrimStats};
 use crate::logger::{debug, error, info, warn, Component};
-use crate::transcription::Transcriber;
+use crate::transcription::{Transcriber, VocabularyConfig};
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Instant;
@@ -20,6 +21,12 @@ pub struct SimpleTranscriptionService {
     /// Performance metrics
     total_transcriptions: u64,
     total_processing_time: std::time::Duration,
+    /// Thresholds for trimming silence out of a recording before it's
+    /// handed to the transcriber; see `audio::silence_trimmer`.
+    trim_config: SilenceTrimmerConfig,
+    /// Custom phrases/substitutions used to bias decoding and correct the
+    /// decoded text; see `transcription::vocabulary`.
+    vocabulary_config: VocabularyConfig,
 }
 
 #[derive(Debug, Clone)]
@@ -46,6 +53,9 @@ pub struct TranscriptionResponse {
     pub audio_duration_seconds: f64,
     /// Confidence score if available
     pub confidence: Option<f32>,
+    /// How much of `audio_duration_seconds` was trimmed as silence before
+    /// transcription; zero when trimming is disabled.
+    pub trim_stats: TrimStats,
 }
 
 impl SimpleTranscriptionService {
@@ -61,9 +71,22 @@ impl SimpleTranscriptionService {
             model_name,
             total_transcriptions: 0,
             total_processing_time: std::time::Duration::ZERO,
+            trim_config: SilenceTrimmerConfig::default(),
+            vocabulary_config: VocabularyConfig::default(),
         }
     }
 
+    /// Override the silence-trimming thresholds, e.g. from user settings.
+    pub fn set_trim_config(&mut self, trim_config: SilenceTrimmerConfig) {
+        self.trim_config = trim_config;
+    }
+
+    /// Override the custom-vocabulary phrases/substitutions, e.g. from user
+    /// settings.
+    pub fn set_vocabulary_config(&mut self, vocabulary_config: VocabularyConfig) {
+        self.vocabulary_config = vocabulary_config;
+    }
+
     /// Transcribe an audio file with performance optimization
     pub async fn transcribe(&mut self, request: TranscriptionRequest) -> Result<TranscriptionResponse, String> {
         let start_time = Instant::now();
@@ -84,10 +107,12 @@ impl SimpleTranscriptionService {
         // Log performance expectation based on model
         self.log_performance_expectation(audio_duration);
 
-        // Perform transcription using the existing transcriber methods
+        // Perform transcription using the existing transcriber methods,
+        // trimming silence out of the recording first so Whisper isn't
+        // charged for dead air.
         let transcription_result = {
             let transcriber = self.transcriber.lock().await;
-            transcriber.transcribe(&request.audio_path)
+            transcriber.transcribe_trimmed(&request.audio_path, &self.trim_config, &self.vocabulary_config)
         };
 
         let processing_time = start_time.elapsed();
@@ -95,7 +120,7 @@ impl SimpleTranscriptionService {
         let real_time_factor = processing_time.as_secs_f64() / audio_duration;
 
         match transcription_result {
-            Ok(text) => {
+            Ok((text, trim_stats)) => {
                 // Update performance metrics
                 self.total_transcriptions += 1;
                 self.total_processing_time += processing_time;
@@ -107,6 +132,7 @@ impl SimpleTranscriptionService {
                     model_name: self.model_name.clone(),
                     audio_duration_seconds: audio_duration,
                     confidence: None,  // Whisper doesn't provide confidence scores directly
+                    trim_stats,
                 };
 
                 // Log performance metrics
@@ -202,6 +228,19 @@ impl SimpleTranscriptionService {
             );
         }
 
+        let trimmed_secs = response.trim_stats.trimmed_duration_secs();
+        if trimmed_secs > 0.0 {
+            info(
+                Component::Transcription,
+                &format!(
+                    "✂️ Silence trimming dropped {:.1}s before transcription ({:.1}s → {:.1}s)",
+                    trimmed_secs,
+                    response.trim_stats.input_duration_secs,
+                    response.trim_stats.output_duration_secs
+                ),
+            );
+        }
+
         // Log text preview (first 100 characters)
         let text_preview = if response.text.len() > 100 {
             format!("{}...", &response.text[..100])
@@ -231,6 +270,16 @@ impl SimpleTranscriptionService {
         }
     }
 
+    /// Decode raw in-memory samples directly, bypassing `transcribe`'s
+    /// file-based entry point and performance bookkeeping. Used for
+    /// streaming previews, where the caller re-decodes a trailing window of
+    /// a still-growing recording many times per session and doesn't want
+    /// each preview polluting `total_transcriptions`/`total_processing_time`.
+    pub async fn transcribe_preview(&self, audio_data: &[f32]) -> Result<String, String> {
+        let transcriber = self.transcriber.lock().await;
+        transcriber.transcribe_samples(audio_data, &self.vocabulary_config)
+    }
+
     /// Reset performance statistics
     pub fn reset_stats(&mut self) {
         self.total_transcriptions = 0;
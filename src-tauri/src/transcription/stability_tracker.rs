@@ -0,0 +1,270 @@
+/// Stability tracking for streaming transcription.
+///
+/// Whisper re-decodes overlapping audio windows as recording progresses, so the
+/// tail of a hypothesis keeps changing while the earlier text settles. This
+/// tracker buffers the current hypothesis as a `VecDeque` of items keyed by time
+/// window, diffs each new partial decode against the buffer, and promotes an
+/// item to `stable` once its text has held steady across a configurable number
+/// of consecutive updates. Callers emit `transcript-stable` for newly frozen
+/// prefixes and `transcript-partial` for the still-volatile tail; only the
+/// stable text plus the final tail is persisted.
+
+use std::collections::VecDeque;
+
+/// A single buffered transcript item spanning one time window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptItem {
+    pub text: String,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub stable: bool,
+    /// Consecutive updates during which `text` has remained unchanged.
+    unchanged_count: u32,
+}
+
+/// A freshly decoded item before it is reconciled against the buffer.
+#[derive(Debug, Clone)]
+pub struct PartialItem {
+    pub text: String,
+    pub start_ms: u64,
+    pub end_ms: u64,
+}
+
+/// Configuration for the stability tracker.
+#[derive(Debug, Clone)]
+pub struct StabilityConfig {
+    /// Number of consecutive unchanged updates before an item is frozen.
+    pub stability_threshold: u32,
+    /// Two windows overlapping by at least this many milliseconds are
+    /// considered the same item for diffing.
+    pub overlap_tolerance_ms: u64,
+}
+
+impl Default for StabilityConfig {
+    fn default() -> Self {
+        Self {
+            stability_threshold: 2,
+            overlap_tolerance_ms: 200,
+        }
+    }
+}
+
+/// What the caller should emit after an `update`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StabilityUpdate {
+    /// Newly frozen text, concatenated, if any items were promoted this update.
+    pub newly_stable: Option<String>,
+    /// The still-volatile tail that should replace the previous partial.
+    pub volatile_tail: String,
+}
+
+/// Buffers streaming hypotheses and scores their stability.
+#[derive(Debug)]
+pub struct StabilityTracker {
+    items: VecDeque<TranscriptItem>,
+    config: StabilityConfig,
+}
+
+impl StabilityTracker {
+    pub fn new(config: StabilityConfig) -> Self {
+        Self {
+            items: VecDeque::new(),
+            config,
+        }
+    }
+
+    /// Two windows belong to the same item if they overlap by at least the
+    /// configured tolerance.
+    fn windows_match(&self, a: (u64, u64), b: (u64, u64)) -> bool {
+        let overlap = a.1.min(b.1).saturating_sub(a.0.max(b.0));
+        overlap >= self.config.overlap_tolerance_ms.min(a.1.saturating_sub(a.0).max(1))
+    }
+
+    /// Reconcile a new partial decode against the buffer and report what to
+    /// emit. Frozen items are never modified — a retraction in the new decode
+    /// is ignored for anything already stable.
+    pub fn update(&mut self, new_items: Vec<PartialItem>) -> StabilityUpdate {
+        let mut newly_stable: Vec<String> = Vec::new();
+
+        for new_item in new_items {
+            let window = (new_item.start_ms, new_item.end_ms);
+
+            // A frozen item covering this window wins — never re-emit it.
+            if self
+                .items
+                .iter()
+                .any(|i| i.stable && self.windows_match((i.start_ms, i.end_ms), window))
+            {
+                continue;
+            }
+
+            match self
+                .items
+                .iter_mut()
+                .find(|i| !i.stable && windows_overlap((i.start_ms, i.end_ms), window, self.config.overlap_tolerance_ms))
+            {
+                Some(existing) => {
+                    if existing.text == new_item.text {
+                        existing.unchanged_count += 1;
+                        existing.end_ms = new_item.end_ms;
+                    } else {
+                        // Content changed — reset its stability score.
+                        existing.text = new_item.text;
+                        existing.start_ms = new_item.start_ms;
+                        existing.end_ms = new_item.end_ms;
+                        existing.unchanged_count = 1;
+                    }
+                }
+                None => {
+                    self.items.push_back(TranscriptItem {
+                        text: new_item.text,
+                        start_ms: new_item.start_ms,
+                        end_ms: new_item.end_ms,
+                        stable: false,
+                        unchanged_count: 1,
+                    });
+                }
+            }
+        }
+
+        // Promote items from the front of the buffer as long as they have met
+        // the threshold; only a contiguous stable prefix can freeze.
+        for item in self.items.iter_mut() {
+            if item.stable {
+                continue;
+            }
+            if item.unchanged_count >= self.config.stability_threshold {
+                item.stable = true;
+                newly_stable.push(item.text.clone());
+            } else {
+                break;
+            }
+        }
+
+        StabilityUpdate {
+            newly_stable: if newly_stable.is_empty() {
+                None
+            } else {
+                Some(join_text(&newly_stable))
+            },
+            volatile_tail: self.volatile_tail(),
+        }
+    }
+
+    /// The concatenated text of all items that have not yet frozen.
+    pub fn volatile_tail(&self) -> String {
+        let tail: Vec<&str> = self
+            .items
+            .iter()
+            .filter(|i| !i.stable)
+            .map(|i| i.text.as_str())
+            .collect();
+        join_refs(&tail)
+    }
+
+    /// The concatenated stable prefix.
+    pub fn stable_text(&self) -> String {
+        let stable: Vec<&str> = self
+            .items
+            .iter()
+            .filter(|i| i.stable)
+            .map(|i| i.text.as_str())
+            .collect();
+        join_refs(&stable)
+    }
+
+    /// Full text to persist: stable prefix plus the final volatile tail.
+    pub fn finalized_text(&self) -> String {
+        join_text(&[self.stable_text(), self.volatile_tail()])
+    }
+
+    /// Clear the buffer between utterances.
+    pub fn reset(&mut self) {
+        self.items.clear();
+    }
+}
+
+fn windows_overlap(a: (u64, u64), b: (u64, u64), tolerance: u64) -> bool {
+    let overlap = a.1.min(b.1).saturating_sub(a.0.max(b.0));
+    overlap >= tolerance.min(a.1.saturating_sub(a.0).max(1))
+}
+
+fn join_refs(parts: &[&str]) -> String {
+    parts
+        .iter()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn join_text(parts: &[String]) -> String {
+    parts
+        .iter()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(text: &str, start: u64, end: u64) -> PartialItem {
+        PartialItem { text: text.into(), start_ms: start, end_ms: end }
+    }
+
+    #[test]
+    fn promotes_after_threshold() {
+        let mut tracker = StabilityTracker::new(StabilityConfig { stability_threshold: 2, overlap_tolerance_ms: 100 });
+
+        let u1 = tracker.update(vec![item("hello", 0, 500)]);
+        assert_eq!(u1.newly_stable, None);
+        assert_eq!(u1.volatile_tail, "hello");
+
+        let u2 = tracker.update(vec![item("hello", 0, 500), item("world", 500, 1000)]);
+        assert_eq!(u2.newly_stable.as_deref(), Some("hello"));
+        assert_eq!(u2.volatile_tail, "world");
+        assert_eq!(tracker.stable_text(), "hello");
+    }
+
+    #[test]
+    fn volatile_change_resets_score() {
+        let mut tracker = StabilityTracker::new(StabilityConfig { stability_threshold: 3, overlap_tolerance_ms: 100 });
+        tracker.update(vec![item("teh", 0, 400)]);
+        tracker.update(vec![item("teh", 0, 400)]);
+        // A correction before freezing simply replaces the volatile item.
+        let u = tracker.update(vec![item("the", 0, 400)]);
+        assert_eq!(u.newly_stable, None);
+        assert_eq!(u.volatile_tail, "the");
+    }
+
+    #[test]
+    fn frozen_items_are_not_retracted() {
+        let mut tracker = StabilityTracker::new(StabilityConfig { stability_threshold: 1, overlap_tolerance_ms: 100 });
+        tracker.update(vec![item("meeting", 0, 500)]);
+        // "meeting" froze on the first update; a later contradictory decode for
+        // the same window must be ignored.
+        let u = tracker.update(vec![item("greeting", 0, 500), item("notes", 500, 900)]);
+        assert_eq!(u.newly_stable.as_deref(), Some("notes"));
+        assert_eq!(tracker.stable_text(), "meeting notes");
+    }
+
+    #[test]
+    fn reset_clears_buffer() {
+        let mut tracker = StabilityTracker::new(StabilityConfig::default());
+        tracker.update(vec![item("one", 0, 300)]);
+        tracker.reset();
+        assert_eq!(tracker.volatile_tail(), "");
+        assert_eq!(tracker.stable_text(), "");
+    }
+
+    #[test]
+    fn finalized_text_joins_stable_and_tail() {
+        let mut tracker = StabilityTracker::new(StabilityConfig { stability_threshold: 1, overlap_tolerance_ms: 100 });
+        tracker.update(vec![item("hello", 0, 500)]);
+        tracker.update(vec![item("hello", 0, 500), item("there", 500, 900)]);
+        assert_eq!(tracker.finalized_text(), "hello there");
+    }
+}
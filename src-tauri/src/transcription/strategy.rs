@@ -9,12 +9,22 @@ use std::sync::Arc;
 use tauri::Emitter;
 
 /// Result of transcription containing the text and metadata
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct TranscriptionResult {
     pub text: String,
     pub processing_time_ms: u64,
     pub strategy_used: String,
     pub chunks_processed: usize,
+    /// Streaming strategy only: ms from `start_recording` to the first partial emitted.
+    pub time_to_first_partial_ms: Option<u64>,
+    /// Streaming strategy only: ms between the last partial update and full stabilization.
+    pub stabilization_latency_ms: Option<u64>,
+    /// Streaming strategy only: the configured target latency that drove stabilization.
+    pub streaming_latency_ms: Option<u64>,
+    /// Ring buffer strategy only: average CPU utilization percentage (active
+    /// decode time / total chunk interval) across all fixed-timer chunks
+    /// processed during recording.
+    pub avg_cpu_utilization_pct: Option<f64>,
 }
 
 /// Configuration for selecting transcription strategy
@@ -30,6 +40,12 @@ pub struct TranscriptionConfig {
     pub force_strategy: Option<String>,
     /// Duration of refinement chunks in seconds (for progressive strategy)
     pub refinement_chunk_secs: Option<u64>,
+    /// Target end-to-end latency for the streaming strategy's word stabilization, in
+    /// milliseconds. Lower values commit text sooner at the cost of more revisions.
+    pub streaming_latency_ms: u64,
+    /// Whether the streaming strategy should emit revisable partial text at all, or only
+    /// ever report the fully stabilized prefix.
+    pub streaming_use_partial_results: bool,
 }
 
 impl Default for TranscriptionConfig {
@@ -40,6 +56,8 @@ impl Default for TranscriptionConfig {
             chunk_duration_secs: 5,     // 5-second chunks for better coverage
             force_strategy: None,
             refinement_chunk_secs: Some(10), // 10-second chunks for Medium model refinement
+            streaming_latency_ms: 8_000, // ~8s default, matching AWS Transcribe-style stabilization
+            streaming_use_partial_results: true,
         }
     }
 }
@@ -156,6 +174,7 @@ impl TranscriptionStrategy for ClassicTranscriptionStrategy {
             processing_time_ms: processing_time.as_millis() as u64,
             strategy_used: self.name().to_string(),
             chunks_processed: 1,
+            ..Default::default()
         })
     }
 
@@ -302,6 +321,7 @@ impl TranscriptionStrategy for RingBufferTranscriptionStrategy {
         // Stop the file-based monitor and collect results
         let mut final_chunks = Vec::new();
         let mut chunks_processed = 0;
+        let mut avg_cpu_utilization_pct = None;
 
         if let Some((monitor_handle, stop_sender)) = self.monitor_handle.take() {
             debug(Component::RingBuffer, "Stopping file-based ring buffer monitor...");
@@ -319,9 +339,10 @@ impl TranscriptionStrategy for RingBufferTranscriptionStrategy {
 
                     // Collect all transcribed chunks
                     match monitor.recording_complete().await {
-                        Ok(chunk_results) => {
-                            final_chunks = chunk_results;
+                        Ok(monitor_result) => {
+                            final_chunks = monitor_result.chunks;
                             chunks_processed = final_chunks.len();
+                            avg_cpu_utilization_pct = monitor_result.avg_cpu_utilization_pct;
                             info(
                                 Component::RingBuffer,
                                 &format!(
@@ -495,6 +516,8 @@ impl TranscriptionStrategy for RingBufferTranscriptionStrategy {
             processing_time_ms: transcription_time.as_millis() as u64,
             strategy_used: self.name().to_string(),
             chunks_processed,
+            avg_cpu_utilization_pct,
+            ..Default::default()
         };
 
         // CRITICAL: Clean up all state to prevent corruption in subsequent recordings
@@ -516,6 +539,259 @@ impl TranscriptionStrategy for RingBufferTranscriptionStrategy {
     }
 }
 
+/// Streaming transcription strategy - incrementally re-decodes the growing recording file and
+/// commits word-prefix text once each word's timestamp is old enough relative to the target
+/// latency, via [`crate::transcription::timestamp_stability::TimestampStabilizer`]. Unlike
+/// [`RingBufferTranscriptionStrategy`], which only ever reports fully-decoded chunk text, this
+/// surfaces revisable partial text while audio is still arriving.
+pub struct StreamingTranscriptionStrategy {
+    transcriber: Arc<tokio::sync::Mutex<Transcriber>>,
+    recording_path: Option<std::path::PathBuf>,
+    start_time: Option<std::time::Instant>,
+    latency_ms: u64,
+    use_partial_results: bool,
+    poll_handle: Option<(
+        tokio::task::JoinHandle<()>,
+        tokio::sync::mpsc::Sender<()>,
+    )>,
+    /// Shared with the background poll task so `get_partial_results`/`finish_recording` can
+    /// observe its progress without joining it early.
+    shared: Arc<tokio::sync::Mutex<StreamingShared>>,
+    app_handle: Option<tauri::AppHandle>,
+}
+
+/// State mutated by the background poll task and read back by the strategy.
+#[derive(Default)]
+struct StreamingShared {
+    stabilizer_stable_text: String,
+    tentative_text: String,
+    partial_update_count: usize,
+    time_to_first_partial_ms: Option<u64>,
+    last_partial_at: Option<std::time::Instant>,
+    stabilization_latency_ms: Option<u64>,
+}
+
+impl StreamingTranscriptionStrategy {
+    pub fn new(transcriber: Arc<tokio::sync::Mutex<Transcriber>>) -> Self {
+        Self {
+            transcriber,
+            recording_path: None,
+            start_time: None,
+            latency_ms: TranscriptionConfig::default().streaming_latency_ms,
+            use_partial_results: true,
+            poll_handle: None,
+            shared: Arc::new(tokio::sync::Mutex::new(StreamingShared::default())),
+            app_handle: None,
+        }
+    }
+
+    pub fn with_app_handle(mut self, app_handle: tauri::AppHandle) -> Self {
+        self.app_handle = Some(app_handle);
+        self
+    }
+
+    /// Approximates per-word timestamps by spreading `text`'s words evenly across the span
+    /// from 0 to `duration_ms`. `Transcriber` only returns plain decoded text today, so this is
+    /// the best timing signal available without teaching whisper.cpp's token timestamps through
+    /// to callers; it's close enough for a stability horizon measured in seconds.
+    fn words_with_interpolated_timestamps(
+        text: &str,
+        duration_ms: u64,
+    ) -> Vec<crate::transcription::timestamp_stability::TimedWord> {
+        let words: Vec<&str> = text.split_whitespace().collect();
+        if words.is_empty() {
+            return vec![];
+        }
+        let per_word_ms = duration_ms / words.len() as u64;
+        words
+            .into_iter()
+            .enumerate()
+            .map(|(i, w)| crate::transcription::timestamp_stability::TimedWord {
+                text: w.to_string(),
+                start_ms: i as u64 * per_word_ms,
+                end_ms: (i as u64 + 1) * per_word_ms,
+            })
+            .collect()
+    }
+
+    /// Polls the growing recording file on an interval, re-decoding it in full each time and
+    /// feeding the result through a [`TimestampStabilizer`].
+    fn start_poll_task(&mut self, output_path: std::path::PathBuf) {
+        const POLL_INTERVAL_MS: u64 = 500;
+
+        let transcriber = self.transcriber.clone();
+        let shared = self.shared.clone();
+        let app_handle = self.app_handle.clone();
+        let latency_ms = self.latency_ms;
+        let use_partial_results = self.use_partial_results;
+        let (stop_tx, mut stop_rx) = tokio::sync::mpsc::channel(1);
+
+        let handle = tokio::spawn(async move {
+            let mut stabilizer =
+                crate::transcription::timestamp_stability::TimestampStabilizer::new(latency_ms);
+            let start = std::time::Instant::now();
+
+            loop {
+                tokio::select! {
+                    _ = stop_rx.recv() => break,
+                    _ = tokio::time::sleep(std::time::Duration::from_millis(POLL_INTERVAL_MS)) => {}
+                }
+
+                let Ok(reader) = hound::WavReader::open(&output_path) else {
+                    continue; // file not written yet
+                };
+                let spec = reader.spec();
+                let duration_ms = (reader.duration() as u64 * 1000) / spec.sample_rate.max(1) as u64;
+                if duration_ms == 0 {
+                    continue;
+                }
+                drop(reader);
+
+                let text = {
+                    let transcriber = transcriber.lock().await;
+                    match transcriber.transcribe_file(&output_path) {
+                        Ok(text) => text,
+                        Err(e) => {
+                            warn(
+                                Component::Transcription,
+                                &format!("Streaming strategy decode pass failed: {}", e),
+                            );
+                            continue;
+                        }
+                    }
+                };
+
+                let words = Self::words_with_interpolated_timestamps(&text, duration_ms);
+                let update = stabilizer.ingest(&words, duration_ms);
+
+                let mut shared = shared.lock().await;
+                shared.partial_update_count += 1;
+                if shared.time_to_first_partial_ms.is_none() {
+                    shared.time_to_first_partial_ms = Some(start.elapsed().as_millis() as u64);
+                }
+                shared.last_partial_at = Some(std::time::Instant::now());
+                shared.stabilizer_stable_text = update.stable_text.clone();
+                shared.tentative_text = if use_partial_results {
+                    update.tentative_text.clone()
+                } else {
+                    String::new()
+                };
+
+                if let Some(ref app) = app_handle {
+                    let _ = app.emit(
+                        "transcript-streaming-update",
+                        serde_json::json!({
+                            "stable": update.stable_text,
+                            "tentative": shared.tentative_text,
+                        }),
+                    );
+                }
+            }
+        });
+
+        self.poll_handle = Some((handle, stop_tx));
+    }
+}
+
+#[async_trait]
+impl TranscriptionStrategy for StreamingTranscriptionStrategy {
+    fn name(&self) -> &str {
+        "streaming"
+    }
+
+    fn can_handle(
+        &self,
+        _duration_estimate: Option<std::time::Duration>,
+        _config: &TranscriptionConfig,
+    ) -> bool {
+        true
+    }
+
+    async fn start_recording(
+        &mut self,
+        output_path: &Path,
+        config: &TranscriptionConfig,
+    ) -> Result<(), String> {
+        self.latency_ms = config.streaming_latency_ms;
+        self.use_partial_results = config.streaming_use_partial_results;
+        self.start_time = Some(std::time::Instant::now());
+        self.recording_path = Some(output_path.to_path_buf());
+        *self.shared.lock().await = StreamingShared::default();
+
+        info(
+            Component::Transcription,
+            &format!(
+                "Streaming transcription strategy started for: {:?} (latency_ms={}, use_partial_results={})",
+                output_path, self.latency_ms, self.use_partial_results
+            ),
+        );
+
+        self.start_poll_task(output_path.to_path_buf());
+        Ok(())
+    }
+
+    async fn process_samples(&mut self, _samples: &[f32]) -> Result<(), String> {
+        // The poll task re-reads the growing file directly, same as the ring buffer strategy.
+        Ok(())
+    }
+
+    async fn finish_recording(&mut self) -> Result<TranscriptionResult, String> {
+        let start_time = self.start_time.take().ok_or("Recording was not started")?;
+        let recording_path = self.recording_path.take().ok_or("Recording path not set")?;
+
+        if let Some((handle, stop_tx)) = self.poll_handle.take() {
+            let _ = stop_tx.send(()).await;
+            let _ = handle.await;
+        }
+
+        info(
+            Component::Transcription,
+            &format!("Streaming transcription finishing, final decode of: {:?}", recording_path),
+        );
+
+        let final_text = {
+            let transcriber = self.transcriber.lock().await;
+            transcriber
+                .transcribe_file(&recording_path)
+                .map_err(|e| format!("Streaming strategy final decode failed: {}", e))?
+        };
+
+        let mut shared = self.shared.lock().await;
+        if let Some(last_partial_at) = shared.last_partial_at {
+            shared.stabilization_latency_ms = Some(last_partial_at.elapsed().as_millis() as u64);
+        }
+        let partial_update_count = shared.partial_update_count;
+        let time_to_first_partial_ms = shared.time_to_first_partial_ms;
+        let stabilization_latency_ms = shared.stabilization_latency_ms;
+
+        let processing_time = start_time.elapsed();
+
+        Ok(TranscriptionResult {
+            text: final_text,
+            processing_time_ms: processing_time.as_millis() as u64,
+            strategy_used: self.name().to_string(),
+            chunks_processed: partial_update_count,
+            time_to_first_partial_ms,
+            stabilization_latency_ms,
+            streaming_latency_ms: Some(self.latency_ms),
+            avg_cpu_utilization_pct: None,
+        })
+    }
+
+    fn get_partial_results(&self) -> Vec<String> {
+        match self.shared.try_lock() {
+            Ok(shared) => {
+                if shared.tentative_text.is_empty() {
+                    vec![shared.stabilizer_stable_text.clone()]
+                } else {
+                    vec![shared.stabilizer_stable_text.clone(), shared.tentative_text.clone()]
+                }
+            }
+            Err(_) => vec![], // poll task is mid-update; caller can retry next tick
+        }
+    }
+}
+
 /// Progressive transcription strategy - uses Tiny model for real-time feedback, then refines with Medium model
 pub struct ProgressiveTranscriptionStrategy {
     tiny_transcriber: Arc<tokio::sync::Mutex<Transcriber>>,
@@ -1163,6 +1439,7 @@ impl TranscriptionStrategy for ProgressiveTranscriptionStrategy {
             processing_time_ms: transcription_time.as_millis() as u64,
             strategy_used: format!("{} (final)", self.name()),
             chunks_processed: 1, // Final transcription is one complete pass
+            ..Default::default()
         };
 
         // CRITICAL: Clean up all state to prevent corruption in subsequent recordings
@@ -1280,10 +1557,19 @@ impl TranscriptionStrategySelector {
                     }
                     return Box::new(strategy);
                 }
+                "streaming" => {
+                    info(Component::Transcription, "🎯 STRATEGY SELECTION: Environment-forced STREAMING strategy (timestamp-stabilized partials)");
+                    info(Component::Transcription, "📝 Streaming strategy: AudioRecorder → growing WAV → periodic full re-decode → timestamp-stabilized partials");
+                    let mut strategy = StreamingTranscriptionStrategy::new(transcriber);
+                    if let Some(app_handle) = app_handle {
+                        strategy = strategy.with_app_handle(app_handle);
+                    }
+                    return Box::new(strategy);
+                }
                 "native_streaming" => {
                     info(Component::Transcription, "🎯 STRATEGY SELECTION: Environment-forced NATIVE STREAMING strategy (16kHz mono, whisper-rs streaming)");
                     info(Component::Transcription, "📝 Native streaming: 16kHz mono recording → Circular buffers → Real-time chunks → Streaming transcription");
-                    
+
                     // Try to create native streaming strategy with the given model
                     let model_path = if temp_dir.join("ggml-tiny.en.bin").exists() {
                         temp_dir.join("ggml-tiny.en.bin")
@@ -1322,6 +1608,14 @@ impl TranscriptionStrategySelector {
                     info(Component::Transcription, "Using forced classic strategy");
                     return Box::new(ClassicTranscriptionStrategy::new(transcriber));
                 }
+                "streaming" => {
+                    info(Component::Transcription, "Using forced streaming strategy");
+                    let mut strategy = StreamingTranscriptionStrategy::new(transcriber);
+                    if let Some(app_handle) = app_handle {
+                        strategy = strategy.with_app_handle(app_handle);
+                    }
+                    return Box::new(strategy);
+                }
                 "ring_buffer" => {
                     info(
                         Component::Transcription,
@@ -1677,6 +1971,7 @@ mod tests {
             chunk_duration_secs: 3,
             force_strategy: Some("test".to_string()),
             refinement_chunk_secs: None,
+            ..Default::default()
         };
         
         let cloned = config.clone();
@@ -1692,6 +1987,7 @@ mod tests {
             processing_time_ms: 150,
             strategy_used: "test".to_string(),
             chunks_processed: 2,
+            ..Default::default()
         };
         
         assert_eq!(result.text, "Test result");
@@ -14,12 +14,25 @@
 
 use crate::audio::streaming_recorder_16khz::StreamingSampleCallback;
 use crate::logger::{debug, error, info, Component};
+use crate::transcription::overlap_stitcher::OverlapStitcher;
+use crate::transcription::vad::{VadConfig, VadEvent, VoiceActivityDetector};
 use crate::transcription::Transcriber;
+use crate::transport::ResultSink;
 use std::collections::VecDeque;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+
+/// Default bounded channel capacity backing `results_stream` when the caller
+/// doesn't specify one.
+const DEFAULT_RESULTS_STREAM_CAPACITY: usize = 32;
+
+/// How often the VAD-driven processing loop polls the audio buffer for new
+/// samples; matches the VAD's own frame size so every tick yields a whole frame.
+const VAD_POLL_INTERVAL_MS: u64 = 20;
 
 /// Configuration for streaming transcription
 #[derive(Debug, Clone)]
@@ -34,16 +47,31 @@ pub struct StreamingTranscriberConfig {
     pub min_chunk_duration_secs: f32,
     /// Enable aggressive processing for low latency
     pub low_latency_mode: bool,
+    /// Drive chunk boundaries from voice activity instead of a wall-clock
+    /// timer: flush exactly the speech segment once a real pause is detected.
+    pub vad_enabled: bool,
+    /// dB above the adaptive noise floor a frame must exceed to count as speech.
+    pub vad_threshold_offset_db: f32,
+    /// How long energy must stay above threshold before a chunk is considered
+    /// to have started (filters out transients).
+    pub vad_speech_onset_ms: u32,
+    /// How long energy must stay below threshold before a chunk is flushed
+    /// (lets natural mid-sentence pauses pass without cutting the chunk).
+    pub vad_silence_hangover_ms: u32,
 }
 
 impl Default for StreamingTranscriberConfig {
     fn default() -> Self {
         Self {
             chunk_duration_secs: 5.0,      // 5 second chunks (progressive-like)
-            overlap_duration_secs: 0.0,    // No overlap (eliminates repetition)
+            overlap_duration_secs: 0.0,    // No overlap by default; OverlapStitcher makes ~0.5s safe to opt into
             max_buffer_duration_secs: 12.0, // 12 second max buffer
             min_chunk_duration_secs: 2.0,  // 2 second minimum (reasonable start point)
             low_latency_mode: false,
+            vad_enabled: false,
+            vad_threshold_offset_db: 12.0,
+            vad_speech_onset_ms: 100,
+            vad_silence_hangover_ms: 300,
         }
     }
 }
@@ -133,6 +161,22 @@ impl StreamingAudioBuffer {
     fn clear(&mut self) {
         self.samples.clear();
     }
+
+    /// Pop every sample currently buffered, leaving the buffer empty. Used by
+    /// the VAD-driven loop to pull only what has arrived since the last poll.
+    fn drain_all(&mut self) -> Vec<f32> {
+        self.samples.drain(..).collect()
+    }
+
+    /// Drops every sample except the last `n`. Used after processing an
+    /// overlapping chunk so the retained tail is restated (and deduplicated
+    /// by `OverlapStitcher`) in the next chunk, instead of clearing the
+    /// buffer outright.
+    fn retain_tail(&mut self, n: usize) {
+        while self.samples.len() > n {
+            self.samples.pop_front();
+        }
+    }
 }
 
 pub struct StreamingTranscriber {
@@ -140,6 +184,15 @@ pub struct StreamingTranscriber {
     transcriber: Arc<tokio::sync::Mutex<Transcriber>>,
     audio_buffer: Arc<Mutex<StreamingAudioBuffer>>,
     transcription_callback: Arc<Mutex<Option<StreamingTranscriptionCallback>>>,
+    /// Sender half of the channel backing the most recently created
+    /// `results_stream`, if any consumer has asked for one.
+    result_stream_tx: Arc<Mutex<Option<mpsc::Sender<StreamingTranscriptionResult>>>>,
+    /// Optional transport sink (file, TCP, ZeroMQ, ...) results are also
+    /// pushed to as length-prefixed JSON frames, set via `set_result_sink`.
+    result_sink: Arc<Mutex<Option<Box<dyn ResultSink>>>>,
+    /// De-duplicates and confirms text across overlapping chunks when
+    /// `overlap_duration_secs > 0`; `None` when overlap is disabled.
+    stitcher: Arc<Mutex<Option<OverlapStitcher>>>,
     is_active: Arc<Mutex<bool>>,
     chunk_counter: Arc<Mutex<u64>>,
     processing_thread: Option<thread::JoinHandle<()>>,
@@ -169,6 +222,11 @@ impl StreamingTranscriber {
             transcriber,
             audio_buffer,
             transcription_callback: Arc::new(Mutex::new(None)),
+            result_stream_tx: Arc::new(Mutex::new(None)),
+            result_sink: Arc::new(Mutex::new(None)),
+            stitcher: Arc::new(Mutex::new(
+                if config.overlap_duration_secs > 0.0 { Some(OverlapStitcher::new()) } else { None },
+            )),
             is_active: Arc::new(Mutex::new(false)),
             chunk_counter: Arc::new(Mutex::new(0)),
             processing_thread: None,
@@ -183,6 +241,33 @@ impl StreamingTranscriber {
         *self.transcription_callback.lock().unwrap() = callback;
     }
 
+    /// Returns a pollable stream of transcription results, bridging the
+    /// processing thread's output through a bounded `mpsc` channel instead of
+    /// the imperative callback in `set_transcription_callback`. The callback
+    /// path (if set) keeps receiving every result too; this just fans out a
+    /// second consumer. Dropping the returned stream drops the receiver, so
+    /// the next `blocking_send` from the processing thread simply fails and
+    /// is logged rather than panicking. Capacity bounds the channel so a
+    /// slow consumer back-pressures the processing thread instead of letting
+    /// it buffer unboundedly.
+    pub fn results_stream(&mut self, capacity: usize) -> ReceiverStream<StreamingTranscriptionResult> {
+        let (tx, rx) = mpsc::channel(capacity.max(1));
+        *self.result_stream_tx.lock().unwrap() = Some(tx);
+        ReceiverStream::new(rx)
+    }
+
+    /// `results_stream` with the default channel capacity.
+    pub fn results_stream_default(&mut self) -> ReceiverStream<StreamingTranscriptionResult> {
+        self.results_stream(DEFAULT_RESULTS_STREAM_CAPACITY)
+    }
+
+    /// Mirrors every result this transcriber produces to `sink` as a
+    /// length-prefixed JSON frame, alongside the callback and/or
+    /// `results_stream` consumer. Pass `None` to stop mirroring.
+    pub fn set_result_sink(&mut self, sink: Option<Box<dyn ResultSink>>) {
+        *self.result_sink.lock().unwrap() = sink;
+    }
+
     pub fn start_streaming(&mut self) -> Result<(), String> {
         info(Component::Transcription, "üöÄ Starting streaming transcription");
 
@@ -235,18 +320,38 @@ impl StreamingTranscriber {
         let transcriber = self.transcriber.clone();
         let audio_buffer = self.audio_buffer.clone();
         let transcription_callback = self.transcription_callback.clone();
+        let result_stream_tx = self.result_stream_tx.clone();
+        let result_sink = self.result_sink.clone();
+        let stitcher = self.stitcher.clone();
         let is_active = self.is_active.clone();
         let chunk_counter = self.chunk_counter.clone();
 
         let handle = thread::spawn(move || {
-            Self::simple_interval_processing(
-                config,
-                transcriber,
-                audio_buffer,
-                transcription_callback,
-                is_active,
-                chunk_counter,
-            );
+            if config.vad_enabled {
+                Self::vad_driven_processing(
+                    config,
+                    transcriber,
+                    audio_buffer,
+                    transcription_callback,
+                    result_stream_tx,
+                    result_sink,
+                    stitcher,
+                    is_active,
+                    chunk_counter,
+                );
+            } else {
+                Self::simple_interval_processing(
+                    config,
+                    transcriber,
+                    audio_buffer,
+                    transcription_callback,
+                    result_stream_tx,
+                    result_sink,
+                    stitcher,
+                    is_active,
+                    chunk_counter,
+                );
+            }
         });
 
         self.processing_thread = Some(handle);
@@ -258,16 +363,19 @@ impl StreamingTranscriber {
         transcriber: Arc<tokio::sync::Mutex<Transcriber>>,
         audio_buffer: Arc<Mutex<StreamingAudioBuffer>>,
         transcription_callback: Arc<Mutex<Option<StreamingTranscriptionCallback>>>,
+        result_stream_tx: Arc<Mutex<Option<mpsc::Sender<StreamingTranscriptionResult>>>>,
+        result_sink: Arc<Mutex<Option<Box<dyn ResultSink>>>>,
+        stitcher: Arc<Mutex<Option<OverlapStitcher>>>,
         is_active: Arc<Mutex<bool>>,
         chunk_counter: Arc<Mutex<u64>>,
     ) {
         let process_interval = Duration::from_secs_f32(config.chunk_duration_secs);
-        info(Component::Transcription, &format!("üîÑ Simple interval processing started (every {:.1}s)", config.chunk_duration_secs));
+        info(Component::Transcription, &format!("üîÑ Simple interval processing started (every {:.1}s)", config.chunk_duration_secs));
 
         while *is_active.lock().unwrap() {
             // Simple: just sleep for the chunk duration, then process
             thread::sleep(process_interval);
-            
+
             if !*is_active.lock().unwrap() {
                 break;
             }
@@ -278,13 +386,111 @@ impl StreamingTranscriber {
                 &transcriber,
                 &audio_buffer,
                 &transcription_callback,
+                &result_stream_tx,
+                &result_sink,
+                &stitcher,
                 &chunk_counter,
             ) {
                 error(Component::Transcription, &format!("Processing error: {}", e));
             }
         }
 
-        info(Component::Transcription, "üèÅ Simple interval processing ended");
+        info(Component::Transcription, "üèÅ Simple interval processing ended");
+    }
+
+    /// VAD-driven alternative to `simple_interval_processing`: instead of
+    /// waking up on a fixed timer, poll the buffer every `VAD_POLL_INTERVAL_MS`,
+    /// score each new frame with a [`VoiceActivityDetector`], and flush
+    /// exactly the accumulated speech segment once a real pause is detected
+    /// (or `max_buffer_duration_secs` is hit mid-speech).
+    fn vad_driven_processing(
+        config: StreamingTranscriberConfig,
+        transcriber: Arc<tokio::sync::Mutex<Transcriber>>,
+        audio_buffer: Arc<Mutex<StreamingAudioBuffer>>,
+        transcription_callback: Arc<Mutex<Option<StreamingTranscriptionCallback>>>,
+        result_stream_tx: Arc<Mutex<Option<mpsc::Sender<StreamingTranscriptionResult>>>>,
+        result_sink: Arc<Mutex<Option<Box<dyn ResultSink>>>>,
+        stitcher: Arc<Mutex<Option<OverlapStitcher>>>,
+        is_active: Arc<Mutex<bool>>,
+        chunk_counter: Arc<Mutex<u64>>,
+    ) {
+        info(Component::Transcription, &format!(
+            "üîÑ VAD-driven processing started (threshold={}dB, onset={}ms, hangover={}ms)",
+            config.vad_threshold_offset_db, config.vad_speech_onset_ms, config.vad_silence_hangover_ms
+        ));
+
+        let sample_rate = audio_buffer.lock().unwrap().sample_rate;
+        let mut vad = VoiceActivityDetector::new(
+            sample_rate,
+            VadConfig {
+                threshold_offset_db: config.vad_threshold_offset_db,
+                speech_onset_ms: config.vad_speech_onset_ms,
+                silence_hangover_ms: config.vad_silence_hangover_ms,
+            },
+        );
+
+        // Rolling pre-roll so a confirmed speech segment includes the onset
+        // runway that preceded the decision, not just audio after it.
+        let pre_roll_cap = ((sample_rate as f32 * config.vad_speech_onset_ms as f32) / 1000.0) as usize;
+        let mut pre_roll: VecDeque<f32> = VecDeque::with_capacity(pre_roll_cap.max(1));
+        let mut segment: Vec<f32> = Vec::new();
+        let max_segment_samples = (sample_rate as f32 * config.max_buffer_duration_secs) as usize;
+
+        while *is_active.lock().unwrap() {
+            thread::sleep(Duration::from_millis(VAD_POLL_INTERVAL_MS));
+
+            if !*is_active.lock().unwrap() {
+                break;
+            }
+
+            let drained = audio_buffer.lock().unwrap().drain_all();
+            if drained.is_empty() {
+                continue;
+            }
+
+            let was_in_speech = vad.in_speech();
+            let event = vad.process(&drained);
+
+            if was_in_speech || vad.in_speech() {
+                segment.extend_from_slice(&drained);
+            }
+
+            for &sample in &drained {
+                if pre_roll.len() >= pre_roll_cap.max(1) {
+                    pre_roll.pop_front();
+                }
+                pre_roll.push_back(sample);
+            }
+
+            if event == VadEvent::SpeechStarted {
+                // Splice the pre-roll in front of what we've captured for
+                // this tick so the segment covers the onset runway too.
+                let mut with_preroll: Vec<f32> = pre_roll.iter().cloned().collect();
+                with_preroll.append(&mut segment);
+                segment = with_preroll;
+            }
+
+            let hit_max_buffer = segment.len() >= max_segment_samples;
+            if event == VadEvent::SpeechEnded || hit_max_buffer {
+                if !segment.is_empty() {
+                    let speech_segment = std::mem::take(&mut segment);
+                    if let Err(e) = Self::transcribe_and_emit(
+                        &config,
+                        &transcriber,
+                        &transcription_callback,
+                        &result_stream_tx,
+                        &result_sink,
+                        &stitcher,
+                        &chunk_counter,
+                        speech_segment,
+                    ) {
+                        error(Component::Transcription, &format!("Processing error: {}", e));
+                    }
+                }
+            }
+        }
+
+        info(Component::Transcription, "üèÅ VAD-driven processing ended");
     }
 
     fn process_chunk(
@@ -292,10 +498,11 @@ impl StreamingTranscriber {
         transcriber: &Arc<tokio::sync::Mutex<Transcriber>>,
         audio_buffer: &Arc<Mutex<StreamingAudioBuffer>>,
         transcription_callback: &Arc<Mutex<Option<StreamingTranscriptionCallback>>>,
+        result_stream_tx: &Arc<Mutex<Option<mpsc::Sender<StreamingTranscriptionResult>>>>,
+        result_sink: &Arc<Mutex<Option<Box<dyn ResultSink>>>>,
+        stitcher: &Arc<Mutex<Option<OverlapStitcher>>>,
         chunk_counter: &Arc<Mutex<u64>>,
     ) -> Result<(), String> {
-        let start_time = Instant::now();
-
         // Extract audio chunk - simple approach
         let audio_chunk = {
             let mut buffer = audio_buffer.lock().unwrap();
@@ -303,23 +510,51 @@ impl StreamingTranscriber {
                 return Ok(()); // Not enough audio yet
             }
 
-            // Get all available audio, then clear the buffer (no overlap)
-            let chunk = buffer.get_chunk(buffer.duration_secs());
-            buffer.clear(); // Simple: clear everything we just processed
-            chunk
+            if config.overlap_duration_secs > 0.0 {
+                // Re-cover the tail of the previous chunk so OverlapStitcher
+                // has the context it needs to align and de-duplicate it.
+                let chunk = buffer.get_overlapping_chunk(config.chunk_duration_secs, config.overlap_duration_secs);
+                let overlap_samples = (buffer.sample_rate as f32 * config.overlap_duration_secs) as usize;
+                buffer.retain_tail(overlap_samples);
+                chunk
+            } else {
+                // Get all available audio, then clear the buffer (no overlap)
+                let chunk = buffer.get_chunk(buffer.duration_secs());
+                buffer.clear(); // Simple: clear everything we just processed
+                chunk
+            }
         };
 
         if audio_chunk.is_empty() {
             return Ok(());
         }
 
+        Self::transcribe_and_emit(config, transcriber, transcription_callback, result_stream_tx, result_sink, stitcher, chunk_counter, audio_chunk)
+    }
+
+    /// Transcribe an already-extracted chunk of samples and emit the result
+    /// via the callback, the `results_stream` channel (if a consumer asked
+    /// for one), and the `result_sink` transport (if one was set). Shared by
+    /// both the fixed-interval and VAD-driven processing loops.
+    fn transcribe_and_emit(
+        _config: &StreamingTranscriberConfig,
+        transcriber: &Arc<tokio::sync::Mutex<Transcriber>>,
+        transcription_callback: &Arc<Mutex<Option<StreamingTranscriptionCallback>>>,
+        result_stream_tx: &Arc<Mutex<Option<mpsc::Sender<StreamingTranscriptionResult>>>>,
+        result_sink: &Arc<Mutex<Option<Box<dyn ResultSink>>>>,
+        stitcher: &Arc<Mutex<Option<OverlapStitcher>>>,
+        chunk_counter: &Arc<Mutex<u64>>,
+        audio_chunk: Vec<f32>,
+    ) -> Result<(), String> {
+        let start_time = Instant::now();
+
         let chunk_id = {
             let mut counter = chunk_counter.lock().unwrap();
             *counter += 1;
             *counter
         };
 
-        debug(Component::Transcription, &format!("Processing chunk {} ({} samples)", 
+        debug(Component::Transcription, &format!("Processing chunk {} ({} samples)",
             chunk_id, audio_chunk.len()));
 
         // Process with whisper-rs directly in memory (no file I/O)
@@ -331,23 +566,68 @@ impl StreamingTranscriber {
 
         let processing_time = start_time.elapsed();
 
+        // If overlap stitching is active, align this chunk against the
+        // previous one's tail and only report confirmed + still-tentative
+        // text instead of the raw (partially-restated) transcription.
+        let (text, is_partial) = match stitcher.lock().unwrap().as_mut() {
+            Some(stitcher) => {
+                let stitched = stitcher.stitch(&transcription_result);
+                let is_partial = !stitched.tentative_text.is_empty();
+                let text = match (stitched.confirmed_text.is_empty(), stitched.tentative_text.is_empty()) {
+                    (true, true) => String::new(),
+                    (false, true) => stitched.confirmed_text,
+                    (true, false) => stitched.tentative_text,
+                    (false, false) => format!("{} {}", stitched.confirmed_text, stitched.tentative_text),
+                };
+                (text, is_partial)
+            }
+            None => (transcription_result, true), // All streaming results are partial
+        };
+
         // Create result
         let result = StreamingTranscriptionResult {
-            text: transcription_result,
+            text,
             start_time,
             end_time: Instant::now(),
             chunk_id,
-            is_partial: true, // All streaming results are partial
+            is_partial,
             confidence: None, // whisper-rs doesn't provide confidence scores
             processing_time_ms: processing_time.as_millis() as u64,
         };
 
-        let result_text = if result.text.len() > 50 { 
-            format!("{}...", &result.text[..50]) 
-        } else { 
-            result.text.clone() 
+        let result_text = if result.text.len() > 50 {
+            format!("{}...", &result.text[..50])
+        } else {
+            result.text.clone()
         };
 
+        // Fan out to the results_stream consumer, if any. blocking_send
+        // naturally back-pressures this thread when the consumer falls
+        // behind, instead of buffering results unboundedly.
+        let stream_tx = result_stream_tx.lock().unwrap().clone();
+        if let Some(tx) = stream_tx {
+            if let Err(e) = tx.blocking_send(result.clone()) {
+                debug(Component::Transcription, &format!("results_stream consumer gone: {}", e));
+            }
+        }
+
+        // Mirror to the transport sink, if one is set, as a length-prefixed
+        // JSON frame alongside the stream/callback fan-out above. Built by
+        // hand rather than derived, since `start_time`/`end_time` are
+        // `Instant`s with no serializable representation.
+        if let Some(sink) = result_sink.lock().unwrap().as_mut() {
+            let value = serde_json::json!({
+                "text": result.text,
+                "chunk_id": result.chunk_id,
+                "is_partial": result.is_partial,
+                "confidence": result.confidence,
+                "processing_time_ms": result.processing_time_ms,
+            });
+            if let Err(e) = sink.send_json(&value) {
+                error(Component::Transcription, &format!("Failed to write result to sink: {}", e));
+            }
+        }
+
         // Call callback if set
         if let Some(ref callback) = *transcription_callback.lock().unwrap() {
             callback(result);
@@ -419,27 +699,32 @@ impl Drop for StreamingTranscriber {
     }
 }
 
-/// Integration example: Connect streaming recorder to streaming transcriber
+/// Integration example: Connect streaming recorder(s) to streaming transcriber
 pub struct StreamingTranscriptionPipeline {
-    recorder: crate::audio::streaming_recorder_16khz::StreamingAudioRecorder16kHz,
+    mixer: crate::audio::streaming_mixer::StreamingAudioMixer,
     transcriber: StreamingTranscriber,
 }
 
 impl StreamingTranscriptionPipeline {
+    /// Builds a pipeline from one or more audio sources (e.g. microphone plus
+    /// system/loopback output for meeting mode), mixed down to a single
+    /// 16kHz mono stream by `StreamingAudioMixer` before it reaches the
+    /// transcriber. A single-element `source_configs` reproduces the
+    /// original one-recorder pipeline.
     pub async fn new(
         model_path: &Path,
-        recorder_config: crate::audio::streaming_recorder_16khz::StreamingRecorderConfig,
+        source_configs: Vec<crate::audio::streaming_mixer::AudioSourceConfig>,
         transcriber_config: StreamingTranscriberConfig,
     ) -> Result<Self, String> {
         info(Component::Transcription, "üîó Creating streaming transcription pipeline");
 
-        let mut recorder = crate::audio::streaming_recorder_16khz::StreamingAudioRecorder16kHz::new(recorder_config);
-        recorder.init()?;
+        let mut mixer = crate::audio::streaming_mixer::StreamingAudioMixer::new(source_configs);
+        mixer.init()?;
 
         let transcriber = StreamingTranscriber::new(model_path, transcriber_config).await?;
 
         Ok(Self {
-            recorder,
+            mixer,
             transcriber,
         })
     }
@@ -448,19 +733,25 @@ impl StreamingTranscriptionPipeline {
         self.transcriber.set_transcription_callback(Some(callback));
     }
 
+    /// Mirrors every streaming result to `sink` (file, TCP, ZeroMQ, ...) in
+    /// addition to the transcription callback.
+    pub fn set_result_sink(&mut self, sink: Option<Box<dyn crate::transport::ResultSink>>) {
+        self.transcriber.set_result_sink(sink);
+    }
+
     pub fn start_pipeline(&mut self) -> Result<(), String> {
         info(Component::Transcription, "üöÄ Starting transcription pipeline");
 
         // Start transcriber
         self.transcriber.start_streaming()?;
 
-        // Connect recorder to transcriber
+        // Connect mixer to transcriber
         if let Some(sample_callback) = self.transcriber.get_sample_callback() {
-            self.recorder.set_sample_callback(Some(sample_callback))?;
+            self.mixer.set_sample_callback(Some(sample_callback))?;
         }
 
-        // Start recording
-        self.recorder.start_recording()?;
+        // Start recording all sources
+        self.mixer.start_recording()?;
 
         info(Component::Transcription, "‚úÖ Transcription pipeline active");
         Ok(())
@@ -469,7 +760,7 @@ impl StreamingTranscriptionPipeline {
     pub fn stop_pipeline(&mut self) -> Result<(), String> {
         info(Component::Transcription, "‚èπÔ∏è Stopping transcription pipeline");
 
-        self.recorder.stop_recording()?;
+        self.mixer.stop_recording()?;
         self.transcriber.stop_streaming()?;
 
         info(Component::Transcription, "‚úÖ Transcription pipeline stopped");
@@ -477,7 +768,7 @@ impl StreamingTranscriptionPipeline {
     }
 
     pub fn is_active(&self) -> bool {
-        self.recorder.is_recording() && self.transcriber.is_active()
+        self.mixer.is_recording() && self.transcriber.is_active()
     }
 }
 
@@ -0,0 +1,163 @@
+/// Timestamp-based word stability for the `"streaming"` transcription strategy.
+///
+/// Unlike [`super::partial_stability::PartialResultStabilizer`], which commits a word once it
+/// has survived unchanged across a window of full re-decodes, this tracker commits a word once
+/// its own end timestamp is old enough relative to the configured target latency. That lets it
+/// make a commit decision the first time a word is seen, as long as the word is already behind
+/// the stability horizon, rather than waiting for several more decode passes to agree.
+///
+/// Each decode pass re-transcribes from scratch, so word timings shift slightly between passes,
+/// and partials carry no stable IDs. Rather than diffing text against the previous pass, each
+/// new pass is re-aligned against what has already been committed by timestamp overlap: any
+/// word ending at or before the already-stable region is assumed to be the same content
+/// (possibly re-decoded with a slightly different timestamp or spelling) and is skipped, since
+/// committed words are never retracted.
+use std::collections::VecDeque;
+
+/// Width of one decode pass, in milliseconds. Subtracted twice from `latency_ms` when computing
+/// the stability horizon, so a word can survive one more re-decode before being committed.
+pub const GRANULARITY_MS: u64 = 100;
+
+/// A single decoded word with its position in the recording.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedWord {
+    pub text: String,
+    pub start_ms: u64,
+    pub end_ms: u64,
+}
+
+/// Result of feeding one decode pass through a [`TimestampStabilizer`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StabilityUpdate {
+    /// Words committed for the first time in this pass, oldest first.
+    pub newly_stable: Vec<TimedWord>,
+    /// All stable text committed so far.
+    pub stable_text: String,
+    /// Revisable tail of the latest pass, not yet old enough to commit.
+    pub tentative_text: String,
+}
+
+/// Commits words once their end timestamp falls far enough behind the latest decode position.
+pub struct TimestampStabilizer {
+    latency_ms: u64,
+    stable_words: Vec<TimedWord>,
+}
+
+impl TimestampStabilizer {
+    pub fn new(latency_ms: u64) -> Self {
+        Self {
+            latency_ms,
+            stable_words: Vec::new(),
+        }
+    }
+
+    pub fn latency_ms(&self) -> u64 {
+        self.latency_ms
+    }
+
+    pub fn stable_text(&self) -> String {
+        join_words(&self.stable_words)
+    }
+
+    /// Feeds the words decoded in the latest pass, which covers everything from the start of
+    /// the recording up to `current_position_ms`, and returns the newly stable/tentative split.
+    pub fn ingest(&mut self, words: &[TimedWord], current_position_ms: u64) -> StabilityUpdate {
+        let horizon_ms = self.latency_ms.saturating_sub(2 * GRANULARITY_MS);
+        let stability_cutoff_ms = current_position_ms.saturating_sub(horizon_ms);
+        let already_stable_until_ms = self.stable_words.last().map(|w| w.end_ms).unwrap_or(0);
+
+        let mut newly_stable = Vec::new();
+        let mut tentative: VecDeque<TimedWord> = VecDeque::new();
+        for word in words {
+            if word.end_ms <= already_stable_until_ms {
+                continue; // re-aligned away: already reflected in `stable_words`
+            }
+            if word.end_ms <= stability_cutoff_ms {
+                newly_stable.push(word.clone());
+            } else {
+                tentative.push_back(word.clone());
+            }
+        }
+
+        self.stable_words.extend(newly_stable.clone());
+
+        StabilityUpdate {
+            tentative_text: join_words(tentative.make_contiguous()),
+            newly_stable,
+            stable_text: self.stable_text(),
+        }
+    }
+
+    /// Clears all buffered state, e.g. when a new recording starts.
+    pub fn reset(&mut self) {
+        self.stable_words.clear();
+    }
+}
+
+fn join_words(words: &[TimedWord]) -> String {
+    words
+        .iter()
+        .map(|w| w.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, start_ms: u64, end_ms: u64) -> TimedWord {
+        TimedWord {
+            text: text.to_string(),
+            start_ms,
+            end_ms,
+        }
+    }
+
+    #[test]
+    fn commits_words_once_old_enough_relative_to_latency() {
+        // latency 500ms, granularity 100ms x2 => horizon = 300ms behind current position.
+        let mut stabilizer = TimestampStabilizer::new(500);
+
+        let words = vec![word("hello", 0, 200), word("world", 200, 450)];
+        let update = stabilizer.ingest(&words, 500); // cutoff = 500 - 300 = 200
+        assert_eq!(update.stable_text, "hello");
+        assert_eq!(update.tentative_text, "world");
+    }
+
+    #[test]
+    fn realigns_against_already_stable_words_by_timestamp() {
+        let mut stabilizer = TimestampStabilizer::new(500);
+
+        stabilizer.ingest(&[word("hello", 0, 200), word("world", 200, 450)], 500);
+        assert_eq!(stabilizer.stable_text(), "hello");
+
+        // Next pass re-decodes from scratch and slightly revises "world" -> "worlds", with a
+        // shifted end timestamp, plus a new word.
+        let update = stabilizer.ingest(
+            &[
+                word("hello", 0, 200),
+                word("worlds", 200, 460),
+                word("today", 460, 700),
+            ],
+            800, // cutoff = 800 - 300 = 500
+        );
+
+        assert_eq!(update.stable_text, "hello worlds");
+        assert_eq!(update.tentative_text, "today");
+    }
+
+    #[test]
+    fn never_retracts_previously_committed_words() {
+        let mut stabilizer = TimestampStabilizer::new(500);
+
+        stabilizer.ingest(&[word("hello", 0, 200)], 500); // cutoff = 200
+        assert_eq!(stabilizer.stable_text(), "hello");
+
+        // A later pass re-sends the already-committed word; it must not be duplicated, and the
+        // next word should stay tentative until it crosses the horizon itself.
+        let update = stabilizer.ingest(&[word("hello", 0, 200), word("world", 200, 260)], 520); // cutoff = 220
+        assert_eq!(update.stable_text, "hello");
+        assert_eq!(update.tentative_text, "world");
+    }
+}
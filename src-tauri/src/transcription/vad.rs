@@ -0,0 +1,175 @@
+/// Energy-driven voice activity detection for streaming transcription.
+///
+/// `simple_interval_processing` used to flush the streaming buffer on a fixed
+/// wall-clock timer, which re-runs Whisper over silence and cuts chunk
+/// boundaries mid-word. This tracker instead slices incoming audio into short
+/// frames, scores each frame's short-time RMS energy against an adaptive
+/// noise floor, and debounces the raw per-frame decision with onset/hangover
+/// durations so a chunk boundary only ever falls on a real pause: speech
+/// starts after energy has stayed above the threshold for `speech_onset_ms`
+/// and ends only after it has stayed below for `silence_hangover_ms`.
+
+use std::collections::VecDeque;
+
+/// Frame size used for energy scoring: 20ms at 16kHz (320 samples).
+const FRAME_DURATION_MS: f32 = 20.0;
+/// Window (in frames) over which the adaptive noise floor is tracked (~1s).
+const NOISE_FLOOR_WINDOW_FRAMES: usize = 50;
+
+/// Configuration for [`VoiceActivityDetector`].
+#[derive(Debug, Clone)]
+pub struct VadConfig {
+    /// dB above the adaptive noise floor a frame's energy must exceed to be
+    /// considered speech.
+    pub threshold_offset_db: f32,
+    /// How long energy must stay above threshold before entering the speech
+    /// state, filtering out brief transients.
+    pub speech_onset_ms: u32,
+    /// How long energy must stay below threshold before leaving the speech
+    /// state, so natural mid-sentence pauses don't split a chunk.
+    pub silence_hangover_ms: u32,
+}
+
+impl Default for VadConfig {
+    fn default() -> Self {
+        Self {
+            threshold_offset_db: 12.0,
+            speech_onset_ms: 100,
+            silence_hangover_ms: 300,
+        }
+    }
+}
+
+/// Debounced speech/silence state machine driven by per-frame energy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VadState {
+    Silence,
+    Speech,
+}
+
+/// Tracks short-time energy against an adaptive noise floor and reports
+/// debounced speech/silence transitions, one frame at a time.
+pub struct VoiceActivityDetector {
+    config: VadConfig,
+    sample_rate: u32,
+    frame_samples: usize,
+    state: VadState,
+    /// Running minimum RMS energy over the last `NOISE_FLOOR_WINDOW_FRAMES`,
+    /// used as the adaptive noise floor.
+    recent_energies: VecDeque<f32>,
+    /// Milliseconds the raw (pre-debounce) decision has held its current value.
+    candidate_state: VadState,
+    candidate_hold_ms: f32,
+    /// Leftover samples carried over between `process` calls that didn't fill
+    /// a whole frame.
+    pending: Vec<f32>,
+}
+
+/// One frame's worth of VAD evaluation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VadEvent {
+    /// Debounced state did not change this frame.
+    Unchanged,
+    /// Energy has stayed above threshold for `speech_onset_ms`; start of a
+    /// speech segment.
+    SpeechStarted,
+    /// Energy has stayed below threshold for `silence_hangover_ms`; end of a
+    /// speech segment.
+    SpeechEnded,
+}
+
+impl VoiceActivityDetector {
+    pub fn new(sample_rate: u32, config: VadConfig) -> Self {
+        let frame_samples = ((sample_rate as f32 * FRAME_DURATION_MS) / 1000.0) as usize;
+        Self {
+            config,
+            sample_rate,
+            frame_samples: frame_samples.max(1),
+            state: VadState::Silence,
+            recent_energies: VecDeque::with_capacity(NOISE_FLOOR_WINDOW_FRAMES),
+            candidate_state: VadState::Silence,
+            candidate_hold_ms: 0.0,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Whether the detector currently considers us mid-speech-segment.
+    pub fn in_speech(&self) -> bool {
+        self.state == VadState::Speech
+    }
+
+    /// Feed newly arrived samples and evaluate every complete frame within
+    /// them, returning the most significant event observed (a state
+    /// transition takes priority over `Unchanged` if both occur across
+    /// multiple frames in this call).
+    pub fn process(&mut self, samples: &[f32]) -> VadEvent {
+        self.pending.extend_from_slice(samples);
+
+        let mut event = VadEvent::Unchanged;
+        while self.pending.len() >= self.frame_samples {
+            let frame: Vec<f32> = self.pending.drain(..self.frame_samples).collect();
+            let frame_event = self.process_frame(&frame);
+            if frame_event != VadEvent::Unchanged {
+                event = frame_event;
+            }
+        }
+
+        event
+    }
+
+    fn process_frame(&mut self, frame: &[f32]) -> VadEvent {
+        let energy_db = rms_energy_db(frame);
+
+        if self.recent_energies.len() >= NOISE_FLOOR_WINDOW_FRAMES {
+            self.recent_energies.pop_front();
+        }
+        self.recent_energies.push_back(energy_db);
+
+        let noise_floor = self
+            .recent_energies
+            .iter()
+            .cloned()
+            .fold(f32::INFINITY, f32::min);
+        let threshold = noise_floor + self.config.threshold_offset_db;
+        let raw_is_speech = energy_db >= threshold;
+        let raw_state = if raw_is_speech { VadState::Speech } else { VadState::Silence };
+
+        if raw_state == self.candidate_state {
+            self.candidate_hold_ms += FRAME_DURATION_MS;
+        } else {
+            self.candidate_state = raw_state;
+            self.candidate_hold_ms = FRAME_DURATION_MS;
+        }
+
+        match (self.state, self.candidate_state) {
+            (VadState::Silence, VadState::Speech)
+                if self.candidate_hold_ms >= self.config.speech_onset_ms as f32 =>
+            {
+                self.state = VadState::Speech;
+                VadEvent::SpeechStarted
+            }
+            (VadState::Speech, VadState::Silence)
+                if self.candidate_hold_ms >= self.config.silence_hangover_ms as f32 =>
+            {
+                self.state = VadState::Silence;
+                VadEvent::SpeechEnded
+            }
+            _ => VadEvent::Unchanged,
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+}
+
+/// Short-time RMS energy of `frame`, expressed in dB (floored to avoid `-inf`
+/// on silent frames).
+fn rms_energy_db(frame: &[f32]) -> f32 {
+    if frame.is_empty() {
+        return -100.0;
+    }
+    let sum_sq: f32 = frame.iter().map(|s| s * s).sum();
+    let rms = (sum_sq / frame.len() as f32).sqrt();
+    20.0 * rms.max(1e-10).log10()
+}
@@ -0,0 +1,139 @@
+use super::silero_vad::SileroVad;
+use std::path::{Path, PathBuf};
+
+/// Configuration for [`VadSegmenter`].
+#[derive(Debug, Clone)]
+pub struct VadSegmenterConfig {
+    /// Minimum Silero speech probability for a frame to count as speech.
+    pub speech_threshold: f32,
+    /// How long probability must stay below `speech_threshold` before a
+    /// chunk boundary is cut at that point, so a brief dip mid-sentence
+    /// doesn't fragment one utterance.
+    pub silence_hangover_ms: u32,
+}
+
+impl Default for VadSegmenterConfig {
+    fn default() -> Self {
+        Self {
+            speech_threshold: 0.5,
+            silence_hangover_ms: 300,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SegmenterState {
+    Silence,
+    Speech,
+}
+
+/// Cuts a stream of audio samples into chunks at natural speech pauses
+/// instead of fixed time boundaries.
+///
+/// Fixed-size chunking (e.g. the ring buffer's old `chunk_size_ms` grid)
+/// slices audio on the wall clock, so a boundary lands mid-word whenever
+/// speech happens to be crossing it. This instead scores every frame with
+/// [`SileroVad`] and only emits a boundary once silence has held for
+/// `silence_hangover_ms`, so each chunk ends on a real pause.
+///
+/// Unlike [`super::vad::VoiceActivityDetector`], which debounces an
+/// energy-based decision against an adaptive noise floor, this drives the
+/// decision from Silero's recurrent speech probability, carried
+/// frame-to-frame by [`SileroVad`] (the same model-backed detector used by
+/// `WavFileReader::extract_speech_segments`).
+pub struct VadSegmenter {
+    vad: SileroVad,
+    config: VadSegmenterConfig,
+    frame_duration_ms: f32,
+    state: SegmenterState,
+    silence_hold_ms: f32,
+    /// Samples not yet long enough to fill one VAD frame.
+    pending: Vec<f32>,
+    /// Samples accumulated for the chunk in progress since the last
+    /// emitted boundary.
+    current_chunk: Vec<f32>,
+}
+
+impl VadSegmenter {
+    /// Load the Silero VAD model and start a fresh segmenter. `chunk_size`
+    /// is the VAD frame size in samples (512 samples = 32ms at 16kHz).
+    pub fn new(
+        model_path: &Path,
+        chunk_size: usize,
+        sample_rate: u32,
+        config: VadSegmenterConfig,
+    ) -> Result<Self, String> {
+        let vad = SileroVad::new(model_path, chunk_size, sample_rate)?;
+        let frame_duration_ms = (chunk_size as f32 / sample_rate as f32) * 1000.0;
+
+        Ok(Self {
+            vad,
+            config,
+            frame_duration_ms,
+            state: SegmenterState::Silence,
+            silence_hold_ms: 0.0,
+            pending: Vec::new(),
+            current_chunk: Vec::new(),
+        })
+    }
+
+    /// Feed newly arrived samples, scoring every complete VAD frame within
+    /// them. Returns any chunks that were closed out by a silence boundary
+    /// during this call, in order.
+    pub fn process(&mut self, samples: &[f32]) -> Result<Vec<Vec<f32>>, String> {
+        self.pending.extend_from_slice(samples);
+        let mut closed_chunks = Vec::new();
+
+        let frame_size = self.vad.chunk_size();
+        while self.pending.len() >= frame_size {
+            let frame: Vec<f32> = self.pending.drain(..frame_size).collect();
+            let speech_prob = self.vad.process(&frame)?;
+            self.current_chunk.extend_from_slice(&frame);
+
+            if speech_prob >= self.config.speech_threshold {
+                self.state = SegmenterState::Speech;
+                self.silence_hold_ms = 0.0;
+                continue;
+            }
+
+            if self.state == SegmenterState::Speech {
+                self.silence_hold_ms += self.frame_duration_ms;
+                if self.silence_hold_ms >= self.config.silence_hangover_ms as f32 {
+                    closed_chunks.push(std::mem::take(&mut self.current_chunk));
+                    self.state = SegmenterState::Silence;
+                    self.silence_hold_ms = 0.0;
+                }
+            }
+        }
+
+        Ok(closed_chunks)
+    }
+
+    /// Resolve the conventional on-disk location for the bundled Silero VAD
+    /// model; delegates to [`SileroVad::default_model_path`] since both
+    /// detectors share the same model file.
+    pub fn default_model_path(models_dir: &Path) -> PathBuf {
+        SileroVad::default_model_path(models_dir)
+    }
+
+    /// Take whatever samples have accumulated since the last boundary,
+    /// e.g. to flush a trailing chunk at end of stream. Returns `None` if
+    /// nothing has accumulated.
+    pub fn flush(&mut self) -> Option<Vec<f32>> {
+        if self.current_chunk.is_empty() {
+            None
+        } else {
+            Some(std::mem::take(&mut self.current_chunk))
+        }
+    }
+
+    /// Reset the recurrent VAD state and segmentation state, e.g. at the
+    /// start of a new recording.
+    pub fn reset(&mut self) {
+        self.vad.reset_state();
+        self.state = SegmenterState::Silence;
+        self.silence_hold_ms = 0.0;
+        self.pending.clear();
+        self.current_chunk.clear();
+    }
+}
@@ -0,0 +1,231 @@
+/// Custom vocabulary and phrase biasing for domain terms, names, and
+/// acronyms Whisper would otherwise mishear.
+///
+/// Two mechanisms share one configured phrase list: [`build_initial_prompt`]
+/// primes decoding by folding the phrases into Whisper's initial prompt
+/// (applied in [`super::Transcriber::transcribe_samples`]), and
+/// [`apply_corrections`] runs on the decoded text afterward, resolving exact
+/// literal substitutions first (e.g. "gpt three" -> "GPT-3") and then
+/// rewriting near-misses back to the canonical spelling via bounded
+/// Levenshtein-distance fuzzy matching. The correction pass is meant to run
+/// early - as a stage in `simple_transcriber::SimpleTranscriptionService`
+/// and before `foundation_models::FoundationModelsProcessor` enhancement -
+/// so corrected terms survive grammar cleanup rather than being re-mangled
+/// by it.
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Configuration for [`build_initial_prompt`] and [`apply_corrections`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VocabularyConfig {
+    pub enabled: bool,
+    /// Phrases to bias decoding toward and to fuzzy-correct transcript
+    /// tokens against (product names, acronyms, jargon).
+    pub phrases: Vec<String>,
+    /// Exact, case-insensitive literal replacements applied before fuzzy
+    /// correction, keyed by the mis-transcribed form.
+    pub substitutions: HashMap<String, String>,
+}
+
+impl Default for VocabularyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            phrases: Vec::new(),
+            substitutions: HashMap::new(),
+        }
+    }
+}
+
+/// Fold `config.phrases` into `base_prompt` for Whisper's initial-prompt
+/// biasing. Returns `base_prompt` unchanged if vocabulary is disabled or
+/// empty.
+pub fn build_initial_prompt(base_prompt: &str, config: &VocabularyConfig) -> String {
+    if !config.enabled || config.phrases.is_empty() {
+        return base_prompt.to_string();
+    }
+
+    format!("{} Vocabulary: {}.", base_prompt, config.phrases.join(", "))
+}
+
+/// Apply literal substitutions, then bounded fuzzy-match correction against
+/// `config.phrases`, to `transcript`. Returns `transcript` unchanged if
+/// vocabulary is disabled.
+pub fn apply_corrections(transcript: &str, config: &VocabularyConfig) -> String {
+    if !config.enabled {
+        return transcript.to_string();
+    }
+
+    let substituted = apply_literal_substitutions(transcript, &config.substitutions);
+    apply_fuzzy_corrections(&substituted, &config.phrases)
+}
+
+fn apply_literal_substitutions(transcript: &str, substitutions: &HashMap<String, String>) -> String {
+    let mut result = transcript.to_string();
+    for (from, to) in substitutions {
+        result = replace_case_insensitive(&result, from, to);
+    }
+    result
+}
+
+/// Replace every case-insensitive occurrence of `needle` in `haystack` with
+/// `replacement`, preserving the rest of the text verbatim.
+fn replace_case_insensitive(haystack: &str, needle: &str, replacement: &str) -> String {
+    if needle.is_empty() {
+        return haystack.to_string();
+    }
+
+    let lower_haystack = haystack.to_lowercase();
+    let lower_needle = needle.to_lowercase();
+    let mut result = String::with_capacity(haystack.len());
+    let mut cursor = 0;
+
+    while let Some(found_at) = lower_haystack[cursor..].find(&lower_needle) {
+        let match_start = cursor + found_at;
+        let match_end = match_start + needle.len();
+        result.push_str(&haystack[cursor..match_start]);
+        result.push_str(replacement);
+        cursor = match_end;
+    }
+    result.push_str(&haystack[cursor..]);
+
+    result
+}
+
+/// Bounded edit distance a phrase is allowed to match within: roughly one
+/// edit per four characters (rounded down), floored at one so even short
+/// phrases tolerate a single substitution.
+fn max_distance_for(phrase: &str) -> usize {
+    (phrase.chars().count() / 4).max(1)
+}
+
+/// Slide a window the width of each phrase's word count across
+/// `transcript`'s words and rewrite any window within that phrase's bounded
+/// edit distance to the phrase's canonical spelling. Windows already
+/// consumed by an earlier (earlier-listed) phrase are skipped so phrases
+/// don't compete for overlapping text.
+fn apply_fuzzy_corrections(transcript: &str, phrases: &[String]) -> String {
+    if phrases.is_empty() {
+        return transcript.to_string();
+    }
+
+    let words: Vec<&str> = transcript.split_whitespace().collect();
+    if words.is_empty() {
+        return transcript.to_string();
+    }
+
+    let mut output_words: Vec<String> = words.iter().map(|w| w.to_string()).collect();
+    let mut consumed = vec![false; words.len()];
+
+    for phrase in phrases {
+        let phrase_word_count = phrase.split_whitespace().count().max(1);
+        let max_distance = max_distance_for(phrase);
+        let mut i = 0;
+
+        while i + phrase_word_count <= words.len() {
+            if consumed[i..i + phrase_word_count].iter().any(|&c| c) {
+                i += 1;
+                continue;
+            }
+
+            let window = strip_punctuation(&words[i..i + phrase_word_count].join(" "));
+            if !window.eq_ignore_ascii_case(phrase)
+                && levenshtein(&window.to_lowercase(), &phrase.to_lowercase()) <= max_distance
+            {
+                output_words[i] = phrase.clone();
+                for word in output_words.iter_mut().take(i + phrase_word_count).skip(i + 1) {
+                    word.clear();
+                }
+                for flag in consumed.iter_mut().take(i + phrase_word_count).skip(i) {
+                    *flag = true;
+                }
+                i += phrase_word_count;
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    output_words
+        .into_iter()
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn strip_punctuation(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric()).to_string()
+}
+
+/// Levenshtein edit distance between two strings, computed over `char`s
+/// (not bytes) so multi-byte characters count as a single edit.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (n, m) = (a.len(), b.len());
+
+    let mut prev: Vec<usize> = (0..=m).collect();
+    let mut curr = vec![0usize; m + 1];
+
+    for i in 1..=n {
+        curr[0] = i;
+        for j in 1..=m {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[m]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(phrases: &[&str], substitutions: &[(&str, &str)]) -> VocabularyConfig {
+        VocabularyConfig {
+            enabled: true,
+            phrases: phrases.iter().map(|s| s.to_string()).collect(),
+            substitutions: substitutions
+                .iter()
+                .map(|(from, to)| (from.to_string(), to.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn initial_prompt_includes_configured_phrases() {
+        let prompt = build_initial_prompt("Base prompt.", &config(&["Kubernetes", "etcd"], &[]));
+        assert!(prompt.contains("Base prompt."));
+        assert!(prompt.contains("Kubernetes"));
+        assert!(prompt.contains("etcd"));
+    }
+
+    #[test]
+    fn disabled_config_leaves_prompt_and_transcript_unchanged() {
+        let disabled = VocabularyConfig::default();
+        assert_eq!(build_initial_prompt("Base prompt.", &disabled), "Base prompt.");
+        assert_eq!(apply_corrections("some words here", &disabled), "some words here");
+    }
+
+    #[test]
+    fn literal_substitution_is_case_insensitive() {
+        let result = apply_corrections("I love GPT Three", &config(&[], &[("gpt three", "GPT-3")]));
+        assert_eq!(result, "I love GPT-3");
+    }
+
+    #[test]
+    fn fuzzy_match_corrects_near_miss_to_canonical_phrase() {
+        // "Kubernetis" is a one-edit-away mishearing of "Kubernetes".
+        let result = apply_corrections("deploying to kubernetis today", &config(&["Kubernetes"], &[]));
+        assert_eq!(result, "deploying to Kubernetes today");
+    }
+
+    #[test]
+    fn exact_match_is_left_in_place() {
+        let result = apply_corrections("already says Kubernetes", &config(&["Kubernetes"], &[]));
+        assert_eq!(result, "already says Kubernetes");
+    }
+}
@@ -0,0 +1,19 @@
+//! Pluggable output transports for streaming transcription results and
+//! transcript exports.
+//!
+//! Modeled on lonelyradio's extensible `Writer`/`Reader` enums: a small
+//! `ResultSink` trait abstracts "somewhere to send length-prefixed JSON
+//! frames", with implementations for a local file, stdout, and a plain TCP
+//! socket. `ZmqSink` pushes onto the same kind of ZeroMQ PUSH/PULL pipe
+//! `scout-transcriber`'s `--use-zeromq` queue already uses, so streaming
+//! results can land in the same broker setup as transcription jobs.
+//!
+//! [`encryption`] layers an optional keystream-XOR cipher over any of these,
+//! so transcripts can be shipped to a remote consumer, or written to disk,
+//! encrypted at rest.
+
+pub mod encryption;
+pub mod sink;
+
+pub use encryption::{EncryptedReader, EncryptedWriter};
+pub use sink::{FileSink, ResultSink, StdoutSink, TcpSink, ZmqSink};
@@ -0,0 +1,113 @@
+use std::fs::File;
+use std::io::{self, Write};
+use std::net::TcpStream;
+use std::path::Path;
+
+/// Destination for length-prefixed JSON frames: a live
+/// `StreamingTranscriptionResult` or a transcript export. Each frame is a
+/// 4-byte big-endian length prefix followed by that many bytes of UTF-8
+/// JSON, so a reader never has to guess where one record ends and the next
+/// begins.
+pub trait ResultSink: Send {
+    /// Write a single length-prefixed frame of raw bytes.
+    fn send_frame(&mut self, payload: &[u8]) -> Result<(), String>;
+
+    /// Serialize `value` to JSON and write it as one length-prefixed frame.
+    fn send_json(&mut self, value: &serde_json::Value) -> Result<(), String> {
+        let payload = serde_json::to_vec(value).map_err(|e| format!("Failed to serialize result: {}", e))?;
+        self.send_frame(&payload)
+    }
+}
+
+fn write_frame<W: Write>(writer: &mut W, payload: &[u8]) -> Result<(), String> {
+    let len = payload.len() as u32;
+    writer
+        .write_all(&len.to_be_bytes())
+        .map_err(|e| format!("Failed to write frame length: {}", e))?;
+    writer
+        .write_all(payload)
+        .map_err(|e| format!("Failed to write frame payload: {}", e))?;
+    writer.flush().map_err(|e| format!("Failed to flush sink: {}", e))
+}
+
+/// Writes frames to a local file, truncating it first.
+pub struct FileSink {
+    file: File,
+}
+
+impl FileSink {
+    pub fn create(path: &Path) -> Result<Self, String> {
+        let file = File::create(path).map_err(|e| format!("Failed to create sink file {:?}: {}", path, e))?;
+        Ok(Self { file })
+    }
+}
+
+impl ResultSink for FileSink {
+    fn send_frame(&mut self, payload: &[u8]) -> Result<(), String> {
+        write_frame(&mut self.file, payload)
+    }
+}
+
+/// Writes frames to stdout; useful for piping results into another process.
+pub struct StdoutSink;
+
+impl ResultSink for StdoutSink {
+    fn send_frame(&mut self, payload: &[u8]) -> Result<(), String> {
+        write_frame(&mut io::stdout(), payload)
+    }
+}
+
+/// Writes frames to a connected TCP socket.
+pub struct TcpSink {
+    stream: TcpStream,
+}
+
+impl TcpSink {
+    pub fn connect(addr: &str) -> Result<Self, String> {
+        let stream = TcpStream::connect(addr).map_err(|e| format!("Failed to connect TCP sink to {}: {}", addr, e))?;
+        Ok(Self { stream })
+    }
+}
+
+impl ResultSink for TcpSink {
+    fn send_frame(&mut self, payload: &[u8]) -> Result<(), String> {
+        write_frame(&mut self.stream, payload)
+    }
+}
+
+/// Pushes frames onto a ZeroMQ PUSH socket, the same push/pull pattern
+/// `scout-transcriber`'s `--use-zeromq` queue uses for transcription jobs,
+/// so streaming results can be consumed by the same broker setup. The
+/// `zeromq` crate's socket API is async-only, so unlike the other sinks here
+/// `ZmqSink` exposes `send_frame_async`/`send_json_async` directly rather
+/// than implementing the synchronous `ResultSink` trait.
+pub struct ZmqSink {
+    socket: zeromq::PushSocket,
+}
+
+impl ZmqSink {
+    pub async fn connect(endpoint: &str) -> Result<Self, String> {
+        use zeromq::Socket;
+
+        let mut socket = zeromq::PushSocket::new();
+        socket
+            .connect(endpoint)
+            .await
+            .map_err(|e| format!("Failed to connect ZeroMQ sink to {}: {}", endpoint, e))?;
+        Ok(Self { socket })
+    }
+
+    pub async fn send_frame_async(&mut self, payload: &[u8]) -> Result<(), String> {
+        use zeromq::SocketSend;
+
+        self.socket
+            .send(payload.to_vec().into())
+            .await
+            .map_err(|e| format!("Failed to send ZeroMQ frame: {}", e))
+    }
+
+    pub async fn send_json_async(&mut self, value: &serde_json::Value) -> Result<(), String> {
+        let payload = serde_json::to_vec(value).map_err(|e| format!("Failed to serialize result: {}", e))?;
+        self.send_frame_async(&payload).await
+    }
+}
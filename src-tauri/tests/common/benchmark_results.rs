@@ -0,0 +1,97 @@
+/// Persists benchmark results across runs so a regression can be caught by
+/// diffing two runs instead of eyeballing printed numbers.
+///
+/// Each [`BenchmarkCollection`] is one run's worth of [`BenchmarkRecord`]s,
+/// serialized to `target/scout-benchmarks/<unix-timestamp>.json`. The
+/// `scout-bench-compare` binary (`src/bin/scout_bench_compare.rs`) loads two
+/// such files and prints an old-vs-new delta table, flagging regressions.
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// One measured benchmark data point.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkRecord {
+    /// The benchmark/group name, e.g. `"sample_writing"`.
+    pub name: String,
+    /// The parameter under test, e.g. `"100ms"` or `"48000Hz"`.
+    pub parameter: String,
+    pub mean_ns: f64,
+    /// Samples/bytes per second, when the benchmark has a natural
+    /// throughput figure; `None` for ones that don't (e.g. startup latency).
+    pub throughput: Option<f64>,
+    pub timestamp: u64,
+    /// Short git commit hash the run was captured at, or `"unknown"` if
+    /// `git` isn't available (e.g. a source tarball with no `.git`).
+    pub git_commit: String,
+}
+
+/// A full run's worth of [`BenchmarkRecord`]s.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BenchmarkCollection {
+    pub records: Vec<BenchmarkRecord>,
+}
+
+impl BenchmarkCollection {
+    pub const fn new() -> Self {
+        Self { records: Vec::new() }
+    }
+
+    /// Measures `iterations` calls to `f` with a plain wall-clock timer and
+    /// appends the mean as a new record. Separate from Criterion's own
+    /// statistical sampling (which `performance/simplified_benchmarks.rs`
+    /// still uses for its normal reports) — this is the lightweight number that gets persisted for
+    /// trend tracking. `units_per_call` (e.g. samples written per call) is
+    /// used to derive a `throughput` (units/sec) from the measured mean;
+    /// pass `None` for benchmarks with no natural throughput figure (e.g.
+    /// startup latency).
+    pub fn record_timed(&mut self, name: &str, parameter: &str, iterations: u32, units_per_call: Option<f64>, mut f: impl FnMut()) {
+        let start = std::time::Instant::now();
+        for _ in 0..iterations {
+            f();
+        }
+        let mean_ns = start.elapsed().as_nanos() as f64 / iterations.max(1) as f64;
+        let throughput = units_per_call.map(|units| units / (mean_ns / 1_000_000_000.0));
+
+        self.records.push(BenchmarkRecord {
+            name: name.to_string(),
+            parameter: parameter.to_string(),
+            mean_ns,
+            throughput,
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0),
+            git_commit: current_git_commit(),
+        });
+    }
+
+    /// Serializes to `target/scout-benchmarks/<unix-timestamp>.json`,
+    /// creating the directory if needed, and returns the path written.
+    pub fn save_timestamped(&self) -> std::io::Result<PathBuf> {
+        let dir = Path::new("target/scout-benchmarks");
+        std::fs::create_dir_all(dir)?;
+
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        let path = dir.join(format!("{}.json", timestamp));
+
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(&path, json)?;
+
+        Ok(path)
+    }
+
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+}
+
+fn current_git_commit() -> String {
+    std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
@@ -3,9 +3,12 @@
 /// Run with: cargo test --test simplified_benchmarks --release -- --nocapture
 /// For stress tests: cargo test --test simplified_benchmarks --release -- --ignored --nocapture
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
+use criterion::{black_box, criterion_group, Criterion, BenchmarkId, Throughput};
 use scout::{
+    audio::resampler::StreamingResampler,
+    audio::signal_gen::{SignalKind, TestSignal},
     audio::simple_recorder::SimpleAudioRecorder,
+    bench::sysinfo::{get_memory_usage_mb, HostProfile},
     transcription::simple_transcriber::SimpleTranscriptionService,
 };
 use std::time::Duration;
@@ -17,6 +20,16 @@ use hound::WavSpec;
 mod common;
 use common::*;
 
+#[path = "../common/benchmark_results.rs"]
+mod benchmark_results;
+use benchmark_results::BenchmarkCollection;
+use std::sync::Mutex;
+
+/// Persisted benchmark results for this run, written to
+/// `target/scout-benchmarks/` once all groups have finished (see `main`
+/// below) so `scout-bench-compare` can diff it against a previous run.
+static RESULTS: Mutex<BenchmarkCollection> = Mutex::new(BenchmarkCollection::new());
+
 /// Benchmark recording startup latency
 fn bench_recording_startup(c: &mut Criterion) {
     let mut group = c.benchmark_group("recording_startup");
@@ -39,19 +52,27 @@ fn bench_recording_startup(c: &mut Criterion) {
                 
                 let recorder = SimpleAudioRecorder::new(spec);
                 let mut counter = 0;
-                
+
                 b.iter(|| {
                     let path = temp_dir.path().join(format!("bench_{}.wav", counter));
                     counter += 1;
-                    
+
                     // Measure startup time
                     recorder.start_recording(&path).unwrap();
                     recorder.stop_recording().unwrap();
                 });
+
+                let mut persist_counter = counter;
+                RESULTS.lock().unwrap().record_timed("recording_startup", &format!("{}Hz", sample_rate), 20, None, || {
+                    let path = temp_dir.path().join(format!("bench_persist_{}.wav", persist_counter));
+                    persist_counter += 1;
+                    recorder.start_recording(&path).unwrap();
+                    recorder.stop_recording().unwrap();
+                });
             },
         );
     }
-    
+
     group.finish();
 }
 
@@ -65,7 +86,11 @@ fn bench_sample_writing(c: &mut Criterion) {
     // Test different buffer sizes
     for buffer_size in &[480usize, 4800, 48000, 480000] {
         let duration_ms = (*buffer_size as f64 / 48.0) as u64;
-        
+        // Elements (samples/sec) and bytes (MB/s, 4 bytes per f32 sample)
+        // report throughput directly instead of leaving readers to divide
+        // `buffer_size` by the printed mean themselves.
+        group.throughput(Throughput::Bytes((*buffer_size * std::mem::size_of::<f32>()) as u64));
+
         group.bench_with_input(
             BenchmarkId::from_parameter(format!("{}ms", duration_ms)),
             buffer_size,
@@ -76,22 +101,128 @@ fn bench_sample_writing(c: &mut Criterion) {
                     bits_per_sample: 32,
                     sample_format: hound::SampleFormat::Float,
                 };
-                
+
                 let recorder = SimpleAudioRecorder::new(spec);
                 let path = temp_dir.path().join("write_bench.wav");
                 recorder.start_recording(&path).unwrap();
-                
-                let samples = vec![0.0f32; buffer_size];
-                
+
+                // A real 440Hz tone, not silence, so the write path is
+                // exercised with non-zero data the way a live recording
+                // would be.
+                let mut signal = TestSignal::new(SignalKind::Sine { frequency_hz: 440.0, amplitude: 0.5 }, 48000, 0);
+                let mut samples = vec![0.0f32; buffer_size];
+
                 b.iter(|| {
+                    signal.fill(&mut samples);
                     recorder.write_samples(black_box(&samples)).unwrap();
                 });
-                
+
+                let mut persist_samples = vec![0.0f32; buffer_size];
+                RESULTS.lock().unwrap().record_timed(
+                    "sample_writing",
+                    &format!("{}ms", duration_ms),
+                    20,
+                    Some(buffer_size as f64),
+                    || {
+                        signal.fill(&mut persist_samples);
+                        recorder.write_samples(&persist_samples).unwrap();
+                    },
+                );
+
                 recorder.stop_recording().unwrap();
             },
         );
     }
-    
+
+    group.finish();
+}
+
+/// Compares `write_samples` fed a freshly-allocated `Vec` each iteration
+/// against `write_samples_into` fed a reused `ScratchBuffer`, mirroring
+/// `bench_memory_allocation`'s "reuse buffer vs fresh alloc" comparison but
+/// against the actual recorder write path.
+fn bench_write_path_comparison(c: &mut Criterion) {
+    let mut group = c.benchmark_group("write_path_alloc_vs_pooled");
+    let temp_dir = TempDir::new().unwrap();
+
+    for buffer_size in &[480usize, 4800, 48000] {
+        group.throughput(Throughput::Bytes((*buffer_size * std::mem::size_of::<f32>()) as u64));
+
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 48000,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+
+        group.bench_with_input(
+            BenchmarkId::new("allocating", buffer_size),
+            buffer_size,
+            |b, &buffer_size| {
+                let recorder = SimpleAudioRecorder::new(spec);
+                let path = temp_dir.path().join("write_path_alloc.wav");
+                recorder.start_recording(&path).unwrap();
+                let mut signal = TestSignal::new(SignalKind::Sine { frequency_hz: 440.0, amplitude: 0.5 }, 48000, 0);
+
+                b.iter(|| {
+                    let samples = signal.generate(buffer_size);
+                    recorder.write_samples(black_box(&samples)).unwrap();
+                });
+
+                recorder.stop_recording().unwrap();
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("pooled", buffer_size),
+            buffer_size,
+            |b, &buffer_size| {
+                let recorder = SimpleAudioRecorder::new(spec);
+                let path = temp_dir.path().join("write_path_pooled.wav");
+                recorder.start_recording(&path).unwrap();
+                let mut signal = TestSignal::new(SignalKind::Sine { frequency_hz: 440.0, amplitude: 0.5 }, 48000, 0);
+                let mut scratch = recorder.take_scratch_buffer(buffer_size);
+
+                b.iter(|| {
+                    let buf = scratch.as_mut_vec();
+                    buf.resize(buffer_size, 0.0);
+                    signal.fill(buf);
+                    recorder.write_samples_into(black_box(&mut scratch)).unwrap();
+                });
+
+                recorder.stop_recording().unwrap();
+                recorder.return_scratch_buffer(scratch);
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// Benchmark streaming 48kHz -> 16kHz resampling throughput, paralleling
+/// `bench_sample_writing`'s buffer sizes so the two can be compared.
+fn bench_resampling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("resampling_48k_to_16k");
+    group.measurement_time(Duration::from_secs(10));
+
+    for buffer_size in &[480usize, 4800, 48000, 480000] {
+        let duration_ms = (*buffer_size as f64 / 48.0) as u64;
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{}ms", duration_ms)),
+            buffer_size,
+            |b, &buffer_size| {
+                let mut signal = TestSignal::new(SignalKind::Sine { frequency_hz: 440.0, amplitude: 0.5 }, 48000, 0);
+                let mut resampler = StreamingResampler::new(48000, 16000);
+                let samples = signal.generate(buffer_size);
+
+                b.iter(|| {
+                    let _ = black_box(resampler.resample(black_box(&samples)));
+                });
+            },
+        );
+    }
+
     group.finish();
 }
 
@@ -105,6 +236,10 @@ fn bench_recording_session(c: &mut Criterion) {
     
     // Test different recording durations
     for duration_secs in &[1u64, 5, 10] {
+        // Samples/sec throughput, so results read directly as a
+        // real-time-factor-comparable rate rather than a raw mean time.
+        group.throughput(Throughput::Elements(48000 * duration_secs));
+
         group.bench_with_input(
             BenchmarkId::from_parameter(format!("{}s", duration_secs)),
             duration_secs,
@@ -126,21 +261,49 @@ fn bench_recording_session(c: &mut Criterion) {
                     // Complete recording session
                     recorder.start_recording(&path).unwrap();
                     
-                    // Write audio data
+                    // Write audio data - a real tone, not silence, so this
+                    // exercises the same non-zero data path a live
+                    // recording would.
                     let total_samples = 48000 * duration_secs as usize;
                     let chunk_size = 4800; // 100ms chunks
-                    
+                    let mut signal = TestSignal::new(SignalKind::Sine { frequency_hz: 440.0, amplitude: 0.5 }, 48000, 0);
+
                     for _ in 0..(total_samples / chunk_size) {
-                        let samples = vec![0.0f32; chunk_size];
+                        let samples = signal.generate(chunk_size);
                         recorder.write_samples(&samples).unwrap();
                     }
                     
                     let _info = recorder.stop_recording().unwrap();
                 });
+
+                let mut persist_counter = 0;
+                let persist_total_samples = 48000 * duration_secs as usize;
+                let persist_chunk_size = 4800;
+                RESULTS.lock().unwrap().record_timed(
+                    "recording_session",
+                    &format!("{}s", duration_secs),
+                    10,
+                    Some(persist_total_samples as f64),
+                    || {
+                        let recorder = SimpleAudioRecorder::new(spec.clone());
+                        let path = temp_dir.path().join(format!("session_persist_{}.wav", persist_counter));
+                        persist_counter += 1;
+
+                        recorder.start_recording(&path).unwrap();
+
+                        let mut signal = TestSignal::new(SignalKind::Sine { frequency_hz: 440.0, amplitude: 0.5 }, 48000, 0);
+                        for _ in 0..(persist_total_samples / persist_chunk_size) {
+                            let samples = signal.generate(persist_chunk_size);
+                            recorder.write_samples(&samples).unwrap();
+                        }
+
+                        recorder.stop_recording().unwrap();
+                    },
+                );
             },
         );
     }
-    
+
     group.finish();
 }
 
@@ -220,25 +383,30 @@ fn bench_file_io(c: &mut Criterion) {
 fn stress_test_long_recording() {
     let temp_dir = TempDir::new().unwrap();
     let output_path = temp_dir.path().join("long_recording.wav");
-    
+
+    let host_profile = HostProfile::probe(temp_dir.path());
+    host_profile.print_summary();
+
     let spec = WavSpec {
         channels: 1,
         sample_rate: 48000,
         bits_per_sample: 32,
         sample_format: hound::SampleFormat::Float,
     };
-    
+
     let recorder = SimpleAudioRecorder::new(spec);
     recorder.start_recording(&output_path).unwrap();
-    
+
     let start = std::time::Instant::now();
     let target_duration = Duration::from_secs(1800); // 30 minutes
     let chunk_size = 48000; // 1 second chunks
     let mut samples_written = 0u64;
-    let mut max_memory = get_memory_usage_mb();
-    
+    let baseline_memory = get_memory_usage_mb();
+    let mut max_memory = baseline_memory;
+    let mut signal = TestSignal::new(SignalKind::Sine { frequency_hz: 440.0, amplitude: 0.5 }, 48000, 0);
+
     while start.elapsed() < target_duration {
-        let samples = vec![0.0f32; chunk_size];
+        let samples = signal.generate(chunk_size);
         recorder.write_samples(&samples).unwrap();
         samples_written += chunk_size as u64;
         
@@ -253,15 +421,48 @@ fn stress_test_long_recording() {
     }
     
     let info = recorder.stop_recording().unwrap();
-    
+    let elapsed_secs = start.elapsed().as_secs_f64();
+
     println!("\n=== Long Recording Stress Test Results ===");
     println!("Duration: {:.2} seconds", info.duration_seconds);
     println!("Samples: {}", info.duration_samples);
     println!("File size: {} MB", std::fs::metadata(&output_path).unwrap().len() / 1_000_000);
-    println!("Max memory: {} MB", max_memory);
-    
-    // Verify no memory leaks (memory should be stable)
-    assert!(max_memory < 300, "Memory usage exceeded 300MB: {}MB", max_memory);
+    println!("Max memory: {} MB (baseline: {} MB)", max_memory, baseline_memory);
+
+    // Writing was never throttled, so it ran as fast as this host's write
+    // path allows - the ratio of samples actually written to what 30
+    // minutes of real-time audio at this sample rate would be is a direct
+    // "real-time factor" for the recorder on this machine.
+    let real_time_factor = (samples_written as f64 / elapsed_secs) / spec.sample_rate as f64;
+    println!("Real-time factor: {:.1}x", real_time_factor);
+    const MIN_REAL_TIME_FACTOR: f64 = 2.0;
+    assert!(
+        real_time_factor >= MIN_REAL_TIME_FACTOR,
+        "Write throughput only kept up at {:.1}x real-time, below the {:.1}x floor",
+        real_time_factor,
+        MIN_REAL_TIME_FACTOR
+    );
+
+    // Verify no memory leaks: growth over the pre-recording baseline should
+    // stay bounded regardless of how much headroom this particular machine
+    // has, rather than comparing against a number tuned on one machine.
+    const MAX_MEMORY_GROWTH_FACTOR: f64 = 3.0;
+    let max_allowed_memory = (baseline_memory.max(1) as f64 * MAX_MEMORY_GROWTH_FACTOR) as usize;
+    assert!(
+        max_memory <= max_allowed_memory,
+        "Memory grew from {}MB to {}MB, past the {:.1}x baseline allowance ({}MB)",
+        baseline_memory,
+        max_memory,
+        MAX_MEMORY_GROWTH_FACTOR,
+        max_allowed_memory
+    );
+
+    println!(
+        "Discontinuities: {}, lost: {:.1}ms, avg headroom: {:.2}",
+        info.discontinuity_count, info.lost_audio_ms, info.average_parked_ratio
+    );
+    assert_eq!(info.discontinuity_count, 0, "Write path had {} discontinuities during an untethered write loop", info.discontinuity_count);
+    assert!(info.average_parked_ratio > 0.0, "Expected headroom writing to a fast local disk, got {:.2}", info.average_parked_ratio);
 }
 
 /// Stress test: Rapid start/stop cycles
@@ -269,13 +470,16 @@ fn stress_test_long_recording() {
 #[ignore] // Run with --ignored flag
 fn stress_test_rapid_cycles() {
     let temp_dir = TempDir::new().unwrap();
+    let host_profile = HostProfile::probe(temp_dir.path());
+    host_profile.print_summary();
+
     let spec = WavSpec {
         channels: 1,
         sample_rate: 48000,
         bits_per_sample: 32,
         sample_format: hound::SampleFormat::Float,
     };
-    
+
     let recorder = SimpleAudioRecorder::new(spec);
     let cycles = 1000;
     let mut startup_times = Vec::new();
@@ -291,8 +495,9 @@ fn stress_test_rapid_cycles() {
         let startup_time = start.elapsed();
         startup_times.push(startup_time.as_micros() as u64);
         
-        // Write minimal data
-        recorder.write_samples(&vec![0.0f32; 100]).unwrap();
+        // Write minimal data - a short burst of tone rather than silence.
+        let mut signal = TestSignal::new(SignalKind::Sine { frequency_hz: 440.0, amplitude: 0.5 }, 48000, i as u64);
+        recorder.write_samples(&signal.generate(100)).unwrap();
         
         recorder.stop_recording().unwrap();
         
@@ -313,8 +518,23 @@ fn stress_test_rapid_cycles() {
     println!("Min startup: {}µs", min_startup);
     println!("Max startup: {}µs", max_startup);
     
-    // All startups should be under 100ms (100,000µs)
-    assert!(*max_startup < 100_000, "Max startup exceeded 100ms: {}µs", max_startup);
+    // `start_recording` is dominated by opening the output file, so the
+    // acceptable startup latency scales with how fast this host can write
+    // to disk rather than a number tuned on one reference machine: a disk
+    // at `REFERENCE_DISK_MIB_PER_SEC` gets `BASE_MAX_STARTUP_US`, and a
+    // slower disk is given proportionally more headroom.
+    const REFERENCE_DISK_MIB_PER_SEC: f64 = 500.0;
+    const BASE_MAX_STARTUP_US: f64 = 100_000.0;
+    let max_allowed_startup_us = (BASE_MAX_STARTUP_US
+        * (REFERENCE_DISK_MIB_PER_SEC / host_profile.disk_write_mib_per_sec.max(1.0)))
+        .max(BASE_MAX_STARTUP_US) as u64;
+    println!("Max allowed startup: {}µs (disk: {:.0} MiB/s)", max_allowed_startup_us, host_profile.disk_write_mib_per_sec);
+    assert!(
+        *max_startup < max_allowed_startup_us,
+        "Max startup {}µs exceeded the {}µs allowance for this host's disk throughput",
+        max_startup,
+        max_allowed_startup_us
+    );
 }
 
 /// Stress test: Concurrent write attempts
@@ -367,10 +587,16 @@ fn stress_test_concurrent_writes() {
     }
     
     let info = recorder.stop_recording().unwrap();
-    
+
     println!("\n=== Results ===");
     println!("Total samples written: {}", info.duration_samples);
     println!("File created successfully: {}", output_path.exists());
+    println!(
+        "Discontinuities: {}, lost: {:.1}ms, avg headroom: {:.2}",
+        info.discontinuity_count, info.lost_audio_ms, info.average_parked_ratio
+    );
+    assert_eq!(info.discontinuity_count, 0, "{} concurrent writer threads should arrive faster than the {}-sample chunk duration", thread_count, 480);
+    assert!(info.average_parked_ratio > 0.0, "Expected headroom with 10 threads hammering a fast local disk, got {:.2}", info.average_parked_ratio);
 }
 
 /// Benchmark comparison: Simplified vs Legacy (when available)
@@ -395,10 +621,11 @@ fn bench_pipeline_comparison() {
         let path = temp_dir.path().join("simplified.wav");
         
         recorder.start_recording(&path).unwrap();
-        
-        // Simulate 5 seconds of recording
+
+        // Simulate 5 seconds of recording with a real tone, not silence.
+        let mut signal = TestSignal::new(SignalKind::Sine { frequency_hz: 440.0, amplitude: 0.5 }, 48000, 0);
         for _ in 0..50 {
-            let samples = vec![0.0f32; 4800]; // 100ms chunks
+            let samples = signal.generate(4800); // 100ms chunks
             recorder.write_samples(&samples).unwrap();
             std::thread::sleep(Duration::from_millis(100));
         }
@@ -421,29 +648,21 @@ criterion_group!(
     benches,
     bench_recording_startup,
     bench_sample_writing,
+    bench_write_path_comparison,
+    bench_resampling,
     bench_recording_session,
     bench_memory_allocation,
     bench_file_io
 );
 
-criterion_main!(benches);
+// Expanded by hand instead of `criterion_main!(benches)` so we can persist
+// `RESULTS` once all groups have finished, alongside Criterion's own report.
+fn main() {
+    benches();
+    Criterion::default().configure_from_args().final_summary();
 
-// Helper function
-fn get_memory_usage_mb() -> usize {
-    #[cfg(target_os = "macos")]
-    {
-        use std::process::Command;
-        let output = Command::new("ps")
-            .args(&["-o", "rss=", "-p", &std::process::id().to_string()])
-            .output()
-            .unwrap();
-        String::from_utf8_lossy(&output.stdout)
-            .trim()
-            .parse::<usize>()
-            .unwrap_or(0) / 1024
-    }
-    #[cfg(not(target_os = "macos"))]
-    {
-        0
+    match RESULTS.lock().unwrap().save_timestamped() {
+        Ok(path) => println!("Benchmark trend results written to {}", path.display()),
+        Err(e) => eprintln!("Failed to persist benchmark trend results: {}", e),
     }
 }
\ No newline at end of file
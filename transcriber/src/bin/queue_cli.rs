@@ -1,17 +1,151 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use symphonia::core::audio::AudioBufferRef;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
 use transcriber::{
-    protocol::{AudioChunk, Transcript, TranscriptionError},
+    protocol::{AudioChunk, Transcript, TranscriptUpdate, TranscriptionError},
     queue::{Queue, SledQueue},
 };
-use std::path::PathBuf;
 use uuid::Uuid;
 
+/// Sample rate the transcription pipeline expects every `AudioChunk` to
+/// arrive at, matching `audioconvert ! audioresample !
+/// audio/x-raw,channels=1,rate=16000`.
+const PIPELINE_SAMPLE_RATE: u32 = 16000;
+
+/// The waveform [`Commands::TestPush`] and [`Commands::VerifySignal`]
+/// synthesize, mirroring (in miniature) `scout_lib::audio::signal_gen`'s
+/// `SignalKind` — kept self-contained here since this crate doesn't depend
+/// on the Tauri app crate.
+#[derive(Debug, Clone, Copy, clap::ValueEnum, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Waveform {
+    Sine,
+    Square,
+    Saw,
+    Triangle,
+    WhiteNoise,
+    PinkNoise,
+    Silence,
+}
+
+/// How every subcommand's result is printed: `json` (compact, one line),
+/// `ndjson` (one compact JSON line per record — for commands that can emit
+/// more than one, e.g. `TestPush --num-chunks`, `List`), or `pretty`
+/// (indented JSON). Makes `queue-cli` composable in shell pipelines the way
+/// a `--json` flag is on other device/queue CLIs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    Json,
+    Ndjson,
+    Pretty,
+}
+
+/// `{"ok":true,"data":...}` / `{"ok":false,"error":...}` envelope every
+/// subcommand's result is wrapped in before printing.
+#[derive(Serialize)]
+struct Envelope<T: Serialize> {
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+fn print_record(format: OutputFormat, value: &impl Serialize) {
+    let text = if format == OutputFormat::Pretty {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    }
+    .expect("envelope values are always serializable");
+    println!("{}", text);
+}
+
+/// Prints `data` wrapped in a success envelope.
+fn emit_ok<T: Serialize>(format: OutputFormat, data: T) {
+    print_record(
+        format,
+        &Envelope {
+            ok: true,
+            data: Some(data),
+            error: None,
+        },
+    );
+}
+
+/// Prints one envelope per item when `format` is `ndjson`, otherwise a
+/// single envelope wrapping the whole vec — lets streaming-shaped commands
+/// (`TestPush --num-chunks`, `List`) emit tail-f-friendly lines without
+/// changing behavior for `json`/`pretty` callers.
+fn emit_many<T: Serialize>(format: OutputFormat, items: Vec<T>) {
+    if format == OutputFormat::Ndjson {
+        for item in items {
+            emit_ok(format, item);
+        }
+    } else {
+        emit_ok(format, items);
+    }
+}
+
+/// Prints `message` wrapped in a failure envelope.
+fn emit_err(format: OutputFormat, message: impl Into<String>) {
+    print_record(
+        format,
+        &Envelope::<()> {
+            ok: false,
+            data: None,
+            error: Some(message.into()),
+        },
+    );
+}
+
+/// Process exit code for a subcommand's outcome: 0 on success, and a few
+/// distinct non-zero codes for conditions scripts commonly branch on rather
+/// than treat as a generic failure.
+#[derive(Debug, Clone, Copy)]
+enum ExitStatus {
+    Success,
+    /// `WaitResult`/`VerifySignal --id` hit their timeout with no result.
+    Timeout,
+    /// `Pop` found nothing to pop.
+    EmptyQueue,
+    Failure,
+}
+
+impl From<ExitStatus> for ExitCode {
+    fn from(status: ExitStatus) -> Self {
+        match status {
+            ExitStatus::Success => ExitCode::from(0),
+            ExitStatus::Timeout => ExitCode::from(3),
+            ExitStatus::EmptyQueue => ExitCode::from(4),
+            ExitStatus::Failure => ExitCode::from(1),
+        }
+    }
+}
+
 /// CLI tool for interacting with Transcriber queues
 #[derive(Parser)]
 #[command(name = "queue-cli")]
 #[command(about = "CLI for Scout Transcriber queue operations")]
 struct Cli {
+    /// Output format for the result envelope
+    #[arg(long, value_enum, global = true, default_value = "pretty")]
+    format: OutputFormat,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -23,234 +157,990 @@ enum Commands {
         /// Audio data as JSON array
         #[arg(long)]
         audio: String,
-        
+
         /// Sample rate
         #[arg(long, default_value = "16000")]
         sample_rate: u32,
-        
+
         /// Number of channels
         #[arg(long, default_value = "1")]
         channels: u8,
     },
-    
+
+    /// Push audio decoded from a WAV/FLAC/MP3 file, downmixed to mono and
+    /// resampled to the pipeline's 16kHz, split into fixed-duration chunks
+    PushFile {
+        /// Path to the audio file to decode
+        #[arg(long)]
+        path: PathBuf,
+
+        /// Split the (resampled) audio into chunks of this length
+        #[arg(long, default_value = "5.0")]
+        chunk_secs: f32,
+
+        /// Session id stamped onto every emitted chunk's metadata so they
+        /// can be grouped back together; generated if not given
+        #[arg(long)]
+        session_id: Option<String>,
+    },
+
     /// Pop from a queue
     Pop {
         /// Queue to pop from (input or output)
         #[arg(long)]
         queue: String,
     },
-    
+
     /// List queue contents
     List {
         /// Queue to list (input or output)
         #[arg(long)]
         queue: String,
     },
-    
+
+    /// Non-destructively look at up to `--limit` pending items, without
+    /// popping them or disturbing queue order
+    Peek {
+        /// Queue to peek (input or output)
+        #[arg(long)]
+        queue: String,
+
+        /// Maximum number of items to show
+        #[arg(long, default_value = "10")]
+        limit: usize,
+    },
+
+    /// Block and stream newly-arrived items as NDJSON (tail -f style) until
+    /// interrupted
+    Watch {
+        /// Queue to watch (input or output)
+        #[arg(long)]
+        queue: String,
+    },
+
     /// Get queue length
     Len {
         /// Queue to check (input or output)
         #[arg(long)]
         queue: String,
     },
-    
+
     /// Clear a queue
     Clear {
         /// Queue to clear (input or output)
         #[arg(long)]
         queue: String,
     },
-    
+
     /// Push test audio
     TestPush {
         /// Duration in seconds
         #[arg(long, default_value = "2.0")]
         duration: f32,
-        
+
         /// Frequency in Hz
         #[arg(long, default_value = "440")]
         frequency: f32,
+
+        /// Waveform shape to synthesize
+        #[arg(long, value_enum, default_value = "sine")]
+        waveform: Waveform,
+
+        /// Peak amplitude, in `[0.0, 1.0]`
+        #[arg(long, default_value = "0.3")]
+        amplitude: f32,
+
+        /// Number of chunks to push, each an independent signal with its
+        /// own id, for exercising more than one round through the backend
+        /// at a time
+        #[arg(long, default_value = "1")]
+        num_chunks: usize,
+    },
+
+    /// Check a synthesized signal for discontinuities introduced by
+    /// waveform generation or by the `AudioChunk` wire round-trip, and
+    /// (when `--id` is given) report how its transcript came back
+    VerifySignal {
+        /// Waveform shape to synthesize and check
+        #[arg(long, value_enum, default_value = "sine")]
+        waveform: Waveform,
+
+        /// Duration in seconds
+        #[arg(long, default_value = "2.0")]
+        duration: f32,
+
+        /// Frequency in Hz
+        #[arg(long, default_value = "440")]
+        frequency: f32,
+
+        /// Peak amplitude, in `[0.0, 1.0]`
+        #[arg(long, default_value = "0.3")]
+        amplitude: f32,
+
+        /// Sample-to-sample delta above which a jump is flagged as a
+        /// discontinuity
+        #[arg(long, default_value = "0.2")]
+        threshold: f32,
+
+        /// Chunk id previously submitted via `TestPush` — if given, also
+        /// pop the matching transcript from the output queue and report
+        /// its timing
+        #[arg(long)]
+        id: Option<String>,
+
+        /// Timeout in seconds when `--id` is given
+        #[arg(long, default_value = "30")]
+        timeout: u64,
     },
-    
-    /// Wait for a specific result
+
+    /// Wait for a specific result, printing each `Partial` hypothesis as it
+    /// arrives before the matching `Final` transcript
     WaitResult {
         /// Chunk ID to wait for
         #[arg(long)]
         id: String,
-        
+
         /// Timeout in seconds
         #[arg(long, default_value = "30")]
         timeout: u64,
     },
+
+    /// Throughput/latency benchmark: push N synthetic chunks at a
+    /// configured rate and measure end-to-end queue latency draining the
+    /// output side
+    Bench {
+        /// Number of synthetic chunks to submit
+        #[arg(long, default_value = "100")]
+        num_chunks: usize,
+
+        /// Offered submission rate in chunks per second
+        #[arg(long, default_value = "10")]
+        rate: f64,
+
+        /// How long to keep draining the output queue for in-flight chunks
+        /// after the last one is submitted, in seconds
+        #[arg(long, default_value = "30")]
+        drain_timeout: u64,
+    },
 }
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> ExitCode {
     let cli = Cli::parse();
-    
+    let format = cli.format;
+
+    match run(cli).await {
+        Ok(status) => ExitCode::from(status),
+        Err(err) => {
+            emit_err(format, err.to_string());
+            ExitCode::from(ExitStatus::Failure)
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<ExitStatus> {
+    let format = cli.format;
+
     match cli.command {
         Commands::Push { audio, sample_rate, channels } => {
             let input_queue = SledQueue::<AudioChunk>::new(&PathBuf::from("/tmp/transcriber/input"))?;
-            
-            // Parse audio data
+
             let audio_data: Vec<f32> = serde_json::from_str(&audio)?;
-            
-            let chunk = AudioChunk::new(
-                audio_data,
-                sample_rate,
-                channels as u16,
-            );
-            
+            let chunk = AudioChunk::new(audio_data, sample_rate, channels as u16);
+
             input_queue.push(&chunk).await?;
-            println!("{}", serde_json::to_string(&chunk)?);
+            emit_ok(format, chunk);
+            Ok(ExitStatus::Success)
+        }
+
+        Commands::PushFile { path, chunk_secs, session_id } => {
+            let input_queue = SledQueue::<AudioChunk>::new(&PathBuf::from("/tmp/transcriber/input"))?;
+
+            let session_id = session_id.unwrap_or_else(|| Uuid::new_v4().to_string());
+            let samples = decode_and_resample_to_pipeline_rate(&path)?;
+
+            let chunk_len = ((chunk_secs * PIPELINE_SAMPLE_RATE as f32) as usize).max(1);
+            let mut chunk_ids = Vec::new();
+
+            for segment in samples.chunks(chunk_len) {
+                let mut metadata = HashMap::new();
+                metadata.insert("session_id".to_string(), session_id.clone());
+                let chunk = AudioChunk::with_metadata(segment.to_vec(), PIPELINE_SAMPLE_RATE, 1, metadata);
+
+                input_queue.push(&chunk).await?;
+                chunk_ids.push(chunk.id);
+            }
+
+            eprintln!(
+                "Pushed {} chunk(s) for session {} from {}",
+                chunk_ids.len(),
+                session_id,
+                path.display()
+            );
+
+            emit_ok(format, PushFileResult { session_id, chunk_ids });
+            Ok(ExitStatus::Success)
         }
-        
-        Commands::Pop { queue } => {
-            match queue.as_str() {
-                "input" => {
-                    let queue = SledQueue::<AudioChunk>::new(&PathBuf::from("/tmp/transcriber/input"))?;
-                    if let Some(chunk) = queue.pop().await? {
-                        println!("{}", serde_json::to_string(&chunk)?);
-                    } else {
-                        println!("null");
+
+        Commands::Pop { queue } => match queue.as_str() {
+            "input" => {
+                let queue = SledQueue::<AudioChunk>::new(&PathBuf::from("/tmp/transcriber/input"))?;
+                match queue.pop().await? {
+                    Some(chunk) => {
+                        emit_ok(format, chunk);
+                        Ok(ExitStatus::Success)
                     }
-                }
-                "output" => {
-                    let queue = SledQueue::<Result<Transcript, TranscriptionError>>::new(
-                        &PathBuf::from("/tmp/transcriber/output")
-                    )?;
-                    if let Some(result) = queue.pop().await? {
-                        match result {
-                            Ok(transcript) => println!("{}", serde_json::to_string(&transcript)?),
-                            Err(error) => println!("{}", serde_json::to_string(&error)?),
-                        }
-                    } else {
-                        println!("null");
+                    None => {
+                        emit_err(format, "input queue is empty");
+                        Ok(ExitStatus::EmptyQueue)
                     }
                 }
-                _ => eprintln!("Invalid queue: {}", queue),
             }
-        }
-        
-        Commands::List { queue } => {
-            match queue.as_str() {
-                "input" => {
-                    let queue = SledQueue::<AudioChunk>::new(&PathBuf::from("/tmp/transcriber/input"))?;
-                    let mut items = Vec::new();
-                    // Note: This is a simplified approach - in production, use proper iteration
-                    while let Some(chunk) = queue.pop().await? {
-                        items.push(chunk);
+            "output" => {
+                let queue = SledQueue::<Result<TranscriptUpdate, TranscriptionError>>::new(
+                    &PathBuf::from("/tmp/transcriber/output"),
+                )?;
+                match queue.pop().await? {
+                    Some(Ok(update)) => {
+                        emit_ok(format, update);
+                        Ok(ExitStatus::Success)
                     }
-                    // Push them back (this changes order, but it's for debugging)
-                    for item in &items {
-                        queue.push(item).await?;
+                    Some(Err(error)) => {
+                        emit_ok(format, error);
+                        Ok(ExitStatus::Success)
+                    }
+                    None => {
+                        emit_err(format, "output queue is empty");
+                        Ok(ExitStatus::EmptyQueue)
                     }
-                    println!("{}", serde_json::to_string(&items)?);
-                }
-                "output" => {
-                    let queue = SledQueue::<Result<Transcript, TranscriptionError>>::new(
-                        &PathBuf::from("/tmp/transcriber/output")
-                    )?;
-                    println!("Output queue listing not fully implemented");
                 }
-                _ => eprintln!("Invalid queue: {}", queue),
             }
-        }
-        
-        Commands::Len { queue } => {
-            match queue.as_str() {
-                "input" => {
-                    let queue = SledQueue::<AudioChunk>::new(&PathBuf::from("/tmp/transcriber/input"))?;
-                    println!("{}", queue.len().await?);
-                }
-                "output" => {
-                    let queue = SledQueue::<Result<Transcript, TranscriptionError>>::new(
-                        &PathBuf::from("/tmp/transcriber/output")
-                    )?;
-                    println!("{}", queue.len().await?);
-                }
-                _ => eprintln!("Invalid queue: {}", queue),
+            other => {
+                emit_err(format, format!("invalid queue: {}", other));
+                Ok(ExitStatus::Failure)
             }
-        }
-        
-        Commands::Clear { queue } => {
-            match queue.as_str() {
-                "input" => {
-                    let queue = SledQueue::<AudioChunk>::new(&PathBuf::from("/tmp/transcriber/input"))?;
-                    queue.clear().await?;
-                    println!("Input queue cleared");
+        },
+
+        Commands::List { queue } => match queue.as_str() {
+            "input" => {
+                let queue = SledQueue::<AudioChunk>::new(&PathBuf::from("/tmp/transcriber/input"))?;
+                let mut items = Vec::new();
+                // Note: This is a simplified approach - in production, use proper iteration
+                while let Some(chunk) = queue.pop().await? {
+                    items.push(chunk);
                 }
-                "output" => {
-                    let queue = SledQueue::<Result<Transcript, TranscriptionError>>::new(
-                        &PathBuf::from("/tmp/transcriber/output")
-                    )?;
-                    queue.clear().await?;
-                    println!("Output queue cleared");
+                // Push them back (this changes order, but it's for debugging)
+                for item in &items {
+                    queue.push(item).await?;
                 }
-                _ => eprintln!("Invalid queue: {}", queue),
+                emit_many(format, items);
+                Ok(ExitStatus::Success)
             }
-        }
-        
-        Commands::TestPush { duration, frequency } => {
+            "output" => {
+                emit_err(format, "output queue listing not fully implemented");
+                Ok(ExitStatus::Failure)
+            }
+            other => {
+                emit_err(format, format!("invalid queue: {}", other));
+                Ok(ExitStatus::Failure)
+            }
+        },
+
+        Commands::Peek { queue, limit } => match queue.as_str() {
+            "input" => {
+                let queue = SledQueue::<AudioChunk>::new(&PathBuf::from("/tmp/transcriber/input"))?;
+                emit_many(format, queue.peek(limit).await?);
+                Ok(ExitStatus::Success)
+            }
+            "output" => {
+                let queue = SledQueue::<Result<TranscriptUpdate, TranscriptionError>>::new(
+                    &PathBuf::from("/tmp/transcriber/output"),
+                )?;
+                emit_many(format, queue.peek(limit).await?);
+                Ok(ExitStatus::Success)
+            }
+            other => {
+                emit_err(format, format!("invalid queue: {}", other));
+                Ok(ExitStatus::Failure)
+            }
+        },
+
+        Commands::Watch { queue } => match queue.as_str() {
+            "input" => {
+                watch_loop::<AudioChunk, _>(&PathBuf::from("/tmp/transcriber/input"), |chunk| chunk.id).await
+            }
+            "output" => {
+                watch_loop::<Result<TranscriptUpdate, TranscriptionError>, _>(
+                    &PathBuf::from("/tmp/transcriber/output"),
+                    |result| match result {
+                        Ok(TranscriptUpdate::Partial { id, .. }) => *id,
+                        Ok(TranscriptUpdate::Final(transcript)) => transcript.id,
+                        Err(error) => error.id,
+                    },
+                )
+                .await
+            }
+            other => {
+                emit_err(format, format!("invalid queue: {}", other));
+                Ok(ExitStatus::Failure)
+            }
+        },
+
+        Commands::Len { queue } => match queue.as_str() {
+            "input" => {
+                let queue = SledQueue::<AudioChunk>::new(&PathBuf::from("/tmp/transcriber/input"))?;
+                emit_ok(format, queue.len().await?);
+                Ok(ExitStatus::Success)
+            }
+            "output" => {
+                let queue = SledQueue::<Result<TranscriptUpdate, TranscriptionError>>::new(
+                    &PathBuf::from("/tmp/transcriber/output"),
+                )?;
+                emit_ok(format, queue.len().await?);
+                Ok(ExitStatus::Success)
+            }
+            other => {
+                emit_err(format, format!("invalid queue: {}", other));
+                Ok(ExitStatus::Failure)
+            }
+        },
+
+        Commands::Clear { queue } => match queue.as_str() {
+            "input" => {
+                let queue = SledQueue::<AudioChunk>::new(&PathBuf::from("/tmp/transcriber/input"))?;
+                queue.clear().await?;
+                emit_ok(format, ClearResult { queue: "input".to_string(), cleared: true });
+                Ok(ExitStatus::Success)
+            }
+            "output" => {
+                let queue = SledQueue::<Result<TranscriptUpdate, TranscriptionError>>::new(
+                    &PathBuf::from("/tmp/transcriber/output"),
+                )?;
+                queue.clear().await?;
+                emit_ok(format, ClearResult { queue: "output".to_string(), cleared: true });
+                Ok(ExitStatus::Success)
+            }
+            other => {
+                emit_err(format, format!("invalid queue: {}", other));
+                Ok(ExitStatus::Failure)
+            }
+        },
+
+        Commands::TestPush { duration, frequency, waveform, amplitude, num_chunks } => {
             let input_queue = SledQueue::<AudioChunk>::new(&PathBuf::from("/tmp/transcriber/input"))?;
-            
-            // Generate test audio
-            let sample_rate = 16000;
-            let num_samples = (duration * sample_rate as f32) as usize;
-            let mut audio = Vec::with_capacity(num_samples);
-            
-            for i in 0..num_samples {
-                let t = i as f32 / sample_rate as f32;
-                let sample = (2.0 * std::f32::consts::PI * frequency * t).sin() * 0.3;
-                audio.push(sample);
-            }
-            
-            let chunk = AudioChunk::new(
-                audio,
-                sample_rate,
-                1,
-            );
-            
-            input_queue.push(&chunk).await?;
-            println!("{}", serde_json::to_string(&chunk)?);
+
+            let mut chunks = Vec::new();
+            for _ in 0..num_chunks.max(1) {
+                let audio = generate_waveform(waveform, duration, frequency, amplitude, PIPELINE_SAMPLE_RATE);
+                let chunk = AudioChunk::new(audio, PIPELINE_SAMPLE_RATE, 1);
+
+                input_queue.push(&chunk).await?;
+                chunks.push(chunk);
+            }
+
+            emit_many(format, chunks);
+            Ok(ExitStatus::Success)
         }
-        
+
         Commands::WaitResult { id, timeout } => {
             let chunk_id = Uuid::parse_str(&id)?;
-            let output_queue = SledQueue::<Result<Transcript, TranscriptionError>>::new(
-                &PathBuf::from("/tmp/transcriber/output")
+            let output_queue = SledQueue::<Result<TranscriptUpdate, TranscriptionError>>::new(
+                &PathBuf::from("/tmp/transcriber/output"),
             )?;
-            
-            let start = std::time::Instant::now();
-            let timeout_duration = std::time::Duration::from_secs(timeout);
-            
+
+            let start = Instant::now();
+            let timeout_duration = Duration::from_secs(timeout);
+
             loop {
-                if let Some(result) = output_queue.pop().await? {
-                    match result {
-                        Ok(transcript) if transcript.id == chunk_id => {
-                            println!("{}", serde_json::to_string(&transcript)?);
+                // Targeted peek-by-id rather than draining the whole queue
+                // and pushing unrelated results back, which reordered them.
+                let matched = output_queue
+                    .remove_matching(move |result: &Result<TranscriptUpdate, TranscriptionError>| match result {
+                        Ok(TranscriptUpdate::Partial { id, .. }) => *id == chunk_id,
+                        Ok(TranscriptUpdate::Final(transcript)) => transcript.id == chunk_id,
+                        Err(error) => error.id == chunk_id,
+                    })
+                    .await?;
+
+                match matched {
+                    // A partial isn't terminal — print it and keep waiting
+                    // for the `Final` (or the timeout) rather than exiting.
+                    Some(Ok(TranscriptUpdate::Partial { id, stability, text })) => {
+                        emit_ok(format, PartialUpdate { id, stability, text });
+                    }
+                    Some(Ok(TranscriptUpdate::Final(transcript))) => {
+                        emit_ok(format, transcript);
+                        return Ok(ExitStatus::Success);
+                    }
+                    Some(Err(error)) => {
+                        emit_ok(format, error);
+                        return Ok(ExitStatus::Success);
+                    }
+                    None => {}
+                }
+
+                if start.elapsed() > timeout_duration {
+                    emit_err(format, format!("timed out waiting for a result for {}", chunk_id));
+                    return Ok(ExitStatus::Timeout);
+                }
+
+                tokio::time::sleep(Duration::from_millis(500)).await;
+            }
+        }
+
+        Commands::VerifySignal { waveform, duration, frequency, amplitude, threshold, id, timeout } => {
+            let audio = generate_waveform(waveform, duration, frequency, amplitude, PIPELINE_SAMPLE_RATE);
+            let chunk = AudioChunk::new(audio, PIPELINE_SAMPLE_RATE, 1);
+
+            // Round-trip through the same wire format the queues store
+            // chunks in, so discontinuities introduced by (de)serialization
+            // — not just by the generator — are caught too.
+            let round_tripped = AudioChunk::from_bytes(&chunk.to_bytes()?)?;
+            let discontinuities: Vec<DiscontinuitySample> = find_discontinuities(&round_tripped.audio, threshold)
+                .into_iter()
+                .map(|(index, delta)| DiscontinuitySample { index, delta })
+                .collect();
+
+            let mut report = DiscontinuityReport {
+                waveform,
+                sample_count: round_tripped.audio.len(),
+                threshold,
+                discontinuities,
+                transcript: None,
+                timed_out: false,
+            };
+
+            if let Some(id) = id {
+                let chunk_id = Uuid::parse_str(&id)?;
+                let output_queue = SledQueue::<Result<TranscriptUpdate, TranscriptionError>>::new(
+                    &PathBuf::from("/tmp/transcriber/output"),
+                )?;
+
+                let start = Instant::now();
+                let timeout_duration = Duration::from_secs(timeout);
+                loop {
+                    let matched = output_queue
+                        .remove_matching(move |result: &Result<TranscriptUpdate, TranscriptionError>| match result {
+                            Ok(TranscriptUpdate::Partial { id, .. }) => *id == chunk_id,
+                            Ok(TranscriptUpdate::Final(transcript)) => transcript.id == chunk_id,
+                            Err(error) => error.id == chunk_id,
+                        })
+                        .await?;
+
+                    match matched {
+                        // Not terminal — keep waiting for the `Final`.
+                        Some(Ok(TranscriptUpdate::Partial { .. })) => {}
+                        Some(Ok(TranscriptUpdate::Final(transcript))) => {
+                            let processing_time_ms =
+                                transcript.metadata.as_ref().and_then(|m| m.processing_time_ms);
+                            // A pure tone with no speech should come back
+                            // empty/low-confidence; non-empty text here is
+                            // more likely a backend-introduced artifact
+                            // than a genuine transcription.
+                            let likely_artifact = !transcript.text.trim().is_empty();
+                            report.transcript = Some(TranscriptCheck {
+                                text: transcript.text,
+                                confidence: transcript.confidence,
+                                processing_time_ms,
+                                likely_artifact,
+                            });
                             break;
                         }
-                        Err(error) if error.id == chunk_id => {
-                            println!("{}", serde_json::to_string(&error)?);
+                        Some(Err(error)) => {
+                            report.transcript = Some(TranscriptCheck {
+                                text: format!("error: {}", error.message),
+                                confidence: 0.0,
+                                processing_time_ms: None,
+                                likely_artifact: true,
+                            });
                             break;
                         }
-                        other => {
-                            // Not our result, push it back
-                            output_queue.push(&other).await?;
+                        None => {}
+                    }
+
+                    if start.elapsed() > timeout_duration {
+                        report.timed_out = true;
+                        break;
+                    }
+
+                    tokio::time::sleep(Duration::from_millis(500)).await;
+                }
+            }
+
+            let timed_out = report.timed_out;
+            emit_ok(format, report);
+            Ok(if timed_out { ExitStatus::Timeout } else { ExitStatus::Success })
+        }
+
+        Commands::Bench { num_chunks, rate, drain_timeout } => {
+            let input_queue = SledQueue::<AudioChunk>::new(&PathBuf::from("/tmp/transcriber/input"))?;
+            let output_queue = SledQueue::<Result<TranscriptUpdate, TranscriptionError>>::new(
+                &PathBuf::from("/tmp/transcriber/output"),
+            )?;
+
+            // Sample the input queue's backlog on a fixed interval for the
+            // whole run, so the report shows whether the pipeline kept up
+            // or let a backlog build rather than just the before/after.
+            let backlog_samples = Arc::new(Mutex::new(Vec::new()));
+            let backlog_samples_for_task = backlog_samples.clone();
+            let stop_backlog_sampling = Arc::new(AtomicBool::new(false));
+            let stop_backlog_sampling_for_task = stop_backlog_sampling.clone();
+            let backlog_queue = input_queue.clone();
+            tokio::spawn(async move {
+                let mut tick = tokio::time::interval(Duration::from_millis(250));
+                while !stop_backlog_sampling_for_task.load(Ordering::Relaxed) {
+                    tick.tick().await;
+                    if let Ok(len) = backlog_queue.len().await {
+                        if let Ok(mut guard) = backlog_samples_for_task.lock() {
+                            guard.push(len);
+                        }
+                    }
+                }
+            });
+
+            let mut submitted: HashMap<Uuid, Instant> = HashMap::new();
+            let mut latencies_ms: Vec<f64> = Vec::new();
+            let mut idle_time = Duration::ZERO;
+            let bench_start = Instant::now();
+
+            let period = Duration::from_secs_f64(1.0 / rate.max(0.001));
+            for _ in 0..num_chunks {
+                let chunk = bench_test_chunk();
+                input_queue.push(&chunk).await?;
+                submitted.insert(chunk.id, Instant::now());
+                tokio::time::sleep(period).await;
+            }
+
+            eprintln!("📨 Submitted {} chunks at {:.1}/sec, draining output...", num_chunks, rate);
+
+            let drain_deadline = Instant::now() + Duration::from_secs(drain_timeout);
+            while !submitted.is_empty() && Instant::now() < drain_deadline {
+                let poll_start = Instant::now();
+                match output_queue.pop().await? {
+                    // Partials aren't terminal; Bench only measures
+                    // time-to-final latency, so one of ours is simply
+                    // dropped, and one that isn't is pushed back.
+                    Some(Ok(TranscriptUpdate::Partial { id, stability, text })) => {
+                        if !submitted.contains_key(&id) {
+                            output_queue
+                                .push(&Ok(TranscriptUpdate::Partial { id, stability, text }))
+                                .await?;
+                        }
+                    }
+                    Some(Ok(TranscriptUpdate::Final(transcript))) => {
+                        if let Some(sent_at) = submitted.remove(&transcript.id) {
+                            latencies_ms.push(sent_at.elapsed().as_secs_f64() * 1000.0);
+                        } else {
+                            // Not one of ours (e.g. left over from another
+                            // run); push it back rather than dropping it.
+                            output_queue.push(&Ok(TranscriptUpdate::Final(transcript))).await?;
+                        }
+                    }
+                    Some(Err(error)) => {
+                        if let Some(sent_at) = submitted.remove(&error.id) {
+                            latencies_ms.push(sent_at.elapsed().as_secs_f64() * 1000.0);
+                        } else {
+                            output_queue.push(&Err(error)).await?;
                         }
                     }
+                    None => {
+                        idle_time += poll_start.elapsed();
+                        tokio::time::sleep(Duration::from_millis(50)).await;
+                        idle_time += Duration::from_millis(50);
+                    }
                 }
-                
-                if start.elapsed() > timeout_duration {
-                    println!("null");
-                    break;
+            }
+
+            stop_backlog_sampling.store(true, Ordering::Relaxed);
+            let wall_clock = bench_start.elapsed();
+
+            let mut sorted_latencies = latencies_ms.clone();
+            sorted_latencies.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let percentile = |p: f64| -> f64 {
+                if sorted_latencies.is_empty() {
+                    return 0.0;
+                }
+                let idx = ((sorted_latencies.len() - 1) as f64 * p).round() as usize;
+                sorted_latencies[idx]
+            };
+            let mean_latency = if latencies_ms.is_empty() {
+                0.0
+            } else {
+                latencies_ms.iter().sum::<f64>() / latencies_ms.len() as f64
+            };
+
+            let backlog = backlog_samples.lock().unwrap().clone();
+            let avg_backlog = if backlog.is_empty() {
+                0.0
+            } else {
+                backlog.iter().sum::<usize>() as f64 / backlog.len() as f64
+            };
+            let peak_backlog = backlog.iter().max().copied().unwrap_or(0);
+
+            let idle_fraction = idle_time.as_secs_f64() / wall_clock.as_secs_f64().max(0.001);
+            let achieved_chunks_per_sec = latencies_ms.len() as f64 / wall_clock.as_secs_f64().max(0.001);
+            let incomplete = submitted.len();
+
+            emit_ok(
+                format,
+                BenchReport {
+                    submitted: num_chunks,
+                    completed: latencies_ms.len(),
+                    wall_clock_secs: wall_clock.as_secs_f64(),
+                    offered_chunks_per_sec: rate,
+                    achieved_chunks_per_sec,
+                    latency_ms_min: sorted_latencies.first().copied().unwrap_or(0.0),
+                    latency_ms_mean: mean_latency,
+                    latency_ms_p50: percentile(0.50),
+                    latency_ms_p95: percentile(0.95),
+                    latency_ms_p99: percentile(0.99),
+                    latency_ms_max: sorted_latencies.last().copied().unwrap_or(0.0),
+                    backlog_avg: avg_backlog,
+                    backlog_peak: peak_backlog,
+                    idle_fraction,
+                    incomplete,
+                },
+            );
+            Ok(ExitStatus::Success)
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct PushFileResult {
+    session_id: String,
+    chunk_ids: Vec<Uuid>,
+}
+
+#[derive(Serialize)]
+struct ClearResult {
+    queue: String,
+    cleared: bool,
+}
+
+#[derive(Serialize)]
+struct DiscontinuitySample {
+    index: usize,
+    delta: f32,
+}
+
+/// An interim, not-yet-final hypothesis streamed from [`Commands::WaitResult`]
+/// while waiting for the matching `TranscriptUpdate::Final`.
+#[derive(Serialize)]
+struct PartialUpdate {
+    id: Uuid,
+    stability: f32,
+    text: String,
+}
+
+#[derive(Serialize)]
+struct TranscriptCheck {
+    text: String,
+    confidence: f32,
+    processing_time_ms: Option<u64>,
+    /// A pure tone carries no speech, so non-empty text suggests the
+    /// backend (or something upstream of it) introduced an artifact rather
+    /// than genuinely transcribing content.
+    likely_artifact: bool,
+}
+
+#[derive(Serialize)]
+struct DiscontinuityReport {
+    waveform: Waveform,
+    sample_count: usize,
+    threshold: f32,
+    discontinuities: Vec<DiscontinuitySample>,
+    transcript: Option<TranscriptCheck>,
+    timed_out: bool,
+}
+
+/// Throughput/latency/backlog/idle-fraction summary for [`Commands::Bench`].
+#[derive(Serialize)]
+struct BenchReport {
+    submitted: usize,
+    completed: usize,
+    wall_clock_secs: f64,
+    offered_chunks_per_sec: f64,
+    achieved_chunks_per_sec: f64,
+    latency_ms_min: f64,
+    latency_ms_mean: f64,
+    latency_ms_p50: f64,
+    latency_ms_p95: f64,
+    latency_ms_p99: f64,
+    latency_ms_max: f64,
+    backlog_avg: f64,
+    backlog_peak: usize,
+    /// Fraction of wall-clock time spent with the output queue empty — low
+    /// means the backend is the bottleneck, high means the queue/transcriber
+    /// coupling has headroom to spare.
+    idle_fraction: f64,
+    /// Chunks submitted but never observed on the output queue before the
+    /// drain timeout.
+    incomplete: usize,
+}
+
+/// Poll `path`'s queue for newly-arrived items (via the non-destructive
+/// [`SledQueue::iter`]) and print each exactly once, as NDJSON, until the
+/// process is interrupted. `id_of` extracts the identity used to recognize
+/// an item already printed, since `T` is generic here and the two queues
+/// this is called for (`AudioChunk` / `Result<TranscriptUpdate,
+/// TranscriptionError>`) carry their id differently.
+async fn watch_loop<T, F>(path: &PathBuf, id_of: F) -> Result<ExitStatus>
+where
+    T: Serialize + serde::de::DeserializeOwned + Clone + Send + Sync,
+    F: Fn(&T) -> Uuid,
+{
+    let queue = SledQueue::<T>::new(path)?;
+    let mut seen: HashSet<Uuid> = HashSet::new();
+
+    eprintln!("👀 Watching {} (Ctrl-C to stop)...", path.display());
+    loop {
+        for item in queue.iter().await? {
+            let id = id_of(&item);
+            if seen.insert(id) {
+                print_record(OutputFormat::Ndjson, &Envelope { ok: true, data: Some(item), error: None });
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+}
+
+/// Generate a short synthetic 440Hz chunk for [`Commands::Bench`], the same
+/// shape `TestPush` emits but kept local since bench pushes many of these in
+/// a loop rather than one per invocation.
+fn bench_test_chunk() -> AudioChunk {
+    let sample_rate = PIPELINE_SAMPLE_RATE;
+    let duration_secs = 1.0_f32;
+    let num_samples = (duration_secs * sample_rate as f32) as usize;
+    let audio: Vec<f32> = (0..num_samples)
+        .map(|i| {
+            let t = i as f32 / sample_rate as f32;
+            (2.0 * std::f32::consts::PI * 440.0 * t).sin() * 0.3
+        })
+        .collect();
+    AudioChunk::new(audio, sample_rate, 1)
+}
+
+/// Synthesizes `duration` seconds of `waveform` at `frequency`/`amplitude`,
+/// `sample_rate` samples/sec. Square and saw are band-limited with PolyBLEP
+/// at their transitions rather than generated as naive clipped/sawtooth
+/// waveforms, which would otherwise introduce a true sample-to-sample
+/// discontinuity every cycle.
+fn generate_waveform(waveform: Waveform, duration: f32, frequency: f32, amplitude: f32, sample_rate: u32) -> Vec<f32> {
+    let num_samples = (duration * sample_rate as f32).max(0.0) as usize;
+    let mut rng_state: u64 = 0x9E3779B97F4A7C15;
+    let mut pink_rows = [0.0f32; 16];
+    let mut pink_counter: u64 = 0;
+
+    let mut next_white_unit = move || -> f32 {
+        rng_state = rng_state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = rng_state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^= z >> 31;
+        let unit = (z >> 40) as f32 / (1u64 << 24) as f32;
+        unit * 2.0 - 1.0
+    };
+
+    (0..num_samples)
+        .map(|i| {
+            let t = i as f32 / sample_rate as f32;
+            let phase = (frequency * t).fract();
+            match waveform {
+                Waveform::Sine => amplitude * (2.0 * std::f32::consts::PI * frequency * t).sin(),
+                Waveform::Square => {
+                    let naive = if phase < 0.5 { 1.0 } else { -1.0 };
+                    let dt = frequency / sample_rate as f32;
+                    amplitude * (naive + poly_blep(phase, dt) - poly_blep((phase + 0.5).fract(), dt))
+                }
+                Waveform::Saw => {
+                    let naive = 2.0 * phase - 1.0;
+                    let dt = frequency / sample_rate as f32;
+                    amplitude * (naive - poly_blep(phase, dt))
+                }
+                Waveform::Triangle => {
+                    // Triangle is already continuous (only its slope has a
+                    // kink), so no band-limiting is needed.
+                    amplitude * (4.0 * (phase - 0.5).abs() - 1.0)
+                }
+                Waveform::WhiteNoise => amplitude * next_white_unit(),
+                Waveform::PinkNoise => {
+                    pink_counter = pink_counter.wrapping_add(1);
+                    let num_rows = pink_rows.len();
+                    let row = (pink_counter.trailing_zeros() as usize).min(num_rows - 1);
+                    pink_rows[row] = next_white_unit();
+                    amplitude * (pink_rows.iter().sum::<f32>() / num_rows as f32)
+                }
+                Waveform::Silence => 0.0,
+            }
+        })
+        .collect()
+}
+
+/// PolyBLEP (polynomial band-limited step) correction, added at a
+/// discontinuous transition to round off the otherwise-infinite harmonic
+/// content of a naive square/saw into a couple of samples either side of
+/// the edge. `phase` is in `[0.0, 1.0)`, `dt` is the phase increment per
+/// sample (`frequency / sample_rate`).
+fn poly_blep(phase: f32, dt: f32) -> f32 {
+    if dt <= 0.0 {
+        return 0.0;
+    }
+    if phase < dt {
+        let t = phase / dt;
+        t + t - t * t - 1.0
+    } else if phase > 1.0 - dt {
+        let t = (phase - 1.0) / dt;
+        t * t + t + t + 1.0
+    } else {
+        0.0
+    }
+}
+
+/// Scans `samples` for sample-to-sample jumps larger than `threshold`,
+/// returning `(index, delta)` for each offending sample.
+fn find_discontinuities(samples: &[f32], threshold: f32) -> Vec<(usize, f32)> {
+    samples
+        .windows(2)
+        .enumerate()
+        .filter_map(|(i, pair)| {
+            let delta = (pair[1] - pair[0]).abs();
+            if delta > threshold {
+                Some((i + 1, delta))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Decode `path` (WAV/FLAC/MP3, whatever Symphonia's probe recognizes),
+/// downmix to mono, and resample to [`PIPELINE_SAMPLE_RATE`], mirroring the
+/// `audioconvert ! audioresample ! audio/x-raw,channels=1,rate=16000`
+/// GStreamer stage this pipeline models.
+fn decode_and_resample_to_pipeline_rate(path: &PathBuf) -> Result<Vec<f32>> {
+    let file = File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mss = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(&hint, mss, &FormatOptions::default(), &MetadataOptions::default())
+        .context("Failed to probe audio format")?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != CODEC_TYPE_NULL)
+        .context("No audio tracks found")?;
+    let track_id = track.id;
+
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .context("Failed to create decoder")?;
+
+    let source_sample_rate = track.codec_params.sample_rate.unwrap_or(PIPELINE_SAMPLE_RATE);
+    let channels = track.codec_params.channels.map(|c| c.count()).unwrap_or(1) as usize;
+
+    let mut mono_samples = Vec::new();
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(_)) => break,
+            Err(SymphoniaError::ResetRequired) => {
+                decoder.reset();
+                continue;
+            }
+            Err(err) => return Err(err).context("Decode error"),
+        };
+
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        match decoder.decode(&packet) {
+            Ok(decoded) => {
+                let samples = audio_buffer_to_f32(decoded);
+                if channels > 1 {
+                    mono_samples.extend(
+                        samples
+                            .chunks(channels)
+                            .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32),
+                    );
+                } else {
+                    mono_samples.extend(samples);
                 }
-                
-                tokio::time::sleep(std::time::Duration::from_millis(500)).await;
             }
+            Err(SymphoniaError::IoError(_)) => break,
+            Err(SymphoniaError::ResetRequired) => {
+                decoder.reset();
+                continue;
+            }
+            Err(err) => return Err(err).context("Decode error"),
         }
     }
-    
-    Ok(())
-}
\ No newline at end of file
+
+    Ok(resample_linear(&mono_samples, source_sample_rate, PIPELINE_SAMPLE_RATE))
+}
+
+/// Flatten a decoded audio buffer (whatever sample format the codec
+/// produced) to interleaved `f32` samples.
+fn audio_buffer_to_f32(buffer: AudioBufferRef) -> Vec<f32> {
+    let mut samples = Vec::new();
+    match buffer {
+        AudioBufferRef::F32(buf) => {
+            for plane in buf.planes().planes() {
+                samples.extend_from_slice(plane);
+            }
+        }
+        AudioBufferRef::F64(buf) => {
+            for plane in buf.planes().planes() {
+                samples.extend(plane.iter().map(|&s| s as f32));
+            }
+        }
+        AudioBufferRef::S32(buf) => {
+            for plane in buf.planes().planes() {
+                samples.extend(plane.iter().map(|&s| s as f32 / i32::MAX as f32));
+            }
+        }
+        AudioBufferRef::S16(buf) => {
+            for plane in buf.planes().planes() {
+                samples.extend(plane.iter().map(|&s| s as f32 / i16::MAX as f32));
+            }
+        }
+        _ => {}
+    }
+    samples
+}
+
+/// Linear-interpolation resampler; good enough for re-pitching a CLI test
+/// fixture and consistent with the resampler `AudioConverter::resample` in
+/// the main app uses for the same job.
+fn resample_linear(samples: &[f32], src_rate: u32, dst_rate: u32) -> Vec<f32> {
+    if src_rate == dst_rate || samples.is_empty() {
+        return samples.to_vec();
+    }
+
+    let ratio = dst_rate as f32 / src_rate as f32;
+    let new_len = (samples.len() as f32 * ratio) as usize;
+    let mut resampled = Vec::with_capacity(new_len);
+
+    for i in 0..new_len {
+        let src_idx = i as f32 / ratio;
+        let idx = src_idx as usize;
+        let frac = src_idx - idx as f32;
+
+        let sample = if idx + 1 < samples.len() {
+            samples[idx] * (1.0 - frac) + samples[idx + 1] * frac
+        } else {
+            samples.last().copied().unwrap_or(0.0)
+        };
+        resampled.push(sample);
+    }
+
+    resampled
+}
@@ -4,18 +4,20 @@ use transcriber::{
     protocol::{AudioChunk, Transcript, TranscriptionError},
     queue::{Queue, SledQueue},
     tracker::{MessageTracker, MessageTrackerStats},
-    worker::{WorkerConfig, WorkerPool},
+    worker::{WorkerConfig, WorkerPool, WorkerSnapshot, WorkerState},
 };
 
 #[cfg(feature = "zeromq-queue")]
 use transcriber::queue::{ZmqQueue, ZmqQueueConfig};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::signal;
 use tokio::sync::broadcast;
-use tokio::time::interval;
+use tokio::time::{interval, sleep};
+use serde::{Deserialize, Serialize};
 use tracing::{debug, error, info, warn};
 
 #[derive(Parser)]
@@ -67,6 +69,10 @@ pub struct Args {
     #[arg(long, default_value = "30")]
     pub response_timeout: u64,
 
+    /// Seconds a worker may stay continuously unhealthy before it is restarted
+    #[arg(long, default_value = "60")]
+    pub unhealthy_timeout: u64,
+
     /// Queue processing interval in milliseconds
     #[arg(long, default_value = "100")]
     pub poll_interval: u64,
@@ -102,6 +108,31 @@ pub struct Args {
     /// PID file location (only used with --daemon)
     #[arg(long, default_value = "/tmp/transcriber.pid")]
     pub pid_file: String,
+
+    /// Tranquility ratio T: after each batch the dispatcher sleeps for
+    /// `T * processing_time`, so T=0 runs flat out and T=2 idles two-thirds of
+    /// wall-clock. Overridden by a persisted value from a previous run.
+    #[arg(long, default_value = "0.0")]
+    pub tranquility: f64,
+}
+
+/// File used to persist the current tranquility ratio across restarts.
+const TRANQUILITY_STATE_FILE: &str = "/tmp/transcriber.tranquility";
+
+/// Load the persisted tranquility ratio, falling back to `default`.
+fn load_tranquility(default: f64) -> f64 {
+    std::fs::read_to_string(TRANQUILITY_STATE_FILE)
+        .ok()
+        .and_then(|s| s.trim().parse::<f64>().ok())
+        .map(|t| t.max(0.0))
+        .unwrap_or_else(|| default.max(0.0))
+}
+
+/// Persist the tranquility ratio so it survives a restart.
+fn persist_tranquility(value: f64) {
+    if let Err(e) = std::fs::write(TRANQUILITY_STATE_FILE, value.to_string()) {
+        warn!("Failed to persist tranquility ratio: {}", e);
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
@@ -125,6 +156,179 @@ impl From<LogLevel> for tracing::Level {
     }
 }
 
+/// An operator command issued against a running service to introspect or
+/// steer the worker pool at runtime. Carried over the ZeroMQ control endpoint
+/// as MessagePack, or dispatched directly in non-ZeroMQ mode.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case", tag = "command")]
+pub enum ControlCommand {
+    /// List every worker with its state, in-flight chunk, and heartbeat age.
+    List,
+    /// Stop handing a worker new chunks until resumed.
+    Pause { worker_id: String },
+    /// Resume a paused or drained worker.
+    Resume { worker_id: String },
+    /// Let a worker finish its current chunk, then park it.
+    Drain { worker_id: String },
+    /// Set the tranquility throttle ratio at runtime.
+    SetTranquility { value: f64 },
+}
+
+/// The service's reply to a [`ControlCommand`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "response")]
+pub enum ControlResponse {
+    /// Snapshot of all workers, in reply to [`ControlCommand::List`].
+    Workers { workers: Vec<WorkerSnapshot> },
+    /// The command was applied successfully.
+    Ok,
+    /// The command could not be applied (e.g. unknown worker id).
+    Error { message: String },
+}
+
+impl ControlResponse {
+    /// Convenience constructor for an error reply.
+    pub fn error(message: impl Into<String>) -> Self {
+        ControlResponse::Error {
+            message: message.into(),
+        }
+    }
+}
+
+/// A fixed-precision, logarithmically-bucketed latency histogram.
+///
+/// This is a compact HDR-style histogram: values are placed into a bucket
+/// chosen by their magnitude (a power-of-two range) and a linear sub-bucket
+/// within it, giving roughly `significant_figures` decimal digits of
+/// resolution across the whole `[1, highest]` range. Recording is O(1) and a
+/// percentile query walks the buckets until the cumulative count crosses the
+/// requested quantile. Values are milliseconds.
+pub struct LatencyHistogram {
+    sub_bucket_count: u64,
+    sub_bucket_half_count: u64,
+    sub_bucket_mask: u64,
+    sub_bucket_count_magnitude: u32,
+    counts: Vec<u64>,
+    total: u64,
+    max_recorded: u64,
+}
+
+impl LatencyHistogram {
+    /// Create a histogram covering `[1, highest]` ms at `sig_figures` digits.
+    pub fn new(highest: u64, sig_figures: u32) -> Self {
+        let largest_single_unit = 2 * 10u64.pow(sig_figures);
+        let sub_bucket_count_magnitude =
+            (largest_single_unit as f64).log2().ceil() as u32;
+        let sub_bucket_count = 1u64 << sub_bucket_count_magnitude;
+        let sub_bucket_half_count = sub_bucket_count / 2;
+        let sub_bucket_mask = sub_bucket_count - 1;
+
+        // Grow buckets until `highest` is representable.
+        let mut bucket_count = 1u64;
+        let mut smallest_untrackable = sub_bucket_count;
+        while smallest_untrackable < highest {
+            smallest_untrackable <<= 1;
+            bucket_count += 1;
+        }
+
+        let len = ((bucket_count + 1) * sub_bucket_half_count) as usize;
+        Self {
+            sub_bucket_count,
+            sub_bucket_half_count,
+            sub_bucket_mask,
+            sub_bucket_count_magnitude,
+            counts: vec![0; len],
+            total: 0,
+            max_recorded: 0,
+        }
+    }
+
+    fn bucket_index(&self, value: u64) -> u32 {
+        let ored = value | self.sub_bucket_mask;
+        (63 - ored.leading_zeros()) - self.sub_bucket_count_magnitude
+    }
+
+    fn counts_index(&self, value: u64) -> usize {
+        let bucket_index = self.bucket_index(value);
+        let sub_bucket_index = value >> bucket_index;
+        let bucket_base = (bucket_index as u64 + 1) * self.sub_bucket_half_count;
+        (bucket_base + sub_bucket_index - self.sub_bucket_half_count) as usize
+    }
+
+    /// Lowest value that would be recorded into the same bucket as `index`.
+    fn value_at_index(&self, index: usize) -> u64 {
+        let index = index as u64;
+        let mut bucket_index = (index >> self.sub_bucket_count_magnitude.saturating_sub(1)) as i64 - 1;
+        let mut sub_bucket_index =
+            (index & (self.sub_bucket_half_count - 1)) + self.sub_bucket_half_count;
+        if bucket_index < 0 {
+            sub_bucket_index -= self.sub_bucket_half_count;
+            bucket_index = 0;
+        }
+        sub_bucket_index << bucket_index
+    }
+
+    /// Record a single millisecond value.
+    pub fn record(&mut self, value: u64) {
+        let value = value.max(1);
+        let idx = self.counts_index(value);
+        if idx < self.counts.len() {
+            self.counts[idx] += 1;
+            self.total += 1;
+            self.max_recorded = self.max_recorded.max(value);
+        }
+    }
+
+    /// Fold another histogram of identical shape into this one.
+    pub fn merge(&mut self, other: &LatencyHistogram) {
+        for (slot, count) in self.counts.iter_mut().zip(other.counts.iter()) {
+            *slot += count;
+        }
+        self.total += other.total;
+        self.max_recorded = self.max_recorded.max(other.max_recorded);
+    }
+
+    /// Value at the given percentile (0.0..=100.0), in milliseconds.
+    pub fn value_at_percentile(&self, percentile: f64) -> u64 {
+        if self.total == 0 {
+            return 0;
+        }
+        let target = ((percentile / 100.0 * self.total as f64).ceil() as u64).max(1);
+        let mut cumulative = 0u64;
+        for (index, count) in self.counts.iter().enumerate() {
+            cumulative += count;
+            if cumulative >= target {
+                return self.value_at_index(index);
+            }
+        }
+        self.max_recorded
+    }
+
+    /// Largest value recorded.
+    pub fn max(&self) -> u64 {
+        self.max_recorded
+    }
+
+    /// Number of recorded values.
+    pub fn len(&self) -> u64 {
+        self.total
+    }
+
+    /// Whether any value has been recorded in the current window.
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+
+    /// Clear all counts, starting a fresh rolling window.
+    pub fn reset(&mut self) {
+        for slot in self.counts.iter_mut() {
+            *slot = 0;
+        }
+        self.total = 0;
+        self.max_recorded = 0;
+    }
+}
+
 /// Queue type enumeration to support different queue implementations
 #[derive(Clone)]
 pub enum QueueType<T> {
@@ -209,6 +413,11 @@ pub struct TranscriptionService {
     message_tracker: Arc<MessageTracker>,
     running: Arc<AtomicBool>,
     shutdown_tx: broadcast::Sender<()>,
+    /// Tranquility ratio (f64 bits) shared with the dispatch loop; settable at
+    /// runtime via the control channel and persisted across restarts.
+    tranquility: Arc<AtomicU64>,
+    /// Aggregate end-to-end latency histogram folded across all workers.
+    latencies: Arc<Mutex<LatencyHistogram>>,
     args: Args,
 }
 
@@ -324,6 +533,9 @@ impl TranscriptionService {
 
         let (shutdown_tx, _) = broadcast::channel(1);
 
+        // A persisted ratio from a previous run wins over the command-line default.
+        let tranquility = load_tranquility(args.tranquility);
+
         Ok(Self {
             input_queue,
             output_queue,
@@ -331,6 +543,9 @@ impl TranscriptionService {
             message_tracker,
             running: Arc::new(AtomicBool::new(false)),
             shutdown_tx,
+            tranquility: Arc::new(AtomicU64::new(tranquility.to_bits())),
+            // 1ms..120s at 3 significant digits.
+            latencies: Arc::new(Mutex::new(LatencyHistogram::new(120_000, 3))),
             args,
         })
     }
@@ -372,7 +587,15 @@ impl TranscriptionService {
 
         // Start health monitoring
         let health_handle = self.spawn_health_monitor();
-        
+
+        // Start the result consumer that drains worker replies back onto the
+        // output queue (stdin/stdout mode only; ZeroMQ workers publish directly).
+        let result_handle = if !self.args.use_zeromq {
+            Some(self.spawn_result_consumer())
+        } else {
+            None
+        };
+
         // Start control plane receiver for ZeroMQ mode
         let control_plane_handle = if self.args.use_zeromq {
             Some(self.spawn_control_plane_receiver())
@@ -380,6 +603,15 @@ impl TranscriptionService {
             None
         };
 
+        // Start the operator command responder (ZeroMQ mode only; non-ZMQ
+        // callers drive the pool through `handle_control_command` directly).
+        #[cfg(feature = "zeromq-queue")]
+        let command_handle = if self.args.use_zeromq {
+            Some(self.spawn_control_command_responder())
+        } else {
+            None
+        };
+
         info!("Transcription service started successfully");
 
         // Wait for shutdown signal
@@ -405,9 +637,16 @@ impl TranscriptionService {
         processing_handle.abort();
         stats_handle.abort();
         health_handle.abort();
+        if let Some(handle) = result_handle {
+            handle.abort();
+        }
         if let Some(handle) = control_plane_handle {
             handle.abort();
         }
+        #[cfg(feature = "zeromq-queue")]
+        if let Some(handle) = command_handle {
+            handle.abort();
+        }
 
         // Stop worker pool only if not using ZeroMQ
         if !self.args.use_zeromq {
@@ -448,6 +687,7 @@ impl TranscriptionService {
         let mut shutdown_rx = self.shutdown_tx.subscribe();
         let poll_interval = Duration::from_millis(self.args.poll_interval);
         let use_zeromq = self.args.use_zeromq;
+        let tranquility = Arc::clone(&self.tranquility);
 
         tokio::spawn(async move {
             let mut interval = interval(poll_interval);
@@ -460,12 +700,21 @@ impl TranscriptionService {
                     break;
                 }
 
+                let work_start = Instant::now();
+
                 if use_zeromq {
                     // In ZeroMQ mode, monitor messages and track their lifecycle
                     Self::monitor_zeromq_queues(&input_queue, &output_queue, &message_tracker).await;
                 } else {
                     // Process input queue for stdin/stdout workers
-                    match Self::process_input_queue(&input_queue, &worker_pool).await {
+                    match Self::process_input_queue(
+                        &input_queue,
+                        &output_queue,
+                        &worker_pool,
+                        &message_tracker,
+                    )
+                    .await
+                    {
                         Ok(processed) => {
                             if processed > 0 {
                                 debug!("Processed {} items from input queue", processed);
@@ -475,12 +724,24 @@ impl TranscriptionService {
                             error!("Error processing input queue: {}", e);
                         }
                     }
+
+                    // Fail fast on anything that never came back within the timeout.
+                    Self::sweep_timeouts(&output_queue, &message_tracker).await;
                 }
 
-                // Small delay to prevent busy waiting
                 if !running.load(Ordering::Relaxed) {
                     break;
                 }
+
+                // Cooperative throttle: sleep in proportion to the work the
+                // batch just did, leaving CPU headroom for the foreground UI.
+                let t = f64::from_bits(tranquility.load(Ordering::Relaxed));
+                if t > 0.0 {
+                    let nap = work_start.elapsed().mul_f64(t);
+                    if !nap.is_zero() {
+                        sleep(nap).await;
+                    }
+                }
             }
 
             info!("Queue processing loop ended");
@@ -561,6 +822,113 @@ impl TranscriptionService {
         tokio::spawn(async {})
     }
 
+    /// Apply a control command against the worker pool and build the reply.
+    ///
+    /// This is the single entry point for both the ZeroMQ responder and any
+    /// in-process (non-ZMQ) caller, so the two modes stay behaviorally
+    /// identical.
+    pub async fn handle_control_command(&self, command: ControlCommand) -> ControlResponse {
+        Self::apply_control_command(&self.worker_pool, &self.tranquility, command).await
+    }
+
+    /// Bind a REP socket on the control endpoint and answer operator commands.
+    #[cfg(feature = "zeromq-queue")]
+    fn spawn_control_command_responder(&self) -> tokio::task::JoinHandle<()> {
+        use ::zeromq::{RepSocket, Socket, SocketRecv, SocketSend};
+
+        let worker_pool = self.worker_pool.clone();
+        let tranquility = Arc::clone(&self.tranquility);
+        let endpoint = self.args.zmq_control_endpoint.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        tokio::spawn(async move {
+            let mut socket = RepSocket::new();
+            if let Err(e) = socket.bind(&endpoint).await {
+                error!("Failed to bind control command endpoint {}: {}", endpoint, e);
+                return;
+            }
+            info!("Control command responder listening on {}", endpoint);
+
+            loop {
+                let message = tokio::select! {
+                    _ = shutdown_rx.recv() => break,
+                    msg = socket.recv() => msg,
+                };
+
+                let request = match message {
+                    Ok(msg) => msg,
+                    Err(e) => {
+                        warn!("Control command receive error: {}", e);
+                        continue;
+                    }
+                };
+
+                let response = match request.get(0) {
+                    Some(bytes) => match rmp_serde::from_slice::<ControlCommand>(bytes) {
+                        Ok(command) => {
+                            Self::apply_control_command(&worker_pool, &tranquility, command).await
+                        }
+                        Err(e) => ControlResponse::error(format!("Invalid command: {}", e)),
+                    },
+                    None => ControlResponse::error("Empty control request"),
+                };
+
+                match rmp_serde::to_vec_named(&response) {
+                    Ok(bytes) => {
+                        if let Err(e) = socket.send(bytes.into()).await {
+                            warn!("Failed to send control response: {}", e);
+                        }
+                    }
+                    Err(e) => error!("Failed to encode control response: {}", e),
+                }
+            }
+
+            debug!("Control command responder stopped");
+        })
+    }
+
+    /// Apply a single command against the pool (shared by the responder and
+    /// the in-process [`Self::handle_control_command`] entry point).
+    async fn apply_control_command(
+        worker_pool: &WorkerPool,
+        tranquility: &Arc<AtomicU64>,
+        command: ControlCommand,
+    ) -> ControlResponse {
+        match command {
+            ControlCommand::List => ControlResponse::Workers {
+                workers: worker_pool.list().await,
+            },
+            ControlCommand::SetTranquility { value } => {
+                let value = value.max(0.0);
+                tranquility.store(value.to_bits(), Ordering::Relaxed);
+                persist_tranquility(value);
+                info!("Tranquility ratio set to {}", value);
+                ControlResponse::Ok
+            }
+            ControlCommand::Pause { worker_id } => {
+                if worker_pool.pause(&worker_id).await {
+                    ControlResponse::Ok
+                } else {
+                    ControlResponse::error(format!("Unknown worker: {}", worker_id))
+                }
+            }
+            ControlCommand::Resume { worker_id } => {
+                if worker_pool.resume(&worker_id).await {
+                    ControlResponse::Ok
+                } else {
+                    ControlResponse::error(format!("Unknown worker: {}", worker_id))
+                }
+            }
+            ControlCommand::Drain { worker_id } => {
+                if worker_pool.drain(&worker_id).await {
+                    ControlResponse::Ok
+                } else {
+                    ControlResponse::error(format!("Unknown worker: {}", worker_id))
+                }
+            }
+        }
+    }
+
     /// Spawn ZeroMQ workers that connect directly to the queues
     async fn spawn_zeromq_workers(&self) -> Result<()> {
         use tokio::process::Command;
@@ -654,31 +1022,55 @@ impl TranscriptionService {
     }
 
     /// Process items from the input queue
+    ///
+    /// Each chunk is assigned its `AudioChunk` id as the correlation key and
+    /// registered with the [`MessageTracker`] (recording the submit timestamp)
+    /// before being handed to the pool. Replies are reunited with their id by
+    /// [`Self::spawn_result_consumer`]; a dispatch failure here is terminal, so
+    /// the error is pushed straight onto the output queue.
     async fn process_input_queue(
         input_queue: &QueueType<AudioChunk>,
+        output_queue: &QueueType<Result<Transcript, TranscriptionError>>,
         worker_pool: &WorkerPool,
+        message_tracker: &Arc<MessageTracker>,
     ) -> Result<usize> {
         let mut processed = 0;
 
         // Process up to 10 items at a time to avoid blocking
         for _ in 0..10 {
             if let Some(audio_chunk) = input_queue.pop().await? {
-                debug!("Processing audio chunk {} (duration: {:.2}s)", 
-                       audio_chunk.id, audio_chunk.duration());
+                let id = audio_chunk.id;
+                debug!("Processing audio chunk {} (duration: {:.2}s)",
+                       id, audio_chunk.duration());
+
+                // Register the chunk so its reply can be correlated and so the
+                // timeout sweep can account for anything that never returns.
+                let size_bytes = std::mem::size_of::<f32>() * audio_chunk.audio.len();
+                if let Err(e) = message_tracker.track_message(id, size_bytes).await {
+                    warn!("Failed to track message {}: {}", id, e);
+                }
 
                 // Send to worker pool
                 if let Err(e) = worker_pool.transcribe(audio_chunk.clone()).await {
-                    error!("Failed to send audio chunk {} to workers: {}", audio_chunk.id, e);
-                    
-                    // Create error result and push to output queue
-                    let _error = TranscriptionError::new(
-                        audio_chunk.id,
+                    error!("Failed to send audio chunk {} to workers: {}", id, e);
+
+                    // Surface the dispatch failure to consumers instead of dropping it.
+                    let error = TranscriptionError::new(
+                        id,
                         format!("Worker processing failed: {}", e),
                         "WORKER_ERROR".to_string(),
                     );
-                    
-                    // Note: In a real implementation, you'd need to handle worker responses
-                    // and push results to the output queue. This is a simplified version.
+                    if let Err(push_err) = output_queue.push(&Err(error)).await {
+                        error!("Failed to push error result for {}: {}", id, push_err);
+                    }
+                    let _ = message_tracker
+                        .mark_failed(id, "dispatch".to_string(), e.to_string())
+                        .await;
+                } else {
+                    // Mark in-flight so the timeout sweep can reason about it.
+                    let _ = message_tracker
+                        .assign_to_worker(id, "pool".to_string())
+                        .await;
                 }
 
                 processed += 1;
@@ -691,6 +1083,89 @@ impl TranscriptionService {
         Ok(processed)
     }
 
+    /// Drain worker replies and reunite them with their originating chunk.
+    ///
+    /// Each reply (a `Transcript` on success, a `TranscriptionError` on failure)
+    /// carries the original `AudioChunk` id, so it is pushed onto the output
+    /// queue keyed by that id and the tracker is updated accordingly.
+    fn spawn_result_consumer(&self) -> tokio::task::JoinHandle<()> {
+        let output_queue = self.output_queue.clone();
+        let worker_pool = self.worker_pool.clone();
+        let message_tracker = Arc::clone(&self.message_tracker);
+        let latencies = Arc::clone(&self.latencies);
+        let running = Arc::clone(&self.running);
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        tokio::spawn(async move {
+            let mut results = match worker_pool.take_results() {
+                Some(rx) => rx,
+                None => {
+                    error!("Result receiver already taken; consumer not started");
+                    return;
+                }
+            };
+
+            while running.load(Ordering::Relaxed) {
+                tokio::select! {
+                    _ = shutdown_rx.recv() => break,
+                    reply = results.recv() => {
+                        let Some(reply) = reply else { break };
+                        let id = match &reply {
+                            Ok(transcript) => transcript.id,
+                            Err(error) => error.id,
+                        };
+                        match &reply {
+                            Ok(_) => {
+                                let _ = message_tracker
+                                    .mark_completed(id, "pool".to_string())
+                                    .await;
+                                // Fold the end-to-end latency into the aggregate.
+                                if let Some(d) = message_tracker.processing_duration(id).await {
+                                    if let Ok(mut hist) = latencies.lock() {
+                                        hist.record(d.num_milliseconds().max(0) as u64);
+                                    }
+                                }
+                            }
+                            Err(error) => {
+                                let _ = message_tracker
+                                    .mark_failed(id, "pool".to_string(), error.message.clone())
+                                    .await;
+                            }
+                        }
+                        if let Err(e) = output_queue.push(&reply).await {
+                            error!("Failed to push result for {}: {}", id, e);
+                        }
+                    }
+                }
+            }
+
+            info!("Result consumer ended");
+        })
+    }
+
+    /// Synthesize a `TIMEOUT` error for any in-flight chunk that never returned.
+    async fn sweep_timeouts(
+        output_queue: &QueueType<Result<Transcript, TranscriptionError>>,
+        message_tracker: &Arc<MessageTracker>,
+    ) {
+        for id in message_tracker.check_timeouts().await {
+            match message_tracker.handle_timeout(id).await {
+                Ok(true) => debug!("Message {} timed out and will be retried", id),
+                Ok(false) => {
+                    let error = TranscriptionError::new(
+                        id,
+                        "No transcription result received before the response timeout".to_string(),
+                        "TIMEOUT".to_string(),
+                    );
+                    if let Err(e) = output_queue.push(&Err(error)).await {
+                        error!("Failed to push timeout result for {}: {}", id, e);
+                    }
+                }
+                Err(e) => error!("Failed to handle timeout for {}: {}", id, e),
+            }
+        }
+    }
+
     /// Spawn the statistics reporter
     fn spawn_stats_reporter(&self) -> tokio::task::JoinHandle<()> {
         let worker_pool = self.worker_pool.clone();
@@ -698,6 +1173,8 @@ impl TranscriptionService {
         let output_queue = self.output_queue.clone();
         let message_tracker = Arc::clone(&self.message_tracker);
         let running = Arc::clone(&self.running);
+        let tranquility = Arc::clone(&self.tranquility);
+        let latencies = Arc::clone(&self.latencies);
         let mut shutdown_rx = self.shutdown_tx.subscribe();
         let use_zeromq = self.args.use_zeromq;
 
@@ -719,7 +1196,8 @@ impl TranscriptionService {
                     info!("ZeroMQ tracker stats: {}", tracker_stats);
                 }
                 
-                match Self::gather_stats(&input_queue, &output_queue, &worker_pool).await {
+                let t = f64::from_bits(tranquility.load(Ordering::Relaxed));
+                match Self::gather_stats(&input_queue, &output_queue, &worker_pool, t).await {
                     Ok(stats) => {
                         info!("Service stats: {}", stats);
                     }
@@ -727,6 +1205,21 @@ impl TranscriptionService {
                         error!("Failed to gather statistics: {}", e);
                     }
                 }
+
+                // Emit recent latency percentiles, then reset the rolling window.
+                if let Ok(mut hist) = latencies.lock() {
+                    if !hist.is_empty() {
+                        info!(
+                            "Latency (ms): p50={}, p90={}, p99={}, max={} (n={})",
+                            hist.value_at_percentile(50.0),
+                            hist.value_at_percentile(90.0),
+                            hist.value_at_percentile(99.0),
+                            hist.max(),
+                            hist.len()
+                        );
+                        hist.reset();
+                    }
+                }
             }
 
             info!("Statistics reporter ended");
@@ -738,31 +1231,59 @@ impl TranscriptionService {
         input_queue: &QueueType<AudioChunk>,
         output_queue: &QueueType<Result<Transcript, TranscriptionError>>,
         worker_pool: &WorkerPool,
+        tranquility: f64,
     ) -> Result<String> {
         let input_len = input_queue.len().await?;
         let output_len = output_queue.len().await?;
         let worker_stats = worker_pool.get_stats().await;
-        
+
         let total_requests: u64 = worker_stats.iter().map(|s| s.total_requests).sum();
         let successful_requests: u64 = worker_stats.iter().map(|s| s.successful_requests).sum();
         let failed_requests: u64 = worker_stats.iter().map(|s| s.failed_requests).sum();
-        
+
+        // Per-state worker counts from the control-plane state machine.
+        let (mut active, mut idle, mut draining, mut dead) = (0, 0, 0, 0);
+        for snapshot in worker_pool.list().await {
+            match snapshot.state {
+                WorkerState::Active => active += 1,
+                WorkerState::Idle => idle += 1,
+                WorkerState::Draining => draining += 1,
+                WorkerState::Dead => dead += 1,
+            }
+        }
+
         Ok(format!(
-            "input_queue={}, output_queue={}, total_requests={}, successful={}, failed={}, workers={}",
-            input_len, output_len, total_requests, successful_requests, failed_requests, worker_stats.len()
+            "input_queue={}, output_queue={}, total_requests={}, successful={}, failed={}, \
+             workers={}, active={}, idle={}, draining={}, dead={}, tranquility={:.2}",
+            input_len, output_len, total_requests, successful_requests, failed_requests,
+            worker_stats.len(), active, idle, draining, dead, tranquility
         ))
     }
 
     /// Spawn the health monitor
+    ///
+    /// Besides logging, this enforces an auto-healing policy modeled on a
+    /// container supervisor: a worker that stays unhealthy for longer than
+    /// `unhealthy_timeout` is restarted, restarts are capped at `max_restarts`
+    /// within a sliding window, and a worker that trips the cap is marked dead
+    /// and removed from rotation so a crash-looping subprocess can't starve the
+    /// rest of the pool.
     fn spawn_health_monitor(&self) -> tokio::task::JoinHandle<()> {
         let worker_pool = self.worker_pool.clone();
         let running = Arc::clone(&self.running);
         let mut shutdown_rx = self.shutdown_tx.subscribe();
         let use_zeromq = self.args.use_zeromq;
+        let unhealthy_timeout = Duration::from_secs(self.args.unhealthy_timeout);
+        let max_restarts = self.args.max_restarts;
 
         tokio::spawn(async move {
             let mut interval = interval(Duration::from_secs(30)); // Check every 30 seconds
 
+            // Per-worker auto-healing bookkeeping.
+            let mut unhealthy_since: HashMap<String, Instant> = HashMap::new();
+            let mut restart_history: HashMap<String, VecDeque<Instant>> = HashMap::new();
+            let mut dead: HashSet<String> = HashSet::new();
+
             while running.load(Ordering::Relaxed) {
                 interval.tick().await;
 
@@ -773,15 +1294,57 @@ impl TranscriptionService {
 
                 // Only check worker health for non-ZeroMQ mode
                 // In ZeroMQ mode, workers are managed independently and report via control plane
-                if !use_zeromq {
-                    // Check worker health
-                    let health_statuses = worker_pool.get_health().await;
-                    for status in health_statuses {
-                        if !status.healthy {
-                            warn!("Worker {} is unhealthy", status.worker_id);
-                        } else {
-                            debug!("Worker {} is healthy", status.worker_id);
-                        }
+                if use_zeromq {
+                    continue;
+                }
+
+                let health_statuses = worker_pool.get_health().await;
+                for status in health_statuses {
+                    let id = status.worker_id;
+                    if dead.contains(&id) {
+                        continue;
+                    }
+
+                    if status.healthy {
+                        unhealthy_since.remove(&id);
+                        debug!("Worker {} is healthy", id);
+                        continue;
+                    }
+
+                    warn!("Worker {} is unhealthy", id);
+                    let first_seen = *unhealthy_since.entry(id.clone()).or_insert_with(Instant::now);
+                    if first_seen.elapsed() < unhealthy_timeout {
+                        continue;
+                    }
+
+                    // Drop restarts that have aged out of the sliding window.
+                    let window = unhealthy_timeout * max_restarts.max(1);
+                    let history = restart_history.entry(id.clone()).or_default();
+                    while history.front().is_some_and(|t| t.elapsed() > window) {
+                        history.pop_front();
+                    }
+
+                    if history.len() as u32 >= max_restarts {
+                        error!(
+                            "Worker {} exceeded {} restarts in {:?}; marking permanently dead",
+                            id, max_restarts, window
+                        );
+                        worker_pool.mark_dead(&id).await;
+                        dead.insert(id.clone());
+                        unhealthy_since.remove(&id);
+                        continue;
+                    }
+
+                    history.push_back(Instant::now());
+                    unhealthy_since.remove(&id);
+                    match worker_pool.restart_worker(&id).await {
+                        Ok(()) => info!(
+                            "Restarted unhealthy worker {} ({} of {} in window)",
+                            id,
+                            history.len(),
+                            max_restarts
+                        ),
+                        Err(e) => error!("Failed to restart worker {}: {}", id, e),
                     }
                 }
             }
@@ -884,7 +1447,9 @@ mod tests {
             max_restarts: 5,
             heartbeat_interval: 10,
             response_timeout: 5,
+            unhealthy_timeout: 30,
             poll_interval: 50,
+            tranquility: 0.0,
             persistent_queues: false, // Use in-memory for tests
             #[cfg(feature = "zeromq-queue")]
             zmq_push_endpoint: "tcp://127.0.0.1:5555".to_string(),
@@ -1,9 +1,11 @@
 use anyhow::{Context, Result};
+use chrono::Utc;
 use serde::{de::DeserializeOwned, Serialize};
 use sled::{Db, Tree};
 use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
@@ -49,10 +51,107 @@ pub struct SledQueue<T> {
     db: Db,
     data_tree: Tree,
     index_tree: Tree,
+    /// Items currently leased out for at-least-once delivery, keyed by
+    /// sequence. Holds the payload, the active lease, and the redelivery
+    /// count, so a crashed worker's item can be reclaimed and redelivered.
+    inflight_tree: Tree,
+    /// Items that exceeded `max_deliveries` without being acked, keyed by
+    /// sequence, so they stop looping through `lease`/`reclaim_expired`.
+    dead_letter_tree: Tree,
+    /// Deliveries allowed before a nacked or expired lease is moved to the
+    /// dead-letter tree instead of being requeued.
+    max_deliveries: u32,
+    /// Monotonic sequence source. Must never go backwards or be reused once
+    /// assigned, even across different priorities: `index_tree` keys are
+    /// `(priority, sequence)` composites, and a repeated sequence at the same
+    /// priority would make two entries compare equal and scramble both pop
+    /// order and [`IndexedSledQueue::range`]'s sequence windows.
     counter: Arc<AtomicU64>,
     _phantom: std::marker::PhantomData<T>,
 }
 
+/// Default cap on redeliveries before an item is dead-lettered. Chosen to
+/// give a transient failure (worker restart, brief model OOM) a few retries
+/// without letting a poison message loop forever.
+const DEFAULT_MAX_DELIVERIES: u32 = 5;
+
+/// Priority assigned to items pushed via the plain [`Queue::push`]/
+/// [`SledQueue::push_batch`] paths. Sits in the middle of `u8`'s range so
+/// callers can use [`SledQueue::push_with_priority`] to jump items ahead
+/// (lower number) or behind (higher number) the default band.
+const DEFAULT_PRIORITY: u8 = 128;
+
+/// Build the `index_tree` key: a big-endian `(priority, sequence)` composite.
+/// Sled orders tree keys by byte comparison, so this sorts strictly by
+/// priority first (lower numbers pop first) and by sequence within a
+/// priority band (preserving FIFO), as long as the sequence counter stays
+/// monotonic across all priorities.
+fn index_key(priority: u8, seq: u64) -> [u8; 9] {
+    let mut key = [0u8; 9];
+    key[0] = priority;
+    key[1..].copy_from_slice(&seq.to_be_bytes());
+    key
+}
+
+/// Split an `index_tree` key back into its `(priority, sequence)` parts.
+fn decode_index_key(key: &[u8]) -> Result<(u8, u64)> {
+    if key.len() != 9 {
+        anyhow::bail!("Invalid index key length: expected 9 bytes, got {}", key.len());
+    }
+    let priority = key[0];
+    let seq = u64::from_be_bytes(
+        key[1..9].try_into().context("Invalid sequence number in index key")?,
+    );
+    Ok((priority, seq))
+}
+
+/// Outcome of resolving a lease via [`SledQueue::nack`] or reclaiming one via
+/// [`SledQueue::reclaim_expired`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedeliveryOutcome {
+    /// The item was returned to the front of the queue for another attempt.
+    Requeued,
+    /// The item exceeded `max_deliveries` and was moved to the dead-letter tree.
+    DeadLettered,
+    /// The token did not match an in-flight lease.
+    NotFound,
+}
+
+/// Handle to an item leased out via [`SledQueue::lease`]. Must be presented
+/// back to [`SledQueue::ack`] or [`SledQueue::nack`] to resolve the lease;
+/// the embedded `lease_id` is checked against the stored lease so a stale
+/// token (e.g. from a lease that already expired and was reclaimed) can't
+/// accidentally ack/nack a different delivery of the same item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeaseToken {
+    sequence: u64,
+    lease_id: Uuid,
+}
+
+/// On-disk representation of a leased-out item.
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+struct InflightEntry<T> {
+    data: T,
+    lease_id: Uuid,
+    /// Unix epoch milliseconds after which the lease is considered expired.
+    expires_at_ms: i64,
+    /// Number of times this item has been handed out via `lease`.
+    delivery_count: u32,
+    /// Priority the item was indexed under before it was leased, so it can
+    /// be reinserted at the same priority band on nack/reclaim.
+    priority: u8,
+}
+
+/// On-disk representation of a dead-lettered item.
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+struct DeadLetterEntry<T> {
+    data: T,
+    delivery_count: u32,
+    /// Priority the item was indexed under before it was dead-lettered, so
+    /// `requeue_dead_letter` can restore it to the same band.
+    priority: u8,
+}
+
 impl<T> SledQueue<T>
 where
     T: Serialize + DeserializeOwned + Clone + Send + Sync,
@@ -67,66 +166,97 @@ where
         
         let index_tree = db.open_tree("index")
             .context("Failed to open index tree")?;
-        
-        // Initialize counter from the highest existing sequence number
-        let counter = if let Some((key, _)) = index_tree.last()? {
+
+        let inflight_tree = db.open_tree("inflight")
+            .context("Failed to open inflight tree")?;
+
+        let dead_letter_tree = db.open_tree("dead_letter")
+            .context("Failed to open dead-letter tree")?;
+
+        // Initialize counter from the highest existing sequence number.
+        // `data_tree` stays plain-sequence-keyed even though `index_tree`'s
+        // keys are now `(priority, sequence)` composites, so it's the
+        // cheaper source of the true max (no need to decode every key to
+        // find the one with the highest sequence).
+        let counter = if let Some((key, _)) = data_tree.last()? {
             let seq = u64::from_be_bytes(
                 key.as_ref().try_into()
-                    .context("Invalid sequence number in index")?
+                    .context("Invalid sequence number in data tree")?
             );
             Arc::new(AtomicU64::new(seq + 1))
         } else {
             Arc::new(AtomicU64::new(0))
         };
-        
+
         info!("Initialized SledQueue at {}", path.as_ref().display());
-        
+
         Ok(Self {
             db,
             data_tree,
             index_tree,
+            inflight_tree,
+            dead_letter_tree,
+            max_deliveries: DEFAULT_MAX_DELIVERIES,
             counter,
             _phantom: std::marker::PhantomData,
         })
     }
-    
+
+    /// Override the number of deliveries allowed before an item is moved to
+    /// the dead-letter tree instead of being requeued.
+    pub fn with_max_deliveries(mut self, max_deliveries: u32) -> Self {
+        self.max_deliveries = max_deliveries;
+        self
+    }
+
     /// Create a new in-memory SledQueue (useful for testing)
     pub fn new_temp() -> Result<Self> {
         let db = sled::Config::new()
             .temporary(true)
             .open()
             .context("Failed to create temporary Sled database")?;
-        
+
         let data_tree = db.open_tree("data")
             .context("Failed to open data tree")?;
-        
+
         let index_tree = db.open_tree("index")
             .context("Failed to open index tree")?;
-        
+
+        let inflight_tree = db.open_tree("inflight")
+            .context("Failed to open inflight tree")?;
+
+        let dead_letter_tree = db.open_tree("dead_letter")
+            .context("Failed to open dead-letter tree")?;
+
         let counter = Arc::new(AtomicU64::new(0));
-        
+
         Ok(Self {
             db,
             data_tree,
             index_tree,
+            inflight_tree,
+            dead_letter_tree,
+            max_deliveries: DEFAULT_MAX_DELIVERIES,
             counter,
             _phantom: std::marker::PhantomData,
         })
     }
-    
+
     /// Get database statistics
     pub fn stats(&self) -> Result<QueueStats> {
         let data_size = self.data_tree.len();
         let index_size = self.index_tree.len();
         let db_size = self.db.size_on_disk()?;
-        
+        let dead_letter_entries = self.dead_letter_tree.len();
+
         Ok(QueueStats {
             items: data_size,
             index_entries: index_size,
             disk_size_bytes: db_size,
+            dead_letter_entries,
         })
     }
-    
+
     /// Flush all pending writes to disk
     pub async fn flush(&self) -> Result<()> {
         self.db.flush_async().await
@@ -138,6 +268,451 @@ where
     pub fn db(&self) -> &Db {
         &self.db
     }
+
+    /// Push an item under a specific priority band (lower numbers pop
+    /// first). [`Queue::push`] is equivalent to
+    /// `push_with_priority(item, DEFAULT_PRIORITY)`.
+    pub async fn push_with_priority(&self, item: &T, priority: u8) -> Result<()> {
+        let seq = self.counter.fetch_add(1, Ordering::SeqCst);
+        let data_tree = self.data_tree.clone();
+        let index_tree = self.index_tree.clone();
+        let item = item.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let data = rmp_serde::to_vec(&item).context("Failed to serialize item")?;
+            let seq_key = seq.to_be_bytes();
+            data_tree
+                .insert(&seq_key, data.as_slice())
+                .with_context(|| format!("Failed to insert item with sequence {}", seq))?;
+            index_tree
+                .insert(index_key(priority, seq).as_slice(), &seq_key)
+                .with_context(|| format!("Failed to index item with sequence {}", seq))?;
+            Ok::<(), anyhow::Error>(())
+        })
+        .await
+        .context("Queue push task panicked")??;
+
+        debug!("Pushed item with sequence {} at priority {}", seq, priority);
+        Ok(())
+    }
+
+    /// Push many items in a single atomic batch.
+    ///
+    /// All items and their sequence-index entries are applied via one
+    /// `sled::Batch`, so the write costs one transaction instead of one per
+    /// item, and the sequence counter stays contiguous even if the batch is
+    /// interrupted (either all items land or none do).
+    pub async fn push_batch(&self, items: &[T]) -> Result<()> {
+        if items.is_empty() {
+            return Ok(());
+        }
+
+        let first_seq = self.counter.fetch_add(items.len() as u64, Ordering::SeqCst);
+        let data_tree = self.data_tree.clone();
+        let index_tree = self.index_tree.clone();
+        let items = items.to_vec();
+
+        tokio::task::spawn_blocking(move || {
+            let mut data_batch = sled::Batch::default();
+            let mut index_batch = sled::Batch::default();
+
+            for (offset, item) in items.iter().enumerate() {
+                let seq = first_seq + offset as u64;
+                let seq_key = seq.to_be_bytes();
+                let data = rmp_serde::to_vec(item)
+                    .with_context(|| format!("Failed to serialize item with sequence {}", seq))?;
+                data_batch.insert(&seq_key, data);
+                index_batch.insert(index_key(DEFAULT_PRIORITY, seq).as_slice(), &seq_key);
+            }
+
+            data_tree
+                .apply_batch(data_batch)
+                .context("Failed to apply data batch")?;
+            index_tree
+                .apply_batch(index_batch)
+                .context("Failed to apply index batch")?;
+
+            Ok::<(), anyhow::Error>(())
+        })
+        .await
+        .context("Queue push_batch task panicked")??;
+
+        debug!("Pushed batch of {} items starting at sequence {}", items.len(), first_seq);
+        Ok(())
+    }
+
+    /// Pop up to `max` items in FIFO order, removing them from both trees in
+    /// a single atomic batch.
+    pub async fn pop_batch(&self, max: usize) -> Result<Vec<T>> {
+        if max == 0 {
+            return Ok(Vec::new());
+        }
+
+        let data_tree = self.data_tree.clone();
+        let index_tree = self.index_tree.clone();
+
+        tokio::task::spawn_blocking(move || {
+            // Index keys are no longer data-tree keys themselves (they're
+            // `(priority, sequence)` composites), so take both halves of
+            // each entry: the index key to remove from `index_tree`, and its
+            // value (the plain sequence key) to look up and remove from
+            // `data_tree`.
+            let entries: Vec<(sled::IVec, sled::IVec)> = index_tree
+                .iter()
+                .take(max)
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .context("Failed to scan index tree")?;
+
+            if entries.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let mut index_batch = sled::Batch::default();
+            let mut data_batch = sled::Batch::default();
+            for (index_key, seq_key) in &entries {
+                index_batch.remove(index_key.clone());
+                data_batch.remove(seq_key.clone());
+            }
+
+            let mut items = Vec::with_capacity(entries.len());
+            for (_, seq_key) in &entries {
+                if let Some(data) = data_tree.get(seq_key)? {
+                    items.push(
+                        rmp_serde::from_slice(&data).context("Failed to deserialize popped item")?,
+                    );
+                }
+            }
+
+            index_tree
+                .apply_batch(index_batch)
+                .context("Failed to remove index batch")?;
+            data_tree
+                .apply_batch(data_batch)
+                .context("Failed to remove data batch")?;
+
+            Ok(items)
+        })
+        .await
+        .context("Queue pop_batch task panicked")?
+    }
+
+    /// Non-destructively look at up to `max` pending items in delivery order
+    /// (lowest priority, then earliest sequence), by scanning `index_tree`
+    /// and resolving each entry against `data_tree` — the same traversal
+    /// `pop_batch` uses, without removing anything.
+    pub async fn peek(&self, max: usize) -> Result<Vec<T>> {
+        if max == 0 {
+            return Ok(Vec::new());
+        }
+
+        let data_tree = self.data_tree.clone();
+        let index_tree = self.index_tree.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut out = Vec::new();
+            for result in index_tree.iter().take(max) {
+                let (_, seq_key) = result?;
+                if let Some(data) = data_tree.get(&seq_key)? {
+                    out.push(
+                        rmp_serde::from_slice(&data).context("Failed to deserialize peeked item")?,
+                    );
+                }
+            }
+            Ok(out)
+        })
+        .await
+        .context("Queue peek task panicked")?
+    }
+
+    /// Non-destructively walk the entire queue in delivery order. Built on
+    /// [`Self::peek`] with no limit — fine for the small debug/inspection
+    /// queues this is used against, not a true streaming API.
+    pub async fn iter(&self) -> Result<Vec<T>> {
+        self.peek(usize::MAX).await
+    }
+
+    /// Scan pending items in delivery order for the first one matching
+    /// `predicate`, removing only that one. Unlike draining the queue and
+    /// pushing every non-matching item back — which reorders the queue and
+    /// can lose items if the process dies mid-drain — every other item's
+    /// position is left untouched.
+    pub async fn remove_matching<F>(&self, predicate: F) -> Result<Option<T>>
+    where
+        F: Fn(&T) -> bool + Send + 'static,
+    {
+        let data_tree = self.data_tree.clone();
+        let index_tree = self.index_tree.clone();
+
+        tokio::task::spawn_blocking(move || {
+            for result in index_tree.iter() {
+                let (idx_key, seq_key) = result?;
+                let Some(data) = data_tree.get(&seq_key)? else {
+                    continue;
+                };
+                let item: T = rmp_serde::from_slice(&data)
+                    .context("Failed to deserialize item during targeted scan")?;
+                if predicate(&item) {
+                    index_tree.remove(&idx_key)?;
+                    data_tree.remove(&seq_key)?;
+                    return Ok(Some(item));
+                }
+            }
+            Ok(None)
+        })
+        .await
+        .context("Queue remove_matching task panicked")?
+    }
+
+    /// Hand out the front item for at-least-once delivery instead of deleting
+    /// it outright. The payload moves from `data_tree`/`index_tree` into
+    /// `inflight_tree` under a fresh lease; the caller must resolve it with
+    /// [`Self::ack`] or [`Self::nack`] before `timeout` elapses, or
+    /// [`Self::reclaim_expired`] will make it available again.
+    pub async fn lease(&self, timeout: Duration) -> Result<Option<(LeaseToken, T)>> {
+        let data_tree = self.data_tree.clone();
+        let index_tree = self.index_tree.clone();
+        let inflight_tree = self.inflight_tree.clone();
+        let expires_at_ms = Utc::now().timestamp_millis() + timeout.as_millis() as i64;
+
+        tokio::task::spawn_blocking(move || {
+            let Some((idx_key, seq_key)) = index_tree.first()? else {
+                return Ok(None);
+            };
+            index_tree.remove(&idx_key)?;
+
+            let Some(data) = data_tree.remove(&seq_key)? else {
+                warn!("Index pointed to non-existent data entry during lease");
+                return Ok(None);
+            };
+
+            let item: T = rmp_serde::from_slice(&data)
+                .context("Failed to deserialize item for lease")?;
+            let lease_id = Uuid::new_v4();
+            let (priority, sequence) = decode_index_key(&idx_key)?;
+            let entry = InflightEntry {
+                data: item.clone(),
+                lease_id,
+                expires_at_ms,
+                delivery_count: 1,
+                priority,
+            };
+            let encoded = rmp_serde::to_vec(&entry).context("Failed to serialize inflight entry")?;
+            inflight_tree.insert(&seq_key, encoded)?;
+
+            debug!("Leased item with sequence {} (lease {})", sequence, lease_id);
+
+            Ok(Some((LeaseToken { sequence, lease_id }, item)))
+        })
+        .await
+        .context("Queue lease task panicked")?
+    }
+
+    /// Permanently remove a leased item once it has been processed
+    /// successfully. Returns `false` if the token no longer matches an
+    /// in-flight lease (already acked, nacked, or reclaimed).
+    pub async fn ack(&self, token: LeaseToken) -> Result<bool> {
+        let inflight_tree = self.inflight_tree.clone();
+        tokio::task::spawn_blocking(move || {
+            let seq_key = token.sequence.to_be_bytes();
+            match inflight_tree.get(&seq_key)? {
+                Some(raw) => {
+                    let entry: InflightEntry<T> = rmp_serde::from_slice(&raw)
+                        .context("Failed to deserialize inflight entry")?;
+                    if entry.lease_id != token.lease_id {
+                        return Ok(false);
+                    }
+                    inflight_tree.remove(&seq_key)?;
+                    debug!("Acked item with sequence {}", token.sequence);
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        })
+        .await
+        .context("Queue ack task panicked")?
+    }
+
+    /// Return a leased item to the front of the queue for redelivery, unless
+    /// it has already been redelivered `max_deliveries` times, in which case
+    /// it is moved to the dead-letter tree instead.
+    pub async fn nack(&self, token: LeaseToken) -> Result<RedeliveryOutcome> {
+        let data_tree = self.data_tree.clone();
+        let index_tree = self.index_tree.clone();
+        let inflight_tree = self.inflight_tree.clone();
+        let dead_letter_tree = self.dead_letter_tree.clone();
+        let max_deliveries = self.max_deliveries;
+        tokio::task::spawn_blocking(move || {
+            let seq_key = token.sequence.to_be_bytes();
+            let Some(raw) = inflight_tree.get(&seq_key)? else {
+                return Ok(RedeliveryOutcome::NotFound);
+            };
+            let entry: InflightEntry<T> = rmp_serde::from_slice(&raw)
+                .context("Failed to deserialize inflight entry")?;
+            if entry.lease_id != token.lease_id {
+                return Ok(RedeliveryOutcome::NotFound);
+            }
+
+            inflight_tree.remove(&seq_key)?;
+
+            if entry.delivery_count >= max_deliveries {
+                let dead_letter = DeadLetterEntry {
+                    data: entry.data,
+                    delivery_count: entry.delivery_count,
+                    priority: entry.priority,
+                };
+                let data = rmp_serde::to_vec(&dead_letter)
+                    .context("Failed to serialize dead-letter entry")?;
+                dead_letter_tree.insert(&seq_key, data)?;
+                warn!(
+                    "Dead-lettered item with sequence {} after {} deliveries",
+                    token.sequence, entry.delivery_count
+                );
+                return Ok(RedeliveryOutcome::DeadLettered);
+            }
+
+            let data = rmp_serde::to_vec(&entry.data).context("Failed to serialize item")?;
+            data_tree.insert(&seq_key, data)?;
+            // Sequence numbers are monotonic, so reinserting under the item's
+            // original priority and sequence naturally sorts it ahead of
+            // anything pushed (at that priority) since.
+            index_tree.insert(index_key(entry.priority, token.sequence), &seq_key)?;
+
+            debug!("Nacked item with sequence {}", token.sequence);
+            Ok(RedeliveryOutcome::Requeued)
+        })
+        .await
+        .context("Queue nack task panicked")?
+    }
+
+    /// Scan `inflight_tree` for leases past their expiry and make them
+    /// available for redelivery again, bumping each item's delivery count, or
+    /// dead-lettering items that have now exceeded `max_deliveries`. Returns
+    /// `(requeued, dead_lettered)` counts.
+    pub async fn reclaim_expired(&self) -> Result<(usize, usize)> {
+        let data_tree = self.data_tree.clone();
+        let index_tree = self.index_tree.clone();
+        let inflight_tree = self.inflight_tree.clone();
+        let dead_letter_tree = self.dead_letter_tree.clone();
+        let max_deliveries = self.max_deliveries;
+        let now_ms = Utc::now().timestamp_millis();
+
+        tokio::task::spawn_blocking(move || {
+            let mut requeued = 0usize;
+            let mut dead_lettered = 0usize;
+
+            for entry in inflight_tree.iter() {
+                let (seq_key, raw) = entry?;
+                let mut inflight: InflightEntry<T> = rmp_serde::from_slice(&raw)
+                    .context("Failed to deserialize inflight entry")?;
+
+                if inflight.expires_at_ms > now_ms {
+                    continue;
+                }
+
+                inflight.delivery_count += 1;
+                inflight_tree.remove(&seq_key)?;
+
+                let sequence = u64::from_be_bytes(
+                    seq_key.as_ref().try_into().context("Invalid sequence number")?,
+                );
+
+                if inflight.delivery_count > max_deliveries {
+                    let dead_letter = DeadLetterEntry {
+                        data: inflight.data,
+                        delivery_count: inflight.delivery_count,
+                        priority: inflight.priority,
+                    };
+                    let data = rmp_serde::to_vec(&dead_letter)
+                        .context("Failed to serialize dead-letter entry")?;
+                    dead_letter_tree.insert(&seq_key, data)?;
+                    warn!(
+                        "Dead-lettered expired lease for sequence {} after {} deliveries",
+                        sequence, inflight.delivery_count
+                    );
+                    dead_lettered += 1;
+                    continue;
+                }
+
+                let data = rmp_serde::to_vec(&inflight.data).context("Failed to serialize item")?;
+                data_tree.insert(&seq_key, data)?;
+                index_tree.insert(index_key(inflight.priority, sequence), &seq_key)?;
+
+                warn!(
+                    "Reclaimed expired lease for sequence {} (delivery #{})",
+                    sequence, inflight.delivery_count
+                );
+                requeued += 1;
+            }
+
+            Ok((requeued, dead_lettered))
+        })
+        .await
+        .context("Queue reclaim_expired task panicked")?
+    }
+
+    /// List dead-lettered items along with their sequence number and final
+    /// delivery count.
+    ///
+    /// The original request described this keyed by `Uuid`, but leasing here
+    /// operates on plain `SledQueue<T>`, which is sequence-keyed and has no
+    /// UUID concept (UUIDs only exist one layer up, in
+    /// [`IndexedSledQueue`]). The sequence number plays the same role: a
+    /// stable, unique handle for [`Self::requeue_dead_letter`].
+    pub async fn dead_letters(&self) -> Result<Vec<(u64, T, u32)>> {
+        let dead_letter_tree = self.dead_letter_tree.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut out = Vec::new();
+            for entry in dead_letter_tree.iter() {
+                let (seq_key, raw) = entry?;
+                let entry: DeadLetterEntry<T> = rmp_serde::from_slice(&raw)
+                    .context("Failed to deserialize dead-letter entry")?;
+                let sequence = u64::from_be_bytes(
+                    seq_key.as_ref().try_into().context("Invalid sequence number")?,
+                );
+                out.push((sequence, entry.data, entry.delivery_count));
+            }
+            Ok(out)
+        })
+        .await
+        .context("Queue dead_letters task panicked")?
+    }
+
+    /// Move a dead-lettered item back into the live queue for another
+    /// attempt, resetting its delivery count. Returns `false` if `sequence`
+    /// does not match a dead-lettered item.
+    pub async fn requeue_dead_letter(&self, sequence: u64) -> Result<bool> {
+        let data_tree = self.data_tree.clone();
+        let index_tree = self.index_tree.clone();
+        let dead_letter_tree = self.dead_letter_tree.clone();
+        tokio::task::spawn_blocking(move || {
+            let seq_key = sequence.to_be_bytes();
+            let Some(raw) = dead_letter_tree.remove(&seq_key)? else {
+                return Ok(false);
+            };
+            let entry: DeadLetterEntry<T> = rmp_serde::from_slice(&raw)
+                .context("Failed to deserialize dead-letter entry")?;
+            let data = rmp_serde::to_vec(&entry.data).context("Failed to serialize item")?;
+            data_tree.insert(&seq_key, data)?;
+            index_tree.insert(index_key(entry.priority, sequence), &seq_key)?;
+            info!("Requeued dead-lettered item with sequence {}", sequence);
+            Ok(true)
+        })
+        .await
+        .context("Queue requeue_dead_letter task panicked")?
+    }
+
+    /// Permanently discard all dead-lettered items, returning how many were
+    /// removed.
+    pub async fn purge_dead_letters(&self) -> Result<usize> {
+        let dead_letter_tree = self.dead_letter_tree.clone();
+        tokio::task::spawn_blocking(move || {
+            let count = dead_letter_tree.len();
+            dead_letter_tree.clear()?;
+            Ok(count)
+        })
+        .await
+        .context("Queue purge_dead_letters task panicked")?
+    }
 }
 
 impl<T> Queue<T> for SledQueue<T>
@@ -145,62 +720,58 @@ where
     T: Serialize + DeserializeOwned + Clone + Send + Sync,
 {
     async fn push(&self, item: &T) -> Result<()> {
-        // Serialize the item
-        let data = rmp_serde::to_vec(item)
-            .context("Failed to serialize item")?;
-        
-        // Get next sequence number
-        let seq = self.counter.fetch_add(1, Ordering::SeqCst);
-        let seq_key = seq.to_be_bytes();
-        
-        // Store in data tree with sequence as key
-        self.data_tree.insert(&seq_key, data.as_slice())
-            .with_context(|| format!("Failed to insert item with sequence {}", seq))?;
-        
-        debug!("Pushed item with sequence {}", seq);
-        Ok(())
+        // Serialization and the sled write are both blocking (CPU + syscall),
+        // so run them off the async runtime to keep the reactor responsive.
+        self.push_with_priority(item, DEFAULT_PRIORITY).await
     }
-    
+
     async fn pop(&self) -> Result<Option<T>> {
-        // Get the first item from index tree
-        if let Some((seq_key, _)) = self.index_tree.first()? {
-            // Remove from index first
-            self.index_tree.remove(&seq_key)?;
-            
-            // Get and remove from data tree
-            if let Some(data) = self.data_tree.remove(&seq_key)? {
+        let data_tree = self.data_tree.clone();
+        let index_tree = self.index_tree.clone();
+
+        // The tree scan, removal, and deserialization are all blocking.
+        tokio::task::spawn_blocking(move || {
+            // Get the first item from index tree (lowest priority, then
+            // lowest sequence). Its value is the plain sequence key used to
+            // look the item up in `data_tree`.
+            if let Some((idx_key, seq_key)) = index_tree.first()? {
+                // Remove from index first
+                index_tree.remove(&idx_key)?;
+
+                // Get and remove from data tree
+                if let Some(data) = data_tree.remove(&seq_key)? {
+                    let item = rmp_serde::from_slice(&data)
+                        .context("Failed to deserialize popped item")?;
+
+                    let (_, seq) = decode_index_key(&idx_key)?;
+                    debug!("Popped item with sequence {}", seq);
+
+                    return Ok(Some(item));
+                } else {
+                    warn!("Index pointed to non-existent data entry");
+                }
+            }
+
+            // Fallback: pop directly from data tree if index is inconsistent
+            if let Some((seq_key, data)) = data_tree.first()? {
+                data_tree.remove(&seq_key)?;
+
                 let item = rmp_serde::from_slice(&data)
                     .context("Failed to deserialize popped item")?;
-                
+
                 let seq = u64::from_be_bytes(
                     seq_key.as_ref().try_into()
                         .context("Invalid sequence number")?
                 );
-                debug!("Popped item with sequence {}", seq);
-                
+                debug!("Popped item with sequence {} (fallback)", seq);
+
                 return Ok(Some(item));
-            } else {
-                warn!("Index pointed to non-existent data entry");
             }
-        }
-        
-        // Fallback: pop directly from data tree if index is inconsistent
-        if let Some((seq_key, data)) = self.data_tree.first()? {
-            self.data_tree.remove(&seq_key)?;
-            
-            let item = rmp_serde::from_slice(&data)
-                .context("Failed to deserialize popped item")?;
-            
-            let seq = u64::from_be_bytes(
-                seq_key.as_ref().try_into()
-                    .context("Invalid sequence number")?
-            );
-            debug!("Popped item with sequence {} (fallback)", seq);
-            
-            return Ok(Some(item));
-        }
-        
-        Ok(None)
+
+            Ok(None)
+        })
+        .await
+        .context("Queue pop task panicked")?
     }
     
     async fn get(&self, _id: &Uuid) -> Result<Option<T>> {
@@ -251,6 +822,8 @@ pub struct QueueStats {
     pub items: usize,
     pub index_entries: usize,
     pub disk_size_bytes: u64,
+    /// Items moved to the dead-letter tree after exceeding `max_deliveries`.
+    pub dead_letter_entries: usize,
 }
 
 /// Efficient SledQueue implementation with UUID indexing
@@ -329,19 +902,114 @@ where
                 seq_bytes.as_ref().try_into()
                     .context("Invalid sequence in UUID index")?
             );
-            
+
             let seq_key = seq.to_be_bytes();
             let removed = self.queue.data_tree.remove(&seq_key)?.is_some();
-            
+
             if removed {
                 debug!("Removed item with UUID {}", id);
             }
-            
+
             return Ok(removed);
         }
-        
+
         Ok(false)
     }
+
+    /// Remove many items by UUID in one atomic batch, returning how many were
+    /// actually present and removed.
+    pub async fn remove_batch(&self, ids: &[Uuid]) -> Result<usize> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let mut seq_keys = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(seq_bytes) = self.uuid_index.get(id.as_bytes())? {
+                seq_keys.push(seq_bytes);
+            }
+        }
+
+        if seq_keys.is_empty() {
+            return Ok(0);
+        }
+
+        let mut index_batch = sled::Batch::default();
+        for id in ids {
+            index_batch.remove(id.as_bytes().as_slice());
+        }
+        self.uuid_index
+            .apply_batch(index_batch)
+            .context("Failed to remove UUID index batch")?;
+
+        let mut data_batch = sled::Batch::default();
+        for seq_key in &seq_keys {
+            data_batch.remove(seq_key.clone());
+        }
+        self.queue
+            .data_tree
+            .apply_batch(data_batch)
+            .context("Failed to remove data batch")?;
+
+        debug!("Removed batch of {} items by UUID", seq_keys.len());
+        Ok(seq_keys.len())
+    }
+
+    /// Inspect pending items with sequence numbers in `[from_seq, to_seq)`
+    /// without removing them, so callers can snapshot a window of the queue.
+    ///
+    /// Scans `queue.data_tree` directly rather than `queue.index_tree`:
+    /// `data_tree` stays plain-sequence-keyed (it's the same tree `pop`
+    /// removes from), whereas `index_tree`'s keys are now `(priority,
+    /// sequence)` composites used for delivery ordering, not contiguous
+    /// sequence ranges. This relies on the sequence counter staying
+    /// monotonic across priorities, so that sequence order here matches push
+    /// order.
+    pub async fn range(&self, from_seq: u64, to_seq: u64) -> Result<Vec<(Uuid, T)>> {
+        let data_tree = self.queue.data_tree.clone();
+        let from_key = from_seq.to_be_bytes();
+        let to_key = to_seq.to_be_bytes();
+
+        tokio::task::spawn_blocking(move || {
+            let mut out = Vec::new();
+            for result in data_tree.range(from_key..to_key) {
+                let (_, data) = result?;
+                let entry: QueueEntry<T> = rmp_serde::from_slice(&data)
+                    .context("Failed to deserialize queue entry during range scan")?;
+                out.push((entry.id, entry.data));
+            }
+            Ok(out)
+        })
+        .await
+        .context("Queue range task panicked")?
+    }
+
+    /// Peek at the next `n` pending items in delivery order (lowest priority,
+    /// then earliest sequence) without popping them, by scanning
+    /// `queue.index_tree` and resolving each entry against `queue.data_tree`.
+    pub async fn peek_n(&self, n: usize) -> Result<Vec<(Uuid, T)>> {
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+
+        let data_tree = self.queue.data_tree.clone();
+        let index_tree = self.queue.index_tree.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut out = Vec::with_capacity(n);
+            for result in index_tree.iter().take(n) {
+                let (_, seq_key) = result?;
+                if let Some(data) = data_tree.get(&seq_key)? {
+                    let entry: QueueEntry<T> = rmp_serde::from_slice(&data)
+                        .context("Failed to deserialize queue entry during peek")?;
+                    out.push((entry.id, entry.data));
+                }
+            }
+            Ok(out)
+        })
+        .await
+        .context("Queue peek_n task panicked")?
+    }
 }
 
 #[cfg(test)]
@@ -442,4 +1110,145 @@ mod tests {
         assert!(queue.remove_by_uuid(&id).await.unwrap());
         assert!(queue.get_by_uuid(&id).await.unwrap().is_none());
     }
+
+    #[tokio::test]
+    async fn test_push_batch_then_pop_batch_preserves_fifo_order() {
+        let queue = SledQueue::<TestItem>::new_temp().unwrap();
+
+        let items = vec![
+            TestItem { id: Uuid::new_v4(), data: "first".to_string() },
+            TestItem { id: Uuid::new_v4(), data: "second".to_string() },
+            TestItem { id: Uuid::new_v4(), data: "third".to_string() },
+        ];
+        queue.push_batch(&items).await.unwrap();
+        assert_eq!(queue.len().await.unwrap(), 3);
+
+        let popped = queue.pop_batch(2).await.unwrap();
+        assert_eq!(popped.len(), 2);
+        assert_eq!(popped[0].data, "first");
+        assert_eq!(popped[1].data, "second");
+        assert_eq!(queue.len().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_lease_then_ack_removes_item_permanently() {
+        let queue = SledQueue::<TestItem>::new_temp().unwrap();
+        let item = TestItem { id: Uuid::new_v4(), data: "leased".to_string() };
+        queue.push(&item).await.unwrap();
+
+        let (token, leased) = queue.lease(Duration::from_secs(30)).await.unwrap().unwrap();
+        assert_eq!(leased.data, item.data);
+        assert!(queue.is_empty().await.unwrap());
+
+        assert!(queue.ack(token).await.unwrap());
+        // Acking again with the same (now-stale) token is a no-op, not an error.
+        assert!(!queue.ack(token).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_nack_requeues_item_until_max_deliveries_then_dead_letters() {
+        let queue = SledQueue::<TestItem>::new_temp()
+            .unwrap()
+            .with_max_deliveries(2);
+        let item = TestItem { id: Uuid::new_v4(), data: "flaky".to_string() };
+        queue.push(&item).await.unwrap();
+
+        // Delivery 1: nacked, under max_deliveries, requeued.
+        let (token, _) = queue.lease(Duration::from_secs(30)).await.unwrap().unwrap();
+        assert_eq!(queue.nack(token).await.unwrap(), RedeliveryOutcome::Requeued);
+        assert_eq!(queue.len().await.unwrap(), 1);
+
+        // Delivery 2: at max_deliveries, the next nack dead-letters it instead
+        // of requeuing it again.
+        let (token, _) = queue.lease(Duration::from_secs(30)).await.unwrap().unwrap();
+        assert_eq!(queue.nack(token).await.unwrap(), RedeliveryOutcome::DeadLettered);
+        assert!(queue.is_empty().await.unwrap());
+
+        let dead_letters = queue.dead_letters().await.unwrap();
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].1.data, item.data);
+        assert_eq!(dead_letters[0].2, 2);
+    }
+
+    #[tokio::test]
+    async fn test_nack_with_stale_token_is_not_found() {
+        let queue = SledQueue::<TestItem>::new_temp().unwrap();
+        let item = TestItem { id: Uuid::new_v4(), data: "stale".to_string() };
+        queue.push(&item).await.unwrap();
+
+        let (token, _) = queue.lease(Duration::from_secs(30)).await.unwrap().unwrap();
+        assert_eq!(queue.nack(token).await.unwrap(), RedeliveryOutcome::Requeued);
+        // The lease was already resolved above, so resolving it again finds
+        // nothing in `inflight_tree`.
+        assert_eq!(queue.nack(token).await.unwrap(), RedeliveryOutcome::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_reclaim_expired_requeues_and_dead_letters() {
+        let queue = SledQueue::<TestItem>::new_temp()
+            .unwrap()
+            .with_max_deliveries(1);
+        let items = vec![
+            TestItem { id: Uuid::new_v4(), data: "expires-once".to_string() },
+            TestItem { id: Uuid::new_v4(), data: "expires-twice".to_string() },
+        ];
+        queue.push_batch(&items).await.unwrap();
+
+        // Lease both with an already-elapsed timeout so they're immediately
+        // reclaimable.
+        queue.lease(Duration::from_millis(0)).await.unwrap().unwrap();
+        queue.lease(Duration::from_millis(0)).await.unwrap().unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        // First pass: both leases are past their expiry and at
+        // delivery_count 1 (== max_deliveries), so the bump to 2 sends both
+        // straight to the dead-letter tree.
+        let (requeued, dead_lettered) = queue.reclaim_expired().await.unwrap();
+        assert_eq!(requeued, 0);
+        assert_eq!(dead_lettered, 2);
+        assert!(queue.is_empty().await.unwrap());
+        assert_eq!(queue.dead_letters().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_requeue_dead_letter_restores_item_for_another_attempt() {
+        let queue = SledQueue::<TestItem>::new_temp()
+            .unwrap()
+            .with_max_deliveries(1);
+        let item = TestItem { id: Uuid::new_v4(), data: "revivable".to_string() };
+        queue.push(&item).await.unwrap();
+
+        let (token, _) = queue.lease(Duration::from_secs(30)).await.unwrap().unwrap();
+        assert_eq!(queue.nack(token).await.unwrap(), RedeliveryOutcome::DeadLettered);
+
+        let dead_letters = queue.dead_letters().await.unwrap();
+        let sequence = dead_letters[0].0;
+
+        assert!(queue.requeue_dead_letter(sequence).await.unwrap());
+        assert!(queue.dead_letters().await.unwrap().is_empty());
+        assert_eq!(queue.len().await.unwrap(), 1);
+
+        let popped = queue.pop().await.unwrap().unwrap();
+        assert_eq!(popped.data, item.data);
+    }
+
+    #[tokio::test]
+    async fn test_purge_dead_letters_clears_tree_and_returns_count() {
+        let queue = SledQueue::<TestItem>::new_temp()
+            .unwrap()
+            .with_max_deliveries(1);
+        let items = vec![
+            TestItem { id: Uuid::new_v4(), data: "a".to_string() },
+            TestItem { id: Uuid::new_v4(), data: "b".to_string() },
+        ];
+        queue.push_batch(&items).await.unwrap();
+
+        for _ in 0..2 {
+            let (token, _) = queue.lease(Duration::from_secs(30)).await.unwrap().unwrap();
+            assert_eq!(queue.nack(token).await.unwrap(), RedeliveryOutcome::DeadLettered);
+        }
+
+        assert_eq!(queue.purge_dead_letters().await.unwrap(), 2);
+        assert!(queue.dead_letters().await.unwrap().is_empty());
+    }
 }
\ No newline at end of file
@@ -355,6 +355,12 @@ impl MessageTracker {
         stats
     }
 
+    /// Get the end-to-end processing duration for a message, if it completed.
+    pub async fn processing_duration(&self, message_id: Uuid) -> Option<chrono::Duration> {
+        let messages = self.messages.read().await;
+        messages.get(&message_id).and_then(|info| info.processing_duration())
+    }
+
     /// Get messages assigned to a specific worker
     pub async fn get_worker_messages(&self, worker_id: &str) -> Vec<Uuid> {
         let assignments = self.worker_assignments.read().await;
@@ -0,0 +1,236 @@
+//! End-to-end integration tests that drive the compiled `transcriber` binary.
+//!
+//! Unlike the in-crate unit tests, these launch the actual binary as a child
+//! process against temp-dir queues, feed it a known [`AudioChunk`], and assert
+//! on the result that lands on the output queue. A stub "python" worker script
+//! stands in for the real model so the tests are deterministic and hermetic —
+//! no network, no model download, no GPU.
+
+use std::io::Write;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command};
+use std::time::{Duration, Instant};
+
+use transcriber::protocol::{AudioChunk, Transcript, TranscriptionError};
+use transcriber::queue::{Queue, SledQueue};
+use uuid::Uuid;
+
+/// Path to the binary under test, provided by Cargo for integration tests.
+fn binary() -> PathBuf {
+    env!("CARGO_BIN_EXE_transcriber").into()
+}
+
+/// Write an executable stub worker script and return its path.
+fn write_stub_worker(dir: &Path, name: &str, body: &str) -> PathBuf {
+    let path = dir.join(name);
+    let mut file = std::fs::File::create(&path).unwrap();
+    file.write_all(body.as_bytes()).unwrap();
+    file.flush().unwrap();
+    let mut perms = std::fs::metadata(&path).unwrap().permissions();
+    perms.set_mode(0o755);
+    std::fs::set_permissions(&path, perms).unwrap();
+    path
+}
+
+/// Spawn the transcriber binary wired to the given queue dirs and worker command.
+fn spawn_service(input: &Path, output: &Path, worker_cmd: &Path) -> Child {
+    Command::new(binary())
+        .arg("--input-queue")
+        .arg(input)
+        .arg("--output-queue")
+        .arg(output)
+        .arg("--workers")
+        .arg("1")
+        .arg("--python-cmd")
+        .arg(worker_cmd)
+        .arg("--python-args")
+        .arg("")
+        .arg("--poll-interval")
+        .arg("50")
+        .arg("--log-level")
+        .arg("debug")
+        .spawn()
+        .expect("failed to launch transcriber binary")
+}
+
+/// A stub worker that echoes a fixed transcript for every chunk it reads, using
+/// the length-prefixed MessagePack framing the real Python worker speaks.
+const ECHO_WORKER: &str = r#"#!/usr/bin/env python3
+import sys, struct
+try:
+    import msgpack
+except ImportError:
+    sys.exit("msgpack not available")
+
+stdin = sys.stdin.buffer
+stdout = sys.stdout.buffer
+while True:
+    header = stdin.read(4)
+    if len(header) < 4:
+        break
+    (length,) = struct.unpack("<I", header)
+    payload = stdin.read(length)
+    chunk = msgpack.unpackb(payload, raw=False)
+    reply = {"id": chunk["id"], "text": "hello world", "confidence": 1.0}
+    encoded = msgpack.packb(reply, use_bin_type=True)
+    stdout.write(struct.pack("<I", len(encoded)))
+    stdout.write(encoded)
+    stdout.flush()
+"#;
+
+/// A stub worker that crashes immediately, exercising the restart path.
+const CRASHING_WORKER: &str = r#"#!/usr/bin/env python3
+import sys
+sys.exit(1)
+"#;
+
+/// Poll the output queue until a result arrives or the deadline elapses.
+async fn await_result(
+    output: &SledQueue<Result<Transcript, TranscriptionError>>,
+    deadline: Duration,
+) -> Option<Result<Transcript, TranscriptionError>> {
+    let start = Instant::now();
+    while start.elapsed() < deadline {
+        if let Ok(Some(result)) = output.pop().await {
+            return Some(result);
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    None
+}
+
+#[tokio::test]
+async fn transcribes_a_known_chunk_end_to_end() {
+    let dir = tempfile::tempdir().unwrap();
+    let input_dir = dir.path().join("input");
+    let output_dir = dir.path().join("output");
+    let worker = write_stub_worker(dir.path(), "echo_worker.py", ECHO_WORKER);
+
+    // Seed the input queue before the service starts draining it.
+    let chunk = AudioChunk::new(vec![0.0f32; 16000], 16000, 1);
+    let id = chunk.id;
+    {
+        let input = SledQueue::<AudioChunk>::new(&input_dir).unwrap();
+        input.push(&chunk).await.unwrap();
+        input.flush().await.unwrap();
+    }
+
+    let mut child = spawn_service(&input_dir, &output_dir, &worker);
+
+    let output = SledQueue::<Result<Transcript, TranscriptionError>>::new(&output_dir).unwrap();
+    let result = await_result(&output, Duration::from_secs(20)).await;
+
+    let _ = child.kill();
+    let _ = child.wait();
+
+    let transcript = result
+        .expect("no result reached the output queue")
+        .expect("expected a successful transcript");
+    assert_eq!(transcript.id, id);
+    assert_eq!(transcript.text, "hello world");
+}
+
+#[tokio::test]
+async fn restarts_a_crashing_worker() {
+    let dir = tempfile::tempdir().unwrap();
+    let input_dir = dir.path().join("input");
+    let output_dir = dir.path().join("output");
+    let worker = write_stub_worker(dir.path(), "crashing_worker.py", CRASHING_WORKER);
+
+    let mut child = spawn_service(&input_dir, &output_dir, &worker);
+
+    // Let the supervisor observe the crash and attempt a restart.
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    let still_running = child.try_wait().unwrap().is_none();
+
+    let _ = child.kill();
+    let _ = child.wait();
+
+    // The service itself must stay up and keep retrying the worker.
+    assert!(still_running, "service exited when a worker crashed");
+}
+
+/// Grab a currently-free localhost TCP port by binding to port 0 and releasing it.
+#[cfg(feature = "zeromq-queue")]
+fn free_port() -> u16 {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+#[cfg(feature = "zeromq-queue")]
+#[tokio::test]
+async fn boots_and_stops_in_zeromq_mode() {
+    let dir = tempfile::tempdir().unwrap();
+    let input_dir = dir.path().join("input");
+    let output_dir = dir.path().join("output");
+    let worker = write_stub_worker(dir.path(), "echo_worker.py", ECHO_WORKER);
+
+    let push = format!("tcp://127.0.0.1:{}", free_port());
+    let pull = format!("tcp://127.0.0.1:{}", free_port());
+    let control = format!("tcp://127.0.0.1:{}", free_port());
+
+    let mut child = Command::new(binary())
+        .arg("--input-queue").arg(&input_dir)
+        .arg("--output-queue").arg(&output_dir)
+        .arg("--workers").arg("1")
+        .arg("--python-cmd").arg(&worker)
+        .arg("--python-args").arg("")
+        .arg("--use-zeromq").arg("true")
+        .arg("--zmq-push-endpoint").arg(&push)
+        .arg("--zmq-pull-endpoint").arg(&pull)
+        .arg("--zmq-control-endpoint").arg(&control)
+        .spawn()
+        .expect("failed to launch transcriber binary");
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    unsafe {
+        libc::kill(child.id() as libc::pid_t, libc::SIGTERM);
+    }
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        if child.try_wait().unwrap().is_some() {
+            break;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            panic!("zeromq-mode service did not shut down within the deadline");
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
+#[tokio::test]
+async fn shuts_down_cleanly_on_sigterm() {
+    let dir = tempfile::tempdir().unwrap();
+    let input_dir = dir.path().join("input");
+    let output_dir = dir.path().join("output");
+    let worker = write_stub_worker(dir.path(), "echo_worker.py", ECHO_WORKER);
+
+    let mut child = spawn_service(&input_dir, &output_dir, &worker);
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    // SIGTERM should trigger the graceful shutdown path.
+    unsafe {
+        libc::kill(child.id() as libc::pid_t, libc::SIGTERM);
+    }
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            assert!(status.success() || status.code().is_none());
+            break;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            panic!("service did not shut down within the deadline");
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}